@@ -3,7 +3,6 @@ use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
 use bevy_remote::RemoteMethodSystemId;
 use bevy_remote::RemoteMethods;
-use bevy_remote::error_codes::INTERNAL_ERROR;
 use schemars::Schema;
 use serde::Serialize;
 use serde_json::Value;
@@ -14,6 +13,7 @@ use super::RegisteredAgentTools;
 use crate::constants::AGENT_TOOLS_CATALOG_VERSION;
 use crate::constants::BACKING_METHOD_MISSING_REASON;
 use crate::constants::BACKING_METHOD_WATCHING_REASON;
+use crate::error::BrpExtrasError;
 
 /// Borrowed wire record that preserves the registered raw schemas unchanged.
 #[derive(Serialize)]
@@ -70,7 +70,9 @@ pub(crate) fn handler(
         version: AGENT_TOOLS_CATALOG_VERSION,
         tools,
     })
-    .map_err(|error| BrpError::internal(format!("failed to serialize agent tool catalog: {error}")))
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("failed to serialize agent tool catalog: {error}"))
+    })
 }
 
 fn validate_backing_method(
@@ -93,18 +95,17 @@ fn validate_backing_method(
 }
 
 fn backing_method_error(tool: &CatalogAgentTool<'_>, reason: &str, detail: &str) -> BrpError {
-    BrpError {
-        code:    INTERNAL_ERROR,
-        message: format!(
+    BrpExtrasError::Internal.with_details(
+        format!(
             "agent tool `{}` cannot be listed: backing BRP method `{}` {detail}",
             tool.name, tool.method,
         ),
-        data:    Some(json!({
+        json!({
             "name": tool.name,
             "method": tool.method,
             "reason": reason,
-        })),
-    }
+        }),
+    )
 }
 
 #[cfg(test)]
@@ -252,15 +253,18 @@ mod tests {
         assert_eq!(
             call_catalog(&mut app),
             Err(BrpError {
-                code:    INTERNAL_ERROR,
+                code:    BrpExtrasError::Internal.code(),
                 message: String::from(
                     "agent tool `test.missing` cannot be listed: backing BRP method \
                      `test/missing` is not registered",
                 ),
                 data:    Some(json!({
-                    "name": "test.missing",
-                    "method": MISSING_METHOD,
-                    "reason": BACKING_METHOD_MISSING_REASON,
+                    "kind": "internal",
+                    "details": {
+                        "name": "test.missing",
+                        "method": MISSING_METHOD,
+                        "reason": BACKING_METHOD_MISSING_REASON,
+                    },
                 })),
             }),
         );
@@ -279,15 +283,18 @@ mod tests {
         assert_eq!(
             call_catalog(&mut app),
             Err(BrpError {
-                code:    INTERNAL_ERROR,
+                code:    BrpExtrasError::Internal.code(),
                 message: String::from(
                     "agent tool `test.watching` cannot be listed: backing BRP method \
                      `test/watching` is registered as a watching method",
                 ),
                 data:    Some(json!({
-                    "name": "test.watching",
-                    "method": WATCHING_METHOD,
-                    "reason": BACKING_METHOD_WATCHING_REASON,
+                    "kind": "internal",
+                    "details": {
+                        "name": "test.watching",
+                        "method": WATCHING_METHOD,
+                        "reason": BACKING_METHOD_WATCHING_REASON,
+                    },
                 })),
             }),
         );
@@ -0,0 +1,378 @@
+//! Asset inspection handlers for BRP extras
+//!
+//! Assets are found through reflection: every asset type registered with `ReflectAsset` (which
+//! `App::register_asset_reflect` and most built-in asset types provide) can be listed. Load state
+//! and paths come from the `AssetServer`, so assets created at runtime show up without a path.
+
+use bevy::asset::AssetPath;
+use bevy::asset::DependencyLoadState;
+use bevy::asset::LoadState;
+use bevy::asset::RecursiveDependencyLoadState;
+use bevy::asset::ReflectAsset;
+use bevy::asset::UntypedAssetId;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistration;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::ASSET_LOAD_STATE_FAILED;
+use crate::constants::ASSET_LOAD_STATE_LOADED;
+use crate::constants::ASSET_LOAD_STATE_LOADING;
+use crate::constants::ASSET_LOAD_STATE_NOT_LOADED;
+use crate::constants::ASSET_LOAD_STATE_UNTRACKED;
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `list_assets`
+#[derive(Deserialize, Default, JsonSchema)]
+pub(crate) struct ListAssetsRequest {
+    /// Full or short type path of the asset type to list (None = list asset types)
+    #[serde(default)]
+    asset_type: Option<String>,
+}
+
+/// Request structure for `get_asset_info`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct GetAssetInfoRequest {
+    /// Full or short type path of the asset type
+    asset_type:    String,
+    /// Asset id as returned by `list_assets`
+    #[serde(default)]
+    id:            Option<String>,
+    /// Asset path as loaded by the `AssetServer`
+    #[serde(default)]
+    path:          Option<String>,
+    /// Include the reflected asset value
+    #[serde(default)]
+    include_value: bool,
+}
+
+/// Request structure for `reload_asset`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct ReloadAssetRequest {
+    /// Asset path to reload from its source
+    path: String,
+}
+
+/// One registered asset type and how many assets of it exist
+#[derive(Serialize)]
+struct AssetTypeSummary {
+    type_path: String,
+    count:     usize,
+}
+
+/// Response structure for `list_assets` without an asset type
+#[derive(Serialize)]
+struct ListAssetTypesResponse {
+    asset_types: Vec<AssetTypeSummary>,
+}
+
+/// One asset entry in a `list_assets` response
+#[derive(Serialize)]
+struct AssetEntry {
+    id:         String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path:       Option<String>,
+    load_state: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    load_error: Option<String>,
+}
+
+/// Response structure for `list_assets` with an asset type
+#[derive(Serialize)]
+struct ListAssetsResponse {
+    asset_type: String,
+    count:      usize,
+    assets:     Vec<AssetEntry>,
+}
+
+/// Response structure for `get_asset_info`
+#[derive(Serialize)]
+struct AssetInfoResponse {
+    asset_type:                      String,
+    #[serde(flatten)]
+    asset:                           AssetEntry,
+    dependency_load_state:           &'static str,
+    recursive_dependency_load_state: &'static str,
+    /// Whether the asset's data is currently stored in `Assets<T>`
+    present:                         bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value:                           Option<Value>,
+}
+
+/// Response structure for `reload_asset`
+#[derive(Serialize)]
+struct ReloadAssetResponse {
+    path:                String,
+    previous_load_state: &'static str,
+    message:             String,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `list_assets` BRP method
+///
+/// Without `asset_type`, lists every reflected asset type with its asset count. With
+/// `asset_type`, lists that type's assets with their ids, paths, and load states.
+pub(crate) fn list_assets_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: ListAssetsRequest = parse_optional(params)?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let Some(asset_type) = request.asset_type else {
+        let mut asset_types: Vec<AssetTypeSummary> = registry
+            .iter()
+            .filter_map(|registration| {
+                let reflect_asset = registration.data::<ReflectAsset>()?;
+                assets_initialized(world, reflect_asset).then(|| AssetTypeSummary {
+                    type_path: registration.type_info().type_path().to_string(),
+                    count:     reflect_asset.ids(world).count(),
+                })
+            })
+            .collect();
+        asset_types.sort_by(|a, b| a.type_path.cmp(&b.type_path));
+        return serialize_response(ListAssetTypesResponse { asset_types });
+    };
+
+    let (registration, reflect_asset) = resolve_asset_type(&registry, &asset_type)?;
+    let type_path = registration.type_info().type_path().to_string();
+    require_initialized(world, reflect_asset, &asset_type)?;
+    let asset_server = world.get_resource::<AssetServer>();
+    let mut assets: Vec<AssetEntry> = reflect_asset
+        .ids(world)
+        .map(|id| asset_entry(asset_server, id))
+        .collect();
+    assets.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.id.cmp(&b.id)));
+    drop(registry);
+
+    serialize_response(ListAssetsResponse {
+        asset_type: type_path,
+        count: assets.len(),
+        assets,
+    })
+}
+
+/// Handler for `get_asset_info` BRP method
+///
+/// Looks up one asset by `id` or `path` and reports its load and dependency states, optionally
+/// including the reflected asset value.
+pub(crate) fn get_asset_info_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: GetAssetInfoRequest = parse_required(params)?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let (registration, reflect_asset) = resolve_asset_type(&registry, &request.asset_type)?;
+    require_initialized(world, reflect_asset, &request.asset_type)?;
+    let asset_server = world.get_resource::<AssetServer>();
+
+    let id = match (&request.id, &request.path) {
+        (Some(id), None) => reflect_asset
+            .ids(world)
+            .find(|candidate| candidate.to_string() == *id),
+        (None, Some(path)) => asset_server
+            .and_then(|server| server.get_handle_untyped(AssetPath::from(path.clone())))
+            .map(|handle| handle.id())
+            .filter(|id| id.type_id() == registration.type_id()),
+        _ => {
+            return Err(
+                BrpExtrasError::InvalidParams.error("Provide exactly one of 'id' or 'path'")
+            );
+        },
+    }
+    .ok_or_else(|| {
+        let lookup = request.id.as_ref().map_or_else(
+            || format!("path '{}'", request.path.as_deref().unwrap_or_default()),
+            |id| format!("id '{id}'"),
+        );
+        BrpExtrasError::AssetNotFound.with_details(
+            format!("No '{}' asset found for {lookup}", request.asset_type),
+            json!({
+                "asset_type": request.asset_type,
+                "id": request.id,
+                "path": request.path,
+            }),
+        )
+    })?;
+
+    let reflected = reflect_asset.get(world, id);
+    let value = if request.include_value {
+        reflected
+            .map(|asset| {
+                serde_json::to_value(TypedReflectSerializer::new(
+                    asset.as_partial_reflect(),
+                    &registry,
+                ))
+            })
+            .transpose()
+            .map_err(|error| {
+                BrpExtrasError::Internal.error(format!("Failed to serialize asset value: {error}"))
+            })?
+    } else {
+        None
+    };
+
+    serialize_response(AssetInfoResponse {
+        asset_type: registration.type_info().type_path().to_string(),
+        asset: asset_entry(asset_server, id),
+        dependency_load_state: asset_server
+            .and_then(|server| server.get_dependency_load_state(id))
+            .map_or(ASSET_LOAD_STATE_UNTRACKED, |state| {
+                dependency_load_state_label(&state)
+            }),
+        recursive_dependency_load_state: asset_server
+            .and_then(|server| server.get_recursive_dependency_load_state(id))
+            .map_or(ASSET_LOAD_STATE_UNTRACKED, |state| {
+                recursive_dependency_load_state_label(&state)
+            }),
+        present: reflected.is_some(),
+        value,
+    })
+}
+
+/// Handler for `reload_asset` BRP method
+///
+/// Queues a reload of an already loaded asset path. The reload completes asynchronously; poll
+/// `get_asset_info` to see the new load state.
+pub(crate) fn reload_asset_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: ReloadAssetRequest = parse_required(params)?;
+    let asset_server = world.get_resource::<AssetServer>().ok_or_else(|| {
+        BrpExtrasError::Unsupported
+            .error("AssetServer not found - AssetPlugin may not be installed")
+    })?;
+
+    let handle = asset_server
+        .get_handle_untyped(AssetPath::from(request.path.clone()))
+        .ok_or_else(|| {
+            BrpExtrasError::AssetNotFound.with_details(
+                format!("Asset '{}' is not loaded", request.path),
+                json!({ "path": request.path }),
+            )
+        })?;
+    let previous_load_state = asset_server
+        .get_load_state(handle.id())
+        .map_or(ASSET_LOAD_STATE_UNTRACKED, |state| {
+            load_state_label(&state).0
+        });
+
+    asset_server.reload(request.path.clone());
+
+    serialize_response(ReloadAssetResponse {
+        message: format!("Reload of '{}' queued", request.path),
+        path: request.path,
+        previous_load_state,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Find a reflected asset type by full type path, falling back to the short type path.
+fn resolve_asset_type<'r>(
+    registry: &'r TypeRegistry,
+    asset_type: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectAsset), BrpError> {
+    let registration = registry
+        .get_with_type_path(asset_type)
+        .or_else(|| registry.get_with_short_type_path(asset_type))
+        .ok_or_else(|| {
+            BrpExtrasError::AssetNotFound.with_details(
+                format!("Unknown asset type '{asset_type}'"),
+                json!({ "asset_type": asset_type }),
+            )
+        })?;
+    let reflect_asset = registration.data::<ReflectAsset>().ok_or_else(|| {
+        BrpExtrasError::AssetNotFound.with_details(
+            format!(
+                "'{asset_type}' is not a reflected asset type - register it with `register_asset_reflect`"
+            ),
+            json!({ "asset_type": asset_type }),
+        )
+    })?;
+    Ok((registration, reflect_asset))
+}
+
+/// `Assets<T>` only exists once the asset type has been initialized, and `ReflectAsset` accessors
+/// panic without it.
+fn assets_initialized(world: &World, reflect_asset: &ReflectAsset) -> bool {
+    world
+        .components()
+        .get_id(reflect_asset.assets_resource_type_id())
+        .is_some_and(|component_id| world.contains_resource_by_id(component_id))
+}
+
+fn require_initialized(
+    world: &World,
+    reflect_asset: &ReflectAsset,
+    asset_type: &str,
+) -> Result<(), BrpError> {
+    if assets_initialized(world, reflect_asset) {
+        Ok(())
+    } else {
+        Err(BrpExtrasError::AssetNotFound.with_details(
+            format!("Asset type '{asset_type}' has not been initialized with `init_asset`"),
+            json!({ "asset_type": asset_type }),
+        ))
+    }
+}
+
+fn asset_entry(asset_server: Option<&AssetServer>, id: UntypedAssetId) -> AssetEntry {
+    let path = asset_server
+        .and_then(|server| server.get_path(id))
+        .map(|path| path.to_string());
+    let (load_state, load_error) = asset_server
+        .and_then(|server| server.get_load_state(id))
+        .map_or((ASSET_LOAD_STATE_UNTRACKED, None), |state| {
+            load_state_label(&state)
+        });
+    AssetEntry {
+        id: id.to_string(),
+        path,
+        load_state,
+        load_error,
+    }
+}
+
+fn load_state_label(state: &LoadState) -> (&'static str, Option<String>) {
+    match state {
+        LoadState::NotLoaded => (ASSET_LOAD_STATE_NOT_LOADED, None),
+        LoadState::Loading => (ASSET_LOAD_STATE_LOADING, None),
+        LoadState::Loaded => (ASSET_LOAD_STATE_LOADED, None),
+        LoadState::Failed(error) => (ASSET_LOAD_STATE_FAILED, Some(error.to_string())),
+    }
+}
+
+const fn dependency_load_state_label(state: &DependencyLoadState) -> &'static str {
+    match state {
+        DependencyLoadState::NotLoaded => ASSET_LOAD_STATE_NOT_LOADED,
+        DependencyLoadState::Loading => ASSET_LOAD_STATE_LOADING,
+        DependencyLoadState::Loaded => ASSET_LOAD_STATE_LOADED,
+        DependencyLoadState::Failed(_) => ASSET_LOAD_STATE_FAILED,
+    }
+}
+
+const fn recursive_dependency_load_state_label(
+    state: &RecursiveDependencyLoadState,
+) -> &'static str {
+    match state {
+        RecursiveDependencyLoadState::NotLoaded => ASSET_LOAD_STATE_NOT_LOADED,
+        RecursiveDependencyLoadState::Loading => ASSET_LOAD_STATE_LOADING,
+        RecursiveDependencyLoadState::Loaded => ASSET_LOAD_STATE_LOADED,
+        RecursiveDependencyLoadState::Failed(_) => ASSET_LOAD_STATE_FAILED,
+    }
+}
@@ -0,0 +1,393 @@
+//! Camera placement handlers for BRP extras
+//!
+//! Framing a screenshot through `world.mutate_components` means computing a rotation quaternion
+//! on the client. `set_camera_transform`, `look_at`, and `orbit_camera` write a camera's
+//! `Transform` inside the app instead, aiming it at a world-space point or an entity.
+//!
+//! Targets are world-space. For a camera with a parent they are converted into the parent's
+//! space, because `Transform` is relative to the parent; the response reports that local
+//! `Transform`. The camera's `GlobalTransform` catches up at the next transform propagation.
+
+use bevy::math::Affine3A;
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_CAMERA;
+use crate::error::BrpExtrasError;
+use crate::projection;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+/// Pitch magnitude, in degrees, at which an orbit would look straight along the up axis
+const MAX_ORBIT_PITCH_DEGREES: f32 = 90.0;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `set_camera_transform`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetCameraTransformRequest {
+    /// Camera whose `Transform` is set
+    camera:      u64,
+    /// New translation (None = unchanged)
+    #[serde(default)]
+    translation: Option<[f32; 3]>,
+    /// New rotation as a quaternion `[x, y, z, w]`, normalized before use (None = unchanged)
+    #[serde(default)]
+    rotation:    Option<[f32; 4]>,
+    /// New scale (None = unchanged)
+    #[serde(default)]
+    scale:       Option<[f32; 3]>,
+}
+
+/// Request structure for `look_at`
+///
+/// Give either a world-space `point` or an `entity` whose `GlobalTransform` translation is used.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LookAtRequest {
+    /// Camera to rotate
+    camera: u64,
+    /// World-space point to look at
+    #[serde(default)]
+    point:  Option<[f32; 3]>,
+    /// Entity whose translation is looked at
+    #[serde(default)]
+    entity: Option<u64>,
+    /// Up direction the camera keeps (default `[0, 1, 0]`)
+    #[serde(default)]
+    up:     Option<[f32; 3]>,
+}
+
+/// Request structure for `orbit_camera`
+///
+/// Give either a world-space `point` or an `entity` whose `GlobalTransform` translation is used.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct OrbitCameraRequest {
+    /// Camera to move
+    camera:   u64,
+    /// World-space point to orbit around
+    #[serde(default)]
+    point:    Option<[f32; 3]>,
+    /// Entity whose translation is orbited around
+    #[serde(default)]
+    entity:   Option<u64>,
+    /// Angle around the Y axis in degrees, where `0` places the camera on the target's `+Z` side
+    #[serde(default)]
+    yaw:      f32,
+    /// Elevation above the target's horizontal plane in degrees, strictly between `-90` and `90`
+    #[serde(default)]
+    pitch:    f32,
+    /// Distance from the target (default: the camera's current distance)
+    #[serde(default)]
+    distance: Option<f32>,
+}
+
+/// Response structure shared by the camera placement methods
+#[derive(Serialize)]
+struct CameraTransformResponse {
+    camera:      u64,
+    /// Translation after the change
+    translation: [f32; 3],
+    /// Rotation after the change, as a quaternion `[x, y, z, w]`
+    rotation:    [f32; 4],
+    /// Scale after the change
+    scale:       [f32; 3],
+    /// Direction the camera looks along after the change
+    forward:     [f32; 3],
+    /// World-space point the camera was aimed at, for `look_at` and `orbit_camera`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target:      Option<[f32; 3]>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `set_camera_transform` BRP method
+///
+/// Replaces the given parts of a camera's `Transform` and keeps the rest.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed, or give none of `translation`, `rotation`, or
+///   `scale`
+/// - `rotation` has zero length or a component is not finite
+/// - `camera` is not an entity with a `Camera` and a `Transform`
+pub(crate) fn set_camera_transform_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SetCameraTransformRequest = parse_required(params)?;
+    if request.translation.is_none() && request.rotation.is_none() && request.scale.is_none() {
+        return Err(BrpExtrasError::InvalidParams
+            .error("Provide at least one of `translation`, `rotation`, or `scale`"));
+    }
+    let rotation = request
+        .rotation
+        .map(|rotation| {
+            Some(Quat::from_array(rotation).normalize())
+                .filter(|normalized| normalized.is_finite())
+                .ok_or_else(|| {
+                    BrpExtrasError::InvalidParams.with_details(
+                        "rotation must be a finite, non-zero quaternion [x, y, z, w]",
+                        json!({ "rotation": rotation }),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let camera = projection::camera_entity(world, request.camera)?;
+    let mut transform = camera_transform(world, camera)?;
+    if let Some(translation) = request.translation {
+        transform.translation = Vec3::from_array(translation);
+    }
+    if let Some(rotation) = rotation {
+        transform.rotation = rotation;
+    }
+    if let Some(scale) = request.scale {
+        transform.scale = Vec3::from_array(scale);
+    }
+    world.entity_mut(camera).insert(transform);
+
+    serialize_response(transform_response(camera, &transform, None))
+}
+
+/// Handler for `look_at` BRP method
+///
+/// Rotates a camera in place so it faces a point or entity.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed, or do not give exactly one of `point` or `entity`
+/// - `up` is zero, or the target sits at the camera's position
+/// - `camera` is not an entity with a `Camera` and a `Transform`
+/// - `entity` does not exist or has no `GlobalTransform`
+pub(crate) fn look_at_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: LookAtRequest = parse_required(params)?;
+    let up = request.up.map_or(Ok(Dir3::Y), |up| {
+        Dir3::new(Vec3::from_array(up)).map_err(|error| {
+            BrpExtrasError::InvalidParams.error(format!("`up` must be a non-zero vector: {error}"))
+        })
+    })?;
+
+    let camera = projection::camera_entity(world, request.camera)?;
+    let target = target_point(world, request.point, request.entity)?;
+    let mut transform = camera_transform(world, camera)?;
+    aim(world, camera, &mut transform, target, up)?;
+    world.entity_mut(camera).insert(transform);
+
+    serialize_response(transform_response(camera, &transform, Some(target)))
+}
+
+/// Handler for `orbit_camera` BRP method
+///
+/// Places a camera on a sphere around a point or entity and points it at that target, keeping
+/// `+Y` up.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed, or do not give exactly one of `point` or `entity`
+/// - `pitch` is not strictly between `-90` and `90`, or `yaw` is not finite
+/// - `distance` is not a positive, finite number, or is omitted while the camera sits at the target
+/// - `camera` is not an entity with a `Camera` and a `Transform`
+/// - `entity` does not exist or has no `GlobalTransform`
+pub(crate) fn orbit_camera_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: OrbitCameraRequest = parse_required(params)?;
+    if !request.yaw.is_finite() {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("yaw must be finite, got {}", request.yaw),
+            json!({ "yaw": request.yaw }),
+        ));
+    }
+    if !request.pitch.is_finite() || request.pitch.abs() >= MAX_ORBIT_PITCH_DEGREES {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "pitch must be strictly between -{MAX_ORBIT_PITCH_DEGREES} and \
+                 {MAX_ORBIT_PITCH_DEGREES} degrees, got {}",
+                request.pitch
+            ),
+            json!({ "pitch": request.pitch }),
+        ));
+    }
+
+    let camera = projection::camera_entity(world, request.camera)?;
+    let target = target_point(world, request.point, request.entity)?;
+    let mut transform = camera_transform(world, camera)?;
+    let distance = match request.distance {
+        Some(distance) => distance,
+        None => world
+            .get::<GlobalTransform>(camera)
+            .map_or(transform.translation, GlobalTransform::translation)
+            .distance(target),
+    };
+    if !distance.is_finite() || distance <= 0.0 {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("distance must be a positive, finite number, got {distance}"),
+            json!({ "distance": distance }),
+        ));
+    }
+
+    let (yaw, pitch) = (request.yaw.to_radians(), request.pitch.to_radians());
+    let offset = Vec3::new(
+        pitch.cos() * yaw.sin(),
+        pitch.sin(),
+        pitch.cos() * yaw.cos(),
+    );
+    transform.translation = to_parent_space(world, camera, target + offset * distance);
+    aim(world, camera, &mut transform, target, Dir3::Y)?;
+    world.entity_mut(camera).insert(transform);
+
+    serialize_response(transform_response(camera, &transform, Some(target)))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The camera's current `Transform`
+fn camera_transform(world: &World, camera: Entity) -> Result<Transform, BrpError> {
+    world.get::<Transform>(camera).copied().ok_or_else(|| {
+        BrpExtrasError::CameraUnavailable.with_details(
+            format!("Camera {} has no Transform", camera.to_bits()),
+            json!({ PARAM_CAMERA: camera.to_bits() }),
+        )
+    })
+}
+
+/// World-space point from exactly one of `point` or `entity`
+fn target_point(
+    world: &World,
+    point: Option<[f32; 3]>,
+    entity: Option<u64>,
+) -> Result<Vec3, BrpError> {
+    match (point, entity) {
+        (Some(point), None) => Ok(Vec3::from_array(point)),
+        (None, Some(entity)) => projection::entity_translation(world, entity),
+        _ => Err(BrpExtrasError::InvalidParams.error("Provide exactly one of `point` or `entity`")),
+    }
+}
+
+/// Rotates `transform` to face the world-space `target`, with `up` given in world space
+fn aim(
+    world: &World,
+    camera: Entity,
+    transform: &mut Transform,
+    target: Vec3,
+    up: Dir3,
+) -> Result<(), BrpError> {
+    let local_target = to_parent_space(world, camera, target);
+    let local_up =
+        parent_affine(world, camera).map_or(*up, |affine| affine.inverse().transform_vector3(*up));
+    if local_target.distance_squared(transform.translation) <= f32::EPSILON {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            "The target is at the camera's position, so there is no direction to look in",
+            json!({ "target": target.to_array() }),
+        ));
+    }
+    transform.look_at(local_target, local_up);
+    Ok(())
+}
+
+/// Converts a world-space point into the space of the camera's parent, if it has one
+fn to_parent_space(world: &World, camera: Entity, point: Vec3) -> Vec3 {
+    parent_affine(world, camera).map_or(point, |affine| affine.inverse().transform_point3(point))
+}
+
+fn parent_affine(world: &World, camera: Entity) -> Option<Affine3A> {
+    world
+        .get::<ChildOf>(camera)
+        .and_then(|child_of| world.get::<GlobalTransform>(child_of.parent()))
+        .map(GlobalTransform::affine)
+}
+
+fn transform_response(
+    camera: Entity,
+    transform: &Transform,
+    target: Option<Vec3>,
+) -> CameraTransformResponse {
+    CameraTransformResponse {
+        camera:      camera.to_bits(),
+        translation: transform.translation.to_array(),
+        rotation:    transform.rotation.to_array(),
+        scale:       transform.scale.to_array(),
+        forward:     transform.forward().to_array(),
+        target:      target.map(|v| v.to_array()),
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::look_at_handler;
+    use super::orbit_camera_handler;
+    use crate::error::BrpExtrasError;
+
+    fn spawn_camera(app: &mut App, translation: Vec3) -> u64 {
+        app.world_mut()
+            .spawn((Camera::default(), Transform::from_translation(translation)))
+            .id()
+            .to_bits()
+    }
+
+    #[test]
+    fn orbit_places_the_camera_around_the_target_and_faces_it() {
+        let mut app = App::new();
+        let camera = spawn_camera(&mut app, Vec3::ZERO);
+
+        let response = orbit_camera_handler(
+            In(Some(json!({
+                "camera": camera,
+                "point": [1.0, 0.0, 0.0],
+                "yaw": 90.0,
+                "distance": 5.0
+            }))),
+            app.world_mut(),
+        )
+        .expect("the camera should orbit the point");
+
+        let transform = app
+            .world()
+            .get::<Transform>(Entity::from_bits(camera))
+            .expect("the camera keeps its Transform");
+        assert!(
+            transform
+                .translation
+                .abs_diff_eq(Vec3::new(6.0, 0.0, 0.0), 1e-4)
+        );
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_X, 1e-4));
+        assert_eq!(response["target"], json!([1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn look_at_the_camera_position_is_rejected() {
+        let mut app = App::new();
+        let camera = spawn_camera(&mut app, Vec3::new(0.0, 2.0, 0.0));
+
+        let error = look_at_handler(
+            In(Some(json!({ "camera": camera, "point": [0.0, 2.0, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect_err("there is no direction to look in");
+
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+    }
+}
@@ -0,0 +1,367 @@
+//! Capability discovery handler for BRP extras
+//!
+//! `capabilities` lists the `brp_extras/` methods this app actually registered, which depends on
+//! the `bevy_brp_extras` version, its cargo features, and the target platform. Methods that take
+//! parameters carry a JSON schema of them, generated from the same request types the handlers
+//! deserialize, so clients can validate a call before sending it.
+
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use bevy_remote::RemoteMethodSystemId;
+use bevy_remote::RemoteMethods;
+use schemars::Schema;
+use schemars::schema_for;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::assets::GetAssetInfoRequest;
+use crate::assets::ListAssetsRequest;
+use crate::assets::ReloadAssetRequest;
+use crate::camera::LookAtRequest;
+use crate::camera::OrbitCameraRequest;
+use crate::camera::SetCameraTransformRequest;
+use crate::change_detection::QueryChangedRequest;
+use crate::constants::EXTRAS_COMMAND_PREFIX;
+use crate::constants::METHOD_CLEAR_OVERRIDES;
+use crate::constants::METHOD_CLICK_MOUSE;
+#[cfg(feature = "ui")]
+use crate::constants::METHOD_CLICK_UI_NODE;
+use crate::constants::METHOD_DESPAWN_RECURSIVE;
+#[cfg(feature = "diagnostics")]
+use crate::constants::METHOD_DIAGNOSTICS_WATCH;
+use crate::constants::METHOD_DOUBLE_CLICK_MOUSE;
+use crate::constants::METHOD_DOUBLE_TAP_GESTURE;
+use crate::constants::METHOD_DRAG_MOUSE;
+#[cfg(feature = "gizmos")]
+use crate::constants::METHOD_DRAW_GIZMO;
+use crate::constants::METHOD_EVENTS_WATCH;
+use crate::constants::METHOD_GET_ASSET_INFO;
+use crate::constants::METHOD_GET_COMPONENT_RAW;
+#[cfg(feature = "physics")]
+use crate::constants::METHOD_GET_CONTACTS;
+#[cfg(feature = "diagnostics")]
+use crate::constants::METHOD_GET_DIAGNOSTICS;
+use crate::constants::METHOD_GET_SCHEDULE_GRAPH;
+#[cfg(feature = "text")]
+use crate::constants::METHOD_GET_VISIBLE_TEXT;
+use crate::constants::METHOD_GET_WORLD_STATS;
+#[cfg(feature = "gizmos")]
+use crate::constants::METHOD_HIGHLIGHT_ENTITY;
+use crate::constants::METHOD_HOVER_MOUSE;
+use crate::constants::METHOD_LIST_ASSETS;
+use crate::constants::METHOD_LIST_SYSTEMS;
+use crate::constants::METHOD_LOAD_WORLD_SNAPSHOT;
+use crate::constants::METHOD_LOOK_AT;
+use crate::constants::METHOD_MOVE_MOUSE;
+use crate::constants::METHOD_MUTATE_REFLECT;
+use crate::constants::METHOD_ORBIT_CAMERA;
+#[cfg(feature = "picking")]
+use crate::constants::METHOD_PICK_ENTITY;
+use crate::constants::METHOD_PINCH_GESTURE;
+use crate::constants::METHOD_PRESS_KEYS;
+use crate::constants::METHOD_QUERY_CHANGED;
+use crate::constants::METHOD_QUERY_SPATIAL;
+use crate::constants::METHOD_RELEASE_KEYS;
+use crate::constants::METHOD_RELOAD_ASSET;
+use crate::constants::METHOD_ROTATION_GESTURE;
+use crate::constants::METHOD_RUN_INPUT_SCRIPT;
+use crate::constants::METHOD_RUN_SYSTEM_BY_NAME;
+use crate::constants::METHOD_SAVE_WORLD_SNAPSHOT;
+use crate::constants::METHOD_SCREEN_TO_WORLD;
+use crate::constants::METHOD_SCREENSHOT;
+use crate::constants::METHOD_SCROLL_MOUSE;
+use crate::constants::METHOD_SEND_EVENT;
+use crate::constants::METHOD_SEND_IME;
+use crate::constants::METHOD_SEND_KEYS;
+use crate::constants::METHOD_SEND_MOUSE_BUTTON;
+#[cfg(feature = "light")]
+use crate::constants::METHOD_SET_AMBIENT_LIGHT;
+use crate::constants::METHOD_SET_CAMERA_TRANSFORM;
+use crate::constants::METHOD_SET_CLEAR_COLOR;
+use crate::constants::METHOD_SET_COMPONENT_OVERRIDE;
+#[cfg(feature = "physics")]
+use crate::constants::METHOD_SET_GRAVITY;
+#[cfg(feature = "physics")]
+use crate::constants::METHOD_SET_LINEAR_VELOCITY;
+use crate::constants::METHOD_SET_WINDOW;
+use crate::constants::METHOD_TYPE_TEXT;
+use crate::constants::METHOD_WAIT_FOR;
+use crate::constants::METHOD_WORLD_TO_SCREEN;
+use crate::despawn::DespawnRecursiveRequest;
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::DiagnosticsWatchRequest;
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::GetDiagnosticsRequest;
+#[cfg(feature = "light")]
+use crate::environment::SetAmbientLightRequest;
+use crate::environment::SetClearColorRequest;
+use crate::events::EventsWatchRequest;
+use crate::events::SendEventRequest;
+use crate::frame_sync;
+#[cfg(feature = "gizmos")]
+use crate::gizmo::DrawGizmoRequest;
+#[cfg(feature = "gizmos")]
+use crate::highlight::HighlightEntityRequest;
+use crate::input_script::RunInputScriptRequest;
+use crate::keyboard::PressKeysRequest;
+use crate::keyboard::ReleaseKeysRequest;
+use crate::keyboard::SendImeRequest;
+use crate::keyboard::SendKeysRequest;
+use crate::keyboard::TypeTextRequest;
+use crate::mouse::ClickMouseRequest;
+#[cfg(feature = "ui")]
+use crate::mouse::ClickUiNodeRequest;
+use crate::mouse::DoubleClickMouseRequest;
+use crate::mouse::DoubleTapGestureRequest;
+use crate::mouse::DragMouseRequest;
+use crate::mouse::HoverMouseRequest;
+use crate::mouse::MoveMouseRequest;
+use crate::mouse::PinchGestureRequest;
+use crate::mouse::RotationGestureRequest;
+use crate::mouse::ScrollMouseRequest;
+use crate::mouse::SendMouseButtonRequest;
+use crate::named_systems::RunSystemByNameRequest;
+use crate::overrides::ClearOverridesRequest;
+use crate::overrides::SetComponentOverrideRequest;
+#[cfg(feature = "physics")]
+use crate::physics::GetContactsRequest;
+#[cfg(feature = "physics")]
+use crate::physics::SetGravityRequest;
+#[cfg(feature = "physics")]
+use crate::physics::SetLinearVelocityRequest;
+#[cfg(feature = "picking")]
+use crate::picking::PickEntityRequest;
+use crate::plugin::CustomMethods;
+use crate::projection::ScreenToWorldRequest;
+use crate::projection::WorldToScreenRequest;
+use crate::reflection::GetComponentRawRequest;
+use crate::reflection::MutateReflectRequest;
+use crate::schedules::GetScheduleGraphRequest;
+use crate::schedules::ListSystemsRequest;
+use crate::screenshot::RawScreenshotRequest;
+use crate::spatial::QuerySpatialRequest;
+use crate::support::serialize_response;
+#[cfg(feature = "text")]
+use crate::visible_text::GetVisibleTextRequest;
+use crate::wait_for::WaitForRequest;
+use crate::window::SetWindowRequest;
+use crate::world_snapshot::LoadWorldSnapshotRequest;
+use crate::world_snapshot::SaveWorldSnapshotRequest;
+use crate::world_stats::GetWorldStatsRequest;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// One registered extras method
+#[derive(Serialize)]
+struct MethodCapability {
+    /// Full method name, e.g. `brp_extras/send_keys`
+    method:        String,
+    /// Whether the method streams responses instead of answering once
+    watching:      bool,
+    /// JSON schema of the method's parameters; omitted for methods that take none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params_schema: Option<Schema>,
+}
+
+/// Response structure for `capabilities`
+#[derive(Serialize)]
+struct CapabilitiesResponse {
+    plugin_version: &'static str,
+    methods:        Vec<MethodCapability>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `capabilities` BRP method
+///
+/// Lists every registered `brp_extras/` method and every method added with
+/// `BrpExtrasPlugin::with_method`, sorted by name, with its parameter schema.
+pub(crate) fn capabilities_handler(
+    In(_): In<Option<Value>>,
+    remote_methods: Res<RemoteMethods>,
+    custom_methods: Option<Res<CustomMethods>>,
+) -> BrpResult {
+    let mut names: Vec<String> = remote_methods
+        .methods()
+        .into_iter()
+        .filter(|name| {
+            name.starts_with(EXTRAS_COMMAND_PREFIX)
+                || custom_methods
+                    .as_ref()
+                    .is_some_and(|custom_methods| custom_methods.0.contains(name))
+        })
+        .collect();
+    names.sort();
+
+    let methods = names
+        .into_iter()
+        .map(|method| MethodCapability {
+            watching: matches!(
+                remote_methods.get(&method),
+                Some(RemoteMethodSystemId::Watching(_))
+            ),
+            params_schema: method
+                .strip_prefix(EXTRAS_COMMAND_PREFIX)
+                .and_then(params_schema),
+            method,
+        })
+        .collect();
+
+    serialize_response(CapabilitiesResponse {
+        plugin_version: env!("CARGO_PKG_VERSION"),
+        methods,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Parameter schema of an extras method, by its name without the `brp_extras/` prefix
+fn params_schema(method: &str) -> Option<Schema> {
+    let mut schema = match method {
+        METHOD_CLEAR_OVERRIDES => schema_for!(ClearOverridesRequest),
+        METHOD_CLICK_MOUSE => schema_for!(ClickMouseRequest),
+        #[cfg(feature = "ui")]
+        METHOD_CLICK_UI_NODE => schema_for!(ClickUiNodeRequest),
+        METHOD_DESPAWN_RECURSIVE => schema_for!(DespawnRecursiveRequest),
+        #[cfg(feature = "diagnostics")]
+        METHOD_DIAGNOSTICS_WATCH => schema_for!(DiagnosticsWatchRequest),
+        METHOD_DOUBLE_CLICK_MOUSE => schema_for!(DoubleClickMouseRequest),
+        METHOD_DOUBLE_TAP_GESTURE => schema_for!(DoubleTapGestureRequest),
+        METHOD_DRAG_MOUSE => schema_for!(DragMouseRequest),
+        #[cfg(feature = "gizmos")]
+        METHOD_DRAW_GIZMO => schema_for!(DrawGizmoRequest),
+        METHOD_EVENTS_WATCH => schema_for!(EventsWatchRequest),
+        METHOD_GET_ASSET_INFO => schema_for!(GetAssetInfoRequest),
+        METHOD_GET_COMPONENT_RAW => schema_for!(GetComponentRawRequest),
+        #[cfg(feature = "physics")]
+        METHOD_GET_CONTACTS => schema_for!(GetContactsRequest),
+        #[cfg(feature = "diagnostics")]
+        METHOD_GET_DIAGNOSTICS => schema_for!(GetDiagnosticsRequest),
+        METHOD_GET_SCHEDULE_GRAPH => schema_for!(GetScheduleGraphRequest),
+        #[cfg(feature = "text")]
+        METHOD_GET_VISIBLE_TEXT => schema_for!(GetVisibleTextRequest),
+        METHOD_GET_WORLD_STATS => schema_for!(GetWorldStatsRequest),
+        #[cfg(feature = "gizmos")]
+        METHOD_HIGHLIGHT_ENTITY => schema_for!(HighlightEntityRequest),
+        METHOD_HOVER_MOUSE => schema_for!(HoverMouseRequest),
+        METHOD_LIST_ASSETS => schema_for!(ListAssetsRequest),
+        METHOD_LIST_SYSTEMS => schema_for!(ListSystemsRequest),
+        METHOD_LOAD_WORLD_SNAPSHOT => schema_for!(LoadWorldSnapshotRequest),
+        METHOD_LOOK_AT => schema_for!(LookAtRequest),
+        METHOD_MOVE_MOUSE => schema_for!(MoveMouseRequest),
+        METHOD_MUTATE_REFLECT => schema_for!(MutateReflectRequest),
+        METHOD_ORBIT_CAMERA => schema_for!(OrbitCameraRequest),
+        #[cfg(feature = "picking")]
+        METHOD_PICK_ENTITY => schema_for!(PickEntityRequest),
+        METHOD_PINCH_GESTURE => schema_for!(PinchGestureRequest),
+        METHOD_PRESS_KEYS => schema_for!(PressKeysRequest),
+        METHOD_QUERY_CHANGED => schema_for!(QueryChangedRequest),
+        METHOD_QUERY_SPATIAL => schema_for!(QuerySpatialRequest),
+        METHOD_RELEASE_KEYS => schema_for!(ReleaseKeysRequest),
+        METHOD_RELOAD_ASSET => schema_for!(ReloadAssetRequest),
+        METHOD_ROTATION_GESTURE => schema_for!(RotationGestureRequest),
+        METHOD_RUN_INPUT_SCRIPT => schema_for!(RunInputScriptRequest),
+        METHOD_RUN_SYSTEM_BY_NAME => schema_for!(RunSystemByNameRequest),
+        METHOD_SAVE_WORLD_SNAPSHOT => schema_for!(SaveWorldSnapshotRequest),
+        METHOD_SCREEN_TO_WORLD => schema_for!(ScreenToWorldRequest),
+        METHOD_SCREENSHOT => schema_for!(RawScreenshotRequest),
+        METHOD_SCROLL_MOUSE => schema_for!(ScrollMouseRequest),
+        METHOD_SEND_EVENT => schema_for!(SendEventRequest),
+        METHOD_SEND_IME => schema_for!(SendImeRequest),
+        METHOD_SEND_KEYS => schema_for!(SendKeysRequest),
+        METHOD_SEND_MOUSE_BUTTON => schema_for!(SendMouseButtonRequest),
+        #[cfg(feature = "light")]
+        METHOD_SET_AMBIENT_LIGHT => schema_for!(SetAmbientLightRequest),
+        METHOD_SET_CAMERA_TRANSFORM => schema_for!(SetCameraTransformRequest),
+        METHOD_SET_CLEAR_COLOR => schema_for!(SetClearColorRequest),
+        METHOD_SET_COMPONENT_OVERRIDE => schema_for!(SetComponentOverrideRequest),
+        #[cfg(feature = "physics")]
+        METHOD_SET_GRAVITY => schema_for!(SetGravityRequest),
+        #[cfg(feature = "physics")]
+        METHOD_SET_LINEAR_VELOCITY => schema_for!(SetLinearVelocityRequest),
+        METHOD_SET_WINDOW => schema_for!(SetWindowRequest),
+        METHOD_TYPE_TEXT => schema_for!(TypeTextRequest),
+        METHOD_WAIT_FOR => schema_for!(WaitForRequest),
+        METHOD_WORLD_TO_SCREEN => schema_for!(WorldToScreenRequest),
+        _ => return None,
+    };
+    if frame_sync::is_schedulable(method) {
+        frame_sync::add_timing_properties(&mut schema);
+    }
+    Some(schema)
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy_remote::RemoteMethodSystemId;
+    use bevy_remote::RemoteMethods;
+
+    use super::capabilities_handler;
+    use crate::keyboard::send_keys_handler;
+    use crate::ping::ping_handler;
+    use crate::plugin::CustomMethods;
+
+    #[test]
+    fn lists_registered_extras_methods_with_param_schemas() {
+        let mut app = App::new();
+        let world = app.world_mut();
+        let send_keys = RemoteMethodSystemId::Instant(world.register_system(send_keys_handler));
+        let ping = RemoteMethodSystemId::Instant(world.register_system(ping_handler));
+        let mut remote_methods = RemoteMethods::new();
+        remote_methods.insert("brp_extras/send_keys", send_keys);
+        remote_methods.insert("brp_extras/ping", ping);
+        world.insert_resource(remote_methods);
+
+        let response = world
+            .run_system_cached_with(capabilities_handler, None)
+            .expect("capabilities system should run")
+            .expect("capabilities should serialize");
+
+        assert_eq!(response["methods"][0]["method"], "brp_extras/ping");
+        assert!(response["methods"][0].get("params_schema").is_none());
+        assert_eq!(response["methods"][1]["method"], "brp_extras/send_keys");
+        assert!(
+            response["methods"][1]["params_schema"]["properties"]
+                .get("keys")
+                .is_some()
+        );
+        assert!(
+            response["methods"][1]["params_schema"]["properties"]
+                .get("after_frames")
+                .is_some()
+        );
+    }
+    #[test]
+    fn lists_custom_methods_without_other_foreign_methods() {
+        let mut app = App::new();
+        let world = app.world_mut();
+        let ping = world.register_system(ping_handler);
+        let mut remote_methods = RemoteMethods::new();
+        remote_methods.insert("my_game/do_thing", RemoteMethodSystemId::Instant(ping));
+        remote_methods.insert("other/method", RemoteMethodSystemId::Instant(ping));
+        world.insert_resource(remote_methods);
+        world.insert_resource(CustomMethods(vec!["my_game/do_thing".to_string()]));
+
+        let response = world
+            .run_system_cached_with(capabilities_handler, None)
+            .expect("capabilities system should run")
+            .expect("capabilities should serialize");
+
+        let methods = response["methods"]
+            .as_array()
+            .expect("methods should be an array");
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0]["method"], "my_game/do_thing");
+    }
+}
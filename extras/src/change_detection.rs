@@ -0,0 +1,208 @@
+//! Change detection handler for BRP extras
+//!
+//! `world.query` has no change ticks, so it cannot answer "what moved since I last looked".
+//! `query_changed` resolves reflected component type names to their component ids and checks each
+//! entity's change ticks the way `Changed<T>` and `Added<T>` filters do. The response carries a
+//! `cursor`: the world change tick the query ran at. Passing it back as `since` returns only
+//! entities changed after that point.
+
+use bevy::ecs::archetype::ArchetypeEntity;
+use bevy::ecs::change_detection::MAX_CHANGE_AGE;
+use bevy::ecs::change_detection::Tick;
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistration;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Which change ticks to check, like Bevy's `Changed<T>` and `Added<T>` filters
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ChangeFilter {
+    /// The component was added or mutably accessed
+    #[default]
+    Changed,
+    /// The component was added
+    Added,
+}
+
+/// Request structure for `query_changed`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct QueryChangedRequest {
+    /// Full type paths of reflected components; entities must have all of them, each changed
+    components: Vec<String>,
+    /// Whether components must have changed or been added
+    #[serde(default)]
+    filter:     ChangeFilter,
+    /// `cursor` from a previous response; only changes after it are returned. Without it, every
+    /// entity with the components is returned, like the first run of a system.
+    #[serde(default)]
+    since:      Option<u32>,
+}
+
+/// Response structure for `query_changed`
+#[derive(Serialize)]
+struct QueryChangedResponse {
+    /// Matching entities, in ascending order
+    entities:     Vec<u64>,
+    entity_count: usize,
+    filter:       ChangeFilter,
+    /// Pass to the next call to only get changes made after this one
+    cursor:       u32,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `query_changed` BRP method
+///
+/// Returns the entities whose components changed or were added since the `since` cursor.
+pub(crate) fn query_changed_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: QueryChangedRequest = parse_required(params)?;
+    if request.components.is_empty() {
+        return Err(BrpExtrasError::InvalidParams.error("'components' must not be empty"));
+    }
+    let component_ids = request
+        .components
+        .iter()
+        .map(|component| resolve_component_id(world, component))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Changes made after this call get newer ticks, so the next call sees exactly those
+    let this_run = world.increment_change_tick();
+    let last_run = request.since.map_or_else(
+        || Tick::new(this_run.get().wrapping_sub(MAX_CHANGE_AGE)),
+        Tick::new,
+    );
+
+    let mut entities: Vec<u64> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| component_ids.iter().all(|&id| archetype.contains(id)))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        .filter(|&entity| {
+            let entity = world.entity(entity);
+            component_ids.iter().all(|&id| {
+                entity
+                    .get_change_ticks_by_id(id)
+                    .is_some_and(|ticks| match request.filter {
+                        ChangeFilter::Changed => ticks.is_changed(last_run, this_run),
+                        ChangeFilter::Added => ticks.is_added(last_run, this_run),
+                    })
+            })
+        })
+        .map(Entity::to_bits)
+        .collect();
+    entities.sort_unstable();
+
+    serialize_response(QueryChangedResponse {
+        entity_count: entities.len(),
+        entities,
+        filter: request.filter,
+        cursor: this_run.get(),
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Component id of a reflected component type, if any entity has been given one
+fn resolve_component_id(world: &World, component: &str) -> Result<ComponentId, BrpError> {
+    let type_id = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_with_type_path(component)
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(TypeRegistration::type_id)
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("'{component}' is not a registered component with reflect(Component)"),
+                json!({ "component": component }),
+            )
+        })?;
+    world.components().get_id(type_id).ok_or_else(|| {
+        BrpExtrasError::InvalidParams.with_details(
+            format!("'{component}' is registered but has never been added to an entity"),
+            json!({ "component": component }),
+        )
+    })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::query_changed_handler;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Velocity(f32);
+
+    fn entities(response: &serde_json::Value) -> Vec<u64> {
+        serde_json::from_value(response["entities"].clone()).expect("entities are ids")
+    }
+
+    #[test]
+    fn cursor_returns_only_later_changes() {
+        let mut app = App::new();
+        app.register_type::<Velocity>();
+        let world = app.world_mut();
+        let still = world.spawn(Velocity(0.0)).id();
+        let moving = world.spawn(Velocity(1.0)).id();
+
+        let first = query_changed_handler(
+            In(Some(json!({ "components": [Velocity::type_path()] }))),
+            world,
+        )
+        .expect("first query should succeed");
+        let mut both = [still.to_bits(), moving.to_bits()];
+        both.sort_unstable();
+        assert_eq!(entities(&first), both);
+
+        world
+            .get_mut::<Velocity>(moving)
+            .expect("entity has a velocity")
+            .0 = 2.0;
+        let second = query_changed_handler(
+            In(Some(json!({
+                "components": [Velocity::type_path()],
+                "since": first["cursor"],
+            }))),
+            world,
+        )
+        .expect("second query should succeed");
+        assert_eq!(entities(&second), [moving.to_bits()]);
+
+        let added = query_changed_handler(
+            In(Some(json!({
+                "components": [Velocity::type_path()],
+                "filter": "added",
+                "since": first["cursor"],
+            }))),
+            world,
+        )
+        .expect("added query should succeed");
+        assert!(entities(&added).is_empty());
+    }
+}
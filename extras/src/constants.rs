@@ -1,6 +1,5 @@
 //! Crate-level constants for `bevy_brp_extras`
 
-#[cfg(not(target_arch = "wasm32"))]
 use std::time::Duration;
 
 // agent tool catalog constants
@@ -8,32 +7,160 @@ pub(crate) const AGENT_TOOLS_CATALOG_VERSION: u32 = 1;
 pub(crate) const BACKING_METHOD_MISSING_REASON: &str = "backing_method_missing";
 pub(crate) const BACKING_METHOD_WATCHING_REASON: &str = "backing_method_watching";
 
+// asset constants
+pub(crate) const ASSET_LOAD_STATE_FAILED: &str = "failed";
+pub(crate) const ASSET_LOAD_STATE_LOADED: &str = "loaded";
+pub(crate) const ASSET_LOAD_STATE_LOADING: &str = "loading";
+pub(crate) const ASSET_LOAD_STATE_NOT_LOADED: &str = "not_loaded";
+/// Load state reported for assets the `AssetServer` does not track, such as runtime-created assets
+pub(crate) const ASSET_LOAD_STATE_UNTRACKED: &str = "untracked";
+
 // command constants
 /// Command prefix for `brp_extras` methods
 pub(crate) const EXTRAS_COMMAND_PREFIX: &str = "brp_extras/";
 pub(crate) const METHOD_AGENT_TOOLS: &str = "agent_tools";
+pub(crate) const METHOD_CAPABILITIES: &str = "capabilities";
+pub(crate) const METHOD_CLEAR_OVERRIDES: &str = "clear_overrides";
 pub(crate) const METHOD_CLICK_MOUSE: &str = "click_mouse";
+#[cfg(feature = "ui")]
+pub(crate) const METHOD_CLICK_UI_NODE: &str = "click_ui_node";
+pub(crate) const METHOD_DESPAWN_RECURSIVE: &str = "despawn_recursive";
+#[cfg(feature = "diagnostics")]
+pub(crate) const METHOD_DIAGNOSTICS_WATCH: &str = "diagnostics_watch";
 pub(crate) const METHOD_DOUBLE_CLICK_MOUSE: &str = "double_click_mouse";
 pub(crate) const METHOD_DOUBLE_TAP_GESTURE: &str = "double_tap_gesture";
 pub(crate) const METHOD_DRAG_MOUSE: &str = "drag_mouse";
+#[cfg(feature = "gizmos")]
+pub(crate) const METHOD_DRAW_GIZMO: &str = "draw_gizmo";
+pub(crate) const METHOD_EVENTS_WATCH: &str = "events_watch";
+pub(crate) const METHOD_GET_ASSET_INFO: &str = "get_asset_info";
+pub(crate) const METHOD_GET_COMPONENT_RAW: &str = "get_component_raw";
+#[cfg(feature = "physics")]
+pub(crate) const METHOD_GET_CONTACTS: &str = "get_contacts";
 #[cfg(feature = "diagnostics")]
 pub(crate) const METHOD_GET_DIAGNOSTICS: &str = "get_diagnostics";
+pub(crate) const METHOD_GET_SCHEDULE_GRAPH: &str = "get_schedule_graph";
+#[cfg(feature = "text")]
+pub(crate) const METHOD_GET_VISIBLE_TEXT: &str = "get_visible_text";
+pub(crate) const METHOD_GET_WORLD_STATS: &str = "get_world_stats";
+#[cfg(feature = "gizmos")]
+pub(crate) const METHOD_HIGHLIGHT_ENTITY: &str = "highlight_entity";
+pub(crate) const METHOD_HOVER_MOUSE: &str = "hover_mouse";
+pub(crate) const METHOD_LIST_ASSETS: &str = "list_assets";
+pub(crate) const METHOD_LIST_SYSTEMS: &str = "list_systems";
+pub(crate) const METHOD_LOAD_WORLD_SNAPSHOT: &str = "load_world_snapshot";
+pub(crate) const METHOD_LOOK_AT: &str = "look_at";
 pub(crate) const METHOD_MOVE_MOUSE: &str = "move_mouse";
+pub(crate) const METHOD_MUTATE_REFLECT: &str = "mutate_reflect";
+pub(crate) const METHOD_ORBIT_CAMERA: &str = "orbit_camera";
+#[cfg(feature = "picking")]
+pub(crate) const METHOD_PICK_ENTITY: &str = "pick_entity";
 pub(crate) const METHOD_PINCH_GESTURE: &str = "pinch_gesture";
+pub(crate) const METHOD_PING: &str = "ping";
+pub(crate) const METHOD_PRESS_KEYS: &str = "press_keys";
+pub(crate) const METHOD_QUERY_CHANGED: &str = "query_changed";
+pub(crate) const METHOD_QUERY_SPATIAL: &str = "query_spatial";
+pub(crate) const METHOD_RELEASE_KEYS: &str = "release_keys";
+pub(crate) const METHOD_RELOAD_ASSET: &str = "reload_asset";
 pub(crate) const METHOD_ROTATION_GESTURE: &str = "rotation_gesture";
+pub(crate) const METHOD_RUN_INPUT_SCRIPT: &str = "run_input_script";
+pub(crate) const METHOD_RUN_SYSTEM_BY_NAME: &str = "run_system_by_name";
+pub(crate) const METHOD_SAVE_WORLD_SNAPSHOT: &str = "save_world_snapshot";
+pub(crate) const METHOD_SCREEN_TO_WORLD: &str = "screen_to_world";
 pub(crate) const METHOD_SCREENSHOT: &str = "screenshot";
 pub(crate) const METHOD_SCROLL_MOUSE: &str = "scroll_mouse";
+pub(crate) const METHOD_SEND_EVENT: &str = "send_event";
+pub(crate) const METHOD_SEND_IME: &str = "send_ime";
 pub(crate) const METHOD_SEND_KEYS: &str = "send_keys";
 pub(crate) const METHOD_SEND_MOUSE_BUTTON: &str = "send_mouse_button";
+#[cfg(feature = "light")]
+pub(crate) const METHOD_SET_AMBIENT_LIGHT: &str = "set_ambient_light";
+pub(crate) const METHOD_SET_CAMERA_TRANSFORM: &str = "set_camera_transform";
+pub(crate) const METHOD_SET_CLEAR_COLOR: &str = "set_clear_color";
+pub(crate) const METHOD_SET_COMPONENT_OVERRIDE: &str = "set_component_override";
+#[cfg(feature = "physics")]
+pub(crate) const METHOD_SET_GRAVITY: &str = "set_gravity";
+#[cfg(feature = "physics")]
+pub(crate) const METHOD_SET_LINEAR_VELOCITY: &str = "set_linear_velocity";
+pub(crate) const METHOD_SET_WINDOW: &str = "set_window";
 pub(crate) const METHOD_SET_WINDOW_TITLE: &str = "set_window_title";
 pub(crate) const METHOD_SHUTDOWN: &str = "shutdown";
+pub(crate) const METHOD_START_INPUT_RECORDING: &str = "start_input_recording";
+pub(crate) const METHOD_STOP_INPUT_RECORDING: &str = "stop_input_recording";
 pub(crate) const METHOD_TYPE_TEXT: &str = "type_text";
+pub(crate) const METHOD_WAIT_FOR: &str = "wait_for";
+pub(crate) const METHOD_WORLD_TO_SCREEN: &str = "world_to_screen";
+
+// diagnostics constants
+/// Path prefixes of the diagnostics `SystemInformationDiagnosticsPlugin` records
+#[cfg(feature = "diagnostics")]
+pub(crate) const SYSTEM_INFORMATION_PATH_PREFIXES: [&str; 2] = ["system/", "process/"];
+/// Path prefix of the diagnostics `RenderDiagnosticsPlugin` records, as
+/// `render/<pass>/<measurement>`
+#[cfg(feature = "diagnostics")]
+pub(crate) const RENDER_DIAGNOSTICS_PATH_PREFIX: &str = "render/";
+/// Render measurement counting the draw calls of a pass
+#[cfg(feature = "diagnostics")]
+pub(crate) const RENDER_DRAW_CALLS_MEASUREMENT: &str = "draw_calls";
+/// Render measurement recorded only when the GPU supports timestamp queries
+#[cfg(feature = "diagnostics")]
+pub(crate) const RENDER_ELAPSED_GPU_MEASUREMENT: &str = "elapsed_gpu";
+/// Frames between `diagnostics_watch` samples when the request gives no `every_n_frames`
+#[cfg(feature = "diagnostics")]
+pub(crate) const DEFAULT_DIAGNOSTICS_WATCH_INTERVAL_FRAMES: u32 = 60;
+
+// event watch constants
+/// Maximum number of recorded events kept for `events_watch`
+pub(crate) const MAX_RECORDED_EVENTS: usize = 1024;
+
+// gizmo constants
+/// Frames a `draw_gizmo` shape stays visible when the request gives no `ttl_frames`
+#[cfg(feature = "gizmos")]
+pub(crate) const DEFAULT_GIZMO_TTL_FRAMES: u32 = 120;
+/// Name of the entity holding the retained `draw_gizmo` shapes
+#[cfg(feature = "gizmos")]
+pub(crate) const GIZMO_ENTITY_NAME: &str = "BRP Gizmos";
+/// Name of each `draw_gizmo` text label node
+#[cfg(all(feature = "gizmos", feature = "ui"))]
+pub(crate) const GIZMO_LABEL_ENTITY_NAME: &str = "BRP Gizmo Label";
+/// Seconds a `highlight_entity` outline stays visible when the request gives no duration
+#[cfg(feature = "gizmos")]
+pub(crate) const DEFAULT_HIGHLIGHT_DURATION_SECS: f32 = 3.0;
+/// Radius of the sphere marking a highlighted entity that has no `Aabb` to outline
+#[cfg(feature = "gizmos")]
+pub(crate) const HIGHLIGHT_MARKER_RADIUS: f32 = 0.25;
+
+// ping constants
+/// Bevy version this release of `bevy_brp_extras` is built for, reported by `ping`
+pub(crate) const SUPPORTED_BEVY_VERSION: &str = "0.19";
+
+// headless constants
+pub(crate) const HEADLESS_REASON_NO_RENDER_ADAPTER: &str = "no_render_adapter";
+pub(crate) const HEADLESS_REASON_NO_WINDOW: &str = "no_window";
+
+// input script constants
+/// Maximum number of events accepted in one `run_input_script` request
+pub(crate) const MAX_INPUT_SCRIPT_EVENTS: usize = 10_000;
+
+// world snapshot constants
+/// Format version written to and required from world snapshots
+pub(crate) const WORLD_SNAPSHOT_VERSION: u32 = 1;
+
+// wait constants
+/// How long `wait_for` waits when the request gives no `timeout_ms`
+pub(crate) const DEFAULT_WAIT_FOR_TIMEOUT_MS: u64 = 5_000;
+/// Longest `wait_for` timeout, kept under the MCP server's 30-second HTTP timeout
+pub(crate) const MAX_WAIT_FOR_TIMEOUT_MS: u64 = 25_000;
 
 // environment variables
 /// Environment variable that overrides the BRP extras HTTP port
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) const BRP_EXTRAS_PORT_ENV_VAR: &str = "BRP_EXTRAS_PORT";
 
+// error data fields
+pub(crate) const ERROR_DETAILS_FIELD: &str = "details";
+pub(crate) const ERROR_KIND_FIELD: &str = "kind";
+
 // error messages
 pub(crate) const MISSING_REQUEST_PARAMETERS_MESSAGE: &str = "Missing request parameters";
 
@@ -43,20 +170,18 @@ pub(crate) const MISSING_REQUEST_PARAMETERS_MESSAGE: &str = "Missing request par
 /// This matches Bevy's `RemoteHttpPlugin` default port to ensure compatibility.
 pub const DEFAULT_REMOTE_PORT: u16 = 15702;
 /// File extension used by screenshot output.
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const IMAGE_EXTENSION_PNG: &str = "png";
 
 // parameter fields
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const PARAM_AFTER_FRAMES: &str = "after_frames";
 pub(crate) const PARAM_CAMERA: &str = "camera";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const PARAM_ENTITY: &str = "entity";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const PARAM_INLINE: &str = "inline";
 pub(crate) const PARAM_PATH: &str = "path";
+pub(crate) const PARAM_RUN_AT_FRAME: &str = "run_at_frame";
 pub(crate) const PARAM_TITLE: &str = "title";
 
 // response fields
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const CAMERA_CANDIDATES_FIELD: &str = "camera_candidates";
 #[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_AVERAGE_FIELD: &str = "average";
@@ -65,71 +190,98 @@ pub(crate) const DIAGNOSTICS_CURRENT_FIELD: &str = "current";
 #[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_FPS_FIELD: &str = "fps";
 #[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_DRAW_CALLS_FIELD: &str = "draw_calls";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_ELAPSED_SECS_FIELD: &str = "elapsed_secs";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_ENTRIES_FIELD: &str = "entries";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_FRAME_FIELD: &str = "frame";
+#[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_FRAME_COUNT_FIELD: &str = "frame_count";
 #[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_FRAME_TIME_MS_FIELD: &str = "frame_time_ms";
 #[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_GPU_TIMINGS_AVAILABLE_FIELD: &str = "gpu_timings_available";
+#[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_HISTORY_DURATION_SECS_FIELD: &str = "history_duration_secs";
 #[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_HISTORY_LEN_FIELD: &str = "history_len";
 #[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_MAX_HISTORY_LEN_FIELD: &str = "max_history_len";
 #[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_MEASUREMENTS_FIELD: &str = "measurements";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_PASS_FIELD: &str = "pass";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_PASSES_FIELD: &str = "passes";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_PATH_FIELD: &str = "path";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_RENDER_FIELD: &str = "render";
+#[cfg(feature = "diagnostics")]
 pub(crate) const DIAGNOSTICS_SMOOTHED_FIELD: &str = "smoothed";
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_SUFFIX_FIELD: &str = "suffix";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_SYSTEM_INFORMATION_FIELD: &str = "system_information";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_SYSTEM_TIMINGS_FIELD: &str = "system_timings";
+#[cfg(feature = "diagnostics")]
+pub(crate) const DIAGNOSTICS_VISIBLE_ENTITIES_FIELD: &str = "visible_entities";
 pub(crate) const RESPONSE_BOUNDS_KIND_FIELD: &str = "bounds_kind";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_CAPTURE_KIND_FIELD: &str = "capture_kind";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const RESPONSE_DATA_FIELD: &str = "data";
+pub(crate) const RESPONSE_ENCODING_FIELD: &str = "encoding";
 pub(crate) const RESPONSE_HEIGHT_FIELD: &str = "height";
 pub(crate) const RESPONSE_MESSAGE_FIELD: &str = "message";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const RESPONSE_MIME_TYPE_FIELD: &str = "mime_type";
 pub(crate) const RESPONSE_NAME_FIELD: &str = "name";
 pub(crate) const RESPONSE_NEW_TITLE_FIELD: &str = "new_title";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_NOTE_FIELD: &str = "note";
 pub(crate) const RESPONSE_OLD_TITLE_FIELD: &str = "old_title";
 pub(crate) const RESPONSE_PID_FIELD: &str = "pid";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_REASON_FIELD: &str = "reason";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_RECT_FIELD: &str = "rect";
 pub(crate) const RESPONSE_STATUS_FIELD: &str = "status";
 pub(crate) const RESPONSE_STATUS_SUCCESS: &str = "success";
 pub(crate) const RESPONSE_SUCCESS_FIELD: &str = "success";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const RESPONSE_TEMPLATE_FIELD: &str = "template";
 pub(crate) const RESPONSE_WIDTH_FIELD: &str = "width";
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_WORKING_DIRECTORY_FIELD: &str = "working_directory";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_X_FIELD: &str = "x";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RESPONSE_Y_FIELD: &str = "y";
 
 // screenshot constants
-#[cfg(not(target_arch = "wasm32"))]
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_BOUNDS_KIND_AABB: &str = "aabb";
-#[cfg(all(feature = "ui", not(target_arch = "wasm32")))]
+#[cfg(feature = "ui")]
 pub(crate) const SCREENSHOT_BOUNDS_KIND_UI: &str = "ui";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_CAMERA_REASON_AMBIGUOUS: &str = "ambiguous_camera";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_CAPTURE_DEADLINE: Duration = Duration::from_secs(25);
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_CAPTURE_KIND_ENTITY: &str = "entity";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_CAPTURE_NOTE: &str =
     "Screenshot capture completed and the PNG was published.";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SCREENSHOT_ENCODING_BASE64: &str = "base64";
 pub(crate) const SCREENSHOT_ENTITY_NAME: &str = "BRP Screenshot Capture";
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SCREENSHOT_MIME_TYPE_PNG: &str = "image/png";
 pub(crate) const SCREENSHOT_STATUS_COMPLETED: &str = "completed";
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const SCREENSHOT_ZERO_PADDING: u32 = 0;
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) const UNKNOWN_WORKING_DIRECTORY: &str = "unknown";
 
+// window title constants
+/// Placeholder replaced with the smoothed FPS
+pub(crate) const TITLE_PLACEHOLDER_FPS: &str = "{fps}";
+/// Placeholder replaced with the current frame count
+pub(crate) const TITLE_PLACEHOLDER_FRAME: &str = "{frame}";
+/// Placeholder replaced with the number of entities in the world
+pub(crate) const TITLE_PLACEHOLDER_ENTITIES: &str = "{entities}";
+/// Seconds between re-renders of a title template
+pub(crate) const TITLE_TEMPLATE_REFRESH_SECS: f32 = 1.0;
+/// Substituted for a placeholder whose value is not available
+pub(crate) const TITLE_VALUE_UNAVAILABLE: &str = "n/a";
+
 // shutdown constants
 /// Number of frames to defer shutdown to allow the response to be sent
 pub(crate) const DEFERRED_SHUTDOWN_FRAMES: u32 = 10;
@@ -0,0 +1,135 @@
+//! Recursive despawn handler for BRP extras
+//!
+//! `despawn_recursive` removes an entity together with every entity below it in the `Children`
+//! hierarchy and reports each entity it removed, so callers can see exactly what disappeared.
+
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `despawn_recursive`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DespawnRecursiveRequest {
+    /// Root of the hierarchy to despawn
+    entity: u64,
+}
+
+/// Response structure for `despawn_recursive`
+#[derive(Serialize)]
+struct DespawnRecursiveResponse {
+    /// Root entity that was despawned
+    entity:          u64,
+    /// Every despawned entity, the root first and then its descendants depth-first
+    despawned:       Vec<u64>,
+    /// Number of despawned entities, including the root
+    despawned_count: usize,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `despawn_recursive` BRP method
+///
+/// Despawns the entity and all of its descendants.
+pub(crate) fn despawn_recursive_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: DespawnRecursiveRequest = parse_required(params)?;
+    let root = Entity::try_from_bits(request.entity)
+        .filter(|entity| world.get_entity(*entity).is_ok())
+        .ok_or_else(|| {
+            BrpExtrasError::EntityNotFound.with_details(
+                format!("Entity {} does not exist", request.entity),
+                json!({ "entity": request.entity }),
+            )
+        })?;
+
+    let hierarchy = collect_hierarchy(world, root);
+
+    // Despawn leaves first; entities already removed through a linked relationship are skipped
+    for &entity in hierarchy.iter().rev() {
+        if let Ok(entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.despawn();
+        }
+    }
+
+    serde_json::to_value(DespawnRecursiveResponse {
+        entity:          request.entity,
+        despawned_count: hierarchy.len(),
+        despawned:       hierarchy.into_iter().map(Entity::to_bits).collect(),
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The root followed by its descendants in depth-first order
+fn collect_hierarchy(world: &World, root: Entity) -> Vec<Entity> {
+    let mut hierarchy = Vec::new();
+    let mut pending = vec![root];
+    while let Some(entity) = pending.pop() {
+        hierarchy.push(entity);
+        if let Some(children) = world.get::<Children>(entity) {
+            pending.extend(children.iter().rev());
+        }
+    }
+    hierarchy
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::ChildOf;
+    use bevy::prelude::In;
+    use serde_json::json;
+
+    use super::despawn_recursive_handler;
+
+    #[test]
+    fn despawns_the_entity_and_all_descendants() {
+        let mut app = App::new();
+        let world = app.world_mut();
+        let root = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(root)).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+        let unrelated = world.spawn_empty().id();
+
+        let response = despawn_recursive_handler(
+            In(Some(json!({ "entity": root.to_bits() }))),
+            app.world_mut(),
+        )
+        .expect("hierarchy should despawn");
+
+        assert_eq!(
+            response["despawned"],
+            json!([root.to_bits(), child.to_bits(), grandchild.to_bits()])
+        );
+        assert_eq!(response["despawned_count"], 3);
+        let world = app.world();
+        assert!(world.get_entity(root).is_err());
+        assert!(world.get_entity(grandchild).is_err());
+        assert!(world.get_entity(unrelated).is_ok());
+    }
+}
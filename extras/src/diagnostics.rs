@@ -1,40 +1,145 @@
-//! FPS diagnostics handler for BRP extras
+//! FPS and per-system diagnostics handler for BRP extras
+//!
+//! `detail: "systems"` adds every other diagnostic in the `DiagnosticsStore`: process and host
+//! usage from `SystemInformationDiagnosticsPlugin`, and per-system timings recorded by profiling
+//! plugins that register a diagnostic per system. Bevy records neither by default, so both are
+//! empty unless the app adds such a plugin.
+//!
+//! `categories: ["render"]` adds the diagnostics `RenderDiagnosticsPlugin` records, grouped by
+//! render pass, with draw calls summed across passes and the number of entities visible to any
+//! view. GPU timings (`elapsed_gpu`) only exist when the GPU supports timestamp queries.
+//!
+//! `diagnostics_watch` is a watching method that samples the store every `every_n_frames` frames,
+//! so a client can record an FPS timeline while it reproduces a problem. Samples are taken on
+//! frames whose `FrameCount` is a multiple of the interval, which keeps the handler stateless.
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
+use bevy::camera::visibility::ViewVisibility;
 use bevy::diagnostic::Diagnostic;
 use bevy::diagnostic::DiagnosticsStore;
+use bevy::diagnostic::FrameCount;
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::prelude::*;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Map;
 use serde_json::Value;
 use serde_json::json;
 
+use crate::constants::DEFAULT_DIAGNOSTICS_WATCH_INTERVAL_FRAMES;
 use crate::constants::DIAGNOSTICS_AVERAGE_FIELD;
 use crate::constants::DIAGNOSTICS_CURRENT_FIELD;
+use crate::constants::DIAGNOSTICS_DRAW_CALLS_FIELD;
+use crate::constants::DIAGNOSTICS_ELAPSED_SECS_FIELD;
+use crate::constants::DIAGNOSTICS_ENTRIES_FIELD;
 use crate::constants::DIAGNOSTICS_FPS_FIELD;
 use crate::constants::DIAGNOSTICS_FRAME_COUNT_FIELD;
+use crate::constants::DIAGNOSTICS_FRAME_FIELD;
 use crate::constants::DIAGNOSTICS_FRAME_TIME_MS_FIELD;
+use crate::constants::DIAGNOSTICS_GPU_TIMINGS_AVAILABLE_FIELD;
 use crate::constants::DIAGNOSTICS_HISTORY_DURATION_SECS_FIELD;
 use crate::constants::DIAGNOSTICS_HISTORY_LEN_FIELD;
 use crate::constants::DIAGNOSTICS_MAX_HISTORY_LEN_FIELD;
+use crate::constants::DIAGNOSTICS_MEASUREMENTS_FIELD;
+use crate::constants::DIAGNOSTICS_PASS_FIELD;
+use crate::constants::DIAGNOSTICS_PASSES_FIELD;
+use crate::constants::DIAGNOSTICS_PATH_FIELD;
+use crate::constants::DIAGNOSTICS_RENDER_FIELD;
 use crate::constants::DIAGNOSTICS_SMOOTHED_FIELD;
+use crate::constants::DIAGNOSTICS_SUFFIX_FIELD;
+use crate::constants::DIAGNOSTICS_SYSTEM_INFORMATION_FIELD;
+use crate::constants::DIAGNOSTICS_SYSTEM_TIMINGS_FIELD;
+use crate::constants::DIAGNOSTICS_VISIBLE_ENTITIES_FIELD;
+use crate::constants::RENDER_DIAGNOSTICS_PATH_PREFIX;
+use crate::constants::RENDER_DRAW_CALLS_MEASUREMENT;
+use crate::constants::RENDER_ELAPSED_GPU_MEASUREMENT;
+use crate::constants::SYSTEM_INFORMATION_PATH_PREFIXES;
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+
+/// How much a `get_diagnostics` response includes
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum DiagnosticsDetail {
+    /// FPS, frame time, and frame count
+    #[default]
+    Summary,
+    /// The summary plus system information and per-system timings
+    Systems,
+}
+
+/// Optional sections a `get_diagnostics` response can add to the summary
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum DiagnosticsCategory {
+    /// System information and per-system timings, the same as `detail: "systems"`
+    Systems,
+    /// Per-pass render diagnostics, draw calls, and visible entity count
+    Render,
+}
+
+/// Request structure for `get_diagnostics`
+#[derive(Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetDiagnosticsRequest {
+    #[serde(default)]
+    detail:     DiagnosticsDetail,
+    /// Sections to add to the summary
+    #[serde(default)]
+    categories: Vec<DiagnosticsCategory>,
+}
+
+/// Request structure for `diagnostics_watch`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DiagnosticsWatchRequest {
+    /// Frames between samples (default: 60)
+    #[serde(default = "default_watch_interval")]
+    every_n_frames: u32,
+    /// Diagnostic paths to sample, e.g. `["fps", "frame_time"]` (None = FPS and frame time)
+    #[serde(default)]
+    paths:          Option<Vec<String>>,
+}
+
+impl Default for DiagnosticsWatchRequest {
+    fn default() -> Self {
+        Self {
+            every_n_frames: DEFAULT_DIAGNOSTICS_WATCH_INTERVAL_FRAMES,
+            paths:          None,
+        }
+    }
+}
+
+const fn default_watch_interval() -> u32 { DEFAULT_DIAGNOSTICS_WATCH_INTERVAL_FRAMES }
 
 /// Handler for `get_diagnostics` requests
 ///
 /// Returns FPS and frame time diagnostics from Bevy's `DiagnosticsStore`.
 /// Requires `FrameTimeDiagnosticsPlugin` to be installed (done automatically
 /// by `BrpExtrasPlugin` when the `diagnostics` feature is enabled).
-pub(crate) fn handler(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
-    let Some(store) = world.get_resource::<DiagnosticsStore>() else {
-        return Err(BrpError {
-            code:    INTERNAL_ERROR,
-            message: "DiagnosticsStore not found - FrameTimeDiagnosticsPlugin may not be installed"
-                .to_string(),
-            data:    None,
+pub(crate) fn handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: GetDiagnosticsRequest = parse_optional(params)?;
+    let include_systems = request.detail == DiagnosticsDetail::Systems
+        || request.categories.contains(&DiagnosticsCategory::Systems);
+    let visible_entities = request
+        .categories
+        .contains(&DiagnosticsCategory::Render)
+        .then(|| {
+            world
+                .query::<&ViewVisibility>()
+                .iter(world)
+                .filter(|visibility| visibility.get())
+                .count()
         });
+
+    let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+        return Err(BrpExtrasError::Unsupported.error(
+            "DiagnosticsStore not found - FrameTimeDiagnosticsPlugin may not be installed",
+        ));
     };
 
     let fps = store.get(&FrameTimeDiagnosticsPlugin::FPS);
@@ -57,7 +162,7 @@ pub(crate) fn handler(In(_): In<Option<Value>>, world: &mut World) -> BrpResult
 
     let total_frames = frame_count.and_then(Diagnostic::value);
 
-    Ok(json!({
+    let mut response = json!({
         DIAGNOSTICS_FPS_FIELD: {
             DIAGNOSTICS_CURRENT_FIELD: fps_value,
             DIAGNOSTICS_AVERAGE_FIELD: fps_avg,
@@ -72,5 +177,310 @@ pub(crate) fn handler(In(_): In<Option<Value>>, world: &mut World) -> BrpResult
             DIAGNOSTICS_SMOOTHED_FIELD: frame_time_smoothed,
         },
         DIAGNOSTICS_FRAME_COUNT_FIELD: total_frames,
-    }))
+    });
+
+    let Value::Object(fields) = &mut response else {
+        return Ok(response);
+    };
+    if include_systems {
+        let (system_information, system_timings) = system_diagnostics(store);
+        fields.insert(
+            DIAGNOSTICS_SYSTEM_INFORMATION_FIELD.to_string(),
+            Value::Object(system_information),
+        );
+        fields.insert(
+            DIAGNOSTICS_SYSTEM_TIMINGS_FIELD.to_string(),
+            Value::Array(system_timings),
+        );
+    }
+    if let Some(visible_entities) = visible_entities {
+        fields.insert(
+            DIAGNOSTICS_RENDER_FIELD.to_string(),
+            render_diagnostics(store, visible_entities),
+        );
+    }
+
+    Ok(response)
+}
+
+/// Handler for the `diagnostics_watch` BRP method
+///
+/// Streams `{ "frame", "elapsed_secs", "entries": [...] }` on every `every_n_frames`th frame, one
+/// entry per sampled diagnostic, and nothing on the frames in between.
+pub(crate) fn watch_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let request: DiagnosticsWatchRequest = parse_optional(params)?;
+    if request.every_n_frames == 0 {
+        return Err(BrpExtrasError::InvalidParams.error("'every_n_frames' must be at least 1"));
+    }
+
+    let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+        return Err(BrpExtrasError::Unsupported.error(
+            "DiagnosticsStore not found - FrameTimeDiagnosticsPlugin may not be installed",
+        ));
+    };
+    let diagnostics = watched_diagnostics(store, request.paths.as_deref())?;
+
+    let Some(frame) = world.get_resource::<FrameCount>().map(|count| count.0) else {
+        return Err(BrpExtrasError::Unsupported
+            .error("FrameCount not found - FrameCountPlugin may not be installed"));
+    };
+    if frame % request.every_n_frames != 0 {
+        return Ok(None);
+    }
+
+    let entries: Vec<Value> = diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            let mut entry = json!({ DIAGNOSTICS_PATH_FIELD: diagnostic.path().as_str() });
+            if let (Value::Object(entry), Value::Object(values)) =
+                (&mut entry, diagnostic_values(diagnostic))
+            {
+                entry.extend(values);
+            }
+            entry
+        })
+        .collect();
+    Ok(Some(json!({
+        DIAGNOSTICS_FRAME_FIELD: frame,
+        DIAGNOSTICS_ELAPSED_SECS_FIELD: world.get_resource::<Time>().map(Time::elapsed_secs_f64),
+        DIAGNOSTICS_ENTRIES_FIELD: entries,
+    })))
+}
+
+/// The diagnostics a watch samples, in request order; unknown paths are rejected with the
+/// available ones in the error details
+fn watched_diagnostics<'a>(
+    store: &'a DiagnosticsStore,
+    paths: Option<&[String]>,
+) -> Result<Vec<&'a Diagnostic>, BrpError> {
+    let Some(paths) = paths else {
+        return Ok([
+            &FrameTimeDiagnosticsPlugin::FPS,
+            &FrameTimeDiagnosticsPlugin::FRAME_TIME,
+        ]
+        .into_iter()
+        .filter_map(|path| store.get(path))
+        .collect());
+    };
+
+    let find = |path: &str| {
+        store
+            .iter()
+            .find(|diagnostic| diagnostic.path().as_str() == path)
+    };
+    let unknown: Vec<&str> = paths
+        .iter()
+        .map(String::as_str)
+        .filter(|path| find(path).is_none())
+        .collect();
+    if !unknown.is_empty() {
+        let mut available: Vec<&str> = store
+            .iter()
+            .map(|diagnostic| diagnostic.path().as_str())
+            .collect();
+        available.sort_unstable();
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("Unknown diagnostic paths: {}", unknown.join(", ")),
+            json!({ "unknown": unknown, "available": available }),
+        ));
+    }
+    Ok(paths.iter().filter_map(|path| find(path)).collect())
+}
+
+/// Current, average, and smoothed values of one diagnostic with its suffix
+fn diagnostic_values(diagnostic: &Diagnostic) -> Value {
+    json!({
+        DIAGNOSTICS_CURRENT_FIELD: diagnostic.value(),
+        DIAGNOSTICS_AVERAGE_FIELD: diagnostic.average(),
+        DIAGNOSTICS_SMOOTHED_FIELD: diagnostic.smoothed(),
+        DIAGNOSTICS_SUFFIX_FIELD: diagnostic.suffix,
+    })
+}
+
+/// Render diagnostics grouped by pass in name order, with the current draw calls summed across
+/// passes
+fn render_diagnostics(store: &DiagnosticsStore, visible_entities: usize) -> Value {
+    let mut passes: BTreeMap<&str, Map<String, Value>> = BTreeMap::new();
+    let mut draw_calls: Option<f64> = None;
+    let mut gpu_timings_available = false;
+    for diagnostic in store.iter() {
+        // Pass names may be nested spans containing `/`, so the measurement is the last segment
+        let Some((pass, measurement)) = diagnostic
+            .path()
+            .as_str()
+            .strip_prefix(RENDER_DIAGNOSTICS_PATH_PREFIX)
+            .and_then(|rest| rest.rsplit_once('/'))
+        else {
+            continue;
+        };
+        if measurement == RENDER_DRAW_CALLS_MEASUREMENT
+            && let Some(value) = diagnostic.value()
+        {
+            *draw_calls.get_or_insert(0.0) += value;
+        }
+        gpu_timings_available |=
+            measurement == RENDER_ELAPSED_GPU_MEASUREMENT && diagnostic.value().is_some();
+        passes
+            .entry(pass)
+            .or_default()
+            .insert(measurement.to_string(), diagnostic_values(diagnostic));
+    }
+
+    json!({
+        DIAGNOSTICS_VISIBLE_ENTITIES_FIELD: visible_entities,
+        DIAGNOSTICS_DRAW_CALLS_FIELD: draw_calls,
+        DIAGNOSTICS_GPU_TIMINGS_AVAILABLE_FIELD: gpu_timings_available,
+        DIAGNOSTICS_PASSES_FIELD: passes
+            .into_iter()
+            .map(|(pass, measurements)| json!({
+                DIAGNOSTICS_PASS_FIELD: pass,
+                DIAGNOSTICS_MEASUREMENTS_FIELD: measurements,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Every diagnostic besides the frame time and render ones, split into system information keyed by
+/// path and per-system timings sorted slowest first by average
+fn system_diagnostics(store: &DiagnosticsStore) -> (Map<String, Value>, Vec<Value>) {
+    let frame_paths = [
+        &FrameTimeDiagnosticsPlugin::FPS,
+        &FrameTimeDiagnosticsPlugin::FRAME_TIME,
+        &FrameTimeDiagnosticsPlugin::FRAME_COUNT,
+    ];
+
+    let mut system_information = Map::new();
+    let mut system_timings: Vec<(f64, Value)> = Vec::new();
+    for diagnostic in store
+        .iter()
+        .filter(|diagnostic| !frame_paths.contains(&diagnostic.path()))
+        .filter(|diagnostic| {
+            !diagnostic
+                .path()
+                .as_str()
+                .starts_with(RENDER_DIAGNOSTICS_PATH_PREFIX)
+        })
+    {
+        let path = diagnostic.path().as_str();
+        let values = diagnostic_values(diagnostic);
+        if SYSTEM_INFORMATION_PATH_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            system_information.insert(path.to_string(), values);
+        } else {
+            let mut timing = json!({ DIAGNOSTICS_PATH_FIELD: path });
+            if let (Value::Object(timing), Value::Object(values)) = (&mut timing, values) {
+                timing.extend(values);
+            }
+            system_timings.push((diagnostic.average().unwrap_or_default(), timing));
+        }
+    }
+    system_timings.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+    (
+        system_information,
+        system_timings
+            .into_iter()
+            .map(|(_, timing)| timing)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::diagnostic::Diagnostic;
+    use bevy::diagnostic::DiagnosticMeasurement;
+    use bevy::diagnostic::DiagnosticPath;
+    use bevy::diagnostic::DiagnosticsStore;
+    use bevy::diagnostic::FrameCount;
+    use bevy::platform::time::Instant;
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::handler;
+    use super::watch_handler;
+
+    fn store_with(measurements: &[(&'static str, f64)]) -> DiagnosticsStore {
+        let mut store = DiagnosticsStore::default();
+        for &(path, value) in measurements {
+            let mut diagnostic = Diagnostic::new(DiagnosticPath::new(path));
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+            store.add(diagnostic);
+        }
+        store
+    }
+
+    #[test]
+    fn render_category_groups_passes_and_sums_draw_calls() {
+        let mut world = World::new();
+        world.insert_resource(store_with(&[
+            ("render/main_opaque_pass_3d/draw_calls", 12.0),
+            ("render/main_opaque_pass_3d/elapsed_cpu", 0.4),
+            ("render/shadows/point_light/draw_calls", 3.0),
+            ("my_game/physics_step", 1.5),
+        ]));
+
+        let response = handler(
+            In(Some(json!({ "categories": ["systems", "render"] }))),
+            &mut world,
+        )
+        .expect("diagnostics should be returned");
+
+        let render = &response["render"];
+        assert_eq!(render["draw_calls"], json!(15.0));
+        assert_eq!(render["visible_entities"], json!(0));
+        assert_eq!(render["gpu_timings_available"], json!(false));
+        let passes: Vec<&str> = render["passes"]
+            .as_array()
+            .expect("passes is an array")
+            .iter()
+            .filter_map(|pass| pass["pass"].as_str())
+            .collect();
+        assert_eq!(passes, ["main_opaque_pass_3d", "shadows/point_light"]);
+        assert_eq!(
+            render["passes"][0]["measurements"]["elapsed_cpu"]["current"],
+            json!(0.4)
+        );
+
+        // Render diagnostics are not reported again as system timings
+        let timings = response["system_timings"]
+            .as_array()
+            .expect("system_timings is an array");
+        assert_eq!(timings.len(), 1);
+        assert_eq!(timings[0]["path"], json!("my_game/physics_step"));
+    }
+
+    #[test]
+    fn watch_samples_every_n_frames() {
+        let mut world = World::new();
+        world.insert_resource(store_with(&[("fps", 59.5), ("frame_time", 16.8)]));
+        let params = || Some(json!({ "every_n_frames": 30, "paths": ["frame_time"] }));
+
+        world.insert_resource(FrameCount(60));
+        let sample = watch_handler(In(params()), &mut world)
+            .expect("watch should succeed")
+            .expect("frame 60 is sampled");
+        assert_eq!(sample["frame"], json!(60));
+        assert_eq!(sample["entries"][0]["path"], json!("frame_time"));
+        assert_eq!(sample["entries"][0]["current"], json!(16.8));
+        assert_eq!(sample["entries"].as_array().map(Vec::len), Some(1));
+
+        world.insert_resource(FrameCount(61));
+        let skipped = watch_handler(In(params()), &mut world).expect("watch should succeed");
+        assert_eq!(skipped, None);
+
+        let unknown = watch_handler(In(Some(json!({ "paths": ["gpu_time"] }))), &mut world);
+        assert!(unknown.is_err());
+    }
 }
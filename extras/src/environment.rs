@@ -0,0 +1,279 @@
+//! Clear color and ambient light handlers for BRP extras
+//!
+//! Setting `ClearColor` or the ambient light through `world.insert_resources` or
+//! `world.mutate_resources` means spelling out `Color` enum variants by hand, and per-camera
+//! overrides are components on a camera that has to be found first. `set_clear_color` and
+//! `set_ambient_light` take plain sRGBA arrays instead, for the whole app or for one camera.
+
+#[cfg(feature = "light")]
+use bevy::light::AmbientLight;
+#[cfg(feature = "light")]
+use bevy::light::GlobalAmbientLight;
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `set_clear_color`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetClearColorRequest {
+    /// sRGBA color with components in `0.0..=1.0`
+    color:  [f32; 4],
+    /// Camera to set the clear color of, instead of the app-wide `ClearColor`
+    #[serde(default)]
+    camera: Option<u64>,
+}
+
+/// Response structure for `set_clear_color`
+#[derive(Serialize)]
+struct SetClearColorResponse {
+    /// The color that was set, as sRGBA
+    color:    [f32; 4],
+    /// Camera whose clear color was set, when one was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    camera:   Option<u64>,
+    /// The clear color before the change, as sRGBA. Absent when the camera used the app-wide
+    /// color or did not clear.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<[f32; 4]>,
+}
+
+/// Request structure for `set_ambient_light`
+#[cfg(feature = "light")]
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetAmbientLightRequest {
+    /// sRGBA light color with components in `0.0..=1.0` (None = unchanged)
+    #[serde(default)]
+    color:      Option<[f32; 4]>,
+    /// Brightness in candela per square meter (None = unchanged)
+    #[serde(default)]
+    brightness: Option<f32>,
+    /// Camera to set an `AmbientLight` override on, instead of the app-wide `GlobalAmbientLight`
+    #[serde(default)]
+    camera:     Option<u64>,
+}
+
+/// Response structure for `set_ambient_light`
+#[cfg(feature = "light")]
+#[derive(Serialize)]
+struct SetAmbientLightResponse {
+    /// The ambient light color after the change, as sRGBA
+    color:      [f32; 4],
+    /// The ambient light brightness after the change
+    brightness: f32,
+    /// Camera whose ambient light was set, when one was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    camera:     Option<u64>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `set_clear_color` BRP method
+///
+/// Without `camera`, replaces the `ClearColor` resource used by every camera whose
+/// `clear_color` is `ClearColorConfig::Default`. With `camera`, sets that camera's
+/// `clear_color` to `ClearColorConfig::Custom`.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed
+/// - `camera` is not an entity with a `Camera`
+pub(crate) fn set_clear_color_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SetClearColorRequest = parse_required(params)?;
+    let [red, green, blue, alpha] = request.color;
+    let color = Color::srgba(red, green, blue, alpha);
+
+    let previous = if let Some(camera_id) = request.camera {
+        let mut camera = camera_mut(world, camera_id)?;
+        let previous = match camera.clear_color {
+            ClearColorConfig::Custom(previous) => Some(to_srgba(previous)),
+            ClearColorConfig::Default | ClearColorConfig::None => None,
+        };
+        camera.clear_color = ClearColorConfig::Custom(color);
+        previous
+    } else {
+        let previous = world
+            .get_resource::<ClearColor>()
+            .map(|clear_color| to_srgba(clear_color.0));
+        world.insert_resource(ClearColor(color));
+        previous
+    };
+
+    serialize_response(SetClearColorResponse {
+        color: request.color,
+        camera: request.camera,
+        previous,
+    })
+}
+
+/// Handler for `set_ambient_light` BRP method
+///
+/// Without `camera`, updates the `GlobalAmbientLight` resource. With `camera`, updates that
+/// camera's `AmbientLight` override, inserting one that starts from the global values if the
+/// camera has none.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed, or give neither `color` nor `brightness`
+/// - `brightness` is negative or not finite
+/// - `camera` is not an entity with a `Camera`
+#[cfg(feature = "light")]
+pub(crate) fn set_ambient_light_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SetAmbientLightRequest = parse_required(params)?;
+    if request.color.is_none() && request.brightness.is_none() {
+        return Err(
+            BrpExtrasError::InvalidParams.error("Provide at least one of `color` or `brightness`")
+        );
+    }
+    if let Some(brightness) = request.brightness
+        && (!brightness.is_finite() || brightness < 0.0)
+    {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("brightness must be a finite, non-negative number, got {brightness}"),
+            json!({ "brightness": brightness }),
+        ));
+    }
+    let color = request
+        .color
+        .map(|[red, green, blue, alpha]| Color::srgba(red, green, blue, alpha));
+
+    let global = world.get_resource_or_init::<GlobalAmbientLight>().clone();
+    let (color, brightness) = if let Some(camera_id) = request.camera {
+        camera_mut(world, camera_id)?;
+        let camera = Entity::from_bits(camera_id);
+        let mut ambient_light =
+            world
+                .get::<AmbientLight>(camera)
+                .cloned()
+                .unwrap_or(AmbientLight {
+                    color:                      global.color,
+                    brightness:                 global.brightness,
+                    affects_lightmapped_meshes: global.affects_lightmapped_meshes,
+                });
+        if let Some(color) = color {
+            ambient_light.color = color;
+        }
+        if let Some(brightness) = request.brightness {
+            ambient_light.brightness = brightness;
+        }
+        let applied = (ambient_light.color, ambient_light.brightness);
+        world.entity_mut(camera).insert(ambient_light);
+        applied
+    } else {
+        let mut ambient_light = world.resource_mut::<GlobalAmbientLight>();
+        if let Some(color) = color {
+            ambient_light.color = color;
+        }
+        if let Some(brightness) = request.brightness {
+            ambient_light.brightness = brightness;
+        }
+        (ambient_light.color, ambient_light.brightness)
+    };
+
+    serialize_response(SetAmbientLightResponse {
+        color: to_srgba(color),
+        brightness,
+        camera: request.camera,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The `Camera` of `camera_id`, or an error naming why it cannot be used
+fn camera_mut(world: &mut World, camera_id: u64) -> Result<Mut<'_, Camera>, BrpError> {
+    let not_found = || {
+        BrpExtrasError::EntityNotFound.with_details(
+            format!("Entity {camera_id} does not exist"),
+            json!({ PARAM_ENTITY: camera_id }),
+        )
+    };
+    let entity = Entity::try_from_bits(camera_id).ok_or_else(not_found)?;
+    if world.get_entity(entity).is_err() {
+        return Err(not_found());
+    }
+    world.get_mut::<Camera>(entity).ok_or_else(|| {
+        BrpExtrasError::CameraUnavailable.with_details(
+            format!("Entity {camera_id} is not a camera"),
+            json!({ PARAM_ENTITY: camera_id }),
+        )
+    })
+}
+
+fn to_srgba(color: Color) -> [f32; 4] { color.to_srgba().to_f32_array() }
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::set_clear_color_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn clear_color_replaces_the_resource_and_reports_the_previous_color() {
+        let mut app = App::new();
+        app.insert_resource(ClearColor(Color::BLACK));
+
+        let response = set_clear_color_handler(
+            In(Some(json!({ "color": [1.0, 0.0, 0.0, 1.0] }))),
+            app.world_mut(),
+        )
+        .expect("the app-wide clear color should be set");
+
+        assert_eq!(response["previous"], json!([0.0, 0.0, 0.0, 1.0]));
+        assert_eq!(
+            app.world()
+                .resource::<ClearColor>()
+                .0
+                .to_srgba(),
+            Srgba::RED
+        );
+    }
+
+    #[test]
+    fn clear_color_for_a_non_camera_entity_is_rejected() {
+        let mut app = App::new();
+        let entity = app.world_mut().spawn_empty().id();
+
+        let error = set_clear_color_handler(
+            In(Some(
+                json!({ "color": [1.0, 0.0, 0.0, 1.0], "camera": entity.to_bits() }),
+            )),
+            app.world_mut(),
+        )
+        .expect_err("an entity without a Camera should be rejected");
+
+        assert_eq!(error.code, BrpExtrasError::CameraUnavailable.code());
+    }
+}
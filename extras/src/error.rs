@@ -0,0 +1,110 @@
+//! Structured errors returned by `brp_extras/*` methods
+//!
+//! Every extras failure uses a code from the extras code space (`-24000` to `-24099`) instead of
+//! the generic JSON-RPC codes, and carries `data` of the form
+//! `{ "kind": "<snake_case kind>", "details": { ... } }`. Clients branch on `code` or `kind`;
+//! `message` stays human-readable and may change between releases.
+
+use bevy_remote::BrpError;
+use serde_json::Value;
+use serde_json::json;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use strum_macros::IntoStaticStr;
+
+use crate::constants::ERROR_DETAILS_FIELD;
+use crate::constants::ERROR_KIND_FIELD;
+
+/// Error kinds returned by `brp_extras/*` methods, each with a stable numeric code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[repr(i16)]
+pub enum BrpExtrasError {
+    /// Required parameters were not sent
+    MissingParams     = -24_000,
+    /// Parameters failed to parse or are out of range
+    InvalidParams     = -24_001,
+    /// A referenced entity does not exist or cannot be used by the method
+    EntityNotFound    = -24_002,
+    /// A referenced window does not exist, or the app has no primary window
+    WindowNotFound    = -24_003,
+    /// No camera, or more than one, satisfies the request
+    CameraUnavailable = -24_004,
+    /// An asset or asset type is not registered or not loaded
+    AssetNotFound     = -24_005,
+    /// A plugin, resource, or cargo feature the method needs is not available
+    Unsupported       = -24_006,
+    /// The method cannot run in the app's current state
+    InvalidState      = -24_007,
+    /// Reading or writing a file failed
+    Io                = -24_008,
+    /// The method did not finish before its deadline
+    Timeout           = -24_009,
+    /// The method needs a window, but the app is running headless without one
+    Headless          = -24_010,
+    /// The method failed while producing its result
+    Internal          = -24_099,
+}
+
+impl BrpExtrasError {
+    /// Numeric JSON-RPC error code
+    #[must_use]
+    pub const fn code(self) -> i16 { self as i16 }
+
+    /// Snake-case name reported as `data.kind`
+    #[must_use]
+    pub fn kind(self) -> &'static str { self.into() }
+
+    /// Error kind for a code returned by an extras method
+    #[must_use]
+    pub fn from_code(code: i16) -> Option<Self> { Self::iter().find(|error| error.code() == code) }
+
+    /// Build a BRP error with empty details
+    ///
+    /// Custom methods added with `BrpExtrasPlugin::with_method` use this to report failures the
+    /// same way as the built-in methods.
+    #[must_use]
+    pub fn error(self, message: impl Into<String>) -> BrpError {
+        self.with_details(message, json!({}))
+    }
+
+    /// Build a BRP error with machine-readable details
+    #[must_use]
+    pub fn with_details(self, message: impl Into<String>, details: Value) -> BrpError {
+        BrpError {
+            code:    self.code(),
+            message: message.into(),
+            data:    Some(json!({
+                ERROR_KIND_FIELD: self.kind(),
+                ERROR_DETAILS_FIELD: details,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+    use strum::IntoEnumIterator;
+
+    use super::BrpExtrasError;
+
+    #[test]
+    fn codes_are_unique_and_round_trip() {
+        let codes: HashSet<i16> = BrpExtrasError::iter().map(BrpExtrasError::code).collect();
+        assert_eq!(codes.len(), BrpExtrasError::iter().count());
+        for error in BrpExtrasError::iter() {
+            assert!((-24_099..=-24_000).contains(&error.code()));
+            assert_eq!(BrpExtrasError::from_code(error.code()), Some(error));
+        }
+
+        let error = BrpExtrasError::WindowNotFound.with_details("gone", json!({ "window": 7 }));
+        assert_eq!(error.code, -24_003);
+        assert_eq!(
+            error.data,
+            Some(json!({ "kind": "window_not_found", "details": { "window": 7 } }))
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! Event watching and sending for BRP extras
+//!
+//! Messages can't be read or written through reflection alone, so apps choose which message types
+//! remote clients may use with [`AppBrpMessageExt::register_brp_message`]; keyboard, mouse button,
+//! and mouse wheel input are registered by default. Each registered type gets a system that
+//! serializes its messages into the [`RecordedEvents`] ring buffer, which
+//! `brp_extras/events_watch` streams each frame, and a writer that `brp_extras/send_event` uses to
+//! write messages deserialized from JSON.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+
+use bevy::diagnostic::FrameCount;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::reflect::GetTypeRegistration;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::MAX_RECORDED_EVENTS;
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::parse_required;
+
+/// Writes a deserialized message of the type it was registered for
+type WriteMessageFn = fn(&mut World, &dyn PartialReflect) -> Result<(), BrpError>;
+
+/// Plugin that records the default registered input messages
+pub(crate) struct EventWatchPlugin;
+
+impl Plugin for EventWatchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecordedEvents>();
+        app.add_systems(First, start_event_frame);
+        app.register_brp_message::<KeyboardInput>()
+            .register_brp_message::<MouseButtonInput>()
+            .register_brp_message::<MouseWheel>();
+    }
+}
+
+/// Extends [`App`] with message types that BRP clients can watch and send.
+pub trait AppBrpMessageExt {
+    /// Lets `brp_extras/events_watch` stream and `brp_extras/send_event` write messages of type
+    /// `M`.
+    ///
+    /// Messages are serialized through reflection, so the types of `M`'s fields must be
+    /// registered. Registering a type twice has no effect, and registration can occur before or
+    /// after `BrpExtrasPlugin` is added. Messages are only recorded and sent while the app has a
+    /// `Messages<M>` resource.
+    fn register_brp_message<M>(&mut self) -> &mut Self
+    where
+        M: Message + Reflect + FromReflect + TypePath + GetTypeRegistration;
+}
+
+impl AppBrpMessageExt for App {
+    fn register_brp_message<M>(&mut self) -> &mut Self
+    where
+        M: Message + Reflect + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.init_resource::<RecordedEvents>();
+        let newly_registered = self
+            .world_mut()
+            .resource_mut::<RecordedEvents>()
+            .registered
+            .insert(M::type_path().to_string(), write_reflected_message::<M>)
+            .is_none();
+        if newly_registered {
+            self.register_type::<M>();
+            self.add_systems(
+                Last,
+                record_messages::<M>.run_if(resource_exists::<Messages<M>>),
+            );
+        }
+        self
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `events_watch`
+#[derive(Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct EventsWatchRequest {
+    /// Only stream events of these message type paths (None = every registered type)
+    #[serde(default)]
+    types: Option<Vec<String>>,
+}
+
+/// Request structure for `send_event`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SendEventRequest {
+    /// Type path of the message to write
+    event: String,
+    /// The message in BRP's reflect format (None = a unit struct)
+    #[serde(default)]
+    value: Option<Value>,
+}
+
+/// Response structure for `send_event`
+#[derive(Serialize)]
+struct SendEventResponse {
+    /// Type path of the written message
+    event: String,
+    /// Frame the message was written in, when `FrameCount` is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame: Option<u32>,
+}
+
+/// Registered message types and recently recorded messages of each
+#[derive(Resource, Default)]
+pub(crate) struct RecordedEvents {
+    /// Writers of the registered message types, by type path
+    registered:  BTreeMap<String, WriteMessageFn>,
+    /// Recorded events, oldest first, at most [`MAX_RECORDED_EVENTS`]
+    events:      VecDeque<RecordedEvent>,
+    /// Sequence number of the next recorded event
+    next_seq:    u64,
+    /// Sequence number of the first event recorded this frame
+    frame_start: u64,
+}
+
+impl RecordedEvents {
+    fn push(&mut self, type_path: &str, frame: Option<u32>, value: Value) {
+        if self.events.len() >= MAX_RECORDED_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(RecordedEvent {
+            seq: self.next_seq,
+            type_path: type_path.to_string(),
+            frame,
+            value,
+        });
+        self.next_seq += 1;
+    }
+
+    /// Events recorded since the frame started
+    fn this_frame(&self) -> impl Iterator<Item = &RecordedEvent> {
+        self.events
+            .iter()
+            .skip_while(|event| event.seq < self.frame_start)
+    }
+}
+
+/// One recorded message
+#[derive(Clone, Serialize)]
+struct RecordedEvent {
+    /// Increases by one per recorded event, so gaps show events dropped from the buffer
+    seq:       u64,
+    type_path: String,
+    /// Frame the message was recorded in, when `FrameCount` is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame:     Option<u32>,
+    /// The message, serialized through reflection
+    value:     Value,
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn start_event_frame(mut recorded: ResMut<RecordedEvents>) {
+    recorded.frame_start = recorded.next_seq;
+}
+
+fn record_messages<M: Message + Reflect + TypePath>(
+    mut reader: MessageReader<M>,
+    registry: Res<AppTypeRegistry>,
+    frame_count: Option<Res<FrameCount>>,
+    mut recorded: ResMut<RecordedEvents>,
+) {
+    let registry = registry.read();
+    let values: Vec<Value> = reader
+        .read()
+        .filter_map(|message| {
+            let serializer = TypedReflectSerializer::new(message.as_partial_reflect(), &registry);
+            serde_json::to_value(serializer)
+                .inspect_err(|error| {
+                    warn_once!("Failed to serialize {}: {error}", M::type_path());
+                })
+                .ok()
+        })
+        .collect();
+    drop(registry);
+
+    let frame = frame_count.map(|count| count.0);
+    for value in values {
+        recorded.push(M::type_path(), frame, value);
+    }
+}
+
+fn write_reflected_message<M: Message + FromReflect + TypePath>(
+    world: &mut World,
+    reflected: &dyn PartialReflect,
+) -> Result<(), BrpError> {
+    let message = M::from_reflect(reflected).ok_or_else(|| {
+        BrpExtrasError::InvalidParams.error(format!(
+            "Value does not describe a complete {}",
+            M::type_path()
+        ))
+    })?;
+    if !world.contains_resource::<Messages<M>>() {
+        return Err(BrpExtrasError::InvalidState.error(format!(
+            "The app has no Messages<{}>; add it with `App::add_message`",
+            M::type_path()
+        )));
+    }
+    world.write_message(message);
+    Ok(())
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for the `events_watch` BRP method
+///
+/// Streams the events recorded each frame as `{ "events": [...] }`, skipping frames without any.
+pub(crate) fn events_watch_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let request: EventsWatchRequest = parse_optional(params)?;
+
+    world.init_resource::<RecordedEvents>();
+    let recorded = world.resource::<RecordedEvents>();
+    if let Some(types) = &request.types {
+        let unregistered: Vec<&str> = types
+            .iter()
+            .map(String::as_str)
+            .filter(|type_path| !recorded.registered.contains_key(*type_path))
+            .collect();
+        if !unregistered.is_empty() {
+            return Err(unregistered_error(recorded, &unregistered));
+        }
+    }
+
+    let events: Vec<&RecordedEvent> = recorded
+        .this_frame()
+        .filter(|event| {
+            request
+                .types
+                .as_ref()
+                .is_none_or(|types| types.contains(&event.type_path))
+        })
+        .collect();
+    if events.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(json!({ "events": events })))
+}
+
+/// Handler for the `send_event` BRP method
+///
+/// Deserializes the message through reflection and writes it, so systems reading it see it on
+/// the next frame.
+pub(crate) fn send_event_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: SendEventRequest = parse_required(params)?;
+
+    world.init_resource::<RecordedEvents>();
+    let recorded = world.resource::<RecordedEvents>();
+    let Some(&write_message) = recorded.registered.get(&request.event) else {
+        return Err(unregistered_error(recorded, &[&request.event]));
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let registration = type_registry
+        .get_with_type_path(&request.event)
+        .ok_or_else(|| {
+            BrpExtrasError::Internal.error(format!(
+                "'{}' is missing from the type registry",
+                request.event
+            ))
+        })?;
+    let value = request.value.unwrap_or_else(|| json!({}));
+    let reflected = TypedReflectDeserializer::new(registration, &type_registry)
+        .deserialize(&value)
+        .map_err(|error| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("Failed to deserialize '{}': {error}", request.event),
+                json!({ "event": request.event, "value": value }),
+            )
+        })?;
+    drop(type_registry);
+
+    write_message(world, reflected.as_partial_reflect())?;
+    let frame = world.get_resource::<FrameCount>().map(|count| count.0);
+    serde_json::to_value(SendEventResponse {
+        event: request.event,
+        frame,
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+fn unregistered_error(recorded: &RecordedEvents, unregistered: &[&str]) -> BrpError {
+    let registered: Vec<&String> = recorded.registered.keys().collect();
+    BrpExtrasError::InvalidParams.with_details(
+        format!(
+            "Message types are not registered for BRP: {}. Register them with \
+             `App::register_brp_message`",
+            unregistered.join(", ")
+        ),
+        json!({ "unregistered": unregistered, "registered": registered }),
+    )
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::AppBrpMessageExt;
+    use super::EventWatchPlugin;
+    use super::events_watch_handler;
+    use super::send_event_handler;
+
+    #[derive(Message, Reflect)]
+    struct Scored {
+        points: u32,
+    }
+
+    fn watch(app: &mut App, params: Option<Value>) -> Option<Value> {
+        let world = app.world_mut();
+        let handler = world.register_system(events_watch_handler);
+        world
+            .run_system_with(handler, params)
+            .expect("handler runs")
+            .expect("handler succeeds")
+    }
+
+    #[test]
+    fn streams_messages_recorded_this_frame() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, EventWatchPlugin))
+            .add_message::<Scored>()
+            .register_brp_message::<Scored>();
+
+        app.world_mut().write_message(Scored { points: 3 });
+        app.update();
+        let update = watch(&mut app, None).expect("the message is streamed");
+        assert_eq!(update["events"][0]["value"], json!({ "points": 3 }));
+        assert_eq!(update["events"][0]["type_path"], json!(Scored::type_path()));
+
+        // Nothing new is streamed on the next frame
+        app.update();
+        assert_eq!(watch(&mut app, None), None);
+    }
+
+    #[test]
+    fn sent_events_are_written_as_messages() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, EventWatchPlugin))
+            .add_message::<Scored>()
+            .register_brp_message::<Scored>();
+        app.update();
+
+        let world = app.world_mut();
+        let handler = world.register_system(send_event_handler);
+        world
+            .run_system_with(
+                handler,
+                Some(json!({ "event": Scored::type_path(), "value": { "points": 5 } })),
+            )
+            .expect("handler runs")
+            .expect("handler succeeds");
+        app.update();
+
+        let update = watch(&mut app, None).expect("the sent message is streamed");
+        assert_eq!(update["events"][0]["value"], json!({ "points": 5 }));
+    }
+
+    #[test]
+    fn rejects_unregistered_types() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, EventWatchPlugin));
+        app.update();
+
+        let world = app.world_mut();
+        let handler = world.register_system(events_watch_handler);
+        let result = world
+            .run_system_with(handler, Some(json!({ "types": ["game::Unknown"] })))
+            .expect("handler runs");
+        assert!(result.is_err());
+    }
+}
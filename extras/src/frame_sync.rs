@@ -0,0 +1,388 @@
+//! Frame-synchronized execution for BRP extras
+//!
+//! Input and mutation methods accept `after_frames` or `run_at_frame` to run at the start of a
+//! later frame instead of immediately. The request is answered at once with the frame the call is
+//! scheduled for, so a client can queue a sequence, such as a key press now and a click three
+//! frames later, without sleeping between requests. Scheduled calls run in `First`, before the
+//! frame's input is processed, in the order they were received. Their request has already been
+//! answered, so failures are logged.
+
+use bevy::diagnostic::FrameCount;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use bevy_remote::RemoteMethodSystemId;
+use schemars::JsonSchema;
+use schemars::Schema;
+use schemars::schema_for;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::EXTRAS_COMMAND_PREFIX;
+use crate::constants::METHOD_CLEAR_OVERRIDES;
+use crate::constants::METHOD_CLICK_MOUSE;
+#[cfg(feature = "ui")]
+use crate::constants::METHOD_CLICK_UI_NODE;
+use crate::constants::METHOD_DESPAWN_RECURSIVE;
+use crate::constants::METHOD_DOUBLE_CLICK_MOUSE;
+use crate::constants::METHOD_DOUBLE_TAP_GESTURE;
+use crate::constants::METHOD_DRAG_MOUSE;
+use crate::constants::METHOD_HOVER_MOUSE;
+use crate::constants::METHOD_MOVE_MOUSE;
+use crate::constants::METHOD_MUTATE_REFLECT;
+use crate::constants::METHOD_PINCH_GESTURE;
+use crate::constants::METHOD_PRESS_KEYS;
+use crate::constants::METHOD_RELEASE_KEYS;
+use crate::constants::METHOD_ROTATION_GESTURE;
+use crate::constants::METHOD_RUN_SYSTEM_BY_NAME;
+use crate::constants::METHOD_SCROLL_MOUSE;
+use crate::constants::METHOD_SEND_EVENT;
+use crate::constants::METHOD_SEND_IME;
+use crate::constants::METHOD_SEND_KEYS;
+use crate::constants::METHOD_SEND_MOUSE_BUTTON;
+use crate::constants::METHOD_SET_COMPONENT_OVERRIDE;
+use crate::constants::METHOD_TYPE_TEXT;
+use crate::constants::PARAM_AFTER_FRAMES;
+use crate::constants::PARAM_RUN_AT_FRAME;
+use crate::error::BrpExtrasError;
+use crate::support::serialize_response;
+
+/// Methods that accept `after_frames` and `run_at_frame`, without the `brp_extras/` prefix
+const SCHEDULABLE_METHODS: &[&str] = &[
+    METHOD_CLEAR_OVERRIDES,
+    METHOD_CLICK_MOUSE,
+    #[cfg(feature = "ui")]
+    METHOD_CLICK_UI_NODE,
+    METHOD_DESPAWN_RECURSIVE,
+    METHOD_DOUBLE_CLICK_MOUSE,
+    METHOD_DOUBLE_TAP_GESTURE,
+    METHOD_DRAG_MOUSE,
+    METHOD_HOVER_MOUSE,
+    METHOD_MOVE_MOUSE,
+    METHOD_MUTATE_REFLECT,
+    METHOD_PINCH_GESTURE,
+    METHOD_PRESS_KEYS,
+    METHOD_RELEASE_KEYS,
+    METHOD_ROTATION_GESTURE,
+    METHOD_RUN_SYSTEM_BY_NAME,
+    METHOD_SCROLL_MOUSE,
+    METHOD_SEND_EVENT,
+    METHOD_SEND_IME,
+    METHOD_SEND_KEYS,
+    METHOD_SEND_MOUSE_BUTTON,
+    METHOD_SET_COMPONENT_OVERRIDE,
+    METHOD_TYPE_TEXT,
+];
+
+pub(super) struct FrameSyncPlugin;
+
+impl Plugin for FrameSyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduledCalls>()
+            .add_systems(First, run_scheduled_calls);
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+type MethodSystemId = SystemId<In<Option<Value>>, BrpResult>;
+
+/// When to run a request, taken out of its parameters before the method sees them
+#[derive(Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct FrameTiming {
+    /// Run this many frames from now instead of immediately; 1 runs at the start of the next
+    /// frame
+    #[serde(default)]
+    after_frames: Option<u32>,
+    /// Run at the start of the first frame whose `FrameCount` is at least this value; `ping`
+    /// reports the current `frame_count`. Not combined with `after_frames`
+    #[serde(default)]
+    run_at_frame: Option<u32>,
+}
+
+/// A call waiting for its frame
+struct ScheduledCall {
+    method: String,
+    system: MethodSystemId,
+    params: Option<Value>,
+    frame:  u32,
+}
+
+/// Calls waiting for their frame, in the order they were received
+#[derive(Resource, Default)]
+struct ScheduledCalls(Vec<ScheduledCall>);
+
+/// Response structure for a scheduled call
+#[derive(Serialize)]
+struct ScheduledResponse {
+    scheduled:     bool,
+    method:        String,
+    /// Frame the call runs at the start of
+    run_at_frame:  u32,
+    /// `FrameCount` when the request was received
+    current_frame: u32,
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+/// Whether an extras method, named without the `brp_extras/` prefix, accepts frame timing
+pub(crate) fn is_schedulable(method: &str) -> bool { SCHEDULABLE_METHODS.contains(&method) }
+
+/// Wrap the handler of a schedulable extras method so it honors `after_frames` and
+/// `run_at_frame`; other methods are returned unchanged
+pub(crate) fn wrap_schedulable(
+    world: &mut World,
+    name: &str,
+    system_id: RemoteMethodSystemId,
+) -> RemoteMethodSystemId {
+    let RemoteMethodSystemId::Instant(system) = system_id else {
+        return system_id;
+    };
+    if !name
+        .strip_prefix(EXTRAS_COMMAND_PREFIX)
+        .is_some_and(is_schedulable)
+    {
+        return system_id;
+    }
+    let method = name.to_string();
+    RemoteMethodSystemId::Instant(world.register_system(
+        move |In(params): In<Option<Value>>, world: &mut World| {
+            schedule_or_run(world, &method, system, params)
+        },
+    ))
+}
+
+/// Add the `after_frames` and `run_at_frame` properties to a method's parameter schema
+pub(crate) fn add_timing_properties(schema: &mut Schema) {
+    let timing = schema_for!(FrameTiming);
+    let (Some(properties), Some(timing_properties)) = (
+        schema
+            .as_object_mut()
+            .and_then(|schema| schema.get_mut("properties"))
+            .and_then(Value::as_object_mut),
+        timing
+            .as_object()
+            .and_then(|timing| timing.get("properties"))
+            .and_then(Value::as_object),
+    ) else {
+        return;
+    };
+    properties.extend(timing_properties.clone());
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Run the method now, or queue it for the frame its parameters ask for
+fn schedule_or_run(
+    world: &mut World,
+    method: &str,
+    system: MethodSystemId,
+    params: Option<Value>,
+) -> BrpResult {
+    let (timing, params) = take_timing(params)?;
+    if timing.after_frames.is_none() && timing.run_at_frame.is_none() {
+        return run(world, method, system, params);
+    }
+
+    let Some(current_frame) = world.get_resource::<FrameCount>().map(|count| count.0) else {
+        return Err(BrpExtrasError::Unsupported.error(
+            "after_frames and run_at_frame need the FrameCount resource, added by \
+             FrameCountPlugin in DefaultPlugins and MinimalPlugins",
+        ));
+    };
+    let frame = target_frame(&timing, current_frame)?;
+
+    world
+        .resource_mut::<ScheduledCalls>()
+        .0
+        .push(ScheduledCall {
+            method: method.to_string(),
+            system,
+            params,
+            frame,
+        });
+    serialize_response(ScheduledResponse {
+        scheduled: true,
+        method: method.to_string(),
+        run_at_frame: frame,
+        current_frame,
+    })
+}
+
+/// Run the calls whose frame has come, in the order they were received
+fn run_scheduled_calls(world: &mut World) {
+    let Some(frame) = world.get_resource::<FrameCount>().map(|count| count.0) else {
+        return;
+    };
+    let mut due = {
+        let mut scheduled = world.resource_mut::<ScheduledCalls>();
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut scheduled.0)
+            .into_iter()
+            .partition(|call| call.frame <= frame);
+        scheduled.0 = pending;
+        due
+    };
+    due.sort_by_key(|call| call.frame);
+
+    for call in due {
+        match run(world, &call.method, call.system, call.params) {
+            Ok(_) => debug!("Ran scheduled `{}` at frame {frame}", call.method),
+            Err(error) => warn!(
+                "Scheduled `{}` failed at frame {frame}: {}",
+                call.method, error.message
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Split `after_frames` and `run_at_frame` from the parameters the method receives
+fn take_timing(params: Option<Value>) -> BrpResult<(FrameTiming, Option<Value>)> {
+    let Some(Value::Object(mut object)) = params else {
+        return Ok((FrameTiming::default(), params));
+    };
+    let mut timing = serde_json::Map::new();
+    for field in [PARAM_AFTER_FRAMES, PARAM_RUN_AT_FRAME] {
+        if let Some(value) = object.remove(field) {
+            timing.insert(field.to_string(), value);
+        }
+    }
+    let timing = serde_json::from_value(Value::Object(timing)).map_err(|error| {
+        BrpExtrasError::InvalidParams.error(format!("Failed to parse frame timing: {error}"))
+    })?;
+    Ok((timing, Some(Value::Object(object))))
+}
+
+/// Frame a call runs at the start of, given the `FrameCount` when its request arrived
+///
+/// Requests are answered after `FrameCount` is advanced for the current frame, so the next frame
+/// starts with the same count.
+fn target_frame(timing: &FrameTiming, current_frame: u32) -> BrpResult<u32> {
+    match (timing.after_frames, timing.run_at_frame) {
+        (Some(_), Some(_)) => Err(BrpExtrasError::InvalidParams
+            .error("Give either after_frames or run_at_frame, not both")),
+        (Some(0), None) => Err(BrpExtrasError::InvalidParams
+            .error("after_frames must be at least 1; omit it to run immediately")),
+        (Some(after_frames), None) => Ok(current_frame.saturating_add(after_frames - 1)),
+        (None, Some(run_at_frame)) if run_at_frame < current_frame => {
+            Err(BrpExtrasError::InvalidParams.with_details(
+                format!(
+                    "Frame {run_at_frame} has already started; the next frame is {current_frame}"
+                ),
+                json!({ PARAM_RUN_AT_FRAME: run_at_frame, "current_frame": current_frame }),
+            ))
+        },
+        (None, Some(run_at_frame)) => Ok(run_at_frame),
+        (None, None) => Ok(current_frame),
+    }
+}
+
+fn run(
+    world: &mut World,
+    method: &str,
+    system: MethodSystemId,
+    params: Option<Value>,
+) -> BrpResult {
+    world.run_system_with(system, params).map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to run `{method}`: {error}"))
+    })?
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::diagnostic::FrameCount;
+    use bevy::prelude::*;
+    use bevy_remote::BrpResult;
+    use bevy_remote::RemoteMethodSystemId;
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::FrameSyncPlugin;
+    use super::MethodSystemId;
+    use super::wrap_schedulable;
+
+    #[derive(Resource, Default)]
+    struct Received(Vec<Value>);
+
+    #[allow(
+        clippy::unnecessary_wraps,
+        reason = "BRP handler signature requires BrpResult return type"
+    )]
+    fn record(In(params): In<Option<Value>>, mut received: ResMut<Received>) -> BrpResult {
+        received.0.push(params.unwrap_or(Value::Null));
+        Ok(Value::Null)
+    }
+
+    fn wrap(app: &mut App, name: &str) -> (MethodSystemId, Option<MethodSystemId>) {
+        let inner = app.world_mut().register_system(record);
+        let wrapped = wrap_schedulable(app.world_mut(), name, RemoteMethodSystemId::Instant(inner));
+        match wrapped {
+            RemoteMethodSystemId::Instant(wrapped) => (inner, Some(wrapped)),
+            RemoteMethodSystemId::Watching(_) => (inner, None),
+        }
+    }
+
+    fn call(app: &mut App, system: MethodSystemId, params: Value) -> BrpResult {
+        app.world_mut()
+            .run_system_with(system, Some(params))
+            .expect("wrapper should run")
+    }
+
+    #[test]
+    fn scheduled_calls_run_at_their_frame_without_timing_params() {
+        let mut app = App::new();
+        app.add_plugins(FrameSyncPlugin)
+            .init_resource::<Received>()
+            .insert_resource(FrameCount(10));
+        let (_, send_keys) = wrap(&mut app, "brp_extras/send_keys");
+        let send_keys = send_keys.expect("send_keys should stay instant");
+
+        let response = call(
+            &mut app,
+            send_keys,
+            json!({ "keys": ["KeyA"], "after_frames": 2 }),
+        )
+        .expect("call should be scheduled");
+        assert_eq!(response["run_at_frame"], 11);
+        assert!(
+            call(
+                &mut app,
+                send_keys,
+                json!({ "keys": [], "run_at_frame": 9 })
+            )
+            .is_err()
+        );
+
+        app.update();
+        assert!(app.world().resource::<Received>().0.is_empty());
+        app.world_mut().insert_resource(FrameCount(11));
+        app.update();
+        assert_eq!(
+            app.world().resource::<Received>().0,
+            [json!({ "keys": ["KeyA"] })]
+        );
+    }
+
+    #[test]
+    fn other_methods_are_not_wrapped() {
+        let mut app = App::new();
+        let (inner, ping) = wrap(&mut app, "brp_extras/ping");
+        assert_eq!(ping, Some(inner));
+    }
+}
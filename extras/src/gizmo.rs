@@ -0,0 +1,588 @@
+//! Debug gizmo handler for BRP extras
+//!
+//! `draw_gizmo` lets an agent mark what it is reasoning about while a human watches the app.
+//! Shapes live in the [`DrawnGizmos`] resource with a time-to-live in frames; whenever the set
+//! changes it is rebuilt into one retained `GizmoAsset`, so idle frames cost nothing. Text labels
+//! are screen-aligned UI nodes that follow a world position, and need the `ui` feature.
+
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::DEFAULT_GIZMO_TTL_FRAMES;
+use crate::constants::GIZMO_ENTITY_NAME;
+#[cfg(feature = "ui")]
+use crate::constants::GIZMO_LABEL_ENTITY_NAME;
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+#[cfg(feature = "ui")]
+use crate::projection;
+use crate::support::parse_required;
+
+/// Color used when a shape does not give one
+const DEFAULT_GIZMO_COLOR: Color = Color::srgb(1.0, 0.85, 0.0);
+
+/// Plugin that draws, ages, and expires gizmos requested through `draw_gizmo`
+pub(crate) struct DebugGizmoPlugin;
+
+impl Plugin for DebugGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DrawnGizmos>();
+        app.add_systems(PostUpdate, (rebuild_gizmo, expire_gizmos).chain());
+        #[cfg(feature = "ui")]
+        app.add_systems(
+            PostUpdate,
+            position_labels.after(TransformSystems::Propagate),
+        );
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `draw_gizmo`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DrawGizmoRequest {
+    /// Shapes to draw
+    #[serde(default)]
+    shapes:     Vec<GizmoShapeRequest>,
+    /// Number of frames the shapes stay visible (None = 120)
+    #[serde(default)]
+    ttl_frames: Option<u32>,
+    /// Camera text labels are placed for (None = the highest-order active camera)
+    #[serde(default)]
+    camera:     Option<u64>,
+    /// Remove every previously drawn shape first
+    #[serde(default)]
+    clear:      bool,
+}
+
+/// One shape to draw, tagged by `type`
+#[derive(Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum GizmoShapeRequest {
+    /// Line segment between two world-space points
+    Line {
+        start: [f32; 3],
+        end:   [f32; 3],
+        /// sRGBA color with components in `0.0..=1.0`
+        #[serde(default)]
+        color: Option<[f32; 4]>,
+    },
+    /// Wireframe sphere
+    Sphere {
+        center: [f32; 3],
+        radius: f32,
+        /// sRGBA color with components in `0.0..=1.0`
+        #[serde(default)]
+        color:  Option<[f32; 4]>,
+    },
+    /// Box given by `min` and `max` corners, or an entity's `Aabb` in its world transform
+    Aabb {
+        #[serde(default)]
+        min:    Option<[f32; 3]>,
+        #[serde(default)]
+        max:    Option<[f32; 3]>,
+        #[serde(default)]
+        entity: Option<u64>,
+        /// sRGBA color with components in `0.0..=1.0`
+        #[serde(default)]
+        color:  Option<[f32; 4]>,
+    },
+    /// Screen-aligned text anchored at a world-space point (`ui` feature)
+    Text {
+        position: [f32; 3],
+        text:     String,
+        /// sRGBA color with components in `0.0..=1.0`
+        #[serde(default)]
+        color:    Option<[f32; 4]>,
+    },
+}
+
+/// Shapes currently drawn, and the entity holding their retained gizmo
+#[derive(Resource, Default)]
+struct DrawnGizmos {
+    shapes: Vec<DrawnShape>,
+    gizmo:  Option<Entity>,
+    /// Set when `shapes` changed and the retained gizmo must be rebuilt
+    dirty:  bool,
+}
+
+/// A shape with the frames it has left
+struct DrawnShape {
+    kind:        ShapeKind,
+    color:       Color,
+    frames_left: u32,
+}
+
+#[derive(Clone, Copy)]
+enum ShapeKind {
+    Line {
+        start: Vec3,
+        end:   Vec3,
+    },
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    /// Unit cube scaled, rotated, and placed by the transform
+    Cuboid(Transform),
+    /// UI text node kept over `position` as seen by `camera`
+    #[cfg(feature = "ui")]
+    Label {
+        position: Vec3,
+        camera:   Entity,
+        node:     Entity,
+    },
+}
+
+/// A validated shape, before any label entity is spawned
+enum PendingShape {
+    Drawn(ShapeKind),
+    #[cfg(feature = "ui")]
+    Label {
+        position: Vec3,
+        text:     String,
+        camera:   Entity,
+    },
+}
+
+/// Response structure for `draw_gizmo`
+#[derive(Serialize)]
+struct DrawGizmoResponse {
+    /// Number of shapes added by this request
+    shapes_drawn:  usize,
+    /// Number of shapes visible after this request, including earlier ones
+    active_shapes: usize,
+    ttl_frames:    u32,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `draw_gizmo` BRP method
+///
+/// Validates every shape before drawing any, so a bad request leaves the current gizmos alone.
+pub(crate) fn draw_gizmo_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: DrawGizmoRequest = parse_required(params)?;
+    let ttl_frames = request.ttl_frames.unwrap_or(DEFAULT_GIZMO_TTL_FRAMES);
+    if ttl_frames == 0 {
+        return Err(BrpExtrasError::InvalidParams.error("ttl_frames must be at least 1"));
+    }
+    if !world.contains_resource::<Assets<GizmoAsset>>() {
+        return Err(BrpExtrasError::Unsupported
+            .error("Gizmos are unavailable: the app does not include Bevy's `GizmoPlugin`"));
+    }
+
+    let pending = request
+        .shapes
+        .into_iter()
+        .enumerate()
+        .map(|(index, shape)| resolve_shape(world, index, shape, request.camera))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if request.clear {
+        clear_shapes(world);
+    }
+
+    let shapes_drawn = pending.len();
+    let shapes: Vec<DrawnShape> = pending
+        .into_iter()
+        .map(|(shape, color)| {
+            let kind = match shape {
+                PendingShape::Drawn(kind) => kind,
+                #[cfg(feature = "ui")]
+                PendingShape::Label {
+                    position,
+                    text,
+                    camera,
+                } => ShapeKind::Label {
+                    position,
+                    camera,
+                    node: spawn_label(world, text, color, camera),
+                },
+            };
+            DrawnShape {
+                kind,
+                color,
+                frames_left: ttl_frames,
+            }
+        })
+        .collect();
+
+    let mut drawn = world.resource_mut::<DrawnGizmos>();
+    drawn.dirty |= !shapes.is_empty();
+    drawn.shapes.extend(shapes);
+
+    serde_json::to_value(DrawGizmoResponse {
+        shapes_drawn,
+        active_shapes: drawn.shapes.len(),
+        ttl_frames,
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Replace the retained gizmo with one built from the current shapes
+fn rebuild_gizmo(
+    mut commands: Commands,
+    mut drawn: ResMut<DrawnGizmos>,
+    assets: Option<ResMut<Assets<GizmoAsset>>>,
+) {
+    let Some(mut assets) = assets else {
+        return;
+    };
+    if !drawn.dirty {
+        return;
+    }
+    drawn.dirty = false;
+
+    if let Some(gizmo) = drawn.gizmo.take() {
+        commands.entity(gizmo).despawn();
+    }
+    if drawn.shapes.is_empty() {
+        return;
+    }
+
+    let mut asset = GizmoAsset::new();
+    for shape in &drawn.shapes {
+        shape.draw(&mut asset);
+    }
+    let gizmo = commands
+        .spawn((
+            Name::new(GIZMO_ENTITY_NAME),
+            Gizmo {
+                handle: assets.add(asset),
+                // Draw over scene geometry so marked entities stay visible
+                depth_bias: -1.0,
+                ..default()
+            },
+        ))
+        .id();
+    drawn.gizmo = Some(gizmo);
+}
+
+/// Count down each shape's frames and drop the ones that ran out
+fn expire_gizmos(mut commands: Commands, mut drawn: ResMut<DrawnGizmos>) {
+    let before = drawn.shapes.len();
+    drawn.shapes.retain_mut(|shape| {
+        shape.frames_left = shape.frames_left.saturating_sub(1);
+        if shape.frames_left > 0 {
+            return true;
+        }
+        if let Some(node) = shape.kind.label_node() {
+            commands.entity(node).try_despawn();
+        }
+        false
+    });
+
+    if drawn.shapes.len() != before {
+        drawn.dirty = true;
+    }
+}
+
+/// Keep each text label over its world position, hiding it when the point is off camera
+#[cfg(feature = "ui")]
+fn position_labels(
+    drawn: Res<DrawnGizmos>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut labels: Query<(&mut Node, &mut Visibility)>,
+) {
+    for shape in &drawn.shapes {
+        let ShapeKind::Label {
+            position,
+            camera,
+            node,
+        } = shape.kind
+        else {
+            continue;
+        };
+        let Ok((mut label, mut visibility)) = labels.get_mut(node) else {
+            continue;
+        };
+
+        let screen = cameras
+            .get(camera)
+            .ok()
+            .and_then(|(camera, transform)| camera.world_to_viewport(transform, position).ok());
+        if let Some(screen) = screen {
+            label.left = Val::Px(screen.x);
+            label.top = Val::Px(screen.y);
+            *visibility = Visibility::Inherited;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+impl ShapeKind {
+    /// The UI node of a text label
+    const fn label_node(self) -> Option<Entity> {
+        match self {
+            #[cfg(feature = "ui")]
+            Self::Label { node, .. } => Some(node),
+            _ => None,
+        }
+    }
+}
+
+impl DrawnShape {
+    fn draw(&self, asset: &mut GizmoAsset) {
+        match self.kind {
+            ShapeKind::Line { start, end } => asset.line(start, end, self.color),
+            ShapeKind::Sphere { center, radius } => {
+                asset.sphere(Isometry3d::from_translation(center), radius, self.color);
+            },
+            ShapeKind::Cuboid(transform) => asset.cube(transform, self.color),
+            #[cfg(feature = "ui")]
+            ShapeKind::Label { .. } => {},
+        }
+    }
+}
+
+#[cfg_attr(
+    not(feature = "ui"),
+    expect(
+        unused_variables,
+        clippy::needless_pass_by_ref_mut,
+        reason = "`camera` and mutable world access only place text labels"
+    )
+)]
+fn resolve_shape(
+    world: &mut World,
+    index: usize,
+    shape: GizmoShapeRequest,
+    camera: Option<u64>,
+) -> Result<(PendingShape, Color), BrpError> {
+    let to_color = |color: Option<[f32; 4]>| {
+        color.map_or(DEFAULT_GIZMO_COLOR, |[red, green, blue, alpha]| {
+            Color::srgba(red, green, blue, alpha)
+        })
+    };
+
+    match shape {
+        GizmoShapeRequest::Line { start, end, color } => Ok((
+            PendingShape::Drawn(ShapeKind::Line {
+                start: Vec3::from_array(start),
+                end:   Vec3::from_array(end),
+            }),
+            to_color(color),
+        )),
+        GizmoShapeRequest::Sphere {
+            center,
+            radius,
+            color,
+        } => {
+            if !radius.is_finite() || radius <= 0.0 {
+                return Err(BrpExtrasError::InvalidParams.with_details(
+                    format!("Shape {index}: radius must be a finite, positive number"),
+                    json!({ "shape": index, "radius": radius }),
+                ));
+            }
+            Ok((
+                PendingShape::Drawn(ShapeKind::Sphere {
+                    center: Vec3::from_array(center),
+                    radius,
+                }),
+                to_color(color),
+            ))
+        },
+        GizmoShapeRequest::Aabb {
+            min,
+            max,
+            entity,
+            color,
+        } => {
+            let transform = match (min, max, entity) {
+                (Some(min), Some(max), None) => {
+                    let (min, max) = (Vec3::from_array(min), Vec3::from_array(max));
+                    if !min.cmple(max).all() {
+                        return Err(BrpExtrasError::InvalidParams.with_details(
+                            format!("Shape {index}: min must not be greater than max on any axis"),
+                            json!({ "shape": index, "min": min.to_array(), "max": max.to_array() }),
+                        ));
+                    }
+                    Transform::from_translation(min.midpoint(max)).with_scale(max - min)
+                },
+                (None, None, Some(entity)) => entity_box(world, entity)?,
+                _ => {
+                    return Err(BrpExtrasError::InvalidParams.error(format!(
+                        "Shape {index}: an aabb needs either `min` and `max` or `entity`"
+                    )));
+                },
+            };
+            Ok((
+                PendingShape::Drawn(ShapeKind::Cuboid(transform)),
+                to_color(color),
+            ))
+        },
+        #[cfg(feature = "ui")]
+        GizmoShapeRequest::Text {
+            position,
+            text,
+            color,
+        } => Ok((
+            PendingShape::Label {
+                position: Vec3::from_array(position),
+                text,
+                camera: label_camera(world, camera)?,
+            },
+            to_color(color),
+        )),
+        #[cfg(not(feature = "ui"))]
+        GizmoShapeRequest::Text { .. } => Err(BrpExtrasError::Unsupported.with_details(
+            format!("Shape {index}: text labels require the `ui` feature"),
+            json!({ "shape": index }),
+        )),
+    }
+}
+
+/// World-space box of an entity's `Aabb`, oriented by its `GlobalTransform`
+fn entity_box(world: &World, entity: u64) -> Result<Transform, BrpError> {
+    let id = Entity::try_from_bits(entity).filter(|id| world.get_entity(*id).is_ok());
+    let parts = id.and_then(|id| world.get::<Aabb>(id).zip(world.get::<GlobalTransform>(id)));
+    let Some((aabb, global_transform)) = parts else {
+        return Err(BrpExtrasError::EntityNotFound.with_details(
+            format!("Entity {entity} does not exist or has no Aabb and GlobalTransform"),
+            json!({ PARAM_ENTITY: entity }),
+        ));
+    };
+
+    let local = Transform::from_translation(aabb.center.into())
+        .with_scale(Vec3::from(aabb.half_extents) * 2.0);
+    Ok(global_transform.compute_transform() * local)
+}
+
+/// The requested camera, else the highest-order active camera
+#[cfg(feature = "ui")]
+fn label_camera(world: &mut World, camera: Option<u64>) -> Result<Entity, BrpError> {
+    if let Some(camera) = camera {
+        return projection::camera_entity(world, camera);
+    }
+    let mut query = world.query::<(Entity, &Camera)>();
+    query
+        .iter(world)
+        .filter(|(_, camera)| camera.is_active)
+        .max_by_key(|(entity, camera)| (camera.order, entity.to_bits()))
+        .map(|(entity, _)| entity)
+        .ok_or_else(|| {
+            BrpExtrasError::CameraUnavailable.error("No active camera to place text labels with")
+        })
+}
+
+/// Spawn a hidden text node; `position_labels` places and shows it
+#[cfg(feature = "ui")]
+fn spawn_label(world: &mut World, text: String, color: Color, camera: Entity) -> Entity {
+    world
+        .spawn((
+            Name::new(GIZMO_LABEL_ENTITY_NAME),
+            Text::new(text),
+            TextColor(color),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            UiTargetCamera(camera),
+            Visibility::Hidden,
+        ))
+        .id()
+}
+
+/// Drop every drawn shape and its label
+fn clear_shapes(world: &mut World) {
+    let mut drawn = world.resource_mut::<DrawnGizmos>();
+    let shapes = std::mem::take(&mut drawn.shapes);
+    drawn.dirty = true;
+
+    for node in shapes.iter().filter_map(|shape| shape.kind.label_node()) {
+        if let Ok(label) = world.get_entity_mut(node) {
+            label.despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::DebugGizmoPlugin;
+    use super::draw_gizmo_handler;
+    use crate::error::BrpExtrasError;
+
+    fn gizmo_count(app: &mut App) -> usize {
+        app.world_mut().query::<&Gizmo>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn shapes_are_drawn_for_their_ttl_and_then_removed() {
+        let mut app = App::new();
+        app.init_resource::<Assets<GizmoAsset>>();
+        app.add_plugins(DebugGizmoPlugin);
+
+        let response = draw_gizmo_handler(
+            In(Some(json!({
+                "ttl_frames": 2,
+                "shapes": [
+                    { "type": "line", "start": [0.0, 0.0, 0.0], "end": [1.0, 0.0, 0.0] },
+                    { "type": "sphere", "center": [0.0, 1.0, 0.0], "radius": 0.5 }
+                ]
+            }))),
+            app.world_mut(),
+        )
+        .expect("valid shapes should draw");
+        assert_eq!(response["shapes_drawn"], 2);
+
+        app.update();
+        assert_eq!(gizmo_count(&mut app), 1);
+        app.update();
+        assert_eq!(gizmo_count(&mut app), 1);
+        app.update();
+        assert_eq!(gizmo_count(&mut app), 0);
+    }
+
+    #[test]
+    fn an_invalid_shape_draws_nothing() {
+        let mut app = App::new();
+        app.init_resource::<Assets<GizmoAsset>>();
+        app.add_plugins(DebugGizmoPlugin);
+
+        let error = draw_gizmo_handler(
+            In(Some(json!({
+                "shapes": [
+                    { "type": "line", "start": [0.0, 0.0, 0.0], "end": [1.0, 0.0, 0.0] },
+                    { "type": "aabb", "min": [0.0, 0.0, 0.0] }
+                ]
+            }))),
+            app.world_mut(),
+        )
+        .expect_err("an aabb without max should be rejected");
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+
+        app.update();
+        assert_eq!(gizmo_count(&mut app), 0);
+    }
+}
@@ -0,0 +1,84 @@
+//! Headless detection for BRP extras
+//!
+//! CI runs often build the app without any window (`WindowPlugin { primary_window: None }` with
+//! a `ScheduleRunnerPlugin`), and usually without a GPU either. Methods that drive a window, move
+//! its cursor, or capture it check here first and fail with [`BrpExtrasError::Headless`], so a CI
+//! test can tell "this app is headless" apart from "that window does not exist" instead of waiting
+//! on a frame that is never presented. Screenshots also check for a render adapter, since without
+//! a GPU there is nothing to read a frame back from.
+
+use bevy::prelude::*;
+use bevy::render::error_handler::RenderErrorHandler;
+use bevy::render::renderer::RenderAdapter;
+use bevy_remote::BrpError;
+use serde_json::json;
+
+use crate::constants::HEADLESS_REASON_NO_RENDER_ADAPTER;
+use crate::constants::HEADLESS_REASON_NO_WINDOW;
+use crate::constants::RESPONSE_REASON_FIELD;
+use crate::error::BrpExtrasError;
+
+/// Fail when the app has no windows at all
+pub(crate) fn ensure_window(world: &World) -> Result<(), BrpError> {
+    let has_window = world
+        .try_query_filtered::<(), With<Window>>()
+        .is_some_and(|mut windows| windows.iter(world).next().is_some());
+    if has_window {
+        return Ok(());
+    }
+    Err(BrpExtrasError::Headless.with_details(
+        "Unsupported in headless mode: the app has no window",
+        json!({ RESPONSE_REASON_FIELD: HEADLESS_REASON_NO_WINDOW }),
+    ))
+}
+
+/// Fail when rendering found no GPU adapter, so no frame can be captured
+///
+/// `RenderPlugin` inserts `RenderErrorHandler` whether or not it finds an adapter, so apps that
+/// never added rendering are not checked.
+pub(crate) fn ensure_render_adapter(world: &World) -> Result<(), BrpError> {
+    if world.contains_resource::<RenderAdapter>()
+        || !world.contains_resource::<RenderErrorHandler>()
+    {
+        return Ok(());
+    }
+    Err(BrpExtrasError::Headless.with_details(
+        "Unsupported in headless mode: the app has no render adapter (GPU)",
+        json!({ RESPONSE_REASON_FIELD: HEADLESS_REASON_NO_RENDER_ADAPTER }),
+    ))
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::render::error_handler::RenderErrorHandler;
+    use bevy::window::Window;
+
+    use super::ensure_render_adapter;
+    use super::ensure_window;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn apps_without_windows_are_headless() {
+        let mut app = App::new();
+        let error = ensure_window(app.world()).expect_err("no window was spawned");
+        assert_eq!(error.code, BrpExtrasError::Headless.code());
+
+        app.world_mut().spawn(Window::default());
+        ensure_window(app.world()).expect("a window exists");
+    }
+
+    #[test]
+    fn apps_without_a_render_adapter_are_headless() {
+        let mut app = App::new();
+        ensure_render_adapter(app.world()).expect("apps without rendering are not checked");
+
+        app.init_resource::<RenderErrorHandler>();
+        let error = ensure_render_adapter(app.world()).expect_err("rendering found no adapter");
+        assert_eq!(error.code, BrpExtrasError::Headless.code());
+    }
+}
@@ -0,0 +1,236 @@
+//! Entity highlight handler for BRP extras
+//!
+//! `highlight_entity` inserts a [`Highlighted`] marker that outlines the entity with gizmo boxes
+//! every frame, so a human supervisor can see which entity the agent is inspecting. The outline
+//! follows the entity as it moves, and the marker removes itself when its timer runs out.
+
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::DEFAULT_HIGHLIGHT_DURATION_SECS;
+use crate::constants::HIGHLIGHT_MARKER_RADIUS;
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+
+/// Outline color used when the request does not give one
+const DEFAULT_HIGHLIGHT_COLOR: Color = Color::srgb(0.0, 1.0, 1.0);
+
+/// Plugin that draws and expires entity highlights
+pub(crate) struct HighlightPlugin;
+
+impl Plugin for HighlightPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                draw_highlights
+                    .after(TransformSystems::Propagate)
+                    .run_if(resource_exists::<GizmoConfigStore>),
+                expire_highlights.run_if(resource_exists::<Time>),
+            ),
+        );
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `highlight_entity`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct HighlightEntityRequest {
+    /// Entity to highlight
+    entity:        u64,
+    /// Seconds the highlight stays visible (None = 3)
+    #[serde(default)]
+    duration_secs: Option<f32>,
+    /// sRGBA outline color with components in `0.0..=1.0`
+    #[serde(default)]
+    color:         Option<[f32; 4]>,
+}
+
+/// Marks an entity outlined by `highlight_entity` until `timer` finishes
+#[derive(Component)]
+struct Highlighted {
+    timer: Timer,
+    color: Color,
+}
+
+/// Response structure for `highlight_entity`
+#[derive(Serialize)]
+struct HighlightEntityResponse {
+    entity:        u64,
+    duration_secs: f32,
+    /// Whether the entity was already highlighted and its timer was restarted
+    restarted:     bool,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `highlight_entity` BRP method
+///
+/// Highlighting an entity that is already highlighted restarts its timer with the new color.
+pub(crate) fn highlight_entity_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: HighlightEntityRequest = parse_required(params)?;
+    let duration_secs = request
+        .duration_secs
+        .unwrap_or(DEFAULT_HIGHLIGHT_DURATION_SECS);
+    if !duration_secs.is_finite() || duration_secs <= 0.0 {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("duration_secs must be a finite, positive number, got {duration_secs}"),
+            json!({ "duration_secs": duration_secs }),
+        ));
+    }
+
+    let mut entity = Entity::try_from_bits(request.entity)
+        .and_then(|entity| world.get_entity_mut(entity).ok())
+        .ok_or_else(|| {
+            BrpExtrasError::EntityNotFound.with_details(
+                format!("Entity {} does not exist", request.entity),
+                json!({ PARAM_ENTITY: request.entity }),
+            )
+        })?;
+
+    let restarted = entity.contains::<Highlighted>();
+    entity.insert(Highlighted {
+        timer: Timer::from_seconds(duration_secs, TimerMode::Once),
+        color: request
+            .color
+            .map_or(DEFAULT_HIGHLIGHT_COLOR, |[red, green, blue, alpha]| {
+                Color::srgba(red, green, blue, alpha)
+            }),
+    });
+
+    serde_json::to_value(HighlightEntityResponse {
+        entity: request.entity,
+        duration_secs,
+        restarted,
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Outline each highlighted entity with the `Aabb`s of itself and its descendants, or mark its
+/// position with a small sphere when none of them has one
+fn draw_highlights(
+    mut gizmos: Gizmos,
+    highlighted: Query<(Entity, &Highlighted, &GlobalTransform)>,
+    bounds: Query<(&Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
+) {
+    for (entity, highlight, transform) in &highlighted {
+        let mut outlined = false;
+        for (aabb, global_transform) in std::iter::once(entity)
+            .chain(children.iter_descendants(entity))
+            .filter_map(|entity| bounds.get(entity).ok())
+        {
+            let local = Transform::from_translation(aabb.center.into())
+                .with_scale(Vec3::from(aabb.half_extents) * 2.0);
+            gizmos.cube(
+                global_transform.compute_transform() * local,
+                highlight.color,
+            );
+            outlined = true;
+        }
+
+        if !outlined {
+            gizmos.sphere(
+                Isometry3d::from_translation(transform.translation()),
+                HIGHLIGHT_MARKER_RADIUS,
+                highlight.color,
+            );
+        }
+    }
+}
+
+/// Remove highlights whose timer finished
+fn expire_highlights(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut highlighted: Query<(Entity, &mut Highlighted)>,
+) {
+    for (entity, mut highlight) in &mut highlighted {
+        if highlight.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).try_remove::<Highlighted>();
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::app::App;
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::HighlightPlugin;
+    use super::Highlighted;
+    use super::highlight_entity_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn highlight_is_removed_when_its_timer_finishes() {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_plugins(HighlightPlugin);
+        let entity = app.world_mut().spawn(GlobalTransform::IDENTITY).id();
+
+        let response = highlight_entity_handler(
+            In(Some(
+                json!({ "entity": entity.to_bits(), "duration_secs": 1.0 }),
+            )),
+            app.world_mut(),
+        )
+        .expect("an existing entity should be highlighted");
+        assert_eq!(response["restarted"], false);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        app.update();
+        assert!(app.world().get::<Highlighted>(entity).is_some());
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(600));
+        app.update();
+        assert!(app.world().get::<Highlighted>(entity).is_none());
+    }
+
+    #[test]
+    fn a_missing_entity_is_rejected() {
+        let mut app = App::new();
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut().entity_mut(entity).despawn();
+
+        let error = highlight_entity_handler(
+            In(Some(json!({ "entity": entity.to_bits() }))),
+            app.world_mut(),
+        )
+        .expect_err("a despawned entity cannot be highlighted");
+        assert_eq!(error.code, BrpExtrasError::EntityNotFound.code());
+    }
+}
@@ -0,0 +1,278 @@
+//! Recording of real keyboard and mouse input as a replayable input script
+//!
+//! `start_input_recording` begins capturing input messages; `stop_input_recording` returns them
+//! as `run_input_script` events, so a manual repro can be recorded once and replayed through BRP.
+//!
+//! Input is recorded at the granularity the script format supports:
+//! - a key press and its release become one `key` event on the press frame, held for the measured
+//!   duration
+//! - a mouse button press becomes a `click` event
+//! - cursor movement becomes at most one absolute `move_mouse` event per frame
+//! - each wheel message becomes a `scroll` event
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::time::Real;
+use bevy_remote::BrpResult;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::MAX_INPUT_SCRIPT_EVENTS;
+use crate::error::BrpExtrasError;
+use crate::input_script::ScriptEvent;
+use crate::input_script::ScriptedInputKind;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Response structure for `start_input_recording`
+#[derive(Serialize)]
+struct StartInputRecordingResponse {
+    /// Always `true` once the request succeeds
+    recording:        bool,
+    /// Events dropped from a recording that was already in progress
+    discarded_events: usize,
+}
+
+/// Response structure for `stop_input_recording`
+#[derive(Serialize)]
+struct StopInputRecordingResponse {
+    /// Recorded events in `run_input_script` format, sorted by frame
+    events:          Vec<ScriptEvent>,
+    /// Number of recorded events
+    events_recorded: usize,
+    /// Frames elapsed between start and stop
+    duration_frames: u32,
+    /// Whether recording stopped capturing at the script event limit
+    truncated:       bool,
+}
+
+/// A key that is down, waiting for its release to become a `key` event
+struct HeldKey {
+    frame:      u32,
+    pressed_at: Duration,
+}
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// State of the recording in progress, if any
+#[derive(Resource, Default)]
+struct InputRecording {
+    active:    bool,
+    /// Frames elapsed since recording started
+    frame:     u32,
+    events:    Vec<ScriptEvent>,
+    held_keys: HashMap<String, HeldKey>,
+    truncated: bool,
+}
+
+impl InputRecording {
+    fn push(&mut self, frame: u32, kind: ScriptedInputKind, params: Value) {
+        if self.events.len() >= MAX_INPUT_SCRIPT_EVENTS {
+            self.truncated = true;
+            return;
+        }
+        let Value::Object(params) = params else {
+            return;
+        };
+        self.events.push(ScriptEvent {
+            frame,
+            kind,
+            params,
+        });
+    }
+
+    fn push_key(&mut self, key: String, held: &HeldKey, released_at: Duration) {
+        let duration_ms = u32::try_from(released_at.saturating_sub(held.pressed_at).as_millis())
+            .unwrap_or(u32::MAX);
+        self.push(
+            held.frame,
+            ScriptedInputKind::Key,
+            json!({ "keys": [key], "duration_ms": duration_ms.max(1) }),
+        );
+    }
+}
+
+pub(super) struct InputRecordingPlugin;
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecording>();
+        app.add_systems(Update, record_input);
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `start_input_recording` BRP method
+///
+/// Restarts the recording if one is already in progress.
+pub(crate) fn start_input_recording_handler(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let mut recording = world.resource_mut::<InputRecording>();
+    let discarded_events = if recording.active {
+        recording.events.len()
+    } else {
+        0
+    };
+    *recording = InputRecording {
+        active: true,
+        ..InputRecording::default()
+    };
+
+    serialize_response(StartInputRecordingResponse {
+        recording: true,
+        discarded_events,
+    })
+}
+
+/// Handler for `stop_input_recording` BRP method
+///
+/// Keys still held when recording stops are recorded as released now.
+pub(crate) fn stop_input_recording_handler(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let now = world
+        .get_resource::<Time<Real>>()
+        .map_or(Duration::ZERO, Time::elapsed);
+    let mut recording = std::mem::take(&mut *world.resource_mut::<InputRecording>());
+    if !recording.active {
+        return Err(BrpExtrasError::InvalidState.error("No input recording in progress"));
+    }
+
+    for (key, held) in std::mem::take(&mut recording.held_keys) {
+        recording.push_key(key, &held, now);
+    }
+    // Stable sort keeps same-frame events in the order they happened
+    recording.events.sort_by_key(|event| event.frame);
+
+    serialize_response(StopInputRecordingResponse {
+        events_recorded: recording.events.len(),
+        duration_frames: recording.frame,
+        truncated:       recording.truncated,
+        events:          recording.events,
+    })
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Convert this frame's input messages into script events
+fn record_input(
+    mut recording: ResMut<InputRecording>,
+    time: Res<Time<Real>>,
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut button_events: MessageReader<MouseButtonInput>,
+    mut cursor_events: MessageReader<CursorMoved>,
+    mut wheel_events: MessageReader<MouseWheel>,
+) {
+    if !recording.active {
+        keyboard_events.clear();
+        button_events.clear();
+        cursor_events.clear();
+        wheel_events.clear();
+        return;
+    }
+
+    let frame = recording.frame;
+    let now = time.elapsed();
+
+    for event in keyboard_events.read() {
+        if matches!(event.key_code, KeyCode::Unidentified(_)) {
+            continue;
+        }
+        let key = format!("{:?}", event.key_code);
+        match event.state {
+            ButtonState::Pressed if !event.repeat => {
+                recording.held_keys.entry(key).or_insert(HeldKey {
+                    frame,
+                    pressed_at: now,
+                });
+            },
+            ButtonState::Released => {
+                if let Some(held) = recording.held_keys.remove(&key) {
+                    recording.push_key(key, &held, now);
+                }
+            },
+            ButtonState::Pressed => {},
+        }
+    }
+
+    for event in button_events.read() {
+        if event.state == ButtonState::Pressed {
+            recording.push(
+                frame,
+                ScriptedInputKind::Click,
+                json!({ "button": event.button }),
+            );
+        }
+    }
+
+    if let Some(event) = cursor_events.read().last() {
+        recording.push(
+            frame,
+            ScriptedInputKind::MoveMouse,
+            json!({ "position": [event.position.x, event.position.y] }),
+        );
+    }
+
+    for event in wheel_events.read() {
+        recording.push(
+            frame,
+            ScriptedInputKind::Scroll,
+            json!({ "x": event.x, "y": event.y, "unit": event.unit }),
+        );
+    }
+
+    recording.frame = frame.saturating_add(1);
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::In;
+
+    use super::InputRecording;
+    use super::start_input_recording_handler;
+    use super::stop_input_recording_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn stop_returns_recorded_events_and_requires_start() {
+        let mut app = App::new();
+        app.init_resource::<InputRecording>();
+
+        let error = stop_input_recording_handler(In(None), app.world_mut())
+            .expect_err("stop without start should be rejected");
+        assert_eq!(error.code, BrpExtrasError::InvalidState.code());
+
+        start_input_recording_handler(In(None), app.world_mut()).expect("recording should start");
+        let response =
+            stop_input_recording_handler(In(None), app.world_mut()).expect("recording should stop");
+
+        assert_eq!(response["events_recorded"], 0);
+        assert_eq!(response["truncated"], false);
+        assert!(!app.world().resource::<InputRecording>().active);
+    }
+}
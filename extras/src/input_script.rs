@@ -0,0 +1,249 @@
+//! Frame-accurate playback of scripted keyboard and mouse input
+//!
+//! `run_input_script` queues a list of input events, each tagged with the frame (relative to the
+//! start of playback) on which it fires. A scheduler system drains due events every frame and
+//! dispatches them through the existing keyboard and mouse handlers, so scripted input gets the
+//! same validation, timed releases, and window targeting as the individual methods.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::MAX_INPUT_SCRIPT_EVENTS;
+use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
+use crate::keyboard;
+use crate::mouse;
+use crate::window_event;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `run_input_script`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct RunInputScriptRequest {
+    /// Events to play back; they need not be sorted
+    events: Vec<ScriptEvent>,
+}
+
+/// One scripted input event
+///
+/// Also the format produced by input recording, so a recording can be played back as-is.
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub(crate) struct ScriptEvent {
+    /// Frame offset from the start of playback on which the event fires
+    pub(crate) frame:  u32,
+    /// Which input to send
+    #[serde(rename = "type")]
+    pub(crate) kind:   ScriptedInputKind,
+    /// Parameters passed to the matching keyboard or mouse handler
+    #[serde(flatten)]
+    pub(crate) params: Map<String, Value>,
+}
+
+/// Input kinds a script can contain, each backed by an existing handler
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScriptedInputKind {
+    /// `send_keys` parameters: `keys` or `chord`, optional `duration_ms`
+    Key,
+    /// `move_mouse` parameters: `position` or `delta`, optional `window`
+    MoveMouse,
+    /// `click_mouse` parameters: `button`, optional `window`
+    Click,
+    /// `scroll_mouse` parameters: `x`, `y`, `unit`, optional `window`
+    Scroll,
+}
+
+/// Response structure for `run_input_script`
+#[derive(Serialize)]
+struct RunInputScriptResponse {
+    /// Number of events queued
+    events_scheduled: usize,
+    /// Frame offset of the last event
+    duration_frames:  u32,
+    /// Number of events dropped from a script that was still playing
+    replaced_events:  usize,
+}
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// Scheduler state for the script currently playing
+#[derive(Resource, Default)]
+struct InputScriptPlayback {
+    /// Events not yet dispatched, sorted by frame
+    pending: VecDeque<ScriptEvent>,
+    /// Frames elapsed since playback started
+    frame:   u32,
+}
+
+pub(super) struct InputScriptPlugin;
+
+impl Plugin for InputScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputScriptPlayback>();
+        app.add_systems(Update, play_input_script);
+    }
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `run_input_script` BRP method
+///
+/// Replaces any script that is still playing. Playback starts on the next frame (frame 0).
+pub(crate) fn run_input_script_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let params = params
+        .ok_or_else(|| BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE))?;
+    let mut request: RunInputScriptRequest = serde_json::from_value(params)
+        .map_err(|e| BrpExtrasError::InvalidParams.error(format!("Invalid input script: {e}")))?;
+
+    if request.events.is_empty() {
+        return Err(BrpExtrasError::InvalidParams.error("Input script has no events"));
+    }
+    if request.events.len() > MAX_INPUT_SCRIPT_EVENTS {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "Input script has {} events, more than the maximum of {MAX_INPUT_SCRIPT_EVENTS}",
+                request.events.len()
+            ),
+            json!({
+                "event_count": request.events.len(),
+                "max_event_count": MAX_INPUT_SCRIPT_EVENTS,
+            }),
+        ));
+    }
+
+    // Stable sort keeps same-frame events in script order
+    request.events.sort_by_key(|event| event.frame);
+    let events_scheduled = request.events.len();
+    let duration_frames = request.events.last().map_or(0, |event| event.frame);
+
+    let mut playback = world.resource_mut::<InputScriptPlayback>();
+    let replaced_events = playback.pending.len();
+    playback.pending = request.events.into();
+    playback.frame = 0;
+
+    Ok(json!(RunInputScriptResponse {
+        events_scheduled,
+        duration_frames,
+        replaced_events,
+    }))
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Dispatch every event due on the current playback frame
+fn play_input_script(world: &mut World) {
+    let due = {
+        let mut playback = world.resource_mut::<InputScriptPlayback>();
+        if playback.pending.is_empty() {
+            return;
+        }
+        let frame = playback.frame;
+        let mut due = Vec::new();
+        while playback
+            .pending
+            .front()
+            .is_some_and(|event| event.frame <= frame)
+        {
+            due.extend(playback.pending.pop_front());
+        }
+        playback.frame = frame.saturating_add(1);
+        due
+    };
+
+    for event in due {
+        let (frame, kind) = (event.frame, event.kind);
+        if let Err(error) = dispatch(world, event) {
+            warn!(
+                "Input script event {kind:?} at frame {frame} failed: {}",
+                error.message
+            );
+        }
+    }
+
+    // Playback counts frames, so keep them coming in reactive update modes
+    if !world.resource::<InputScriptPlayback>().pending.is_empty() {
+        window_event::request_redraw(world);
+    }
+}
+
+fn dispatch(world: &mut World, event: ScriptEvent) -> BrpResult {
+    let params = In(Some(Value::Object(event.params)));
+    match event.kind {
+        ScriptedInputKind::Key => keyboard::send_keys_handler(params, world),
+        ScriptedInputKind::MoveMouse => mouse::move_mouse_handler(params, world),
+        ScriptedInputKind::Click => mouse::click_mouse_handler(params, world),
+        ScriptedInputKind::Scroll => mouse::scroll_mouse_handler(params, world),
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::In;
+    use serde_json::json;
+
+    use super::InputScriptPlayback;
+    use super::run_input_script_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn script_events_are_queued_in_frame_order() {
+        let mut app = App::new();
+        app.init_resource::<InputScriptPlayback>();
+
+        let params = json!({
+            "events": [
+                { "frame": 10, "type": "click", "button": "Left" },
+                { "frame": 0, "type": "move_mouse", "position": [100.0, 200.0] },
+                { "frame": 10, "type": "key", "keys": ["Space"] }
+            ]
+        });
+        let response = run_input_script_handler(In(Some(params)), app.world_mut())
+            .expect("script should be accepted");
+
+        assert_eq!(response["events_scheduled"], 3);
+        assert_eq!(response["duration_frames"], 10);
+        let frames: Vec<u32> = app
+            .world()
+            .resource::<InputScriptPlayback>()
+            .pending
+            .iter()
+            .map(|event| event.frame)
+            .collect();
+        assert_eq!(frames, vec![0, 10, 10]);
+    }
+
+    #[test]
+    fn empty_script_is_rejected() {
+        let mut app = App::new();
+        app.init_resource::<InputScriptPlayback>();
+
+        let error = run_input_script_handler(In(Some(json!({ "events": [] }))), app.world_mut())
+            .expect_err("empty script should be rejected");
+
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+    }
+}
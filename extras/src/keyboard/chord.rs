@@ -0,0 +1,144 @@
+//! Chord parsing: turns shortcut strings like `"Ctrl+Shift+P"` into key codes.
+
+use std::str::FromStr;
+
+use bevy_remote::BrpError;
+use serde_json::json;
+use strum::IntoEnumIterator;
+
+use super::key_code::KeyCodeWrapper;
+use super::typing;
+use crate::error::BrpExtrasError;
+
+/// Modifier that `CmdOrCtrl` resolves to: Command on macOS, Control elsewhere
+#[cfg(target_os = "macos")]
+const PRIMARY_MODIFIER: KeyCodeWrapper = KeyCodeWrapper::SuperLeft;
+#[cfg(not(target_os = "macos"))]
+const PRIMARY_MODIFIER: KeyCodeWrapper = KeyCodeWrapper::ControlLeft;
+
+/// A parsed chord, ready to press
+#[derive(Debug)]
+pub(super) struct Chord {
+    /// Modifiers in the order written, followed by the key they modify
+    pub(super) keys: Vec<KeyCodeWrapper>,
+    /// Text the chord types, `None` when Control, Alt, or Super turn it into a shortcut
+    pub(super) text: Option<char>,
+}
+
+/// Parse a chord such as `"Ctrl+Shift+P"`, `"Cmd+S"`, or `"Alt+F4"`.
+///
+/// Modifiers are matched case-insensitively (`Ctrl`/`Control`, `Shift`, `Alt`/`Option`,
+/// `Cmd`/`Command`/`Super`/`Meta`/`Win`, and `CmdOrCtrl`). The final key is a single character, a
+/// common alias (`Esc`, `Return`, `Up`, `PgDn`, ...), or any key code name. A trailing `++` means
+/// the `+` key.
+pub(super) fn parse_chord(chord: &str) -> Result<Chord, BrpError> {
+    let (modifiers, key) = chord.strip_suffix("++").map_or_else(
+        || chord.rsplit_once('+').unwrap_or(("", chord)),
+        |modifiers| (modifiers, "+"),
+    );
+
+    let mut keys = Vec::new();
+    if !modifiers.is_empty() {
+        for name in modifiers.split('+') {
+            let modifier = parse_modifier(name.trim()).ok_or_else(|| {
+                chord_error(chord, &format!("'{}' is not a modifier", name.trim()))
+            })?;
+            push_unique(&mut keys, modifier);
+        }
+    }
+
+    let key = key.trim();
+    let key_codes =
+        parse_key(key).ok_or_else(|| chord_error(chord, &format!("'{key}' is not a key")))?;
+    for key_code in key_codes {
+        push_unique(&mut keys, key_code);
+    }
+
+    let text = if keys.iter().any(|key| is_command_modifier(*key)) {
+        None
+    } else {
+        typed_char(key, &keys)
+    };
+
+    Ok(Chord { keys, text })
+}
+
+fn parse_modifier(name: &str) -> Option<KeyCodeWrapper> {
+    match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Some(KeyCodeWrapper::ControlLeft),
+        "shift" => Some(KeyCodeWrapper::ShiftLeft),
+        "alt" | "option" | "opt" => Some(KeyCodeWrapper::AltLeft),
+        "cmd" | "command" | "super" | "meta" | "win" | "windows" => Some(KeyCodeWrapper::SuperLeft),
+        "cmdorctrl" | "mod" => Some(PRIMARY_MODIFIER),
+        _ => None,
+    }
+}
+
+/// Key codes for the final key of a chord; shifted symbols such as `?` include `ShiftLeft`
+fn parse_key(name: &str) -> Option<Vec<KeyCodeWrapper>> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return if c.is_ascii_alphabetic() {
+            KeyCodeWrapper::from_str(&format!("Key{}", c.to_ascii_uppercase()))
+                .ok()
+                .map(|key| vec![key])
+        } else {
+            typing::char_to_keys(c)
+        };
+    }
+
+    let alias = match name.to_ascii_lowercase().as_str() {
+        "esc" => Some(KeyCodeWrapper::Escape),
+        "return" => Some(KeyCodeWrapper::Enter),
+        "del" => Some(KeyCodeWrapper::Delete),
+        "ins" => Some(KeyCodeWrapper::Insert),
+        "up" => Some(KeyCodeWrapper::ArrowUp),
+        "down" => Some(KeyCodeWrapper::ArrowDown),
+        "left" => Some(KeyCodeWrapper::ArrowLeft),
+        "right" => Some(KeyCodeWrapper::ArrowRight),
+        "pgup" => Some(KeyCodeWrapper::PageUp),
+        "pgdn" | "pgdown" => Some(KeyCodeWrapper::PageDown),
+        _ => None,
+    };
+    alias
+        .or_else(|| KeyCodeWrapper::iter().find(|key| key.to_string().eq_ignore_ascii_case(name)))
+        .or_else(|| parse_modifier(name))
+        .map(|key| vec![key])
+}
+
+/// Character the chord's key types, uppercased for letters when Shift is held
+fn typed_char(key: &str, keys: &[KeyCodeWrapper]) -> Option<char> {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if keys.contains(&KeyCodeWrapper::ShiftLeft) => {
+            Some(c.to_ascii_uppercase())
+        },
+        (Some(c), None) => Some(c.to_ascii_lowercase()),
+        _ => keys.last().and_then(|key| key.to_char()),
+    }
+}
+
+const fn is_command_modifier(key: KeyCodeWrapper) -> bool {
+    matches!(
+        key,
+        KeyCodeWrapper::ControlLeft
+            | KeyCodeWrapper::ControlRight
+            | KeyCodeWrapper::AltLeft
+            | KeyCodeWrapper::AltRight
+            | KeyCodeWrapper::SuperLeft
+            | KeyCodeWrapper::SuperRight
+    )
+}
+
+fn push_unique(keys: &mut Vec<KeyCodeWrapper>, key: KeyCodeWrapper) {
+    if !keys.contains(&key) {
+        keys.push(key);
+    }
+}
+
+fn chord_error(chord: &str, reason: &str) -> BrpError {
+    BrpExtrasError::InvalidParams.with_details(
+        format!("Invalid chord '{chord}': {reason}"),
+        json!({ "chord": chord }),
+    )
+}
@@ -5,3 +5,5 @@
 pub(super) const DEFAULT_KEY_DURATION_MS: u32 = 100;
 /// Maximum duration for holding keys in milliseconds (1 minute)
 pub(super) const MAX_KEY_DURATION_MS: u32 = 60_000;
+/// Default time after which keys held by `press_keys` are released automatically
+pub(super) const DEFAULT_AUTO_RELEASE_MS: u32 = 10_000;
@@ -0,0 +1,277 @@
+//! Press-keys and release-keys handlers: hold keys down across requests.
+//!
+//! `send_keys` releases everything it presses within one call. `press_keys` leaves its keys down
+//! in [`HeldKeys`] until `release_keys` lifts them, so a client can hold W while clicking
+//! elsewhere. Every held key carries an auto-release timer so a client that disconnects mid-hold
+//! cannot leave the app with a stuck key.
+
+use std::time::Duration;
+
+use bevy::ecs::message::Messages;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::prelude::*;
+use bevy::window::RequestRedraw;
+use bevy::window::WindowEvent;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::constants::DEFAULT_AUTO_RELEASE_MS;
+use super::constants::MAX_KEY_DURATION_MS;
+use super::events;
+use super::key_code::KeyCodeWrapper;
+use super::keys;
+use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
+use crate::window_event;
+
+/// Keys pressed by `press_keys` and not yet released, in press order
+#[derive(Resource, Default)]
+pub(super) struct HeldKeys {
+    keys: Vec<HeldKey>,
+}
+
+/// One held key and the timer that releases it if the client never does
+struct HeldKey {
+    key:          KeyCodeWrapper,
+    auto_release: Timer,
+}
+
+impl HeldKeys {
+    fn names(&self) -> Vec<String> { self.keys.iter().map(|held| held.key.to_string()).collect() }
+}
+
+/// Request structure for `press_keys`
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct PressKeysRequest {
+    /// Array of key codes to press and hold
+    #[serde(default)]
+    keys:            Option<Vec<String>>,
+    /// Shortcut to hold instead of `keys`, e.g. `"Shift+W"`
+    #[serde(default)]
+    chord:           Option<String>,
+    /// Milliseconds after which the keys are released if `release_keys` never is called
+    #[serde(default = "default_auto_release")]
+    auto_release_ms: u32,
+}
+
+const fn default_auto_release() -> u32 { DEFAULT_AUTO_RELEASE_MS }
+
+/// Request structure for `release_keys`
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct ReleaseKeysRequest {
+    /// Key codes to release (`keys` and `chord` both omitted = release every held key)
+    #[serde(default)]
+    keys:  Option<Vec<String>>,
+    /// Shortcut to release instead of `keys`
+    #[serde(default)]
+    chord: Option<String>,
+}
+
+/// Response structure for `press_keys`
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct PressKeysResponse {
+    /// Whether the operation was successful
+    pub(super) success:         bool,
+    /// Keys that went down with this call, excluding keys that were already held
+    pub(super) keys_pressed:    Vec<String>,
+    /// Every key held after this call, in press order
+    pub(super) held:            Vec<String>,
+    /// Milliseconds until the keys of this call are released automatically
+    pub(super) auto_release_ms: u32,
+}
+
+/// Response structure for `release_keys`
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct ReleaseKeysResponse {
+    /// Whether the operation was successful
+    pub(super) success:       bool,
+    /// Keys that were released, in release order
+    pub(super) keys_released: Vec<String>,
+    /// Requested keys that were not held
+    pub(super) not_held:      Vec<String>,
+    /// Every key still held after this call
+    pub(super) held:          Vec<String>,
+}
+
+/// Handler for `press_keys` requests
+///
+/// Presses keys and keeps them down until `release_keys` or their auto-release timer. Pressing a
+/// key that is already held restarts its timer without sending another press.
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or invalid
+/// - Any key code or the chord is invalid
+/// - `auto_release_ms` is zero or exceeds the one-minute maximum
+pub(crate) fn press_keys_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: PressKeysRequest = if let Some(params) = params {
+        serde_json::from_value(params).map_err(|e| {
+            BrpExtrasError::InvalidParams.error(format!("Invalid request format: {e}"))
+        })?
+    } else {
+        return Err(BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE));
+    };
+
+    if request.auto_release_ms == 0 || request.auto_release_ms > MAX_KEY_DURATION_MS {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "auto_release_ms must be between 1 and {MAX_KEY_DURATION_MS} (1 minute), got {}",
+                request.auto_release_ms
+            ),
+            json!({
+                "auto_release_ms": request.auto_release_ms,
+                "max_auto_release_ms": MAX_KEY_DURATION_MS,
+            }),
+        ));
+    }
+
+    let (wrappers, press_events) =
+        keys::resolve_keys(request.keys.as_deref(), request.chord.as_deref())?;
+    let timer = Timer::new(
+        Duration::from_millis(u64::from(request.auto_release_ms)),
+        TimerMode::Once,
+    );
+
+    let mut held = world.get_resource_or_init::<HeldKeys>();
+    let mut newly_pressed = Vec::new();
+    for key in wrappers {
+        if let Some(existing) = held.keys.iter_mut().find(|held_key| held_key.key == key) {
+            existing.auto_release = timer.clone();
+        } else {
+            held.keys.push(HeldKey {
+                key,
+                auto_release: timer.clone(),
+            });
+            newly_pressed.push(key);
+        }
+    }
+    let held_names = held.names();
+
+    for event in press_events {
+        let newly = newly_pressed
+            .iter()
+            .any(|key| key.to_key_code() == event.key_code);
+        if newly {
+            window_event::write_input_event(world, event);
+        }
+    }
+
+    Ok(json!(PressKeysResponse {
+        success:         true,
+        keys_pressed:    newly_pressed.iter().map(ToString::to_string).collect(),
+        held:            held_names,
+        auto_release_ms: request.auto_release_ms,
+    }))
+}
+
+/// Handler for `release_keys` requests
+///
+/// Releases the given held keys, or every held key when none are given, in reverse press order.
+///
+/// # Errors
+///
+/// Returns `BrpError` if the request format is invalid, both `keys` and `chord` are given, or any
+/// key code or the chord is invalid.
+pub(crate) fn release_keys_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: ReleaseKeysRequest = params
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| BrpExtrasError::InvalidParams.error(format!("Invalid request format: {e}")))?
+        .unwrap_or_default();
+
+    let requested = if request.keys.is_none() && request.chord.is_none() {
+        None
+    } else {
+        Some(keys::resolve_keys(request.keys.as_deref(), request.chord.as_deref())?.0)
+    };
+
+    let mut held = world.get_resource_or_init::<HeldKeys>();
+    let (to_release, still_held): (Vec<HeldKey>, Vec<HeldKey>) = std::mem::take(&mut held.keys)
+        .into_iter()
+        .partition(|held_key| {
+            requested
+                .as_ref()
+                .is_none_or(|requested| requested.contains(&held_key.key))
+        });
+    held.keys = still_held;
+    let released: Vec<KeyCodeWrapper> = to_release
+        .iter()
+        .rev()
+        .map(|held_key| held_key.key)
+        .collect();
+    let not_held: Vec<String> = requested
+        .unwrap_or_default()
+        .iter()
+        .filter(|key| !released.contains(key))
+        .map(ToString::to_string)
+        .collect();
+    let held_names = held.names();
+
+    for event in events::create_keyboard_events(&released, ButtonState::Released) {
+        window_event::write_input_event(world, event);
+    }
+
+    Ok(json!(ReleaseKeysResponse {
+        success: true,
+        keys_released: released.iter().map(ToString::to_string).collect(),
+        not_held,
+        held: held_names,
+    }))
+}
+
+/// System that releases held keys whose auto-release timer ran out
+pub(super) fn process_held_key_auto_release(
+    time: Res<Time<Real>>,
+    mut held: ResMut<HeldKeys>,
+    mut keyboard_events: MessageWriter<KeyboardInput>,
+    mut window_events: MessageWriter<WindowEvent>,
+) {
+    if held.keys.is_empty() {
+        return;
+    }
+
+    let mut expired = Vec::new();
+    held.keys.retain_mut(|held_key| {
+        held_key.auto_release.tick(time.delta());
+        if held_key.auto_release.is_finished() {
+            expired.push(held_key.key);
+        }
+        !held_key.auto_release.is_finished()
+    });
+    if expired.is_empty() {
+        return;
+    }
+
+    warn!(
+        "Auto-releasing held keys without a release_keys call: {}",
+        expired
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let release_events = events::create_keyboard_events(&expired, ButtonState::Released);
+    for event in release_events.into_iter().rev() {
+        window_events.write(WindowEvent::from(event.clone()));
+        keyboard_events.write(event);
+    }
+}
+
+/// Request another frame while keys are held, so their auto-release timers keep running under
+/// reactive update modes.
+pub(super) fn request_redraw_while_held(
+    held: Res<HeldKeys>,
+    redraw: Option<ResMut<Messages<RequestRedraw>>>,
+) {
+    if let Some(mut redraw) = redraw
+        && !held.keys.is_empty()
+    {
+        redraw.write(RequestRedraw);
+    }
+}
@@ -0,0 +1,231 @@
+//! Send-IME handler: input method composition events via BRP.
+//!
+//! `type_text` only synthesizes key codes, which cannot produce CJK text. Apps that read Bevy's
+//! `Ime` messages receive these events exactly as winit would write them: optional `enabled`,
+//! any number of `preedit` updates while composing, then a `commit` with the final text.
+
+use bevy::prelude::*;
+use bevy::window::Ime;
+use bevy::window::PrimaryWindow;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
+use crate::window_event;
+
+/// One IME event, in the order the input method would report it
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+enum ImeEventRequest {
+    /// The input method was enabled for the window
+    Enabled,
+    /// Composition text changed; an empty `value` clears the preedit
+    Preedit {
+        /// Text being composed
+        value:  String,
+        /// Byte range of the cursor or selection within `value` (None = hide the cursor)
+        #[serde(default)]
+        cursor: Option<(usize, usize)>,
+    },
+    /// Composition finished and `value` should be inserted
+    Commit {
+        /// Text to insert
+        value: String,
+    },
+    /// The input method was disabled for the window
+    Disabled,
+}
+
+/// Request structure for `send_ime`
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SendImeRequest {
+    /// IME events to write, in order, during the current frame
+    events: Vec<ImeEventRequest>,
+    /// Target window entity (None = primary window)
+    #[serde(default)]
+    window: Option<u64>,
+}
+
+/// Response structure for `send_ime`
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SendImeResponse {
+    /// Whether the operation was successful
+    pub(super) success:     bool,
+    /// Number of IME events written
+    pub(super) events_sent: usize,
+    /// Concatenated text of every `commit` event
+    pub(super) committed:   String,
+}
+
+impl ImeEventRequest {
+    fn into_ime(self, window: Entity) -> Ime {
+        match self {
+            Self::Enabled => Ime::Enabled { window },
+            Self::Preedit { value, cursor } => Ime::Preedit {
+                window,
+                value,
+                cursor,
+            },
+            Self::Commit { value } => Ime::Commit { window, value },
+            Self::Disabled => Ime::Disabled { window },
+        }
+    }
+}
+
+/// Validate that each preedit cursor lies on character boundaries within its text
+fn validate_cursors(events: &[ImeEventRequest]) -> Result<(), BrpError> {
+    for (index, event) in events.iter().enumerate() {
+        if let ImeEventRequest::Preedit {
+            value,
+            cursor: Some((start, end)),
+        } = event
+            && (start > end || !value.is_char_boundary(*start) || !value.is_char_boundary(*end))
+        {
+            return Err(BrpExtrasError::InvalidParams.with_details(
+                format!(
+                    "Preedit cursor ({start}, {end}) of event {index} is not a byte range on \
+                     character boundaries of '{value}'"
+                ),
+                json!({ "event": index, "cursor": [start, end], "value": value }),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Resolve the target window: the given entity, else the primary window.
+///
+/// Falls back to `Entity::PLACEHOLDER` like keyboard input when the app has no window.
+fn resolve_window(world: &mut World, window: Option<u64>) -> Result<Entity, BrpError> {
+    if let Some(id) = window {
+        let entity = Entity::from_bits(id);
+        if world.get_entity(entity).is_err() {
+            return Err(BrpExtrasError::WindowNotFound.with_details(
+                format!("Invalid window entity: {id}"),
+                json!({ "window": id }),
+            ));
+        }
+        return Ok(entity);
+    }
+
+    let mut query = world.query_filtered::<Entity, With<PrimaryWindow>>();
+    Ok(query.iter(world).next().unwrap_or(Entity::PLACEHOLDER))
+}
+
+/// Handler for `send_ime` requests
+///
+/// Writes IME composition events to the `Ime` and `WindowEvent` channels in request order
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing
+/// - Request format is invalid
+/// - A preedit cursor is outside its text or splits a character
+/// - The requested window entity does not exist
+pub(crate) fn send_ime_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: SendImeRequest = if let Some(params) = params {
+        serde_json::from_value(params).map_err(|e| {
+            BrpExtrasError::InvalidParams.error(format!("Invalid request format: {e}"))
+        })?
+    } else {
+        return Err(BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE));
+    };
+
+    validate_cursors(&request.events)?;
+    let window = resolve_window(world, request.window)?;
+
+    let events_sent = request.events.len();
+    let mut committed = String::new();
+    for event in request.events {
+        if let ImeEventRequest::Commit { value } = &event {
+            committed.push_str(value);
+        }
+        window_event::write_input_event(world, event.into_ime(window));
+    }
+
+    serde_json::to_value(SendImeResponse {
+        success: true,
+        events_sent,
+        committed,
+    })
+    .map_err(|e| BrpExtrasError::Internal.error(format!("Failed to serialize response: {e}")))
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::ecs::message::Messages;
+    use bevy::prelude::In;
+    use bevy::window::Ime;
+    use bevy::window::WindowEvent;
+    use serde_json::json;
+
+    use super::SendImeResponse;
+    use super::send_ime_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn writes_composition_events_in_order() {
+        let mut app = App::new();
+        app.add_message::<Ime>().add_message::<WindowEvent>();
+
+        let params = json!({
+            "events": [
+                { "type": "enabled" },
+                { "type": "preedit", "value": "にほ", "cursor": [6, 6] },
+                { "type": "preedit", "value": "日本", "cursor": [0, 6] },
+                { "type": "commit", "value": "日本" }
+            ]
+        });
+
+        let response: SendImeResponse = serde_json::from_value(
+            send_ime_handler(In(Some(params)), app.world_mut()).expect("IME events should send"),
+        )
+        .expect("response should deserialize");
+        assert_eq!(response.events_sent, 4);
+        assert_eq!(response.committed, "日本");
+
+        let messages = app.world().resource::<Messages<Ime>>();
+        let written: Vec<&Ime> = messages.iter_current_update_messages().collect();
+        assert_eq!(written.len(), 4);
+        assert!(matches!(written[0], Ime::Enabled { .. }));
+        assert!(matches!(
+            written[2],
+            Ime::Preedit { value, cursor: Some((0, 6)), .. } if value == "日本"
+        ));
+        assert!(matches!(written[3], Ime::Commit { value, .. } if value == "日本"));
+    }
+
+    #[test]
+    fn rejects_a_cursor_inside_a_character() {
+        let mut app = App::new();
+        app.add_message::<Ime>().add_message::<WindowEvent>();
+
+        let params = json!({
+            "events": [{ "type": "preedit", "value": "日本", "cursor": [1, 1] }]
+        });
+
+        let error = send_ime_handler(In(Some(params)), app.world_mut())
+            .expect_err("a cursor splitting a character should be rejected");
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+        assert_eq!(
+            app.world()
+                .resource::<Messages<Ime>>()
+                .iter_current_update_messages()
+                .count(),
+            0
+        );
+    }
+}
@@ -9,17 +9,19 @@ use bevy::prelude::*;
 use bevy::window::WindowEvent;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 use serde_json::json;
 
+use super::chord;
 use super::constants::DEFAULT_KEY_DURATION_MS;
 use super::constants::MAX_KEY_DURATION_MS;
 use super::events;
 use super::key_code::KeyCodeWrapper;
 use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
 use crate::window_event;
 
 /// Component that tracks keys that need to be released after a duration
@@ -32,10 +34,14 @@ pub(super) struct TimedKeyRelease {
 }
 
 /// Request structure for `send_keys`
-#[derive(Debug, Deserialize)]
-pub(super) struct SendKeysRequest {
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct SendKeysRequest {
     /// Array of key codes to send
-    keys:        Vec<String>,
+    #[serde(default)]
+    keys:        Option<Vec<String>>,
+    /// Shortcut to send instead of `keys`, e.g. `"Ctrl+Shift+P"` or `"Cmd+S"`
+    #[serde(default)]
+    chord:       Option<String>,
     /// Duration in milliseconds to hold the keys before releasing
     #[serde(default = "default_duration")]
     duration_ms: u32,
@@ -50,25 +56,27 @@ pub(super) struct SendKeysResponse {
     pub(super) success:     bool,
     /// List of keys that were sent
     pub(super) keys_sent:   Vec<String>,
+    /// The chord the keys were parsed from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) chord:       Option<String>,
     /// Duration in milliseconds the keys were held
     pub(super) duration_ms: u32,
 }
 
 /// Validate key codes and return the parsed key code wrappers
-fn validate_keys(keys: &[String]) -> Result<Vec<(String, KeyCodeWrapper)>, BrpError> {
+fn validate_keys(keys: &[String]) -> Result<Vec<KeyCodeWrapper>, BrpError> {
     let mut validated_keys = Vec::new();
 
     for key_str in keys {
         match KeyCodeWrapper::from_str(key_str) {
             Ok(wrapper) => {
-                validated_keys.push((key_str.clone(), wrapper));
+                validated_keys.push(wrapper);
             },
             Err(_) => {
-                return Err(BrpError {
-                    code:    INVALID_PARAMS,
-                    message: format!("Invalid key code '{key_str}': Unknown key code"),
-                    data:    None,
-                });
+                return Err(BrpExtrasError::InvalidParams.with_details(
+                    format!("Invalid key code '{key_str}': Unknown key code"),
+                    json!({ "key": key_str }),
+                ));
             },
         }
     }
@@ -76,6 +84,36 @@ fn validate_keys(keys: &[String]) -> Result<Vec<(String, KeyCodeWrapper)>, BrpEr
     Ok(validated_keys)
 }
 
+/// Resolve the `keys` or `chord` of a request into key codes and the press events that type their
+/// text. Exactly one of the two must be given.
+pub(super) fn resolve_keys(
+    keys: Option<&[String]>,
+    chord: Option<&str>,
+) -> Result<(Vec<KeyCodeWrapper>, Vec<KeyboardInput>), BrpError> {
+    match (keys, chord) {
+        (Some(keys), None) => {
+            let wrappers = validate_keys(keys)?;
+            let press_events = events::create_keyboard_events(&wrappers, ButtonState::Pressed);
+            Ok((wrappers, press_events))
+        },
+        (None, Some(chord)) => {
+            let chord = chord::parse_chord(chord)?;
+            let mut press_events = events::create_keyboard_events_with_text(
+                &chord.keys,
+                ButtonState::Pressed,
+                chord.text,
+            );
+            if chord.text.is_none() {
+                for event in &mut press_events {
+                    event.text = None;
+                }
+            }
+            Ok((chord.keys, press_events))
+        },
+        _ => Err(BrpExtrasError::InvalidParams.error("Provide exactly one of `keys` or `chord`")),
+    }
+}
+
 /// Handler for `send_keys` requests
 ///
 /// Simulates keyboard input by sending key press/release events
@@ -86,41 +124,36 @@ fn validate_keys(keys: &[String]) -> Result<Vec<(String, KeyCodeWrapper)>, BrpEr
 /// - Request parameters are missing
 /// - Request format is invalid
 /// - Any key code is invalid or unknown
+/// - Both or neither of `keys` and `chord` are given, or the chord cannot be parsed
 pub(crate) fn send_keys_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
     // Parse the request
     let request: SendKeysRequest = if let Some(params) = params {
-        serde_json::from_value(params).map_err(|e| BrpError {
-            code:    INVALID_PARAMS,
-            message: format!("Invalid request format: {e}"),
-            data:    None,
+        serde_json::from_value(params).map_err(|e| {
+            BrpExtrasError::InvalidParams.error(format!("Invalid request format: {e}"))
         })?
     } else {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: MISSING_REQUEST_PARAMETERS_MESSAGE.to_string(),
-            data:    None,
-        });
+        return Err(BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE));
     };
 
-    // Validate key codes
-    let validated_keys = validate_keys(&request.keys)?;
-    let valid_key_strings: Vec<String> = validated_keys.iter().map(|(s, _)| s.clone()).collect();
-    let wrappers: Vec<KeyCodeWrapper> = validated_keys.iter().map(|(_, w)| *w).collect();
+    // Resolve the keys to press, with the press events that type their text
+    let (wrappers, press_events) = resolve_keys(request.keys.as_deref(), request.chord.as_deref())?;
+    let valid_key_strings: Vec<String> = wrappers.iter().map(ToString::to_string).collect();
 
     // Validate duration doesn't exceed maximum
     if request.duration_ms > MAX_KEY_DURATION_MS {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: format!(
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
                 "Duration {}ms exceeds maximum allowed duration of {}ms (1 minute)",
                 request.duration_ms, MAX_KEY_DURATION_MS
             ),
-            data:    None,
-        });
+            json!({
+                "duration_ms": request.duration_ms,
+                "max_duration_ms": MAX_KEY_DURATION_MS,
+            }),
+        ));
     }
 
-    // Always send press events first
-    let press_events = events::create_keyboard_events(&wrappers, ButtonState::Pressed);
+    // Always send press events first, modifiers before the keys they modify
     for event in press_events {
         window_event::write_input_event(world, event);
     }
@@ -139,6 +172,7 @@ pub(crate) fn send_keys_handler(In(params): In<Option<Value>>, world: &mut World
     Ok(json!(SendKeysResponse {
         success:     true,
         keys_sent:   valid_key_strings,
+        chord:       request.chord,
         duration_ms: request.duration_ms,
     }))
 }
@@ -146,7 +180,7 @@ pub(crate) fn send_keys_handler(In(params): In<Option<Value>>, world: &mut World
 /// System that processes timed key releases
 pub(super) fn process_timed_key_releases(
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut query: Query<(Entity, &mut TimedKeyRelease)>,
     mut keyboard_events: MessageWriter<KeyboardInput>,
     mut window_events: MessageWriter<WindowEvent>,
@@ -155,10 +189,11 @@ pub(super) fn process_timed_key_releases(
         timed_release.timer.tick(time.delta());
 
         if timed_release.timer.is_finished() {
-            // Send release events for all keys (text is None for release events)
+            // Send release events in reverse press order so modifiers are released last
+            // (text is None for release events)
             let release_events =
                 events::create_keyboard_events(&timed_release.keys, ButtonState::Released);
-            for event in release_events {
+            for event in release_events.into_iter().rev() {
                 window_events.write(WindowEvent::from(event.clone()));
                 keyboard_events.write(event);
             }
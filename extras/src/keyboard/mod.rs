@@ -1,22 +1,47 @@
 //! Keyboard input simulation for BRP extras
 
+mod chord;
 mod constants;
 mod events;
+mod held;
+mod ime;
 mod key_code;
 mod keys;
 mod typing;
 
 use bevy::prelude::*;
 
+use self::held::HeldKeys;
+pub(crate) use self::held::PressKeysRequest;
+pub(crate) use self::held::ReleaseKeysRequest;
+pub(crate) use self::held::press_keys_handler;
+pub(crate) use self::held::release_keys_handler;
+pub(crate) use self::ime::SendImeRequest;
+pub(crate) use self::ime::send_ime_handler;
+pub(crate) use self::keys::SendKeysRequest;
+use self::keys::TimedKeyRelease;
 pub(crate) use self::keys::send_keys_handler;
+use self::typing::TextTypingQueue;
+pub(crate) use self::typing::TypeTextRequest;
 pub(crate) use self::typing::type_text_handler;
+use crate::window_event;
 
 pub(super) struct KeyboardPlugin;
 
 impl Plugin for KeyboardPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<HeldKeys>();
         app.add_systems(Update, keys::process_timed_key_releases);
+        app.add_systems(Update, held::process_held_key_auto_release);
         app.add_systems(Update, typing::process_text_typing);
+        app.add_systems(
+            Last,
+            (
+                window_event::request_redraw_while_queued::<TimedKeyRelease>,
+                window_event::request_redraw_while_queued::<TextTypingQueue>,
+                held::request_redraw_while_held,
+            ),
+        );
     }
 }
 
@@ -29,17 +54,22 @@ mod tests {
     use bevy::app::App;
     use bevy::prelude::In;
     use bevy::prelude::MinimalPlugins;
-    use bevy_remote::error_codes::INVALID_PARAMS;
     use serde_json::json;
     use strum::IntoEnumIterator;
 
+    use super::chord::parse_chord;
     use super::constants::DEFAULT_KEY_DURATION_MS;
     use super::constants::MAX_KEY_DURATION_MS;
+    use super::held::PressKeysResponse;
+    use super::held::ReleaseKeysResponse;
     use super::key_code::KeyCodeWrapper;
     use super::keys::SendKeysResponse;
     use super::keys::TimedKeyRelease;
+    use super::press_keys_handler;
+    use super::release_keys_handler;
     use super::send_keys_handler;
     use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+    use crate::error::BrpExtrasError;
 
     const CUSTOM_KEY_DURATION_MS: u32 = 500;
 
@@ -62,7 +92,7 @@ mod tests {
         assert!(result.is_err());
 
         let error = result.expect_err("Expected an error but got success");
-        assert_eq!(error.code, INVALID_PARAMS);
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
         assert!(error.message.contains("exceeds maximum allowed duration"));
         assert!(error.message.contains(&format!("{MAX_KEY_DURATION_MS}ms")));
     }
@@ -341,4 +371,95 @@ mod tests {
             "Expected no TimedKeyRelease components when keys array is empty"
         );
     }
+
+    /// Test that chords resolve to modifiers followed by their key
+    #[test]
+    fn test_chord_parsing() {
+        let cases = [
+            ("Ctrl+Shift+P", vec!["ControlLeft", "ShiftLeft", "KeyP"]),
+            ("cmd+s", vec!["SuperLeft", "KeyS"]),
+            ("Alt+F4", vec!["AltLeft", "F4"]),
+            ("Shift+Tab", vec!["ShiftLeft", "Tab"]),
+            ("Ctrl+?", vec!["ControlLeft", "ShiftLeft", "Slash"]),
+            ("Ctrl++", vec!["ControlLeft", "ShiftLeft", "Equal"]),
+            ("Esc", vec!["Escape"]),
+        ];
+
+        for (chord, expected) in cases {
+            let parsed = parse_chord(chord).expect("chord should parse");
+            let keys: Vec<String> = parsed.keys.iter().map(ToString::to_string).collect();
+            assert_eq!(keys, expected, "chord {chord}");
+        }
+
+        assert_eq!(parse_chord("Shift+a").expect("chord").text, Some('A'));
+        assert_eq!(parse_chord("Ctrl+Shift+P").expect("chord").text, None);
+        assert!(parse_chord("Ctrl+Banana").is_err());
+        assert!(parse_chord("Hyper+A").is_err());
+        assert!(parse_chord("").is_err());
+    }
+
+    /// Test that `send_keys` accepts a chord instead of keys, but not both
+    #[test]
+    fn test_send_keys_chord() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        let result = send_keys_handler(
+            In(Some(json!({ "chord": "Ctrl+Shift+P" }))),
+            app.world_mut(),
+        )
+        .expect("chord should be sent");
+        let response: SendKeysResponse =
+            serde_json::from_value(result).expect("Failed to deserialize response");
+        assert_eq!(response.keys_sent, ["ControlLeft", "ShiftLeft", "KeyP"]);
+        assert_eq!(response.chord.as_deref(), Some("Ctrl+Shift+P"));
+
+        let both = json!({ "keys": ["KeyA"], "chord": "Ctrl+A" });
+        assert!(send_keys_handler(In(Some(both)), app.world_mut()).is_err());
+        assert!(send_keys_handler(In(Some(json!({}))), app.world_mut()).is_err());
+    }
+
+    /// Test that keys stay held across `press_keys` calls until `release_keys`
+    #[test]
+    fn test_press_and_release_keys() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        let pressed: PressKeysResponse = serde_json::from_value(
+            press_keys_handler(In(Some(json!({ "keys": ["KeyW"] }))), app.world_mut())
+                .expect("press should succeed"),
+        )
+        .expect("Failed to deserialize response");
+        assert_eq!(pressed.keys_pressed, ["KeyW"]);
+
+        let pressed: PressKeysResponse = serde_json::from_value(
+            press_keys_handler(In(Some(json!({ "chord": "Shift+W" }))), app.world_mut())
+                .expect("press should succeed"),
+        )
+        .expect("Failed to deserialize response");
+        assert_eq!(pressed.keys_pressed, ["ShiftLeft"]);
+        assert_eq!(pressed.held, ["KeyW", "ShiftLeft"]);
+
+        let released: ReleaseKeysResponse = serde_json::from_value(
+            release_keys_handler(
+                In(Some(json!({ "keys": ["KeyW", "KeyA"] }))),
+                app.world_mut(),
+            )
+            .expect("release should succeed"),
+        )
+        .expect("Failed to deserialize response");
+        assert_eq!(released.keys_released, ["KeyW"]);
+        assert_eq!(released.not_held, ["KeyA"]);
+        assert_eq!(released.held, ["ShiftLeft"]);
+
+        let released: ReleaseKeysResponse = serde_json::from_value(
+            release_keys_handler(In(None), app.world_mut()).expect("release should succeed"),
+        )
+        .expect("Failed to deserialize response");
+        assert_eq!(released.keys_released, ["ShiftLeft"]);
+        assert!(released.held.is_empty());
+
+        let no_timeout = json!({ "keys": ["KeyW"], "auto_release_ms": 0 });
+        assert!(press_keys_handler(In(Some(no_timeout)), app.world_mut()).is_err());
+    }
 }
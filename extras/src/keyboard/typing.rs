@@ -7,9 +7,8 @@ use bevy::input::ButtonState;
 use bevy::input::keyboard::KeyboardInput;
 use bevy::prelude::*;
 use bevy::window::WindowEvent;
-use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -18,6 +17,7 @@ use serde_json::json;
 use super::events;
 use super::key_code::KeyCodeWrapper;
 use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
 
 /// Phase of the text typing state machine
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,8 +43,8 @@ pub(super) struct TextTypingQueue {
 }
 
 /// Request structure for `type_text`
-#[derive(Debug, Deserialize)]
-pub(super) struct TypeTextRequest {
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct TypeTextRequest {
     /// Text to type (supports letters, numbers, symbols, newlines, tabs)
     text: String,
 }
@@ -62,7 +62,7 @@ pub(super) struct TypeTextResponse {
 
 /// Convert a character to the key(s) needed to type it.
 /// Returns None for unmappable characters.
-fn char_to_keys(c: char) -> Option<Vec<KeyCodeWrapper>> {
+pub(super) fn char_to_keys(c: char) -> Option<Vec<KeyCodeWrapper>> {
     match c {
         // Lowercase letters
         'a'..='z' => {
@@ -131,17 +131,11 @@ fn char_to_keys(c: char) -> Option<Vec<KeyCodeWrapper>> {
 /// Types text one character per frame, simulating realistic keyboard input.
 pub(crate) fn type_text_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
     let request: TypeTextRequest = if let Some(params) = params {
-        serde_json::from_value(params).map_err(|e| BrpError {
-            code:    INVALID_PARAMS,
-            message: format!("Invalid request format: {e}"),
-            data:    None,
+        serde_json::from_value(params).map_err(|e| {
+            BrpExtrasError::InvalidParams.error(format!("Invalid request format: {e}"))
         })?
     } else {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: MISSING_REQUEST_PARAMETERS_MESSAGE.to_string(),
-            data:    None,
-        });
+        return Err(BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE));
     };
 
     if request.text.is_empty() {
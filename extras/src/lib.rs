@@ -51,7 +51,7 @@
 //! [`struct@BrpExtrasPlugin`] installs the instant `brp_extras/agent_tools` endpoint that publishes
 //! the current metadata. Each request validates every backing method against the live
 //! [`RemoteMethods`](bevy_remote::RemoteMethods) resource. If any entry's method is missing or is a
-//! watching method, the request returns no partial catalog. Its BRP error data identifies the
+//! watching method, the request returns no partial catalog. Its BRP error details identify the
 //! rejected entry with stable `name`, `method`, and `reason` fields.
 //!
 //! # Plugin Composability
@@ -77,24 +77,85 @@
 //! 3. **Full control** — `BrpExtrasPlugin::with_http_plugin(plugin)` accepts a pre-configured
 //!    [`RemoteHttpPlugin`](bevy_remote::http::RemoteHttpPlugin)
 //!
+//! # Custom Methods
+//!
+//! [`BrpExtrasPlugin::with_method`] registers a downstream method alongside the built-in ones.
+//! Its handler is a system taking `In<Option<Value>>` and returning a
+//! [`BrpResult`](bevy_remote::BrpResult), so it can report failures with [`BrpExtrasError`] and
+//! is listed by `brp_extras/capabilities`. The `brp_extras/` prefix is reserved.
+//!
+//! # Frame-Synchronized Execution
+//!
+//! The keyboard, mouse, and gesture methods, along with `mutate_reflect`,
+//! `set_component_override`, `clear_overrides`, `send_event`, `run_system_by_name`, and
+//! `despawn_recursive`, accept two optional parameters that delay the call to a later frame:
+//! - `after_frames` (u32): run this many frames from now; `1` is the next frame
+//! - `run_at_frame` (u32): run at the start of the first frame whose `FrameCount` is at least this
+//!   value, as reported by `brp_extras/ping`
+//!
+//! A delayed request is answered immediately with `scheduled`, `run_at_frame`, and
+//! `current_frame`, so a client can queue a precise sequence of calls without sleeping between
+//! them. Delayed calls run in `First` in the order they were received; their failures are logged
+//! with `warn!` because the request has already been answered.
+//!
+//! # Errors
+//!
+//! Every `brp_extras/*` method reports failures with a [`BrpExtrasError`] code between `-24000`
+//! and `-24099` rather than the generic JSON-RPC codes, so clients can branch on the code instead
+//! of parsing the message. The error `data` always has the same shape:
+//!
+//! ```text
+//! { "kind": "window_not_found", "details": { "window": 4294967299 } }
+//! ```
+//!
+//! `kind` is the snake-case name of the error variant and `details` holds the request values that
+//! caused the failure, or an empty object when there are none.
+//!
+//! Apps running headless, such as CI runs with `WindowPlugin { primary_window: None }`, report
+//! `headless` from every method that needs a window: primary window screenshots, `set_window`,
+//! `set_window_title`, and the mouse methods. Keyboard input and camera captures to image targets
+//! still work. Apps without a GPU render adapter also report `headless`, with reason
+//! `no_render_adapter`, from every screenshot instead of writing an empty image.
+//!
+//! | Code | Kind | Meaning |
+//! |------|------|---------|
+//! | `-24000` | `missing_params` | Required parameters were not sent |
+//! | `-24001` | `invalid_params` | Parameters failed to parse or are out of range |
+//! | `-24002` | `entity_not_found` | A referenced entity does not exist or cannot be used |
+//! | `-24003` | `window_not_found` | A referenced window does not exist, or there is no primary window |
+//! | `-24004` | `camera_unavailable` | No camera, or more than one, satisfies the request |
+//! | `-24005` | `asset_not_found` | An asset or asset type is not registered or not loaded |
+//! | `-24006` | `unsupported` | A plugin, resource, or cargo feature the method needs is missing |
+//! | `-24007` | `invalid_state` | The method cannot run in the app's current state |
+//! | `-24008` | `io` | Reading or writing a file failed |
+//! | `-24009` | `timeout` | The method did not finish before its deadline |
+//! | `-24010` | `headless` | The method needs a window or GPU, but the app has none |
+//! | `-24099` | `internal` | The method failed while producing its result |
+//!
 //! # Available BRP Methods
 //!
 //! ## App Lifecycle
 //!
 //! ### `brp_extras/screenshot`
-//! Captures the primary window, a camera viewport, or a bounds-backed entity and publishes a
-//! complete PNG.
+//! Captures the primary window, another window, a camera viewport, or a bounds-backed entity, or
+//! a region of a window or viewport, and publishes a complete PNG.
 //! The watching request returns only after publication.
 //! Success means the PNG is fully encoded and atomically published; it does not assert that scene
 //! content is nonuniform. A minimized, hidden, or fully occluded primary-window surface may
 //! legitimately produce a black image on platforms that stop presenting it. Entity captures
 //! reflect the selected camera target; retained image or other offscreen targets avoid
 //! primary-window presentation dependence when the application is designed to use them.
-//! - `path` (string, required): destination file path
+//! - `path` (string, required unless `inline`): destination file path; unavailable on WASM
+//! - `inline` (bool, optional): return the PNG base64-encoded in `data` instead of writing a file;
+//!   mutually exclusive with `path` and the default on WASM
 //! - `entity` (u64, optional): exact Bevy entity ID whose bounds select the crop
 //! - `camera` (u64, optional): active camera viewport, or the camera used for entity capture
 //! - `padding` (u32, optional): physical pixels added around entity bounds; requires `entity` and
 //!   defaults to zero
+//! - `window` (u64, optional): window entity to capture instead of the primary window; not combined
+//!   with `camera` or `entity`
+//! - `region` (`[x, y, width, height]`, optional): physical pixels to keep, relative to the
+//!   captured window or camera viewport; must lie inside it and is not combined with `entity`
 //!
 //! AABB capture projects the selected entity's [`Aabb`](bevy::camera::primitives::Aabb) through
 //! the selected camera. With the default `ui` feature, complete Bevy UI computed components take
@@ -114,30 +175,411 @@
 //! ### `brp_extras/shutdown`
 //! Schedules a graceful application shutdown. No parameters.
 //!
+//! ### `brp_extras/ping`
+//! Cheap health check that touches no component data. Returns `plugin_version` (this crate's
+//! version), `bevy_version` (the Bevy release this crate targets), the enabled cargo `features`,
+//! `uptime_secs` since the first update, and `frame_count` when `FrameCount` is available. No
+//! parameters.
+//!
+//! ### `brp_extras/capabilities`
+//! Lists the `brp_extras/` methods registered in this app, which varies with the crate version,
+//! cargo features, and platform. Returns `plugin_version` and `methods`, each with `method`,
+//! `watching`, and `params_schema` (a JSON schema, omitted for methods without parameters). No
+//! parameters.
+//!
 //! ### `brp_extras/set_window_title`
 //! Changes the title of the primary window.
 //! - `title` (string, required): new window title
 //!
+//! Titles containing `{fps}`, `{frame}`, or `{entities}` become a live template that the app
+//! re-renders every second. `{fps}` requires the `diagnostics` feature and shows `n/a` without it.
+//! Setting a title without placeholders stops the refresh.
+//!
+//! ### `brp_extras/set_window`
+//! Changes properties of a window. Every property is optional and omitted properties are left
+//! unchanged; the response reports which properties changed and the resulting window state.
+//! - `window` (u64, optional): window entity ID (defaults to the primary window)
+//! - `resolution` (`[f32; 2]`, optional): logical `[width, height]`
+//! - `position` (`[i32; 2]`, optional): top-left corner in physical screen pixels
+//! - `mode` (string, optional): `"windowed"`, `"fullscreen"`, or `"borderless"` (fullscreen modes
+//!   use the window's current monitor)
+//! - `decorations` (bool, optional): show the title bar and border
+//! - `cursor_visible` (bool, optional): show the cursor over the window
+//! - `vsync` (bool, optional): `true` selects `PresentMode::AutoVsync`, `false`
+//!   `PresentMode::AutoNoVsync`
+//!
+//! ### `brp_extras/set_clear_color`
+//! Sets the color cameras clear to before rendering. Without `camera`, replaces the `ClearColor`
+//! resource, which only affects cameras whose `clear_color` is `ClearColorConfig::Default`. The
+//! response includes the `previous` color when there was one.
+//! - `color` (`[f32; 4]`, required): sRGBA with components in `0.0..=1.0`
+//! - `camera` (u64, optional): camera entity to give a `ClearColorConfig::Custom` color instead
+//!
+//! ### `brp_extras/set_ambient_light`
+//! Sets the ambient light color and brightness, leaving omitted values unchanged. Without
+//! `camera`, updates the `GlobalAmbientLight` resource; with it, updates that camera's
+//! `AmbientLight` override, starting from the global values when the camera has none. Requires
+//! the opt-in `light` cargo feature.
+//! - `color` (`[f32; 4]`, optional): sRGBA with components in `0.0..=1.0`
+//! - `brightness` (f32, optional): non-negative brightness in candela per square meter
+//! - `camera` (u64, optional): camera entity to override
+//!
 //! ### `brp_extras/get_diagnostics`
 //! Returns FPS and frame time diagnostics from Bevy's `DiagnosticsStore`.
-//! No parameters. Requires the `diagnostics` cargo feature (enabled by default).
+//! Requires the `diagnostics` cargo feature (enabled by default).
 //!
 //! Returns current, average, and smoothed values for FPS and frame time,
 //! plus total frame count and history buffer metadata.
+//! - `detail` (string, optional, default: `"summary"`): `"systems"` adds `system_information`
+//!   (usage from `SystemInformationDiagnosticsPlugin`, keyed by path) and `system_timings` (every
+//!   other diagnostic in the store, such as per-system timings from a profiling plugin, slowest
+//!   average first). Both are empty unless the app adds a plugin that records them.
+//! - `categories` (array, optional): sections to add to the summary. `"systems"` is the same as
+//!   `detail: "systems"`. `"render"` adds `render` with `visible_entities` (entities whose
+//!   `ViewVisibility` is visible), `draw_calls` (summed across passes, `null` when none are
+//!   recorded), `gpu_timings_available`, and `passes` (each render pass with its measurements, such
+//!   as `elapsed_cpu` and `elapsed_gpu`). Pass measurements require `RenderDiagnosticsPlugin` in
+//!   the app, and `elapsed_gpu` requires GPU timestamp query support.
+//!
+//! ### `brp_extras/diagnostics_watch`
+//! A watching method: samples the `DiagnosticsStore` on every frame whose `FrameCount` is a
+//! multiple of `every_n_frames` and streams `{ "frame", "elapsed_secs", "entries": [...] }`. Each
+//! entry has the diagnostic's `path`, `current`, `average`, `smoothed`, and `suffix`. Requires the
+//! `diagnostics` cargo feature.
+//! - `every_n_frames` (u32, optional, default: 60): frames between samples
+//! - `paths` (array of strings, optional, default: `["fps", "frame_time"]`): diagnostic paths to
+//!   sample; unknown paths are rejected with the available paths in the error details
+//!
+//! ## Assets
+//!
+//! Asset types are found through reflection, so only types registered with `ReflectAsset` (for
+//! example via `App::register_asset_reflect`) are visible. Type names accept the full or short type
+//! path (`bevy_image::image::Image` or `Image`).
+//!
+//! ### `brp_extras/list_assets`
+//! Without parameters, lists reflected asset types with their asset counts. With `asset_type`,
+//! lists that type's assets with their id, path, load state, and load error.
+//! - `asset_type` (string, optional): asset type path
+//!
+//! ### `brp_extras/get_asset_info`
+//! Reports one asset's path, load state, dependency load states, and whether its data is present.
+//! - `asset_type` (string, required): asset type path
+//! - `id` (string, optional): asset id as returned by `list_assets`
+//! - `path` (string, optional): asset path; exactly one of `id` or `path` is required
+//! - `include_value` (bool, optional, default: false): include the reflected asset value
+//!
+//! ### `brp_extras/reload_asset`
+//! Queues a reload of a loaded asset from its source. The reload completes asynchronously.
+//! - `path` (string, required): asset path
+//!
+//! ## World Snapshots
+//!
+//! Snapshots hold every reflectable component and resource, captured through `DynamicWorld` and
+//! serialized as JSON with entities keyed by their BRP ids.
+//!
+//! ### `brp_extras/save_world_snapshot`
+//! Captures a snapshot and writes it to a file or returns it inline.
+//! - `path` (string, optional): file to write; the snapshot is returned as `snapshot` when omitted
+//! - `entities` (array of u64, optional): entities to capture (default: all)
+//! - `include_resources` (bool, optional, default: true): capture reflectable resources
+//!
+//! ### `brp_extras/load_world_snapshot`
+//! Writes a snapshot back over the entities it came from. Despawned entities are respawned and
+//! reported under `respawned` with their new ids; components added after the snapshot are kept.
+//! - `path` (string, optional): snapshot file written by `save_world_snapshot`
+//! - `snapshot` (object, optional): inline snapshot; exactly one of `path` or `snapshot` is
+//!   required
+//!
+//! ## Component Overrides
+//!
+//! Overrides replace a component's value for an experiment and restore it afterwards. Overriding
+//! an overridden component stacks; the component always ends up back at its value before the
+//! first override.
+//!
+//! ### `brp_extras/set_component_override`
+//! Writes a component value through reflection and reports the replaced value as `original`
+//! (`null` if the entity did not have the component, which is then removed on restore) and the
+//! `override_id`.
+//! - `entity` (u64, required): entity whose component is overridden
+//! - `component` (string, required): full component type path
+//! - `value` (any, required): component value in the `world.insert_components` format
+//! - `duration_secs` (f32, optional): restore the original after this many real-time seconds;
+//!   without it the override lasts until `clear_overrides`
+//!
+//! ### `brp_extras/clear_overrides`
+//! Ends matching overrides newest first. `cleared` lists each with an `outcome` of `restored`,
+//! `superseded` (a newer override of the component is still active), or `entity_despawned`.
+//! - `entity` (u64, optional): only clear this entity's overrides
+//! - `component` (string, optional): only clear overrides of this component type path
+//!
+//! ## Reflection
+//!
+//! ### `brp_extras/get_component_raw`
+//! Reads a component that `world.get_components` cannot serialize, such as one holding an opaque
+//! type without `ReflectSerialize`. The component is walked through reflection: every value that
+//! serializes is returned in BRP's format and the rest as their `Debug` output, each listed in
+//! `unserializable` with its `path` and `type_path`. The `value` is marked `read_only`, as it may
+//! not be accepted by `world.insert_components`.
+//! - `entity` (u64, required): entity to read the component from
+//! - `component` (string, required): full type path of a component registered with
+//!   `reflect(Component)`
+//!
+//! ### `brp_extras/mutate_reflect`
+//! Changes a component value through reflection. The value is deserialized and applied like
+//! `world.mutate_components`; when it cannot be deserialized as a whole, for example because the
+//! type holds an opaque value without `ReflectDeserialize`, each field given in a struct, tuple,
+//! list, or array value is applied on its own and fields left out keep their values. Returns the
+//! value now at `path`, in the `get_component_raw` format.
+//! - `entity` (u64, required): entity whose component is changed
+//! - `component` (string, required): full type path of a component registered with
+//!   `reflect(Component)`
+//! - `path` (string, optional, default: whole component): path in `world.mutate_components` syntax,
+//!   such as `.translation.x`
+//! - `value` (any, required): new value in BRP's reflect format
+//!
+//! ## Events
+//!
+//! Messages can only be watched and sent remotely once their type is registered for BRP.
+//! `KeyboardInput`, `MouseButtonInput`, and `MouseWheel` are registered by default; register
+//! other reflected message types, such as collision events, with
+//! [`AppBrpMessageExt::register_brp_message`]:
+//! ```ignore
+//! app.add_plugins(BrpExtrasPlugin::default())
+//!     .register_brp_message::<CollisionStarted>();
+//! ```
+//!
+//! ### `brp_extras/events_watch`
+//! A watching method: streams `{ "events": [...] }` each frame in which registered messages were
+//! written. Each event has `seq`, `type_path`, `frame`, and the reflected `value`. At most 1024
+//! events are buffered, so a gap in `seq` means a frame wrote more and the oldest were dropped.
+//! - `types` (array of strings, optional): only stream these message type paths; unregistered types
+//!   are rejected with the registered types in the error details
+//!
+//! ### `brp_extras/send_event`
+//! Deserializes a message through reflection and writes it, so systems reading that message type
+//! see it on the next frame, just as if the app had written it. Returns the `event` type path and
+//! the `frame` it was written in.
+//! - `event` (string, required): type path of a registered message type
+//! - `value` (any, optional): the message in BRP's reflect format; omit for unit structs
+//!
+//! ## Entities
+//!
+//! ### `brp_extras/despawn_recursive`
+//! Despawns an entity and every entity below it in the `Children` hierarchy. The response lists
+//! each despawned entity under `despawned`, the root first.
+//! - `entity` (u64, required): root of the hierarchy to despawn
+//!
+//! ### `brp_extras/get_world_stats`
+//! Summarizes the world without serializing component values: `entity_count`, archetype and table
+//! counts, `component_type_count`, and `estimated_component_bytes` (component type layout sizes
+//! times entity counts, excluding heap data). `archetypes` lists each archetype's table, entity
+//! count, sorted component names, and estimated bytes, largest first.
+//! - `include_empty` (bool, optional, default: false): also list archetypes without entities
+//! - `limit` (usize, optional): only list the largest `limit` archetypes
+//!
+//! ### `brp_extras/get_visible_text`
+//! Lists the text currently on screen so tests can assert on labels without OCR. `texts` has one
+//! entry per visible UI `Text` node or `Text2d` entity in reading order, each with `entity`,
+//! `name` (when it has a `Name`), `kind` (`"ui"` or `"text2d"`), `text` (the root text followed by
+//! its `TextSpan` children), `window`, and `rect` (`[x, y, width, height]` in window logical
+//! pixels, clipped to the screen). UI nodes use their computed layout; `Text2d` uses its `Aabb`
+//! seen through the highest-order window camera that shows it. Requires the opt-in `text` cargo
+//! feature; UI text also needs the `ui` feature.
+//! - `contains` (string, optional): only return text containing this substring
+//! - `window` (u64, optional): only return text shown in this window
+//!
+//! ### `brp_extras/query_changed`
+//! Returns the `entities` whose components changed or were added, checked against each
+//! component's change ticks like `Changed<T>` and `Added<T>` filters, which `world.query` cannot
+//! express. Each response has a `cursor`; pass it as `since` to the next call to get only entities
+//! changed after this one. Without `since`, every entity with the components is returned.
+//! - `components` (array of strings, required): full type paths of components registered with
+//!   `reflect(Component)`; entities must have all of them, and each must match `filter`
+//! - `filter` (string, optional, default: `changed`): `changed` or `added`
+//! - `since` (u32, optional): `cursor` from a previous response
+//!
+//! ### `brp_extras/query_spatial`
+//! Returns entities whose `GlobalTransform` translation lies inside a sphere or an axis-aligned
+//! box, nearest first. Each hit has `entity`, `name` (when it has a `Name`), `translation`, and
+//! `distance` from the sphere or box center; `total_count` counts hits before `limit`.
+//! - `center` (`[x, y, z]`) and `radius` (f32): search sphere
+//! - `min` and `max` (`[x, y, z]`): search box, inclusive; give either the sphere or the box
+//! - `limit` (usize, optional): only return the nearest `limit` entities
+//!
+//! ### `brp_extras/pick_entity`
+//! Casts a ray through a window position from the camera rendering it and returns the nearest mesh
+//! hit, like `bevy_picking`'s mesh backend: `hit` has `entity`, `name`, world-space `point` and
+//! `normal`, and `distance`, or is `null` on a miss. The response also reports the `camera` and
+//! the world-space `ray`. Requires the opt-in `picking` cargo feature.
+//! - `x`, `y` (f32, required): window position in logical pixels from the top-left corner
+//! - `window` (u64, optional): target window entity; defaults to the primary window
+//! - `camera` (u64, optional): camera to cast from; defaults to the highest-order active camera
+//!   whose viewport contains the position
+//!
+//! ## Physics
+//!
+//! Requires the `physics` cargo feature (off by default). Extras does not depend on a physics
+//! engine: Avian's and `bevy_rapier`'s components are found in the type registry by crate and
+//! type name, and the response names the `backend` and full `component` type path that was used.
+//!
+//! ### `brp_extras/set_linear_velocity`
+//! Sets a rigid body's linear velocity: Avian's `LinearVelocity` or `bevy_rapier`'s
+//! `Velocity::linvel`. Returns the `velocity` now on the component.
+//! - `entity` (u64, required): rigid body entity
+//! - `velocity` (array, required): `[x, y]` for 2D engines or `[x, y, z]` for 3D engines
+//!
+//! ### `brp_extras/get_contacts`
+//! Lists the entities a collider is touching, sorted by ID, from Avian's `CollidingEntities`.
+//! The collider needs that component, which Avian only maintains on entities that have it.
+//! `bevy_rapier` keeps contacts in its context rather than on entities and fails with
+//! `unsupported`.
+//! - `entity` (u64, required): collider entity
+//!
+//! ### `brp_extras/set_gravity`
+//! Sets the world's gravity: Avian's `Gravity` resource or the `gravity` of every `bevy_rapier`
+//! `RapierConfiguration`. Fails with `invalid_state` until the engine's plugin has inserted it.
+//! Returns the `gravity` now in effect.
+//! - `gravity` (array, required): `[x, y]` for 2D engines or `[x, y, z]` for 3D engines
+//!
+//! ## Projection
+//!
+//! Screen positions are window coordinates in logical pixels from the top-left corner, the space
+//! the mouse methods use. Both methods take the `camera` (u64, required) whose projection is used.
+//!
+//! ### `brp_extras/world_to_screen`
+//! Projects a world-space point to a window position. Returns `world_point`, `position` (`null`
+//! when the point is behind the camera or beyond its depth range), and `in_view`, whether the
+//! position lies inside the camera's viewport.
+//! - `point` (`[x, y, z]`) or `entity` (u64): what to project; an entity's `GlobalTransform`
+//!   translation is used
+//!
+//! ### `brp_extras/screen_to_world`
+//! Returns the world-space `ray` through a window position, and a `point` on it when `distance`
+//! or `plane` is given (omitted if the ray misses the plane).
+//! - `x`, `y` (f32, required): window position
+//! - `distance` (f32, optional): return the point this far along the ray
+//! - `plane` (object, optional): `{ "origin": [x, y, z], "normal": [x, y, z] }`; return where the
+//!   ray crosses it
+//!
+//! ## Camera
+//!
+//! Each method takes the `camera` (u64, required) to change and writes its `Transform`. Targets
+//! are world-space points, converted into the parent's space for a parented camera. Responses
+//! return the new `translation`, `rotation` (`[x, y, z, w]`), `scale`, and `forward` direction,
+//! plus the world-space `target` for `look_at` and `orbit_camera`.
+//!
+//! ### `brp_extras/set_camera_transform`
+//! Replaces the given parts of the camera's `Transform` and keeps the rest.
+//! - `translation` (`[x, y, z]`, optional)
+//! - `rotation` (`[x, y, z, w]`, optional): normalized before use
+//! - `scale` (`[x, y, z]`, optional)
+//!
+//! ### `brp_extras/look_at`
+//! Rotates the camera in place to face a target.
+//! - `point` (`[x, y, z]`) or `entity` (u64): the target; an entity's `GlobalTransform` translation
+//!   is used
+//! - `up` (`[x, y, z]`, optional): up direction to keep (default `[0, 1, 0]`)
+//!
+//! ### `brp_extras/orbit_camera`
+//! Moves the camera onto a sphere around a target and faces it, keeping `+Y` up.
+//! - `point` (`[x, y, z]`) or `entity` (u64): the target to orbit
+//! - `yaw` (f32, default 0): degrees around the Y axis; `0` is the target's `+Z` side
+//! - `pitch` (f32, default 0): degrees above the horizontal, strictly between -90 and 90
+//! - `distance` (f32, optional): distance from the target (default: the current distance)
+//!
+//! ## Gizmos
+//!
+//! ### `brp_extras/draw_gizmo`
+//! Draws temporary debug shapes over the scene so an agent can mark what it is looking at while a
+//! human watches. Shapes stay visible for `ttl_frames` frames and are drawn in front of scene
+//! geometry. Requires the opt-in `gizmos` cargo feature and Bevy's `GizmoPlugin`.
+//! The response reports `shapes_drawn`, `active_shapes` (including earlier requests), and
+//! `ttl_frames`. No shape is drawn if any of them is invalid.
+//! - `shapes` (array): shapes tagged by `type`, each with an optional sRGBA `color`:
+//!   - `{"type": "line", "start": [x, y, z], "end": [x, y, z]}`
+//!   - `{"type": "sphere", "center": [x, y, z], "radius": r}`
+//!   - `{"type": "aabb", "min": [x, y, z], "max": [x, y, z]}` or `{"type": "aabb", "entity": id}`
+//!     for the entity's `Aabb` in its world transform
+//!   - `{"type": "text", "position": [x, y, z], "text": "..."}`: a screen-aligned label over a
+//!     world position; requires the `ui` feature
+//! - `ttl_frames` (u32, optional, default: 120): frames the shapes stay visible
+//! - `camera` (u64, optional): camera text labels are placed for; defaults to the highest-order
+//!   active camera
+//! - `clear` (bool, optional, default: false): remove every previously drawn shape first
+//!
+//! ### `brp_extras/highlight_entity`
+//! Outlines an entity for a few seconds so a human supervisor can see which entity the agent is
+//! inspecting. The outline is a gizmo box around the `Aabb` of the entity and each of its
+//! descendants, or a small sphere at its position when none has an `Aabb`, and follows the entity
+//! as it moves. The inserted marker component removes itself when the time runs out;
+//! highlighting the same entity again restarts the timer (`restarted` in the response). Requires
+//! the `gizmos` cargo feature.
+//! - `entity` (u64, required): entity to highlight
+//! - `duration_secs` (f32, optional, default: 3.0): how long the highlight stays visible
+//! - `color` (`[r, g, b, a]`, optional): sRGBA outline color; defaults to cyan
+//!
+//! ## Schedules
+//!
+//! Schedules are read from the `Schedules` resource and named by the debug form of their label
+//! (`Update`, `FixedUpdate`). A schedule is removed from `Schedules` while it runs, so `Main` and
+//! the schedule processing the request are not visible.
+//!
+//! ### `brp_extras/list_systems`
+//! Lists each schedule's systems with the sets they are in, their own run conditions, conditions
+//! inherited from their sets, the systems and sets they run after and before, and the change tick
+//! of their last run (`0` if they never ran).
+//! - `schedule` (string, optional): only list this schedule
+//!
+//! ### `brp_extras/get_schedule_graph`
+//! Returns one schedule's system and set nodes with their run conditions, the set `hierarchy` as
+//! `{parent, child}` edges, and ordering `dependencies` as `{before, after}` edges.
+//! - `schedule` (string, required): schedule to describe
+//!
+//! ### `brp_extras/run_system_by_name`
+//! Runs a one-shot system registered with [`BrpExtrasSystems::register_brp_system`], so apps can
+//! expose game commands without writing RPC handlers. The system's commands are applied before the
+//! response, which returns the `name` that ran. Unknown names are rejected with the registered
+//! names in the error details.
+//! - `name` (string, required): name the system was registered under
+//! ```ignore
+//! app.add_plugins(BrpExtrasPlugin::default())
+//!     .register_brp_system("reset_level", reset_level);
+//! ```
 //!
 //! ## Keyboard
 //!
 //! ### `brp_extras/send_keys`
 //! Simulates keyboard input with a press-hold-release cycle. All keys are
-//! pressed simultaneously and held for the specified duration.
-//! - `keys` (array of strings, required): key codes (e.g., `["KeyA", "Space", "ShiftLeft"]`)
+//! pressed in order, held for the specified duration, and released in reverse order.
+//! - `keys` (array of strings): key codes (e.g., `["KeyA", "Space", "ShiftLeft"]`)
+//! - `chord` (string): a shortcut to send instead of `keys`, e.g. `"Ctrl+Shift+P"`, `"Cmd+S"`, or
+//!   `"Alt+F4"`. Modifiers (`Ctrl`, `Shift`, `Alt`/`Option`, `Cmd`/`Super`/`Win`, `CmdOrCtrl`) are
+//!   pressed before the key and released after it. Chords with Control, Alt, or Super type no text
 //! - `duration_ms` (u32, optional, default: 100, max: 60000): hold duration in milliseconds
 //!
+//! ### `brp_extras/press_keys` and `brp_extras/release_keys`
+//! Hold keys down across requests, e.g. hold `KeyW` while clicking elsewhere. `press_keys` takes
+//! `keys` or `chord` like `send_keys` and leaves the keys pressed; pressing a held key again only
+//! restarts its timer. `release_keys` releases the given `keys` or `chord`, or every held key when
+//! neither is given, in reverse press order. Both respond with the keys still `held`.
+//! - `auto_release_ms` (u32, optional, default: 10000, max: 60000): `press_keys` only; held keys
+//!   are released automatically after this long, so a client that never calls `release_keys` cannot
+//!   leave a key stuck
+//!
 //! ### `brp_extras/type_text`
 //! Types text sequentially, one character per frame, with proper shift handling
 //! for uppercase and symbols.
 //! - `text` (string, required): text to type (letters, numbers, symbols, newlines, tabs)
 //!
+//! ### `brp_extras/send_ime`
+//! Writes IME composition events in order during one frame, for apps that accept CJK and other
+//! composed text through Bevy's `Ime` messages. Each event is an object tagged by `type`:
+//! `{"type": "enabled"}`, `{"type": "preedit", "value": "にほ", "cursor": [6, 6]}`,
+//! `{"type": "commit", "value": "日本"}`, or `{"type": "disabled"}`. A preedit `cursor` is a byte
+//! range within `value`; omit it to hide the cursor.
+//! - `events` (array, required): IME events to write
+//! - `window` (u64, optional): target window entity; defaults to the primary window
+//!
 //! ## Mouse
 //!
 //! All mouse methods accept an optional `window` parameter (entity ID) to target
@@ -150,6 +592,14 @@
 //! - `button` (string, required)
 //! - `window` (u64, optional)
 //!
+//! ### `brp_extras/click_ui_node`
+//! Finds a UI node by `Name` or entity, moves the cursor to the center of its computed layout,
+//! and clicks there. The response has the `entity`, its `name`, the `window`, the logical
+//! `position` clicked, and the `button`. Requires the `ui` cargo feature (enabled by default).
+//! - `name` (string, optional): `Name` of the node; must match exactly one UI node
+//! - `entity` (u64, optional): node entity, instead of `name`
+//! - `button` (string, optional, default: `"Left"`)
+//!
 //! ### `brp_extras/double_click_mouse`
 //! Performs two rapid clicks with configurable delay.
 //! - `button` (string, required)
@@ -168,11 +618,23 @@
 //! - `position` ([f32; 2], optional): absolute position
 //! - `window` (u64, optional)
 //!
+//! ### `brp_extras/hover_mouse`
+//! Moves the cursor to a position and keeps reporting it there every frame for a dwell time, so
+//! hover states and tooltips that wait for a resting pointer appear. Responds immediately; take
+//! a screenshot once `dwell_ms` has passed. A new hover on the same window replaces the last one.
+//! - `position` ([f32; 2], required): position to hover over
+//! - `dwell_ms` (u32, optional, default: 500, max: 60000): how long the cursor stays
+//! - `window` (u64, optional)
+//!
 //! ### `brp_extras/drag_mouse`
-//! Performs a smooth drag with linear interpolation over a number of frames.
+//! Performs a smooth drag along a path over a number of frames.
 //! - `button` (string, required)
 //! - `start` ([f32; 2], required): starting position
 //! - `end` ([f32; 2], required): ending position
+//! - `waypoints` (array of [f32; 2], optional): positions to pass through between `start` and
+//!   `end`; the cursor moves along the resulting path at a pace measured by distance
+//! - `easing` (optional, default: `"linear"`): `"ease_in"`, `"ease_out"`, `"ease_in_out"`, or
+//!   `{"cubic_bezier": [x1, y1, x2, y2]}` like CSS `cubic-bezier()`
 //! - `frames` (u32, required): number of frames to interpolate over
 //! - `window` (u64, optional)
 //!
@@ -183,6 +645,29 @@
 //! - `unit` (string, required): `"Line"` or `"Pixel"`
 //! - `window` (u64, optional)
 //!
+//! ## Input Scripts
+//!
+//! ### `brp_extras/run_input_script`
+//! Plays back a list of input events frame-accurately. Each event fires on the frame given by its
+//! `frame` offset from the start of playback (frame 0 is the frame after the request) and is
+//! dispatched through the matching keyboard or mouse method, so it accepts that method's
+//! parameters. Same-frame events fire in script order. A new script replaces one still playing.
+//! - `events` (array, required, max 10000): objects with `frame` (u32), `type`, and parameters:
+//!   - `key`: `send_keys` parameters (`keys` or `chord`, `duration_ms`)
+//!   - `move_mouse`: `move_mouse` parameters (`position` or `delta`, `window`)
+//!   - `click`: `click_mouse` parameters (`button`, `window`)
+//!   - `scroll`: `scroll_mouse` parameters (`x`, `y`, `unit`, `window`)
+//!
+//! ### `brp_extras/start_input_recording`
+//! Starts recording real keyboard and mouse input, restarting any recording in progress. No
+//! parameters.
+//!
+//! ### `brp_extras/stop_input_recording`
+//! Stops recording and returns the input as `events` in `run_input_script` format: key presses
+//! become `key` events held for the measured duration, button presses become `click` events,
+//! cursor movement becomes one `move_mouse` per frame, and wheel input becomes `scroll` events.
+//! No parameters.
+//!
 //! ## Trackpad Gestures (macOS)
 //!
 //! ### `brp_extras/double_tap_gesture`
@@ -197,6 +682,20 @@
 //! Sends a rotation gesture.
 //! - `delta` (f32, required): rotation in radians
 //!
+//! ## Waiting
+//!
+//! ### `brp_extras/wait_for`
+//! Holds the response until a condition holds, checking it every frame, so a client does not have
+//! to poll and sleep while the app loads or reacts. Answers with the `condition`, what it
+//! `observed` on the frame it held, `elapsed_ms`, and `frames_waited`, or fails with `timeout`
+//! and the last observation.
+//! - `condition` (object, required), selected by `kind`:
+//!   - `component_exists` with `component`: some entity has the reflected component
+//!   - `resource_equals` with `resource` and `value`: the reflected resource serializes to `value`
+//!   - `diagnostic_threshold` with `path` and `above` and/or `below`: the diagnostic's smoothed
+//!     value is past the threshold
+//! - `timeout_ms` (u64, optional, default: 5000, max: 25000)
+//!
 //! ## Agent Tools
 //!
 //! ### `brp_extras/agent_tools`
@@ -206,20 +705,56 @@
 //! rules and the BRP error data returned for a rejected entry.
 
 mod agent_tools;
+mod assets;
+mod camera;
+mod capabilities;
+mod change_detection;
 mod constants;
+mod despawn;
 #[cfg(feature = "diagnostics")]
 mod diagnostics;
+mod environment;
+mod error;
+mod events;
+mod frame_sync;
+#[cfg(feature = "gizmos")]
+mod gizmo;
+mod headless;
+#[cfg(feature = "gizmos")]
+mod highlight;
+mod input_recording;
+mod input_script;
 mod keyboard;
 mod mouse;
+mod named_systems;
+mod overrides;
+#[cfg(feature = "physics")]
+mod physics;
+#[cfg(feature = "picking")]
+mod picking;
+mod ping;
 mod plugin;
+mod projection;
+mod reflection;
+mod schedules;
 mod screenshot;
 mod shutdown;
+mod spatial;
+mod support;
+#[cfg(feature = "text")]
+mod visible_text;
+mod wait_for;
+mod window;
 mod window_event;
-mod window_title;
+mod world_snapshot;
+mod world_stats;
 
 pub use agent_tools::AgentTool;
 pub use agent_tools::AppAgentToolExt;
 pub use constants::DEFAULT_REMOTE_PORT;
+pub use error::BrpExtrasError;
+pub use events::AppBrpMessageExt;
+pub use named_systems::BrpExtrasSystems;
 pub use plugin::BrpExtrasPlugin;
 #[cfg(not(target_arch = "wasm32"))]
 pub use plugin::HasEffectivePort;
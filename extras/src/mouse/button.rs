@@ -6,27 +6,29 @@ use bevy::input::mouse::MouseButton;
 use bevy::input::mouse::MouseButtonInput;
 use bevy::prelude::*;
 use bevy::window::WindowEvent;
-use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
+use serde_json::json;
 
 use super::constants::DEFAULT_MOUSE_DURATION_MS;
 use super::constants::MAX_MOUSE_DURATION_MS;
 use super::support;
 use super::support::EmptyParamsPolicy;
 use crate::constants::METHOD_SEND_MOUSE_BUTTON;
+use crate::error::BrpExtrasError;
 
 // ============================================================================
 // Types
 // ============================================================================
 
 /// Request structure for `send_mouse_button`
-#[derive(Deserialize)]
-struct SendMouseButtonRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct SendMouseButtonRequest {
     /// Mouse button to press
+    #[schemars(with = "String")]
     button:      MouseButton,
     /// Duration in milliseconds to hold button (default: 100ms, max: 60000ms)
     #[serde(default)]
@@ -80,13 +82,10 @@ pub(crate) fn send_mouse_button_handler(
     // Validate duration
     let duration_ms = request.duration_ms.unwrap_or(DEFAULT_MOUSE_DURATION_MS);
     if duration_ms > MAX_MOUSE_DURATION_MS {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: format!(
-                "Duration exceeds maximum: {duration_ms}ms > {MAX_MOUSE_DURATION_MS}ms"
-            ),
-            data:    None,
-        });
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("Duration exceeds maximum: {duration_ms}ms > {MAX_MOUSE_DURATION_MS}ms"),
+            json!({ "duration_ms": duration_ms, "max_duration_ms": MAX_MOUSE_DURATION_MS }),
+        ));
     }
 
     let window = support::resolve_window(world, request.window)?;
@@ -111,7 +110,7 @@ pub(crate) fn send_mouse_button_handler(
 /// sends the button release event and despawns the entity.
 pub(super) fn process_timed_button_releases(
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut query: Query<(Entity, &mut TimedButtonRelease)>,
     mut button_events: MessageWriter<MouseButtonInput>,
     mut window_events: MessageWriter<WindowEvent>,
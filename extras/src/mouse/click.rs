@@ -9,6 +9,7 @@ use bevy::input::mouse::MouseButtonInput;
 use bevy::prelude::*;
 use bevy::window::WindowEvent;
 use bevy_remote::BrpResult;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -27,9 +28,10 @@ use crate::window_event;
 // ============================================================================
 
 /// Request structure for `click_mouse`
-#[derive(Deserialize)]
-struct ClickMouseRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct ClickMouseRequest {
     /// Mouse button to click
+    #[schemars(with = "String")]
     button: MouseButton,
     /// Target window entity (None = primary window)
     #[serde(default)]
@@ -44,9 +46,10 @@ struct ClickMouseResponse {
 }
 
 /// Request structure for `double_click_mouse`
-#[derive(Deserialize)]
-struct DoubleClickMouseRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct DoubleClickMouseRequest {
     /// Mouse button to double click
+    #[schemars(with = "String")]
     button:   MouseButton,
     /// Delay between clicks in milliseconds (default: 250ms)
     #[serde(default)]
@@ -163,7 +166,7 @@ pub(crate) fn double_click_mouse_handler(
 /// - Despawns the scheduled click entity
 pub(super) fn process_scheduled_clicks(
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut query: Query<(Entity, &mut ScheduledClick)>,
     mut button_events: MessageWriter<MouseButtonInput>,
     mut window_events: MessageWriter<WindowEvent>,
@@ -5,6 +5,8 @@
 pub(super) const DEFAULT_DOUBLE_CLICK_DELAY_MS: u32 = 250;
 /// Default duration for mouse button presses (100 milliseconds)
 pub(super) const DEFAULT_MOUSE_DURATION_MS: u32 = 100;
+/// Default time `hover_mouse` keeps the cursor in place (500 milliseconds)
+pub(super) const DEFAULT_HOVER_DWELL_MS: u32 = 500;
 /// Maximum duration for timed mouse button releases (60 seconds)
 pub(super) const MAX_MOUSE_DURATION_MS: u32 = 60_000;
 /// Minimum accepted `DragMouseRequest::frames` value.
@@ -6,9 +6,8 @@ use bevy::ecs::system::In;
 use bevy::math::Vec2;
 use bevy::prelude::*;
 use bevy::window::CursorMoved;
-use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -16,19 +15,22 @@ use serde_json::Value;
 use super::support;
 use super::support::EmptyParamsPolicy;
 use crate::constants::METHOD_MOVE_MOUSE;
+use crate::error::BrpExtrasError;
 
 // ============================================================================
 // Types
 // ============================================================================
 
 /// Request structure for `move_mouse`
-#[derive(Deserialize)]
-struct MoveMouseRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct MoveMouseRequest {
     /// Delta movement (mutually exclusive with position)
     #[serde(default)]
+    #[schemars(with = "Option<[f32; 2]>")]
     delta:    Option<Vec2>,
     /// Absolute position (mutually exclusive with delta)
     #[serde(default)]
+    #[schemars(with = "Option<[f32; 2]>")]
     position: Option<Vec2>,
     /// Target window entity (None = primary window)
     #[serde(default)]
@@ -110,19 +112,15 @@ pub(crate) fn move_mouse_handler(In(params): In<Option<Value>>, world: &mut Worl
 
     // Validate that exactly one of delta or position is provided
     if request.delta.is_none() && request.position.is_none() {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: "Must provide either 'delta' or 'position'".to_string(),
-            data:    None,
-        });
+        return Err(
+            BrpExtrasError::InvalidParams.error("Must provide either 'delta' or 'position'")
+        );
     }
 
     if request.delta.is_some() && request.position.is_some() {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: "Cannot provide both 'delta' and 'position'".to_string(),
-            data:    None,
-        });
+        return Err(
+            BrpExtrasError::InvalidParams.error("Cannot provide both 'delta' and 'position'")
+        );
     }
 
     // Resolve window entity
@@ -145,11 +143,9 @@ pub(crate) fn move_mouse_handler(In(params): In<Option<Value>>, world: &mut Worl
         (pos, pos - current_pos)
     } else {
         // Validation above already rejects this case
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: "Must provide either 'delta' or 'position'".to_string(),
-            data:    None,
-        });
+        return Err(
+            BrpExtrasError::InvalidParams.error("Must provide either 'delta' or 'position'")
+        );
     };
 
     // Update resource and send motion events
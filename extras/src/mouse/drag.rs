@@ -1,4 +1,8 @@
 //! Mouse drag operations with interpolation
+//!
+//! A drag follows the polyline from `start` through any `waypoints` to `end`, at a pace set by
+//! its easing curve. Some drag handlers react to velocity, so a drag that starts slowly and
+//! settles into its target behaves more like a hand on a mouse than a constant-speed line.
 
 use bevy::ecs::system::In;
 use bevy::input::ButtonState;
@@ -6,13 +10,15 @@ use bevy::input::mouse::MouseButton;
 use bevy::input::mouse::MouseButtonInput;
 use bevy::input::mouse::MouseMotion;
 use bevy::math::Vec2;
+use bevy::math::cubic_splines::CubicSegment;
+use bevy::math::curve::Curve;
+use bevy::math::curve::EaseFunction;
 use bevy::prelude::*;
 use bevy::window::CursorMoved;
 use bevy::window::WindowEvent;
 use bevy_kana::ToF32;
-use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -22,6 +28,7 @@ use super::cursor::SimulatedCursorPosition;
 use super::support;
 use super::support::EmptyParamsPolicy;
 use crate::constants::METHOD_DRAG_MOUSE;
+use crate::error::BrpExtrasError;
 
 // ============================================================================
 // Types
@@ -38,33 +45,80 @@ pub(super) enum DragState {
     Released,
 }
 
+/// Pace of a drag along its path
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum DragEasing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Start slowly, then speed up
+    EaseIn,
+    /// Start quickly, then settle into the end
+    EaseOut,
+    /// Start and end slowly
+    EaseInOut,
+    /// CSS-style `cubic-bezier(x1, y1, x2, y2)` timing curve; `x1` and `x2` must be in `0..=1`
+    CubicBezier([f32; 4]),
+}
+
+impl DragEasing {
+    /// Fraction of the path covered at `t`, the fraction of frames elapsed
+    fn ease(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => EaseFunction::CubicIn.sample_clamped(t),
+            Self::EaseOut => EaseFunction::CubicOut.sample_clamped(t),
+            Self::EaseInOut => EaseFunction::CubicInOut.sample_clamped(t),
+            Self::CubicBezier([x1, y1, x2, y2]) => {
+                CubicSegment::new_bezier_easing(Vec2::new(x1, y1), Vec2::new(x2, y2)).ease(t)
+            },
+        }
+    }
+}
+
 /// Request structure for `drag_mouse`
-#[derive(Deserialize)]
-struct DragMouseRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct DragMouseRequest {
     /// Button to hold during drag
-    button: MouseButton,
+    #[schemars(with = "String")]
+    button:    MouseButton,
     /// Starting position
-    start:  Vec2,
+    #[schemars(with = "[f32; 2]")]
+    start:     Vec2,
     /// Ending position
-    end:    Vec2,
+    #[schemars(with = "[f32; 2]")]
+    end:       Vec2,
+    /// Positions to pass through between `start` and `end`, in order
+    #[serde(default)]
+    #[schemars(with = "Vec<[f32; 2]>")]
+    waypoints: Vec<Vec2>,
+    /// Pace of the drag along its path (default: linear)
+    #[serde(default)]
+    easing:    DragEasing,
     /// Number of frames to interpolate over
-    frames: u32,
+    frames:    u32,
     /// Target window entity (None = primary window)
     #[serde(default)]
-    window: Option<u64>,
+    window:    Option<u64>,
 }
 
 /// Response structure for `drag_mouse`
 #[derive(Serialize)]
 struct DragMouseResponse {
     /// Button that was used for dragging
-    button: MouseButton,
+    button:    MouseButton,
     /// Starting position
-    start:  Vec2,
+    start:     Vec2,
     /// Ending position
-    end:    Vec2,
+    end:       Vec2,
+    /// Positions passed through between `start` and `end`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    waypoints: Vec<Vec2>,
+    /// Pace of the drag along its path
+    easing:    DragEasing,
     /// Number of frames for interpolation
-    frames: u32,
+    frames:    u32,
 }
 
 // ============================================================================
@@ -73,18 +127,18 @@ struct DragMouseResponse {
 
 /// Component for drag operations
 ///
-/// Manages multi-frame drag operations with linear interpolation between
-/// start and end positions. Runs a state machine: Pressed -> Dragging -> Released.
+/// Manages multi-frame drag operations that move along a path of positions at the pace of an
+/// easing curve. Runs a state machine: Pressed -> Dragging -> Released.
 #[derive(Component)]
 pub(super) struct DragOperation {
     /// Which button is pressed during drag
     pub button:        MouseButton,
     /// Which window to target (None = primary)
     pub window:        Option<Entity>,
-    /// Starting position
-    pub start:         Vec2,
-    /// Ending position
-    pub end:           Vec2,
+    /// Start position, waypoints, and end position
+    pub path:          Vec<Vec2>,
+    /// Pace of the drag along `path`
+    pub easing:        DragEasing,
     /// Total number of frames for the drag
     pub total_frames:  u32,
     /// Current frame index
@@ -103,37 +157,67 @@ pub(crate) fn drag_mouse_handler(In(params): In<Option<Value>>, world: &mut Worl
 
     // Validate frames
     if request.frames < MIN_DRAG_FRAMES {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: "Frames must be greater than 0".to_string(),
-            data:    None,
-        });
+        return Err(BrpExtrasError::InvalidParams.error("Frames must be greater than 0"));
+    }
+    if let DragEasing::CubicBezier([x1, _, x2, _]) = request.easing
+        && !((0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2))
+    {
+        return Err(
+            BrpExtrasError::InvalidParams.error("cubic_bezier x1 and x2 must be between 0 and 1")
+        );
     }
 
     let window = support::resolve_window(world, request.window)?;
 
     // Spawn drag operation component
+    let path = std::iter::once(request.start)
+        .chain(request.waypoints.iter().copied())
+        .chain(std::iter::once(request.end))
+        .collect();
     world.spawn(DragOperation {
-        button:        request.button,
-        window:        Some(window),
-        start:         request.start,
-        end:           request.end,
-        total_frames:  request.frames,
+        button: request.button,
+        window: Some(window),
+        path,
+        easing: request.easing,
+        total_frames: request.frames,
         current_frame: 0,
-        drag_state:    DragState::Pressed,
+        drag_state: DragState::Pressed,
     });
 
     support::serialize_response(
         DragMouseResponse {
-            button: request.button,
-            start:  request.start,
-            end:    request.end,
-            frames: request.frames,
+            button:    request.button,
+            start:     request.start,
+            end:       request.end,
+            waypoints: request.waypoints,
+            easing:    request.easing,
+            frames:    request.frames,
         },
         METHOD_DRAG_MOUSE,
     )
 }
 
+/// Position `fraction` of the way along the polyline `path`, measured by distance
+fn point_along(path: &[Vec2], fraction: f32) -> Vec2 {
+    let total: f32 = path.windows(2).map(|pair| pair[0].distance(pair[1])).sum();
+    let Some(&first) = path.first() else {
+        return Vec2::ZERO;
+    };
+    if total <= f32::EPSILON {
+        return first;
+    }
+
+    let mut remaining = fraction.clamp(0.0, 1.0) * total;
+    for pair in path.windows(2) {
+        let length = pair[0].distance(pair[1]);
+        if length > 0.0 && remaining <= length {
+            return pair[0].lerp(pair[1], remaining / length);
+        }
+        remaining -= length;
+    }
+    path.last().copied().unwrap_or(first)
+}
+
 // ============================================================================
 // Systems
 // ============================================================================
@@ -169,7 +253,8 @@ pub(super) fn process_drag_operations(
                 button_events.write(btn_event);
 
                 // Move cursor to start position
-                let delta = cursor_res.update_position(window, drag.start);
+                let start = point_along(&drag.path, 0.0);
+                let delta = cursor_res.update_position(window, start);
 
                 // Send motion events
                 let motion = MouseMotion { delta };
@@ -177,7 +262,7 @@ pub(super) fn process_drag_operations(
                 motion_events.write(motion);
                 let cursor = CursorMoved {
                     window,
-                    position: drag.start,
+                    position: start,
                     delta: Some(delta),
                 };
                 window_events.write(WindowEvent::from(cursor.clone()));
@@ -185,16 +270,16 @@ pub(super) fn process_drag_operations(
 
                 // Update `Window` component so `cursor_position()` works when unfocused
                 if let Ok(mut win) = windows.get_mut(window) {
-                    win.set_cursor_position(Some(drag.start));
+                    win.set_cursor_position(Some(start));
                 }
 
                 // Transition to dragging
                 drag.drag_state = DragState::Dragging;
             },
             DragState::Dragging => {
-                // Calculate interpolation factor
+                // Calculate interpolation factor, then how far along the path it puts the cursor
                 let t = drag.current_frame.to_f32() / drag.total_frames.to_f32();
-                let new_position = drag.start.lerp(drag.end, t);
+                let new_position = point_along(&drag.path, drag.easing.ease(t));
 
                 // Update position
                 let delta = cursor_res.update_position(window, new_position);
@@ -240,3 +325,45 @@ pub(super) fn process_drag_operations(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec2;
+
+    use super::DragEasing;
+    use super::point_along;
+
+    #[test]
+    fn point_along_follows_waypoints_by_distance() {
+        let path = [Vec2::ZERO, Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)];
+
+        assert_eq!(point_along(&path, 0.0), Vec2::ZERO);
+        assert_eq!(point_along(&path, 0.25), Vec2::new(50.0, 0.0));
+        assert_eq!(point_along(&path, 0.75), Vec2::new(100.0, 50.0));
+        assert_eq!(point_along(&path, 1.0), Vec2::new(100.0, 100.0));
+        assert_eq!(point_along(&[Vec2::ONE, Vec2::ONE], 0.5), Vec2::ONE);
+    }
+
+    #[test]
+    fn easing_starts_and_ends_on_the_path_ends() {
+        let easings = [
+            DragEasing::Linear,
+            DragEasing::EaseIn,
+            DragEasing::EaseOut,
+            DragEasing::EaseInOut,
+            DragEasing::CubicBezier([0.25, 0.1, 0.25, 1.0]),
+        ];
+        for easing in easings {
+            assert!(
+                easing.ease(0.0).abs() < 1e-3,
+                "{easing:?} should start at 0"
+            );
+            assert!(
+                (easing.ease(1.0) - 1.0).abs() < 1e-3,
+                "{easing:?} should end at 1"
+            );
+        }
+        assert!(DragEasing::EaseIn.ease(0.5) < 0.5);
+        assert!(DragEasing::EaseOut.ease(0.5) > 0.5);
+    }
+}
@@ -6,6 +6,7 @@ use bevy::input::gestures::PinchGesture;
 use bevy::input::gestures::RotationGesture;
 use bevy::prelude::*;
 use bevy_remote::BrpResult;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -22,8 +23,8 @@ use crate::window_event;
 // ============================================================================
 
 /// Request structure for `pinch_gesture`
-#[derive(Deserialize)]
-struct PinchGestureRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct PinchGestureRequest {
     /// Pinch delta (positive = zoom in, negative = zoom out)
     delta: f32,
 }
@@ -36,8 +37,8 @@ struct PinchGestureResponse {
 }
 
 /// Request structure for `rotation_gesture`
-#[derive(Deserialize)]
-struct RotationGestureRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct RotationGestureRequest {
     /// Rotation delta in radians
     delta: f32,
 }
@@ -50,8 +51,8 @@ struct RotationGestureResponse {
 }
 
 /// Request structure for `double_tap_gesture`
-#[derive(Deserialize)]
-struct DoubleTapGestureRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct DoubleTapGestureRequest {
     // No parameters needed
 }
 
@@ -0,0 +1,156 @@
+//! Hover with a dwell time
+//!
+//! Hover states and tooltips often wait for the pointer to rest over a widget for a while, and
+//! picking only refreshes what is under the pointer when the cursor reports a position. A hover
+//! moves the cursor once, then reports the same position every frame until its dwell time is up.
+
+use std::time::Duration;
+
+use bevy::ecs::system::In;
+use bevy::math::Vec2;
+use bevy::prelude::*;
+use bevy::window::CursorMoved;
+use bevy::window::WindowEvent;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::constants::DEFAULT_HOVER_DWELL_MS;
+use super::constants::MAX_MOUSE_DURATION_MS;
+use super::cursor::SimulatedCursorPosition;
+use super::support;
+use super::support::EmptyParamsPolicy;
+use crate::constants::METHOD_HOVER_MOUSE;
+use crate::error::BrpExtrasError;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `hover_mouse`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct HoverMouseRequest {
+    /// Position to hover over
+    #[schemars(with = "[f32; 2]")]
+    position: Vec2,
+    /// Milliseconds to keep the cursor there (default: 500ms, max: 60000ms)
+    #[serde(default)]
+    dwell_ms: Option<u32>,
+    /// Target window entity (None = primary window)
+    #[serde(default)]
+    window:   Option<u64>,
+}
+
+/// Response structure for `hover_mouse`
+#[derive(Serialize)]
+struct HoverMouseResponse {
+    /// Position the cursor rests at
+    position: Vec2,
+    /// Milliseconds the cursor keeps reporting that position
+    dwell_ms: u32,
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// Component for a hover in progress
+///
+/// Reports the cursor at `position` every frame until the timer expires, then despawns. A new
+/// hover on the same window replaces it.
+#[derive(Component)]
+pub(super) struct HoverOperation {
+    /// Window the cursor hovers over
+    window:   Entity,
+    /// Position the cursor rests at
+    position: Vec2,
+    /// Remaining dwell time
+    timer:    Timer,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `hover_mouse` BRP method
+pub(crate) fn hover_mouse_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: HoverMouseRequest = support::parse_request(params, EmptyParamsPolicy::Reject)?;
+    let dwell_ms = request.dwell_ms.unwrap_or(DEFAULT_HOVER_DWELL_MS);
+    if dwell_ms > MAX_MOUSE_DURATION_MS {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "Dwell {dwell_ms}ms exceeds maximum allowed dwell of {MAX_MOUSE_DURATION_MS}ms"
+            ),
+            json!({ "dwell_ms": dwell_ms, "max_dwell_ms": MAX_MOUSE_DURATION_MS }),
+        ));
+    }
+
+    let window = support::resolve_window(world, request.window)?;
+
+    // Replace any hover already holding the cursor on this window
+    let previous: Vec<Entity> = world
+        .query::<(Entity, &HoverOperation)>()
+        .iter(world)
+        .filter(|(_, hover)| hover.window == window)
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in previous {
+        world.despawn(entity);
+    }
+
+    let mut cursor_res = world.get_resource_or_init::<SimulatedCursorPosition>();
+    let delta = cursor_res.update_position(window, request.position);
+    cursor_res.last_window = Some(window);
+    support::send_motion_events(world, window, request.position, delta);
+
+    world.spawn(HoverOperation {
+        window,
+        position: request.position,
+        timer: Timer::new(Duration::from_millis(dwell_ms.into()), TimerMode::Once),
+    });
+
+    support::serialize_response(
+        HoverMouseResponse {
+            position: request.position,
+            dwell_ms,
+        },
+        METHOD_HOVER_MOUSE,
+    )
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// System that keeps reporting each hover's cursor position until its dwell time is up
+pub(super) fn process_hover_operations(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &mut HoverOperation)>,
+    mut cursor_events: MessageWriter<CursorMoved>,
+    mut window_events: MessageWriter<WindowEvent>,
+    mut windows: Query<&mut Window>,
+) {
+    for (entity, mut hover) in &mut query {
+        let cursor = CursorMoved {
+            window:   hover.window,
+            position: hover.position,
+            delta:    Some(Vec2::ZERO),
+        };
+        window_events.write(WindowEvent::from(cursor.clone()));
+        cursor_events.write(cursor);
+
+        // Keep `cursor_position()` on the hover position in case real input moved it
+        if let Ok(mut window) = windows.get_mut(hover.window) {
+            window.set_cursor_position(Some(hover.position));
+        }
+
+        hover.timer.tick(time.delta());
+        if hover.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
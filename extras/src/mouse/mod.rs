@@ -3,6 +3,8 @@
 //! This module provides comprehensive mouse input simulation including:
 //! - Cursor movement (delta and absolute positioning)
 //! - Mouse button presses (single click, double click)
+//! - Hovering in place for a dwell time
+//! - Clicking UI nodes by `Name` or entity (`ui` feature)
 //! - Drag operations with interpolation
 //! - Scroll wheel events
 //! - Trackpad gestures (pinch, rotation, double tap)
@@ -15,21 +17,44 @@ mod constants;
 mod cursor;
 mod drag;
 mod gestures;
+mod hover;
 mod scroll;
 mod support;
+#[cfg(feature = "ui")]
+mod ui_node;
 
 use bevy::prelude::*;
+use button::TimedButtonRelease;
+use click::ScheduledClick;
 use cursor::SimulatedCursorPosition;
+use drag::DragOperation;
+use hover::HoverOperation;
 
+pub(crate) use self::button::SendMouseButtonRequest;
 pub(crate) use self::button::send_mouse_button_handler;
+pub(crate) use self::click::ClickMouseRequest;
+pub(crate) use self::click::DoubleClickMouseRequest;
 pub(crate) use self::click::click_mouse_handler;
 pub(crate) use self::click::double_click_mouse_handler;
+pub(crate) use self::cursor::MoveMouseRequest;
 pub(crate) use self::cursor::move_mouse_handler;
+pub(crate) use self::drag::DragMouseRequest;
 pub(crate) use self::drag::drag_mouse_handler;
+pub(crate) use self::gestures::DoubleTapGestureRequest;
+pub(crate) use self::gestures::PinchGestureRequest;
+pub(crate) use self::gestures::RotationGestureRequest;
 pub(crate) use self::gestures::double_tap_gesture_handler;
 pub(crate) use self::gestures::pinch_gesture_handler;
 pub(crate) use self::gestures::rotation_gesture_handler;
+pub(crate) use self::hover::HoverMouseRequest;
+pub(crate) use self::hover::hover_mouse_handler;
+pub(crate) use self::scroll::ScrollMouseRequest;
 pub(crate) use self::scroll::scroll_mouse_handler;
+#[cfg(feature = "ui")]
+pub(crate) use self::ui_node::ClickUiNodeRequest;
+#[cfg(feature = "ui")]
+pub(crate) use self::ui_node::click_ui_node_handler;
+use crate::window_event;
 
 pub(super) struct MousePlugin;
 
@@ -40,5 +65,15 @@ impl Plugin for MousePlugin {
         app.add_systems(Update, button::process_timed_button_releases);
         app.add_systems(Update, click::process_scheduled_clicks);
         app.add_systems(Update, drag::process_drag_operations);
+        app.add_systems(Update, hover::process_hover_operations);
+        app.add_systems(
+            Last,
+            (
+                window_event::request_redraw_while_queued::<TimedButtonRelease>,
+                window_event::request_redraw_while_queued::<ScheduledClick>,
+                window_event::request_redraw_while_queued::<DragOperation>,
+                window_event::request_redraw_while_queued::<HoverOperation>,
+            ),
+        );
     }
 }
@@ -6,6 +6,7 @@ use bevy::input::mouse::MouseWheel;
 use bevy::input::touch::TouchPhase;
 use bevy::prelude::*;
 use bevy_remote::BrpResult;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -20,13 +21,14 @@ use crate::window_event;
 // ============================================================================
 
 /// Request structure for `scroll_mouse`
-#[derive(Deserialize)]
-struct ScrollMouseRequest {
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct ScrollMouseRequest {
     /// Horizontal scroll amount
     x:      f32,
     /// Vertical scroll amount
     y:      f32,
     /// Scroll unit
+    #[schemars(with = "String")]
     unit:   MouseScrollUnit,
     /// Target window entity (None = primary window)
     #[serde(default)]
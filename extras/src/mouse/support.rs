@@ -10,15 +10,15 @@ use bevy::window::CursorMoved;
 use bevy::window::PrimaryWindow;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
-use bevy_remote::error_codes::INVALID_PARAMS;
 use serde::Serialize;
-use serde_json::Map;
 use serde_json::Value;
+use serde_json::json;
 
 use super::button::TimedButtonRelease;
 use super::cursor::SimulatedCursorPosition;
-use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::error::BrpExtrasError;
+use crate::headless;
+use crate::support;
 use crate::window_event;
 
 /// Whether `parse_request` should accept `None` params by treating them as an empty object.
@@ -39,31 +39,16 @@ pub(super) enum EmptyParamsPolicy {
 ///   deserialization); `Reject` returns an error when params is None
 ///
 /// # Returns
-/// Parsed request struct or BRP error with `INVALID_PARAMS` code
+/// Parsed request struct, or a `MissingParams` or `InvalidParams` BRP error
 pub(super) fn parse_request<T: serde::de::DeserializeOwned>(
     params: Option<Value>,
     empty_params_policy: EmptyParamsPolicy,
 ) -> Result<T, BrpError> {
-    if matches!(empty_params_policy, EmptyParamsPolicy::Allow) && params.is_none() {
+    match empty_params_policy {
         // For requests with no required fields (e.g., `DoubleTapGestureRequest`)
-        return serde_json::from_value(Value::Object(Map::default())).map_err(|e| BrpError {
-            code:    INVALID_PARAMS,
-            message: format!("Failed to parse parameters: {e}"),
-            data:    None,
-        });
+        EmptyParamsPolicy::Allow => support::parse_optional(params),
+        EmptyParamsPolicy::Reject => support::parse_required(params),
     }
-
-    let params = params.ok_or_else(|| BrpError {
-        code:    INVALID_PARAMS,
-        message: MISSING_REQUEST_PARAMETERS_MESSAGE.to_string(),
-        data:    None,
-    })?;
-
-    serde_json::from_value(params).map_err(|e| BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Failed to parse parameters: {e}"),
-        data:    None,
-    })
 }
 
 /// Serialize BRP response with standardized error handling
@@ -75,15 +60,13 @@ pub(super) fn parse_request<T: serde::de::DeserializeOwned>(
 /// * `handler_name` - Name of the handler (for logging)
 ///
 /// # Returns
-/// Serialized JSON value or BRP error with `INTERNAL_ERROR` code
+/// Serialized JSON value or an `Internal` BRP error
 pub(super) fn serialize_response<T: Serialize>(response: T, handler_name: &str) -> BrpResult {
-    serde_json::to_value(response).map_err(|e| {
-        warn!("Failed to serialize {handler_name} response: {e}");
-        BrpError {
-            code:    INTERNAL_ERROR,
-            message: format!("Failed to serialize response: {e}"),
-            data:    None,
-        }
+    support::serialize_response(response).inspect_err(|error| {
+        warn!(
+            "Failed to serialize {handler_name} response: {}",
+            error.message
+        );
     })
 }
 
@@ -180,15 +163,15 @@ pub(super) fn resolve_window(
     world: &mut World,
     window_id: Option<u64>,
 ) -> Result<Entity, BrpError> {
+    headless::ensure_window(world)?;
     if let Some(id) = window_id {
         let entity = Entity::from_bits(id);
         // Verify entity exists and is a window
         if world.get_entity(entity).is_err() {
-            return Err(BrpError {
-                code:    INVALID_PARAMS,
-                message: format!("Invalid window entity: {id}"),
-                data:    None,
-            });
+            return Err(BrpExtrasError::WindowNotFound.with_details(
+                format!("Invalid window entity: {id}"),
+                json!({ "window": id }),
+            ));
         }
         return Ok(entity);
     }
@@ -207,9 +190,5 @@ pub(super) fn resolve_window(
         iter.next()
     };
 
-    entity.ok_or_else(|| BrpError {
-        code:    INVALID_PARAMS,
-        message: "No primary window found".to_string(),
-        data:    None,
-    })
+    entity.ok_or_else(|| BrpExtrasError::WindowNotFound.error("No primary window found"))
 }
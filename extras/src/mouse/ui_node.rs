@@ -0,0 +1,240 @@
+//! Click a Bevy UI node by `Name` or entity id
+//!
+//! UI tests that click hardcoded coordinates break whenever the layout moves. `click_ui_node`
+//! looks the node up instead, takes the center of its computed layout, and clicks there the same
+//! way `move_mouse` followed by `click_mouse` would.
+
+use bevy::camera::NormalizedRenderTarget;
+use bevy::camera::RenderTarget;
+use bevy::camera::visibility::InheritedVisibility;
+use bevy::ecs::system::In;
+use bevy::input::mouse::MouseButton;
+use bevy::math::Vec2;
+use bevy::prelude::*;
+use bevy::ui::ComputedNode;
+use bevy::ui::ComputedUiTargetCamera;
+use bevy::ui::UiGlobalTransform;
+use bevy::window::PrimaryWindow;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::constants::DEFAULT_MOUSE_DURATION_MS;
+use super::cursor::SimulatedCursorPosition;
+use super::support;
+use super::support::EmptyParamsPolicy;
+use crate::constants::METHOD_CLICK_UI_NODE;
+use crate::error::BrpExtrasError;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `click_ui_node`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct ClickUiNodeRequest {
+    /// `Name` of the UI node to click
+    #[serde(default)]
+    name:   Option<String>,
+    /// Entity id of the UI node to click, instead of `name`
+    #[serde(default)]
+    entity: Option<u64>,
+    /// Mouse button to click (default: Left)
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    button: Option<MouseButton>,
+}
+
+/// Response structure for `click_ui_node`
+#[derive(Serialize)]
+struct ClickUiNodeResponse {
+    /// The node that was clicked
+    entity:   u64,
+    /// The node's `Name`, when it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name:     Option<String>,
+    /// Window the click was sent to
+    window:   u64,
+    /// Center of the node in window logical pixels, where the cursor was moved
+    position: Vec2,
+    /// Button that was clicked
+    button:   MouseButton,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `click_ui_node` BRP method
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Neither or both of `name` and `entity` are given
+/// - No UI node, or more than one, has the name
+/// - The node is hidden, has no size yet, or its camera does not render to a window
+pub(crate) fn click_ui_node_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: ClickUiNodeRequest = support::parse_request(params, EmptyParamsPolicy::Reject)?;
+    let button = request.button.unwrap_or(MouseButton::Left);
+
+    let entity = match (request.name.as_deref(), request.entity) {
+        (Some(name), None) => find_named_node(world, name)?,
+        (None, Some(id)) => Entity::from_bits(id),
+        _ => {
+            return Err(
+                BrpExtrasError::InvalidParams.error("Provide exactly one of `name` or `entity`")
+            );
+        },
+    };
+    let (window, position) = node_center(world, entity)?;
+
+    let mut cursor_res = world.get_resource_or_init::<SimulatedCursorPosition>();
+    let delta = cursor_res.update_position(window, position);
+    cursor_res.last_window = Some(window);
+    support::send_motion_events(world, window, position, delta);
+    support::send_timed_button_press(world, button, window, DEFAULT_MOUSE_DURATION_MS);
+
+    support::serialize_response(
+        ClickUiNodeResponse {
+            entity: entity.to_bits(),
+            name: world
+                .get::<Name>(entity)
+                .map(|name| name.as_str().to_owned()),
+            window: window.to_bits(),
+            position,
+            button,
+        },
+        METHOD_CLICK_UI_NODE,
+    )
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The single UI node whose `Name` is `name`
+fn find_named_node(world: &mut World, name: &str) -> Result<Entity, BrpError> {
+    let matches: Vec<Entity> = world
+        .query_filtered::<(Entity, &Name), With<ComputedNode>>()
+        .iter(world)
+        .filter(|(_, node_name)| node_name.as_str() == name)
+        .map(|(entity, _)| entity)
+        .collect();
+
+    match matches.as_slice() {
+        [entity] => Ok(*entity),
+        [] => Err(BrpExtrasError::EntityNotFound.with_details(
+            format!("No UI node named '{name}'"),
+            json!({ "name": name }),
+        )),
+        _ => Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "{} UI nodes are named '{name}'; pass `entity` to pick one",
+                matches.len()
+            ),
+            json!({
+                "name": name,
+                "entities": matches.iter().map(|entity| entity.to_bits()).collect::<Vec<_>>(),
+            }),
+        )),
+    }
+}
+
+/// Window a UI node is rendered to, and the node's center in that window's logical pixels
+fn node_center(world: &World, entity: Entity) -> Result<(Entity, Vec2), BrpError> {
+    let (Some(computed_node), Some(ui_global_transform), Some(target_camera)) = (
+        world.get::<ComputedNode>(entity),
+        world.get::<UiGlobalTransform>(entity),
+        world.get::<ComputedUiTargetCamera>(entity),
+    ) else {
+        return Err(node_error(
+            BrpExtrasError::EntityNotFound,
+            entity,
+            "is not a laid-out UI node",
+        ));
+    };
+
+    if world
+        .get::<InheritedVisibility>(entity)
+        .is_some_and(|visibility| !visibility.get())
+    {
+        return Err(node_error(
+            BrpExtrasError::InvalidState,
+            entity,
+            "is hidden",
+        ));
+    }
+    let size = computed_node.size();
+    if !size.is_finite() || !size.cmpgt(Vec2::ZERO).all() {
+        return Err(node_error(
+            BrpExtrasError::InvalidState,
+            entity,
+            "has no computed size yet",
+        ));
+    }
+
+    let camera_entity = target_camera.get().ok_or_else(|| {
+        node_error(
+            BrpExtrasError::CameraUnavailable,
+            entity,
+            "has no UI target camera",
+        )
+    })?;
+    let (Some(camera), Some(render_target)) = (
+        world.get::<Camera>(camera_entity),
+        world.get::<RenderTarget>(camera_entity),
+    ) else {
+        return Err(node_error(
+            BrpExtrasError::CameraUnavailable,
+            entity,
+            "targets a camera that no longer exists",
+        ));
+    };
+    let primary_window = world
+        .iter_entities()
+        .find(EntityRef::contains::<PrimaryWindow>)
+        .map(|entity| entity.id());
+    let Some(NormalizedRenderTarget::Window(window_ref)) = render_target.normalize(primary_window)
+    else {
+        return Err(node_error(
+            BrpExtrasError::CameraUnavailable,
+            entity,
+            "is rendered to an image or texture, not a window",
+        ));
+    };
+    let window = window_ref.entity();
+    let Some(window_component) = world.get::<Window>(window) else {
+        return Err(node_error(
+            BrpExtrasError::WindowNotFound,
+            entity,
+            "is rendered to a window that no longer exists",
+        ));
+    };
+
+    // `UiGlobalTransform` holds the node center in physical pixels of the camera's viewport
+    let viewport_offset = camera
+        .physical_viewport_rect()
+        .map_or(Vec2::ZERO, |viewport| viewport.min.as_vec2());
+    let physical = ui_global_transform.affine().translation + viewport_offset;
+    let position = physical / window_component.scale_factor();
+    if !position.is_finite() {
+        return Err(node_error(
+            BrpExtrasError::Internal,
+            entity,
+            "produced a non-finite position",
+        ));
+    }
+
+    Ok((window, position))
+}
+
+fn node_error(kind: BrpExtrasError, entity: Entity, detail: &str) -> BrpError {
+    kind.with_details(
+        format!("UI node {} {detail}", entity.to_bits()),
+        json!({ "entity": entity.to_bits() }),
+    )
+}
@@ -0,0 +1,172 @@
+//! Named one-shot systems for BRP extras
+//!
+//! Apps expose custom game commands by registering one-shot systems under a name with
+//! [`BrpExtrasSystems::register_brp_system`]. `brp_extras/run_system_by_name` runs them through
+//! [`World::run_system`], so no new RPC handler is needed per command.
+
+use std::collections::BTreeMap;
+
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+/// Extends [`App`] with one-shot systems that `brp_extras/run_system_by_name` can run.
+pub trait BrpExtrasSystems {
+    /// Registers `system` as a one-shot system that BRP clients run by `name`.
+    ///
+    /// Registration can occur before or after `BrpExtrasPlugin` is added. The system runs
+    /// immediately when requested, with its commands applied before the response is sent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty after trimming or was already registered.
+    fn register_brp_system<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self;
+}
+
+impl BrpExtrasSystems for App {
+    fn register_brp_system<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        assert!(
+            !name.trim().is_empty(),
+            "BRP system name must not be empty after trimming"
+        );
+        self.init_resource::<NamedSystems>();
+        assert!(
+            !self
+                .world()
+                .resource::<NamedSystems>()
+                .0
+                .contains_key(&name),
+            "BRP system `{name}` is already registered"
+        );
+
+        let system_id = self.world_mut().register_system(system);
+        self.world_mut()
+            .resource_mut::<NamedSystems>()
+            .0
+            .insert(name, system_id);
+        self
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// One-shot systems registered for BRP, by name
+#[derive(Resource, Default)]
+pub(crate) struct NamedSystems(BTreeMap<String, SystemId>);
+
+/// Request structure for `run_system_by_name`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RunSystemByNameRequest {
+    /// Name the system was registered under with `App::register_brp_system`
+    name: String,
+}
+
+/// Response structure for `run_system_by_name`
+#[derive(Serialize)]
+struct RunSystemByNameResponse {
+    /// Name of the system that ran
+    name: String,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `run_system_by_name` BRP method
+///
+/// Runs the named one-shot system once.
+pub(crate) fn run_system_by_name_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: RunSystemByNameRequest = parse_required(params)?;
+
+    world.init_resource::<NamedSystems>();
+    let named_systems = world.resource::<NamedSystems>();
+    let Some(&system_id) = named_systems.0.get(&request.name) else {
+        let registered: Vec<&String> = named_systems.0.keys().collect();
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "No system is registered as '{}'. Register it with `App::register_brp_system`",
+                request.name
+            ),
+            json!({ "name": request.name, "registered": registered }),
+        ));
+    };
+
+    world.run_system(system_id).map_err(|error| {
+        BrpExtrasError::InvalidState.with_details(
+            format!("System '{}' could not run: {error}", request.name),
+            json!({ "name": request.name }),
+        )
+    })?;
+
+    serialize_response(RunSystemByNameResponse { name: request.name })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::BrpExtrasSystems;
+    use super::run_system_by_name_handler;
+
+    #[derive(Resource, Default)]
+    struct Score(u32);
+
+    #[test]
+    fn runs_registered_systems_by_name() {
+        let mut app = App::new();
+        app.init_resource::<Score>()
+            .register_brp_system("add_point", |mut score: ResMut<Score>| score.0 += 1);
+
+        let world = app.world_mut();
+        let handler = world.register_system(run_system_by_name_handler);
+        for _ in 0..2 {
+            world
+                .run_system_with(handler, Some(json!({ "name": "add_point" })))
+                .expect("handler runs")
+                .expect("handler succeeds");
+        }
+        assert_eq!(world.resource::<Score>().0, 2);
+
+        let unknown = world
+            .run_system_with(handler, Some(json!({ "name": "missing" })))
+            .expect("handler runs");
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "BRP system `reset` is already registered")]
+    fn duplicate_names_are_rejected() {
+        App::new()
+            .register_brp_system("reset", || {})
+            .register_brp_system("reset", || {});
+    }
+}
@@ -0,0 +1,470 @@
+//! Component override handlers for BRP extras
+//!
+//! `set_component_override` replaces a component's value through reflection and remembers the
+//! value it replaced, so experiments like "what happens if gravity is 0 for 5 seconds" don't need
+//! a manual restore. Overrides live on a stack in the [`ComponentOverrides`] resource: a timed
+//! override restores the original value when its timer runs out, and `clear_overrides` restores
+//! the matching overrides newest first.
+//!
+//! Overriding a component that is already overridden stacks: ending the newer override restores
+//! the older override's value, and ending the older one first hands its original value to the
+//! newer one, so the component always ends up back at the value it had before the first override.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistration;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::parse_required;
+use crate::support::resolve_entity;
+use crate::support::serialize_response;
+
+/// Plugin that expires timed component overrides
+pub(crate) struct OverridesPlugin;
+
+impl Plugin for OverridesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ComponentOverrides>();
+        app.add_systems(
+            PreUpdate,
+            expire_overrides.run_if(resource_exists::<Time<Real>>),
+        );
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `set_component_override`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetComponentOverrideRequest {
+    /// Entity whose component is overridden
+    entity:        u64,
+    /// Full type path of the component
+    component:     String,
+    /// Value to set, in the same format as `world.insert_components`
+    value:         Value,
+    /// Seconds until the original value is restored (None = until `clear_overrides`)
+    #[serde(default)]
+    duration_secs: Option<f32>,
+}
+
+/// Request structure for `clear_overrides`
+#[derive(Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ClearOverridesRequest {
+    /// Only clear overrides of this entity (None = every entity)
+    #[serde(default)]
+    entity:    Option<u64>,
+    /// Only clear overrides of this component type path (None = every component)
+    #[serde(default)]
+    component: Option<String>,
+}
+
+/// Active overrides, oldest first
+#[derive(Resource, Default)]
+struct ComponentOverrides {
+    next_id: u64,
+    stack:   Vec<ComponentOverride>,
+}
+
+/// A component value replaced by `set_component_override`
+struct ComponentOverride {
+    id:        u64,
+    entity:    Entity,
+    component: String,
+    /// Value before the override, or `None` if the entity did not have the component
+    original:  Option<Value>,
+    /// Runs out when the original should be restored; `None` until `clear_overrides`
+    timer:     Option<Timer>,
+}
+
+/// What ending an override did to the component
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum RestoreOutcome {
+    /// The original value was written back, or the component removed if it was absent
+    Restored,
+    /// A newer override of the same component is still active and now holds the original value
+    Superseded,
+    /// The entity no longer exists
+    EntityDespawned,
+}
+
+/// Response structure for `set_component_override`
+#[derive(Serialize)]
+struct SetComponentOverrideResponse {
+    override_id:      u64,
+    entity:           u64,
+    component:        String,
+    /// Value before the override; `null` if the entity did not have the component
+    original:         Option<Value>,
+    duration_secs:    Option<f32>,
+    active_overrides: usize,
+}
+
+/// One override ended by `clear_overrides`
+#[derive(Serialize)]
+struct ClearedOverride {
+    override_id: u64,
+    entity:      u64,
+    component:   String,
+    outcome:     RestoreOutcome,
+}
+
+/// Response structure for `clear_overrides`
+#[derive(Serialize)]
+struct ClearOverridesResponse {
+    cleared:          Vec<ClearedOverride>,
+    active_overrides: usize,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `set_component_override` BRP method
+///
+/// Writes the new value and pushes an override holding the value it replaced.
+pub(crate) fn set_component_override_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SetComponentOverrideRequest = parse_required(params)?;
+    let timer = request
+        .duration_secs
+        .map(|duration_secs| {
+            if duration_secs.is_finite() && duration_secs > 0.0 {
+                Ok(Timer::from_seconds(duration_secs, TimerMode::Once))
+            } else {
+                Err(BrpExtrasError::InvalidParams.with_details(
+                    format!("duration_secs must be a finite, positive number, got {duration_secs}"),
+                    json!({ "duration_secs": duration_secs }),
+                ))
+            }
+        })
+        .transpose()?;
+    let entity = resolve_entity(world, request.entity)?;
+
+    let original = read_component(world, entity, &request.component)?;
+    write_component(world, entity, &request.component, Some(&request.value))?;
+
+    world.init_resource::<ComponentOverrides>();
+    let mut overrides = world.resource_mut::<ComponentOverrides>();
+    overrides.next_id += 1;
+    let override_id = overrides.next_id;
+    overrides.stack.push(ComponentOverride {
+        id: override_id,
+        entity,
+        component: request.component.clone(),
+        original: original.clone(),
+        timer,
+    });
+
+    serialize_response(SetComponentOverrideResponse {
+        override_id,
+        entity: request.entity,
+        component: request.component,
+        original,
+        duration_secs: request.duration_secs,
+        active_overrides: overrides.stack.len(),
+    })
+}
+
+/// Handler for `clear_overrides` BRP method
+///
+/// Ends every override matching the optional `entity` and `component` filters, newest first.
+pub(crate) fn clear_overrides_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: ClearOverridesRequest = parse_optional(params)?;
+
+    world.init_resource::<ComponentOverrides>();
+    let ended = take_overrides(world, |override_| {
+        request
+            .entity
+            .is_none_or(|entity| override_.entity.to_bits() == entity)
+            && request
+                .component
+                .as_ref()
+                .is_none_or(|component| override_.component == *component)
+    });
+
+    let mut cleared = Vec::with_capacity(ended.len());
+    for ended in ended.into_iter().rev() {
+        let (override_id, entity) = (ended.id, ended.entity.to_bits());
+        let component = ended.component.clone();
+        cleared.push(ClearedOverride {
+            override_id,
+            entity,
+            component,
+            outcome: end_override(world, ended)?,
+        });
+    }
+
+    serialize_response(ClearOverridesResponse {
+        cleared,
+        active_overrides: world.resource::<ComponentOverrides>().stack.len(),
+    })
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Restore the original values of timed overrides whose timer finished
+fn expire_overrides(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta();
+    tick_overrides(world, delta);
+    let expired = take_overrides(world, |override_| {
+        override_.timer.as_ref().is_some_and(Timer::is_finished)
+    });
+
+    for ended in expired.into_iter().rev() {
+        let (id, component) = (ended.id, ended.component.clone());
+        if let Err(error) = end_override(world, ended) {
+            warn!(
+                "Failed to restore '{component}' after override {id}: {}",
+                error.message
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn tick_overrides(world: &mut World, delta: Duration) {
+    let Some(mut overrides) = world.get_resource_mut::<ComponentOverrides>() else {
+        return;
+    };
+    for timer in overrides
+        .stack
+        .iter_mut()
+        .filter_map(|override_| override_.timer.as_mut())
+    {
+        timer.tick(delta);
+    }
+}
+
+/// Remove the overrides matching `predicate` from the stack, oldest first
+fn take_overrides(
+    world: &mut World,
+    predicate: impl Fn(&ComponentOverride) -> bool,
+) -> Vec<ComponentOverride> {
+    let Some(mut overrides) = world.get_resource_mut::<ComponentOverrides>() else {
+        return Vec::new();
+    };
+    let (taken, kept) = std::mem::take(&mut overrides.stack)
+        .into_iter()
+        .partition(|override_| predicate(override_));
+    overrides.stack = kept;
+    taken
+}
+
+/// Restore the value an override replaced, or hand it to a newer override of the same component
+///
+/// `ended` must already be removed from the stack.
+fn end_override(world: &mut World, ended: ComponentOverride) -> Result<RestoreOutcome, BrpError> {
+    if let Some(mut overrides) = world.get_resource_mut::<ComponentOverrides>()
+        && let Some(newer) = overrides.stack.iter_mut().find(|override_| {
+            override_.id > ended.id
+                && override_.entity == ended.entity
+                && override_.component == ended.component
+        })
+    {
+        newer.original = ended.original;
+        return Ok(RestoreOutcome::Superseded);
+    }
+
+    if world.get_entity(ended.entity).is_err() {
+        return Ok(RestoreOutcome::EntityDespawned);
+    }
+    write_component(
+        world,
+        ended.entity,
+        &ended.component,
+        ended.original.as_ref(),
+    )?;
+    Ok(RestoreOutcome::Restored)
+}
+
+fn resolve_component<'a>(
+    registry: &'a TypeRegistry,
+    component: &str,
+) -> Result<(&'a TypeRegistration, &'a ReflectComponent), BrpError> {
+    registry
+        .get_with_type_path(component)
+        .and_then(|registration| {
+            registration
+                .data::<ReflectComponent>()
+                .map(|reflect_component| (registration, reflect_component))
+        })
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("'{component}' is not a registered component with reflect(Component)"),
+                json!({ "component": component }),
+            )
+        })
+}
+
+/// Current value of `component` on `entity`, or `None` if the entity does not have it
+fn read_component(
+    world: &World,
+    entity: Entity,
+    component: &str,
+) -> Result<Option<Value>, BrpError> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let (_, reflect_component) = resolve_component(&type_registry, component)?;
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return Ok(None);
+    };
+    reflect_component
+        .reflect(entity_ref)
+        .map(|value| {
+            serde_json::to_value(TypedReflectSerializer::new(
+                value.as_partial_reflect(),
+                &type_registry,
+            ))
+        })
+        .transpose()
+        .map_err(|error| {
+            BrpExtrasError::Internal.error(format!("Failed to serialize '{component}': {error}"))
+        })
+}
+
+/// Insert `value` as `component` on `entity`, or remove the component when `value` is `None`
+fn write_component(
+    world: &mut World,
+    entity: Entity,
+    component: &str,
+    value: Option<&Value>,
+) -> Result<(), BrpError> {
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let (registration, reflect_component) = resolve_component(&type_registry, component)?;
+    let reflected = value
+        .map(|value| {
+            TypedReflectDeserializer::new(registration, &type_registry)
+                .deserialize(value)
+                .map_err(|error| {
+                    BrpExtrasError::InvalidParams.with_details(
+                        format!("Failed to deserialize '{component}': {error}"),
+                        json!({ "component": component, "value": value }),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let mut entity_mut = world.get_entity_mut(entity).map_err(|_| {
+        BrpExtrasError::EntityNotFound.with_details(
+            format!("Entity {} does not exist", entity.to_bits()),
+            json!({ PARAM_ENTITY: entity.to_bits() }),
+        )
+    })?;
+    match reflected {
+        Some(reflected) => reflect_component.insert(
+            &mut entity_mut,
+            reflected.as_partial_reflect(),
+            &type_registry,
+        ),
+        None => reflect_component.remove(&mut entity_mut),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::app::App;
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::OverridesPlugin;
+    use super::clear_overrides_handler;
+    use super::set_component_override_handler;
+
+    const NAME_TYPE_PATH: &str = "bevy_ecs::name::Name";
+
+    fn name(app: &App, entity: Entity) -> Option<&str> {
+        app.world().get::<Name>(entity).map(Name::as_str)
+    }
+
+    #[test]
+    fn timed_override_restores_the_original_value() {
+        let mut app = App::new();
+        app.register_type::<Name>();
+        app.init_resource::<Time<Real>>();
+        app.add_plugins(OverridesPlugin);
+        let entity = app.world_mut().spawn(Name::new("original")).id();
+
+        let response = set_component_override_handler(
+            In(Some(json!({
+                "entity": entity.to_bits(),
+                "component": NAME_TYPE_PATH,
+                "value": "overridden",
+                "duration_secs": 1.0,
+            }))),
+            app.world_mut(),
+        )
+        .expect("a reflected component should be overridden");
+        assert_eq!(response["original"], "original");
+        assert_eq!(name(&app, entity), Some("overridden"));
+
+        let mut time = app.world_mut().resource_mut::<Time<Real>>();
+        // The first update only starts the clock
+        time.update_with_duration(Duration::ZERO);
+        time.update_with_duration(Duration::from_millis(1100));
+        app.update();
+        assert_eq!(name(&app, entity), Some("original"));
+    }
+
+    #[test]
+    fn clearing_stacked_overrides_restores_the_first_original() {
+        let mut app = App::new();
+        app.register_type::<Name>();
+        let entity = app.world_mut().spawn(Name::new("original")).id();
+
+        for value in ["first", "second"] {
+            set_component_override_handler(
+                In(Some(json!({
+                    "entity": entity.to_bits(),
+                    "component": NAME_TYPE_PATH,
+                    "value": value,
+                }))),
+                app.world_mut(),
+            )
+            .expect("a reflected component should be overridden");
+        }
+        assert_eq!(name(&app, entity), Some("second"));
+
+        let response =
+            clear_overrides_handler(In(None), app.world_mut()).expect("overrides should clear");
+        assert_eq!(response["cleared"][0]["outcome"], "restored");
+        assert_eq!(response["active_overrides"], 0);
+        assert_eq!(name(&app, entity), Some("original"));
+    }
+}
@@ -0,0 +1,604 @@
+//! Physics velocity, contact, and gravity handlers for BRP extras
+//!
+//! Avian and `bevy_rapier` keep velocities, contacts, and gravity in their own types, so setting a
+//! velocity over `world.mutate_components` means knowing which engine the app uses and the full
+//! type path of its velocity component. `set_linear_velocity`, `get_contacts`, and `set_gravity`
+//! find the engine's types in the type registry instead. Extras does not depend on either engine:
+//! types are recognized by crate and type name, and are reached through reflection, which both
+//! engines register.
+
+use bevy::math::DVec2;
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy::reflect::PartialReflect;
+use bevy::reflect::ReflectPath;
+use bevy::reflect::ReflectRef;
+use bevy::reflect::TypeRegistration;
+use bevy::reflect::TypeRegistry;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::missing_component;
+use crate::support::parse_required;
+use crate::support::resolve_entity;
+use crate::support::serialize_response;
+
+/// Components that hold a rigid body's linear velocity, and the path to the vector inside them
+const VELOCITY_COMPONENTS: &[PhysicsComponent] = &[
+    PhysicsComponent {
+        backend:    PhysicsBackend::Avian,
+        crate_name: "avian",
+        type_name:  "LinearVelocity",
+        field_path: ".0",
+    },
+    PhysicsComponent {
+        backend:    PhysicsBackend::Rapier,
+        crate_name: "bevy_rapier",
+        type_name:  "Velocity",
+        field_path: ".linvel",
+    },
+];
+
+/// Components that list the entities a collider is touching
+///
+/// `bevy_rapier` has none: its contacts live in the `RapierContext`, not on entities.
+const CONTACT_COMPONENTS: &[PhysicsComponent] = &[PhysicsComponent {
+    backend:    PhysicsBackend::Avian,
+    crate_name: "avian",
+    type_name:  "CollidingEntities",
+    field_path: "",
+}];
+
+/// Types that hold the world's gravity
+///
+/// Avian's `Gravity` is a resource, which is stored on its own entity. `bevy_rapier` keeps gravity
+/// in `RapierConfiguration`, a component on each physics context entity.
+const GRAVITY_COMPONENTS: &[PhysicsComponent] = &[
+    PhysicsComponent {
+        backend:    PhysicsBackend::Avian,
+        crate_name: "avian",
+        type_name:  "Gravity",
+        field_path: ".0",
+    },
+    PhysicsComponent {
+        backend:    PhysicsBackend::Rapier,
+        crate_name: "bevy_rapier",
+        type_name:  "RapierConfiguration",
+        field_path: ".gravity",
+    },
+];
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Physics engine a component belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PhysicsBackend {
+    Avian,
+    Rapier,
+}
+
+/// A physics engine component recognized by crate and type name
+struct PhysicsComponent {
+    backend:    PhysicsBackend,
+    /// Prefix of the crate name, so 2D and 3D crates both match
+    crate_name: &'static str,
+    /// Type name without its module path
+    type_name:  &'static str,
+    /// Reflect path to the value inside the component
+    field_path: &'static str,
+}
+
+/// Request structure for `set_linear_velocity`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetLinearVelocityRequest {
+    /// Rigid body entity
+    entity:   u64,
+    /// New linear velocity: `[x, y]` for 2D engines, `[x, y, z]` for 3D engines
+    velocity: Vec<f64>,
+}
+
+/// Request structure for `get_contacts`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetContactsRequest {
+    /// Collider entity
+    entity: u64,
+}
+
+/// Request structure for `set_gravity`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetGravityRequest {
+    /// New gravity: `[x, y]` for 2D engines, `[x, y, z]` for 3D engines
+    gravity: Vec<f64>,
+}
+
+/// Response structure for `set_linear_velocity`
+#[derive(Serialize)]
+struct SetLinearVelocityResponse {
+    entity:    u64,
+    backend:   PhysicsBackend,
+    /// Full type path of the component that was changed
+    component: String,
+    /// The velocity now on the component
+    velocity:  Value,
+}
+
+/// Response structure for `get_contacts`
+#[derive(Serialize)]
+struct GetContactsResponse {
+    entity:    u64,
+    backend:   PhysicsBackend,
+    /// Full type path of the component the contacts were read from
+    component: String,
+    /// Entities the collider is touching, sorted by ID
+    contacts:  Vec<u64>,
+}
+
+/// Response structure for `set_gravity`
+#[derive(Serialize)]
+struct SetGravityResponse {
+    backend:   PhysicsBackend,
+    /// Full type path of the resource or component that was changed
+    component: String,
+    /// The gravity now in effect
+    gravity:   Value,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `set_linear_velocity` BRP method
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed
+/// - Neither Avian's `LinearVelocity` nor `bevy_rapier`'s `Velocity` is registered
+/// - The entity does not exist or has no velocity component
+/// - `velocity` does not have one value per axis of the engine's vectors
+pub(crate) fn set_linear_velocity_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SetLinearVelocityRequest = parse_required(params)?;
+    let entity = resolve_entity(world, request.entity)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let (physics_component, registration, reflect_component) =
+        find_component(&type_registry, VELOCITY_COMPONENTS, "velocity")?;
+    let type_path = registration.type_info().type_path().to_string();
+
+    let mut component = reflect_component
+        .reflect_mut(world.entity_mut(entity))
+        .ok_or_else(|| missing_component(request.entity, &type_path))?;
+    let target = physics_component
+        .field_path
+        .reflect_element_mut(component.as_partial_reflect_mut())
+        .map_err(|error| {
+            BrpExtrasError::Unsupported.error(format!(
+                "'{type_path}' has no value at '{}': {error}",
+                physics_component.field_path
+            ))
+        })?;
+    let velocity = set_vector(target, &request.velocity).map_err(|expected| {
+        BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "'{type_path}' holds {expected}, got {} velocity values",
+                request.velocity.len()
+            ),
+            json!({ "velocity": request.velocity, "component": type_path }),
+        )
+    })?;
+
+    serialize_response(SetLinearVelocityResponse {
+        entity: request.entity,
+        backend: physics_component.backend,
+        component: type_path,
+        velocity,
+    })
+}
+
+/// Handler for `get_contacts` BRP method
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed
+/// - Avian's `CollidingEntities` is not registered, as with `bevy_rapier`
+/// - The entity does not exist or has no `CollidingEntities`
+pub(crate) fn get_contacts_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: GetContactsRequest = parse_required(params)?;
+    let entity = resolve_entity(world, request.entity)?;
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let (physics_component, registration, reflect_component) =
+        find_component(&type_registry, CONTACT_COMPONENTS, "contact")?;
+    let type_path = registration.type_info().type_path().to_string();
+
+    let component = reflect_component
+        .reflect(world.entity(entity))
+        .ok_or_else(|| missing_component(request.entity, &type_path))?;
+    let mut entities = Vec::new();
+    collect_entities(component.as_partial_reflect(), &mut entities);
+    let mut contacts: Vec<u64> = entities.into_iter().map(Entity::to_bits).collect();
+    contacts.sort_unstable();
+    contacts.dedup();
+
+    serialize_response(GetContactsResponse {
+        entity: request.entity,
+        backend: physics_component.backend,
+        component: type_path,
+        contacts,
+    })
+}
+
+/// Handler for `set_gravity` BRP method
+///
+/// # Errors
+///
+/// Returns `BrpError` if:
+/// - Request parameters are missing or malformed
+/// - Neither Avian's `Gravity` nor `bevy_rapier`'s `RapierConfiguration` is registered
+/// - The engine's plugin has not inserted its gravity yet
+/// - `gravity` does not have one value per axis of the engine's vectors
+pub(crate) fn set_gravity_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: SetGravityRequest = parse_required(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let (physics_component, registration, reflect_component) =
+        find_component(&type_registry, GRAVITY_COMPONENTS, "gravity")?;
+    let type_path = registration.type_info().type_path().to_string();
+
+    // Avian's resource lives on the one entity the world tracks for it; `bevy_rapier` has a
+    // configuration on every physics context
+    let component_id = world.components().get_id(registration.type_id());
+    let entities: Vec<Entity> = match component_id {
+        None => Vec::new(),
+        Some(component_id) if registration.data::<ReflectResource>().is_some() => world
+            .resource_entities()
+            .get(component_id)
+            .into_iter()
+            .collect(),
+        Some(component_id) => QueryBuilder::<Entity>::new(world)
+            .with_id(component_id)
+            .build()
+            .iter(world)
+            .collect(),
+    };
+    if entities.is_empty() {
+        return Err(BrpExtrasError::InvalidState.with_details(
+            format!("'{type_path}' is registered but not in the world; add the physics plugin"),
+            json!({ "component": type_path }),
+        ));
+    }
+
+    let mut gravity = Value::Null;
+    for entity in entities {
+        let Some(mut component) = reflect_component.reflect_mut(world.entity_mut(entity)) else {
+            continue;
+        };
+        let target = physics_component
+            .field_path
+            .reflect_element_mut(component.as_partial_reflect_mut())
+            .map_err(|error| {
+                BrpExtrasError::Unsupported.error(format!(
+                    "'{type_path}' has no value at '{}': {error}",
+                    physics_component.field_path
+                ))
+            })?;
+        gravity = set_vector(target, &request.gravity).map_err(|expected| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!(
+                    "'{type_path}' holds {expected}, got {} gravity values",
+                    request.gravity.len()
+                ),
+                json!({ "gravity": request.gravity, "component": type_path }),
+            )
+        })?;
+    }
+
+    serialize_response(SetGravityResponse {
+        backend: physics_component.backend,
+        component: type_path,
+        gravity,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The first registered component from `candidates` that can be reflected on entities
+///
+/// Reflected resources qualify too: `ReflectResource` registers `ReflectComponent` for the entity
+/// that stores the resource.
+fn find_component<'a>(
+    registry: &'a TypeRegistry,
+    candidates: &'static [PhysicsComponent],
+    kind: &str,
+) -> Result<
+    (
+        &'static PhysicsComponent,
+        &'a TypeRegistration,
+        &'a ReflectComponent,
+    ),
+    BrpError,
+> {
+    registry
+        .iter()
+        .find_map(|registration| {
+            let type_path_table = registration.type_info().type_path_table();
+            let physics_component = candidates.iter().find(|candidate| {
+                type_path_table.short_path() == candidate.type_name
+                    && type_path_table
+                        .crate_name()
+                        .is_some_and(|crate_name| crate_name.starts_with(candidate.crate_name))
+            })?;
+            let reflect_component = registration.data::<ReflectComponent>()?;
+            Some((physics_component, registration, reflect_component))
+        })
+        .ok_or_else(|| {
+            let expected: Vec<String> = candidates
+                .iter()
+                .map(|candidate| format!("{}::{}", candidate.crate_name, candidate.type_name))
+                .collect();
+            BrpExtrasError::Unsupported.with_details(
+                format!(
+                    "No supported physics {kind} component is registered; expected one of: {}",
+                    expected.join(", ")
+                ),
+                json!({ "expected": expected }),
+            )
+        })
+}
+
+/// Set a 2D or 3D vector of either precision, returning the new value. Fails with a description
+/// of the vector when `values` has the wrong length or the target is not a vector.
+fn set_vector(target: &mut dyn PartialReflect, values: &[f64]) -> Result<Value, &'static str> {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "single-precision engines store vectors as f32"
+    )]
+    let single = |value: f64| value as f32;
+
+    if let Some(vector) = target.try_downcast_mut::<Vec3>() {
+        let [x, y, z] = values else {
+            return Err("a 3D vector");
+        };
+        *vector = Vec3::new(single(*x), single(*y), single(*z));
+        return Ok(json!(vector));
+    }
+    if let Some(vector) = target.try_downcast_mut::<Vec2>() {
+        let [x, y] = values else {
+            return Err("a 2D vector");
+        };
+        *vector = Vec2::new(single(*x), single(*y));
+        return Ok(json!(vector));
+    }
+    if let Some(vector) = target.try_downcast_mut::<DVec3>() {
+        let [x, y, z] = values else {
+            return Err("a 3D vector");
+        };
+        *vector = DVec3::new(*x, *y, *z);
+        return Ok(json!(vector));
+    }
+    if let Some(vector) = target.try_downcast_mut::<DVec2>() {
+        let [x, y] = values else {
+            return Err("a 2D vector");
+        };
+        *vector = DVec2::new(*x, *y);
+        return Ok(json!(vector));
+    }
+    Err("a value that is not a Vec2, Vec3, DVec2, or DVec3")
+}
+
+/// Every `Entity` inside a reflected value, in whatever collection the engine keeps them
+fn collect_entities(value: &dyn PartialReflect, entities: &mut Vec<Entity>) {
+    if let Some(entity) = value.try_downcast_ref::<Entity>() {
+        entities.push(*entity);
+        return;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(fields) => {
+            fields
+                .iter_fields()
+                .for_each(|(_, field)| collect_entities(field, entities));
+        },
+        ReflectRef::TupleStruct(fields) => {
+            fields
+                .iter_fields()
+                .for_each(|field| collect_entities(field, entities));
+        },
+        ReflectRef::Tuple(fields) => {
+            fields
+                .iter_fields()
+                .for_each(|field| collect_entities(field, entities));
+        },
+        ReflectRef::List(items) => items
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Array(items) => items
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Set(items) => items
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Map(entries) => {
+            for (key, value) in entries.iter() {
+                collect_entities(key, entities);
+                collect_entities(value, entities);
+            }
+        },
+        ReflectRef::Enum(variant) => {
+            for field in variant.iter_fields() {
+                collect_entities(field.value(), entities);
+            }
+        },
+        ReflectRef::Opaque(_) => {},
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::get_contacts_handler;
+    use super::set_gravity_handler;
+    use super::set_linear_velocity_handler;
+    use crate::error::BrpExtrasError;
+
+    /// Stands in for Avian's velocity component, which is found by crate and type name
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    #[type_path = "avian3d::dynamics::rigid_body"]
+    struct LinearVelocity(Vec3);
+
+    /// Stands in for Avian's gravity resource
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    #[type_path = "avian3d::dynamics::integrator"]
+    struct Gravity(Vec3);
+
+    /// Stands in for `bevy_rapier`'s per-context configuration
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    #[type_path = "bevy_rapier3d::plugin::configuration"]
+    struct RapierConfiguration {
+        gravity: Vec3,
+    }
+
+    /// Stands in for Avian's contact list
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    #[type_path = "avian3d::collision"]
+    struct CollidingEntities(Vec<Entity>);
+
+    #[test]
+    fn velocity_is_set_on_the_engine_component() {
+        let mut app = App::new();
+        app.register_type::<LinearVelocity>();
+        let body = app.world_mut().spawn(LinearVelocity::default()).id();
+
+        let response = set_linear_velocity_handler(
+            In(Some(
+                json!({ "entity": body.to_bits(), "velocity": [1.0, 2.0, 3.0] }),
+            )),
+            app.world_mut(),
+        )
+        .expect("the velocity should be set");
+
+        assert_eq!(response["backend"], "avian");
+        assert_eq!(response["velocity"], json!([1.0, 2.0, 3.0]));
+        assert_eq!(
+            app.world()
+                .get::<LinearVelocity>(body)
+                .map(|velocity| velocity.0),
+            Some(Vec3::new(1.0, 2.0, 3.0))
+        );
+
+        let error = set_linear_velocity_handler(
+            In(Some(
+                json!({ "entity": body.to_bits(), "velocity": [1.0, 2.0] }),
+            )),
+            app.world_mut(),
+        )
+        .expect_err("a 2D velocity does not fit a 3D engine");
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+    }
+
+    #[test]
+    fn contacts_are_listed_and_missing_engines_are_unsupported() {
+        let mut app = App::new();
+        let other = app.world_mut().spawn_empty().id();
+        let collider = app.world_mut().spawn(CollidingEntities(vec![other])).id();
+
+        let error = get_contacts_handler(
+            In(Some(json!({ "entity": collider.to_bits() }))),
+            app.world_mut(),
+        )
+        .expect_err("no physics engine component is registered yet");
+        assert_eq!(error.code, BrpExtrasError::Unsupported.code());
+
+        app.register_type::<CollidingEntities>();
+        let response = get_contacts_handler(
+            In(Some(json!({ "entity": collider.to_bits() }))),
+            app.world_mut(),
+        )
+        .expect("contacts should be read");
+        assert_eq!(response["contacts"], json!([other.to_bits()]));
+    }
+
+    #[test]
+    fn gravity_is_set_on_the_engine_resource() {
+        let mut app = App::new();
+        let error = set_gravity_handler(
+            In(Some(json!({ "gravity": [0.0, -1.0, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect_err("no physics engine is registered yet");
+        assert_eq!(error.code, BrpExtrasError::Unsupported.code());
+
+        app.register_type::<Gravity>();
+        let error = set_gravity_handler(
+            In(Some(json!({ "gravity": [0.0, -1.0, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect_err("the engine has not inserted its gravity yet");
+        assert_eq!(error.code, BrpExtrasError::InvalidState.code());
+
+        app.init_resource::<Gravity>();
+        let response = set_gravity_handler(
+            In(Some(json!({ "gravity": [0.0, -1.0, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect("the gravity should be set");
+        assert_eq!(response["backend"], "avian");
+        assert_eq!(response["gravity"], json!([0.0, -1.0, 0.0]));
+        assert_eq!(app.world().resource::<Gravity>().0, Vec3::NEG_Y);
+    }
+
+    #[test]
+    fn gravity_is_set_on_every_rapier_context() {
+        let mut app = App::new();
+        app.register_type::<RapierConfiguration>();
+        let contexts = [
+            app.world_mut().spawn(RapierConfiguration::default()).id(),
+            app.world_mut().spawn(RapierConfiguration::default()).id(),
+        ];
+
+        let response = set_gravity_handler(
+            In(Some(json!({ "gravity": [0.0, -9.81, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect("the gravity should be set");
+
+        assert_eq!(response["backend"], "rapier");
+        for context in contexts {
+            assert_eq!(
+                app.world()
+                    .get::<RapierConfiguration>(context)
+                    .map(|configuration| configuration.gravity),
+                Some(Vec3::new(0.0, -9.81, 0.0))
+            );
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Entity picking handler for BRP extras
+//!
+//! `pick_entity` answers "what is under this point of the window" the way `bevy_picking`'s mesh
+//! backend would: it builds a ray from the camera rendering that part of the window and casts it
+//! with `MeshRayCast`, so agents can check what a `click_mouse` at the same position will hit.
+
+use bevy::camera::NormalizedRenderTarget;
+use bevy::camera::RenderTarget;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::picking::mesh_picking::ray_cast::MeshRayCast;
+use bevy::picking::mesh_picking::ray_cast::MeshRayCastSettings;
+use bevy::picking::mesh_picking::ray_cast::RayMeshHit;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_CAMERA;
+use crate::error::BrpExtrasError;
+use crate::projection;
+use crate::support::parse_required;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `pick_entity`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct PickEntityRequest {
+    /// Horizontal window position in logical pixels from the left edge
+    x:      f32,
+    /// Vertical window position in logical pixels from the top edge
+    y:      f32,
+    /// Target window entity (None = primary window)
+    #[serde(default)]
+    window: Option<u64>,
+    /// Camera to cast from (None = the highest-order camera whose viewport contains the point)
+    #[serde(default)]
+    camera: Option<u64>,
+}
+
+/// The nearest mesh the ray hit
+#[derive(Serialize)]
+struct PickHit {
+    entity:   u64,
+    /// The entity's `Name`, if it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name:     Option<String>,
+    /// World-space hit position
+    point:    [f32; 3],
+    /// World-space surface normal at the hit
+    normal:   [f32; 3],
+    /// Distance from the ray origin
+    distance: f32,
+}
+
+/// World-space ray cast through the requested point
+#[derive(Serialize)]
+struct PickRay {
+    origin:    [f32; 3],
+    direction: [f32; 3],
+}
+
+/// Response structure for `pick_entity`
+#[derive(Serialize)]
+struct PickEntityResponse {
+    /// Camera the ray was cast from
+    camera: u64,
+    ray:    PickRay,
+    /// Nearest hit, or `None` when the ray hit no mesh
+    hit:    Option<PickHit>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `pick_entity` BRP method
+///
+/// Casts a ray from a camera through a window position and returns the nearest mesh it hits.
+pub(crate) fn pick_entity_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: PickEntityRequest = parse_required(params)?;
+    let position = Vec2::new(request.x, request.y);
+    let window = resolve_window(world, request.window)?;
+    let camera = match request.camera {
+        Some(camera) => requested_camera(world, camera, window, position)?,
+        None => camera_at(world, window, position)?,
+    };
+    let ray = projection::window_ray(world, camera, position)?;
+
+    let hit = world
+        .run_system_once_with(nearest_hit, ray)
+        .map_err(|error| {
+            BrpExtrasError::Unsupported.error(format!(
+                "Mesh ray casting is unavailable, the app may not have mesh assets: {error}"
+            ))
+        })?
+        .map(|(entity, hit)| PickHit {
+            entity:   entity.to_bits(),
+            name:     world
+                .get::<Name>(entity)
+                .map(|name| name.as_str().to_string()),
+            point:    hit.point.to_array(),
+            normal:   hit.normal.to_array(),
+            distance: hit.distance,
+        });
+
+    serde_json::to_value(PickEntityResponse {
+        camera: camera.to_bits(),
+        ray: PickRay {
+            origin:    ray.origin.to_array(),
+            direction: ray.direction.to_array(),
+        },
+        hit,
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn nearest_hit(In(ray): In<Ray3d>, mut ray_cast: MeshRayCast) -> Option<(Entity, RayMeshHit)> {
+    ray_cast
+        .cast_ray(ray, &MeshRayCastSettings::default())
+        .first()
+        .cloned()
+}
+
+fn resolve_window(world: &mut World, window: Option<u64>) -> Result<Entity, BrpError> {
+    if let Some(id) = window {
+        let entity = Entity::from_bits(id);
+        if world.get::<Window>(entity).is_none() {
+            return Err(BrpExtrasError::WindowNotFound.with_details(
+                format!("Invalid window entity: {id}"),
+                json!({ "window": id }),
+            ));
+        }
+        return Ok(entity);
+    }
+
+    let mut query = world.query_filtered::<Entity, With<PrimaryWindow>>();
+    query
+        .iter(world)
+        .next()
+        .ok_or_else(|| BrpExtrasError::WindowNotFound.error("No primary window found"))
+}
+
+/// Whether `camera` is active, renders to `window`, and its viewport contains `position`
+fn renders_point(world: &World, camera: Entity, window: Entity, position: Vec2) -> bool {
+    let (Some(camera_component), Some(target)) = (
+        world.get::<Camera>(camera),
+        world.get::<RenderTarget>(camera),
+    ) else {
+        return false;
+    };
+    let primary_window = world
+        .iter_entities()
+        .find(EntityRef::contains::<PrimaryWindow>)
+        .map(|entity| entity.id());
+
+    camera_component.is_active
+        && matches!(
+            target.normalize(primary_window),
+            Some(NormalizedRenderTarget::Window(window_ref)) if window_ref.entity() == window
+        )
+        && camera_component
+            .logical_viewport_rect()
+            .is_some_and(|viewport| viewport.contains(position))
+}
+
+fn requested_camera(
+    world: &World,
+    camera: u64,
+    window: Entity,
+    position: Vec2,
+) -> Result<Entity, BrpError> {
+    let entity = Entity::from_bits(camera);
+    if renders_point(world, entity, window, position) {
+        return Ok(entity);
+    }
+    Err(BrpExtrasError::CameraUnavailable.with_details(
+        format!(
+            "Camera {camera} is missing, inactive, or does not render ({}, {}) of window {}",
+            position.x,
+            position.y,
+            window.to_bits()
+        ),
+        json!({ PARAM_CAMERA: camera }),
+    ))
+}
+
+/// The highest-order camera rendering `position` of `window`, as `bevy_picking` would choose
+fn camera_at(world: &mut World, window: Entity, position: Vec2) -> Result<Entity, BrpError> {
+    let mut query = world.query::<(Entity, &Camera)>();
+    let mut cameras: Vec<(Entity, isize)> = query
+        .iter(world)
+        .map(|(entity, camera)| (entity, camera.order))
+        .collect();
+    cameras.retain(|(entity, _)| renders_point(world, *entity, window, position));
+    cameras.sort_by_key(|(entity, order)| (*order, entity.to_bits()));
+
+    cameras.pop().map(|(entity, _)| entity).ok_or_else(|| {
+        BrpExtrasError::CameraUnavailable.with_details(
+            format!(
+                "No active camera renders ({}, {}) of window {}",
+                position.x,
+                position.y,
+                window.to_bits()
+            ),
+            json!({ "window": window.to_bits(), "x": position.x, "y": position.y }),
+        )
+    })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::camera::ComputedCameraValues;
+    use bevy::camera::RenderTarget;
+    use bevy::camera::RenderTargetInfo;
+    use bevy::prelude::*;
+    use bevy::window::PrimaryWindow;
+    use bevy::window::WindowRef;
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::pick_entity_handler;
+    use crate::error::BrpExtrasError;
+
+    fn spawn_window_camera(app: &mut App, order: isize) -> Entity {
+        app.world_mut()
+            .spawn((
+                Camera {
+                    order,
+                    computed: ComputedCameraValues {
+                        clip_from_view: Mat4::IDENTITY,
+                        target_info: Some(RenderTargetInfo {
+                            physical_size: UVec2::new(100, 100),
+                            scale_factor:  1.0,
+                        }),
+                        ..default()
+                    },
+                    ..default()
+                },
+                RenderTarget::Window(WindowRef::Primary),
+                GlobalTransform::IDENTITY,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn casts_from_the_highest_order_camera_and_reports_a_miss() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>();
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        spawn_window_camera(&mut app, 0);
+        let overlay = spawn_window_camera(&mut app, 1);
+
+        let response =
+            pick_entity_handler(In(Some(json!({ "x": 50.0, "y": 50.0 }))), app.world_mut())
+                .expect("an empty scene should still cast a ray");
+
+        assert_eq!(response["camera"], overlay.to_bits());
+        assert_eq!(response["hit"], Value::Null);
+        assert_eq!(response["ray"]["direction"], json!([0.0, 0.0, -1.0]));
+    }
+
+    #[test]
+    fn a_point_outside_every_viewport_has_no_camera() {
+        let mut app = App::new();
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        spawn_window_camera(&mut app, 0);
+
+        let error =
+            pick_entity_handler(In(Some(json!({ "x": 500.0, "y": 50.0 }))), app.world_mut())
+                .expect_err("the point is outside the 100x100 viewport");
+
+        assert_eq!(error.code, BrpExtrasError::CameraUnavailable.code());
+    }
+}
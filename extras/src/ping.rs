@@ -0,0 +1,105 @@
+//! Health check handler for BRP extras
+//!
+//! `ping` answers without touching any component data, so clients can cheaply confirm that the
+//! app is alive and find out which `bevy_brp_extras` build and features it runs.
+
+use bevy::diagnostic::FrameCount;
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::constants::SUPPORTED_BEVY_VERSION;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Response structure for `ping`
+#[derive(Serialize)]
+struct PingResponse {
+    /// Version of `bevy_brp_extras` compiled into the app
+    plugin_version: &'static str,
+    /// Bevy version this `bevy_brp_extras` release is built for
+    bevy_version:   &'static str,
+    /// Enabled `bevy_brp_extras` cargo features
+    features:       Vec<&'static str>,
+    /// Seconds since the app's first update
+    uptime_secs:    f64,
+    /// Frames rendered so far, when `FrameCount` is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame_count:    Option<u32>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `ping` BRP method
+///
+/// Returns the plugin and Bevy versions, enabled features, and uptime. Parameters are ignored.
+pub(crate) fn ping_handler(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
+    serialize_response(PingResponse {
+        plugin_version: env!("CARGO_PKG_VERSION"),
+        bevy_version:   SUPPORTED_BEVY_VERSION,
+        features:       enabled_features(),
+        uptime_secs:    world
+            .get_resource::<Time<Real>>()
+            .map_or(0.0, Time::elapsed_secs_f64),
+        frame_count:    world.get_resource::<FrameCount>().map(|count| count.0),
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "diagnostics") {
+        features.push("diagnostics");
+    }
+    if cfg!(feature = "gizmos") {
+        features.push("gizmos");
+    }
+    if cfg!(feature = "light") {
+        features.push("light");
+    }
+    if cfg!(feature = "physics") {
+        features.push("physics");
+    }
+    if cfg!(feature = "picking") {
+        features.push("picking");
+    }
+    if cfg!(feature = "text") {
+        features.push("text");
+    }
+    if cfg!(feature = "ui") {
+        features.push("ui");
+    }
+    features
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::In;
+
+    use super::ping_handler;
+
+    #[test]
+    fn reports_the_plugin_version() {
+        let mut app = App::new();
+
+        let response = ping_handler(In(None), app.world_mut()).expect("ping should serialize");
+
+        assert_eq!(response["plugin_version"], env!("CARGO_PKG_VERSION"));
+        assert!(response["features"].is_array());
+        assert!(response.get("frame_count").is_none());
+    }
+}
@@ -1,53 +1,151 @@
 //! Plugin implementation for extra BRP methods
 
-#[cfg(not(target_arch = "wasm32"))]
 use std::sync::Mutex;
 
 #[cfg(feature = "diagnostics")]
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+use bevy::ecs::system::BoxedSystem;
 use bevy::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::window::PrimaryWindow;
+use bevy_remote::BrpResult;
 use bevy_remote::RemoteMethodSystemId;
 use bevy_remote::RemoteMethods;
 use bevy_remote::RemotePlugin;
 #[cfg(not(target_arch = "wasm32"))]
 use bevy_remote::http::RemoteHttpPlugin;
+use serde_json::Value;
 
 #[cfg(not(target_arch = "wasm32"))]
 use super::DEFAULT_REMOTE_PORT;
 use super::agent_tools;
 use super::agent_tools::RegisteredAgentTools;
+use super::assets;
+use super::camera;
+use super::capabilities;
+use super::change_detection;
 #[cfg(not(target_arch = "wasm32"))]
 use super::constants::BRP_EXTRAS_PORT_ENV_VAR;
 use super::constants::EXTRAS_COMMAND_PREFIX;
 use super::constants::METHOD_AGENT_TOOLS;
+use super::constants::METHOD_CAPABILITIES;
+use super::constants::METHOD_CLEAR_OVERRIDES;
 use super::constants::METHOD_CLICK_MOUSE;
+#[cfg(feature = "ui")]
+use super::constants::METHOD_CLICK_UI_NODE;
+use super::constants::METHOD_DESPAWN_RECURSIVE;
+#[cfg(feature = "diagnostics")]
+use super::constants::METHOD_DIAGNOSTICS_WATCH;
 use super::constants::METHOD_DOUBLE_CLICK_MOUSE;
 use super::constants::METHOD_DOUBLE_TAP_GESTURE;
 use super::constants::METHOD_DRAG_MOUSE;
+#[cfg(feature = "gizmos")]
+use super::constants::METHOD_DRAW_GIZMO;
+use super::constants::METHOD_EVENTS_WATCH;
+use super::constants::METHOD_GET_ASSET_INFO;
+use super::constants::METHOD_GET_COMPONENT_RAW;
+#[cfg(feature = "physics")]
+use super::constants::METHOD_GET_CONTACTS;
 #[cfg(feature = "diagnostics")]
 use super::constants::METHOD_GET_DIAGNOSTICS;
+use super::constants::METHOD_GET_SCHEDULE_GRAPH;
+#[cfg(feature = "text")]
+use super::constants::METHOD_GET_VISIBLE_TEXT;
+use super::constants::METHOD_GET_WORLD_STATS;
+#[cfg(feature = "gizmos")]
+use super::constants::METHOD_HIGHLIGHT_ENTITY;
+use super::constants::METHOD_HOVER_MOUSE;
+use super::constants::METHOD_LIST_ASSETS;
+use super::constants::METHOD_LIST_SYSTEMS;
+use super::constants::METHOD_LOAD_WORLD_SNAPSHOT;
+use super::constants::METHOD_LOOK_AT;
 use super::constants::METHOD_MOVE_MOUSE;
+use super::constants::METHOD_MUTATE_REFLECT;
+use super::constants::METHOD_ORBIT_CAMERA;
+#[cfg(feature = "picking")]
+use super::constants::METHOD_PICK_ENTITY;
 use super::constants::METHOD_PINCH_GESTURE;
+use super::constants::METHOD_PING;
+use super::constants::METHOD_PRESS_KEYS;
+use super::constants::METHOD_QUERY_CHANGED;
+use super::constants::METHOD_QUERY_SPATIAL;
+use super::constants::METHOD_RELEASE_KEYS;
+use super::constants::METHOD_RELOAD_ASSET;
 use super::constants::METHOD_ROTATION_GESTURE;
+use super::constants::METHOD_RUN_INPUT_SCRIPT;
+use super::constants::METHOD_RUN_SYSTEM_BY_NAME;
+use super::constants::METHOD_SAVE_WORLD_SNAPSHOT;
+use super::constants::METHOD_SCREEN_TO_WORLD;
 use super::constants::METHOD_SCREENSHOT;
 use super::constants::METHOD_SCROLL_MOUSE;
+use super::constants::METHOD_SEND_EVENT;
+use super::constants::METHOD_SEND_IME;
 use super::constants::METHOD_SEND_KEYS;
 use super::constants::METHOD_SEND_MOUSE_BUTTON;
+#[cfg(feature = "light")]
+use super::constants::METHOD_SET_AMBIENT_LIGHT;
+use super::constants::METHOD_SET_CAMERA_TRANSFORM;
+use super::constants::METHOD_SET_CLEAR_COLOR;
+use super::constants::METHOD_SET_COMPONENT_OVERRIDE;
+#[cfg(feature = "physics")]
+use super::constants::METHOD_SET_GRAVITY;
+#[cfg(feature = "physics")]
+use super::constants::METHOD_SET_LINEAR_VELOCITY;
+use super::constants::METHOD_SET_WINDOW;
 use super::constants::METHOD_SET_WINDOW_TITLE;
 use super::constants::METHOD_SHUTDOWN;
+use super::constants::METHOD_START_INPUT_RECORDING;
+use super::constants::METHOD_STOP_INPUT_RECORDING;
 use super::constants::METHOD_TYPE_TEXT;
+use super::constants::METHOD_WAIT_FOR;
+use super::constants::METHOD_WORLD_TO_SCREEN;
+use super::despawn;
 #[cfg(feature = "diagnostics")]
 use super::diagnostics;
+use super::environment;
+use super::events;
+use super::events::EventWatchPlugin;
+use super::frame_sync;
+use super::frame_sync::FrameSyncPlugin;
+#[cfg(feature = "gizmos")]
+use super::gizmo;
+#[cfg(feature = "gizmos")]
+use super::gizmo::DebugGizmoPlugin;
+#[cfg(feature = "gizmos")]
+use super::highlight;
+#[cfg(feature = "gizmos")]
+use super::highlight::HighlightPlugin;
+use super::input_recording;
+use super::input_recording::InputRecordingPlugin;
+use super::input_script;
+use super::input_script::InputScriptPlugin;
 use super::keyboard;
 use super::keyboard::KeyboardPlugin;
 use super::mouse;
 use super::mouse::MousePlugin;
+use super::named_systems;
+use super::overrides;
+use super::overrides::OverridesPlugin;
+#[cfg(feature = "physics")]
+use super::physics;
+#[cfg(feature = "picking")]
+use super::picking;
+use super::ping;
+use super::projection;
+use super::reflection;
+use super::schedules;
 use super::screenshot;
 use super::screenshot::ScreenshotPlugin;
 use super::shutdown;
-use super::window_title;
+use super::spatial;
+#[cfg(feature = "text")]
+use super::visible_text;
+use super::wait_for;
+use super::wait_for::WaitForPlugin;
+use super::window;
+use super::window::WindowTitlePlugin;
+use super::world_snapshot;
+use super::world_stats;
 
 // ---------------------------------------------------------------------------
 // Port display configuration
@@ -113,6 +211,21 @@ impl HasEffectivePort for PortConfigured {
     fn is_explicit(&self) -> bool { true }
 }
 
+// ---------------------------------------------------------------------------
+// Custom methods
+// ---------------------------------------------------------------------------
+
+/// A downstream BRP method added with [`BrpExtrasPlugin::with_method`]
+struct CustomMethod {
+    name:    String,
+    /// Taken when the plugin is built
+    handler: Mutex<Option<BoxedSystem<In<Option<Value>>, BrpResult>>>,
+}
+
+/// Names of the methods added with [`BrpExtrasPlugin::with_method`], listed by `capabilities`
+#[derive(Resource, Default)]
+pub(crate) struct CustomMethods(pub(crate) Vec<String>);
+
 // ---------------------------------------------------------------------------
 // Plugin struct and const shorthand
 // ---------------------------------------------------------------------------
@@ -122,8 +235,33 @@ impl HasEffectivePort for PortConfigured {
 /// Currently provides:
 /// - `brp_extras/screenshot`: Capture screenshots
 /// - `brp_extras/shutdown`: Gracefully shutdown the app
+/// - `brp_extras/ping`: Report plugin version, features, and uptime
+/// - `brp_extras/capabilities`: List registered extras methods with their parameter schemas
 /// - `brp_extras/send_keys`: Send keyboard input
+/// - `brp_extras/press_keys`, `release_keys`: Hold keys down across requests
+/// - `brp_extras/send_ime`: Send IME composition events
 /// - `brp_extras/set_window_title`: Change the window title
+/// - `brp_extras/set_window`: Change window resolution, position, mode, and other properties
+/// - `brp_extras/set_clear_color`: Set the app-wide or one camera's clear color
+/// - `brp_extras/set_ambient_light`: Set the app-wide or one camera's ambient light (`light`
+///   feature)
+/// - `brp_extras/get_diagnostics`, `diagnostics_watch`: Read and stream diagnostics (`diagnostics`
+///   feature)
+/// - `brp_extras/list_assets`, `get_asset_info`, `reload_asset`: Inspect and reload assets
+/// - `brp_extras/query_spatial`: Find entities near a point or inside a box
+/// - `brp_extras/pick_entity`: Find the mesh under a window position (`picking` feature)
+/// - `brp_extras/click_ui_node`: Click a UI node found by `Name` or entity (`ui` feature)
+/// - `brp_extras/get_visible_text`: List visible `Text` and `Text2d` with screen rects (`text`
+///   feature)
+/// - `brp_extras/world_to_screen`, `screen_to_world`: Convert between world and window positions
+/// - `brp_extras/set_camera_transform`, `look_at`, `orbit_camera`: Place and aim a camera
+/// - `brp_extras/set_linear_velocity`, `get_contacts`, `set_gravity`: Set velocities, list
+///   contacts, and set gravity of Avian or `bevy_rapier` worlds (`physics` feature)
+/// - `brp_extras/draw_gizmo`: Draw temporary lines, spheres, boxes, and labels (`gizmos` feature)
+/// - `brp_extras/highlight_entity`: Outline an entity for a few seconds (`gizmos` feature)
+/// - `brp_extras/events_watch`, `send_event`: Stream and write registered messages
+/// - `brp_extras/run_system_by_name`: Run a one-shot system registered under a name
+/// - `brp_extras/wait_for`: Answer once a component, resource, or diagnostic condition holds
 ///
 /// On native targets, this also adds `RemoteHttpPlugin` for HTTP transport.
 /// On WASM, only the methods are registered - you need to add your own
@@ -163,9 +301,10 @@ pub const BrpExtrasPlugin: BrpExtrasPlugin = BrpExtrasPlugin::new();
 /// The `HttpConfig` type parameter controls how HTTP transport is configured.
 /// See the [module-level documentation](struct@BrpExtrasPlugin) for usage examples.
 pub struct BrpExtrasPlugin<HttpConfig = Unconfigured> {
-    http_config:  HttpConfig,
+    http_config:    HttpConfig,
     #[cfg(not(target_arch = "wasm32"))]
-    port_display: Option<PortDisplay>,
+    port_display:   Option<PortDisplay>,
+    custom_methods: Vec<CustomMethod>,
 }
 
 impl Default for BrpExtrasPlugin<Unconfigured> {
@@ -182,6 +321,7 @@ impl BrpExtrasPlugin<Unconfigured> {
             http_config:                                      Unconfigured,
             #[cfg(not(target_arch = "wasm32"))]
             port_display:                                     None,
+            custom_methods:                                   Vec::new(),
         }
     }
 
@@ -195,8 +335,9 @@ impl BrpExtrasPlugin<Unconfigured> {
     #[must_use]
     pub const fn with_port(port: u16) -> BrpExtrasPlugin<PortConfigured> {
         BrpExtrasPlugin {
-            http_config:  PortConfigured(port),
-            port_display: None,
+            http_config:    PortConfigured(port),
+            port_display:   None,
+            custom_methods: Vec::new(),
         }
     }
 
@@ -213,12 +354,63 @@ impl BrpExtrasPlugin<Unconfigured> {
         plugin: RemoteHttpPlugin,
     ) -> BrpExtrasPlugin<HttpPluginConfigured> {
         BrpExtrasPlugin {
-            http_config:  HttpPluginConfigured(Mutex::new(Some(plugin))),
-            port_display: None,
+            http_config:    HttpPluginConfigured(Mutex::new(Some(plugin))),
+            port_display:   None,
+            custom_methods: Vec::new(),
         }
     }
 }
 
+impl<H> BrpExtrasPlugin<H> {
+    /// Register a downstream BRP method alongside the built-in ones.
+    ///
+    /// `handler` is a system like the built-in handlers: it takes the request's
+    /// `In<Option<Value>>` params and returns a [`BrpResult`], so it can report errors with
+    /// [`BrpExtrasError`](crate::BrpExtrasError) and is listed by `brp_extras/capabilities`.
+    /// A method already in `RemoteMethods` under the same name is replaced, with a warning.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// fn spawn_wave(In(params): In<Option<Value>>, mut commands: Commands) -> BrpResult {
+    ///     // ...
+    ///     Ok(json!({ "spawned": 12 }))
+    /// }
+    ///
+    /// App::new().add_plugins((
+    ///     DefaultPlugins,
+    ///     BrpExtrasPlugin::default().with_method("my_game/spawn_wave", spawn_wave),
+    /// ));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty after trimming or starts with the reserved `brp_extras/`
+    /// prefix.
+    #[must_use]
+    pub fn with_method<M>(
+        mut self,
+        name: impl Into<String>,
+        handler: impl IntoSystem<In<Option<Value>>, BrpResult, M>,
+    ) -> Self {
+        let name = name.into();
+        assert!(
+            !name.trim().is_empty(),
+            "BRP method name must not be empty after trimming"
+        );
+        assert!(
+            !name.starts_with(EXTRAS_COMMAND_PREFIX),
+            "BRP method `{name}` rejected: the `{EXTRAS_COMMAND_PREFIX}` prefix is reserved for \
+             built-in methods"
+        );
+        self.custom_methods.push(CustomMethod {
+            name,
+            handler: Mutex::new(Some(Box::new(IntoSystem::into_system(handler)))),
+        });
+        self
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Port resolution
 // ---------------------------------------------------------------------------
@@ -296,7 +488,7 @@ impl Plugin for BrpExtrasPlugin<Unconfigured> {
             maybe_add_port_title_system(app, &self.http_config, self.port_display);
         }
 
-        build_shared(app);
+        build_shared(app, &self.custom_methods);
     }
 }
 
@@ -305,7 +497,7 @@ impl Plugin for BrpExtrasPlugin<PortConfigured> {
     fn build(&self, app: &mut App) {
         add_managed_http_transport(app, Some(self.http_config.0));
         maybe_add_port_title_system(app, &self.http_config, self.port_display);
-        build_shared(app);
+        build_shared(app, &self.custom_methods);
     }
 }
 
@@ -320,7 +512,7 @@ impl Plugin for BrpExtrasPlugin<HttpPluginConfigured> {
             .and_then(|mut guard| guard.take())
         else {
             error!("failed to retrieve `RemoteHttpPlugin` configuration");
-            build_shared(app);
+            build_shared(app, &self.custom_methods);
             return;
         };
 
@@ -334,7 +526,7 @@ impl Plugin for BrpExtrasPlugin<HttpPluginConfigured> {
             app.add_plugins(plugin);
         }
 
-        build_shared(app);
+        build_shared(app, &self.custom_methods);
     }
 }
 
@@ -343,7 +535,7 @@ impl Plugin for BrpExtrasPlugin<HttpPluginConfigured> {
 // ---------------------------------------------------------------------------
 
 /// Common plugin setup shared across all HTTP configuration states.
-fn build_shared(app: &mut App) {
+fn build_shared(app: &mut App, custom_methods: &[CustomMethod]) {
     app.init_resource::<RegisteredAgentTools>();
 
     // Add `RemotePlugin` if not already present
@@ -353,6 +545,7 @@ fn build_shared(app: &mut App) {
 
     // Register extras methods into the existing `RemoteMethods` resource
     register_extras_methods(app.world_mut());
+    register_custom_methods(app.world_mut(), custom_methods);
 
     // Defensively add `FrameTimeDiagnosticsPlugin` if not already installed
     #[cfg(feature = "diagnostics")]
@@ -362,7 +555,16 @@ fn build_shared(app: &mut App) {
 
     app.add_plugins(KeyboardPlugin);
     app.add_plugins(MousePlugin);
+    app.add_plugins(InputScriptPlugin);
+    app.add_plugins(InputRecordingPlugin);
     app.add_plugins(ScreenshotPlugin);
+    app.add_plugins(WindowTitlePlugin);
+    app.add_plugins(OverridesPlugin);
+    app.add_plugins(EventWatchPlugin);
+    app.add_plugins(FrameSyncPlugin);
+    app.add_plugins(WaitForPlugin);
+    #[cfg(feature = "gizmos")]
+    app.add_plugins((DebugGizmoPlugin, HighlightPlugin));
 
     // Add the system to handle deferred shutdown
     app.add_systems(Update, shutdown::deferred_shutdown_system);
@@ -404,17 +606,73 @@ fn add_managed_http_transport(app: &mut App, configured_port: Option<u16>) {
     });
 }
 
+/// Register the methods added with [`BrpExtrasPlugin::with_method`] into `RemoteMethods`.
+fn register_custom_methods(world: &mut World, custom_methods: &[CustomMethod]) {
+    world.init_resource::<CustomMethods>();
+    for custom_method in custom_methods {
+        let Some(handler) = custom_method
+            .handler
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.take())
+        else {
+            error!(
+                "failed to retrieve the handler of BRP method `{}`",
+                custom_method.name
+            );
+            continue;
+        };
+
+        let system_id = RemoteMethodSystemId::Instant(world.register_boxed_system(handler));
+        let mut remote_methods = world.resource_mut::<RemoteMethods>();
+        if remote_methods.get(&custom_method.name).is_some() {
+            warn!(
+                "BRP method `{}` is already registered and will be replaced by the handler \
+                 given to `BrpExtrasPlugin::with_method()`",
+                custom_method.name
+            );
+        }
+        remote_methods.insert(custom_method.name.clone(), system_id);
+        world
+            .resource_mut::<CustomMethods>()
+            .0
+            .push(custom_method.name.clone());
+    }
+}
+
 /// Register all extras BRP methods into the world's `RemoteMethods` resource.
+#[allow(
+    clippy::too_many_lines,
+    reason = "one registration entry per extras method"
+)]
 fn register_extras_methods(world: &mut World) {
     let methods = vec![
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_AGENT_TOOLS}"),
             RemoteMethodSystemId::Instant(world.register_system(agent_tools::catalog_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_CAPABILITIES}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(capabilities::capabilities_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_CLEAR_OVERRIDES}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(overrides::clear_overrides_handler),
+            ),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_CLICK_MOUSE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::click_mouse_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_DESPAWN_RECURSIVE}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(despawn::despawn_recursive_handler),
+            ),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_DOUBLE_CLICK_MOUSE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::double_click_mouse_handler)),
@@ -427,18 +685,126 @@ fn register_extras_methods(world: &mut World) {
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_DRAG_MOUSE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::drag_mouse_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_EVENTS_WATCH}"),
+            RemoteMethodSystemId::Watching(world.register_system(events::events_watch_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_ASSET_INFO}"),
+            RemoteMethodSystemId::Instant(world.register_system(assets::get_asset_info_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_COMPONENT_RAW}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(reflection::get_component_raw_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_SCHEDULE_GRAPH}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(schedules::get_schedule_graph_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_WORLD_STATS}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(world_stats::get_world_stats_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_HOVER_MOUSE}"),
+            RemoteMethodSystemId::Instant(world.register_system(mouse::hover_mouse_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_LIST_ASSETS}"),
+            RemoteMethodSystemId::Instant(world.register_system(assets::list_assets_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_LIST_SYSTEMS}"),
+            RemoteMethodSystemId::Instant(world.register_system(schedules::list_systems_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_LOAD_WORLD_SNAPSHOT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(world_snapshot::load_world_snapshot_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_LOOK_AT}"),
+            RemoteMethodSystemId::Instant(world.register_system(camera::look_at_handler)),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_MOVE_MOUSE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::move_mouse_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_MUTATE_REFLECT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(reflection::mutate_reflect_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_ORBIT_CAMERA}"),
+            RemoteMethodSystemId::Instant(world.register_system(camera::orbit_camera_handler)),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_PINCH_GESTURE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::pinch_gesture_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_PING}"),
+            RemoteMethodSystemId::Instant(world.register_system(ping::ping_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_PRESS_KEYS}"),
+            RemoteMethodSystemId::Instant(world.register_system(keyboard::press_keys_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_QUERY_CHANGED}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(change_detection::query_changed_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_QUERY_SPATIAL}"),
+            RemoteMethodSystemId::Instant(world.register_system(spatial::query_spatial_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_RELEASE_KEYS}"),
+            RemoteMethodSystemId::Instant(world.register_system(keyboard::release_keys_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_RELOAD_ASSET}"),
+            RemoteMethodSystemId::Instant(world.register_system(assets::reload_asset_handler)),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_ROTATION_GESTURE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::rotation_gesture_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_RUN_INPUT_SCRIPT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(input_script::run_input_script_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_RUN_SYSTEM_BY_NAME}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(named_systems::run_system_by_name_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SAVE_WORLD_SNAPSHOT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(world_snapshot::save_world_snapshot_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SCREEN_TO_WORLD}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(projection::screen_to_world_handler),
+            ),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SCREENSHOT}"),
             RemoteMethodSystemId::Watching(world.register_system(screenshot::handler)),
@@ -447,6 +813,14 @@ fn register_extras_methods(world: &mut World) {
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SCROLL_MOUSE}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::scroll_mouse_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SEND_EVENT}"),
+            RemoteMethodSystemId::Instant(world.register_system(events::send_event_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SEND_IME}"),
+            RemoteMethodSystemId::Instant(world.register_system(keyboard::send_ime_handler)),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SEND_KEYS}"),
             RemoteMethodSystemId::Instant(world.register_system(keyboard::send_keys_handler)),
@@ -455,23 +829,71 @@ fn register_extras_methods(world: &mut World) {
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SEND_MOUSE_BUTTON}"),
             RemoteMethodSystemId::Instant(world.register_system(mouse::send_mouse_button_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_CAMERA_TRANSFORM}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(camera::set_camera_transform_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_CLEAR_COLOR}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(environment::set_clear_color_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_COMPONENT_OVERRIDE}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(overrides::set_component_override_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_WINDOW}"),
+            RemoteMethodSystemId::Instant(world.register_system(window::set_window_handler)),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_WINDOW_TITLE}"),
-            RemoteMethodSystemId::Instant(world.register_system(window_title::handler)),
+            RemoteMethodSystemId::Instant(world.register_system(window::set_window_title_handler)),
         ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SHUTDOWN}"),
             RemoteMethodSystemId::Instant(world.register_system(shutdown::handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_START_INPUT_RECORDING}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(input_recording::start_input_recording_handler),
+            ),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_STOP_INPUT_RECORDING}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(input_recording::stop_input_recording_handler),
+            ),
+        ),
         (
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_TYPE_TEXT}"),
             RemoteMethodSystemId::Instant(world.register_system(keyboard::type_text_handler)),
         ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_WAIT_FOR}"),
+            RemoteMethodSystemId::Watching(world.register_system(wait_for::wait_for_handler)),
+        ),
+        (
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_WORLD_TO_SCREEN}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(projection::world_to_screen_handler),
+            ),
+        ),
     ];
 
     #[cfg(feature = "diagnostics")]
     let methods = {
         let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_DIAGNOSTICS_WATCH}"),
+            RemoteMethodSystemId::Watching(world.register_system(diagnostics::watch_handler)),
+        ));
         methods.push((
             format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_DIAGNOSTICS}"),
             RemoteMethodSystemId::Instant(world.register_system(diagnostics::handler)),
@@ -479,6 +901,93 @@ fn register_extras_methods(world: &mut World) {
         methods
     };
 
+    #[cfg(feature = "gizmos")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_DRAW_GIZMO}"),
+            RemoteMethodSystemId::Instant(world.register_system(gizmo::draw_gizmo_handler)),
+        ));
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_HIGHLIGHT_ENTITY}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(highlight::highlight_entity_handler),
+            ),
+        ));
+        methods
+    };
+
+    #[cfg(feature = "light")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_AMBIENT_LIGHT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(environment::set_ambient_light_handler),
+            ),
+        ));
+        methods
+    };
+
+    #[cfg(feature = "physics")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_CONTACTS}"),
+            RemoteMethodSystemId::Instant(world.register_system(physics::get_contacts_handler)),
+        ));
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_GRAVITY}"),
+            RemoteMethodSystemId::Instant(world.register_system(physics::set_gravity_handler)),
+        ));
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_SET_LINEAR_VELOCITY}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(physics::set_linear_velocity_handler),
+            ),
+        ));
+        methods
+    };
+
+    #[cfg(feature = "picking")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_PICK_ENTITY}"),
+            RemoteMethodSystemId::Instant(world.register_system(picking::pick_entity_handler)),
+        ));
+        methods
+    };
+
+    #[cfg(feature = "text")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_GET_VISIBLE_TEXT}"),
+            RemoteMethodSystemId::Instant(
+                world.register_system(visible_text::get_visible_text_handler),
+            ),
+        ));
+        methods
+    };
+
+    #[cfg(feature = "ui")]
+    let methods = {
+        let mut methods = methods;
+        methods.push((
+            format!("{EXTRAS_COMMAND_PREFIX}{METHOD_CLICK_UI_NODE}"),
+            RemoteMethodSystemId::Instant(world.register_system(mouse::click_ui_node_handler)),
+        ));
+        methods
+    };
+
+    let methods: Vec<_> = methods
+        .into_iter()
+        .map(|(name, system_id)| {
+            let system_id = frame_sync::wrap_schedulable(world, &name, system_id);
+            (name, system_id)
+        })
+        .collect();
     let mut remote_methods = world.resource_mut::<RemoteMethods>();
     for (name, system_id) in methods {
         remote_methods.insert(name, system_id);
@@ -0,0 +1,332 @@
+//! Screen-space projection handlers for BRP extras
+//!
+//! `world_to_screen` and `screen_to_world` run a camera's own projection inside the app, so
+//! automation can find where to click for a world position without duplicating camera math.
+//! Screen positions are window coordinates in logical pixels from the top-left corner, the same
+//! space the mouse methods use; cameras with a viewport are offset by the viewport's origin.
+
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_CAMERA;
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `world_to_screen`
+///
+/// Give either a world-space `point` or an `entity` whose `GlobalTransform` translation is used.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct WorldToScreenRequest {
+    /// Camera whose projection is used
+    camera: u64,
+    /// World-space point to project
+    #[serde(default)]
+    point:  Option<[f32; 3]>,
+    /// Entity whose translation is projected
+    #[serde(default)]
+    entity: Option<u64>,
+}
+
+/// Request structure for `screen_to_world`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScreenToWorldRequest {
+    /// Camera whose projection is used
+    camera:   u64,
+    /// Horizontal window position in logical pixels from the left edge
+    x:        f32,
+    /// Vertical window position in logical pixels from the top edge
+    y:        f32,
+    /// Return the point this far along the ray
+    #[serde(default)]
+    distance: Option<f32>,
+    /// Return the point where the ray crosses this plane
+    #[serde(default)]
+    plane:    Option<PlaneRequest>,
+}
+
+/// An infinite plane through `origin`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PlaneRequest {
+    /// Any point on the plane
+    origin: [f32; 3],
+    /// Plane normal, for example `[0, 1, 0]` for the ground
+    normal: [f32; 3],
+}
+
+/// Response structure for `world_to_screen`
+#[derive(Serialize)]
+struct WorldToScreenResponse {
+    camera:      u64,
+    /// The projected world-space point
+    world_point: [f32; 3],
+    /// Window position, or `None` when the point is behind the camera or outside its depth range
+    position:    Option<[f32; 2]>,
+    /// Whether `position` lies inside the camera's viewport
+    in_view:     bool,
+}
+
+/// World-space ray through a window position
+#[derive(Serialize)]
+struct WorldRay {
+    origin:    [f32; 3],
+    direction: [f32; 3],
+}
+
+/// Response structure for `screen_to_world`
+#[derive(Serialize)]
+struct ScreenToWorldResponse {
+    camera: u64,
+    ray:    WorldRay,
+    /// Point at `distance` or on `plane`; `None` when neither was given or the ray misses the
+    /// plane
+    #[serde(skip_serializing_if = "Option::is_none")]
+    point:  Option<[f32; 3]>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `world_to_screen` BRP method
+///
+/// Projects a world-space point, or an entity's translation, to a window position.
+pub(crate) fn world_to_screen_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: WorldToScreenRequest = parse_required(params)?;
+    let camera = camera_entity(world, request.camera)?;
+    let world_point = match (request.point, request.entity) {
+        (Some(point), None) => Vec3::from_array(point),
+        (None, Some(entity)) => entity_translation(world, entity)?,
+        _ => {
+            return Err(
+                BrpExtrasError::InvalidParams.error("Provide exactly one of `point` or `entity`")
+            );
+        },
+    };
+    let (camera_component, transform) = camera_parts(world, camera)?;
+    let viewport = camera_component.logical_viewport_rect();
+    let viewport_origin = viewport.map_or(Vec2::ZERO, |viewport| viewport.min);
+
+    let position = camera_component
+        .world_to_viewport(transform, world_point)
+        .ok()
+        .map(|position| position + viewport_origin);
+    let in_view = position
+        .zip(viewport)
+        .is_some_and(|(position, viewport)| viewport.contains(position));
+
+    serialize_response(WorldToScreenResponse {
+        camera: camera.to_bits(),
+        world_point: world_point.to_array(),
+        position: position.map(|v| v.to_array()),
+        in_view,
+    })
+}
+
+/// Handler for `screen_to_world` BRP method
+///
+/// Returns the world-space ray through a window position and, optionally, a point along it.
+pub(crate) fn screen_to_world_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: ScreenToWorldRequest = parse_required(params)?;
+    let camera = camera_entity(world, request.camera)?;
+    let ray = window_ray(world, camera, Vec2::new(request.x, request.y))?;
+
+    let point = match (request.distance, request.plane) {
+        (None, None) => None,
+        (Some(distance), None) => Some(ray.get_point(distance)),
+        (None, Some(plane)) => {
+            let normal = Dir3::new(Vec3::from_array(plane.normal)).map_err(|error| {
+                BrpExtrasError::InvalidParams
+                    .error(format!("Plane normal must be a non-zero vector: {error}"))
+            })?;
+            let origin = Vec3::from_array(plane.origin);
+            ray.intersect_plane(origin, InfinitePlane3d::new(normal))
+                .map(|distance| ray.get_point(distance))
+        },
+        (Some(_), Some(_)) => {
+            return Err(BrpExtrasError::InvalidParams
+                .error("`distance` and `plane` are mutually exclusive"));
+        },
+    };
+
+    serialize_response(ScreenToWorldResponse {
+        camera: camera.to_bits(),
+        ray:    WorldRay {
+            origin:    ray.origin.to_array(),
+            direction: ray.direction.to_array(),
+        },
+        point:  point.map(|v| v.to_array()),
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// World-space ray from `camera` through a window position in logical pixels
+pub(crate) fn window_ray(world: &World, camera: Entity, position: Vec2) -> Result<Ray3d, BrpError> {
+    let (camera_component, transform) = camera_parts(world, camera)?;
+    let viewport_origin = camera_component
+        .logical_viewport_rect()
+        .map_or(Vec2::ZERO, |viewport| viewport.min);
+
+    camera_component
+        .viewport_to_world(transform, position - viewport_origin)
+        .map_err(|error| {
+            BrpExtrasError::CameraUnavailable.with_details(
+                format!("Camera {} cannot project a ray: {error}", camera.to_bits()),
+                json!({ PARAM_CAMERA: camera.to_bits() }),
+            )
+        })
+}
+
+fn camera_parts(world: &World, camera: Entity) -> Result<(&Camera, &GlobalTransform), BrpError> {
+    world
+        .get::<Camera>(camera)
+        .zip(world.get::<GlobalTransform>(camera))
+        .ok_or_else(|| {
+            BrpExtrasError::CameraUnavailable.with_details(
+                format!(
+                    "Camera {} is missing or has no GlobalTransform",
+                    camera.to_bits()
+                ),
+                json!({ PARAM_CAMERA: camera.to_bits() }),
+            )
+        })
+}
+
+pub(crate) fn camera_entity(world: &World, camera: u64) -> Result<Entity, BrpError> {
+    Entity::try_from_bits(camera)
+        .filter(|entity| world.get::<Camera>(*entity).is_some())
+        .ok_or_else(|| {
+            BrpExtrasError::CameraUnavailable.with_details(
+                format!("Entity {camera} is not a camera"),
+                json!({ PARAM_CAMERA: camera }),
+            )
+        })
+}
+
+pub(crate) fn entity_translation(world: &World, entity: u64) -> Result<Vec3, BrpError> {
+    Entity::try_from_bits(entity)
+        .and_then(|id| world.get::<GlobalTransform>(id))
+        .map(GlobalTransform::translation)
+        .ok_or_else(|| {
+            BrpExtrasError::EntityNotFound.with_details(
+                format!("Entity {entity} does not exist or has no GlobalTransform"),
+                json!({ PARAM_ENTITY: entity }),
+            )
+        })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::camera::ComputedCameraValues;
+    use bevy::camera::RenderTargetInfo;
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::screen_to_world_handler;
+    use super::world_to_screen_handler;
+    use crate::error::BrpExtrasError;
+
+    /// A 100x100 camera at the origin with an identity projection: window position `(50, 50)`
+    /// looks down `-Z` through the world origin
+    fn spawn_camera(app: &mut App) -> u64 {
+        app.world_mut()
+            .spawn((
+                Camera {
+                    computed: ComputedCameraValues {
+                        clip_from_view: Mat4::IDENTITY,
+                        target_info: Some(RenderTargetInfo {
+                            physical_size: UVec2::new(100, 100),
+                            scale_factor:  1.0,
+                        }),
+                        ..default()
+                    },
+                    ..default()
+                },
+                GlobalTransform::IDENTITY,
+            ))
+            .id()
+            .to_bits()
+    }
+
+    #[test]
+    fn world_to_screen_projects_an_entity_translation() {
+        let mut app = App::new();
+        let camera = spawn_camera(&mut app);
+        let target = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::new(0.5, 0.0, 0.5)))
+            .id()
+            .to_bits();
+
+        let response = world_to_screen_handler(
+            In(Some(json!({ "camera": camera, "entity": target }))),
+            app.world_mut(),
+        )
+        .expect("the entity should project");
+
+        assert_eq!(response["position"], json!([75.0, 50.0]));
+        assert_eq!(response["in_view"], true);
+    }
+
+    #[test]
+    fn screen_to_world_intersects_a_plane() {
+        let mut app = App::new();
+        let camera = spawn_camera(&mut app);
+
+        let response = screen_to_world_handler(
+            In(Some(json!({
+                "camera": camera,
+                "x": 50.0,
+                "y": 50.0,
+                "plane": { "origin": [0.0, 0.0, 0.5], "normal": [0.0, 0.0, 1.0] }
+            }))),
+            app.world_mut(),
+        )
+        .expect("the ray should cross the plane");
+
+        assert_eq!(response["ray"]["direction"], json!([0.0, 0.0, -1.0]));
+        assert_eq!(response["point"], json!([0.0, 0.0, 0.5]));
+    }
+
+    #[test]
+    fn a_non_camera_entity_is_rejected() {
+        let mut app = App::new();
+        let entity = app.world_mut().spawn_empty().id().to_bits();
+
+        let error = world_to_screen_handler(
+            In(Some(json!({ "camera": entity, "point": [0.0, 0.0, 0.0] }))),
+            app.world_mut(),
+        )
+        .expect_err("the entity has no Camera");
+
+        assert_eq!(error.code, BrpExtrasError::CameraUnavailable.code());
+    }
+}
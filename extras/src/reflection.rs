@@ -0,0 +1,519 @@
+//! Reflection-only component access for BRP extras
+//!
+//! `world.get_components` fails for a component when any value inside it is an opaque type
+//! without `ReflectSerialize` registered. `get_component_raw` walks such a component through
+//! [`ReflectRef`] instead, serializing each value that can be serialized and describing the rest
+//! with their `Debug` output, so agents can at least see what the component holds. The result is
+//! a read-only view: it cannot be written back with `world.insert_components`.
+//!
+//! `mutate_reflect` is the writing counterpart. Like `world.mutate_components` it deserializes the
+//! new value through reflection and applies it with [`PartialReflect::try_apply`], but when the
+//! value as a whole cannot be deserialized, it descends into structs, tuples, lists, and arrays
+//! and applies each given field on its own. Fields left out of the value keep their current
+//! values, so a component holding an opaque value without `ReflectDeserialize` can still have its
+//! other fields changed.
+
+use bevy::prelude::*;
+use bevy::reflect::ParsedPath;
+use bevy::reflect::PartialReflect;
+use bevy::reflect::ReflectMut;
+use bevy::reflect::ReflectPath;
+use bevy::reflect::ReflectRef;
+use bevy::reflect::TypeRegistration;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::enums::VariantType;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeSeed;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::missing_component;
+use crate::support::parse_required;
+use crate::support::resolve_entity;
+use crate::support::serialize_response;
+
+/// Type path prefix of `Option`, which is dumped as `null` or its value like serde does
+const OPTION_TYPE_PATH_PREFIX: &str = "core::option::Option<";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `get_component_raw`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetComponentRawRequest {
+    /// Entity to read the component from
+    entity:    u64,
+    /// Full type path of a component registered with `reflect(Component)`
+    component: String,
+}
+
+/// Request structure for `mutate_reflect`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct MutateReflectRequest {
+    /// Entity whose component is changed
+    entity:    u64,
+    /// Full type path of a component registered with `reflect(Component)`
+    component: String,
+    /// Path to the value to change, in `world.mutate_components` syntax; empty for the whole
+    /// component
+    #[serde(default)]
+    path:      String,
+    /// New value in BRP's reflect format; structs may leave out fields to keep their values
+    value:     Value,
+}
+
+/// A value inside the component that could not be serialized
+#[derive(Serialize)]
+struct UnserializableValue {
+    /// Where the value is inside the component, such as `.timer` or `.points[2]`
+    path:      String,
+    type_path: String,
+}
+
+/// Response structure for `get_component_raw`
+#[derive(Serialize)]
+struct GetComponentRawResponse {
+    entity:         u64,
+    component:      String,
+    /// Best-effort JSON view; unserializable values are replaced by their `Debug` output
+    value:          Value,
+    /// Always `true`: the value is not guaranteed to round-trip through `insert_components`
+    read_only:      bool,
+    /// Values replaced by their `Debug` output; empty when the component serialized as a whole
+    unserializable: Vec<UnserializableValue>,
+}
+
+/// Response structure for `mutate_reflect`
+#[derive(Serialize)]
+struct MutateReflectResponse {
+    entity:    u64,
+    component: String,
+    path:      String,
+    /// The value at `path` after the mutation, as `get_component_raw` would show it
+    value:     Value,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `get_component_raw` BRP method
+///
+/// Serializes the component as `world.get_components` would, falling back to walking it through
+/// reflection when that fails.
+pub(crate) fn get_component_raw_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: GetComponentRawRequest = parse_required(params)?;
+    let entity = resolve_entity(world, request.entity)?;
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let (_, reflect_component) = resolve_component(&type_registry, &request.component)?;
+    let component = reflect_component
+        .reflect(world.entity(entity))
+        .ok_or_else(|| missing_component(request.entity, &request.component))?;
+
+    let mut dump = ReflectDump {
+        registry:       &type_registry,
+        unserializable: Vec::new(),
+    };
+    let value = dump.dump(component.as_partial_reflect(), "");
+    let unserializable = dump.unserializable;
+    drop(type_registry);
+
+    serialize_response(GetComponentRawResponse {
+        entity: request.entity,
+        component: request.component,
+        value,
+        read_only: true,
+        unserializable,
+    })
+}
+
+/// Handler for `mutate_reflect` BRP method
+///
+/// Applies the value at the path through reflection and returns the value now at the path.
+pub(crate) fn mutate_reflect_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: MutateReflectRequest = parse_required(params)?;
+    let entity = resolve_entity(world, request.entity)?;
+    let path = ParsedPath::parse(&request.path).map_err(|error| {
+        BrpExtrasError::InvalidParams.with_details(
+            format!("Invalid path '{}': {error}", request.path),
+            json!({ "path": request.path }),
+        )
+    })?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let (_, reflect_component) = resolve_component(&type_registry, &request.component)?;
+    let mut component = reflect_component
+        .reflect_mut(world.entity_mut(entity))
+        .ok_or_else(|| missing_component(request.entity, &request.component))?;
+    let target = path
+        .reflect_element_mut(component.as_partial_reflect_mut())
+        .map_err(|error| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!(
+                    "'{}' has no value at path '{}': {error}",
+                    request.component, request.path
+                ),
+                json!({ "component": request.component, "path": request.path }),
+            )
+        })?;
+    apply_value(target, &request.value, &type_registry, &request.path)?;
+
+    let value = ReflectDump {
+        registry:       &type_registry,
+        unserializable: Vec::new(),
+    }
+    .dump(target, &request.path);
+    drop(type_registry);
+
+    serialize_response(MutateReflectResponse {
+        entity: request.entity,
+        component: request.component,
+        path: request.path,
+        value,
+    })
+}
+
+/// Apply `value` to `target`, as a whole when it deserializes and otherwise element by element
+fn apply_value(
+    target: &mut dyn PartialReflect,
+    value: &Value,
+    registry: &TypeRegistry,
+    path: &str,
+) -> Result<(), BrpError> {
+    let deserialize_error = match deserialize_as(target, value, registry) {
+        Ok(reflected) => {
+            return target.try_apply(reflected.as_ref()).map_err(|error| {
+                BrpExtrasError::InvalidParams.with_details(
+                    format!("Failed to apply the value at '{path}': {error}"),
+                    json!({ "path": path, "type_path": target.reflect_type_path() }),
+                )
+            });
+        },
+        Err(error) => error,
+    };
+
+    let type_path = target.reflect_type_path().to_string();
+    let not_applicable = || {
+        BrpExtrasError::InvalidParams.with_details(
+            format!("Cannot apply the value at '{path}': {deserialize_error}"),
+            json!({ "path": path, "type_path": type_path, "value": value }),
+        )
+    };
+    let no_element = |element: String| {
+        BrpExtrasError::InvalidParams.with_details(
+            format!("'{type_path}' at '{path}' has no element '{element}'"),
+            json!({ "path": path, "type_path": type_path, "element": element }),
+        )
+    };
+
+    match (target.reflect_mut(), value) {
+        (ReflectMut::Struct(fields), Value::Object(values)) => {
+            for (name, value) in values {
+                let field = fields
+                    .field_mut(name)
+                    .ok_or_else(|| no_element(name.clone()))?;
+                apply_value(field, value, registry, &format!("{path}.{name}"))?;
+            }
+        },
+        // Newtypes are written as their single field
+        (ReflectMut::TupleStruct(fields), value) if fields.field_len() == 1 => {
+            let field = fields
+                .field_mut(0)
+                .ok_or_else(|| no_element("0".to_string()))?;
+            apply_value(field, value, registry, &format!("{path}.0"))?;
+        },
+        (ReflectMut::TupleStruct(fields), Value::Array(values)) => {
+            for (index, value) in values.iter().enumerate() {
+                let field = fields
+                    .field_mut(index)
+                    .ok_or_else(|| no_element(index.to_string()))?;
+                apply_value(field, value, registry, &format!("{path}.{index}"))?;
+            }
+        },
+        (ReflectMut::Tuple(fields), Value::Array(values)) => {
+            for (index, value) in values.iter().enumerate() {
+                let field = fields
+                    .field_mut(index)
+                    .ok_or_else(|| no_element(index.to_string()))?;
+                apply_value(field, value, registry, &format!("{path}.{index}"))?;
+            }
+        },
+        (ReflectMut::List(items), Value::Array(values)) if items.len() == values.len() => {
+            for (index, value) in values.iter().enumerate() {
+                let item = items
+                    .get_mut(index)
+                    .ok_or_else(|| no_element(index.to_string()))?;
+                apply_value(item, value, registry, &format!("{path}[{index}]"))?;
+            }
+        },
+        (ReflectMut::Array(items), Value::Array(values)) if items.len() == values.len() => {
+            for (index, value) in values.iter().enumerate() {
+                let item = items
+                    .get_mut(index)
+                    .ok_or_else(|| no_element(index.to_string()))?;
+                apply_value(item, value, registry, &format!("{path}[{index}]"))?;
+            }
+        },
+        _ => return Err(not_applicable()),
+    }
+    Ok(())
+}
+
+/// `value` deserialized as the type of `target`
+fn deserialize_as(
+    target: &dyn PartialReflect,
+    value: &Value,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let registration = target
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .ok_or_else(|| format!("'{}' is not registered", target.reflect_type_path()))?;
+    TypedReflectDeserializer::new(registration, registry)
+        .deserialize(value)
+        .map_err(|error| error.to_string())
+}
+
+/// Best-effort JSON conversion of reflected values
+struct ReflectDump<'a> {
+    registry:       &'a TypeRegistry,
+    unserializable: Vec<UnserializableValue>,
+}
+
+impl ReflectDump<'_> {
+    /// `value` as JSON, in BRP's reflect format wherever it can be serialized
+    fn dump(&mut self, value: &dyn PartialReflect, path: &str) -> Value {
+        if let Ok(serialized) =
+            serde_json::to_value(TypedReflectSerializer::new(value, self.registry))
+        {
+            return serialized;
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(fields) => Value::Object(
+                (0..fields.field_len())
+                    .filter_map(|index| Some((fields.name_at(index)?, fields.field_at(index)?)))
+                    .map(|(name, field)| {
+                        (
+                            name.to_string(),
+                            self.dump(field, &format!("{path}.{name}")),
+                        )
+                    })
+                    .collect(),
+            ),
+            ReflectRef::TupleStruct(fields) => unwrap_newtype(
+                fields
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, field)| self.dump(field, &format!("{path}.{index}")))
+                    .collect(),
+            ),
+            ReflectRef::Tuple(fields) => Value::Array(
+                fields
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, field)| self.dump(field, &format!("{path}.{index}")))
+                    .collect(),
+            ),
+            ReflectRef::List(items) => self.dump_items(items.iter(), path),
+            ReflectRef::Array(items) => self.dump_items(items.iter(), path),
+            ReflectRef::Set(items) => self.dump_items(items.iter(), path),
+            ReflectRef::Map(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, entry)| {
+                        let key = match self.dump(key, path) {
+                            Value::String(key) => key,
+                            key => key.to_string(),
+                        };
+                        let entry = self.dump(entry, &format!("{path}[{key}]"));
+                        (key, entry)
+                    })
+                    .collect::<Map<_, _>>(),
+            ),
+            ReflectRef::Enum(variant) => {
+                let is_option = value
+                    .get_represented_type_info()
+                    .is_some_and(|info| info.type_path().starts_with(OPTION_TYPE_PATH_PREFIX));
+                if is_option {
+                    return variant
+                        .field_at(0)
+                        .map_or(Value::Null, |inner| self.dump(inner, path));
+                }
+
+                let name = variant.variant_name().to_string();
+                let fields = match variant.variant_type() {
+                    VariantType::Unit => return Value::String(name),
+                    VariantType::Tuple => unwrap_newtype(
+                        variant
+                            .iter_fields()
+                            .enumerate()
+                            .map(|(index, field)| {
+                                self.dump(field.value(), &format!("{path}.{index}"))
+                            })
+                            .collect(),
+                    ),
+                    VariantType::Struct => Value::Object(
+                        variant
+                            .iter_fields()
+                            .filter_map(|field| Some((field.name()?, field.value())))
+                            .map(|(field_name, field)| {
+                                (
+                                    field_name.to_string(),
+                                    self.dump(field, &format!("{path}.{field_name}")),
+                                )
+                            })
+                            .collect(),
+                    ),
+                };
+                json!({ name: fields })
+            },
+            // Opaque values without `ReflectSerialize`, which serde cannot reach either
+            ReflectRef::Opaque(_) => {
+                self.unserializable.push(UnserializableValue {
+                    path:      path.to_string(),
+                    type_path: value.reflect_type_path().to_string(),
+                });
+                Value::String(format!("{value:?}"))
+            },
+        }
+    }
+
+    fn dump_items<'v>(
+        &mut self,
+        items: impl Iterator<Item = &'v dyn PartialReflect>,
+        path: &str,
+    ) -> Value {
+        Value::Array(
+            items
+                .enumerate()
+                .map(|(index, item)| self.dump(item, &format!("{path}[{index}]")))
+                .collect(),
+        )
+    }
+}
+
+/// A single field as itself, like serde's newtype structs; several fields as an array
+fn unwrap_newtype(fields: Vec<Value>) -> Value {
+    match <[Value; 1]>::try_from(fields) {
+        Ok([field]) => field,
+        Err(fields) => Value::Array(fields),
+    }
+}
+
+fn resolve_component<'a>(
+    registry: &'a TypeRegistry,
+    component: &str,
+) -> Result<(&'a TypeRegistration, &'a ReflectComponent), BrpError> {
+    registry
+        .get_with_type_path(component)
+        .and_then(|registration| {
+            registration
+                .data::<ReflectComponent>()
+                .map(|reflect_component| (registration, reflect_component))
+        })
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("'{component}' is not a registered component with reflect(Component)"),
+                json!({ "component": component }),
+            )
+        })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::time::Instant;
+
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::get_component_raw_handler;
+    use super::mutate_reflect_handler;
+
+    const COOLDOWN_TYPE_PATH: &str = "bevy_brp_extras::reflection::tests::Cooldown";
+
+    /// A component holding an opaque value that has no `ReflectSerialize`
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Cooldown {
+        label:      String,
+        started_at: Option<Instant>,
+    }
+
+    fn spawn_cooldown(app: &mut App) -> Entity {
+        app.register_type::<Cooldown>();
+        app.world_mut()
+            .spawn(Cooldown {
+                label:      "dash".to_string(),
+                started_at: Some(Instant::now()),
+            })
+            .id()
+    }
+
+    #[test]
+    fn unserializable_values_are_reported_by_path() {
+        let mut app = App::new();
+        let entity = spawn_cooldown(&mut app);
+
+        let response = get_component_raw_handler(
+            In(Some(json!({
+                "entity": entity.to_bits(),
+                "component": COOLDOWN_TYPE_PATH,
+            }))),
+            app.world_mut(),
+        )
+        .expect("a reflected component should be dumped");
+
+        assert_eq!(response["read_only"], true);
+        assert_eq!(response["value"]["label"], "dash");
+        assert_eq!(response["unserializable"][0]["path"], ".started_at");
+        assert!(response["value"]["started_at"].is_string());
+    }
+
+    #[test]
+    fn fields_are_applied_around_undeserializable_values() {
+        let mut app = App::new();
+        let entity = spawn_cooldown(&mut app);
+
+        let response = mutate_reflect_handler(
+            In(Some(json!({
+                "entity": entity.to_bits(),
+                "component": COOLDOWN_TYPE_PATH,
+                "value": { "label": "roll" },
+            }))),
+            app.world_mut(),
+        )
+        .expect("the serializable field should be applied");
+
+        assert_eq!(response["value"]["label"], "roll");
+        let cooldown = app
+            .world()
+            .get::<Cooldown>(entity)
+            .expect("the component is still there");
+        assert_eq!(cooldown.label, "roll");
+        assert!(cooldown.started_at.is_some());
+    }
+}
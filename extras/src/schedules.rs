@@ -0,0 +1,378 @@
+//! System and schedule introspection handlers for BRP extras
+//!
+//! Both methods read the `ScheduleGraph` of each schedule in the `Schedules` resource. A schedule
+//! is taken out of `Schedules` while it runs, so the schedules executing the BRP request itself
+//! (`Main` and the schedule the remote systems run in) are not visible.
+
+use std::collections::BTreeMap;
+
+use bevy::ecs::schedule::ConditionWithAccess;
+use bevy::ecs::schedule::NodeId;
+use bevy::ecs::schedule::ScheduleGraph;
+use bevy::ecs::schedule::Schedules;
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `list_systems`
+#[derive(Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ListSystemsRequest {
+    /// Only list systems of this schedule (e.g. `Update`)
+    #[serde(default)]
+    schedule: Option<String>,
+}
+
+/// Request structure for `get_schedule_graph`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetScheduleGraphRequest {
+    /// Schedule to describe (e.g. `Update`)
+    schedule: String,
+}
+
+/// One system and what decides whether and when it runs
+#[derive(Serialize)]
+struct SystemInfo {
+    name:           String,
+    /// Sets the system is directly in
+    sets:           Vec<String>,
+    /// Run conditions on the system itself
+    conditions:     Vec<String>,
+    /// Run conditions inherited from the sets containing the system, keyed by set
+    set_conditions: BTreeMap<String, Vec<String>>,
+    /// Systems and sets this system runs after
+    after:          Vec<String>,
+    /// Systems and sets this system runs before
+    before:         Vec<String>,
+    /// Change tick of the system's last run; `0` if it has never run
+    last_run_tick:  u32,
+}
+
+/// Systems of one schedule
+#[derive(Serialize)]
+struct ScheduleSystems {
+    schedule: String,
+    systems:  Vec<SystemInfo>,
+}
+
+/// Response structure for `list_systems`
+#[derive(Serialize)]
+struct ListSystemsResponse {
+    schedules:    Vec<ScheduleSystems>,
+    system_count: usize,
+}
+
+/// A system set node and its run conditions
+#[derive(Serialize)]
+struct SetInfo {
+    name:       String,
+    conditions: Vec<String>,
+}
+
+/// A system node and its run conditions
+#[derive(Serialize)]
+struct SystemNode {
+    name:       String,
+    conditions: Vec<String>,
+}
+
+/// Set membership: `child` (a system or set) is in `parent`
+#[derive(Serialize)]
+struct HierarchyEdge {
+    parent: String,
+    child:  String,
+}
+
+/// Ordering constraint: `before` runs before `after`
+#[derive(Serialize)]
+struct DependencyEdge {
+    before: String,
+    after:  String,
+}
+
+/// Response structure for `get_schedule_graph`
+#[derive(Serialize)]
+struct ScheduleGraphResponse {
+    schedule:     String,
+    systems:      Vec<SystemNode>,
+    sets:         Vec<SetInfo>,
+    hierarchy:    Vec<HierarchyEdge>,
+    dependencies: Vec<DependencyEdge>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `list_systems` BRP method
+///
+/// Lists the systems of every visible schedule, or of one schedule, with their sets, run
+/// conditions, and ordering constraints.
+pub(crate) fn list_systems_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: ListSystemsRequest = parse_optional(params)?;
+    let schedules = schedules(world)?;
+
+    if let Some(schedule) = &request.schedule {
+        find_schedule(schedules, schedule)?;
+    }
+
+    let mut listed: Vec<ScheduleSystems> = schedules
+        .iter()
+        .map(|(label, schedule)| (format!("{label:?}"), schedule))
+        .filter(|(label, _)| {
+            request
+                .schedule
+                .as_ref()
+                .is_none_or(|schedule| schedule == label)
+        })
+        .map(|(label, schedule)| ScheduleSystems {
+            schedule: label,
+            systems:  list_graph_systems(schedule.graph()),
+        })
+        .collect();
+    listed.sort_by(|a, b| a.schedule.cmp(&b.schedule));
+
+    let system_count = listed.iter().map(|schedule| schedule.systems.len()).sum();
+    serialize_response(ListSystemsResponse {
+        schedules: listed,
+        system_count,
+    })
+}
+
+/// Handler for `get_schedule_graph` BRP method
+///
+/// Returns the system and set nodes of one schedule with its set hierarchy and ordering edges.
+pub(crate) fn get_schedule_graph_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: GetScheduleGraphRequest = parse_required(params)?;
+    let schedules = schedules(world)?;
+    let graph = find_schedule(schedules, &request.schedule)?.graph();
+
+    let systems = graph
+        .systems
+        .iter()
+        .map(|(_, system, conditions)| SystemNode {
+            name:       system.name().to_string(),
+            conditions: condition_names(conditions),
+        })
+        .collect();
+    let sets = graph
+        .system_sets
+        .iter()
+        .map(|(_, set, conditions)| SetInfo {
+            name:       format!("{set:?}"),
+            conditions: condition_names(conditions),
+        })
+        .collect();
+    let hierarchy = named_edges(graph, graph.hierarchy().graph().all_edges())
+        .map(|(parent, child)| HierarchyEdge { parent, child })
+        .collect();
+    let dependencies = named_edges(graph, graph.dependency().graph().all_edges())
+        .map(|(before, after)| DependencyEdge { before, after })
+        .collect();
+
+    serialize_response(ScheduleGraphResponse {
+        schedule: request.schedule,
+        systems,
+        sets,
+        hierarchy,
+        dependencies,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn schedules(world: &World) -> Result<&Schedules, BrpError> {
+    world
+        .get_resource::<Schedules>()
+        .ok_or_else(|| BrpExtrasError::Unsupported.error("Schedules resource not found"))
+}
+
+/// Find a schedule by the debug name of its label, listing the visible schedules if none matches
+fn find_schedule<'a>(schedules: &'a Schedules, name: &str) -> Result<&'a Schedule, BrpError> {
+    schedules
+        .iter()
+        .find(|(label, _)| format!("{label:?}") == name)
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| {
+            let mut available: Vec<String> = schedules
+                .iter()
+                .map(|(label, _)| format!("{label:?}"))
+                .collect();
+            available.sort();
+            BrpExtrasError::InvalidParams.with_details(
+                format!("Schedule '{name}' not found or currently running"),
+                json!({ "schedule": name, "available_schedules": available }),
+            )
+        })
+}
+
+fn list_graph_systems(graph: &ScheduleGraph) -> Vec<SystemInfo> {
+    let hierarchy: Vec<(NodeId, NodeId)> = graph.hierarchy().graph().all_edges().collect();
+    let dependencies: Vec<(NodeId, NodeId)> = graph.dependency().graph().all_edges().collect();
+
+    graph
+        .systems
+        .iter()
+        .map(|(key, system, conditions)| {
+            let node = NodeId::System(key);
+            let sets = hierarchy
+                .iter()
+                .filter(|(_, child)| *child == node)
+                .filter_map(|(parent, _)| node_name(graph, *parent))
+                .collect();
+            let after = dependencies
+                .iter()
+                .filter(|(_, after)| *after == node)
+                .filter_map(|(before, _)| node_name(graph, *before))
+                .collect();
+            let before = dependencies
+                .iter()
+                .filter(|(before, _)| *before == node)
+                .filter_map(|(_, after)| node_name(graph, *after))
+                .collect();
+
+            SystemInfo {
+                name: system.name().to_string(),
+                sets,
+                conditions: condition_names(conditions),
+                set_conditions: inherited_conditions(graph, &hierarchy, node),
+                after,
+                before,
+                last_run_tick: system.get_last_run().get(),
+            }
+        })
+        .collect()
+}
+
+/// Run conditions of every set above `node` in the hierarchy, skipping sets without conditions
+fn inherited_conditions(
+    graph: &ScheduleGraph,
+    hierarchy: &[(NodeId, NodeId)],
+    node: NodeId,
+) -> BTreeMap<String, Vec<String>> {
+    let mut inherited = BTreeMap::new();
+    let mut visited = Vec::new();
+    let mut pending = vec![node];
+    while let Some(child) = pending.pop() {
+        for &(parent, _) in hierarchy
+            .iter()
+            .filter(|(_, edge_child)| *edge_child == child)
+        {
+            if visited.contains(&parent) {
+                continue;
+            }
+            visited.push(parent);
+            pending.push(parent);
+            if let NodeId::Set(key) = parent
+                && let Some(conditions) = graph.system_sets.get_conditions(key)
+                && !conditions.is_empty()
+                && let Some(name) = node_name(graph, parent)
+            {
+                inherited.insert(name, condition_names(conditions));
+            }
+        }
+    }
+    inherited
+}
+
+fn named_edges<'a>(
+    graph: &'a ScheduleGraph,
+    edges: impl Iterator<Item = (NodeId, NodeId)> + 'a,
+) -> impl Iterator<Item = (String, String)> + 'a {
+    edges.filter_map(|(from, to)| Some((node_name(graph, from)?, node_name(graph, to)?)))
+}
+
+fn node_name(graph: &ScheduleGraph, node: NodeId) -> Option<String> {
+    match node {
+        NodeId::System(key) => graph
+            .systems
+            .get(key)
+            .map(|system| system.name().to_string()),
+        NodeId::Set(key) => graph.system_sets.get(key).map(|set| format!("{set:?}")),
+    }
+}
+
+fn condition_names(conditions: &[ConditionWithAccess]) -> Vec<String> {
+    conditions
+        .iter()
+        .map(|condition| condition.condition.name().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::app::Update;
+    use bevy::prelude::In;
+    use bevy::prelude::IntoScheduleConfigs;
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::list_systems_handler;
+
+    fn setup() {}
+    fn apply_input() {}
+    const fn input_enabled() -> bool { false }
+
+    #[test]
+    fn lists_conditions_and_ordering_of_a_schedule() {
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            (setup, apply_input.after(setup).run_if(input_enabled)),
+        );
+
+        let response =
+            list_systems_handler(In(Some(json!({ "schedule": "Update" }))), app.world_mut())
+                .expect("Update should be listed");
+
+        let systems = response["schedules"][0]["systems"]
+            .as_array()
+            .expect("systems should be an array");
+        let apply_input = systems
+            .iter()
+            .find(|system| {
+                system["name"]
+                    .as_str()
+                    .is_some_and(|name| name.ends_with("apply_input"))
+            })
+            .expect("apply_input should be listed");
+
+        assert_eq!(response["system_count"], 2);
+        assert!(
+            apply_input["conditions"][0]
+                .as_str()
+                .is_some_and(|name| name.ends_with("input_enabled"))
+        );
+        assert!(apply_input["after"].as_array().is_some_and(|after| {
+            after
+                .iter()
+                .any(|name| name.as_str().is_some_and(|name| name.contains("setup")))
+        }));
+        assert_eq!(apply_input["last_run_tick"], Value::from(0));
+    }
+}
@@ -14,9 +14,10 @@ use bevy_kana::ToF32;
 use bevy_kana::ToU32;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use serde_json::json;
 
 use super::SelectedCamera;
+use crate::error::BrpExtrasError;
 
 pub(super) fn resolve(
     world: &World,
@@ -194,19 +195,17 @@ fn nonempty_intersection(rect: URect, hard_bounds: URect, camera: Entity) -> Brp
 }
 
 fn bounds_error(entity: Entity, detail: &str) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Screenshot entity {} {detail}", entity.to_bits()),
-        data:    None,
-    }
+    BrpExtrasError::InvalidParams.with_details(
+        format!("Screenshot entity {} {detail}", entity.to_bits()),
+        json!({ "entity": entity.to_bits() }),
+    )
 }
 
 fn camera_projection_error(camera: Entity, detail: &str) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Screenshot camera {} {detail}", camera.to_bits()),
-        data:    None,
-    }
+    BrpExtrasError::CameraUnavailable.with_details(
+        format!("Screenshot camera {} {detail}", camera.to_bits()),
+        json!({ "camera": camera.to_bits() }),
+    )
 }
 
 #[cfg(test)]
@@ -1,26 +1,27 @@
 //! Single-request screenshot lifecycle and terminal PNG publication.
 
-use std::path::Path;
 use std::sync::mpsc::Sender;
-use std::time::Instant;
 
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
 use bevy::render::view::screenshot::Screenshot;
 use bevy::render::view::screenshot::ScreenshotCaptured;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
 use serde_json::Value;
+use serde_json::json;
 
 use super::CaptureInput;
 use super::screenshot_job;
+use super::screenshot_job::CaptureArtifact;
 use super::screenshot_job::CaptureCompletionChannel;
+use super::screenshot_job::FinishedCapture;
 use super::screenshot_job::ImageConverter;
-use super::screenshot_job::OwnedTempCapture;
 use super::screenshot_job::ScreenshotJob;
 use super::screenshot_job::WorkerCompletion;
 use crate::constants::SCREENSHOT_CAPTURE_DEADLINE;
 use crate::constants::SCREENSHOT_ENTITY_NAME;
+use crate::error::BrpExtrasError;
 use crate::screenshot;
 use crate::screenshot::request::ScreenshotRequest;
 
@@ -101,7 +102,7 @@ impl PendingScreenshotCapture {
 
         let screenshot_job = ScreenshotJob {
             crop:              capture_input.crop,
-            path:              request.path().to_path_buf(),
+            output:            request.output().clone(),
             response_metadata: capture_input.response_metadata,
         };
         self.active = Some(ActiveCapture {
@@ -163,7 +164,7 @@ impl PendingScreenshotCapture {
         }
 
         active.status = match completion.result {
-            Ok(capture) => publish_capture(active.request.path(), capture),
+            Ok(capture) => publish_capture(capture),
             Err(error) => CaptureStatus::Failed(error),
         };
     }
@@ -254,52 +255,65 @@ pub(super) fn advance_capture_lifecycle(
     }
 }
 
-fn publish_capture(path: &Path, capture: OwnedTempCapture) -> CaptureStatus {
+fn publish_capture(capture: FinishedCapture) -> CaptureStatus {
     if !capture.metadata.dimensions.cmpgt(UVec2::ZERO).all() {
         return CaptureStatus::Failed(capture_error("Screenshot worker produced an empty image"));
     }
     let response_metadata = capture.metadata.response_metadata;
-    match capture.temp_path.persist(path) {
-        Ok(()) => {
-            CaptureStatus::Completed(screenshot::completed_response(path, &response_metadata))
+    match capture.artifact {
+        #[cfg(not(target_arch = "wasm32"))]
+        CaptureArtifact::TempFile {
+            destination,
+            temp_path,
+        } => match temp_path.persist(&destination) {
+            Ok(()) => CaptureStatus::Completed(screenshot::completed_response(
+                &destination,
+                &response_metadata,
+            )),
+            Err(error) => {
+                let message = format!(
+                    "Failed to publish screenshot to {}: {}",
+                    destination.display(),
+                    error.error
+                );
+                drop(error.path);
+                CaptureStatus::Failed(BrpExtrasError::Io.with_details(
+                    message,
+                    json!({ "path": destination.display().to_string() }),
+                ))
+            },
         },
-        Err(error) => {
-            let message = format!(
-                "Failed to publish screenshot to {}: {}",
-                path.display(),
-                error.error
-            );
-            drop(error.path);
-            CaptureStatus::Failed(capture_error(message))
+        CaptureArtifact::Inline(bytes) => {
+            CaptureStatus::Completed(screenshot::inline_response(&bytes, &response_metadata))
         },
     }
 }
 
-fn capture_error(message: impl Into<String>) -> BrpError {
-    BrpError {
-        code:    INTERNAL_ERROR,
-        message: message.into(),
-        data:    None,
-    }
-}
+fn capture_error(message: impl Into<String>) -> BrpError { BrpExtrasError::Internal.error(message) }
 
 fn capture_in_progress_error() -> BrpError {
-    capture_error("A screenshot capture is already in progress")
+    BrpExtrasError::InvalidState.error("A screenshot capture is already in progress")
 }
 
 fn timeout_error() -> BrpError {
-    capture_error(format!(
-        "Screenshot capture exceeded the {}-second server deadline",
-        SCREENSHOT_CAPTURE_DEADLINE.as_secs()
-    ))
+    BrpExtrasError::Timeout.with_details(
+        format!(
+            "Screenshot capture exceeded the {}-second server deadline",
+            SCREENSHOT_CAPTURE_DEADLINE.as_secs()
+        ),
+        json!({ "deadline_secs": SCREENSHOT_CAPTURE_DEADLINE.as_secs() }),
+    )
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use std::error::Error;
     use std::fs;
     use std::io;
+    use std::path::Path;
 
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
     use bevy::MinimalPlugins;
     use bevy_remote::RemotePlugin;
     use screenshot::CaptureResponseMetadata;
@@ -315,15 +329,18 @@ mod tests {
     const INITIAL_DESTINATION_CONTENT: &[u8] = b"sentinel";
     const SCREENSHOT_CONTENT: &[u8] = b"complete png";
 
-    fn completed_capture(destination: &Path) -> Result<OwnedTempCapture, io::Error> {
+    fn completed_capture(destination: &Path) -> Result<FinishedCapture, io::Error> {
         let temp_path = screenshot_job::create_temporary_file(destination, SCREENSHOT_CONTENT)
             .map_err(|error| io::Error::other(error.message))?;
-        Ok(OwnedTempCapture {
+        Ok(FinishedCapture {
             metadata: CaptureMetadata {
                 dimensions:        UVec2::ONE,
                 response_metadata: CaptureResponseMetadata::Full,
             },
-            temp_path,
+            artifact: CaptureArtifact::TempFile {
+                destination: destination.to_path_buf(),
+                temp_path,
+            },
         })
     }
 
@@ -351,7 +368,7 @@ mod tests {
 
         let replacement = completed_capture(&existing)?;
         assert!(matches!(
-            publish_capture(&existing, replacement),
+            publish_capture(replacement),
             CaptureStatus::Completed(_)
         ));
         assert_eq!(fs::read(&existing)?, SCREENSHOT_CONTENT);
@@ -359,10 +376,29 @@ mod tests {
         let absent = temp_dir.path().join(ABSENT_DESTINATION_NAME);
         let created = completed_capture(&absent)?;
         assert!(matches!(
-            publish_capture(&absent, created),
+            publish_capture(created),
             CaptureStatus::Completed(_)
         ));
         assert_eq!(fs::read(&absent)?, SCREENSHOT_CONTENT);
         Ok(())
     }
+
+    #[test]
+    fn inline_publication_returns_the_png_as_base64() {
+        let capture = FinishedCapture {
+            metadata: CaptureMetadata {
+                dimensions:        UVec2::ONE,
+                response_metadata: CaptureResponseMetadata::Full,
+            },
+            artifact: CaptureArtifact::Inline(SCREENSHOT_CONTENT.to_vec()),
+        };
+
+        assert!(matches!(
+            publish_capture(capture),
+            CaptureStatus::Completed(response)
+                if response["data"] == STANDARD.encode(SCREENSHOT_CONTENT)
+                    && response["mime_type"] == "image/png"
+                    && response.get("path").is_none()
+        ));
+    }
 }
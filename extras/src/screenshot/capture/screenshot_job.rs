@@ -1,7 +1,10 @@
 //! Screenshot jobs, worker completion, and temporary-file ownership.
 
+#[cfg(not(target_arch = "wasm32"))]
 use std::io::Write;
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
 use std::sync::Mutex;
 use std::sync::mpsc;
@@ -10,21 +13,29 @@ use std::sync::mpsc::Sender;
 
 use bevy::prelude::*;
 use bevy::tasks::AsyncComputeTaskPool;
+#[cfg(not(target_arch = "wasm32"))]
 use bevy::tasks::IoTaskPool;
+#[cfg(not(target_arch = "wasm32"))]
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
+#[cfg(not(target_arch = "wasm32"))]
+use serde_json::json;
+#[cfg(not(target_arch = "wasm32"))]
 use tempfile::NamedTempFile;
+#[cfg(not(target_arch = "wasm32"))]
 use tempfile::TempPath;
 
 use super::target_rgb_image::EncodedCapture;
 use super::target_rgb_image::TargetRgbImage;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::BrpExtrasError;
 use crate::screenshot::CaptureResponseMetadata;
+use crate::screenshot::request::ScreenshotOutput;
 
 pub(super) type ImageConverter = fn(Image) -> BrpResult<TargetRgbImage>;
 
 pub(super) struct ScreenshotJob {
-    pub(super) path:              PathBuf,
+    pub(super) output:            ScreenshotOutput,
     pub(super) crop:              Option<URect>,
     pub(super) response_metadata: CaptureResponseMetadata,
 }
@@ -34,13 +45,25 @@ pub(super) struct CaptureMetadata {
     pub(super) response_metadata: CaptureResponseMetadata,
 }
 
-pub(super) struct OwnedTempCapture {
-    pub(super) metadata:  CaptureMetadata,
-    pub(super) temp_path: TempPath,
+/// Encoded PNG in the form its `ScreenshotOutput` publishes.
+pub(super) enum CaptureArtifact {
+    /// Temporary file beside `destination`, persisted on publication and removed on drop.
+    #[cfg(not(target_arch = "wasm32"))]
+    TempFile {
+        destination: PathBuf,
+        temp_path:   TempPath,
+    },
+    /// PNG bytes returned in the response.
+    Inline(Vec<u8>),
+}
+
+pub(super) struct FinishedCapture {
+    pub(super) metadata: CaptureMetadata,
+    pub(super) artifact: CaptureArtifact,
 }
 
 pub(super) struct WorkerCompletion {
-    pub(super) result: BrpResult<OwnedTempCapture>,
+    pub(super) result: BrpResult<FinishedCapture>,
 }
 
 pub(super) struct CaptureCompletionChannel {
@@ -71,13 +94,29 @@ pub(super) fn start_capture_worker(
             let prepared_job = prepare_capture_job(image, screenshot_job, converter);
             match prepared_job.encoded_capture {
                 Ok(encoded_capture) => {
-                    IoTaskPool::get()
-                        .spawn(async move {
-                            let completion =
-                                write_temporary_capture(prepared_job.job, encoded_capture);
-                            let _ = sender.send(completion);
-                        })
-                        .detach();
+                    let ScreenshotJob {
+                        output,
+                        response_metadata,
+                        ..
+                    } = prepared_job.job;
+                    match output {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        ScreenshotOutput::File(path) => {
+                            IoTaskPool::get()
+                                .spawn(async move {
+                                    let completion = write_temporary_capture(
+                                        path,
+                                        response_metadata,
+                                        encoded_capture,
+                                    );
+                                    let _ = sender.send(completion);
+                                })
+                                .detach();
+                        },
+                        ScreenshotOutput::Inline => {
+                            let _ = sender.send(inline_capture(response_metadata, encoded_capture));
+                        },
+                    }
                 },
                 Err(error) => {
                     let _ = sender.send(WorkerCompletion { result: Err(error) });
@@ -109,69 +148,105 @@ fn prepare_capture_job(
     }
 }
 
+fn inline_capture(
+    response_metadata: CaptureResponseMetadata,
+    encoded_capture: EncodedCapture,
+) -> WorkerCompletion {
+    WorkerCompletion {
+        result: Ok(FinishedCapture {
+            metadata: CaptureMetadata {
+                dimensions: encoded_capture.dimensions,
+                response_metadata,
+            },
+            artifact: CaptureArtifact::Inline(encoded_capture.bytes),
+        }),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 fn write_temporary_capture(
-    job: ScreenshotJob,
+    destination: PathBuf,
+    response_metadata: CaptureResponseMetadata,
     encoded_capture: EncodedCapture,
 ) -> WorkerCompletion {
-    let result = create_temporary_file(&job.path, &encoded_capture.bytes).map(|temp_path| {
-        OwnedTempCapture {
+    let result = create_temporary_file(&destination, &encoded_capture.bytes).map(|temp_path| {
+        FinishedCapture {
             metadata: CaptureMetadata {
-                dimensions:        encoded_capture.dimensions,
-                response_metadata: job.response_metadata.clone(),
+                dimensions: encoded_capture.dimensions,
+                response_metadata,
+            },
+            artifact: CaptureArtifact::TempFile {
+                destination,
+                temp_path,
             },
-            temp_path,
         }
     });
 
     WorkerCompletion { result }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub(super) fn create_temporary_file(destination: &Path, bytes: &[u8]) -> BrpResult<TempPath> {
     let parent = destination.parent().ok_or_else(|| {
-        capture_error(format!(
-            "Screenshot destination {} has no parent directory",
-            destination.display()
-        ))
+        io_error(
+            destination,
+            format!(
+                "Screenshot destination {} has no parent directory",
+                destination.display()
+            ),
+        )
     })?;
     std::fs::create_dir_all(parent).map_err(|error| {
-        capture_error(format!(
-            "Failed to create screenshot directory {}: {error}",
-            parent.display()
-        ))
+        io_error(
+            destination,
+            format!(
+                "Failed to create screenshot directory {}: {error}",
+                parent.display()
+            ),
+        )
     })?;
 
     let mut named_temp_file = NamedTempFile::new_in(parent).map_err(|error| {
-        capture_error(format!(
-            "Failed to create temporary screenshot beside {}: {error}",
-            destination.display()
-        ))
+        io_error(
+            destination,
+            format!(
+                "Failed to create temporary screenshot beside {}: {error}",
+                destination.display()
+            ),
+        )
     })?;
     named_temp_file.write_all(bytes).map_err(|error| {
-        capture_error(format!(
-            "Failed to write temporary screenshot for {}: {error}",
-            destination.display()
-        ))
+        io_error(
+            destination,
+            format!(
+                "Failed to write temporary screenshot for {}: {error}",
+                destination.display()
+            ),
+        )
     })?;
     named_temp_file.flush().map_err(|error| {
-        capture_error(format!(
-            "Failed to flush temporary screenshot for {}: {error}",
-            destination.display()
-        ))
+        io_error(
+            destination,
+            format!(
+                "Failed to flush temporary screenshot for {}: {error}",
+                destination.display()
+            ),
+        )
     })?;
     let (file, temp_path) = named_temp_file.into_parts();
     drop(file);
     Ok(temp_path)
 }
 
-fn capture_error(message: impl Into<String>) -> BrpError {
-    BrpError {
-        code:    INTERNAL_ERROR,
-        message: message.into(),
-        data:    None,
-    }
+#[cfg(not(target_arch = "wasm32"))]
+fn io_error(destination: &Path, message: String) -> BrpError {
+    BrpExtrasError::Io.with_details(
+        message,
+        json!({ "path": destination.display().to_string() }),
+    )
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use std::error::Error;
     use std::fs;
@@ -206,9 +281,9 @@ mod tests {
         )
     }
 
-    fn job(path: PathBuf, crop: Option<URect>) -> ScreenshotJob {
+    const fn job(path: PathBuf, crop: Option<URect>) -> ScreenshotJob {
         ScreenshotJob {
-            path,
+            output: ScreenshotOutput::File(path),
             crop,
             response_metadata: CaptureResponseMetadata::Full,
         }
@@ -5,11 +5,12 @@ use std::io::Cursor;
 use bevy::prelude::*;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
 use image::ImageError;
 use image::ImageFormat;
 use image::RgbImage;
 
+use crate::error::BrpExtrasError;
+
 pub(super) struct TargetRgbImage(RgbImage);
 
 impl TryFrom<Image> for TargetRgbImage {
@@ -77,13 +78,7 @@ pub(super) struct EncodedCapture {
     pub(super) dimensions: UVec2,
 }
 
-fn capture_error(message: impl Into<String>) -> BrpError {
-    BrpError {
-        code:    INTERNAL_ERROR,
-        message: message.into(),
-        data:    None,
-    }
-}
+fn capture_error(message: impl Into<String>) -> BrpError { BrpExtrasError::Internal.error(message) }
 
 fn png_encoding_error(error: ImageError) -> BrpError {
     capture_error(format!("Failed to encode captured image as PNG: {error}"))
@@ -1,12 +1,9 @@
 //! Screenshot request handling for BRP extras.
 
-#[cfg(not(target_arch = "wasm32"))]
 mod aabb;
-#[cfg(not(target_arch = "wasm32"))]
 mod capture;
-#[cfg(not(target_arch = "wasm32"))]
 mod request;
-#[cfg(all(feature = "ui", not(target_arch = "wasm32")))]
+#[cfg(feature = "ui")]
 mod ui;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -14,123 +11,82 @@ use std::path::Path;
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
 
-#[cfg(not(target_arch = "wasm32"))]
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use bevy::asset::RenderAssetUsages;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::camera::NormalizedRenderTarget;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::camera::RenderTarget;
-#[cfg(all(not(feature = "ui"), not(target_arch = "wasm32")))]
+#[cfg(not(feature = "ui"))]
 use bevy::camera::primitives::Aabb;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::camera::primitives::Frustum;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::camera::visibility::RenderLayers;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::camera::visibility::VisibleEntities;
-use bevy::ecs::system::In;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::ecs::world::EntityRef;
-use bevy::prelude::App;
-use bevy::prelude::Plugin;
-use bevy::prelude::World;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::prelude::*;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::render::texture::ManualTextureViews;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::render::view::screenshot::Screenshot;
-#[cfg(not(target_arch = "wasm32"))]
 use bevy::window::PrimaryWindow;
+use bevy::window::WindowRef;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
-#[cfg(not(target_arch = "wasm32"))]
-use bevy_remote::error_codes::INVALID_PARAMS;
 use serde_json::Value;
-#[cfg(not(target_arch = "wasm32"))]
 use serde_json::json;
 
-#[cfg(not(target_arch = "wasm32"))]
 use self::capture::CaptureInput;
-#[cfg(not(target_arch = "wasm32"))]
 use self::capture::CapturePlugin;
-#[cfg(not(target_arch = "wasm32"))]
 use self::capture::PendingScreenshotCapture;
-#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use self::request::RawScreenshotRequest;
 use self::request::ScreenshotRequest;
-#[cfg(not(target_arch = "wasm32"))]
 use self::request::ScreenshotScope;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::CAMERA_CANDIDATES_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::IMAGE_EXTENSION_PNG;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::PARAM_CAMERA;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::PARAM_ENTITY;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::constants::PARAM_PATH;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_BOUNDS_KIND_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_CAPTURE_KIND_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
+use crate::constants::RESPONSE_DATA_FIELD;
+use crate::constants::RESPONSE_ENCODING_FIELD;
 use crate::constants::RESPONSE_HEIGHT_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
+use crate::constants::RESPONSE_MIME_TYPE_FIELD;
 use crate::constants::RESPONSE_NAME_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_NOTE_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_REASON_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_RECT_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_STATUS_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_SUCCESS_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_WIDTH_FIELD;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_WORKING_DIRECTORY_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_X_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::RESPONSE_Y_FIELD;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::SCREENSHOT_BOUNDS_KIND_AABB;
-#[cfg(all(feature = "ui", not(target_arch = "wasm32")))]
+#[cfg(feature = "ui")]
 use crate::constants::SCREENSHOT_BOUNDS_KIND_UI;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::SCREENSHOT_CAMERA_REASON_AMBIGUOUS;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::SCREENSHOT_CAPTURE_KIND_ENTITY;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::constants::SCREENSHOT_CAPTURE_NOTE;
-#[cfg(not(target_arch = "wasm32"))]
+use crate::constants::SCREENSHOT_ENCODING_BASE64;
+use crate::constants::SCREENSHOT_MIME_TYPE_PNG;
 use crate::constants::SCREENSHOT_STATUS_COMPLETED;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::constants::UNKNOWN_WORKING_DIRECTORY;
+use crate::error::BrpExtrasError;
+use crate::headless;
 
 pub(super) struct ScreenshotPlugin;
 
 impl Plugin for ScreenshotPlugin {
-    fn build(&self, app: &mut App) {
-        #[cfg(not(target_arch = "wasm32"))]
-        app.add_plugins(CapturePlugin);
-        #[cfg(target_arch = "wasm32")]
-        let _ = app;
-    }
+    fn build(&self, app: &mut App) { app.add_plugins(CapturePlugin); }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(super) enum CaptureResponseMetadata {
     Full,
     Entity(EntityResponseMetadata),
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(super) struct EntityResponseMetadata {
     bounds_kind: BoundsKind,
@@ -140,7 +96,6 @@ pub(super) struct EntityResponseMetadata {
     rect:        URect,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum BoundsKind {
     Aabb,
@@ -148,7 +103,6 @@ enum BoundsKind {
     Ui,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 struct ValidatedCameraTarget {
     camera:        Camera,
     #[cfg(feature = "ui")]
@@ -158,7 +112,6 @@ struct ValidatedCameraTarget {
     target_size:   UVec2,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 struct SelectedCamera {
     camera:           Camera,
     entity:           Entity,
@@ -170,7 +123,6 @@ struct SelectedCamera {
 }
 
 /// Handles the terminal `brp_extras/screenshot` watching request.
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) fn handler(
     In(params): In<Option<Value>>,
     world: &mut World,
@@ -190,25 +142,9 @@ pub(crate) fn handler(
     Ok(None)
 }
 
-/// Returns an actionable error on targets without filesystem publication.
-#[cfg(target_arch = "wasm32")]
-pub(crate) fn handler(
-    In(params): In<Option<Value>>,
-    world: &mut World,
-) -> BrpResult<Option<Value>> {
-    drop(params);
-    let _ = world;
-    Err(BrpError {
-        code:    INTERNAL_ERROR,
-        message: "Screenshot PNG publication is unsupported on WASM; use a native target with filesystem access"
-            .to_string(),
-        data:    None,
-    })
-}
-
 #[cfg(not(target_arch = "wasm32"))]
 fn completed_response(path: &Path, metadata: &CaptureResponseMetadata) -> Value {
-    let mut response = json!({
+    let response = json!({
         RESPONSE_SUCCESS_FIELD: true,
         PARAM_PATH: path.to_string_lossy(),
         RESPONSE_WORKING_DIRECTORY_FIELD: std::env::current_dir()
@@ -218,6 +154,24 @@ fn completed_response(path: &Path, metadata: &CaptureResponseMetadata) -> Value
         RESPONSE_STATUS_FIELD: SCREENSHOT_STATUS_COMPLETED,
     });
 
+    with_entity_metadata(response, metadata)
+}
+
+/// Builds the response for an inline capture, which carries the PNG itself base64-encoded.
+fn inline_response(png: &[u8], metadata: &CaptureResponseMetadata) -> Value {
+    let response = json!({
+        RESPONSE_SUCCESS_FIELD: true,
+        RESPONSE_DATA_FIELD: STANDARD.encode(png),
+        RESPONSE_ENCODING_FIELD: SCREENSHOT_ENCODING_BASE64,
+        RESPONSE_MIME_TYPE_FIELD: SCREENSHOT_MIME_TYPE_PNG,
+        RESPONSE_NOTE_FIELD: SCREENSHOT_CAPTURE_NOTE,
+        RESPONSE_STATUS_FIELD: SCREENSHOT_STATUS_COMPLETED,
+    });
+
+    with_entity_metadata(response, metadata)
+}
+
+fn with_entity_metadata(mut response: Value, metadata: &CaptureResponseMetadata) -> Value {
     if let CaptureResponseMetadata::Entity(metadata) = metadata {
         response[RESPONSE_CAPTURE_KIND_FIELD] = json!(SCREENSHOT_CAPTURE_KIND_ENTITY);
         response[PARAM_ENTITY] = json!(metadata.entity.to_bits());
@@ -241,10 +195,20 @@ fn completed_response(path: &Path, metadata: &CaptureResponseMetadata) -> Value
     response
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn capture_input(world: &mut World, request: &ScreenshotRequest) -> BrpResult<CaptureInput> {
+    headless::ensure_render_adapter(world)?;
     match request.scope() {
-        ScreenshotScope::Full { camera } => full_capture_input(world, *camera),
+        ScreenshotScope::Full {
+            camera,
+            window,
+            region,
+        } => {
+            let mut input = full_capture_input(world, *camera, *window)?;
+            if let Some(region) = region {
+                input.crop = Some(region_crop(world, &input, *region)?);
+            }
+            Ok(input)
+        },
         ScreenshotScope::Entity {
             entity,
             camera,
@@ -253,8 +217,11 @@ fn capture_input(world: &mut World, request: &ScreenshotRequest) -> BrpResult<Ca
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn full_capture_input(world: &World, requested_camera: Option<Entity>) -> BrpResult<CaptureInput> {
+fn full_capture_input(
+    world: &World,
+    requested_camera: Option<Entity>,
+    requested_window: Option<Entity>,
+) -> BrpResult<CaptureInput> {
     if let Some(camera) = requested_camera {
         let validated = validated_camera_target(world, camera, primary_window(world))
             .ok_or_else(|| invalid_camera_error(camera))?;
@@ -265,6 +232,22 @@ fn full_capture_input(world: &World, requested_camera: Option<Entity>) -> BrpRes
         });
     }
 
+    headless::ensure_window(world)?;
+    if let Some(window) = requested_window {
+        let render_target = RenderTarget::Window(WindowRef::Entity(window));
+        let live = render_target
+            .normalize(None)
+            .and_then(|target| live_target_size(world, &target));
+        if live.is_none() {
+            return Err(invalid_window_error(window));
+        }
+        return Ok(CaptureInput {
+            crop: None,
+            render_target,
+            response_metadata: CaptureResponseMetadata::Full,
+        });
+    }
+
     let primary_window = primary_window(world).ok_or_else(no_primary_window_error)?;
     let render_target = Screenshot::primary_window().0;
     let normalized_target = render_target
@@ -281,7 +264,6 @@ fn full_capture_input(world: &World, requested_camera: Option<Entity>) -> BrpRes
     })
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn entity_capture_input(
     world: &mut World,
     entity: Entity,
@@ -322,7 +304,6 @@ fn entity_capture_input(
     ))
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn entity_capture_from_parts(
     world: &World,
     entity: Entity,
@@ -348,7 +329,6 @@ fn entity_capture_from_parts(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn select_camera(world: &mut World, requested: Option<Entity>) -> BrpResult<SelectedCamera> {
     let primary_window = primary_window(world);
     if let Some(camera) = requested {
@@ -370,7 +350,6 @@ fn select_camera(world: &mut World, requested: Option<Entity>) -> BrpResult<Sele
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn eligible_camera(
     world: &World,
     entity: Entity,
@@ -389,7 +368,6 @@ fn eligible_camera(
     })
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn validated_camera_target(
     world: &World,
     entity: Entity,
@@ -420,7 +398,6 @@ fn validated_camera_target(
     })
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn live_target_size(world: &World, target: &NormalizedRenderTarget) -> Option<UVec2> {
     let size = match target {
         NormalizedRenderTarget::Window(window) => {
@@ -447,7 +424,6 @@ fn live_target_size(world: &World, target: &NormalizedRenderTarget) -> Option<UV
     size.cmpgt(UVec2::ZERO).all().then_some(size)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn primary_window(world: &World) -> Option<Entity> {
     world
         .iter_entities()
@@ -455,84 +431,101 @@ fn primary_window(world: &World) -> Option<Entity> {
         .map(|entity| entity.id())
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+/// Crop of a full capture to `region`, which is relative to the camera viewport when the capture
+/// has one and to the whole render target otherwise
+fn region_crop(world: &World, input: &CaptureInput, region: URect) -> BrpResult<URect> {
+    let bounds = match input.crop {
+        Some(viewport) => viewport,
+        None => input
+            .render_target
+            .normalize(primary_window(world))
+            .and_then(|target| live_target_size(world, &target))
+            .map(|size| URect::from_corners(UVec2::ZERO, size))
+            .ok_or_else(no_primary_window_error)?,
+    };
+    let crop = URect::from_corners(bounds.min + region.min, bounds.min + region.max);
+    if crop.max.cmpgt(bounds.max).any() {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "Screenshot region extends past the {}x{} capture",
+                bounds.width(),
+                bounds.height()
+            ),
+            json!({
+                "field": "region",
+                "region": [region.min.x, region.min.y, region.width(), region.height()],
+                RESPONSE_WIDTH_FIELD: bounds.width(),
+                RESPONSE_HEIGHT_FIELD: bounds.height(),
+            }),
+        ));
+    }
+    Ok(crop)
+}
+
 fn ensure_png_support() -> BrpResult<()> {
     if bevy::image::ImageFormat::from_extension(IMAGE_EXTENSION_PNG).is_some() {
         return Ok(());
     }
 
-    Err(BrpError {
-        code:    INTERNAL_ERROR,
-        message: "PNG support not available. Enable the 'png' feature in your Bevy dependency"
-            .to_string(),
-        data:    None,
-    })
+    Err(BrpExtrasError::Unsupported
+        .error("PNG support not available. Enable the 'png' feature in your Bevy dependency"))
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn no_primary_window_error() -> BrpError {
-    BrpError {
-        code:    INTERNAL_ERROR,
-        message: "Screenshot capture requires a primary window".to_string(),
-        data:    None,
-    }
+    BrpExtrasError::WindowNotFound.error("Screenshot capture requires a primary window")
+}
+
+fn invalid_window_error(window: Entity) -> BrpError {
+    BrpExtrasError::WindowNotFound.with_details(
+        format!("Invalid screenshot window: {}", window.to_bits()),
+        json!({ "window": window.to_bits() }),
+    )
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn invalid_entity_error(entity: Entity) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Invalid screenshot entity: {}", entity.to_bits()),
-        data:    None,
-    }
+    BrpExtrasError::EntityNotFound.with_details(
+        format!("Invalid screenshot entity: {}", entity.to_bits()),
+        json!({ PARAM_ENTITY: entity.to_bits() }),
+    )
 }
 
-#[cfg(all(not(feature = "ui"), not(target_arch = "wasm32")))]
+#[cfg(not(feature = "ui"))]
 fn unsupported_bounds_error(entity: Entity) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!(
+    BrpExtrasError::InvalidParams.with_details(
+        format!(
             "Screenshot entity {} has no supported bounds; UI bounds support is disabled",
             entity.to_bits()
         ),
-        data:    None,
-    }
+        json!({ PARAM_ENTITY: entity.to_bits() }),
+    )
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn invalid_camera_error(camera: Entity) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!(
+    BrpExtrasError::CameraUnavailable.with_details(
+        format!(
             "Screenshot camera {} is missing, inactive, uninitialized, or has an unsupported target",
             camera.to_bits()
         ),
-        data:    None,
-    }
+        json!({ PARAM_CAMERA: camera.to_bits() }),
+    )
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn no_camera_error() -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: "Entity screenshot capture requires one eligible active camera".to_string(),
-        data:    None,
-    }
+    BrpExtrasError::CameraUnavailable
+        .error("Entity screenshot capture requires one eligible active camera")
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn ambiguous_camera_error(candidates: &[SelectedCamera]) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: "Entity screenshot capture has multiple eligible active cameras".to_string(),
-        data:    Some(json!({
+    BrpExtrasError::CameraUnavailable.with_details(
+        "Entity screenshot capture has multiple eligible active cameras",
+        json!({
             RESPONSE_REASON_FIELD: SCREENSHOT_CAMERA_REASON_AMBIGUOUS,
             CAMERA_CANDIDATES_FIELD: candidates
                 .iter()
                 .map(|candidate| candidate.entity.to_bits())
                 .collect::<Vec<_>>(),
-        })),
-    }
+        }),
+    )
 }
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
@@ -567,6 +560,7 @@ mod native_tests {
     use tempfile::TempDir;
 
     use super::*;
+    use crate::constants::ERROR_DETAILS_FIELD;
     use crate::constants::METHOD_SCREENSHOT;
 
     const CAPTURE_TEST_TIMEOUT: Duration = Duration::from_secs(5);
@@ -863,7 +857,8 @@ mod native_tests {
         let candidates = ambiguity
             .data
             .as_ref()
-            .and_then(|data| data.get(CAMERA_CANDIDATES_FIELD))
+            .and_then(|data| data.get(ERROR_DETAILS_FIELD))
+            .and_then(|details| details.get(CAMERA_CANDIDATES_FIELD))
             .and_then(Value::as_array)
             .ok_or_else(|| io::Error::other("missing camera candidate data"))?;
         let mut expected = vec![first.to_bits(), second.to_bits()];
@@ -1083,8 +1078,7 @@ mod wasm_tests {
     use super::*;
 
     #[test]
-    fn unsupported_publication_returns_before_resource_or_job_creation()
-    -> Result<(), Box<dyn Error>> {
+    fn file_publication_is_rejected_before_job_creation() -> Result<(), Box<dyn Error>> {
         let mut app = App::new();
         app.add_plugins(ScreenshotPlugin);
         let initial_entities = app.world().entities().len();
@@ -1092,12 +1086,12 @@ mod wasm_tests {
 
         let result = app
             .world_mut()
-            .run_system_with(system_id, Some(json!({ "path": 42 })))
+            .run_system_with(system_id, Some(json!({ "path": "shot.png" })))
             .map_err(|error| io::Error::other(error.to_string()))?;
 
         assert!(matches!(
             result,
-            Err(error) if error.message.contains("unsupported on WASM")
+            Err(error) if error.message.contains("cannot be written on WASM")
         ));
         assert_eq!(app.world().entities().len(), initial_entities);
         Ok(())
@@ -1,23 +1,34 @@
 //! Screenshot wire request decoding.
 
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::Component;
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
 
+use bevy::math::URect;
+use bevy::math::UVec2;
 use bevy::prelude::Entity;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INTERNAL_ERROR;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
+use serde_json::json;
 
+use crate::constants::PARAM_INLINE;
+use crate::constants::PARAM_PATH;
 use crate::constants::SCREENSHOT_ZERO_PADDING;
+use crate::error::BrpExtrasError;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(super) enum ScreenshotScope {
     Full {
         camera: Option<Entity>,
+        window: Option<Entity>,
+        /// Part of the captured image to keep, relative to its top-left corner
+        region: Option<URect>,
     },
     Entity {
         entity:  Entity,
@@ -26,40 +37,69 @@ pub(super) enum ScreenshotScope {
     },
 }
 
+/// Where the finished PNG goes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum ScreenshotOutput {
+    /// Publish the PNG atomically at this absolute path.
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+    /// Return the PNG base64-encoded in the response.
+    Inline,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(super) struct ScreenshotRequest {
-    path:  PathBuf,
-    scope: ScreenshotScope,
+    output: ScreenshotOutput,
+    scope:  ScreenshotScope,
 }
 
 impl ScreenshotRequest {
     pub(super) fn from_params(params: Option<Value>) -> BrpResult<Self> {
-        let value = params.ok_or_else(missing_path_error)?;
-        let raw =
-            serde_json::from_value::<RawScreenshotRequest>(value).map_err(|error| BrpError {
-                code:    INVALID_PARAMS,
-                message: format!("Invalid screenshot request: {error}"),
-                data:    None,
-            })?;
+        let value = params.unwrap_or_else(|| json!({}));
+        let raw = serde_json::from_value::<RawScreenshotRequest>(value).map_err(|error| {
+            BrpExtrasError::InvalidParams.error(format!("Invalid screenshot request: {error}"))
+        })?;
 
         let scope = ScreenshotScope::try_from(&raw)?;
-        let raw_path = raw.path.ok_or_else(missing_path_error)?;
-        let path = absolute_path(&raw_path)?;
-        Ok(Self { path, scope })
+        let output = ScreenshotOutput::try_from(&raw)?;
+        Ok(Self { output, scope })
     }
 
-    pub(super) fn path(&self) -> &Path { &self.path }
+    pub(super) const fn output(&self) -> &ScreenshotOutput { &self.output }
 
     pub(super) const fn scope(&self) -> &ScreenshotScope { &self.scope }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
-struct RawScreenshotRequest {
+pub(crate) struct RawScreenshotRequest {
     camera:  Option<u64>,
     entity:  Option<u64>,
+    /// Return the PNG base64-encoded instead of writing `path`; the default on WASM
+    inline:  Option<bool>,
     padding: Option<u32>,
+    /// File to publish the PNG to; not available on WASM
     path:    Option<String>,
+    /// Window entity to capture instead of the primary window
+    window:  Option<u64>,
+    /// `[x, y, width, height]` in physical pixels, relative to the window or camera viewport
+    region:  Option<[u32; 4]>,
+}
+
+impl TryFrom<&RawScreenshotRequest> for ScreenshotOutput {
+    type Error = BrpError;
+
+    fn try_from(raw: &RawScreenshotRequest) -> Result<Self, Self::Error> {
+        match (raw.inline, raw.path.as_deref()) {
+            (Some(true), Some(_)) => Err(BrpExtrasError::InvalidParams.with_details(
+                format!("'{PARAM_INLINE}' and '{PARAM_PATH}' are mutually exclusive"),
+                json!({ "field": PARAM_INLINE }),
+            )),
+            (Some(true), None) => Ok(Self::Inline),
+            (_, Some(path)) => file_output(path),
+            (_, None) => default_output(),
+        }
+    }
 }
 
 impl TryFrom<&RawScreenshotRequest> for ScreenshotScope {
@@ -67,6 +107,8 @@ impl TryFrom<&RawScreenshotRequest> for ScreenshotScope {
 
     fn try_from(raw: &RawScreenshotRequest) -> Result<Self, Self::Error> {
         match raw.entity {
+            Some(_) if raw.window.is_some() => Err(full_scope_field_error("window")),
+            Some(_) if raw.region.is_some() => Err(full_scope_field_error("region")),
             Some(entity) => Ok(Self::Entity {
                 entity:  decode_entity_id(entity, "entity")?,
                 camera:  raw
@@ -76,34 +118,84 @@ impl TryFrom<&RawScreenshotRequest> for ScreenshotScope {
                 padding: raw.padding.unwrap_or(SCREENSHOT_ZERO_PADDING),
             }),
             None if raw.padding.is_some() => Err(entity_scope_field_error("padding")),
+            None if raw.camera.is_some() && raw.window.is_some() => {
+                Err(BrpExtrasError::InvalidParams.with_details(
+                    "'camera' and 'window' are mutually exclusive",
+                    json!({ "field": "window" }),
+                ))
+            },
             None => Ok(Self::Full {
                 camera: raw
                     .camera
                     .map(|camera| decode_entity_id(camera, "camera"))
                     .transpose()?,
+                window: raw
+                    .window
+                    .map(|window| decode_entity_id(window, "window"))
+                    .transpose()?,
+                region: raw.region.map(decode_region).transpose()?,
             }),
         }
     }
 }
 
 fn decode_entity_id(bits: u64, field: &str) -> BrpResult<Entity> {
-    Entity::try_from_bits(bits).ok_or_else(|| BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Invalid '{field}' entity ID: {bits}"),
-        data:    None,
+    Entity::try_from_bits(bits).ok_or_else(|| {
+        BrpExtrasError::EntityNotFound.with_details(
+            format!("Invalid '{field}' entity ID: {bits}"),
+            json!({ "field": field, "entity": bits }),
+        )
     })
 }
 
+fn decode_region([x, y, width, height]: [u32; 4]) -> BrpResult<URect> {
+    if width == 0 || height == 0 {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            "'region' must have a non-zero width and height",
+            json!({ "field": "region", "region": [x, y, width, height] }),
+        ));
+    }
+    let min = UVec2::new(x, y);
+    let max = min.checked_add(UVec2::new(width, height)).ok_or_else(|| {
+        BrpExtrasError::InvalidParams.with_details(
+            "'region' extends past the largest pixel coordinate",
+            json!({ "field": "region", "region": [x, y, width, height] }),
+        )
+    })?;
+    Ok(URect::from_corners(min, max))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn file_output(path: &str) -> BrpResult<ScreenshotOutput> {
+    absolute_path(path).map(ScreenshotOutput::File)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn file_output(_path: &str) -> BrpResult<ScreenshotOutput> {
+    Err(BrpExtrasError::Unsupported.with_details(
+        format!(
+            "Screenshot files cannot be written on WASM; omit '{PARAM_PATH}' to receive the PNG inline"
+        ),
+        json!({ "field": PARAM_PATH }),
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_output() -> BrpResult<ScreenshotOutput> { Err(missing_path_error()) }
+
+/// Browsers have no filesystem to publish to, so WASM captures are returned inline.
+#[cfg(target_arch = "wasm32")]
+const fn default_output() -> BrpResult<ScreenshotOutput> { Ok(ScreenshotOutput::Inline) }
+
+#[cfg(not(target_arch = "wasm32"))]
 fn absolute_path(path: &str) -> BrpResult<PathBuf> {
     let path = Path::new(path);
     let absolute = if path.is_absolute() {
         path.to_path_buf()
     } else {
         std::env::current_dir()
-            .map_err(|error| BrpError {
-                code:    INTERNAL_ERROR,
-                message: format!("Failed to get current directory: {error}"),
-                data:    None,
+            .map_err(|error| {
+                BrpExtrasError::Io.error(format!("Failed to get current directory: {error}"))
             })?
             .join(path)
     };
@@ -111,22 +203,27 @@ fn absolute_path(path: &str) -> BrpResult<PathBuf> {
     Ok(normalize_path(&absolute))
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn missing_path_error() -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: "Missing 'path' parameter".to_string(),
-        data:    None,
-    }
+    BrpExtrasError::MissingParams
+        .with_details("Missing 'path' parameter", json!({ "field": PARAM_PATH }))
+}
+
+fn full_scope_field_error(field: &str) -> BrpError {
+    BrpExtrasError::InvalidParams.with_details(
+        format!("'{field}' cannot be combined with an 'entity' screenshot scope"),
+        json!({ "field": field }),
+    )
 }
 
 fn entity_scope_field_error(field: &str) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("'{field}' requires an 'entity' screenshot scope"),
-        data:    None,
-    }
+    BrpExtrasError::InvalidParams.with_details(
+        format!("'{field}' requires an 'entity' screenshot scope"),
+        json!({ "field": field }),
+    )
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn normalize_path(path: &Path) -> PathBuf {
     let mut normalized = PathBuf::new();
 
@@ -145,7 +242,7 @@ fn normalize_path(path: &Path) -> PathBuf {
     normalized
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use serde_json::json;
 
@@ -184,6 +281,8 @@ mod tests {
             Ok(request)
                 if request.scope == ScreenshotScope::Full {
                     camera: None,
+                    window: None,
+                    region: None,
                 }
         ));
         assert!(matches!(
@@ -191,6 +290,8 @@ mod tests {
             Ok(request)
                 if request.scope == ScreenshotScope::Full {
                     camera: Some(Entity::from_bits(9)),
+                    window: None,
+                    region: None,
                 }
         ));
         assert!(matches!(
@@ -232,6 +333,42 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn window_and_region_select_part_of_a_full_capture() {
+        let request = ScreenshotRequest::from_params(Some(json!({
+            "window": 5,
+            "region": [10, 20, 30, 40],
+            "path": "panel.png"
+        })));
+        let empty = ScreenshotRequest::from_params(Some(json!({
+            "region": [10, 20, 0, 40],
+            "path": "panel.png"
+        })));
+        let with_entity = ScreenshotRequest::from_params(Some(json!({
+            "entity": 7,
+            "region": [10, 20, 30, 40],
+            "path": "panel.png"
+        })));
+        let with_camera = ScreenshotRequest::from_params(Some(json!({
+            "camera": 9,
+            "window": 5,
+            "path": "panel.png"
+        })));
+
+        assert!(matches!(
+            request,
+            Ok(request)
+                if request.scope == ScreenshotScope::Full {
+                    camera: None,
+                    window: Some(Entity::from_bits(5)),
+                    region: Some(URect::new(10, 20, 40, 60)),
+                }
+        ));
+        assert!(matches!(empty, Err(error) if error.message.contains("non-zero")));
+        assert!(matches!(with_entity, Err(error) if error.message.contains("'region' cannot")));
+        assert!(matches!(with_camera, Err(error) if error.message.contains("mutually exclusive")));
+    }
+
     #[test]
     fn padding_requires_entity_scope() {
         let result = ScreenshotRequest::from_params(Some(json!({
@@ -245,6 +382,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn entity_scope_rejects_window_and_region() {
+        let window = ScreenshotRequest::from_params(Some(json!({
+            "entity": 7,
+            "window": 5,
+            "path": "entity.png"
+        })));
+        let region = ScreenshotRequest::from_params(Some(json!({
+            "entity": 7,
+            "region": [0, 0, 10, 10],
+            "path": "entity.png"
+        })));
+
+        assert!(matches!(
+            window,
+            Err(error) if error.message.contains("'window' cannot be combined")
+        ));
+        assert!(matches!(
+            region,
+            Err(error) if error.message.contains("'region' cannot be combined")
+        ));
+    }
+
     #[test]
     fn invalid_entity_and_camera_bit_patterns_are_field_specific_errors() {
         let invalid_bits = 0;
@@ -261,6 +421,23 @@ mod tests {
         assert!(matches!(camera, Err(error) if error.message.contains("'camera'")));
     }
 
+    #[test]
+    fn inline_output_replaces_the_required_path() {
+        let inline = ScreenshotRequest::from_params(Some(json!({ "inline": true })));
+        let both = ScreenshotRequest::from_params(Some(json!({
+            "inline": true,
+            "path": "shot.png"
+        })));
+        let neither = ScreenshotRequest::from_params(None);
+
+        assert!(matches!(
+            inline,
+            Ok(request) if request.output == ScreenshotOutput::Inline
+        ));
+        assert!(matches!(both, Err(error) if error.message.contains("mutually exclusive")));
+        assert!(matches!(neither, Err(error) if error.message.contains("Missing 'path'")));
+    }
+
     #[test]
     fn extras_request_rejects_name_field() {
         let name = ScreenshotRequest::from_params(Some(json!({
@@ -11,11 +11,12 @@ use bevy::ui::UiGlobalTransform;
 use bevy_kana::ToU32;
 use bevy_remote::BrpError;
 use bevy_remote::BrpResult;
-use bevy_remote::error_codes::INVALID_PARAMS;
+use serde_json::json;
 
 use super::ValidatedCameraTarget;
 use super::primary_window;
 use super::validated_camera_target;
+use crate::error::BrpExtrasError;
 
 pub(super) struct ResolvedUiCapture {
     pub(super) camera: ValidatedCameraTarget,
@@ -191,11 +192,8 @@ fn transformed_rect(
 
 fn containing_rect(rect: Rect) -> BrpResult<URect> {
     if !rect.min.is_finite() || !rect.max.is_finite() {
-        return Err(BrpError {
-            code:    INVALID_PARAMS,
-            message: "UI screenshot bounds contain non-finite coordinates".to_string(),
-            data:    None,
-        });
+        return Err(BrpExtrasError::InvalidParams
+            .error("UI screenshot bounds contain non-finite coordinates"));
     }
     Ok(URect::from_corners(
         UVec2::new(rect.min.x.floor().to_u32(), rect.min.y.floor().to_u32()),
@@ -204,11 +202,10 @@ fn containing_rect(rect: Rect) -> BrpResult<URect> {
 }
 
 fn ui_error(entity: Entity, detail: &str) -> BrpError {
-    BrpError {
-        code:    INVALID_PARAMS,
-        message: format!("Screenshot UI entity {} {detail}", entity.to_bits()),
-        data:    None,
-    }
+    BrpExtrasError::InvalidParams.with_details(
+        format!("Screenshot UI entity {} {detail}", entity.to_bits()),
+        json!({ "entity": entity.to_bits() }),
+    )
 }
 
 fn invalid_ui_camera_error(entity: Entity, camera: Entity) -> BrpError {
@@ -518,7 +515,7 @@ mod tests {
 
         let error = resolution_error(resolve(test_ui.app.world_mut(), entity, None, 0))?;
 
-        assert_eq!(error.code, INVALID_PARAMS);
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
         assert!(
             error
                 .message
@@ -554,7 +551,7 @@ mod tests {
                 .insert(CalculatedClip { clip });
             let error = resolution_error(resolve(test_ui.app.world_mut(), entity, None, 0))?;
 
-            assert_eq!(error.code, INVALID_PARAMS);
+            assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
             assert!(error.message.contains("non-finite clip coordinates"));
         }
         Ok(())
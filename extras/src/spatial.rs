@@ -0,0 +1,263 @@
+//! Spatial query handler for BRP extras
+//!
+//! `query_spatial` filters entities by their `GlobalTransform` translation inside the app, so a
+//! client looking for "what is near the player" does not have to fetch every transform over BRP.
+
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `query_spatial`
+///
+/// Give either `center` and `radius` for a sphere, or `min` and `max` for an axis-aligned box.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct QuerySpatialRequest {
+    /// Center of the search sphere in world space
+    #[serde(default)]
+    center: Option<[f32; 3]>,
+    /// Radius of the search sphere
+    #[serde(default)]
+    radius: Option<f32>,
+    /// Minimum corner of the search box in world space
+    #[serde(default)]
+    min:    Option<[f32; 3]>,
+    /// Maximum corner of the search box in world space
+    #[serde(default)]
+    max:    Option<[f32; 3]>,
+    /// Only return this many entities, nearest first
+    #[serde(default)]
+    limit:  Option<usize>,
+}
+
+/// Region an entity's translation must lie in
+#[derive(Clone, Copy)]
+enum SpatialRegion {
+    Sphere { center: Vec3, radius: f32 },
+    Box { min: Vec3, max: Vec3 },
+}
+
+/// One entity inside the queried region
+#[derive(Serialize)]
+struct SpatialHit {
+    entity:      u64,
+    /// The entity's `Name`, if it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name:        Option<String>,
+    /// `GlobalTransform` translation
+    translation: [f32; 3],
+    /// Distance from the sphere center, or from the box center
+    distance:    f32,
+}
+
+/// Response structure for `query_spatial`
+#[derive(Serialize)]
+struct QuerySpatialResponse {
+    /// Entities in the region, nearest first
+    entities:    Vec<SpatialHit>,
+    /// Number of entities in the region before `limit` was applied
+    total_count: usize,
+    /// Point distances are measured from
+    origin:      [f32; 3],
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `query_spatial` BRP method
+///
+/// Returns entities with a `GlobalTransform` inside a sphere or box, sorted by distance.
+pub(crate) fn query_spatial_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: QuerySpatialRequest = parse_required(params)?;
+    let region = SpatialRegion::try_from(&request)?;
+    let origin = region.origin();
+
+    let mut query = world.query::<(Entity, &GlobalTransform, Option<&Name>)>();
+    let mut entities: Vec<SpatialHit> = query
+        .iter(world)
+        .filter_map(|(entity, transform, name)| {
+            let translation = transform.translation();
+            region.contains(translation).then(|| SpatialHit {
+                entity:      entity.to_bits(),
+                name:        name.map(|name| name.as_str().to_string()),
+                translation: translation.to_array(),
+                distance:    translation.distance(origin),
+            })
+        })
+        .collect();
+
+    let total_count = entities.len();
+    entities.sort_by(|a, b| {
+        a.distance
+            .total_cmp(&b.distance)
+            .then(a.entity.cmp(&b.entity))
+    });
+    if let Some(limit) = request.limit {
+        entities.truncate(limit);
+    }
+
+    serde_json::to_value(QuerySpatialResponse {
+        entities,
+        total_count,
+        origin: origin.to_array(),
+    })
+    .map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+impl SpatialRegion {
+    fn origin(self) -> Vec3 {
+        match self {
+            Self::Sphere { center, .. } => center,
+            Self::Box { min, max } => min.midpoint(max),
+        }
+    }
+
+    fn contains(self, point: Vec3) -> bool {
+        match self {
+            Self::Sphere { center, radius } => point.distance_squared(center) <= radius * radius,
+            Self::Box { min, max } => point.cmpge(min).all() && point.cmple(max).all(),
+        }
+    }
+}
+
+impl TryFrom<&QuerySpatialRequest> for SpatialRegion {
+    type Error = BrpError;
+
+    fn try_from(request: &QuerySpatialRequest) -> Result<Self, Self::Error> {
+        match (request.center, request.radius, request.min, request.max) {
+            (Some(center), Some(radius), None, None) => {
+                if !radius.is_finite() || radius < 0.0 {
+                    return Err(BrpExtrasError::InvalidParams.with_details(
+                        format!("radius must be a finite, non-negative number, got {radius}"),
+                        json!({ "radius": radius }),
+                    ));
+                }
+                Ok(Self::Sphere {
+                    center: Vec3::from_array(center),
+                    radius,
+                })
+            },
+            (None, None, Some(min), Some(max)) => {
+                let (min, max) = (Vec3::from_array(min), Vec3::from_array(max));
+                if !min.cmple(max).all() {
+                    return Err(BrpExtrasError::InvalidParams.with_details(
+                        "min must not be greater than max on any axis",
+                        json!({ "min": min.to_array(), "max": max.to_array() }),
+                    ));
+                }
+                Ok(Self::Box { min, max })
+            },
+            _ => Err(BrpExtrasError::InvalidParams.error(
+                "Provide either `center` and `radius` for a sphere or `min` and `max` for a box",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::GlobalTransform;
+    use bevy::prelude::In;
+    use bevy::prelude::Name;
+    use bevy::prelude::Vec3;
+    use serde_json::json;
+
+    use super::query_spatial_handler;
+    use crate::error::BrpExtrasError;
+
+    fn spawn_at(app: &mut App, name: &str, translation: Vec3) -> u64 {
+        app.world_mut()
+            .spawn((
+                Name::new(name.to_string()),
+                GlobalTransform::from_translation(translation),
+            ))
+            .id()
+            .to_bits()
+    }
+
+    #[test]
+    fn sphere_returns_entities_inside_the_radius_nearest_first() {
+        let mut app = App::new();
+        let far = spawn_at(&mut app, "Far", Vec3::new(4.0, 0.0, 0.0));
+        let near = spawn_at(&mut app, "Near", Vec3::new(1.0, 0.0, 0.0));
+        spawn_at(&mut app, "Outside", Vec3::new(10.0, 0.0, 0.0));
+
+        let response = query_spatial_handler(
+            In(Some(json!({ "center": [0.0, 0.0, 0.0], "radius": 5.0 }))),
+            app.world_mut(),
+        )
+        .expect("spatial query should succeed");
+
+        assert_eq!(response["total_count"], 2);
+        assert_eq!(response["entities"][0]["entity"], near);
+        assert_eq!(response["entities"][0]["name"], "Near");
+        assert_eq!(response["entities"][1]["entity"], far);
+        assert_eq!(response["entities"][1]["distance"], 4.0);
+    }
+
+    #[test]
+    fn box_is_inclusive_and_limit_keeps_the_nearest() {
+        let mut app = App::new();
+        let center = spawn_at(&mut app, "Center", Vec3::ZERO);
+        spawn_at(&mut app, "Corner", Vec3::ONE);
+        spawn_at(&mut app, "Outside", Vec3::new(0.0, 2.0, 0.0));
+
+        let response = query_spatial_handler(
+            In(Some(
+                json!({ "min": [-1.0, -1.0, -1.0], "max": [1.0, 1.0, 1.0], "limit": 1 }),
+            )),
+            app.world_mut(),
+        )
+        .expect("spatial query should succeed");
+
+        assert_eq!(response["total_count"], 2);
+        assert_eq!(
+            response["entities"],
+            json!([{
+                "entity": center,
+                "name": "Center",
+                "translation": [0.0, 0.0, 0.0],
+                "distance": 0.0
+            }])
+        );
+    }
+
+    #[test]
+    fn mixing_sphere_and_box_parameters_is_rejected() {
+        let mut app = App::new();
+
+        let error = query_spatial_handler(
+            In(Some(
+                json!({ "center": [0.0, 0.0, 0.0], "min": [0.0, 0.0, 0.0] }),
+            )),
+            app.world_mut(),
+        )
+        .expect_err("ambiguous region should be rejected");
+
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+    }
+}
@@ -0,0 +1,101 @@
+//! Request parsing, response serialization, and entity lookup shared by `brp_extras/*` handlers
+//!
+//! Every handler reports bad parameters, serialization failures, and missing entities with the
+//! same `BrpExtrasError` kinds and messages, so clients see one shape across methods.
+
+use bevy::prelude::*;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::MISSING_REQUEST_PARAMETERS_MESSAGE;
+use crate::constants::PARAM_ENTITY;
+use crate::error::BrpExtrasError;
+
+/// Parse parameters a method cannot run without, failing with `MissingParams` when none were sent
+pub(crate) fn parse_required<T: DeserializeOwned>(params: Option<Value>) -> Result<T, BrpError> {
+    parse_optional(Some(params.ok_or_else(|| {
+        BrpExtrasError::MissingParams.error(MISSING_REQUEST_PARAMETERS_MESSAGE)
+    })?))
+}
+
+/// Parse parameters whose fields all have defaults; missing or `null` params parse as `{}`
+pub(crate) fn parse_optional<T: DeserializeOwned>(params: Option<Value>) -> Result<T, BrpError> {
+    let params = match params {
+        None | Some(Value::Null) => Value::Object(Map::default()),
+        Some(params) => params,
+    };
+    serde_json::from_value(params).map_err(|error| {
+        BrpExtrasError::InvalidParams.error(format!("Failed to parse parameters: {error}"))
+    })
+}
+
+/// Serialize a handler's response, reporting failure as an `Internal` error
+pub(crate) fn serialize_response<T: Serialize>(response: T) -> BrpResult {
+    serde_json::to_value(response).map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+    })
+}
+
+/// The entity with these bits, failing with `EntityNotFound` when it does not exist
+pub(crate) fn resolve_entity(world: &World, bits: u64) -> Result<Entity, BrpError> {
+    Entity::try_from_bits(bits)
+        .filter(|entity| world.get_entity(*entity).is_ok())
+        .ok_or_else(|| {
+            BrpExtrasError::EntityNotFound.with_details(
+                format!("Entity {bits} does not exist"),
+                json!({ PARAM_ENTITY: bits }),
+            )
+        })
+}
+
+/// Error for an entity that exists but lacks the component a method reads or writes
+pub(crate) fn missing_component(entity: u64, component: &str) -> BrpError {
+    BrpExtrasError::InvalidState.with_details(
+        format!("Entity {entity} does not have '{component}'"),
+        json!({ PARAM_ENTITY: entity, "component": component }),
+    )
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::parse_optional;
+    use super::parse_required;
+    use crate::error::BrpExtrasError;
+
+    #[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+    #[serde(default)]
+    struct Request {
+        limit: u32,
+    }
+
+    #[test]
+    fn missing_params_are_rejected_only_when_required() {
+        let error = parse_required::<Request>(None).expect_err("params are required");
+        assert_eq!(error.code, BrpExtrasError::MissingParams.code());
+
+        assert_eq!(
+            parse_optional::<Request>(None).ok(),
+            Some(Request::default())
+        );
+        assert_eq!(
+            parse_optional::<Request>(Some(json!(null))).ok(),
+            Some(Request::default())
+        );
+
+        let error = parse_optional::<Request>(Some(json!({ "limit": "many" })))
+            .expect_err("limit is a number");
+        assert_eq!(error.code, BrpExtrasError::InvalidParams.code());
+    }
+}
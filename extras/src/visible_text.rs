@@ -0,0 +1,390 @@
+//! Visible text handler for BRP extras
+//!
+//! `get_visible_text` lists the text an app currently shows, so a test can assert that a label
+//! reads "Score: 42" without running OCR on a screenshot. UI `Text` nodes report their computed
+//! layout bounds; `Text2d` entities report their `Aabb` projected through the camera that shows
+//! them. Rects are window coordinates in logical pixels, the same space the mouse methods use.
+
+use bevy::camera::NormalizedRenderTarget;
+use bevy::camera::RenderTarget;
+use bevy::camera::primitives::Aabb;
+use bevy::camera::visibility::DEFAULT_LAYERS;
+#[cfg(feature = "ui")]
+use bevy::camera::visibility::InheritedVisibility;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::visibility::ViewVisibility;
+use bevy::math::Rect;
+use bevy::prelude::*;
+use bevy::sprite::Text2d;
+use bevy::text::TextSpan;
+#[cfg(feature = "ui")]
+use bevy::ui::CalculatedClip;
+#[cfg(feature = "ui")]
+use bevy::ui::ComputedNode;
+#[cfg(feature = "ui")]
+use bevy::ui::ComputedUiTargetCamera;
+#[cfg(feature = "ui")]
+use bevy::ui::UiGlobalTransform;
+#[cfg(feature = "ui")]
+use bevy::ui::widget::Text;
+use bevy::window::PrimaryWindow;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::serialize_response;
+
+/// `kind` of text drawn by a UI `Text` node
+#[cfg(feature = "ui")]
+const KIND_UI: &str = "ui";
+/// `kind` of text drawn by a `Text2d` entity
+const KIND_TEXT2D: &str = "text2d";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `get_visible_text`
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetVisibleTextRequest {
+    /// Only return text containing this substring
+    #[serde(default)]
+    contains: Option<String>,
+    /// Only return text shown in this window
+    #[serde(default)]
+    window:   Option<u64>,
+}
+
+/// Response structure for `get_visible_text`
+#[derive(Serialize)]
+struct GetVisibleTextResponse {
+    /// Visible text in reading order: by window, then top to bottom, then left to right
+    texts: Vec<VisibleText>,
+}
+
+/// One visible text entity
+#[derive(Serialize)]
+struct VisibleText {
+    entity: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name:   Option<String>,
+    /// `"ui"` for a UI `Text` node, `"text2d"` for a `Text2d` entity
+    kind:   &'static str,
+    /// The root text followed by its `TextSpan` descendants
+    text:   String,
+    window: u64,
+    /// `[x, y, width, height]` in window logical pixels, clipped to what is on screen
+    rect:   [f32; 4],
+}
+
+/// An active camera that renders to a window
+struct WindowCamera {
+    #[cfg_attr(
+        not(feature = "ui"),
+        expect(dead_code, reason = "only UI text finds its camera by entity")
+    )]
+    entity:           Entity,
+    window:           Entity,
+    camera:           Camera,
+    global_transform: GlobalTransform,
+    render_layers:    Option<RenderLayers>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `get_visible_text` BRP method
+///
+/// Parameters are optional; without them every visible text entity is returned.
+pub(crate) fn get_visible_text_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: GetVisibleTextRequest = parse_optional(params)?;
+
+    let window_filter = request
+        .window
+        .map(|id| {
+            Entity::try_from_bits(id)
+                .filter(|entity| world.get::<Window>(*entity).is_some())
+                .ok_or_else(|| {
+                    BrpExtrasError::WindowNotFound.with_details(
+                        format!("Invalid window entity: {id}"),
+                        json!({ "window": id }),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let cameras = window_cameras(world);
+    let mut texts = Vec::new();
+    #[cfg(feature = "ui")]
+    ui_texts(world, &cameras, &mut texts);
+    text2d_texts(world, &cameras, &mut texts);
+
+    texts.retain(|text| {
+        window_filter.is_none_or(|window| text.window == window.to_bits())
+            && request
+                .contains
+                .as_deref()
+                .is_none_or(|needle| text.text.contains(needle))
+    });
+    texts.sort_by(|a, b| {
+        a.window
+            .cmp(&b.window)
+            .then(a.rect[1].total_cmp(&b.rect[1]))
+            .then(a.rect[0].total_cmp(&b.rect[0]))
+    });
+
+    serialize_response(GetVisibleTextResponse { texts })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Active cameras rendering to a window, highest `order` first
+fn window_cameras(world: &mut World) -> Vec<WindowCamera> {
+    let primary_window = world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .iter(world)
+        .next();
+    let mut cameras: Vec<WindowCamera> = world
+        .query::<(
+            Entity,
+            &Camera,
+            &RenderTarget,
+            &GlobalTransform,
+            Option<&RenderLayers>,
+        )>()
+        .iter(world)
+        .filter(|(_, camera, ..)| camera.is_active)
+        .filter_map(
+            |(entity, camera, render_target, global_transform, render_layers)| {
+                let Some(NormalizedRenderTarget::Window(window_ref)) =
+                    render_target.normalize(primary_window)
+                else {
+                    return None;
+                };
+                Some(WindowCamera {
+                    entity,
+                    window: window_ref.entity(),
+                    camera: camera.clone(),
+                    global_transform: *global_transform,
+                    render_layers: render_layers.cloned(),
+                })
+            },
+        )
+        .collect();
+    cameras.sort_by_key(|camera| std::cmp::Reverse(camera.camera.order));
+    cameras
+}
+
+/// Visible UI `Text` nodes, bounded by their computed layout and clip
+#[cfg(feature = "ui")]
+fn ui_texts(world: &mut World, cameras: &[WindowCamera], texts: &mut Vec<VisibleText>) {
+    let mut query = world.query::<(
+        Entity,
+        &Text,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &ComputedUiTargetCamera,
+        Option<&InheritedVisibility>,
+        Option<&CalculatedClip>,
+    )>();
+    for (entity, text, computed_node, ui_global_transform, target_camera, visibility, clip) in
+        query.iter(world)
+    {
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+        let Some(camera) = target_camera
+            .get()
+            .and_then(|camera| cameras.iter().find(|candidate| candidate.entity == camera))
+        else {
+            continue;
+        };
+        let (Some(viewport), Some(scale_factor)) = (
+            camera.camera.physical_viewport_rect(),
+            camera.camera.target_scaling_factor(),
+        ) else {
+            continue;
+        };
+
+        // Node bounds in physical pixels of the camera viewport, clipped like the renderer does
+        let bounds = Rect::from_center_size(
+            ui_global_transform.affine().translation,
+            computed_node.size(),
+        );
+        let bounds = clip.map_or(bounds, |clip| bounds.intersect(clip.clip));
+        let viewport = viewport.as_rect();
+        let on_screen = bounds.translate(viewport.min).intersect(viewport);
+        if on_screen.is_empty() || !on_screen.min.is_finite() || !on_screen.max.is_finite() {
+            continue;
+        }
+
+        texts.push(VisibleText {
+            entity: entity.to_bits(),
+            name:   world
+                .get::<Name>(entity)
+                .map(|name| name.as_str().to_owned()),
+            kind:   KIND_UI,
+            text:   full_text(world, entity, &text.0),
+            window: camera.window.to_bits(),
+            rect:   logical_rect(on_screen, scale_factor),
+        });
+    }
+}
+
+/// Visible `Text2d` entities, bounded by their `Aabb` as seen by the highest-order camera that
+/// shows them
+fn text2d_texts(world: &mut World, cameras: &[WindowCamera], texts: &mut Vec<VisibleText>) {
+    let mut query = world.query::<(
+        Entity,
+        &Text2d,
+        &Aabb,
+        &GlobalTransform,
+        Option<&ViewVisibility>,
+        Option<&RenderLayers>,
+    )>();
+    for (entity, text, aabb, global_transform, visibility, render_layers) in query.iter(world) {
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+        let render_layers = render_layers.unwrap_or(DEFAULT_LAYERS);
+        let Some((camera, on_screen)) = cameras
+            .iter()
+            .filter(|camera| {
+                render_layers.intersects(camera.render_layers.as_ref().unwrap_or(DEFAULT_LAYERS))
+            })
+            .find_map(|camera| {
+                projected_rect(camera, aabb, global_transform).map(|rect| (camera, rect))
+            })
+        else {
+            continue;
+        };
+
+        texts.push(VisibleText {
+            entity: entity.to_bits(),
+            name:   world
+                .get::<Name>(entity)
+                .map(|name| name.as_str().to_owned()),
+            kind:   KIND_TEXT2D,
+            text:   full_text(world, entity, &text.0),
+            window: camera.window.to_bits(),
+            rect:   [
+                on_screen.min.x,
+                on_screen.min.y,
+                on_screen.width(),
+                on_screen.height(),
+            ],
+        });
+    }
+}
+
+/// Logical window rect of an `Aabb` seen through `camera`, clipped to its viewport; `None` when it
+/// is off screen or cannot be projected
+fn projected_rect(
+    camera: &WindowCamera,
+    aabb: &Aabb,
+    global_transform: &GlobalTransform,
+) -> Option<Rect> {
+    let viewport = camera.camera.logical_viewport_rect()?;
+    let center = Vec3::from(aabb.center);
+    let half_extents = Vec3::from(aabb.half_extents);
+
+    let mut bounds = Rect::EMPTY;
+    for x in [-1.0, 1.0] {
+        for y in [-1.0, 1.0] {
+            for z in [-1.0, 1.0] {
+                let corner =
+                    global_transform.transform_point(center + half_extents * Vec3::new(x, y, z));
+                let position = camera
+                    .camera
+                    .world_to_viewport(&camera.global_transform, corner)
+                    .ok()?
+                    + viewport.min;
+                bounds = bounds.union_point(position);
+            }
+        }
+    }
+
+    let on_screen = bounds.intersect(viewport);
+    (!on_screen.is_empty() && on_screen.min.is_finite() && on_screen.max.is_finite())
+        .then_some(on_screen)
+}
+
+/// Physical pixel rect as `[x, y, width, height]` in logical pixels
+#[cfg(feature = "ui")]
+fn logical_rect(physical: Rect, scale_factor: f32) -> [f32; 4] {
+    let min = physical.min / scale_factor;
+    let size = physical.size() / scale_factor;
+    [min.x, min.y, size.x, size.y]
+}
+
+/// The text of `root` followed by the text of its `TextSpan` descendants, in order
+fn full_text(world: &World, root: Entity, root_text: &str) -> String {
+    let mut text = root_text.to_owned();
+    append_spans(world, root, &mut text);
+    text
+}
+
+fn append_spans(world: &World, entity: Entity, text: &mut String) {
+    let Some(children) = world.get::<Children>(entity) else {
+        return;
+    };
+    for child in children {
+        if let Some(span) = world.get::<TextSpan>(*child) {
+            text.push_str(&span.0);
+            append_spans(world, *child, text);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+
+    use super::*;
+
+    #[test]
+    fn full_text_appends_spans_in_hierarchy_order() {
+        let mut app = App::new();
+        let world = app.world_mut();
+        let root = world
+            .spawn(Text2d::new("Score: "))
+            .with_children(|parent| {
+                parent.spawn(TextSpan::new("4")).with_children(|span| {
+                    span.spawn(TextSpan::new("2"));
+                });
+                parent.spawn(TextSpan::new("!"));
+            })
+            .id();
+
+        assert_eq!(full_text(world, root, "Score: "), "Score: 42!");
+    }
+
+    #[test]
+    fn unknown_window_is_rejected() {
+        let mut app = App::new();
+
+        let error = get_visible_text_handler(
+            In(Some(json!({ "window": Entity::PLACEHOLDER.to_bits() }))),
+            app.world_mut(),
+        )
+        .expect_err("a window that does not exist should be rejected");
+
+        assert_eq!(error.code, BrpExtrasError::WindowNotFound.code());
+    }
+}
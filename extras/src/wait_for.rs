@@ -0,0 +1,386 @@
+//! Condition waits for BRP extras
+//!
+//! Agents that need to wait for the app, such as for a level to finish loading, otherwise poll
+//! with a sleep between requests and guess at how long to sleep. `wait_for` is a watching method
+//! that checks its condition every frame and answers once, when the condition holds or with a
+//! `timeout` error when `timeout_ms` passes first. Waits are tracked by their parameters and
+//! forgotten once answered, or on the frame after their client goes away.
+
+use bevy::diagnostic::DiagnosticsStore;
+use bevy::platform::collections::HashMap;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistration;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use bevy_remote::RemoteLast;
+use bevy_remote::RemoteSystems;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::DEFAULT_WAIT_FOR_TIMEOUT_MS;
+use crate::constants::MAX_WAIT_FOR_TIMEOUT_MS;
+use crate::error::BrpExtrasError;
+use crate::support::parse_required;
+
+pub(crate) struct WaitForPlugin;
+
+impl Plugin for WaitForPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingWaits>().add_systems(
+            RemoteLast,
+            drop_abandoned_waits
+                .run_if(waits_active)
+                .after(RemoteSystems::ProcessRequests)
+                .before(RemoteSystems::Cleanup),
+        );
+    }
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `wait_for`
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct WaitForRequest {
+    /// The condition to wait for
+    condition:  WaitCondition,
+    /// Milliseconds to wait before failing with `timeout` (default: 5000, max: 25000)
+    #[serde(default = "default_timeout_ms")]
+    timeout_ms: u64,
+}
+
+const fn default_timeout_ms() -> u64 { DEFAULT_WAIT_FOR_TIMEOUT_MS }
+
+/// What `wait_for` waits for, selected by `kind`
+#[derive(Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum WaitCondition {
+    /// At least one entity has the component
+    ComponentExists {
+        /// Full type path of a component registered with `reflect(Component)`
+        component: String,
+    },
+    /// The resource exists and serializes to exactly `value`
+    ResourceEquals {
+        /// Full type path of a resource registered with `reflect(Resource)`
+        resource: String,
+        /// The value in BRP's reflect format, as `world.get_resources` returns it
+        value:    Value,
+    },
+    /// A diagnostic's smoothed value is above and/or below a threshold
+    DiagnosticThreshold {
+        /// Diagnostic path, e.g. `fps`
+        path:  String,
+        /// Hold once the value is greater than this
+        #[serde(default)]
+        above: Option<f64>,
+        /// Hold once the value is less than this
+        #[serde(default)]
+        below: Option<f64>,
+    },
+}
+
+/// The condition's current state, reported when the wait ends
+struct Observation {
+    holds:    bool,
+    /// What the condition was checked against
+    observed: Value,
+}
+
+/// Response structure for a satisfied `wait_for`
+#[derive(Serialize)]
+struct WaitForResponse {
+    condition:     WaitCondition,
+    /// What the condition was checked against on the frame it held
+    observed:      Value,
+    /// Milliseconds between the request and the frame the condition held
+    elapsed_ms:    u64,
+    /// Frames checked before the condition held, 0 when it already held
+    frames_waited: u64,
+}
+
+/// One wait in progress
+struct ActiveWait {
+    started:       Instant,
+    started_frame: u64,
+    seen_frame:    u64,
+}
+
+/// Waits in progress, keyed by their serialized parameters
+#[derive(Resource, Default)]
+struct PendingWaits {
+    frame: u64,
+    waits: HashMap<String, ActiveWait>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for the `wait_for` BRP method
+///
+/// Returns nothing until the condition holds or the wait times out, then answers once.
+pub(crate) fn wait_for_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let key = params.as_ref().map(Value::to_string).unwrap_or_default();
+    let request: WaitForRequest = parse_required(params)?;
+    if request.timeout_ms > MAX_WAIT_FOR_TIMEOUT_MS {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("'timeout_ms' must be at most {MAX_WAIT_FOR_TIMEOUT_MS}"),
+            json!({ "timeout_ms": request.timeout_ms, "max_timeout_ms": MAX_WAIT_FOR_TIMEOUT_MS }),
+        ));
+    }
+
+    let now = Instant::now();
+    let (started, frames_waited) = {
+        let mut pending = world.resource_mut::<PendingWaits>();
+        let frame = pending.frame;
+        let wait = pending.waits.entry(key.clone()).or_insert(ActiveWait {
+            started:       now,
+            started_frame: frame,
+            seen_frame:    frame,
+        });
+        wait.seen_frame = frame;
+        (wait.started, frame - wait.started_frame)
+    };
+
+    let elapsed_ms = u64::try_from(now.duration_since(started).as_millis()).unwrap_or(u64::MAX);
+    let result = match evaluate(world, &request.condition) {
+        Ok(observation) if observation.holds => serde_json::to_value(WaitForResponse {
+            condition: request.condition,
+            observed: observation.observed,
+            elapsed_ms,
+            frames_waited,
+        })
+        .map(Some)
+        .map_err(|error| {
+            BrpExtrasError::Internal.error(format!("Failed to serialize response: {error}"))
+        }),
+        Ok(observation) if elapsed_ms >= request.timeout_ms => Err(BrpExtrasError::Timeout
+            .with_details(
+                format!("Condition did not hold within {} ms", request.timeout_ms),
+                json!({
+                    "timeout_ms": request.timeout_ms,
+                    "frames_waited": frames_waited,
+                    "observed": observation.observed,
+                }),
+            )),
+        Ok(_) => Ok(None),
+        Err(error) => Err(error),
+    };
+
+    if !matches!(result, Ok(None)) {
+        world.resource_mut::<PendingWaits>().waits.remove(&key);
+    }
+    result
+}
+
+/// Drop waits whose request was not checked this frame because the client went away, and start
+/// the next frame
+fn drop_abandoned_waits(mut pending: ResMut<PendingWaits>) {
+    let pending = &mut *pending;
+    let frame = pending.frame;
+    pending.waits.retain(|_, wait| wait.seen_frame == frame);
+    pending.frame += 1;
+}
+
+fn waits_active(pending: Res<PendingWaits>) -> bool { !pending.waits.is_empty() }
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Check a condition against the world
+fn evaluate(world: &mut World, condition: &WaitCondition) -> Result<Observation, BrpError> {
+    match condition {
+        WaitCondition::ComponentExists { component } => component_exists(world, component),
+        WaitCondition::ResourceEquals { resource, value } => {
+            resource_equals(world, resource, value)
+        },
+        WaitCondition::DiagnosticThreshold { path, above, below } => {
+            diagnostic_threshold(world, path, *above, *below)
+        },
+    }
+}
+
+fn component_exists(world: &mut World, component: &str) -> Result<Observation, BrpError> {
+    let type_id = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_with_type_path(component)
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(TypeRegistration::type_id)
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("'{component}' is not a registered component with reflect(Component)"),
+                json!({ "component": component }),
+            )
+        })?;
+
+    // A component that has never been added has no id yet, so no entity has it
+    let Some(component_id) = world.components().get_id(type_id) else {
+        return Ok(Observation {
+            holds:    false,
+            observed: json!({ "entity_count": 0, "entity": null }),
+        });
+    };
+    let mut query = QueryBuilder::<Entity>::new(world)
+        .with_id(component_id)
+        .build();
+    let entities: Vec<u64> = query.iter(world).map(Entity::to_bits).collect();
+    Ok(Observation {
+        holds:    !entities.is_empty(),
+        observed: json!({ "entity_count": entities.len(), "entity": entities.first() }),
+    })
+}
+
+fn resource_equals(
+    world: &World,
+    resource: &str,
+    expected: &Value,
+) -> Result<Observation, BrpError> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    // Resources are components on a resource entity, so `ReflectResource` implies
+    // `ReflectComponent`
+    let (registration, reflect_component) = registry
+        .get_with_type_path(resource)
+        .filter(|registration| registration.data::<ReflectResource>().is_some())
+        .and_then(|registration| Some((registration, registration.data::<ReflectComponent>()?)))
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.with_details(
+                format!("'{resource}' is not a registered resource with reflect(Resource)"),
+                json!({ "resource": resource }),
+            )
+        })?;
+
+    // A resource that is not inserted yet is observed as null
+    let observed = world
+        .components()
+        .get_id(registration.type_id())
+        .and_then(|component_id| world.resource_entities().get(component_id))
+        .and_then(|entity| world.get_entity(entity).ok())
+        .and_then(|entity| reflect_component.reflect(entity))
+        .map(|value| {
+            serde_json::to_value(TypedReflectSerializer::new(
+                value.as_partial_reflect(),
+                &registry,
+            ))
+        })
+        .transpose()
+        .map_err(|error| {
+            BrpExtrasError::Internal.with_details(
+                format!("Failed to serialize '{resource}': {error}"),
+                json!({ "resource": resource }),
+            )
+        })?
+        .unwrap_or(Value::Null);
+    drop(registry);
+    Ok(Observation {
+        holds: !observed.is_null() && &observed == expected,
+        observed,
+    })
+}
+
+fn diagnostic_threshold(
+    world: &World,
+    path: &str,
+    above: Option<f64>,
+    below: Option<f64>,
+) -> Result<Observation, BrpError> {
+    if above.is_none() && below.is_none() {
+        return Err(BrpExtrasError::InvalidParams
+            .error("diagnostic_threshold needs 'above', 'below', or both"));
+    }
+    let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+        return Err(BrpExtrasError::Unsupported.error(
+            "DiagnosticsStore not found - a diagnostics plugin such as FrameTimeDiagnosticsPlugin \
+             must be installed",
+        ));
+    };
+    let Some(diagnostic) = store
+        .iter()
+        .find(|diagnostic| diagnostic.path().as_str() == path)
+    else {
+        let mut available: Vec<&str> = store
+            .iter()
+            .map(|diagnostic| diagnostic.path().as_str())
+            .collect();
+        available.sort_unstable();
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("Unknown diagnostic path: {path}"),
+            json!({ "path": path, "available": available }),
+        ));
+    };
+
+    // No value is recorded until the diagnostic's first measurement
+    let value = diagnostic.smoothed();
+    let holds = value.is_some_and(|value| {
+        above.is_none_or(|above| value > above) && below.is_none_or(|below| value < below)
+    });
+    Ok(Observation {
+        holds,
+        observed: json!({ "path": path, "smoothed": value }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use serde_json::json;
+
+    use super::WaitCondition;
+    use super::evaluate;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Spawned;
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Spawned>();
+            registry.register::<Score>();
+        }
+        world
+    }
+
+    #[test]
+    fn component_exists_holds_once_an_entity_has_it() {
+        let mut world = world();
+        let condition = WaitCondition::ComponentExists {
+            component: Spawned::type_path().to_string(),
+        };
+        assert!(!evaluate(&mut world, &condition).is_ok_and(|observation| observation.holds));
+        world.spawn(Spawned);
+        assert!(evaluate(&mut world, &condition).is_ok_and(|observation| observation.holds));
+    }
+
+    #[test]
+    fn resource_equals_compares_the_serialized_value() {
+        let mut world = world();
+        let condition = WaitCondition::ResourceEquals {
+            resource: Score::type_path().to_string(),
+            value:    json!(3),
+        };
+        assert!(!evaluate(&mut world, &condition).is_ok_and(|observation| observation.holds));
+        world.insert_resource(Score(2));
+        assert!(!evaluate(&mut world, &condition).is_ok_and(|observation| observation.holds));
+        world.insert_resource(Score(3));
+        assert!(evaluate(&mut world, &condition).is_ok_and(|observation| observation.holds));
+    }
+}
@@ -0,0 +1,15 @@
+//! Window control for Bevy Remote Protocol
+//!
+//! This module provides:
+//! - Window title changes, including live title templates
+//! - Window properties: resolution, position, mode, decorations, cursor visibility, and vsync
+//!
+//! Property changes support multi-window targeting by window entity ID.
+
+mod properties;
+mod title;
+
+pub(crate) use self::properties::SetWindowRequest;
+pub(crate) use self::properties::set_window_handler;
+pub(crate) use self::title::WindowTitlePlugin;
+pub(crate) use self::title::set_window_title_handler;
@@ -0,0 +1,275 @@
+//! Window property handler for BRP extras
+//!
+//! `set_window` changes any combination of resolution, position, mode, decorations, cursor
+//! visibility, and vsync on one window. Omitted properties are left as they are, so a request with
+//! only `window` (or no parameters) reports the window's current state.
+
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+use bevy::window::MonitorSelection;
+use bevy::window::PresentMode;
+use bevy::window::PrimaryWindow;
+use bevy::window::VideoModeSelection;
+use bevy::window::WindowMode;
+use bevy::window::WindowPosition;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::error::BrpExtrasError;
+use crate::headless;
+use crate::support::parse_optional;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `set_window`
+#[derive(Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SetWindowRequest {
+    /// Target window entity (None = primary window)
+    #[serde(default)]
+    window:         Option<u64>,
+    /// Logical size as `[width, height]`
+    #[serde(default)]
+    resolution:     Option<[f32; 2]>,
+    /// Top-left corner in physical screen pixels as `[x, y]`
+    #[serde(default)]
+    position:       Option<[i32; 2]>,
+    /// Windowed, fullscreen, or borderless fullscreen on the current monitor
+    #[serde(default)]
+    mode:           Option<WindowModeName>,
+    /// Show the title bar and border
+    #[serde(default)]
+    decorations:    Option<bool>,
+    /// Show the cursor while it is over the window
+    #[serde(default)]
+    cursor_visible: Option<bool>,
+    /// Synchronize presentation with the display refresh rate
+    #[serde(default)]
+    vsync:          Option<bool>,
+}
+
+/// Window modes accepted and reported by `set_window`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum WindowModeName {
+    Windowed,
+    Fullscreen,
+    Borderless,
+}
+
+impl WindowModeName {
+    const fn to_mode(self) -> WindowMode {
+        match self {
+            Self::Windowed => WindowMode::Windowed,
+            Self::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            },
+            Self::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        }
+    }
+
+    const fn from_mode(mode: WindowMode) -> Self {
+        match mode {
+            WindowMode::Windowed => Self::Windowed,
+            WindowMode::Fullscreen(..) => Self::Fullscreen,
+            WindowMode::BorderlessFullscreen(_) => Self::Borderless,
+        }
+    }
+}
+
+/// Response structure for `set_window`
+#[derive(Serialize)]
+struct SetWindowResponse {
+    /// Window entity that was changed
+    window:         u64,
+    /// Properties this request changed, in request order
+    changed:        Vec<&'static str>,
+    /// Logical size as `[width, height]`
+    resolution:     [f32; 2],
+    /// Top-left corner in physical screen pixels, when the window has an explicit position
+    position:       Option<[i32; 2]>,
+    mode:           WindowModeName,
+    decorations:    bool,
+    cursor_visible: bool,
+    vsync:          bool,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `set_window` BRP method
+///
+/// Applies every provided property and returns the resulting window state.
+pub(crate) fn set_window_handler(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let request: SetWindowRequest = parse_optional(params)?;
+    if let Some([width, height]) = request.resolution
+        && !(width > 0.0 && height > 0.0)
+    {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!("Resolution must be positive, got [{width}, {height}]"),
+            json!({ "resolution": [width, height] }),
+        ));
+    }
+    let entity = resolve_window(world, request.window)?;
+
+    let mut changed = Vec::new();
+    let mut window = world.get_mut::<Window>(entity).ok_or_else(|| {
+        BrpExtrasError::WindowNotFound.with_details(
+            format!("Entity {} has no Window component", entity.to_bits()),
+            json!({ "window": entity.to_bits() }),
+        )
+    })?;
+    if let Some([width, height]) = request.resolution {
+        window.resolution.set(width, height);
+        changed.push("resolution");
+    }
+    if let Some([x, y]) = request.position {
+        window.position = WindowPosition::At(IVec2::new(x, y));
+        changed.push("position");
+    }
+    if let Some(mode) = request.mode {
+        window.mode = mode.to_mode();
+        changed.push("mode");
+    }
+    if let Some(decorations) = request.decorations {
+        window.decorations = decorations;
+        changed.push("decorations");
+    }
+    if let Some(vsync) = request.vsync {
+        window.present_mode = if vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+        changed.push("vsync");
+    }
+
+    let resolution = [window.resolution.width(), window.resolution.height()];
+    let position = match window.position {
+        WindowPosition::At(position) => Some([position.x, position.y]),
+        WindowPosition::Automatic | WindowPosition::Centered(_) => None,
+    };
+    let mode = WindowModeName::from_mode(window.mode);
+    let decorations = window.decorations;
+    let vsync = matches!(
+        window.present_mode,
+        PresentMode::AutoVsync | PresentMode::Fifo | PresentMode::FifoRelaxed
+    );
+
+    // Cursor options live on their own component, which windows may be spawned without
+    let cursor_visible = match request.cursor_visible {
+        Some(visible) => {
+            if let Some(mut cursor_options) = world.get_mut::<CursorOptions>(entity) {
+                cursor_options.visible = visible;
+            } else {
+                world.entity_mut(entity).insert(CursorOptions {
+                    visible,
+                    ..default()
+                });
+            }
+            changed.push("cursor_visible");
+            visible
+        },
+        None => world
+            .get::<CursorOptions>(entity)
+            .is_none_or(|cursor_options| cursor_options.visible),
+    };
+
+    serialize_response(SetWindowResponse {
+        window: entity.to_bits(),
+        changed,
+        resolution,
+        position,
+        mode,
+        decorations,
+        cursor_visible,
+        vsync,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Resolve the requested window, defaulting to the primary window
+fn resolve_window(world: &mut World, window_id: Option<u64>) -> Result<Entity, BrpError> {
+    headless::ensure_window(world)?;
+    if let Some(id) = window_id {
+        return Entity::try_from_bits(id)
+            .filter(|entity| world.get::<Window>(*entity).is_some())
+            .ok_or_else(|| {
+                BrpExtrasError::WindowNotFound.with_details(
+                    format!("Invalid window entity: {id}"),
+                    json!({ "window": id }),
+                )
+            });
+    }
+
+    let mut query = world.query_filtered::<Entity, With<PrimaryWindow>>();
+    query
+        .single(world)
+        .map_err(|_| BrpExtrasError::WindowNotFound.error("No primary window found"))
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::In;
+    use bevy::window::CursorOptions;
+    use bevy::window::Window;
+    use bevy::window::WindowMode;
+    use serde_json::json;
+
+    use super::set_window_handler;
+    use crate::error::BrpExtrasError;
+
+    #[test]
+    fn set_window_applies_properties_to_the_targeted_window() {
+        let mut app = App::new();
+        let window = app.world_mut().spawn(Window::default()).id();
+
+        let response = set_window_handler(
+            In(Some(json!({
+                "window": window.to_bits(),
+                "resolution": [640.0, 480.0],
+                "mode": "borderless",
+                "decorations": false,
+                "cursor_visible": false
+            }))),
+            app.world_mut(),
+        )
+        .expect("window properties should apply");
+
+        assert_eq!(
+            response["changed"],
+            json!(["resolution", "mode", "decorations", "cursor_visible"])
+        );
+        assert_eq!(response["resolution"], json!([640.0, 480.0]));
+        let updated = app.world().get::<Window>(window).expect("window exists");
+        assert!(matches!(updated.mode, WindowMode::BorderlessFullscreen(_)));
+        assert!(!updated.decorations);
+        assert!(
+            !app.world()
+                .get::<CursorOptions>(window)
+                .expect("cursor options are inserted")
+                .visible
+        );
+
+        let error = set_window_handler(In(Some(json!({ "window": 12_345 }))), app.world_mut())
+            .expect_err("unknown window should be rejected");
+        assert_eq!(error.code, BrpExtrasError::WindowNotFound.code());
+    }
+}
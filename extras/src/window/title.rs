@@ -0,0 +1,214 @@
+//! Window title handler for BRP extras
+//!
+//! A title containing placeholders (`{fps}`, `{frame}`, `{entities}`) is kept as a live template
+//! and re-rendered every second, turning the title bar into a remotely controlled heads-up display.
+//! Setting a title without placeholders clears the template.
+
+#[cfg(feature = "diagnostics")]
+use bevy::diagnostic::Diagnostic;
+#[cfg(feature = "diagnostics")]
+use bevy::diagnostic::DiagnosticsStore;
+use bevy::diagnostic::FrameCount;
+#[cfg(feature = "diagnostics")]
+use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_remote::BrpResult;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::PARAM_TITLE;
+use crate::constants::RESPONSE_MESSAGE_FIELD;
+use crate::constants::RESPONSE_NEW_TITLE_FIELD;
+use crate::constants::RESPONSE_OLD_TITLE_FIELD;
+use crate::constants::RESPONSE_STATUS_FIELD;
+use crate::constants::RESPONSE_STATUS_SUCCESS;
+use crate::constants::RESPONSE_TEMPLATE_FIELD;
+use crate::constants::TITLE_PLACEHOLDER_ENTITIES;
+use crate::constants::TITLE_PLACEHOLDER_FPS;
+use crate::constants::TITLE_PLACEHOLDER_FRAME;
+use crate::constants::TITLE_TEMPLATE_REFRESH_SECS;
+use crate::constants::TITLE_VALUE_UNAVAILABLE;
+use crate::error::BrpExtrasError;
+use crate::headless;
+
+/// Plugin that keeps a templated window title up to date
+pub(crate) struct WindowTitlePlugin;
+
+impl Plugin for WindowTitlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TitleTemplate>();
+        app.add_systems(Update, refresh_title_template);
+    }
+}
+
+/// Active title template and the timer that drives its refresh
+#[derive(Resource)]
+struct TitleTemplate {
+    template: Option<String>,
+    refresh:  Timer,
+}
+
+impl Default for TitleTemplate {
+    fn default() -> Self {
+        Self {
+            template: None,
+            refresh:  Timer::from_seconds(TITLE_TEMPLATE_REFRESH_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Live values substituted into a title template
+struct TitleStats {
+    fps:      Option<f64>,
+    frame:    u32,
+    entities: usize,
+}
+
+impl TitleStats {
+    fn collect(world: &mut World) -> Self {
+        #[cfg(feature = "diagnostics")]
+        let fps = world
+            .get_resource::<DiagnosticsStore>()
+            .and_then(|store| store.get(&FrameTimeDiagnosticsPlugin::FPS))
+            .and_then(Diagnostic::smoothed);
+        #[cfg(not(feature = "diagnostics"))]
+        let fps = None;
+
+        let frame = world
+            .get_resource::<FrameCount>()
+            .map_or(0, |count| count.0);
+        let entities = world.query::<Entity>().iter(world).count();
+
+        Self {
+            fps,
+            frame,
+            entities,
+        }
+    }
+}
+
+fn is_template(title: &str) -> bool {
+    [
+        TITLE_PLACEHOLDER_FPS,
+        TITLE_PLACEHOLDER_FRAME,
+        TITLE_PLACEHOLDER_ENTITIES,
+    ]
+    .iter()
+    .any(|placeholder| title.contains(placeholder))
+}
+
+fn render_title(template: &str, stats: &TitleStats) -> String {
+    let fps = stats.fps.map_or_else(
+        || TITLE_VALUE_UNAVAILABLE.to_string(),
+        |fps| format!("{fps:.0}"),
+    );
+    template
+        .replace(TITLE_PLACEHOLDER_FPS, &fps)
+        .replace(TITLE_PLACEHOLDER_FRAME, &stats.frame.to_string())
+        .replace(TITLE_PLACEHOLDER_ENTITIES, &stats.entities.to_string())
+}
+
+/// Re-render the active template into the primary window title once per refresh interval
+fn refresh_title_template(world: &mut World) {
+    let delta = world.resource::<Time>().delta();
+    let template = {
+        let mut title_template = world.resource_mut::<TitleTemplate>();
+        if title_template.template.is_none() || !title_template.refresh.tick(delta).just_finished()
+        {
+            return;
+        }
+        title_template.template.clone()
+    };
+    let Some(template) = template else {
+        return;
+    };
+
+    let title = render_title(&template, &TitleStats::collect(world));
+    let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+    if let Ok(mut window) = query.single_mut(world)
+        && window.title != title
+    {
+        window.title = title;
+    }
+}
+
+/// Handler for `set_window_title` requests
+pub(crate) fn set_window_title_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    // Extract title from params
+    let title = params
+        .as_ref()
+        .and_then(|parameters| parameters.get(PARAM_TITLE))
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            BrpExtrasError::InvalidParams.error("Missing or invalid 'title' parameter")
+        })?;
+
+    // Render templates immediately so the response reflects what the window shows
+    let template = is_template(title).then(|| title.to_string());
+    let new_title = template.as_ref().map_or_else(
+        || title.to_string(),
+        |template| render_title(template, &TitleStats::collect(world)),
+    );
+
+    headless::ensure_window(world)?;
+    let mut query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+
+    // Set new title, keeping the old one for the response
+    let old_title = {
+        let mut window = query
+            .single_mut(world)
+            .map_err(|_| BrpExtrasError::WindowNotFound.error("No primary window found"))?;
+        std::mem::replace(&mut window.title, new_title.clone())
+    };
+
+    let mut title_template = world.resource_mut::<TitleTemplate>();
+    title_template.template.clone_from(&template);
+    title_template.refresh.reset();
+
+    let message = if template.is_some() {
+        format!(
+            "Window title template set to '{title}' (refreshes every {TITLE_TEMPLATE_REFRESH_SECS}s)"
+        )
+    } else {
+        format!("Window title changed from '{old_title}' to '{new_title}'")
+    };
+
+    Ok(json!({
+        RESPONSE_STATUS_FIELD: RESPONSE_STATUS_SUCCESS,
+        RESPONSE_OLD_TITLE_FIELD: old_title,
+        RESPONSE_NEW_TITLE_FIELD: new_title,
+        RESPONSE_TEMPLATE_FIELD: template,
+        RESPONSE_MESSAGE_FIELD: message
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TitleStats;
+    use super::is_template;
+    use super::render_title;
+
+    #[test]
+    fn templates_substitute_live_values() {
+        let stats = TitleStats {
+            fps:      Some(59.6),
+            frame:    1_200,
+            entities: 42,
+        };
+
+        assert!(is_template("{fps} fps"));
+        assert!(!is_template("My Game - Level 2"));
+        assert_eq!(
+            render_title("fps {fps} | frame {frame} | {entities} entities", &stats),
+            "fps 60 | frame 1200 | 42 entities"
+        );
+        assert_eq!(
+            render_title("{fps}", &TitleStats { fps: None, ..stats }),
+            "n/a"
+        );
+    }
+}
@@ -3,9 +3,17 @@
 //! Bevy's picking system reads `MessageReader<WindowEvent>`, while other systems read
 //! individual message types like `MessageReader<CursorMoved>`. Bevy's winit integration
 //! writes to both channels, so our simulated input must do the same.
+//!
+//! Simulated input that spans several frames (timed releases, typing, drags, scripts) also asks
+//! for redraws while it is queued. Reactive update modes such as `WinitSettings::desktop_app()`
+//! and the browser's `requestAnimationFrame` loop only run `Update` when something happens, and
+//! injected BRP messages are not OS events, so a queued release would otherwise wait for real
+//! input.
 
 use bevy::ecs::message::Message;
+use bevy::ecs::message::Messages;
 use bevy::prelude::*;
+use bevy::window::RequestRedraw;
 use bevy::window::WindowEvent;
 
 /// Write an event to both its individual message channel and the `WindowEvent` channel.
@@ -20,3 +28,22 @@ where
     world.write_message(WindowEvent::from(event.clone()));
     world.write_message(event);
 }
+
+/// Request another frame while any entity still carries the queued input component `T`.
+pub(crate) fn request_redraw_while_queued<T: Component>(
+    queued: Query<(), With<T>>,
+    redraw: Option<ResMut<Messages<RequestRedraw>>>,
+) {
+    if let Some(mut redraw) = redraw
+        && !queued.is_empty()
+    {
+        redraw.write(RequestRedraw);
+    }
+}
+
+/// Request another frame, if the app has a windowing backend to receive it.
+pub(crate) fn request_redraw(world: &mut World) {
+    if let Some(mut redraw) = world.get_resource_mut::<Messages<RequestRedraw>>() {
+        redraw.write(RequestRedraw);
+    }
+}
@@ -0,0 +1,390 @@
+//! World snapshot and restore handlers for BRP extras
+//!
+//! A snapshot is every reflectable component and resource captured through `DynamicWorld` and
+//! serialized with Bevy's world serializer into JSON instead of RON, so it can be read back by
+//! `load_world_snapshot` or diffed by tools that speak BRP's JSON. Entities are keyed by the same
+//! ids BRP uses.
+//!
+//! Restoring writes the snapshot back over the entities it came from. Entities that were
+//! despawned since the snapshot are respawned with new ids, which the response reports.
+//! Components added after the snapshot are left in place.
+//!
+//! On WASM there is no filesystem, so snapshots are only saved and loaded inline.
+
+use std::any::TypeId;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bevy::asset::AssetPath;
+use bevy::asset::LoadFromPath;
+use bevy::asset::UntypedHandle;
+use bevy::ecs::archetype::Archetype;
+use bevy::ecs::archetype::ArchetypeEntity;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::world_serialization::DynamicWorld;
+use bevy::world_serialization::DynamicWorldBuilder;
+use bevy::world_serialization::serde::DynamicWorldSerializer;
+use bevy::world_serialization::serde::WorldDeserializer;
+use bevy_remote::BrpError;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::constants::WORLD_SNAPSHOT_VERSION;
+use crate::error::BrpExtrasError;
+use crate::support::parse_optional;
+use crate::support::parse_required;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `save_world_snapshot`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct SaveWorldSnapshotRequest {
+    /// File to write the snapshot to (None = return it inline)
+    #[serde(default)]
+    path:              Option<PathBuf>,
+    /// Entities to capture (None = every entity)
+    #[serde(default)]
+    entities:          Option<Vec<u64>>,
+    /// Capture reflectable resources too
+    #[serde(default = "include_resources_default")]
+    include_resources: bool,
+}
+
+impl Default for SaveWorldSnapshotRequest {
+    fn default() -> Self {
+        Self {
+            path:              None,
+            entities:          None,
+            include_resources: include_resources_default(),
+        }
+    }
+}
+
+const fn include_resources_default() -> bool { true }
+
+/// Request structure for `load_world_snapshot`
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct LoadWorldSnapshotRequest {
+    /// File written by `save_world_snapshot`
+    #[serde(default)]
+    path:     Option<PathBuf>,
+    /// Inline snapshot as returned by `save_world_snapshot`
+    #[serde(default)]
+    snapshot: Option<WorldSnapshot>,
+}
+
+/// Snapshot document as written to disk or returned inline
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct WorldSnapshot {
+    /// Format version, checked on load
+    version: u32,
+    /// World serialized with Bevy's world serializer: `resources` and `entities`
+    scene:   Value,
+}
+
+/// Response structure for `save_world_snapshot`
+#[derive(Serialize)]
+struct SaveWorldSnapshotResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path:           Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot:       Option<WorldSnapshot>,
+    entity_count:   usize,
+    resource_count: usize,
+}
+
+/// A snapshot entity that no longer existed and was respawned under a new id
+#[derive(Serialize)]
+struct RespawnedEntity {
+    snapshot_entity: u64,
+    entity:          u64,
+}
+
+/// Response structure for `load_world_snapshot`
+#[derive(Serialize)]
+struct LoadWorldSnapshotResponse {
+    entity_count:   usize,
+    resource_count: usize,
+    respawned:      Vec<RespawnedEntity>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `save_world_snapshot` BRP method
+///
+/// Captures reflectable entities and resources, writing them to `path` or returning them inline.
+pub(crate) fn save_world_snapshot_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: SaveWorldSnapshotRequest = parse_optional(params)?;
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let scene = {
+        let builder = DynamicWorldBuilder::from_world(world, &registry);
+        let builder = match &request.entities {
+            Some(entities) => {
+                builder.extract_entities(resolve_entities(world, entities)?.into_iter())
+            },
+            None => builder.extract_entities(
+                world
+                    .archetypes()
+                    .iter()
+                    .flat_map(Archetype::entities)
+                    .map(ArchetypeEntity::id),
+            ),
+        };
+        if request.include_resources {
+            builder.extract_resources().build()
+        } else {
+            builder.build()
+        }
+    };
+
+    let serialized =
+        serde_json::to_value(DynamicWorldSerializer::new(&scene, &registry)).map_err(|error| {
+            BrpExtrasError::Internal.error(format!("Failed to serialize world snapshot: {error}"))
+        })?;
+    drop(registry);
+    let snapshot = WorldSnapshot {
+        version: WORLD_SNAPSHOT_VERSION,
+        scene:   serialized,
+    };
+
+    let (path, snapshot) = match request.path {
+        Some(path) => {
+            write_snapshot_file(&path, &snapshot)?;
+            (Some(path.display().to_string()), None)
+        },
+        None => (None, Some(snapshot)),
+    };
+
+    serialize_response(SaveWorldSnapshotResponse {
+        path,
+        snapshot,
+        entity_count: scene.entities.len(),
+        resource_count: scene.resources.len(),
+    })
+}
+
+/// Handler for `load_world_snapshot` BRP method
+///
+/// Writes a snapshot back into the world from `path` or an inline `snapshot`.
+pub(crate) fn load_world_snapshot_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: LoadWorldSnapshotRequest = parse_required(params)?;
+    let snapshot = match (request.path, request.snapshot) {
+        (Some(path), None) => read_snapshot_file(&path)?,
+        (None, Some(snapshot)) => snapshot,
+        _ => {
+            return Err(
+                BrpExtrasError::InvalidParams.error("Provide exactly one of 'path' or 'snapshot'")
+            );
+        },
+    };
+    if snapshot.version != WORLD_SNAPSHOT_VERSION {
+        return Err(BrpExtrasError::InvalidParams.with_details(
+            format!(
+                "Unsupported world snapshot version {} (expected {WORLD_SNAPSHOT_VERSION})",
+                snapshot.version
+            ),
+            json!({ "version": snapshot.version, "expected_version": WORLD_SNAPSHOT_VERSION }),
+        ));
+    }
+
+    let scene = deserialize_scene(world, snapshot.scene)?;
+
+    // Map each snapshot entity onto itself so restoring overwrites it in place
+    let mut entity_map: EntityHashMap<Entity> = scene
+        .entities
+        .iter()
+        .map(|dynamic_entity| dynamic_entity.entity)
+        .filter(|entity| world.get_entity(*entity).is_ok())
+        .map(|entity| (entity, entity))
+        .collect();
+    scene
+        .write_to_world(world, &mut entity_map)
+        .map_err(|error| {
+            BrpExtrasError::InvalidParams
+                .error(format!("Failed to restore world snapshot: {error}"))
+        })?;
+
+    let mut respawned: Vec<RespawnedEntity> = entity_map
+        .iter()
+        .filter(|(snapshot_entity, entity)| snapshot_entity != entity)
+        .map(|(snapshot_entity, entity)| RespawnedEntity {
+            snapshot_entity: snapshot_entity.to_bits(),
+            entity:          entity.to_bits(),
+        })
+        .collect();
+    respawned.sort_by_key(|entity| entity.snapshot_entity);
+
+    serialize_response(LoadWorldSnapshotResponse {
+        entity_count: scene.entities.len(),
+        resource_count: scene.resources.len(),
+        respawned,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn resolve_entities(world: &World, entities: &[u64]) -> Result<Vec<Entity>, BrpError> {
+    entities
+        .iter()
+        .map(|&bits| {
+            Entity::try_from_bits(bits)
+                .filter(|entity| world.get_entity(*entity).is_ok())
+                .ok_or_else(|| {
+                    BrpExtrasError::EntityNotFound.with_details(
+                        format!("Entity {bits} does not exist"),
+                        json!({ "entity": bits }),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Stands in for the `AssetServer` in apps without one, which have no asset handles to restore
+struct NoAssetServer;
+
+impl LoadFromPath for NoAssetServer {
+    fn load_from_path_erased(
+        &mut self,
+        type_id: TypeId,
+        _path: AssetPath<'static>,
+    ) -> UntypedHandle {
+        UntypedHandle::Uuid {
+            type_id,
+            uuid: AssetId::<Image>::INVALID_UUID,
+        }
+    }
+}
+
+fn deserialize_scene(world: &World, scene: Value) -> Result<DynamicWorld, BrpError> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut asset_server = world.get_resource::<AssetServer>();
+    let mut no_asset_server = NoAssetServer;
+    let load_from_path: &mut dyn LoadFromPath = match asset_server.as_mut() {
+        Some(asset_server) => asset_server,
+        None => &mut no_asset_server,
+    };
+    WorldDeserializer {
+        type_registry: &registry,
+        load_from_path,
+    }
+    .deserialize(scene)
+    .map_err(|error| {
+        BrpExtrasError::InvalidParams.error(format!("Failed to read world snapshot: {error}"))
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_snapshot_file(path: &Path, snapshot: &WorldSnapshot) -> Result<(), BrpError> {
+    let contents = serde_json::to_string_pretty(snapshot).map_err(|error| {
+        BrpExtrasError::Internal.error(format!("Failed to serialize world snapshot: {error}"))
+    })?;
+    fs::write(path, contents).map_err(|error| {
+        BrpExtrasError::Io.with_details(
+            format!(
+                "Failed to write world snapshot to {}: {error}",
+                path.display()
+            ),
+            json!({ "path": path.display().to_string() }),
+        )
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_snapshot_file(path: &Path) -> Result<WorldSnapshot, BrpError> {
+    let contents = fs::read_to_string(path).map_err(|error| {
+        BrpExtrasError::Io.with_details(
+            format!("Failed to read world snapshot {}: {error}", path.display()),
+            json!({ "path": path.display().to_string() }),
+        )
+    })?;
+    serde_json::from_str::<WorldSnapshot>(&contents).map_err(|error| {
+        BrpExtrasError::InvalidParams.with_details(
+            format!("{} is not a world snapshot: {error}", path.display()),
+            json!({ "path": path.display().to_string() }),
+        )
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_snapshot_file(path: &Path, _snapshot: &WorldSnapshot) -> Result<(), BrpError> {
+    Err(no_filesystem_error(path))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_snapshot_file(path: &Path) -> Result<WorldSnapshot, BrpError> {
+    Err(no_filesystem_error(path))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn no_filesystem_error(path: &Path) -> BrpError {
+    BrpExtrasError::Unsupported.with_details(
+        "World snapshot files are unavailable on WASM; omit 'path' to pass the snapshot inline",
+        json!({ "path": path.display().to_string() }),
+    )
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::In;
+    use bevy::prelude::Name;
+    use serde_json::json;
+
+    use super::load_world_snapshot_handler;
+    use super::save_world_snapshot_handler;
+
+    #[test]
+    fn load_restores_values_saved_inline() {
+        let mut app = App::new();
+        app.register_type::<Name>();
+        let entity = app.world_mut().spawn(Name::new("before")).id();
+
+        let saved = save_world_snapshot_handler(
+            In(Some(json!({ "entities": [entity.to_bits()] }))),
+            app.world_mut(),
+        )
+        .expect("snapshot should save");
+        assert_eq!(saved["entity_count"], 1);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(Name::new("after"));
+        let loaded = load_world_snapshot_handler(
+            In(Some(json!({ "snapshot": saved["snapshot"] }))),
+            app.world_mut(),
+        )
+        .expect("snapshot should load");
+
+        assert_eq!(loaded["respawned"], json!([]));
+        assert_eq!(
+            app.world().get::<Name>(entity).map(Name::as_str),
+            Some("before")
+        );
+    }
+}
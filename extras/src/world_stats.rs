@@ -0,0 +1,195 @@
+//! World statistics handler for BRP extras
+//!
+//! `get_world_stats` summarizes the world's size without serializing any component values:
+//! entity, archetype, and table counts, each archetype's component set, and an estimate of the
+//! component memory in use. Comparing two calls is a cheap way to spot entity leaks.
+
+use bevy::ecs::archetype::Archetype;
+use bevy::prelude::*;
+use bevy_remote::BrpResult;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::support::parse_optional;
+use crate::support::serialize_response;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Request structure for `get_world_stats`
+#[derive(Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GetWorldStatsRequest {
+    /// Include archetypes that currently hold no entities
+    #[serde(default)]
+    include_empty: bool,
+    /// Only list this many archetypes, largest first
+    #[serde(default)]
+    limit:         Option<usize>,
+}
+
+/// One archetype: a distinct set of components shared by its entities
+#[derive(Serialize)]
+struct ArchetypeStats {
+    archetype:       usize,
+    table:           usize,
+    entity_count:    usize,
+    /// Component type names, sorted
+    components:      Vec<String>,
+    /// Component data size of the archetype's entities, from each component's type layout
+    estimated_bytes: usize,
+}
+
+/// Response structure for `get_world_stats`
+#[derive(Serialize)]
+struct WorldStatsResponse {
+    entity_count:              usize,
+    archetype_count:           usize,
+    /// Archetypes holding at least one entity
+    non_empty_archetype_count: usize,
+    table_count:               usize,
+    /// Number of registered component types
+    component_type_count:      usize,
+    /// Component data size of every entity; heap data owned by components is not included
+    estimated_component_bytes: usize,
+    /// Archetypes by entity count, largest first
+    archetypes:                Vec<ArchetypeStats>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Handler for `get_world_stats` BRP method
+///
+/// Returns entity, archetype, and table counts with per-archetype component sets and sizes.
+pub(crate) fn get_world_stats_handler(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let request: GetWorldStatsRequest = parse_optional(params)?;
+
+    let mut archetypes: Vec<ArchetypeStats> = world
+        .archetypes()
+        .iter()
+        .map(|archetype| archetype_stats(world, archetype))
+        .collect();
+
+    let entity_count = archetypes
+        .iter()
+        .map(|archetype| archetype.entity_count)
+        .sum();
+    let estimated_component_bytes = archetypes
+        .iter()
+        .map(|archetype| archetype.estimated_bytes)
+        .sum();
+    let archetype_count = archetypes.len();
+    let non_empty_archetype_count = archetypes
+        .iter()
+        .filter(|archetype| archetype.entity_count > 0)
+        .count();
+
+    if !request.include_empty {
+        archetypes.retain(|archetype| archetype.entity_count > 0);
+    }
+    archetypes.sort_by(|a, b| {
+        b.entity_count
+            .cmp(&a.entity_count)
+            .then(a.archetype.cmp(&b.archetype))
+    });
+    if let Some(limit) = request.limit {
+        archetypes.truncate(limit);
+    }
+
+    serialize_response(WorldStatsResponse {
+        entity_count,
+        archetype_count,
+        non_empty_archetype_count,
+        table_count: world.storages().tables.len(),
+        component_type_count: world.components().len(),
+        estimated_component_bytes,
+        archetypes,
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn archetype_stats(world: &World, archetype: &Archetype) -> ArchetypeStats {
+    let entity_count = archetype.entities().len();
+    let mut components = Vec::new();
+    let mut component_bytes = 0;
+    for &component in archetype.components() {
+        if let Some(info) = world.components().get_info(component) {
+            components.push(info.name().to_string());
+            component_bytes += info.layout().size();
+        }
+    }
+    components.sort();
+
+    ArchetypeStats {
+        archetype: archetype.id().index(),
+        table: archetype.table_id().as_usize(),
+        entity_count,
+        components,
+        estimated_bytes: component_bytes.saturating_mul(entity_count),
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::Component;
+    use bevy::prelude::In;
+    use serde_json::json;
+
+    use super::get_world_stats_handler;
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Component)]
+    struct Health(#[expect(dead_code, reason = "only its size is measured")] u64);
+
+    #[test]
+    fn reports_component_sets_and_sizes_per_archetype() {
+        let mut app = App::new();
+        let world = app.world_mut();
+        world.spawn(Marker);
+        world.spawn((Marker, Health(10)));
+        world.spawn((Marker, Health(20)));
+
+        let response = get_world_stats_handler(In(None), app.world_mut())
+            .expect("world stats should serialize");
+
+        let with_health = response["archetypes"]
+            .as_array()
+            .and_then(|archetypes| {
+                archetypes.iter().find(|archetype| {
+                    archetype["components"]
+                        .as_array()
+                        .is_some_and(|components| {
+                            components.iter().any(|name| {
+                                name.as_str().is_some_and(|name| name.ends_with("Health"))
+                            })
+                        })
+                })
+            })
+            .expect("the Marker and Health archetype should be listed");
+        assert_eq!(with_health["entity_count"], 2);
+        assert_eq!(with_health["estimated_bytes"], json!(2 * size_of::<u64>()));
+        assert!(
+            response["entity_count"]
+                .as_u64()
+                .is_some_and(|count| count >= 3)
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Report how a launched app exited when it died without `brp_shutdown`
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::crash;
+use super::crash::CrashReport;
+use crate::brp_tools::Port;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetCrashReportParams {
+    /// The BRP port the app was launched on (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result from looking up a crash report
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct GetCrashReportResult {
+    /// How the app exited, or nothing when the app launched on the port has not died
+    #[to_result(skip_if_none)]
+    crash_report:     Option<CrashReport>,
+    /// Whether the app launched on the port exited without `brp_shutdown`
+    #[to_metadata]
+    crashed:          bool,
+    /// Message template for formatting responses
+    #[to_message]
+    message_template: Option<String>,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "GetCrashReportParams", output = "GetCrashReportResult")]
+pub struct GetCrashReport;
+
+#[allow(
+    clippy::unused_async,
+    reason = "ToolFn trait requires async handler signature"
+)]
+async fn handle_impl(params: GetCrashReportParams) -> Result<GetCrashReportResult> {
+    let report = crash::crash_report(params.port);
+    let message = report.as_ref().map_or_else(
+        || {
+            format!(
+                "No unexpected exit recorded for an app launched on port {}",
+                params.port
+            )
+        },
+        |report| format!("'{}' (PID {}) {}", report.app_name, report.pid, report.exit),
+    );
+    let crashed = report.is_some();
+    Ok(GetCrashReportResult::new(report, crashed).with_message_template(message))
+}
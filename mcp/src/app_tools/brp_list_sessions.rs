@@ -0,0 +1,48 @@
+//! List the apps this server launched or probed, with their ports and live status
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::registry;
+use super::registry::AppSession;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ListSessionsParams {
+    /// Remove apps that are no longer running from the registry after listing them (default:
+    /// false)
+    #[serde(default)]
+    pub prune: bool,
+}
+
+/// Result from listing registered apps
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct ListSessionsResult {
+    /// Registered apps, sorted by port
+    #[to_result]
+    sessions:         Vec<AppSession>,
+    /// Number of registered apps
+    #[to_metadata]
+    session_count:    usize,
+    /// Message template for formatting responses
+    #[to_message(message_template = "Found {session_count} registered apps")]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "ListSessionsParams", output = "ListSessionsResult")]
+pub struct ListSessions;
+
+async fn handle_impl(params: ListSessionsParams) -> Result<ListSessionsResult> {
+    let sessions = registry::list_sessions(params.prune).await;
+    let session_count = sessions.len();
+    Ok(ListSessionsResult::new(sessions, session_count))
+}
@@ -0,0 +1,84 @@
+//! `brp_run_scenario` - run a scenario of MCP tool calls with assertions and waits.
+
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::scenario;
+use super::scenario::Scenario;
+use super::scenario::StepReport;
+use super::scenario::StepSummary;
+use crate::brp_tools::Port;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct RunScenarioParams {
+    /// Path to the JSON scenario file
+    pub scenario_path: String,
+    /// The BRP port (default: 15702). Passed to every step that does not set its own `port`.
+    #[serde(default)]
+    pub port:          Port,
+}
+
+/// Result from running a scenario
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct RunScenarioResult {
+    /// Per-step outcomes in execution order
+    #[to_result]
+    steps:            Vec<StepReport>,
+    /// Scenario name from the file
+    #[to_metadata]
+    scenario:         String,
+    /// Number of steps
+    #[to_metadata]
+    total:            usize,
+    /// Number of passed steps
+    #[to_metadata]
+    passed:           usize,
+    /// Number of failed steps
+    #[to_metadata]
+    failed:           usize,
+    /// Number of skipped steps
+    #[to_metadata]
+    skipped:          usize,
+    /// Total run time in milliseconds
+    #[to_metadata]
+    duration_ms:      u128,
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Scenario '{scenario}': {passed}/{total} steps passed, {failed} failed, {skipped} skipped"
+    )]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "RunScenarioParams", output = "RunScenarioResult")]
+pub struct RunScenario;
+
+async fn handle_impl(params: RunScenarioParams) -> Result<RunScenarioResult> {
+    let scenario_path = PathBuf::from(&params.scenario_path);
+    let scenario = Scenario::load(&scenario_path)?;
+    let name = scenario.scenario_name(&scenario_path);
+
+    let run = scenario::run_scenario(&scenario, params.port).await;
+
+    let summary = StepSummary::from_steps(&run.steps);
+    Ok(RunScenarioResult::new(
+        run.steps,
+        name,
+        summary.total,
+        summary.passed,
+        summary.failed,
+        summary.skipped,
+        run.duration.as_millis(),
+    ))
+}
@@ -0,0 +1,106 @@
+//! `brp_run_test_plan` - run a declarative end-to-end test plan and write a `JUnit` report.
+
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::test_plan;
+use super::test_plan::CaseReport;
+use super::test_plan::JUNIT_REPORT_EXTENSION;
+use super::test_plan::Summary;
+use super::test_plan::TestPlan;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct RunTestPlanParams {
+    /// Path to the JSON test plan file
+    pub plan_path:   String,
+    /// Where to write the `JUnit` XML report (default: next to the plan as
+    /// `<plan>.junit.xml`)
+    #[to_metadata(skip_if_none)]
+    pub report_path: Option<String>,
+    /// The BRP port (default: 15702). Also used as the launch port when the plan launches an app.
+    #[serde(default)]
+    pub port:        Port,
+}
+
+/// Result from running a test plan
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct RunTestPlanResult {
+    /// Per-case outcomes in execution order
+    #[to_result]
+    cases:            Vec<CaseReport>,
+    /// Suite name from the plan
+    #[to_metadata]
+    suite:            String,
+    /// Number of reported cases, including failed setup or teardown phases
+    #[to_metadata]
+    tests:            usize,
+    /// Number of passed cases
+    #[to_metadata]
+    passed:           usize,
+    /// Number of failed cases
+    #[to_metadata]
+    failures:         usize,
+    /// Number of skipped cases
+    #[to_metadata]
+    skipped:          usize,
+    /// Total run time in milliseconds
+    #[to_metadata]
+    duration_ms:      u128,
+    /// Where the `JUnit` XML report was written
+    #[to_metadata]
+    report_path:      String,
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Test plan '{suite}': {passed}/{tests} passed, {failures} failed, {skipped} skipped. Report: {report_path}"
+    )]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "RunTestPlanParams", output = "RunTestPlanResult")]
+pub struct RunTestPlan;
+
+async fn handle_impl(params: RunTestPlanParams) -> Result<RunTestPlanResult> {
+    let plan_path = PathBuf::from(&params.plan_path);
+    let plan = TestPlan::load(&plan_path)?;
+    let suite = plan.suite_name(&plan_path);
+    let report_path = params.report_path.map_or_else(
+        || plan_path.with_extension(JUNIT_REPORT_EXTENSION),
+        PathBuf::from,
+    );
+
+    let run = test_plan::run_plan(plan, &plan_path, params.port).await;
+
+    let junit = test_plan::render_junit(&suite, &run.cases, run.duration);
+    std::fs::write(&report_path, junit).map_err(|error| {
+        Error::FileOperation(format!(
+            "Failed to write JUnit report '{}': {error}",
+            report_path.display()
+        ))
+    })?;
+
+    let summary = Summary::from_cases(&run.cases);
+    Ok(RunTestPlanResult::new(
+        run.cases,
+        suite,
+        summary.tests,
+        summary.passed,
+        summary.failures,
+        summary.skipped,
+        run.duration.as_millis(),
+        report_path.display().to_string(),
+    ))
+}
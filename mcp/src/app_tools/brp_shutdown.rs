@@ -11,7 +11,10 @@ use sysinfo::System;
 use tracing::debug;
 
 use super::constants::PID_FIELD;
+use super::crash;
 use super::process;
+use super::registry;
+use crate::brp_tools;
 use crate::brp_tools::BrpClient;
 use crate::brp_tools::JSON_RPC_ERROR_METHOD_NOT_FOUND;
 use crate::brp_tools::Port;
@@ -59,7 +62,7 @@ pub struct ShutdownResult {
 }
 
 /// Result of a shutdown operation
-enum ShutdownOutcome {
+pub(super) enum ShutdownOutcome {
     /// Graceful shutdown via `bevy_brp_extras` succeeded
     Clean { process_id: u32 },
     /// Process was killed using system signal - typically when extras plugin is not available
@@ -75,8 +78,10 @@ enum ShutdownOutcome {
 pub struct Shutdown;
 
 /// Attempt to shutdown a Bevy app, first trying graceful shutdown then falling back to kill
-async fn shutdown_app(app_name: &str, port: Port) -> ShutdownOutcome {
+pub(super) async fn shutdown_app(app_name: &str, port: Port) -> ShutdownOutcome {
     debug!("Starting shutdown process for app '{app_name}' on port {port}");
+    port.forget_owner();
+    crash::expect_exit(port);
 
     // Try graceful shutdown via `bevy_brp_extras`.
     // Older `bevy_brp_extras` versions can return zero extracted entities.
@@ -146,6 +151,13 @@ fn handle_kill_process_fallback(
 async fn handle_impl(params: ShutdownParams) -> Result<ShutdownResult> {
     // Shutdown the app
     let result = shutdown_app(&params.app_name, params.port).await;
+    brp_tools::forget_extras_capabilities(params.port);
+    brp_tools::forget_type_guides(params.port);
+    brp_tools::forget_format_corrections(params.port);
+    brp_tools::forget_undo_history(params.port);
+    if !matches!(result, ShutdownOutcome::Error { .. }) {
+        registry::unregister(params.port);
+    }
 
     // Build and return typed response
     match result {
@@ -226,7 +238,7 @@ fn kill_process(app_name: &str, port: Port) -> Result<Option<u32>> {
     system.refresh_processes(ProcessesToUpdate::All, true);
 
     // First try: Get PID from port for more reliable process identification
-    let target_pid = process::get_pid_for_port(port).map_or_else(
+    let target_pid = port.listening_pid().map_or_else(
         || {
             debug!("No process found listening on port {port}, falling back to name-only lookup");
             None
@@ -1,9 +1,11 @@
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
 use bevy_brp_mcp_macros::ToolFn;
+use cargo_metadata::semver::Version;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 use sysinfo::Process;
 use sysinfo::ProcessesToUpdate;
 use sysinfo::System;
@@ -19,8 +21,12 @@ use super::constants::STATUS_MAX_RETRIES;
 use super::constants::STATUS_POLL_INTERVAL;
 use super::constants::TARGET_DEBUG_PATH;
 use super::constants::TARGET_RELEASE_PATH;
+use super::launch;
 use super::process;
+use super::process::ProcessMetrics;
+use super::registry;
 use crate::brp_tools;
+use crate::brp_tools::JSON_RPC_ERROR_METHOD_NOT_FOUND;
 use crate::brp_tools::Port;
 use crate::brp_tools::ResponseStatus;
 use crate::error::Error;
@@ -52,6 +58,12 @@ pub struct StatusResult {
     /// Port where BRP is responding
     #[to_metadata]
     port:             u16,
+    /// CPU, memory, and thread usage of the process
+    #[to_result(skip_if_none)]
+    process_metrics:  Option<ProcessMetrics>,
+    /// Whether `bevy_brp_extras` is present and as new as this MCP server
+    #[to_metadata(skip_if_none)]
+    extras:           Option<ExtrasStatus>,
     /// Message template for formatting responses
     #[to_message(
         message_template = "Process '{app_name}' (PID: {pid}) is running with BRP enabled on port {port}"
@@ -67,6 +79,38 @@ async fn handle_impl(params: StatusParams) -> Result<StatusResult> {
     check_brp_for_app(&params.app_name, params.port).await
 }
 
+/// Whether `bevy_brp_extras` answers on the port, and which build it is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtrasStatus {
+    state:            ExtrasState,
+    /// `bevy_brp_extras` version reported by `brp_extras/ping`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plugin_version:   Option<String>,
+    /// Version of this MCP server; extras older than this may lack methods its tools call
+    expected_version: String,
+    /// Bevy version the app's `bevy_brp_extras` targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bevy_version:     Option<String>,
+    /// Enabled `bevy_brp_extras` cargo features
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    features:         Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uptime_secs:      Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ExtrasState {
+    /// No `brp_extras/` method is registered: the app lacks `BrpExtrasPlugin`
+    Missing,
+    /// Extras are present but older than this MCP server, or predate `brp_extras/ping`
+    Outdated,
+    /// Extras are present and at least as new as this MCP server
+    Current,
+    /// Extras answered in an unexpected way, e.g. a version that could not be parsed
+    Unknown,
+}
+
 /// Error when process is not found
 #[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
 struct ProcessNotFoundError {
@@ -124,6 +168,10 @@ struct BrpNotRespondingError {
     #[to_error_info]
     port: u16,
 
+    /// CPU, memory, and thread usage, to tell a busy app from a hung or idle one
+    #[to_error_info(skip_if_none)]
+    process_metrics: Option<ProcessMetrics>,
+
     #[to_message(
         message_template = "Process '{app_name}' (PID: {pid}) is running but not responding to BRP on port {port}. Make sure RemotePlugin is added to your Bevy app."
     )]
@@ -221,11 +269,25 @@ fn extract_app_name(process: &Process) -> String {
 
 async fn check_brp_for_app(app_name: &str, port: Port) -> Result<StatusResult> {
     let brp_port_status = check_brp_on_port(port).await?;
+    let extras = if brp_port_status.is_responding() {
+        Some(check_extras_on_port(port).await)
+    } else {
+        None
+    };
     let mut system = System::new_all();
     system.refresh_processes(ProcessesToUpdate::All, true);
 
-    if let Some(process_id) = process::get_pid_for_port(port) {
-        return resolve_pid_on_port(&system, app_name, port, brp_port_status, process_id);
+    if let Some(process_id) = port.listening_pid() {
+        let process_metrics = process::sample_process_metrics(process_id).await;
+        return resolve_pid_on_port(
+            &system,
+            app_name,
+            port,
+            brp_port_status,
+            process_id,
+            process_metrics,
+            extras,
+        );
     }
 
     if let Some(process_id) = find_exact_match_pid(&system, app_name) {
@@ -234,6 +296,7 @@ async fn check_brp_for_app(app_name: &str, port: Port) -> Result<StatusResult> {
                 app_name.to_string(),
                 process_id,
                 port.0,
+                process::sample_process_metrics(process_id).await,
             )),
         })?;
     }
@@ -254,12 +317,21 @@ fn resolve_pid_on_port(
     port: Port,
     brp_port_status: BrpPortStatus,
     process_id: u32,
+    process_metrics: Option<ProcessMetrics>,
+    extras: Option<ExtrasStatus>,
 ) -> Result<StatusResult> {
     if let Some(process) = system.process(sysinfo::Pid::from_u32(process_id))
         && process::process_matches_name_exact(process, app_name)
     {
         if brp_port_status.is_responding() {
-            return Ok(StatusResult::new(app_name.to_string(), process_id, port.0));
+            registry::register_probe(process_id, app_name, port);
+            return Ok(StatusResult::new(
+                app_name.to_string(),
+                process_id,
+                port.0,
+                process_metrics,
+                extras,
+            ));
         }
 
         Err(Error::Structured {
@@ -267,6 +339,7 @@ fn resolve_pid_on_port(
                 app_name.to_string(),
                 process_id,
                 port.0,
+                process_metrics,
             )),
         })?;
     }
@@ -370,3 +443,110 @@ async fn check_brp_on_port(port: Port) -> Result<BrpPortStatus> {
     // After all retries failed
     Ok(BrpPortStatus::NotResponding)
 }
+
+/// Ask the app's `bevy_brp_extras`, if any, for its version with `brp_extras/ping`
+async fn check_extras_on_port(port: Port) -> ExtrasStatus {
+    let client = brp_tools::BrpClient::new(BrpMethod::BrpExtrasPing, port, None);
+    match client.execute_raw().await {
+        Ok(ResponseStatus::Success(ping)) => {
+            brp_tools::refresh_extras_capabilities(port).await;
+            ExtrasStatus::from_ping(ping.as_ref())
+        },
+        Ok(ResponseStatus::Error(brp_error))
+            if brp_error.get_code() == JSON_RPC_ERROR_METHOD_NOT_FOUND =>
+        {
+            // Extras released before `ping` still register their other methods
+            let discover = brp_tools::BrpClient::new(BrpMethod::RpcDiscover, port, None);
+            let state = match discover.execute_raw().await {
+                Ok(ResponseStatus::Success(Some(discovery)))
+                    if launch::has_extras_methods(&discovery) =>
+                {
+                    ExtrasState::Outdated
+                },
+                _ => ExtrasState::Missing,
+            };
+            ExtrasStatus::with_state(state)
+        },
+        Ok(ResponseStatus::Error(_)) | Err(_) => ExtrasStatus::with_state(ExtrasState::Unknown),
+    }
+}
+
+impl ExtrasStatus {
+    fn with_state(state: ExtrasState) -> Self {
+        Self {
+            state,
+            plugin_version: None,
+            expected_version: env!("CARGO_PKG_VERSION").to_string(),
+            bevy_version: None,
+            features: Vec::new(),
+            uptime_secs: None,
+        }
+    }
+
+    fn from_ping(ping: Option<&Value>) -> Self {
+        let field = |name: &str| {
+            ping.and_then(|ping| ping.get(name))
+                .and_then(Value::as_str)
+                .map(ToString::to_string)
+        };
+        let plugin_version = field("plugin_version");
+        let state = plugin_version
+            .as_deref()
+            .map_or(ExtrasState::Unknown, extras_version_state);
+
+        Self {
+            plugin_version,
+            bevy_version: field("bevy_version"),
+            features: ping
+                .and_then(|ping| ping.get("features"))
+                .and_then(Value::as_array)
+                .map(|features| {
+                    features
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            uptime_secs: ping
+                .and_then(|ping| ping.get("uptime_secs"))
+                .and_then(Value::as_f64),
+            ..Self::with_state(state)
+        }
+    }
+}
+
+/// Compare the extras version with this server's; pre-release versions sort before their release
+fn extras_version_state(plugin_version: &str) -> ExtrasState {
+    match (
+        Version::parse(plugin_version),
+        Version::parse(env!("CARGO_PKG_VERSION")),
+    ) {
+        (Ok(extras), Ok(expected)) if extras < expected => ExtrasState::Outdated,
+        (Ok(_), Ok(_)) => ExtrasState::Current,
+        _ => ExtrasState::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ExtrasState;
+    use super::ExtrasStatus;
+
+    #[test]
+    fn classifies_extras_by_version() {
+        let current = ExtrasStatus::from_ping(Some(&json!({
+            "plugin_version": env!("CARGO_PKG_VERSION"),
+            "features": ["diagnostics"],
+        })));
+        let outdated = ExtrasStatus::from_ping(Some(&json!({ "plugin_version": "0.1.0" })));
+        let unparsable = ExtrasStatus::from_ping(Some(&json!({ "plugin_version": "dev" })));
+
+        assert_eq!(current.state, ExtrasState::Current);
+        assert_eq!(current.features, vec!["diagnostics".to_string()]);
+        assert_eq!(outdated.state, ExtrasState::Outdated);
+        assert_eq!(unparsable.state, ExtrasState::Unknown);
+    }
+}
@@ -17,6 +17,12 @@ pub(super) const CARGO_MESSAGE_FORMAT_JSON_FLAG: &str = "--message-format=json";
 pub(super) const CARGO_RUN_SUBCOMMAND: &str = "run";
 pub(super) const USER_ARGUMENT_SEPARATOR: &str = "--";
 
+// crash report constants
+/// Lines from the end of a crashed app's log kept in its crash report
+pub(super) const CRASH_LOG_TAIL_LINES: usize = 40;
+/// Text Rust prints on the line that starts a panic message
+pub(super) const PANIC_MARKER: &str = "panicked at";
+
 // executable suffixes
 pub(super) const APP_EXTENSION_SUFFIX: &str = ".app";
 pub(super) const BIN_EXTENSION_SUFFIX: &str = ".bin";
@@ -35,6 +41,20 @@ pub(super) const VALID_INSTANCE_RANGE: RangeInclusive<u16> =
 pub(super) const MANIFEST_PATH_FIELD: &str = "manifest_path";
 pub(super) const PID_FIELD: &str = "pid";
 
+// orphan cleanup constants
+/// Environment variable selecting startup orphan cleanup: `report` (default), `cleanup`, or `off`
+pub(super) const ORPHAN_CLEANUP_ENV_VAR: &str = "BRP_MCP_ORPHAN_CLEANUP";
+/// Extension of per-server session files
+pub(super) const SESSION_FILE_EXTENSION: &str = "pid";
+/// Filename prefix of per-server session files, followed by the server PID
+pub(super) const SESSION_FILE_PREFIX: &str = "bevy_brp_mcp_session_";
+/// Extension a crashed session's file is renamed to once report mode has listed its orphans
+pub(super) const REPORTED_SESSION_FILE_EXTENSION: &str = "reported";
+
+// port allocation constants
+/// `port` value that asks `brp_launch` to pick free ports
+pub(super) const AUTO_PORT: &str = "auto";
+
 // process matching constants
 pub(super) const GENERIC_PROCESS_NAMES: &[&str] =
     &["tail", "grep", "cat", "less", "more", "head", "sed", "awk"];
@@ -44,6 +64,10 @@ pub(super) const DEFAULT_PROFILE: &str = PROFILE_DEBUG;
 pub(super) const PROFILE_DEBUG: &str = "debug";
 pub(super) const PROFILE_RELEASE: &str = "release";
 
+// restart policy constants
+/// Default number of relaunches per instance under the "on-crash" restart policy
+pub(super) const DEFAULT_RESTART_MAX_RETRIES: u32 = 3;
+
 // status polling constants
 /// Maximum number of retries when checking BRP port responsiveness
 pub(super) const STATUS_MAX_RETRIES: u32 = 5;
@@ -0,0 +1,255 @@
+//! Crash detection for launched apps
+//!
+//! The thread that reaps each launched app records how it exited. Unless `brp_shutdown` stopped
+//! the app, the exit is kept per port as a crash report with the exit code or signal, the panic
+//! message if there was one, and the tail of the app's log. `brp_get_crash_report` returns it, and
+//! BRP calls that find nothing listening on the port point at it, so an agent learns the app died
+//! instead of waiting on a port that will never answer. Reports live as long as this server
+//! process; launching on the port again clears its report.
+//!
+//! Apps launched with a `restart` policy are relaunched after a crash. The next tool call on the
+//! port reports the restart once as `app_restarted`, since entity IDs and other state from before
+//! the crash are gone.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::ExitStatus;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::constants::CRASH_LOG_TAIL_LINES;
+use super::constants::PANIC_MARKER;
+use crate::brp_tools::Port;
+
+/// Launch and exit state keyed by BRP port
+static LAUNCHED_PORTS: LazyLock<Mutex<HashMap<Port, LaunchedPort>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct LaunchedPort {
+    /// Process most recently launched on the port; exits of earlier processes are ignored
+    pid:                u32,
+    /// Set by `brp_shutdown`, so the exit it causes is not reported as a crash
+    shutdown_requested: bool,
+    report:             Option<CrashReport>,
+    /// Restart not yet reported by a tool call on the port
+    restart_notice:     Option<AppRestart>,
+}
+
+/// How a launched app exited without being shut down by `brp_shutdown`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CrashReport {
+    pub app_name:  String,
+    pub pid:       u32,
+    pub port:      Port,
+    /// Exit code, when the app exited on its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Signal that terminated the app (Unix only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signal:    Option<i32>,
+    /// How the app exited, e.g. "exited with code 101" or "terminated by signal 11"
+    pub exit:      String,
+    /// The panic message from the log, when the app panicked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic:     Option<String>,
+    /// Log file the app wrote to, for `brp_read_log`
+    pub log_file:  String,
+    /// Last lines of the log when the app exited
+    pub log_tail:  Vec<String>,
+    /// When the exit was detected (RFC 3339)
+    pub exited_at: String,
+}
+
+/// A crashed app relaunched by its restart policy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppRestart {
+    pub app_name:      String,
+    /// Process that crashed
+    pub previous_pid:  u32,
+    /// Process started in its place
+    pub pid:           u32,
+    /// Which restart this was, counting from 1
+    pub restart:       u32,
+    pub max_retries:   u32,
+    /// How the previous process exited
+    pub previous_exit: String,
+}
+
+/// Start tracking the process just launched on `port`, clearing the port's previous report
+pub(super) fn track_launch(pid: u32, port: Port) {
+    if let Ok(mut ports) = LAUNCHED_PORTS.lock() {
+        ports.insert(
+            port,
+            LaunchedPort {
+                pid,
+                ..LaunchedPort::default()
+            },
+        );
+    }
+}
+
+/// Track the process that replaced a crashed one on `port`, keeping the crash report
+pub(super) fn track_restart(pid: u32, port: Port) {
+    if let Ok(mut ports) = LAUNCHED_PORTS.lock() {
+        let launched = ports.entry(port).or_default();
+        launched.pid = pid;
+        launched.shutdown_requested = false;
+    }
+}
+
+/// Keep a restart for the next tool call on `port` to report
+pub(super) fn record_restart(port: Port, restart: AppRestart) {
+    if let Ok(mut ports) = LAUNCHED_PORTS.lock()
+        && let Some(launched) = ports.get_mut(&port)
+    {
+        launched.restart_notice = Some(restart);
+    }
+}
+
+/// The restart on `port` not yet reported, which is reported only once
+pub(crate) fn take_restart_notice(port: Port) -> Option<AppRestart> {
+    LAUNCHED_PORTS
+        .lock()
+        .ok()
+        .and_then(|mut ports| ports.get_mut(&port)?.restart_notice.take())
+}
+
+/// Note that `brp_shutdown` is stopping the app on `port`, so its exit is expected
+pub(super) fn expect_exit(port: Port) {
+    if let Ok(mut ports) = LAUNCHED_PORTS.lock()
+        && let Some(launched) = ports.get_mut(&port)
+    {
+        launched.shutdown_requested = true;
+    }
+}
+
+/// Record the exit of a launched app, unless it was shut down or replaced by a newer launch
+///
+/// Returns the crash report when the exit was unexpected.
+pub(super) fn record_exit(
+    pid: u32,
+    app_name: &str,
+    port: Port,
+    log_path: &Path,
+    status: ExitStatus,
+) -> Option<CrashReport> {
+    let unexpected = LAUNCHED_PORTS
+        .lock()
+        .ok()?
+        .get(&port)
+        .is_some_and(|launched| launched.pid == pid && !launched.shutdown_requested);
+    if !unexpected {
+        return None;
+    }
+
+    let exit_code = status.code();
+    #[cfg(unix)]
+    let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+    #[cfg(not(unix))]
+    let signal = None;
+
+    let log = fs::read_to_string(log_path).unwrap_or_default();
+    tracing::warn!("'{app_name}' (PID {pid}, port {port}) exited unexpectedly: {status}");
+    let report = CrashReport {
+        app_name: app_name.to_string(),
+        pid,
+        port,
+        exit_code,
+        signal,
+        exit: describe_exit(exit_code, signal),
+        panic: panic_message(&log),
+        log_file: log_path.display().to_string(),
+        log_tail: log_tail(&log, CRASH_LOG_TAIL_LINES),
+        exited_at: chrono::Local::now().to_rfc3339(),
+    };
+    // The log is read without the lock held, so store the report only if the port still belongs
+    // to this process
+    if let Ok(mut ports) = LAUNCHED_PORTS.lock()
+        && let Some(launched) = ports.get_mut(&port).filter(|launched| launched.pid == pid)
+    {
+        launched.report = Some(report.clone());
+    }
+    Some(report)
+}
+
+/// The unexpected exit recorded for `port`, if the app launched there has died
+pub(crate) fn crash_report(port: Port) -> Option<CrashReport> {
+    LAUNCHED_PORTS.lock().ok().and_then(|ports| {
+        ports
+            .get(&port)
+            .and_then(|launched| launched.report.clone())
+    })
+}
+
+fn describe_exit(exit_code: Option<i32>, signal: Option<i32>) -> String {
+    match (exit_code, signal) {
+        (Some(code), _) => format!("exited with code {code}"),
+        (None, Some(signal)) => format!("terminated by signal {signal}"),
+        (None, None) => "exited with an unknown status".to_string(),
+    }
+}
+
+/// Panic location and message, e.g. "thread 'main' panicked at src/main.rs:10:5: boom"
+///
+/// Rust prints the message on the line after the location, so both lines are joined.
+fn panic_message(log: &str) -> Option<String> {
+    let lines: Vec<&str> = log.lines().collect();
+    let index = lines.iter().rposition(|line| line.contains(PANIC_MARKER))?;
+    let location = lines[index].trim_end();
+    Some(match lines.get(index + 1) {
+        Some(message) if location.ends_with(':') && !message.trim().is_empty() => {
+            format!("{location} {}", message.trim())
+        },
+        _ => location.to_string(),
+    })
+}
+
+fn log_tail(log: &str, lines: usize) -> Vec<String> {
+    let all: Vec<&str> = log.lines().collect();
+    all[all.len().saturating_sub(lines)..]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log_tail;
+    use super::panic_message;
+
+    const PANIC_LOG: &str = "=== Bevy BRP MCP Launch Log ===\n\
+        INFO bevy_render: AdapterInfo\n\
+        thread 'main' panicked at src/main.rs:10:5:\n\
+        index out of bounds: the len is 0 but the index is 3\n\
+        note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+
+    #[test]
+    fn panic_location_is_joined_with_its_message() {
+        assert_eq!(
+            panic_message(PANIC_LOG).as_deref(),
+            Some(
+                "thread 'main' panicked at src/main.rs:10:5: index out of bounds: the len is 0 \
+                 but the index is 3"
+            )
+        );
+        assert_eq!(panic_message("INFO all good\n"), None);
+    }
+
+    #[test]
+    fn log_tail_keeps_the_last_lines() {
+        assert_eq!(
+            log_tail(PANIC_LOG, 2),
+            [
+                "index out of bounds: the len is 0 but the index is 3",
+                "note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace",
+            ]
+        );
+        assert_eq!(log_tail("one\n", 5), ["one"]);
+    }
+}
@@ -1,18 +1,23 @@
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
-use std::process::Output;
+use std::process::Stdio;
 
+use bevy_brp_mcp_macros::ResultStruct;
 use error_stack::Report;
-use serde_json::Value;
+use serde::Deserialize;
+use serde::Serialize;
 use tracing::debug;
 use tracing::info;
 
-use super::constants::BUILD_OUTPUT_FRESH_FIELD;
-use super::constants::BUILD_OUTPUT_NAME_FIELD;
-use super::constants::BUILD_OUTPUT_TARGET_FIELD;
+use super::cargo_messages::CargoBuildOutput;
+use super::cargo_messages::CompilerDiagnostic;
+use super::constants::CARGO_FEATURES_FLAG;
 use super::constants::CARGO_RELEASE_FLAG;
 use super::logging;
 use crate::app_tools::constants::CARGO_BUILD_SUBCOMMAND;
@@ -28,11 +33,69 @@ use crate::brp_tools::Port;
 use crate::error::Error;
 use crate::error::Result;
 
-#[derive(Debug, Clone, Copy)]
-pub(super) enum BuildState {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildState {
+    /// The build succeeded without reporting the target, so it is assumed to be built
     NotFound,
+    /// Nothing needed rebuilding
     Fresh,
     Rebuilt,
+    /// `build` was "never", or cargo builds the example when it runs it
+    Skipped,
+}
+
+/// How the target was built before launch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildReport {
+    pub state:         BuildState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features:      Option<Vec<String>>,
+    pub warning_count: usize,
+    /// First compiler warnings, in the order cargo reported them
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings:      Vec<CompilerDiagnostic>,
+}
+
+impl BuildReport {
+    /// Report for a launch that did not run `cargo build`
+    pub(super) const fn without_build(state: BuildState) -> Self {
+        Self {
+            state,
+            features: None,
+            warning_count: 0,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+/// Error when `cargo build` fails before launch
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+struct BuildFailedError {
+    #[to_error_info]
+    target_name: String,
+
+    #[to_error_info]
+    profile: String,
+
+    #[to_error_info]
+    error_count: usize,
+
+    /// First compiler errors, with file locations and rustc's rendered output
+    #[to_error_info]
+    errors: Vec<CompilerDiagnostic>,
+
+    #[to_error_info]
+    warning_count: usize,
+
+    /// Cargo's stderr, for failures that are not compiler errors (missing features, bad manifests)
+    #[to_error_info(skip_if_none)]
+    cargo_output: Option<String>,
+
+    #[to_message(
+        message_template = "cargo build failed for `{target_name}` with {error_count} compiler error(s)"
+    )]
+    message_template: String,
 }
 
 pub(super) fn validate_manifest_directory(manifest_path: &Path) -> Result<&Path> {
@@ -85,9 +148,16 @@ pub(super) fn setup_launch_logging(
     Ok((log_file_path, log_file_for_redirect))
 }
 
+fn add_features_arg(command: &mut Command, features: Option<&[String]>) {
+    if let Some(features) = features.filter(|features| !features.is_empty()) {
+        command.arg(CARGO_FEATURES_FLAG).arg(features.join(","));
+    }
+}
+
 pub(super) fn build_cargo_example_command(
     example_name: &str,
     profile: &str,
+    features: Option<&[String]>,
     port: Option<Port>,
     env: Option<&HashMap<String, String>>,
     command_line_arguments: Option<&[String]>,
@@ -102,6 +172,8 @@ pub(super) fn build_cargo_example_command(
         command.arg(CARGO_RELEASE_FLAG);
     }
 
+    add_features_arg(&mut command, features);
+
     if let Some(user_arguments) = command_line_arguments {
         command.arg(USER_ARGUMENT_SEPARATOR).args(user_arguments);
     }
@@ -131,6 +203,7 @@ fn build_cargo_command(
     target_name: &str,
     target_type: TargetType,
     profile: &str,
+    features: Option<&[String]>,
     manifest_dir: &Path,
 ) -> Command {
     let mut command = Command::new(CARGO_COMMAND_NAME);
@@ -143,62 +216,82 @@ fn build_cargo_command(
         command.arg(CARGO_RELEASE_FLAG);
     }
 
+    add_features_arg(&mut command, features);
+
     command.arg(CARGO_MESSAGE_FORMAT_JSON_FLAG);
 
     command
 }
 
+/// Run the build, reading cargo's JSON messages as they arrive
+///
+/// Compiler errors and warnings are collected for the tool result, so a failed build reports each
+/// error with its location instead of cargo's stderr.
 fn execute_build_command(
     command: &mut Command,
     target_name: &str,
     target_type: TargetType,
     profile: &str,
     manifest_dir: &Path,
-) -> Result<Output> {
+) -> Result<CargoBuildOutput> {
     debug!("Running cargo build for {target_type} '{target_name}' with command: {command:?}");
 
-    let output = command.output().map_err(|e| {
+    let spawn_error = |e: std::io::Error| {
         Error::ProcessManagement(format!(
             "Failed to run cargo build for {target_type} '{target_name}' (profile: {profile}, dir: {}): {e}",
             manifest_dir.display()
         ))
-    })?;
+    };
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(Error::ProcessManagement(format!(
-            "Cargo build failed for {target_type} '{target_name}' (profile: {profile}, dir: {}): {stderr}",
-            manifest_dir.display()
-        ))
-        .into());
-    }
+    // Drain stderr on its own thread so cargo never blocks on a full pipe
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut output = String::new();
+            let _ = stderr.read_to_string(&mut output);
+            output
+        })
+    });
 
-    Ok(output)
-}
+    let mut output = CargoBuildOutput::default();
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout)
+            .lines()
+            .map_while(std::io::Result::ok)
+        {
+            output.add_line(&line, target_name);
+        }
+    }
 
-fn parse_build_output(stdout: &[u8], target_name: &str) -> BuildState {
-    let stdout_str = String::from_utf8_lossy(stdout);
+    let status = child.wait().map_err(spawn_error)?;
+    let stderr = stderr_reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
 
-    for line in stdout_str.lines() {
-        if let Ok(json) = serde_json::from_str::<Value>(line)
-            && let Some(target) = json.get(BUILD_OUTPUT_TARGET_FIELD)
-            && let Some(name) = target.get(BUILD_OUTPUT_NAME_FIELD)
-            && name.as_str() == Some(target_name)
-        {
-            return json
-                .get(BUILD_OUTPUT_FRESH_FIELD)
-                .and_then(serde_json::Value::as_bool)
-                .map_or(BuildState::Rebuilt, |is_fresh| {
-                    if is_fresh {
-                        BuildState::Fresh
-                    } else {
-                        BuildState::Rebuilt
-                    }
-                });
+    if !status.success() {
+        debug!(
+            "Cargo build failed for {target_type} '{target_name}' (profile: {profile}, dir: {}): {stderr}",
+            manifest_dir.display()
+        );
+        let error = BuildFailedError::new(
+            target_name.to_string(),
+            profile.to_string(),
+            output.error_count,
+            output.errors,
+            output.warning_count,
+            Some(stderr.trim().to_string()).filter(|stderr| !stderr.is_empty()),
+        );
+        return Err(Error::Structured {
+            result: Box::new(error),
         }
+        .into());
     }
 
-    BuildState::NotFound
+    Ok(output)
 }
 
 fn log_build_result(build_state: BuildState, target_name: &str, target_type: TargetType) {
@@ -212,6 +305,9 @@ fn log_build_result(build_state: BuildState, target_name: &str, target_type: Tar
         BuildState::Rebuilt => {
             info!("{target_type} '{target_name}' was built successfully");
         },
+        BuildState::Skipped => {
+            debug!("Build of {target_type} '{target_name}' was skipped");
+        },
     }
 }
 
@@ -219,9 +315,11 @@ pub(super) fn run_cargo_build(
     target_name: &str,
     target_type: TargetType,
     profile: &str,
+    features: Option<&[String]>,
     manifest_dir: &Path,
-) -> Result<BuildState> {
-    let mut command = build_cargo_command(target_name, target_type, profile, manifest_dir);
+) -> Result<BuildReport> {
+    let mut command =
+        build_cargo_command(target_name, target_type, profile, features, manifest_dir);
     let output = execute_build_command(
         &mut command,
         target_name,
@@ -229,8 +327,13 @@ pub(super) fn run_cargo_build(
         profile,
         manifest_dir,
     )?;
-    let build_state = parse_build_output(&output.stdout, target_name);
+    let build_state = output.state.unwrap_or(BuildState::NotFound);
     log_build_result(build_state, target_name, target_type);
 
-    Ok(build_state)
+    Ok(BuildReport {
+        state:         build_state,
+        features:      features.map(<[String]>::to_vec),
+        warning_count: output.warning_count,
+        warnings:      output.warnings,
+    })
 }
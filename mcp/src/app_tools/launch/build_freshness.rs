@@ -288,13 +288,14 @@ mod tests {
 
     fn test_target(workspace_root: &Path, manifest_path: &Path, name: &str) -> BevyTarget {
         BevyTarget {
-            name:           name.to_string(),
-            target_type:    TargetType::App,
-            package_name:   "pkg".to_string(),
-            workspace_root: workspace_root.to_path_buf(),
-            manifest:       manifest_path.to_path_buf(),
-            relative:       PathBuf::new(),
-            source:         PathBuf::new(),
+            name:              name.to_string(),
+            target_type:       TargetType::App,
+            package_name:      "pkg".to_string(),
+            workspace_root:    workspace_root.to_path_buf(),
+            manifest:          manifest_path.to_path_buf(),
+            relative:          PathBuf::new(),
+            source:            PathBuf::new(),
+            required_features: Vec::new(),
         }
     }
 
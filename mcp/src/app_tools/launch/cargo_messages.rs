@@ -0,0 +1,191 @@
+//! Parsing `cargo build --message-format=json` output
+//!
+//! Cargo writes one JSON message per line to stdout. `compiler-artifact` messages tell whether the
+//! launch target was rebuilt, and `compiler-message` messages carry rustc's diagnostics, which are
+//! what an agent needs to fix a failed build. Human-readable progress goes to stderr instead.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::build::BuildState;
+use super::constants::CARGO_ARTIFACT_REASON;
+use super::constants::CARGO_COMPILER_MESSAGE_REASON;
+use super::constants::MAX_BUILD_DIAGNOSTICS;
+
+/// One line of cargo's JSON output, keeping only the fields the launch uses
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason:  String,
+    #[serde(default)]
+    target:  Option<CargoTarget>,
+    #[serde(default)]
+    fresh:   Option<bool>,
+    #[serde(default)]
+    message: Option<RustcDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct CargoTarget {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct RustcDiagnostic {
+    message:  String,
+    level:    String,
+    #[serde(default)]
+    spans:    Vec<RustcSpan>,
+    #[serde(default)]
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RustcSpan {
+    file_name:    String,
+    line_start:   u64,
+    column_start: u64,
+    is_primary:   bool,
+}
+
+/// A compiler error or warning from the build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompilerDiagnostic {
+    /// "error" or "warning"
+    pub level:    String,
+    pub message:  String,
+    /// Primary span as `file:line:column`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// The diagnostic as rustc prints it, with source snippets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+}
+
+impl CompilerDiagnostic {
+    pub(super) fn is_error(&self) -> bool { self.level.starts_with("error") }
+}
+
+/// What the build printed to stdout, collected line by line as cargo runs
+#[derive(Debug, Default)]
+pub(super) struct CargoBuildOutput {
+    /// Whether the launch target was rebuilt; `None` until cargo reports its artifact
+    pub state:         Option<BuildState>,
+    /// First errors, up to `MAX_BUILD_DIAGNOSTICS`
+    pub errors:        Vec<CompilerDiagnostic>,
+    pub error_count:   usize,
+    /// First warnings, up to `MAX_BUILD_DIAGNOSTICS`
+    pub warnings:      Vec<CompilerDiagnostic>,
+    pub warning_count: usize,
+}
+
+impl CargoBuildOutput {
+    /// Record one line of cargo output, returning the diagnostic it carried, if any
+    pub(super) fn add_line(&mut self, line: &str, target_name: &str) -> Option<CompilerDiagnostic> {
+        let message: CargoMessage = serde_json::from_str(line).ok()?;
+        if message.reason == CARGO_ARTIFACT_REASON {
+            if self.state.is_none()
+                && message
+                    .target
+                    .is_some_and(|target| target.name == target_name)
+            {
+                self.state = Some(if message.fresh == Some(true) {
+                    BuildState::Fresh
+                } else {
+                    BuildState::Rebuilt
+                });
+            }
+            return None;
+        }
+        if message.reason != CARGO_COMPILER_MESSAGE_REASON {
+            return None;
+        }
+
+        let diagnostic = to_compiler_diagnostic(message.message?)?;
+        let (count, kept) = if diagnostic.is_error() {
+            (&mut self.error_count, &mut self.errors)
+        } else {
+            (&mut self.warning_count, &mut self.warnings)
+        };
+        *count += 1;
+        if kept.len() < MAX_BUILD_DIAGNOSTICS {
+            kept.push(diagnostic.clone());
+        }
+        Some(diagnostic)
+    }
+}
+
+/// Errors and warnings only; notes, help and the trailing "aborting due to" summary are dropped
+fn to_compiler_diagnostic(diagnostic: RustcDiagnostic) -> Option<CompilerDiagnostic> {
+    let is_error = diagnostic.level.starts_with("error");
+    if !is_error && diagnostic.level != "warning" {
+        return None;
+    }
+    if diagnostic.spans.is_empty()
+        && (diagnostic.message.starts_with("aborting due to")
+            || diagnostic.message.ends_with("warning emitted")
+            || diagnostic.message.ends_with("warnings emitted"))
+    {
+        return None;
+    }
+    let location = diagnostic
+        .spans
+        .iter()
+        .find(|span| span.is_primary)
+        .map(|span| {
+            format!(
+                "{}:{}:{}",
+                span.file_name, span.line_start, span.column_start
+            )
+        });
+    Some(CompilerDiagnostic {
+        level: diagnostic.level,
+        message: diagnostic.message,
+        location,
+        rendered: diagnostic.rendered,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BuildState;
+    use super::CargoBuildOutput;
+
+    const ERROR_LINE: &str = r#"{"reason":"compiler-message","package_id":"game 0.1.0","target":{"name":"game"},"message":{"message":"cannot find value `speed` in this scope","level":"error","code":{"code":"E0425"},"spans":[{"file_name":"src/main.rs","line_start":12,"column_start":9,"is_primary":true}],"rendered":"error[E0425]: cannot find value `speed` in this scope\n"}}"#;
+    const ABORT_LINE: &str = r#"{"reason":"compiler-message","target":{"name":"game"},"message":{"message":"aborting due to 1 previous error","level":"error","spans":[],"rendered":"error: aborting due to 1 previous error\n"}}"#;
+    const ARTIFACT_LINE: &str =
+        r#"{"reason":"compiler-artifact","target":{"name":"game"},"fresh":false}"#;
+
+    #[test]
+    fn errors_are_kept_with_their_location() {
+        let mut output = CargoBuildOutput::default();
+        let diagnostic = output.add_line(ERROR_LINE, "game");
+        assert_eq!(
+            diagnostic.and_then(|diagnostic| diagnostic.location),
+            Some("src/main.rs:12:9".to_string())
+        );
+        assert!(output.add_line(ABORT_LINE, "game").is_none());
+        assert!(
+            output
+                .add_line("   Compiling game v0.1.0", "game")
+                .is_none()
+        );
+        assert_eq!(output.error_count, 1);
+        assert_eq!(
+            output.errors[0].message,
+            "cannot find value `speed` in this scope"
+        );
+        assert!(output.state.is_none());
+    }
+
+    #[test]
+    fn the_target_artifact_sets_the_build_state() {
+        let mut output = CargoBuildOutput::default();
+        output.add_line(
+            r#"{"reason":"compiler-artifact","target":{"name":"bevy"},"fresh":true}"#,
+            "game",
+        );
+        assert!(output.state.is_none());
+        output.add_line(ARTIFACT_LINE, "game");
+        assert!(matches!(output.state, Some(BuildState::Rebuilt)));
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::process::Command;
+use std::time::Duration;
 use std::time::Instant;
 
 use bevy_brp_mcp_macros::ResultStruct;
@@ -9,10 +10,15 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::build;
+use super::build::BuildReport;
 use super::build::BuildState;
 use super::build_freshness;
 use super::build_freshness::FreshnessCheckResult;
+use super::verification::BrpVerification;
 use crate::app_tools::instance_count::InstanceCount;
+use crate::app_tools::launch_params::BuildMode;
+use crate::app_tools::launch_params::LaunchPort;
+use crate::app_tools::launch_params::RestartPolicy;
 use crate::app_tools::launch_params::SearchOrder;
 use crate::app_tools::targets::BevyTarget;
 use crate::app_tools::targets::TargetType;
@@ -33,10 +39,13 @@ pub(super) struct LaunchConfig<T> {
     target:         String,
     profile:        String,
     package:        Option<String>,
-    port:           Port,
+    port:           LaunchPort,
     instance_count: InstanceCount,
     env:            Option<HashMap<String, String>>,
     arguments:      Option<Vec<String>>,
+    build_mode:     BuildMode,
+    features:       Option<Vec<String>>,
+    restart:        Option<RestartPolicy>,
     phantom_data:   PhantomData<T>,
 }
 
@@ -45,7 +54,7 @@ impl<T> LaunchConfig<T> {
         target: String,
         profile: String,
         package: Option<String>,
-        port: Port,
+        port: LaunchPort,
         instance_count: InstanceCount,
         env: Option<HashMap<String, String>>,
         arguments: Option<Vec<String>>,
@@ -58,9 +67,23 @@ impl<T> LaunchConfig<T> {
             instance_count,
             env,
             arguments,
+            build_mode: BuildMode::Auto,
+            features: None,
+            restart: None,
             phantom_data: PhantomData,
         }
     }
+
+    fn with_build(mut self, build_mode: BuildMode, features: Option<Vec<String>>) -> Self {
+        self.build_mode = build_mode;
+        self.features = features;
+        self
+    }
+
+    const fn with_restart(mut self, restart: Option<RestartPolicy>) -> Self {
+        self.restart = restart;
+        self
+    }
 }
 
 /// Represents a single launched instance
@@ -69,6 +92,9 @@ pub struct LaunchedInstance {
     pub pid:      u32,
     pub log_file: String,
     pub port:     u16,
+    /// BRP status found by the post-launch check (absent when `verify_brp` is false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brp:      Option<BrpVerification>,
 }
 
 /// Unified result type for launching Bevy apps and examples
@@ -90,6 +116,9 @@ pub struct LaunchResult {
     /// Binary path of the launched app (only for apps, not examples)
     #[to_metadata(skip_if_none)]
     binary_path:       Option<String>,
+    /// How the target was built before launch, with any compiler warnings
+    #[to_metadata(skip_if_none)]
+    build:             Option<BuildReport>,
     /// Launch duration in milliseconds
     #[serde(rename = "launch_duration_ms")]
     #[to_metadata(skip_if_none)]
@@ -111,6 +140,9 @@ pub struct LaunchResult {
     /// Available duplicate paths (for disambiguation errors)
     #[to_metadata(skip_if_none)]
     duplicate_paths:   Option<Vec<String>>,
+    /// Where to enable BRP or `bevy_brp_extras` when the post-launch check found them missing
+    #[to_metadata(skip_if_none)]
+    brp_setup_hints:   Option<Vec<String>>,
     /// Message template for formatting responses
     #[to_message]
     message_template:  Option<String>,
@@ -122,11 +154,17 @@ pub struct LaunchParams {
     pub profile:        String,
     pub path:           Option<String>,
     pub package:        Option<String>,
-    pub port:           Port,
+    pub port:           LaunchPort,
     pub instance_count: InstanceCount,
     pub env:            Option<HashMap<String, String>>,
     pub search_order:   SearchOrder,
     pub args:           Option<Vec<String>>,
+    pub verify_brp:     bool,
+    /// How long the post-launch check waits for each instance to answer BRP, if not the default
+    pub verify_timeout: Option<Duration>,
+    pub build:          BuildMode,
+    pub features:       Option<Vec<String>>,
+    pub restart:        Option<RestartPolicy>,
 }
 
 /// Trait for configuring launch behavior for different target types (app vs example)
@@ -139,18 +177,45 @@ pub(super) trait LaunchConfigTrait: Clone {
 
     fn package(&self) -> Option<&str>;
 
-    fn port(&self) -> Port;
+    /// Port requested for the launch; instances are launched on the ports it resolves to
+    fn port(&self) -> LaunchPort;
 
     fn instance_count(&self) -> InstanceCount;
 
-    fn set_port(&mut self, port: Port);
+    fn build_mode(&self) -> BuildMode;
+
+    /// Cargo features requested for the launch
+    fn features(&self) -> Option<&[String]>;
 
-    fn build_command(&self, target: &BevyTarget) -> Command;
+    /// Features to build with: the requested ones plus the target's `required-features`, without
+    /// which cargo refuses to build it
+    fn build_features(&self, target: &BevyTarget) -> Option<Vec<String>> {
+        let mut features = self.features().map(<[String]>::to_vec).unwrap_or_default();
+        for feature in &target.required_features {
+            if !features.contains(feature) {
+                features.push(feature.clone());
+            }
+        }
+        (!features.is_empty()).then_some(features)
+    }
+
+    /// Relaunch policy for instances that crash
+    fn restart(&self) -> Option<RestartPolicy>;
+
+    fn build_command(&self, target: &BevyTarget, port: Port) -> Command;
 
     fn extra_log_info(&self, target: &BevyTarget) -> Option<String>;
 
-    fn ensure_built(&self, target: &BevyTarget) -> Result<BuildState> {
-        if Self::TARGET_TYPE == TargetType::App {
+    fn ensure_built(&self, target: &BevyTarget) -> Result<BuildReport> {
+        let build_mode = self.build_mode();
+        if build_mode == BuildMode::Never {
+            return Ok(BuildReport::without_build(BuildState::Skipped));
+        }
+        // The freshness check cannot tell which features the binary was built with
+        if Self::TARGET_TYPE == TargetType::App
+            && build_mode == BuildMode::Auto
+            && self.features().is_none()
+        {
             let freshness = build_freshness::check_target_freshness(target, self.profile());
             match &freshness {
                 FreshnessCheckResult::Fresh => {},
@@ -166,7 +231,7 @@ pub(super) trait LaunchConfigTrait: Clone {
                 ),
             }
             if matches!(freshness, FreshnessCheckResult::Fresh) {
-                return Ok(BuildState::Fresh);
+                return Ok(BuildReport::without_build(BuildState::Fresh));
             }
         }
 
@@ -175,6 +240,7 @@ pub(super) trait LaunchConfigTrait: Clone {
             self.target(),
             Self::TARGET_TYPE,
             self.profile(),
+            self.build_features(target).as_deref(),
             manifest_dir,
         )
     }
@@ -184,6 +250,9 @@ pub(super) fn build_launch_result<T: LaunchConfigTrait>(
     all_pids: Vec<u32>,
     all_log_files: Vec<PathBuf>,
     all_ports: Vec<u16>,
+    verifications: Option<Vec<BrpVerification>>,
+    brp_setup_hints: Option<Vec<String>>,
+    build: BuildReport,
     config: &T,
     target: &BevyTarget,
     launch_start: Instant,
@@ -194,10 +263,14 @@ pub(super) fn build_launch_result<T: LaunchConfigTrait>(
         .into_iter()
         .zip(all_log_files.iter())
         .zip(all_ports.iter())
-        .map(|((process_id, log_file), port)| LaunchedInstance {
+        .enumerate()
+        .map(|(index, ((process_id, log_file), port))| LaunchedInstance {
             pid:      process_id,
             log_file: log_file.display().to_string(),
             port:     *port,
+            brp:      verifications
+                .as_ref()
+                .and_then(|verifications| verifications.get(index).copied()),
         })
         .collect();
 
@@ -207,17 +280,31 @@ pub(super) fn build_launch_result<T: LaunchConfigTrait>(
         .and_then(|name| name.to_str())
         .map(String::from);
 
-    let port_range = if all_ports.len() == 1 {
-        all_ports[0].to_string()
-    } else {
-        format!("{}-{}", all_ports[0], all_ports[all_ports.len() - 1])
-    };
+    let port_range = describe_ports(&all_ports);
 
     let instance_count = all_ports.len();
-    let message = format!(
-        "Successfully launched {instance_count} instance(s) of {} on ports {port_range}",
-        config.target()
-    );
+    let not_responding = instances
+        .iter()
+        .filter(|instance| instance.brp.is_some_and(|brp| !brp.responding))
+        .count();
+    let message = if not_responding > 0 {
+        format!(
+            "Launched {instance_count} instance(s) of {} on ports {port_range}, but BRP is not \
+             responding on {not_responding} of them - see brp_setup_hints",
+            config.target()
+        )
+    } else if brp_setup_hints.is_some() {
+        format!(
+            "Launched {instance_count} instance(s) of {} on ports {port_range}; BRP responds but \
+             bevy_brp_extras is not registered - see brp_setup_hints",
+            config.target()
+        )
+    } else {
+        format!(
+            "Successfully launched {instance_count} instance(s) of {} on ports {port_range}",
+            config.target()
+        )
+    };
 
     LaunchResult {
         target: Some(config.target().to_string()),
@@ -244,12 +331,30 @@ pub(super) fn build_launch_result<T: LaunchConfigTrait>(
         } else {
             None
         },
+        build: Some(build),
         launched_as: Some(T::TARGET_TYPE.to_string()),
         duplicate_paths: None,
+        brp_setup_hints,
         message_template: Some(message),
     }
 }
 
+/// `15702`, `15702-15704` for consecutive ports, or a list when automatic allocation skipped ports
+fn describe_ports(ports: &[u16]) -> String {
+    let consecutive = ports
+        .windows(2)
+        .all(|pair| pair[0].checked_add(1) == Some(pair[1]));
+    match (ports.first(), ports.last()) {
+        (Some(first), Some(last)) if first == last => first.to_string(),
+        (Some(first), Some(last)) if consecutive => format!("{first}-{last}"),
+        _ => ports
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
 impl From<&LaunchParams> for LaunchConfig<App> {
     fn from(params: &LaunchParams) -> Self {
         Self::new(
@@ -261,6 +366,8 @@ impl From<&LaunchParams> for LaunchConfig<App> {
             params.env.clone(),
             params.args.clone(),
         )
+        .with_build(params.build, params.features.clone())
+        .with_restart(params.restart)
     }
 }
 
@@ -273,16 +380,20 @@ impl LaunchConfigTrait for LaunchConfig<App> {
 
     fn package(&self) -> Option<&str> { self.package.as_deref() }
 
-    fn port(&self) -> Port { self.port }
+    fn port(&self) -> LaunchPort { self.port }
 
     fn instance_count(&self) -> InstanceCount { self.instance_count }
 
-    fn set_port(&mut self, port: Port) { self.port = port; }
+    fn build_mode(&self) -> BuildMode { self.build_mode }
+
+    fn features(&self) -> Option<&[String]> { self.features.as_deref() }
 
-    fn build_command(&self, target: &BevyTarget) -> Command {
+    fn restart(&self) -> Option<RestartPolicy> { self.restart }
+
+    fn build_command(&self, target: &BevyTarget, port: Port) -> Command {
         build::build_app_command(
             &target.get_binary_path(self.profile()),
-            Some(self.port),
+            Some(port),
             self.env.as_ref(),
             self.arguments.as_deref(),
         )
@@ -302,6 +413,8 @@ impl From<&LaunchParams> for LaunchConfig<Example> {
             params.env.clone(),
             params.args.clone(),
         )
+        .with_build(params.build, params.features.clone())
+        .with_restart(params.restart)
     }
 }
 
@@ -314,17 +427,22 @@ impl LaunchConfigTrait for LaunchConfig<Example> {
 
     fn package(&self) -> Option<&str> { self.package.as_deref() }
 
-    fn port(&self) -> Port { self.port }
+    fn port(&self) -> LaunchPort { self.port }
 
     fn instance_count(&self) -> InstanceCount { self.instance_count }
 
-    fn set_port(&mut self, port: Port) { self.port = port; }
+    fn build_mode(&self) -> BuildMode { self.build_mode }
+
+    fn features(&self) -> Option<&[String]> { self.features.as_deref() }
+
+    fn restart(&self) -> Option<RestartPolicy> { self.restart }
 
-    fn build_command(&self, _: &BevyTarget) -> Command {
+    fn build_command(&self, target: &BevyTarget, port: Port) -> Command {
         build::build_cargo_example_command(
             &self.target,
             self.profile(),
-            Some(self.port),
+            self.build_features(target).as_deref(),
+            Some(port),
             self.env.as_ref(),
             self.arguments.as_deref(),
         )
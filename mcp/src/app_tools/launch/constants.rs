@@ -1,10 +1,22 @@
+use std::time::Duration;
+
+// brp verification
+pub(super) const BRP_EXTRAS_CRATE_NAME: &str = "bevy_brp_extras";
+pub(super) const BRP_EXTRAS_METHOD_PREFIX: &str = "brp_extras/";
+pub(super) const BRP_EXTRAS_PLUGIN_NAME: &str = "BrpExtrasPlugin";
+pub(super) const LAUNCH_VERIFY_TIMEOUT: Duration = Duration::from_secs(10);
+pub(super) const REMOTE_HTTP_PLUGIN_NAME: &str = "RemoteHttpPlugin";
+pub(super) const REMOTE_PLUGIN_NAME: &str = "RemotePlugin";
+
 // cargo arguments
+pub(super) const CARGO_FEATURES_FLAG: &str = "--features";
 pub(super) const CARGO_RELEASE_FLAG: &str = "--release";
 
-// cargo build json fields
-pub(super) const BUILD_OUTPUT_FRESH_FIELD: &str = "fresh";
-pub(super) const BUILD_OUTPUT_NAME_FIELD: &str = "name";
-pub(super) const BUILD_OUTPUT_TARGET_FIELD: &str = "target";
+// cargo build json output
+pub(super) const CARGO_ARTIFACT_REASON: &str = "compiler-artifact";
+pub(super) const CARGO_COMPILER_MESSAGE_REASON: &str = "compiler-message";
+/// Errors and warnings kept in the launch result or error; the counts include the rest
+pub(super) const MAX_BUILD_DIAGNOSTICS: usize = 20;
 
 // error details
 pub(super) const ERROR_CHAIN_FIELD: &str = "error_chain";
@@ -1,10 +1,13 @@
 mod build;
 mod build_freshness;
+mod cargo_messages;
 mod config;
 mod constants;
 mod logging;
 mod orchestration;
+mod verification;
 
 pub(super) use config::LaunchParams;
 pub(super) use config::LaunchResult;
 pub(super) use orchestration::launch_bevy_target;
+pub(super) use verification::has_extras_methods;
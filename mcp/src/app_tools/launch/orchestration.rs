@@ -1,9 +1,11 @@
 use std::fs::File;
 use std::path::PathBuf;
 use std::process::Command;
+use std::time::Duration;
 use std::time::Instant;
 
 use error_stack::Report;
+use futures::future::join_all;
 use tracing::debug;
 use tracing::warn;
 
@@ -14,9 +16,14 @@ use super::config::LaunchParams;
 use super::config::LaunchResult;
 use super::constants::ERROR_CHAIN_FIELD;
 use super::constants::ERROR_FIELD;
+use super::constants::LAUNCH_VERIFY_TIMEOUT;
+use super::verification;
 use crate::app_tools::launch_params::LaunchBevyBinaryParams;
+use crate::app_tools::launch_params::LaunchPort;
 use crate::app_tools::launch_params::SearchOrder;
 use crate::app_tools::process;
+use crate::app_tools::registry;
+use crate::app_tools::session;
 use crate::app_tools::targets;
 use crate::app_tools::targets::AvailableTarget;
 use crate::app_tools::targets::BevyTarget;
@@ -30,16 +37,17 @@ use crate::error::Result;
 fn prepare_launch_environment<T: config::LaunchConfigTrait>(
     config: &T,
     target: &BevyTarget,
+    port: Port,
 ) -> Result<(Command, PathBuf, PathBuf, File)> {
     let manifest_dir = build::validate_manifest_directory(&target.manifest)?;
-    let command = config.build_command(target);
+    let command = config.build_command(target, port);
     let (log_file_path, log_file_for_redirect) = build::setup_launch_logging(
         config.target(),
         T::TARGET_TYPE,
         config.profile(),
         &PathBuf::from(format!("{command:?}")),
         manifest_dir,
-        config.port(),
+        port,
         config.extra_log_info(target).as_deref(),
     )?;
 
@@ -62,30 +70,72 @@ fn validate_port_range(base_port: u16, instance_count: u16) -> Result<()> {
     Ok(())
 }
 
+/// Ports to launch `instance_count` instances on
+///
+/// A fixed port is the first of a consecutive range. `"auto"` takes the first ports after the
+/// default port that no process listens on and no registered app holds, so an app launched moments
+/// ago that has not opened its port yet is not handed out twice.
+fn resolve_ports(requested: LaunchPort, instance_count: u16) -> Result<Vec<Port>> {
+    let base_port = match requested {
+        LaunchPort::Fixed(port) => port.0,
+        LaunchPort::Auto => return allocate_free_ports(instance_count),
+    };
+    validate_port_range(base_port, instance_count)?;
+    Ok((0..instance_count)
+        .map(|i| Port(base_port.saturating_add(i)))
+        .collect())
+}
+
+fn allocate_free_ports(instance_count: u16) -> Result<Vec<Port>> {
+    let registered = registry::registered_ports();
+    let ports: Vec<Port> = (Port::default().0.saturating_add(1)..=MAX_VALID_PORT)
+        .map(Port)
+        .filter(|port| !registered.contains(port) && port.is_available())
+        .take(usize::from(instance_count))
+        .collect();
+
+    if ports.len() < usize::from(instance_count) {
+        return Err(Error::tool_call_failed(format!(
+            "Found only {} free port(s) above {} for {instance_count} instance(s)",
+            ports.len(),
+            Port::default()
+        ))
+        .into());
+    }
+    Ok(ports)
+}
+
 fn launch_instances<T: config::LaunchConfigTrait>(
     config: &T,
     target: &BevyTarget,
-    instance_count: u16,
-    base_port: u16,
+    ports: &[Port],
 ) -> Result<(Vec<u32>, Vec<PathBuf>, Vec<u16>)> {
     let mut all_pids = Vec::new();
     let mut all_log_files = Vec::new();
     let mut all_ports = Vec::new();
 
-    for i in 0..instance_count {
-        let port = Port(base_port.saturating_add(i));
-        let mut instance_config = config.clone();
-        instance_config.set_port(port);
-
+    for &port in ports {
+        debug!("Environment variable: BRP_EXTRAS_PORT={port}");
         let (command, manifest_dir, log_file_path, log_file_for_redirect) =
-            prepare_launch_environment(&instance_config, target)?;
+            prepare_launch_environment(config, target, port)?;
 
         let process_id = process::launch_detached_process(
             &command,
             &manifest_dir,
             log_file_for_redirect,
+            &log_file_path,
             config.target(),
+            port,
+            config.restart(),
         )?;
+        session::record_launch(process_id, config.target(), port, &log_file_path);
+        registry::register_launch(
+            process_id,
+            config.target(),
+            port,
+            log_file_path.display().to_string(),
+        );
+        port.forget_owner();
 
         all_pids.push(process_id);
         all_log_files.push(log_file_path);
@@ -108,7 +158,7 @@ fn handle_target_discovery_error(error: Report<Error>) -> Report<Error> {
     Error::tool_call_failed_with_details(error_message, details).into()
 }
 
-pub fn launch_bevy_target(
+pub async fn launch_bevy_target(
     typed_params: LaunchBevyBinaryParams,
     default_profile: &'static str,
 ) -> Result<LaunchResult> {
@@ -129,7 +179,7 @@ pub fn launch_bevy_target(
         first_targets = targets::filter_targets_by_path_scope(first_targets, scope);
     }
     if !first_targets.is_empty() {
-        return launch_found_target(first, first_targets, &params, &search_roots);
+        return launch_found_target(first, first_targets, &params, &search_roots).await;
     }
 
     let mut second_targets =
@@ -138,7 +188,7 @@ pub fn launch_bevy_target(
         second_targets = targets::filter_targets_by_path_scope(second_targets, scope);
     }
     if !second_targets.is_empty() {
-        return launch_found_target(second, second_targets, &params, &search_roots);
+        return launch_found_target(second, second_targets, &params, &search_roots).await;
     }
 
     let mut all_targets = targets::scan_bevy_targets(&search_roots);
@@ -161,57 +211,85 @@ pub fn launch_bevy_target(
     .into())
 }
 
-fn launch_found_target(
+async fn launch_found_target(
     target_type: TargetType,
     cached_targets: Vec<BevyTarget>,
     params: &LaunchParams,
     roots: &[PathBuf],
 ) -> Result<LaunchResult> {
+    let verify_timeout = params
+        .verify_brp
+        .then(|| params.verify_timeout.unwrap_or(LAUNCH_VERIFY_TIMEOUT));
     match target_type {
         TargetType::App => {
             let config = config::LaunchConfig::<config::App>::from(params);
-            launch_target_with_cached(&config, roots, cached_targets)
+            launch_target_with_cached(&config, roots, cached_targets, verify_timeout).await
         },
         TargetType::Example => {
             let config = config::LaunchConfig::<config::Example>::from(params);
-            launch_target_with_cached(&config, roots, cached_targets)
+            launch_target_with_cached(&config, roots, cached_targets, verify_timeout).await
         },
     }
 }
 
-fn launch_target_with_cached<T: config::LaunchConfigTrait>(
+async fn launch_target_with_cached<T: config::LaunchConfigTrait + Sync>(
     config: &T,
     search_paths: &[PathBuf],
     cached_targets: Vec<BevyTarget>,
+    verify_timeout: Option<Duration>,
 ) -> Result<LaunchResult> {
     let launch_start = Instant::now();
 
-    debug!("Environment variable: BRP_EXTRAS_PORT={}", config.port());
-
     let target = find_and_validate_target_with_cache(config, search_paths, cached_targets)
         .map_err(handle_target_discovery_error)?;
 
-    let build_state = config.ensure_built(&target)?;
-    match build_state {
+    let build = config.ensure_built(&target)?;
+    match build.state {
         BuildState::Fresh => debug!("Target was already up to date, launching immediately"),
         BuildState::Rebuilt => debug!("Target was rebuilt before launch"),
         BuildState::NotFound => {
             warn!("Target not found in build output but build succeeded");
         },
+        BuildState::Skipped => debug!("Launching without building first"),
     }
 
-    let instance_count = *config.instance_count();
-    let base_port = *config.port();
-
-    validate_port_range(base_port, instance_count)?;
+    let ports = resolve_ports(config.port(), *config.instance_count())?;
+    let (all_pids, all_log_files, all_ports) = launch_instances(config, &target, &ports)?;
 
-    let (all_pids, all_log_files, all_ports) =
-        launch_instances(config, &target, instance_count, base_port)?;
+    let (verifications, brp_setup_hints) = if let Some(timeout) = verify_timeout {
+        let verifications = join_all(
+            all_pids
+                .iter()
+                .zip(&all_ports)
+                .map(|(pid, port)| verification::verify_instance(*pid, Port(*port), timeout)),
+        )
+        .await;
+        // Instances share one binary, so the first one needing setup speaks for all of them
+        let hints = verifications
+            .iter()
+            .zip(all_ports.iter().zip(&all_log_files))
+            .find(|(verification, _)| verification.needs_setup())
+            .map(|(verification, (port, log_file))| {
+                verification::setup_hints(
+                    &target,
+                    *verification,
+                    Port(*port),
+                    &log_file.display().to_string(),
+                    timeout,
+                )
+            });
+        (Some(verifications), hints)
+    } else {
+        (None, None)
+    };
 
     Ok(config::build_launch_result(
         all_pids,
         all_log_files,
         all_ports,
+        verifications,
+        brp_setup_hints,
+        build,
         config,
         &target,
         launch_start,
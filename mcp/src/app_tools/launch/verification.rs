@@ -0,0 +1,264 @@
+//! Post-launch check that a launched app actually serves BRP
+//!
+//! A launch only proves the process started. Each instance is polled with `rpc.discover` until it
+//! answers, exits, or the verification timeout passes. When BRP never answers, or answers without
+//! the `bevy_brp_extras` methods, the package manifest and sources are scanned to say exactly what
+//! is missing and where to add it.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sysinfo::Pid;
+use sysinfo::ProcessesToUpdate;
+use sysinfo::System;
+
+use super::constants::BRP_EXTRAS_CRATE_NAME;
+use super::constants::BRP_EXTRAS_METHOD_PREFIX;
+use super::constants::BRP_EXTRAS_PLUGIN_NAME;
+use super::constants::REMOTE_HTTP_PLUGIN_NAME;
+use super::constants::REMOTE_PLUGIN_NAME;
+use crate::app_tools::constants::STATUS_POLL_INTERVAL;
+use crate::app_tools::targets::BevyTarget;
+use crate::brp_tools;
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::tool::BrpMethod;
+
+/// BRP status of one launched instance
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BrpVerification {
+    /// Whether `rpc.discover` answered within the verification timeout
+    pub responding: bool,
+    /// Milliseconds from the start of the check until BRP answered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_ms:   Option<u64>,
+    /// Whether `bevy_brp_extras` methods are registered
+    pub extras:     bool,
+    /// Whether the process exited before BRP answered
+    pub exited:     bool,
+}
+
+impl BrpVerification {
+    /// Whether the instance needs setup changes to be fully usable from the MCP tools
+    pub(super) const fn needs_setup(self) -> bool { !self.responding || !self.extras }
+}
+
+/// Poll one instance until BRP answers, the process exits, or the timeout passes
+pub(super) async fn verify_instance(pid: u32, port: Port, timeout: Duration) -> BrpVerification {
+    let start = Instant::now();
+    let deadline = start + timeout;
+    let mut system = System::new();
+    // Whatever answered on this port before may have been a different build
+    brp_tools::forget_extras_capabilities(port);
+    brp_tools::forget_type_guides(port);
+    brp_tools::forget_format_corrections(port);
+    brp_tools::forget_undo_history(port);
+
+    loop {
+        let client = BrpClient::new(BrpMethod::RpcDiscover, port, None);
+        if let Ok(ResponseStatus::Success(discovery)) = client.execute_raw().await {
+            let ready_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+            let extras = discovery.as_ref().is_some_and(has_extras_methods);
+            if extras {
+                brp_tools::refresh_extras_capabilities(port).await;
+            }
+            return BrpVerification {
+                responding: true,
+                ready_ms: Some(ready_ms),
+                extras,
+                exited: false,
+            };
+        }
+
+        system.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+        let exited = system.process(Pid::from_u32(pid)).is_none();
+        if exited || Instant::now() >= deadline {
+            return BrpVerification {
+                responding: false,
+                ready_ms: None,
+                extras: false,
+                exited,
+            };
+        }
+        tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+    }
+}
+
+/// Whether an `rpc.discover` response lists any `brp_extras/` method
+pub(crate) fn has_extras_methods(discovery: &Value) -> bool {
+    discovery
+        .get("methods")
+        .and_then(Value::as_array)
+        .is_some_and(|methods| {
+            methods.iter().any(|method| {
+                method
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .is_some_and(|name| name.starts_with(BRP_EXTRAS_METHOD_PREFIX))
+            })
+        })
+}
+
+/// Where a plugin is referenced in the package sources
+struct PluginReference {
+    file: PathBuf,
+    line: usize,
+}
+
+impl PluginReference {
+    fn location(&self) -> String { format!("{}:{}", self.file.display(), self.line) }
+}
+
+/// What the package manifest and sources say about BRP setup
+struct ProjectScan {
+    extras_dependency: bool,
+    extras_plugin:     Option<PluginReference>,
+    remote_plugin:     Option<PluginReference>,
+    remote_http:       Option<PluginReference>,
+}
+
+impl ProjectScan {
+    fn scan(target: &BevyTarget) -> Self {
+        let extras_dependency = fs::read_to_string(&target.manifest)
+            .is_ok_and(|manifest| manifest.contains(BRP_EXTRAS_CRATE_NAME));
+
+        let mut sources = vec![target.source.clone()];
+        if let Some(package_dir) = target.manifest.parent() {
+            collect_rust_files(&package_dir.join("src"), &mut sources);
+        }
+        sources.dedup();
+
+        let find = |needle: &str| {
+            sources.iter().find_map(|file| {
+                fs::read_to_string(file).ok().and_then(|contents| {
+                    contents
+                        .lines()
+                        .position(|line| line.contains(needle))
+                        .map(|index| PluginReference {
+                            file: file.clone(),
+                            line: index + 1,
+                        })
+                })
+            })
+        };
+
+        Self {
+            extras_dependency,
+            extras_plugin: find(BRP_EXTRAS_PLUGIN_NAME),
+            remote_plugin: find(REMOTE_PLUGIN_NAME),
+            remote_http: find(REMOTE_HTTP_PLUGIN_NAME),
+        }
+    }
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries
+        .filter_map(std::result::Result::ok)
+        .map(|e| e.path())
+    {
+        if path.is_dir() {
+            collect_rust_files(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Actionable setup hints for instances that need setup changes
+pub(super) fn setup_hints(
+    target: &BevyTarget,
+    verification: BrpVerification,
+    port: Port,
+    log_file: &str,
+    timeout: Duration,
+) -> Vec<String> {
+    if verification.exited {
+        return vec![format!(
+            "The app exited before BRP answered on port {port}. Check the launch log: {log_file}"
+        )];
+    }
+
+    let scan = ProjectScan::scan(target);
+    let manifest = target.manifest.display();
+    let source = target.source.display();
+    let add_dependency =
+        format!("Add `{BRP_EXTRAS_CRATE_NAME}` under [dependencies] in {manifest}");
+    let add_plugin = format!(
+        "Add `.add_plugins({BRP_EXTRAS_PLUGIN_NAME})` to the App in {source} (it adds \
+         {REMOTE_PLUGIN_NAME} and {REMOTE_HTTP_PLUGIN_NAME} if they are missing)"
+    );
+
+    if verification.responding {
+        // BRP works, only the extras methods are missing
+        let mut hints = vec![format!(
+            "BRP responds on port {port} but {BRP_EXTRAS_CRATE_NAME} is not registered, so \
+             brp_extras_* tools (screenshots, input, shutdown) will fail"
+        )];
+        if !scan.extras_dependency {
+            hints.push(add_dependency);
+        }
+        hints.push(add_plugin);
+        return hints;
+    }
+
+    match (&scan.extras_plugin, &scan.remote_plugin, &scan.remote_http) {
+        (Some(reference), ..) => vec![format!(
+            "{BRP_EXTRAS_PLUGIN_NAME} is added at {} but nothing answered on port {port} within \
+             {}s. The app may still be building or starting (raise `timeout_ms` to wait longer); \
+             otherwise make sure that code path runs and that a fixed port (for example \
+             `{BRP_EXTRAS_PLUGIN_NAME}::with_port`) does not override the launch port",
+            reference.location(),
+            timeout.as_secs_f64()
+        )],
+        (None, Some(reference), None) => vec![format!(
+            "{REMOTE_PLUGIN_NAME} is added at {} but {REMOTE_HTTP_PLUGIN_NAME} is not, so BRP is \
+             not served over HTTP. Add {REMOTE_HTTP_PLUGIN_NAME}, or replace both with \
+             {BRP_EXTRAS_PLUGIN_NAME}",
+            reference.location()
+        )],
+        (None, Some(_), Some(reference)) => vec![format!(
+            "{REMOTE_HTTP_PLUGIN_NAME} is added at {} but nothing answered on port {port}. Make \
+             sure it listens on the launch port (the launch sets BRP_EXTRAS_PORT={port})",
+            reference.location()
+        )],
+        (None, None, _) => {
+            let mut hints = vec![format!(
+                "No {REMOTE_PLUGIN_NAME} or {BRP_EXTRAS_PLUGIN_NAME} found in the sources of \
+                 package '{}'",
+                target.package_name
+            )];
+            if !scan.extras_dependency {
+                hints.push(add_dependency);
+            }
+            hints.push(add_plugin);
+            hints
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::has_extras_methods;
+
+    #[test]
+    fn extras_are_detected_from_discovered_methods() {
+        let standard = json!({ "methods": [{ "name": "world.query" }] });
+        let extras =
+            json!({ "methods": [{ "name": "world.query" }, { "name": "brp_extras/screenshot" }] });
+
+        assert!(!has_extras_methods(&standard));
+        assert!(has_extras_methods(&extras));
+    }
+}
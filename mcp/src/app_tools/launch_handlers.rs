@@ -25,7 +25,7 @@ impl ToolFn for LaunchBevyTarget {
         context: HandlerContext,
     ) -> HandlerResult<'_, ToolResult<Self::Output, Self::Params>> {
         tool::call_with_typed_params(context, |_, params: LaunchBevyBinaryParams| async move {
-            launch::launch_bevy_target(params, DEFAULT_PROFILE)
+            launch::launch_bevy_target(params, DEFAULT_PROFILE).await
         })
     }
 
@@ -1,10 +1,16 @@
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
 
 use bevy_brp_mcp_macros::ParamStruct;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
+use super::constants::AUTO_PORT;
+use super::constants::DEFAULT_RESTART_MAX_RETRIES;
 use super::instance_count::InstanceCount;
 use super::launch::LaunchParams;
 use crate::brp_tools::Port;
@@ -21,6 +27,102 @@ pub enum SearchOrder {
     Example,
 }
 
+/// Port requested for a launch: a fixed base port, or `"auto"` to pick free ports
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Value", into = "Value")]
+pub enum LaunchPort {
+    /// Launch the first instance on this port and further instances on the ports after it
+    Fixed(Port),
+    /// Give each instance a port no other process is listening on
+    Auto,
+}
+
+impl Default for LaunchPort {
+    fn default() -> Self { Self::Fixed(Port::default()) }
+}
+
+impl TryFrom<Value> for LaunchPort {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(text) if text == AUTO_PORT => Ok(Self::Auto),
+            Value::Number(number) => number
+                .as_u64()
+                .and_then(|port| u16::try_from(port).ok())
+                .ok_or_else(|| format!("Invalid port {number}"))
+                .and_then(Port::try_from)
+                .map(Self::Fixed),
+            other => Err(format!(
+                "Invalid port {other}: must be a port number or \"{AUTO_PORT}\""
+            )),
+        }
+    }
+}
+
+impl From<LaunchPort> for Value {
+    fn from(port: LaunchPort) -> Self {
+        match port {
+            LaunchPort::Fixed(port) => Self::from(port.0),
+            LaunchPort::Auto => Self::from(AUTO_PORT),
+        }
+    }
+}
+
+impl Display for LaunchPort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(port) => port.fmt(f),
+            Self::Auto => f.write_str(AUTO_PORT),
+        }
+    }
+}
+
+/// When `brp_launch` runs `cargo build` before launching
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildMode {
+    /// Build examples, and apps whose binary looks stale or that are launched with `features`
+    #[default]
+    Auto,
+    /// Always build first, so compiler errors are reported instead of launching an old binary
+    Always,
+    /// Launch the existing binary without building; examples are still run through `cargo run`
+    Never,
+}
+
+/// When a launched app is relaunched after it exits on its own
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartMode {
+    /// Leave crashed apps stopped
+    #[default]
+    Never,
+    /// Relaunch the app when it exits with an error code or is killed by a signal, but not when
+    /// it exits cleanly or is stopped with `brp_shutdown`
+    OnCrash,
+}
+
+/// Restart policy for launched apps, e.g. `{"policy": "on-crash", "max_retries": 3}`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RestartPolicy {
+    /// "on-crash" to relaunch crashed apps, or "never" (default)
+    #[serde(default)]
+    pub policy:      RestartMode,
+    /// Relaunches allowed per instance before a crashed app is left stopped (default: 3)
+    #[serde(default = "default_restart_max_retries")]
+    pub max_retries: u32,
+}
+
+impl RestartPolicy {
+    /// Whether restart number `restart`, counting from 1, is allowed
+    pub(super) fn allows(self, restart: u32) -> bool {
+        self.policy == RestartMode::OnCrash && restart <= self.max_retries
+    }
+}
+
+const fn default_restart_max_retries() -> u32 { DEFAULT_RESTART_MAX_RETRIES }
+
 /// Shared parameters for launching Bevy binaries (apps or examples)
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct LaunchBevyBinaryParams {
@@ -40,9 +142,11 @@ pub struct LaunchBevyBinaryParams {
     #[serde(rename = "package_name")]
     #[to_metadata(skip_if_none)]
     pub package:        Option<String>,
-    /// The BRP port (default: 15702)
+    /// The BRP port (default: 15702), or "auto" to launch on free ports. The chosen ports are
+    /// returned with each instance.
     #[serde(default)]
-    pub port:           Port,
+    #[schemars(with = "Value")]
+    pub port:           LaunchPort,
     /// Number of instances to launch (default: 1)
     #[serde(default)]
     pub instance_count: InstanceCount,
@@ -57,6 +161,28 @@ pub struct LaunchBevyBinaryParams {
     #[serde(default)]
     #[to_metadata(skip_if_none)]
     pub args:           Option<Vec<String>>,
+    /// Wait for each instance to answer BRP after launch and report where to enable it if not
+    /// (default: true)
+    #[serde(default, alias = "wait_for_brp")]
+    #[to_metadata(skip_if_none)]
+    pub verify_brp:     Option<bool>,
+    /// How long to wait for each instance to answer BRP, in milliseconds (default: 10000)
+    #[serde(default)]
+    #[to_metadata(skip_if_none)]
+    pub timeout_ms:     Option<u64>,
+    /// When to run `cargo build` first: "auto" (default), "always", or "never". Compiler errors
+    /// from the build are returned in the error.
+    #[serde(default)]
+    pub build:          BuildMode,
+    /// Cargo features to build the target with, and to run examples with
+    #[serde(default)]
+    #[to_metadata(skip_if_none)]
+    pub features:       Option<Vec<String>>,
+    /// Relaunch instances that crash with the same arguments, e.g. `{"policy": "on-crash",
+    /// "max_retries": 3}`. The next tool call on a restarted instance's port reports the restart.
+    #[serde(default)]
+    #[to_metadata(skip_if_none)]
+    pub restart:        Option<RestartPolicy>,
 }
 
 impl LaunchBevyBinaryParams {
@@ -75,6 +201,37 @@ impl LaunchBevyBinaryParams {
             env:            self.env.clone(),
             search_order:   self.search_order.clone(),
             args:           self.args.clone(),
+            verify_brp:     self.verify_brp.unwrap_or(true),
+            verify_timeout: self.timeout_ms.map(Duration::from_millis),
+            build:          self.build,
+            features:       self.features.clone(),
+            restart:        self.restart,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::LaunchPort;
+    use crate::brp_tools::Port;
+
+    #[test]
+    fn port_accepts_a_number_or_auto() {
+        assert_eq!(
+            serde_json::from_value::<LaunchPort>(json!(15_710)).ok(),
+            Some(LaunchPort::Fixed(Port(15_710)))
+        );
+        assert_eq!(
+            serde_json::from_value::<LaunchPort>(json!("auto")).ok(),
+            Some(LaunchPort::Auto)
+        );
+        assert!(serde_json::from_value::<LaunchPort>(json!("15710")).is_err());
+        assert!(serde_json::from_value::<LaunchPort>(json!(80)).is_err());
+        assert_eq!(
+            serde_json::to_value(LaunchPort::Auto).ok(),
+            Some(json!("auto"))
+        );
+    }
+}
@@ -1,21 +1,43 @@
 // App tools module
 
+mod brp_get_crash_report;
 mod brp_list_bevy;
+mod brp_list_sessions;
+mod brp_run_scenario;
+mod brp_run_test_plan;
 mod brp_shutdown;
 mod brp_status;
 mod constants;
+mod crash;
 mod instance_count;
 mod launch;
 mod launch_handlers;
 mod launch_params;
 mod process;
+mod registry;
+mod scenario;
+mod session;
 mod targets;
+mod test_plan;
 
+pub use brp_get_crash_report::GetCrashReport;
+pub use brp_get_crash_report::GetCrashReportParams;
 pub use brp_list_bevy::ListBevy;
 pub use brp_list_bevy::ListBevyParams;
+pub use brp_list_sessions::ListSessions;
+pub use brp_list_sessions::ListSessionsParams;
+pub use brp_run_scenario::RunScenario;
+pub use brp_run_scenario::RunScenarioParams;
+pub use brp_run_test_plan::RunTestPlan;
+pub use brp_run_test_plan::RunTestPlanParams;
 pub use brp_shutdown::Shutdown;
 pub use brp_shutdown::ShutdownParams;
 pub use brp_status::Status;
 pub use brp_status::StatusParams;
+pub(crate) use crash::AppRestart;
+pub(crate) use crash::crash_report;
+pub(crate) use crash::take_restart_notice;
 pub use launch_handlers::create_launch_handler;
 pub use launch_params::LaunchBevyBinaryParams;
+pub(crate) use session::cleanup_orphaned_sessions;
+pub(crate) use session::end_session;
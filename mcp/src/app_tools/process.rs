@@ -1,31 +1,87 @@
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::fs::File;
+use std::io::Write;
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
 use std::path::Path;
+use std::path::PathBuf;
 use std::process::Command;
 use std::process::Stdio;
 
 use error_stack::Report;
 use error_stack::ResultExt;
-use netstat2::AddressFamilyFlags;
-use netstat2::ProtocolFlags;
-use netstat2::ProtocolSocketInfo;
+use serde::Deserialize;
+use serde::Serialize;
+use sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
+use sysinfo::Pid;
 use sysinfo::Process;
+use sysinfo::ProcessRefreshKind;
+use sysinfo::ProcessesToUpdate;
+use sysinfo::System;
 
 use super::constants::APP_EXTENSION_SUFFIX;
 use super::constants::BIN_EXTENSION_SUFFIX;
 use super::constants::EXE_EXTENSION_SUFFIX;
+use super::crash;
+use super::crash::AppRestart;
+use super::crash::CrashReport;
+use super::launch_params::RestartPolicy;
+use super::registry;
+use super::session;
 use crate::brp_tools::Port;
 use crate::error::Error;
 use crate::error::Result;
 
+/// Everything needed to start a launched app again with the same arguments
+#[derive(Clone)]
+struct DetachedLaunch {
+    program:      OsString,
+    args:         Vec<OsString>,
+    envs:         Vec<(OsString, OsString)>,
+    working_dir:  PathBuf,
+    log_path:     PathBuf,
+    process_name: String,
+    port:         Port,
+    restart:      Option<RestartPolicy>,
+}
+
 /// Launch a detached process with proper setup
+///
+/// The process is tracked for crash reports on `port` from the moment it is spawned, and
+/// relaunched when it crashes if `restart` asks for it.
 pub(super) fn launch_detached_process(
     command: &Command,
     working_dir: &Path,
     log_file: File,
+    log_path: &Path,
     process_name: &str,
+    port: Port,
+    restart: Option<RestartPolicy>,
 ) -> Result<u32> {
+    let launch = DetachedLaunch {
+        program: command.get_program().to_os_string(),
+        args: command.get_args().map(OsStr::to_os_string).collect(),
+        envs: command
+            .get_envs()
+            .filter_map(|(key, value)| Some((key.to_os_string(), value?.to_os_string())))
+            .collect(),
+        working_dir: working_dir.to_path_buf(),
+        log_path: log_path.to_path_buf(),
+        process_name: process_name.to_string(),
+        port,
+        restart,
+    };
+    spawn_supervised(launch, log_file, 0)
+}
+
+/// Spawn the app and a thread that reaps it, records unexpected exits, and applies the restart
+/// policy; `restarts` counts the relaunches before this one
+fn spawn_supervised(launch: DetachedLaunch, log_file: File, restarts: u32) -> Result<u32> {
+    let process_name = &launch.process_name;
+    let working_dir = &launch.working_dir;
+
     // Clone the log file handle for stderr
     let log_file_for_stderr = log_file
         .try_clone()
@@ -34,13 +90,8 @@ pub(super) fn launch_detached_process(
         ))
         .attach(format!("Process: {process_name}, Operation: launch"))?;
 
-    // Create a new command from the provided one
-    let mut new_command = std::process::Command::new(command.get_program());
-
-    // Copy args
-    for arg in command.get_args() {
-        new_command.arg(arg);
-    }
+    let mut new_command = Command::new(&launch.program);
+    new_command.args(&launch.args);
 
     // Set working directory and CARGO_MANIFEST_DIR
     new_command
@@ -48,11 +99,7 @@ pub(super) fn launch_detached_process(
         .env("CARGO_MANIFEST_DIR", working_dir);
 
     // Copy other environment variables
-    for (key, value) in command.get_envs() {
-        if let Some(value) = value {
-            new_command.env(key, value);
-        }
-    }
+    new_command.envs(launch.envs.iter().map(|(key, value)| (key, value)));
 
     // Set stdio
     new_command
@@ -75,12 +122,32 @@ pub(super) fn launch_detached_process(
             let process_id = child.id();
 
             tracing::debug!("Process spawned successfully: {process_name} (PID: {process_id})");
+            if restarts == 0 {
+                crash::track_launch(process_id, launch.port);
+            } else {
+                crash::track_restart(process_id, launch.port);
+            }
 
             // Spawn a background thread to reap the child when it exits
-            // This prevents zombie processes
+            // This prevents zombie processes and records unexpected exits
             std::thread::spawn(move || match child.wait() {
                 Ok(status) => {
                     tracing::debug!("Child process {process_id} exited with status: {status:?}");
+                    let crash_report = crash::record_exit(
+                        process_id,
+                        &launch.process_name,
+                        launch.port,
+                        &launch.log_path,
+                        status,
+                    );
+                    if let Some(crash_report) = crash_report
+                        && !status.success()
+                        && launch
+                            .restart
+                            .is_some_and(|restart| restart.allows(restarts + 1))
+                    {
+                        restart_after_crash(launch, &crash_report, restarts + 1);
+                    }
                 },
                 Err(e) => {
                     tracing::warn!("Failed to wait for child process {process_id}: {e}");
@@ -101,6 +168,60 @@ pub(super) fn launch_detached_process(
     }
 }
 
+/// Relaunch a crashed app with the same arguments, appending to its log
+fn restart_after_crash(launch: DetachedLaunch, crash_report: &CrashReport, restart: u32) {
+    let max_retries = launch.restart.map_or(0, |policy| policy.max_retries);
+    let (process_name, port, log_path) = (
+        launch.process_name.clone(),
+        launch.port,
+        launch.log_path.clone(),
+    );
+    let log_file = File::options().append(true).open(&log_path).and_then(|mut file| {
+        writeln!(
+            file,
+            "\n=== Restarted by bevy_brp_mcp ({restart}/{max_retries}): previous process {} ===\n",
+            crash_report.exit
+        )?;
+        Ok(file)
+    });
+    let spawned = log_file
+        .change_context(Error::LogOperation(
+            "Failed to open log file for restart".to_string(),
+        ))
+        .and_then(|log_file| spawn_supervised(launch, log_file, restart));
+
+    match spawned {
+        Ok(process_id) => {
+            tracing::warn!(
+                "Restarted '{process_name}' on port {port} after it {} (PID {process_id}, \
+                 restart {restart}/{max_retries})",
+                crash_report.exit
+            );
+            session::record_launch(process_id, &process_name, port, &log_path);
+            registry::register_launch(
+                process_id,
+                &process_name,
+                port,
+                log_path.display().to_string(),
+            );
+            // The restart notice covers the new process, so it is not also reported as a change
+            port.forget_owner();
+            crash::record_restart(
+                port,
+                AppRestart {
+                    app_name: process_name,
+                    previous_pid: crash_report.pid,
+                    pid: process_id,
+                    restart,
+                    max_retries,
+                    previous_exit: crash_report.exit.clone(),
+                },
+            );
+        },
+        Err(e) => tracing::error!("Failed to restart '{process_name}' on port {port}: {e:?}"),
+    }
+}
+
 /// Normalize a process name or binary path for robust matching.
 ///
 /// Strips directory paths, removes common executable extensions (.exe, .app, .bin),
@@ -140,20 +261,34 @@ pub fn process_matches_name_exact(process: &Process, target: &str) -> bool {
     normalize_process_name(&process_name) == normalized_target
 }
 
-/// Get the PID for a process listening on the specified port
-pub fn get_pid_for_port(port: Port) -> Option<u32> {
-    let address_family_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
-    let protocol_flags = ProtocolFlags::TCP;
-
-    netstat2::get_sockets_info(address_family_flags, protocol_flags)
-        .ok()?
-        .into_iter()
-        .find_map(|socket_info| {
-            if let ProtocolSocketInfo::Tcp(tcp_socket_info) = socket_info.protocol_socket_info
-                && tcp_socket_info.local_port == *port
-            {
-                return socket_info.associated_pids.first().copied();
-            }
-            None
-        })
+/// CPU, memory, and thread usage of a running process, read from the OS rather than BRP
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProcessMetrics {
+    /// CPU usage over the sample window, in percent of one core
+    pub cpu_percent:  f32,
+    /// Resident memory in bytes
+    pub rss_bytes:    u64,
+    /// Threads (tasks) the OS reports for the process; omitted where sysinfo cannot list them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_count: Option<usize>,
+}
+
+/// Sample a process's metrics from two refreshes one CPU measurement interval apart
+pub(super) async fn sample_process_metrics(pid: u32) -> Option<ProcessMetrics> {
+    let pid = Pid::from_u32(pid);
+    let refresh_kind = ProcessRefreshKind::nothing()
+        .with_cpu()
+        .with_memory()
+        .with_tasks();
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    tokio::time::sleep(MINIMUM_CPU_UPDATE_INTERVAL).await;
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+
+    let process = system.process(pid)?;
+    Some(ProcessMetrics {
+        cpu_percent:  (process.cpu_usage() * 10.0).round() / 10.0,
+        rss_bytes:    process.memory(),
+        thread_count: process.tasks().map(HashSet::len),
+    })
 }
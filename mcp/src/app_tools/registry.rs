@@ -0,0 +1,166 @@
+//! Registry of the apps this server launched or probed
+//!
+//! Tools address apps by port, so after a few launches an agent easily loses track of which port
+//! runs which app. Every launch and every successful `brp_status` records the app here, keyed by
+//! port; `brp_shutdown` removes it. `brp_list_sessions` reports the registry with each app's live
+//! status. The registry lives only as long as this server process.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sysinfo::Pid;
+use sysinfo::ProcessesToUpdate;
+use sysinfo::System;
+
+use super::process;
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::tool::BrpMethod;
+
+/// Registered apps keyed by BRP port
+static APP_REGISTRY: LazyLock<Mutex<HashMap<Port, RegisteredApp>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// How an app came to be registered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AppOrigin {
+    /// Started by `brp_launch`
+    Launched,
+    /// Found running by `brp_status`
+    Probed,
+}
+
+/// Live state of a registered app, in the same terms `brp_status` uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AppStatus {
+    RunningWithBrp,
+    RunningNoBrp,
+    NotRunning,
+}
+
+/// One app known to this server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RegisteredApp {
+    pub(crate) app_name:      String,
+    pub(crate) port:          Port,
+    pub(crate) pid:           u32,
+    pub(crate) origin:        AppOrigin,
+    /// Log file written by a launched app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) log_file:      Option<String>,
+    /// When the app was launched or last probed (RFC 3339)
+    pub(crate) registered_at: String,
+}
+
+/// A registered app with its status checked now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AppSession {
+    #[serde(flatten)]
+    pub(crate) app:    RegisteredApp,
+    pub(crate) status: AppStatus,
+}
+
+/// Record an app launched on `port`, replacing whatever was registered there
+pub(super) fn register_launch(pid: u32, app_name: &str, port: Port, log_file: String) {
+    register(RegisteredApp {
+        app_name: app_name.to_string(),
+        port,
+        pid,
+        origin: AppOrigin::Launched,
+        log_file: Some(log_file),
+        registered_at: chrono::Local::now().to_rfc3339(),
+    });
+}
+
+/// Record an app `brp_status` found running on `port`
+///
+/// A launched app keeps its launch record when it is probed again under the same PID.
+pub(super) fn register_probe(pid: u32, app_name: &str, port: Port) {
+    let Ok(mut registry) = APP_REGISTRY.lock() else {
+        return;
+    };
+    if registry
+        .get(&port)
+        .is_some_and(|app| app.pid == pid && app.origin == AppOrigin::Launched)
+    {
+        return;
+    }
+    registry.insert(
+        port,
+        RegisteredApp {
+            app_name: app_name.to_string(),
+            port,
+            pid,
+            origin: AppOrigin::Probed,
+            log_file: None,
+            registered_at: chrono::Local::now().to_rfc3339(),
+        },
+    );
+}
+
+/// Forget the app on `port`, e.g. after it was shut down
+pub(super) fn unregister(port: Port) {
+    if let Ok(mut registry) = APP_REGISTRY.lock() {
+        registry.remove(&port);
+    }
+}
+
+/// Ports of every registered app, running or not
+pub(super) fn registered_ports() -> Vec<Port> {
+    APP_REGISTRY
+        .lock()
+        .map(|registry| registry.keys().copied().collect())
+        .unwrap_or_default()
+}
+
+fn register(app: RegisteredApp) {
+    if let Ok(mut registry) = APP_REGISTRY.lock() {
+        registry.insert(app.port, app);
+    }
+}
+
+/// Every registered app, sorted by port, with its current status
+///
+/// With `prune`, apps found not running are dropped from the registry after being reported.
+pub(super) async fn list_sessions(prune: bool) -> Vec<AppSession> {
+    let mut apps: Vec<RegisteredApp> = APP_REGISTRY
+        .lock()
+        .map(|registry| registry.values().cloned().collect())
+        .unwrap_or_default();
+    apps.sort_by_key(|app| app.port.0);
+
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut sessions = Vec::with_capacity(apps.len());
+    for app in apps {
+        let status = app_status(&system, &app).await;
+        if prune && status == AppStatus::NotRunning {
+            unregister(app.port);
+        }
+        sessions.push(AppSession { app, status });
+    }
+    sessions
+}
+
+async fn app_status(system: &System, app: &RegisteredApp) -> AppStatus {
+    let running = system
+        .process(Pid::from_u32(app.pid))
+        .is_some_and(|process| process::process_matches_name_exact(process, &app.app_name));
+    if !running {
+        return AppStatus::NotRunning;
+    }
+
+    // One attempt only: a registered app has had time to start, and listing should stay fast
+    let client = BrpClient::new(BrpMethod::RpcDiscover, app.port, None);
+    match client.execute_raw().await {
+        Ok(ResponseStatus::Success(_)) => AppStatus::RunningWithBrp,
+        Ok(ResponseStatus::Error(_)) | Err(_) => AppStatus::RunningNoBrp,
+    }
+}
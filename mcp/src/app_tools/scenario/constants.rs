@@ -0,0 +1,11 @@
+// scenario defaults
+/// Default timeout for `wait_until` steps
+pub(super) const DEFAULT_WAIT_UNTIL_TIMEOUT_MS: u64 = 5_000;
+/// Default delay between `wait_until` polls
+pub(super) const DEFAULT_POLL_INTERVAL_MS: u64 = 100;
+
+// report
+/// Scenario name used when the file has no name and no stem
+pub(super) const DEFAULT_SCENARIO_NAME: &str = "scenario";
+/// Skip reason for steps after a failure
+pub(super) const SKIPPED_AFTER_FAILURE_MESSAGE: &str = "an earlier step failed";
@@ -0,0 +1,150 @@
+//! Scenario file format: a sequence of MCP tool calls with assertions and waits.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::de;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::constants::DEFAULT_POLL_INTERVAL_MS;
+use super::constants::DEFAULT_SCENARIO_NAME;
+use super::constants::DEFAULT_WAIT_UNTIL_TIMEOUT_MS;
+use crate::brp_tools::Expectation;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::ToolName;
+
+/// A scenario loaded from a JSON file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Scenario name used in the report. Defaults to the file stem.
+    #[serde(default)]
+    pub name:                Option<String>,
+    /// Keep running steps after one fails instead of skipping the rest (default: false).
+    #[serde(default)]
+    pub continue_on_failure: bool,
+    /// Steps run in order.
+    pub steps:               Vec<ScenarioStep>,
+}
+
+/// A single scenario step, tagged by `action`.
+#[derive(Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioStep {
+    /// Call an MCP tool. The step fails when the tool returns an error (or succeeds while
+    /// `expect_error` is set) or when an assertion does not hold.
+    Tool {
+        #[serde(default)]
+        name:         Option<String>,
+        #[serde(deserialize_with = "deserialize_tool")]
+        tool:         ToolName,
+        #[serde(default)]
+        args:         Map<String, Value>,
+        #[serde(default)]
+        assert:       Vec<Assertion>,
+        #[serde(default)]
+        expect_error: bool,
+    },
+    /// Call an MCP tool until it succeeds and every assertion holds.
+    WaitUntil {
+        #[serde(default)]
+        name:        Option<String>,
+        #[serde(deserialize_with = "deserialize_tool")]
+        tool:        ToolName,
+        #[serde(default)]
+        args:        Map<String, Value>,
+        #[serde(default)]
+        assert:      Vec<Assertion>,
+        #[serde(default = "default_wait_until_timeout_ms")]
+        timeout_ms:  u64,
+        #[serde(default = "default_poll_interval_ms")]
+        interval_ms: u64,
+    },
+    /// Sleep for a fixed duration, e.g. to let timed inputs release.
+    Wait {
+        #[serde(default)]
+        name:        Option<String>,
+        duration_ms: u64,
+    },
+}
+
+/// An expectation on one value in a tool's structured response.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Assertion {
+    /// JSON pointer into the response, e.g. `/result/0/entity` or `/metadata/count`. Omit it to
+    /// check the whole response.
+    #[serde(default)]
+    pub path:   Option<String>,
+    pub expect: Expectation,
+}
+
+impl Scenario {
+    /// Read and parse a scenario file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|error| {
+            Error::FileOrPathNotFound(format!(
+                "Failed to read scenario '{}': {error}",
+                path.display()
+            ))
+        })?;
+        serde_json::from_str(&contents).map_err(|error| {
+            Error::InvalidArgument(format!("Invalid scenario '{}': {error}", path.display())).into()
+        })
+    }
+
+    /// Scenario name, falling back to the file stem.
+    pub fn scenario_name(&self, path: &Path) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            path.file_stem().map_or_else(
+                || DEFAULT_SCENARIO_NAME.to_string(),
+                |stem| stem.to_string_lossy().to_string(),
+            )
+        })
+    }
+}
+
+impl ScenarioStep {
+    /// Step name for the report, falling back to the action and tool.
+    pub(super) fn label(&self) -> String {
+        match self {
+            Self::Tool { name, tool, .. } => name.clone().unwrap_or_else(|| tool.to_string()),
+            Self::WaitUntil { name, tool, .. } => {
+                name.clone().unwrap_or_else(|| format!("wait_until {tool}"))
+            },
+            Self::Wait { name, duration_ms } => name
+                .clone()
+                .unwrap_or_else(|| format!("wait {duration_ms}ms")),
+        }
+    }
+
+    /// The tool the step calls, if any.
+    pub(super) const fn tool(&self) -> Option<ToolName> {
+        match self {
+            Self::Tool { tool, .. } | Self::WaitUntil { tool, .. } => Some(*tool),
+            Self::Wait { .. } => None,
+        }
+    }
+}
+
+/// Parse a tool name, rejecting scenarios that would run other scenarios.
+fn deserialize_tool<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<ToolName, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    match ToolName::from_str(&name) {
+        Ok(ToolName::BrpRunScenario) => Err(de::Error::custom(
+            "a scenario step cannot call brp_run_scenario",
+        )),
+        Ok(tool) => Ok(tool),
+        Err(_) => Err(de::Error::custom(format!("unknown tool '{name}'"))),
+    }
+}
+
+const fn default_wait_until_timeout_ms() -> u64 { DEFAULT_WAIT_UNTIL_TIMEOUT_MS }
+
+const fn default_poll_interval_ms() -> u64 { DEFAULT_POLL_INTERVAL_MS }
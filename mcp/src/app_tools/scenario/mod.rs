@@ -0,0 +1,9 @@
+mod constants;
+mod definition;
+mod report;
+mod runner;
+
+pub(super) use definition::Scenario;
+pub use report::StepReport;
+pub(super) use report::StepSummary;
+pub(super) use runner::run_scenario;
@@ -0,0 +1,105 @@
+//! Per-step scenario outcomes.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Outcome of a single scenario step.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// Report entry for one scenario step.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StepReport {
+    /// 1-based position of the step in the scenario.
+    pub step:        usize,
+    /// Step name from the scenario, or a name derived from the action.
+    pub name:        String,
+    /// Tool the step called.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool:        Option<String>,
+    /// Step outcome.
+    pub status:      StepStatus,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: u128,
+    /// Failure or skip reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message:     Option<String>,
+}
+
+impl StepReport {
+    pub(super) const fn passed(
+        step: usize,
+        name: String,
+        tool: Option<String>,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            step,
+            name,
+            tool,
+            status: StepStatus::Passed,
+            duration_ms: duration.as_millis(),
+            message: None,
+        }
+    }
+
+    pub(super) const fn failed(
+        step: usize,
+        name: String,
+        tool: Option<String>,
+        duration: Duration,
+        message: String,
+    ) -> Self {
+        Self {
+            step,
+            name,
+            tool,
+            status: StepStatus::Failed,
+            duration_ms: duration.as_millis(),
+            message: Some(message),
+        }
+    }
+
+    pub(super) const fn skipped(
+        step: usize,
+        name: String,
+        tool: Option<String>,
+        message: String,
+    ) -> Self {
+        Self {
+            step,
+            name,
+            tool,
+            status: StepStatus::Skipped,
+            duration_ms: 0,
+            message: Some(message),
+        }
+    }
+}
+
+/// Counts derived from step reports.
+pub struct StepSummary {
+    pub total:   usize,
+    pub passed:  usize,
+    pub failed:  usize,
+    pub skipped: usize,
+}
+
+impl StepSummary {
+    pub fn from_steps(steps: &[StepReport]) -> Self {
+        let count = |status| steps.iter().filter(|step| step.status == status).count();
+        Self {
+            total:   steps.len(),
+            passed:  count(StepStatus::Passed),
+            failed:  count(StepStatus::Failed),
+            skipped: count(StepStatus::Skipped),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Executes a loaded [`Scenario`] by calling MCP tools through their regular handlers.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use super::constants::SKIPPED_AFTER_FAILURE_MESSAGE;
+use super::definition::Assertion;
+use super::definition::Scenario;
+use super::definition::ScenarioStep;
+use super::report::StepReport;
+use crate::brp_tools::Port;
+use crate::tool;
+use crate::tool::ParameterName;
+use crate::tool::ToolName;
+
+/// Everything produced by one scenario run.
+pub struct ScenarioRun {
+    pub steps:    Vec<StepReport>,
+    pub duration: Duration,
+}
+
+/// A tool's structured response and whether the tool reported an error.
+struct ToolResponse {
+    is_error: bool,
+    value:    Value,
+}
+
+impl ToolResponse {
+    fn message(&self) -> &str {
+        self.value
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("no message")
+    }
+}
+
+/// Run every step in order. Step failures are recorded in the step reports rather than returned,
+/// so the caller always gets a complete report.
+pub async fn run_scenario(scenario: &Scenario, port: Port) -> ScenarioRun {
+    let started = Instant::now();
+    let mut steps = Vec::with_capacity(scenario.steps.len());
+    let mut failed = false;
+
+    for (index, step) in scenario.steps.iter().enumerate() {
+        let tool_name = step.tool().map(|tool| tool.to_string());
+        if failed && !scenario.continue_on_failure {
+            steps.push(StepReport::skipped(
+                index + 1,
+                step.label(),
+                tool_name,
+                SKIPPED_AFTER_FAILURE_MESSAGE.to_string(),
+            ));
+            continue;
+        }
+
+        let step_started = Instant::now();
+        let report = match run_step(step, port).await {
+            Ok(()) => {
+                StepReport::passed(index + 1, step.label(), tool_name, step_started.elapsed())
+            },
+            Err(message) => {
+                failed = true;
+                StepReport::failed(
+                    index + 1,
+                    step.label(),
+                    tool_name,
+                    step_started.elapsed(),
+                    message,
+                )
+            },
+        };
+        steps.push(report);
+    }
+
+    ScenarioRun {
+        steps,
+        duration: started.elapsed(),
+    }
+}
+
+async fn run_step(step: &ScenarioStep, port: Port) -> Result<(), String> {
+    match step {
+        ScenarioStep::Tool {
+            tool,
+            args,
+            assert,
+            expect_error,
+            ..
+        } => {
+            let response = call_tool(*tool, args, port).await?;
+            match (response.is_error, *expect_error) {
+                (true, false) => Err(format!("{tool} failed: {}", response.message())),
+                (false, true) => Err(format!("{tool} succeeded but the step expects an error")),
+                _ => check_assertions(assert, &response.value),
+            }
+        },
+        ScenarioStep::WaitUntil {
+            tool,
+            args,
+            assert,
+            timeout_ms,
+            interval_ms,
+            ..
+        } => {
+            wait_until(
+                *tool,
+                args,
+                assert,
+                Duration::from_millis(*timeout_ms),
+                Duration::from_millis(*interval_ms),
+                port,
+            )
+            .await
+        },
+        ScenarioStep::Wait { duration_ms, .. } => {
+            tokio::time::sleep(Duration::from_millis(*duration_ms)).await;
+            Ok(())
+        },
+    }
+}
+
+async fn wait_until(
+    tool: ToolName,
+    args: &Map<String, Value>,
+    assert: &[Assertion],
+    timeout: Duration,
+    interval: Duration,
+    port: Port,
+) -> Result<(), String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let last_error = match call_tool(tool, args, port).await {
+            Ok(response) if response.is_error => {
+                format!("{tool} failed: {}", response.message())
+            },
+            Ok(response) => match check_assertions(assert, &response.value) {
+                Ok(()) => return Ok(()),
+                Err(message) => message,
+            },
+            Err(message) => message,
+        };
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "condition not met within {}ms ({last_error})",
+                timeout.as_millis()
+            ));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Check every assertion against a tool's structured response, reporting the first that fails.
+fn check_assertions(assert: &[Assertion], response: &Value) -> Result<(), String> {
+    for assertion in assert {
+        assertion
+            .expect
+            .check_at(Some(response), assertion.path.as_deref())
+            .map_err(|message| {
+                format!(
+                    "{}: {message}",
+                    assertion.path.as_deref().unwrap_or("response")
+                )
+            })?;
+    }
+    Ok(())
+}
+
+/// Call a tool with the scenario's port unless the step sets its own.
+async fn call_tool(
+    tool: ToolName,
+    args: &Map<String, Value>,
+    port: Port,
+) -> Result<ToolResponse, String> {
+    let mut arguments = args.clone();
+    arguments
+        .entry(String::from(ParameterName::Port))
+        .or_insert_with(|| json!(port));
+
+    let result = tool::call_tool(tool, arguments)
+        .await
+        .map_err(|report| report.current_context().to_string())?;
+    Ok(ToolResponse {
+        is_error: result.is_error == Some(true),
+        value:    result.structured_content.unwrap_or(Value::Null),
+    })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::json;
+
+    use super::check_assertions;
+    use crate::app_tools::scenario::definition::Scenario;
+    use crate::app_tools::scenario::definition::ScenarioStep;
+
+    #[test]
+    fn assertions_check_the_structured_response() {
+        let scenario: Scenario = serde_json::from_value(json!({
+            "steps": [{
+                "action": "tool",
+                "tool": "world_query",
+                "assert": [
+                    { "path": "/status", "expect": { "equals": "success" } },
+                    { "path": "/metadata/entity_count", "expect": { "greater_than": 1 } }
+                ]
+            }]
+        }))
+        .expect("scenario parses");
+        let assert = match scenario.steps.first() {
+            Some(ScenarioStep::Tool { assert, .. }) => assert.as_slice(),
+            _ => &[],
+        };
+
+        let response = json!({ "status": "success", "metadata": { "entity_count": 1 } });
+        let failure = check_assertions(assert, &response);
+        assert!(failure.is_err_and(|message| message.starts_with("/metadata/entity_count")));
+    }
+
+    #[test]
+    fn scenarios_cannot_run_scenarios() {
+        let scenario: Result<Scenario, _> = serde_json::from_value(json!({
+            "steps": [{ "action": "tool", "tool": "brp_run_scenario" }]
+        }));
+        assert!(scenario.is_err());
+    }
+}
@@ -0,0 +1,409 @@
+//! Launch session tracking and orphan cleanup
+//!
+//! Every MCP server process owns a session file in the temp directory named after its PID. Each
+//! app launched by `brp_launch` appends a line with its PID, name, port, and log path. A clean
+//! server exit removes the session file; a crash leaves it behind.
+//!
+//! On startup, session files whose server process is gone belong to crashed sessions. Their apps
+//! may still be holding BRP ports, so they are reported along with their logs. The apps may also
+//! be in use by another server, so nothing is touched unless `BRP_MCP_ORPHAN_CLEANUP` is set to
+//! `cleanup`, which terminates them (after checking the PID still belongs to the recorded app) and
+//! removes their logs. Set it to `off` to skip the check.
+//!
+//! Report mode lists each crashed session once: its file is then renamed with the `reported`
+//! extension, which later startups skip until cleanup mode reclaims it.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sysinfo::Pid;
+use sysinfo::ProcessesToUpdate;
+use sysinfo::Signal;
+use sysinfo::System;
+
+use super::constants::MCP_BINARY_NAME;
+use super::constants::ORPHAN_CLEANUP_ENV_VAR;
+use super::constants::REPORTED_SESSION_FILE_EXTENSION;
+use super::constants::SESSION_FILE_EXTENSION;
+use super::constants::SESSION_FILE_PREFIX;
+use super::process;
+use crate::brp_tools::Port;
+
+/// What to do with orphans from crashed sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CleanupMode {
+    /// Terminate orphaned apps and remove their logs
+    Cleanup,
+    /// Report orphans once, keeping session files for a later cleanup
+    Report,
+    /// Skip the startup check entirely
+    Off,
+}
+
+impl CleanupMode {
+    fn from_env() -> Self {
+        match std::env::var(ORPHAN_CLEANUP_ENV_VAR)
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "off" => Self::Off,
+            "cleanup" => Self::Cleanup,
+            _ => Self::Report,
+        }
+    }
+}
+
+/// One app launched during a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LaunchRecord {
+    pid:      u32,
+    app_name: String,
+    port:     Port,
+    log_path: PathBuf,
+}
+
+/// An app from a crashed session that was still running
+#[derive(Debug, Clone)]
+pub(crate) struct OrphanedProcess {
+    pub(crate) pid:        u32,
+    pub(crate) app_name:   String,
+    pub(crate) port:       Port,
+    /// Whether the process was terminated (always `false` in report mode)
+    pub(crate) terminated: bool,
+}
+
+/// What the startup check found and reclaimed
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OrphanCleanupReport {
+    /// Number of session files left behind by crashed servers
+    pub(crate) stale_sessions: usize,
+    pub(crate) processes:      Vec<OrphanedProcess>,
+    /// Logs of crashed sessions - removed in cleanup mode, only listed in report mode
+    pub(crate) logs:           Vec<PathBuf>,
+    /// Whether anything was actually reclaimed, as opposed to only reported
+    pub(crate) reclaimed:      bool,
+}
+
+impl OrphanCleanupReport {
+    /// One-paragraph summary for the client, or `None` when there was nothing to report
+    pub(crate) fn summary(&self) -> Option<String> {
+        if self.processes.is_empty() && self.logs.is_empty() {
+            return None;
+        }
+
+        let mut summary = if self.reclaimed {
+            format!(
+                "Cleaned up after {} crashed bevy_brp_mcp session(s): ",
+                self.stale_sessions
+            )
+        } else {
+            format!(
+                "Found orphans from {} crashed bevy_brp_mcp session(s) (set {ORPHAN_CLEANUP_ENV_VAR}=cleanup to reclaim them): ",
+                self.stale_sessions
+            )
+        };
+        let processes = self
+            .processes
+            .iter()
+            .map(|orphan| {
+                let state = if orphan.terminated {
+                    "terminated"
+                } else {
+                    "running"
+                };
+                format!(
+                    "'{}' (PID {}, port {}) {state}",
+                    orphan.app_name, orphan.pid, orphan.port
+                )
+            })
+            .collect::<Vec<_>>();
+        if !processes.is_empty() {
+            let _ = write!(summary, "{}; ", processes.join(", "));
+        }
+        let verb = if self.reclaimed { "removed" } else { "stale" };
+        let _ = write!(summary, "{} log file(s) {verb}", self.logs.len());
+        Some(summary)
+    }
+}
+
+/// Session file owned by this server process
+fn own_session_path() -> PathBuf { session_path(std::process::id()) }
+
+fn session_path(server_pid: u32) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "{SESSION_FILE_PREFIX}{server_pid}.{SESSION_FILE_EXTENSION}"
+    ))
+}
+
+/// Server PID of a session file, whether or not its orphans were already reported
+fn server_pid_from_session_path(path: &Path) -> Option<u32> {
+    let extension = path.extension()?.to_str()?;
+    if extension != SESSION_FILE_EXTENSION && extension != REPORTED_SESSION_FILE_EXTENSION {
+        return None;
+    }
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix(SESSION_FILE_PREFIX)?
+        .parse()
+        .ok()
+}
+
+/// Record a launched app in this server's session file.
+///
+/// Failures only cost orphan cleanup after a crash, so they are logged rather than returned.
+pub(super) fn record_launch(pid: u32, app_name: &str, port: Port, log_path: &Path) {
+    let record = LaunchRecord {
+        pid,
+        app_name: app_name.to_string(),
+        port,
+        log_path: log_path.to_path_buf(),
+    };
+    let path = own_session_path();
+    let result = serde_json::to_string(&record)
+        .map_err(std::io::Error::other)
+        .and_then(|line| {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(file, "{line}")
+        });
+    if let Err(e) = result {
+        tracing::warn!(
+            "Failed to record launch of '{app_name}' in {}: {e}",
+            path.display()
+        );
+    }
+}
+
+/// Remove this server's session file on a clean exit; launched apps keep running on purpose.
+pub(crate) fn end_session() {
+    let path = own_session_path();
+    if path.exists()
+        && let Err(e) = fs::remove_file(&path)
+    {
+        tracing::warn!("Failed to remove session file {}: {e}", path.display());
+    }
+}
+
+/// Find session files left by crashed servers and reclaim their apps and logs
+pub(crate) fn cleanup_orphaned_sessions() -> OrphanCleanupReport {
+    let mode = CleanupMode::from_env();
+    let mut report = OrphanCleanupReport {
+        reclaimed: mode == CleanupMode::Cleanup,
+        ..OrphanCleanupReport::default()
+    };
+    if mode == CleanupMode::Off {
+        return report;
+    }
+
+    cleanup_sessions_in(&std::env::temp_dir(), mode, &mut report);
+    report
+}
+
+fn cleanup_sessions_in(dir: &Path, mode: CleanupMode, report: &mut OrphanCleanupReport) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let session_files: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| server_pid_from_session_path(path).is_some())
+        .filter(|path| mode == CleanupMode::Cleanup || !is_reported(path))
+        .collect();
+    if session_files.is_empty() {
+        return;
+    }
+
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    for session in session_files {
+        let Some(server_pid) = server_pid_from_session_path(&session) else {
+            continue;
+        };
+        let server_alive = server_pid == std::process::id()
+            || system
+                .process(Pid::from_u32(server_pid))
+                .is_some_and(|process| {
+                    process::process_matches_name_exact(process, MCP_BINARY_NAME)
+                });
+        if server_alive {
+            continue;
+        }
+
+        report.stale_sessions += 1;
+        for record in read_launch_records(&session) {
+            reclaim_launch(&system, &record, mode, report);
+        }
+        let result = if mode == CleanupMode::Cleanup {
+            fs::remove_file(&session)
+        } else {
+            fs::rename(
+                &session,
+                session.with_extension(REPORTED_SESSION_FILE_EXTENSION),
+            )
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to retire stale session {}: {e}", session.display());
+        }
+    }
+}
+
+fn is_reported(session: &Path) -> bool {
+    session
+        .extension()
+        .is_some_and(|extension| extension == REPORTED_SESSION_FILE_EXTENSION)
+}
+
+fn read_launch_records(session: &Path) -> Vec<LaunchRecord> {
+    fs::read_to_string(session)
+        .map(|contents| {
+            contents
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn reclaim_launch(
+    system: &System,
+    record: &LaunchRecord,
+    mode: CleanupMode,
+    report: &mut OrphanCleanupReport,
+) {
+    // A PID may have been reused since the crash - only touch it if it is still the recorded app
+    if let Some(process) = system.process(Pid::from_u32(record.pid))
+        && process::process_matches_name_exact(process, &record.app_name)
+    {
+        let terminated =
+            mode == CleanupMode::Cleanup && process.kill_with(Signal::Term).unwrap_or(false);
+        report.processes.push(OrphanedProcess {
+            pid: record.pid,
+            app_name: record.app_name.clone(),
+            port: record.port,
+            terminated,
+        });
+    }
+
+    if record.log_path.exists() {
+        if mode == CleanupMode::Cleanup
+            && let Err(e) = fs::remove_file(&record.log_path)
+        {
+            tracing::warn!(
+                "Failed to remove stale log {}: {e}",
+                record.log_path.display()
+            );
+            return;
+        }
+        report.logs.push(record.log_path.clone());
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use tempfile::tempdir;
+
+    use super::CleanupMode;
+    use super::LaunchRecord;
+    use super::OrphanCleanupReport;
+    use super::OrphanedProcess;
+    use super::cleanup_sessions_in;
+    use super::server_pid_from_session_path;
+    use super::session_path;
+    use crate::brp_tools::Port;
+
+    /// PID well above any real PID limit, so neither the server nor its app is running
+    const CRASHED_PID: u32 = 999_999_999;
+
+    fn check(dir: &Path, mode: CleanupMode) -> OrphanCleanupReport {
+        let mut report = OrphanCleanupReport::default();
+        cleanup_sessions_in(dir, mode, &mut report);
+        report
+    }
+
+    #[test]
+    fn session_paths_round_trip_server_pid() {
+        assert_eq!(
+            server_pid_from_session_path(&session_path(4242)),
+            Some(4242)
+        );
+        assert_eq!(
+            server_pid_from_session_path(&PathBuf::from("/tmp/bevy_brp_mcp_app_port15702_1.log")),
+            None
+        );
+    }
+
+    #[test]
+    fn summary_lists_reclaimed_processes_and_logs() {
+        let report = OrphanCleanupReport {
+            stale_sessions: 1,
+            processes:      vec![OrphanedProcess {
+                pid:        101,
+                app_name:   "test_app".to_string(),
+                port:       Port(15702),
+                terminated: true,
+            }],
+            logs:           vec![PathBuf::from("/tmp/bevy_brp_mcp_test_app_port15702_1.log")],
+            reclaimed:      true,
+        };
+
+        assert_eq!(
+            report.summary().as_deref(),
+            Some(
+                "Cleaned up after 1 crashed bevy_brp_mcp session(s): 'test_app' (PID 101, port \
+                 15702) terminated; 1 log file(s) removed"
+            )
+        );
+        assert_eq!(OrphanCleanupReport::default().summary(), None);
+    }
+
+    #[test]
+    fn report_mode_reports_a_crashed_session_once() {
+        let dir = tempdir().expect("temp dir");
+        let log_path = dir.path().join("bevy_brp_mcp_test_app_port15702_1.log");
+        fs::write(&log_path, "").expect("write log");
+        let record = LaunchRecord {
+            pid:      CRASHED_PID,
+            app_name: "test_app".to_string(),
+            port:     Port(15702),
+            log_path: log_path.clone(),
+        };
+        let session = dir
+            .path()
+            .join(session_path(CRASHED_PID).file_name().expect("file name"));
+        let line = serde_json::to_string(&record).expect("record serializes");
+        fs::write(&session, format!("{line}\n")).expect("write session");
+
+        let first = check(dir.path(), CleanupMode::Report);
+        assert_eq!(first.stale_sessions, 1);
+        assert_eq!(first.logs, vec![log_path.clone()]);
+
+        let second = check(dir.path(), CleanupMode::Report);
+        assert_eq!(second.stale_sessions, 0);
+        assert_eq!(second.summary(), None);
+
+        let cleanup = check(dir.path(), CleanupMode::Cleanup);
+        assert_eq!(cleanup.stale_sessions, 1);
+        assert!(!log_path.exists());
+        assert!(
+            fs::read_dir(dir.path())
+                .expect("read temp dir")
+                .next()
+                .is_none()
+        );
+    }
+}
@@ -1,5 +1,8 @@
 //! Simple cargo detector based on `bevy_brp_tool`
 
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
@@ -7,25 +10,31 @@ use std::process::Command;
 use anyhow::Context;
 use anyhow::Error;
 use anyhow::Result;
+use cargo_metadata::DependencyKind;
 use cargo_metadata::Metadata;
 use cargo_metadata::MetadataCommand;
+use cargo_metadata::Node;
 use cargo_metadata::Package;
+use cargo_metadata::PackageId;
 use serde::Serialize;
 use strum::AsRefStr;
 use strum::Display;
 use strum::EnumString;
 
 use super::constants::BEVY_CRATE_NAME;
+use super::constants::BEVY_REMOTE_CRATE_NAME;
 use super::constants::BEVY_REMOTE_FEATURE;
 use super::constants::BEVY_REMOTE_GLOB_IMPORT_PREFIX;
 use super::constants::BEVY_REMOTE_PLUGIN_IMPORT;
 use super::constants::BEVY_REMOTE_REMOTE_GLOB_IMPORT_PREFIX;
 use super::constants::BEVY_REMOTE_REMOTE_PLUGIN_IMPORT;
+use super::constants::BRP_EXTRAS_CRATE_NAME;
 use super::constants::BRP_EXTRAS_GLOB_IMPORT_PREFIX;
 use super::constants::BRP_EXTRAS_PLUGIN_IMPORT;
 use super::constants::BRP_EXTRAS_PLUGIN_NAME;
 use super::constants::CARGO_EXAMPLES_DIRECTORY;
 use super::constants::CARGO_SRC_DIRECTORY;
+use super::constants::INNER_DOC_COMMENT_PREFIX;
 use super::constants::MCP_CRATE_NAME;
 use super::constants::REMOTE_PLUGIN_NAME;
 use super::constants::RUST_SOURCE_EXTENSION;
@@ -67,6 +76,26 @@ impl BrpLevel {
     }
 }
 
+/// A BRP crate compiled into a target, as resolved by cargo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(super) struct BrpDependency {
+    pub(super) version:  String,
+    /// Features enabled on the crate; cargo unifies them across the workspace
+    pub(super) features: Vec<String>,
+    /// Whether the target's package depends on the crate itself rather than through `bevy` or
+    /// another crate
+    pub(super) direct:   bool,
+}
+
+/// The BRP crates in a target's dependency graph, absent when the target is built without them
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub(super) struct BrpDependencies {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) bevy_remote:     Option<BrpDependency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) bevy_brp_extras: Option<BrpDependency>,
+}
+
 impl TargetType {
     /// Add cargo-specific arguments for this target type
     pub fn add_cargo_args(self, command: &mut Command, target_name: &str) {
@@ -85,19 +114,21 @@ impl TargetType {
 #[derive(Debug, Clone)]
 pub struct BevyTarget {
     /// Name of the target
-    pub name:           String,
+    pub name:              String,
     /// Type of target (`App` or `Example`)
-    pub target_type:    TargetType,
+    pub target_type:       TargetType,
     /// Package name (for examples, this is the package containing the example)
-    pub package_name:   String,
+    pub package_name:      String,
     /// Workspace root (for apps)
-    pub workspace_root: PathBuf,
+    pub workspace_root:    PathBuf,
     /// Path to the package's Cargo.toml
-    pub manifest:       PathBuf,
+    pub manifest:          PathBuf,
     /// Relative path from scan root to this item
-    pub relative:       PathBuf,
+    pub relative:          PathBuf,
     /// Path to the target's source file (from `cargo metadata`)
-    pub source:         PathBuf,
+    pub source:            PathBuf,
+    /// Cargo features the target declares in `required-features`
+    pub required_features: Vec<String>,
 }
 
 impl BevyTarget {
@@ -169,26 +200,28 @@ impl CargoDetector {
         // Extract apps
         for target in package.targets.iter().filter(|t| t.is_bin()) {
             targets.push(BevyTarget {
-                name:           target.name.clone(),
-                target_type:    TargetType::App,
-                package_name:   package_name.clone(),
-                workspace_root: workspace_root.clone(),
-                manifest:       manifest_path.clone(),
-                relative:       PathBuf::new(), // Will be set by scanning logic
-                source:         target.src_path.clone().into(),
+                name:              target.name.clone(),
+                target_type:       TargetType::App,
+                package_name:      package_name.clone(),
+                workspace_root:    workspace_root.clone(),
+                manifest:          manifest_path.clone(),
+                relative:          PathBuf::new(), // Will be set by scanning logic
+                source:            target.src_path.clone().into(),
+                required_features: target.required_features.clone(),
             });
         }
 
         // Extract examples
         for target in package.targets.iter().filter(|t| t.is_example()) {
             targets.push(BevyTarget {
-                name:           target.name.clone(),
-                target_type:    TargetType::Example,
-                package_name:   package_name.clone(),
-                workspace_root: workspace_root.clone(),
-                manifest:       manifest_path.clone(),
-                relative:       PathBuf::new(), // Will be set by scanning logic
-                source:         target.src_path.clone().into(),
+                name:              target.name.clone(),
+                target_type:       TargetType::Example,
+                package_name:      package_name.clone(),
+                workspace_root:    workspace_root.clone(),
+                manifest:          manifest_path.clone(),
+                relative:          PathBuf::new(), // Will be set by scanning logic
+                source:            target.src_path.clone().into(),
+                required_features: target.required_features.clone(),
             });
         }
 
@@ -221,6 +254,76 @@ impl CargoDetector {
             .collect()
     }
 
+    /// BRP crates compiled into a target, from cargo's resolved dependency graph
+    ///
+    /// `bevy_remote` is only in the graph when something enables it, such as bevy's `bevy_remote`
+    /// feature or a dependency on `bevy_brp_extras`, so its absence means BRP was never compiled
+    /// in. Examples also see their package's dev-dependencies.
+    pub(super) fn brp_dependencies(&self, target: &BevyTarget) -> BrpDependencies {
+        let Some(resolve) = &self.metadata.resolve else {
+            return BrpDependencies::default();
+        };
+        let Some(root) = self
+            .metadata
+            .packages
+            .iter()
+            .find(|package| package.manifest_path.as_std_path() == target.manifest)
+        else {
+            return BrpDependencies::default();
+        };
+
+        let nodes: HashMap<&PackageId, &Node> =
+            resolve.nodes.iter().map(|node| (&node.id, node)).collect();
+        let include_dev = target.target_type == TargetType::Example;
+
+        // Breadth first, so each crate is recorded at its shallowest depth
+        let mut depths: HashMap<&PackageId, usize> = HashMap::new();
+        let mut queue = VecDeque::from([(&root.id, 0)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            let Some(node) = nodes.get(id) else {
+                continue;
+            };
+            for dependency in &node.deps {
+                let linked = dependency.dep_kinds.is_empty()
+                    || dependency.dep_kinds.iter().any(|info| match info.kind {
+                        DependencyKind::Normal => true,
+                        DependencyKind::Development => include_dev && depth == 0,
+                        _ => false,
+                    });
+                if linked
+                    && dependency.pkg != root.id
+                    && let Entry::Vacant(entry) = depths.entry(&dependency.pkg)
+                {
+                    entry.insert(depth + 1);
+                    queue.push_back((&dependency.pkg, depth + 1));
+                }
+            }
+        }
+
+        let find = |crate_name: &str| {
+            self.metadata
+                .packages
+                .iter()
+                .filter(|package| package.name.as_str() == crate_name)
+                .find_map(|package| {
+                    let depth = depths.get(&package.id)?;
+                    Some(BrpDependency {
+                        version:  package.version.to_string(),
+                        features: nodes
+                            .get(&package.id)
+                            .map(|node| node.features.iter().map(ToString::to_string).collect())
+                            .unwrap_or_default(),
+                        direct:   *depth == 1,
+                    })
+                })
+        };
+
+        BrpDependencies {
+            bevy_remote:     find(BEVY_REMOTE_CRATE_NAME),
+            bevy_brp_extras: find(BRP_EXTRAS_CRATE_NAME),
+        }
+    }
+
     fn package_depends_on_bevy(package: &Package) -> bool {
         // Check direct dependencies (including workspace dependencies)
         package
@@ -341,8 +444,45 @@ impl CargoDetector {
                 && content.contains(REMOTE_PLUGIN_NAME))
     }
 
+    /// First paragraph of a file's `//!` module docs, joined into one line.
+    pub(super) fn file_doc_summary(file_path: &Path) -> Option<String> {
+        std::fs::read_to_string(file_path)
+            .ok()
+            .and_then(|content| Self::doc_summary(&content))
+    }
+
+    fn doc_summary(content: &str) -> Option<String> {
+        let summary = content
+            .lines()
+            .map(str::trim_start)
+            .skip_while(|line| line.is_empty())
+            .map_while(|line| line.strip_prefix(INNER_DOC_COMMENT_PREFIX))
+            .map(str::trim)
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!summary.is_empty()).then_some(summary)
+    }
+
     /// Check if a specific file uses `RemotePlugin` or `BrpExtrasPlugin` (any BRP support)
     pub(super) fn file_uses_brp_plugins(file_path: &Path) -> bool {
         !matches!(Self::file_brp_level(file_path), BrpLevel::None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CargoDetector;
+
+    #[test]
+    fn doc_summary_joins_first_module_doc_paragraph() {
+        let source = "\n//! Spawns a cube\n//! you can rotate over BRP.\n//!\n//! Run with `cargo run`.\nuse bevy::prelude::*;\n";
+
+        assert_eq!(
+            CargoDetector::doc_summary(source).as_deref(),
+            Some("Spawns a cube you can rotate over BRP.")
+        );
+        assert_eq!(CargoDetector::doc_summary("use bevy::prelude::*;\n"), None);
+    }
+}
@@ -7,16 +7,20 @@ use serde_json::Value;
 use serde_json::json;
 
 use super::cargo_detector::BevyTarget;
+use super::cargo_detector::BrpDependencies;
 use super::cargo_detector::BrpLevel;
 use super::cargo_detector::CargoDetector;
+use super::constants::BRP_DEPENDENCIES_FIELD;
 use super::constants::BRP_LEVEL_FIELD;
 use super::constants::BUILD_BUILT_FIELD;
 use super::constants::BUILDS_FIELD;
+use super::constants::DESCRIPTION_FIELD;
 use super::constants::KIND_FIELD;
 use super::constants::NAME_FIELD;
 use super::constants::PACKAGE_NAME_FIELD;
 use super::constants::PATH_FIELD;
 use super::constants::RELATIVE_PATH_FIELD;
+use super::constants::REQUIRED_FEATURES_FIELD;
 use super::constants::WORKSPACE_ROOT_FIELD;
 use super::scanning;
 use crate::app_tools::constants::MANIFEST_PATH_FIELD;
@@ -29,13 +33,17 @@ use crate::app_tools::constants::PROFILE_RELEASE;
 /// Only targets declared in workspace `Cargo.toml` files are included (via `cargo metadata`).
 pub(super) struct AllBevyTargetsStrategy;
 
-/// A `BevyTarget` enriched with BRP status.
+/// A `BevyTarget` enriched with BRP status and a description.
 ///
 /// For bins, `brp_level` reflects whether the package's `src/` tree uses BRP plugins.
 /// For examples, the individual source file is checked for BRP plugin imports.
 pub(super) struct EnrichedTarget {
-    pub(super) target:    BevyTarget,
-    pub(super) brp_level: BrpLevel,
+    pub(super) target:           BevyTarget,
+    pub(super) brp_level:        BrpLevel,
+    /// BRP crates in the target's resolved dependency graph
+    pub(super) brp_dependencies: BrpDependencies,
+    /// First paragraph of the target source's `//!` docs
+    pub(super) description:      Option<String>,
 }
 
 impl AllBevyTargetsStrategy {
@@ -67,7 +75,14 @@ impl AllBevyTargetsStrategy {
                 } else {
                     CargoDetector::file_brp_level(&target.source)
                 };
-                EnrichedTarget { target, brp_level }
+                let brp_dependencies = detector.brp_dependencies(&target);
+                let description = CargoDetector::file_doc_summary(&target.source);
+                EnrichedTarget {
+                    target,
+                    brp_level,
+                    brp_dependencies,
+                    description,
+                }
             })
             .collect()
     }
@@ -90,19 +105,25 @@ impl AllBevyTargetsStrategy {
     }
 
     pub(super) fn serialize_item(item: &EnrichedTarget, relative_path: String) -> Value {
-        json!({
+        let mut value = json!({
             NAME_FIELD: item.target.name,
             KIND_FIELD: item.target.target_type.as_ref(),
             PACKAGE_NAME_FIELD: item.target.package_name,
             BRP_LEVEL_FIELD: item.brp_level.as_str(),
+            BRP_DEPENDENCIES_FIELD: item.brp_dependencies,
             WORKSPACE_ROOT_FIELD: item.target.workspace_root.display().to_string(),
             MANIFEST_PATH_FIELD: item.target.manifest.display().to_string(),
             // The relative_path field is designed for round-trip compatibility with launch functions.
             // This path can be used directly in `brp_launch`'s path parameter
             // to disambiguate between targets with the same name in different locations.
             RELATIVE_PATH_FIELD: relative_path,
-            BUILDS_FIELD: create_builds_json(&item.target)
-        })
+            BUILDS_FIELD: create_builds_json(&item.target),
+            REQUIRED_FEATURES_FIELD: item.target.required_features,
+        });
+        if let Some(description) = &item.description {
+            value[DESCRIPTION_FIELD] = json!(description);
+        }
+        value
     }
 }
 
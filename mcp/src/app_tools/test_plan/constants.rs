@@ -0,0 +1,21 @@
+// plan defaults
+/// Default time to wait for BRP after launching the plan's app
+pub(super) const DEFAULT_READY_TIMEOUT_MS: u64 = 30_000;
+/// Default timeout for `wait_for` steps
+pub(super) const DEFAULT_WAIT_FOR_TIMEOUT_MS: u64 = 5_000;
+/// Default delay between `wait_for` polls
+pub(super) const DEFAULT_POLL_INTERVAL_MS: u64 = 100;
+
+// report
+/// Extension that replaces the plan file's extension for the default report path
+pub const JUNIT_REPORT_EXTENSION: &str = "junit.xml";
+/// Case name used when setup or launch fails before any case runs
+pub(super) const SETUP_CASE_NAME: &str = "setup";
+/// Case name used when teardown fails
+pub(super) const TEARDOWN_CASE_NAME: &str = "teardown";
+
+// brp methods
+/// Method polled to detect that BRP is ready after launch
+pub(super) const READY_PROBE_METHOD: &str = "rpc.discover";
+/// Extras method used by `screenshot` steps
+pub(super) const SCREENSHOT_METHOD: &str = "brp_extras/screenshot";
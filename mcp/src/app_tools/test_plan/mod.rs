@@ -0,0 +1,11 @@
+mod constants;
+mod plan;
+mod report;
+mod runner;
+
+pub(super) use constants::JUNIT_REPORT_EXTENSION;
+pub(super) use plan::TestPlan;
+pub use report::CaseReport;
+pub(super) use report::Summary;
+pub(super) use report::render_junit;
+pub(super) use runner::run_plan;
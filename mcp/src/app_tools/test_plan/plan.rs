@@ -0,0 +1,134 @@
+//! Declarative test plan file format.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::constants::DEFAULT_POLL_INTERVAL_MS;
+use super::constants::DEFAULT_READY_TIMEOUT_MS;
+use super::constants::DEFAULT_WAIT_FOR_TIMEOUT_MS;
+use crate::app_tools::launch_params::LaunchBevyBinaryParams;
+use crate::brp_tools::Expectation;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A test plan loaded from a JSON file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestPlan {
+    /// Suite name used in the report. Defaults to the plan file stem.
+    #[serde(default)]
+    pub name:             Option<String>,
+    /// Optional app to launch before setup runs. Its `port` is replaced by the tool's port.
+    #[serde(default)]
+    pub launch:           Option<LaunchBevyBinaryParams>,
+    /// How long to wait for BRP to respond after launch.
+    #[serde(default = "default_ready_timeout_ms")]
+    pub ready_timeout_ms: u64,
+    /// Steps run once before any case. A failure here fails every case.
+    #[serde(default)]
+    pub setup:            Vec<TestStep>,
+    /// Test cases, each reported as a `JUnit` `testcase`.
+    pub cases:            Vec<TestCase>,
+    /// Steps run after all cases, even when cases fail.
+    #[serde(default)]
+    pub teardown:         Vec<TestStep>,
+    /// Shut the launched app down after teardown (default: true).
+    #[serde(default = "default_true")]
+    pub shutdown:         bool,
+}
+
+/// A named sequence of steps.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestCase {
+    pub name:  String,
+    pub steps: Vec<TestStep>,
+}
+
+/// A single plan step, tagged by `action`.
+#[derive(Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TestStep {
+    /// Call a BRP method and fail the step on a BRP error. Inputs are injected this way, e.g.
+    /// `brp_extras/send_keys` or `brp_extras/click_mouse`.
+    Call {
+        method: String,
+        #[serde(default)]
+        params: Option<Value>,
+    },
+    /// Sleep for a fixed duration, e.g. to let timed inputs release.
+    Wait { duration_ms: u64 },
+    /// Poll a BRP method until the value at `path` satisfies `expect`.
+    WaitFor {
+        method:      String,
+        #[serde(default)]
+        params:      Option<Value>,
+        #[serde(default)]
+        path:        Option<String>,
+        expect:      Expectation,
+        #[serde(default = "default_wait_for_timeout_ms")]
+        timeout_ms:  u64,
+        #[serde(default = "default_poll_interval_ms")]
+        interval_ms: u64,
+    },
+    /// Call a BRP method once and check the value at `path` against `expect`.
+    Assert {
+        method: String,
+        #[serde(default)]
+        params: Option<Value>,
+        #[serde(default)]
+        path:   Option<String>,
+        expect: Expectation,
+    },
+    /// Capture a screenshot via `brp_extras/screenshot`. Relative paths resolve against the
+    /// plan file's directory.
+    Screenshot { path: String },
+}
+
+impl TestPlan {
+    /// Read and parse a plan file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|error| {
+            Error::FileOrPathNotFound(format!(
+                "Failed to read test plan '{}': {error}",
+                path.display()
+            ))
+        })?;
+        serde_json::from_str(&contents).map_err(|error| {
+            Error::InvalidArgument(format!("Invalid test plan '{}': {error}", path.display()))
+                .into()
+        })
+    }
+
+    /// Suite name, falling back to the plan file stem.
+    pub fn suite_name(&self, path: &Path) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            path.file_stem().map_or_else(
+                || "test_plan".to_string(),
+                |stem| stem.to_string_lossy().to_string(),
+            )
+        })
+    }
+}
+
+/// Resolve a path from a plan relative to the plan file's directory.
+pub(super) fn resolve_relative(plan_path: &Path, path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() {
+        return path;
+    }
+    plan_path
+        .parent()
+        .map_or_else(|| path.clone(), |directory| directory.join(&path))
+}
+
+const fn default_true() -> bool { true }
+
+const fn default_ready_timeout_ms() -> u64 { DEFAULT_READY_TIMEOUT_MS }
+
+const fn default_wait_for_timeout_ms() -> u64 { DEFAULT_WAIT_FOR_TIMEOUT_MS }
+
+const fn default_poll_interval_ms() -> u64 { DEFAULT_POLL_INTERVAL_MS }
@@ -0,0 +1,167 @@
+//! Test plan outcomes and `JUnit` XML rendering.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Outcome of a single test case.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// Report entry for one test case.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CaseReport {
+    /// Case name from the plan.
+    pub name:        String,
+    /// Case outcome.
+    pub status:      CaseStatus,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: u128,
+    /// Failure or skip reason, including the failing step index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message:     Option<String>,
+}
+
+impl CaseReport {
+    pub(super) const fn passed(name: String, duration: Duration) -> Self {
+        Self {
+            name,
+            status: CaseStatus::Passed,
+            duration_ms: duration.as_millis(),
+            message: None,
+        }
+    }
+
+    pub(super) const fn failed(name: String, duration: Duration, message: String) -> Self {
+        Self {
+            name,
+            status: CaseStatus::Failed,
+            duration_ms: duration.as_millis(),
+            message: Some(message),
+        }
+    }
+
+    pub(super) const fn skipped(name: String, message: String) -> Self {
+        Self {
+            name,
+            status: CaseStatus::Skipped,
+            duration_ms: 0,
+            message: Some(message),
+        }
+    }
+}
+
+/// Counts derived from case reports.
+pub struct Summary {
+    pub tests:    usize,
+    pub passed:   usize,
+    pub failures: usize,
+    pub skipped:  usize,
+}
+
+impl Summary {
+    pub fn from_cases(cases: &[CaseReport]) -> Self {
+        let count = |status| cases.iter().filter(|case| case.status == status).count();
+        Self {
+            tests:    cases.len(),
+            passed:   count(CaseStatus::Passed),
+            failures: count(CaseStatus::Failed),
+            skipped:  count(CaseStatus::Skipped),
+        }
+    }
+}
+
+/// Render cases as a single `JUnit` `testsuite` document.
+pub fn render_junit(suite: &str, cases: &[CaseReport], total: Duration) -> String {
+    let summary = Summary::from_cases(cases);
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+        escape_xml(suite),
+        summary.tests,
+        summary.failures,
+        summary.skipped,
+        total.as_secs_f64()
+    );
+    for case in cases {
+        let _ = write!(
+            xml,
+            "  <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+            escape_xml(suite),
+            escape_xml(&case.name),
+            Duration::from_millis(u64::try_from(case.duration_ms).unwrap_or(u64::MAX))
+                .as_secs_f64()
+        );
+        let message = escape_xml(case.message.as_deref().unwrap_or_default());
+        match case.status {
+            CaseStatus::Passed => xml.push_str("/>\n"),
+            CaseStatus::Failed => {
+                let _ = writeln!(
+                    xml,
+                    ">\n    <failure message=\"{message}\">{message}</failure>\n  </testcase>"
+                );
+            },
+            CaseStatus::Skipped => {
+                let _ = writeln!(
+                    xml,
+                    ">\n    <skipped message=\"{message}\"/>\n  </testcase>"
+                );
+            },
+        }
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::CaseReport;
+    use super::render_junit;
+
+    #[test]
+    fn junit_report_counts_and_escapes_cases() {
+        let cases = vec![
+            CaseReport::passed("moves <player>".to_string(), Duration::from_millis(1_500)),
+            CaseReport::failed(
+                "score".to_string(),
+                Duration::from_millis(20),
+                "step 2: expected \"10\"".to_string(),
+            ),
+            CaseReport::skipped("later".to_string(), "setup failed".to_string()),
+        ];
+
+        let xml = render_junit("smoke & sanity", &cases, Duration::from_secs(2));
+
+        assert!(xml.contains(
+            "<testsuite name=\"smoke &amp; sanity\" tests=\"3\" failures=\"1\" skipped=\"1\""
+        ));
+        assert!(xml.contains("name=\"moves &lt;player&gt;\" time=\"1.500\"/>"));
+        assert!(xml.contains("<failure message=\"step 2: expected &quot;10&quot;\">"));
+        assert!(xml.contains("<skipped message=\"setup failed\"/>"));
+    }
+}
@@ -0,0 +1,309 @@
+//! Executes a loaded [`TestPlan`] against a running or freshly launched app.
+
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde_json::Value;
+use serde_json::json;
+use tracing::debug;
+
+use super::constants::DEFAULT_POLL_INTERVAL_MS;
+use super::constants::READY_PROBE_METHOD;
+use super::constants::SCREENSHOT_METHOD;
+use super::constants::SETUP_CASE_NAME;
+use super::constants::TEARDOWN_CASE_NAME;
+use super::plan;
+use super::plan::TestPlan;
+use super::plan::TestStep;
+use super::report::CaseReport;
+use crate::app_tools::brp_shutdown;
+use crate::app_tools::constants::DEFAULT_PROFILE;
+use crate::app_tools::instance_count::InstanceCount;
+use crate::app_tools::launch;
+use crate::app_tools::launch_params::LaunchPort;
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Expectation;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::tool;
+use crate::tool::ToolAccess;
+
+/// Everything produced by one plan run.
+pub struct PlanRun {
+    pub cases:    Vec<CaseReport>,
+    pub duration: Duration,
+}
+
+/// Run every phase of the plan. Step failures are recorded in the case reports rather than
+/// returned, so the caller always gets a complete report.
+pub async fn run_plan(plan: TestPlan, plan_path: &Path, port: Port) -> PlanRun {
+    let started = Instant::now();
+    let access = tool::server_access();
+    let mut cases = Vec::with_capacity(plan.cases.len());
+
+    let launched_target = match start_app(&plan, port).await {
+        Ok(target) => target,
+        Err(message) => {
+            return PlanRun {
+                cases:    skip_all(&plan, SETUP_CASE_NAME, started.elapsed(), message),
+                duration: started.elapsed(),
+            };
+        },
+    };
+
+    let setup_started = Instant::now();
+    if let Err(message) = run_steps(&plan.setup, plan_path, port, &access).await {
+        cases = skip_all(&plan, SETUP_CASE_NAME, setup_started.elapsed(), message);
+    } else {
+        for case in &plan.cases {
+            let case_started = Instant::now();
+            let report = match run_steps(&case.steps, plan_path, port, &access).await {
+                Ok(()) => CaseReport::passed(case.name.clone(), case_started.elapsed()),
+                Err(message) => {
+                    CaseReport::failed(case.name.clone(), case_started.elapsed(), message)
+                },
+            };
+            cases.push(report);
+        }
+    }
+
+    let teardown_started = Instant::now();
+    if let Err(message) = run_steps(&plan.teardown, plan_path, port, &access).await {
+        cases.push(CaseReport::failed(
+            TEARDOWN_CASE_NAME.to_string(),
+            teardown_started.elapsed(),
+            message,
+        ));
+    }
+
+    if let Some(target) = launched_target
+        && plan.shutdown
+    {
+        debug!("Shutting down test plan app '{target}' on port {port}");
+        brp_shutdown::shutdown_app(&target, port).await;
+    }
+
+    PlanRun {
+        cases,
+        duration: started.elapsed(),
+    }
+}
+
+/// Launch the plan's app, if any, and wait for BRP. Returns the launched target name.
+///
+/// An app that never becomes ready is shut down again, so a failed run does not leave it running.
+async fn start_app(plan: &TestPlan, port: Port) -> Result<Option<String>, String> {
+    let Some(launch_params) = &plan.launch else {
+        return Ok(None);
+    };
+
+    let mut launch_params = launch_params.clone();
+    launch_params.port = LaunchPort::Fixed(port);
+    launch_params.instance_count = InstanceCount::default();
+    // Readiness is polled below with the plan's own timeout
+    launch_params.verify_brp = Some(false);
+    let target = launch_params.target.clone();
+
+    launch::launch_bevy_target(launch_params, DEFAULT_PROFILE)
+        .await
+        .map_err(|error| format!("launch of '{target}' failed: {error}"))?;
+
+    let deadline = Instant::now() + Duration::from_millis(plan.ready_timeout_ms);
+    loop {
+        if call_brp(READY_PROBE_METHOD, None, port).await.is_ok() {
+            return Ok(Some(target));
+        }
+        if Instant::now() >= deadline {
+            debug!("Shutting down test plan app '{target}' on port {port} after readiness timeout");
+            brp_shutdown::shutdown_app(&target, port).await;
+            return Err(format!(
+                "'{target}' did not respond to BRP on port {port} within {}ms",
+                plan.ready_timeout_ms
+            ));
+        }
+        tokio::time::sleep(Duration::from_millis(DEFAULT_POLL_INTERVAL_MS)).await;
+    }
+}
+
+/// Mark every case skipped and record the failing phase as a failed case.
+fn skip_all(plan: &TestPlan, phase: &str, elapsed: Duration, message: String) -> Vec<CaseReport> {
+    let mut cases = vec![CaseReport::failed(
+        phase.to_string(),
+        elapsed,
+        message.clone(),
+    )];
+    cases.extend(
+        plan.cases.iter().map(|case| {
+            CaseReport::skipped(case.name.clone(), format!("{phase} failed: {message}"))
+        }),
+    );
+    cases
+}
+
+/// Run steps in order, stopping at the first failure.
+///
+/// Each step's method goes through the same access check as the tool that sends it, so a plan
+/// cannot call methods the operator disabled with `--read-only`, `--allow`, or `--deny`.
+async fn run_steps(
+    steps: &[TestStep],
+    plan_path: &Path,
+    port: Port,
+    access: &ToolAccess,
+) -> Result<(), String> {
+    for (index, step) in steps.iter().enumerate() {
+        run_step(step, plan_path, port, access)
+            .await
+            .map_err(|message| format!("step {}: {message}", index + 1))?;
+    }
+    Ok(())
+}
+
+fn check_access(step: &TestStep, access: &ToolAccess) -> Result<(), String> {
+    let method = match step {
+        TestStep::Call { method, .. }
+        | TestStep::WaitFor { method, .. }
+        | TestStep::Assert { method, .. } => method.as_str(),
+        TestStep::Screenshot { .. } => SCREENSHOT_METHOD,
+        TestStep::Wait { .. } => return Ok(()),
+    };
+    if access.allows_brp_method(method) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{method} is disabled by the server's --read-only, --allow, or --deny flags"
+        ))
+    }
+}
+
+async fn run_step(
+    step: &TestStep,
+    plan_path: &Path,
+    port: Port,
+    access: &ToolAccess,
+) -> Result<(), String> {
+    check_access(step, access)?;
+    match step {
+        TestStep::Call { method, params } => {
+            call_brp(method, params.clone(), port).await.map(|_| ())
+        },
+        TestStep::Wait { duration_ms } => {
+            tokio::time::sleep(Duration::from_millis(*duration_ms)).await;
+            Ok(())
+        },
+        TestStep::WaitFor {
+            method,
+            params,
+            path,
+            expect,
+            timeout_ms,
+            interval_ms,
+        } => {
+            wait_for(
+                method,
+                params.as_ref(),
+                path.as_deref(),
+                expect,
+                Duration::from_millis(*timeout_ms),
+                Duration::from_millis(*interval_ms),
+                port,
+            )
+            .await
+        },
+        TestStep::Assert {
+            method,
+            params,
+            path,
+            expect,
+        } => {
+            let response = call_brp(method, params.clone(), port).await?;
+            expect
+                .check_at(response.as_ref(), path.as_deref())
+                .map_err(|message| format!("{method}: {message}"))
+        },
+        TestStep::Screenshot { path } => {
+            let path = plan::resolve_relative(plan_path, path);
+            call_brp(
+                SCREENSHOT_METHOD,
+                Some(json!({ "path": path.to_string_lossy() })),
+                port,
+            )
+            .await
+            .map(|_| ())
+        },
+    }
+}
+
+async fn wait_for(
+    method: &str,
+    params: Option<&Value>,
+    path: Option<&str>,
+    expect: &Expectation,
+    timeout: Duration,
+    interval: Duration,
+    port: Port,
+) -> Result<(), String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let last_error = match call_brp(method, params.cloned(), port).await {
+            Ok(response) => match expect.check_at(response.as_ref(), path) {
+                Ok(()) => return Ok(()),
+                Err(message) => message,
+            },
+            Err(message) => message,
+        };
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "{method}: condition not met within {}ms ({last_error})",
+                timeout.as_millis()
+            ));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn call_brp(
+    method: &str,
+    params: Option<Value>,
+    port: Port,
+) -> Result<Option<Value>, String> {
+    let client = BrpClient::for_application(method.to_string(), port, params);
+    match client.execute_raw().await {
+        Ok(ResponseStatus::Success(value)) => Ok(value),
+        Ok(ResponseStatus::Error(error)) => Err(format!(
+            "{method} returned error {}: {}",
+            error.get_code(),
+            error.get_message()
+        )),
+        Err(error) => Err(format!("{method} failed: {error}")),
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::path::Path;
+
+    use super::Port;
+    use super::TestStep;
+    use super::ToolAccess;
+    use super::run_steps;
+
+    #[tokio::test]
+    async fn denied_methods_fail_the_step_without_calling_the_app() {
+        let access = ToolAccess::from_args(["--read-only".to_string()]).expect("arguments parse");
+        let steps = [TestStep::Call {
+            method: "world.insert_components".to_string(),
+            params: None,
+        }];
+
+        let message = run_steps(&steps, Path::new("plan.json"), Port(1), &access)
+            .await
+            .expect_err("a denied method must fail the plan");
+
+        assert!(message.starts_with("step 1: world.insert_components is disabled"));
+    }
+}
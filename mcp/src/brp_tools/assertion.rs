@@ -0,0 +1,206 @@
+//! Structural comparison of BRP responses against expected JSON, used by [`Expectation`] matchers
+//!
+//! Mismatches are reported per JSON pointer, one line each, so an agent reading a failed assertion
+//! sees exactly which fields differ instead of two full documents.
+//!
+//! [`Expectation`]: super::Expectation
+
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+use super::constants::MAX_REPORTED_MISMATCHES;
+
+/// How leaf values and objects are compared.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Comparison {
+    /// Numbers match when they differ by at most this much. Without it, values must be equal.
+    pub(super) tolerance: Option<f64>,
+    /// Object keys that are not expected are ignored instead of reported.
+    pub(super) partial:   bool,
+}
+
+/// One field that does not match.
+#[derive(Debug, PartialEq)]
+pub(super) struct Mismatch {
+    /// JSON pointer to the field, relative to the compared value.
+    path:     String,
+    expected: String,
+    actual:   String,
+}
+
+/// Every field where `actual` does not match `expected`, in document order.
+pub(super) fn diff(expected: &Value, actual: &Value, comparison: Comparison) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    diff_at(expected, actual, comparison, String::new(), &mut mismatches);
+    mismatches
+}
+
+fn diff_at(
+    expected: &Value,
+    actual: &Value,
+    comparison: Comparison,
+    path: String,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected_fields), Value::Object(actual_fields)) => {
+            for (key, expected_value) in expected_fields {
+                let field_path = format!("{path}/{}", escape_pointer(key));
+                match actual_fields.get(key) {
+                    Some(actual_value) => {
+                        diff_at(
+                            expected_value,
+                            actual_value,
+                            comparison,
+                            field_path,
+                            mismatches,
+                        );
+                    },
+                    None => mismatches.push(Mismatch {
+                        path:     field_path,
+                        expected: expected_value.to_string(),
+                        actual:   "nothing".to_string(),
+                    }),
+                }
+            }
+            if !comparison.partial {
+                for (key, actual_value) in actual_fields {
+                    if !expected_fields.contains_key(key) {
+                        mismatches.push(Mismatch {
+                            path:     format!("{path}/{}", escape_pointer(key)),
+                            expected: "nothing".to_string(),
+                            actual:   actual_value.to_string(),
+                        });
+                    }
+                }
+            }
+        },
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected_item, actual_item)) in
+                expected_items.iter().zip(actual_items).enumerate()
+            {
+                diff_at(
+                    expected_item,
+                    actual_item,
+                    comparison,
+                    format!("{path}/{index}"),
+                    mismatches,
+                );
+            }
+        },
+        (Value::Array(expected_items), Value::Array(actual_items)) => mismatches.push(Mismatch {
+            path,
+            expected: format!("{} items", expected_items.len()),
+            actual: format!("{} items: {actual}", actual_items.len()),
+        }),
+        (Value::Number(expected_number), Value::Number(actual_number))
+            if comparison.tolerance.is_some() =>
+        {
+            let tolerance = comparison.tolerance.unwrap_or_default();
+            let within = expected_number
+                .as_f64()
+                .zip(actual_number.as_f64())
+                .is_some_and(|(expected, actual)| (expected - actual).abs() <= tolerance);
+            if !within {
+                mismatches.push(Mismatch {
+                    path,
+                    expected: format!("{expected_number} ± {tolerance}"),
+                    actual: actual_number.to_string(),
+                });
+            }
+        },
+        _ if expected == actual => {},
+        _ => mismatches.push(Mismatch {
+            path,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }),
+    }
+}
+
+/// Render mismatches as a diff: one `path: expected X, got Y` line per field.
+pub(super) fn render(mismatches: &[Mismatch]) -> String {
+    let mut message = match mismatches {
+        [] => return "values match".to_string(),
+        [_] => "1 difference:".to_string(),
+        _ => format!("{} differences:", mismatches.len()),
+    };
+    for mismatch in mismatches.iter().take(MAX_REPORTED_MISMATCHES) {
+        let path = if mismatch.path.is_empty() {
+            "(value)"
+        } else {
+            mismatch.path.as_str()
+        };
+        let _ = write!(
+            message,
+            "\n  {path}: expected {}, got {}",
+            mismatch.expected, mismatch.actual
+        );
+    }
+    if mismatches.len() > MAX_REPORTED_MISMATCHES {
+        let _ = write!(
+            message,
+            "\n  ... and {} more",
+            mismatches.len() - MAX_REPORTED_MISMATCHES
+        );
+    }
+    message
+}
+
+/// Escape an object key for use as a JSON pointer segment (RFC 6901).
+fn escape_pointer(key: &str) -> String { key.replace('~', "~0").replace('/', "~1") }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Comparison;
+    use super::diff;
+    use super::render;
+
+    const EXACT: Comparison = Comparison {
+        tolerance: None,
+        partial:   false,
+    };
+
+    #[test]
+    fn mismatches_are_reported_per_field() {
+        let expected = json!({ "name": "Player", "translation": [1.0, 2.0, 3.0] });
+        let actual = json!({ "name": "Enemy", "translation": [1.0, 2.5, 3.0], "scale": 1.0 });
+
+        assert_eq!(
+            render(&diff(&expected, &actual, EXACT)),
+            "3 differences:\n  /name: expected \"Player\", got \"Enemy\"\n  /translation/1: \
+             expected 2.0, got 2.5\n  /scale: expected nothing, got 1.0"
+        );
+    }
+
+    #[test]
+    fn tolerance_and_partial_comparison() {
+        let comparison = Comparison {
+            tolerance: Some(0.01),
+            partial:   true,
+        };
+        let expected = json!({ "translation": [1.0, 2.0] });
+
+        assert!(
+            diff(
+                &expected,
+                &json!({ "translation": [1.004, 1.995], "id": 7 }),
+                comparison
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            render(&diff(
+                &expected,
+                &json!({ "translation": [1.0, 2.5] }),
+                comparison
+            )),
+            "1 difference:\n  /translation/1: expected 2.0 ± 0.01, got 2.5"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Per-port cache of the `bevy_brp_extras` methods each app registered
+//!
+//! Apps report their `brp_extras/` methods and parameter schemas through
+//! `brp_extras/capabilities`. The answer is fetched on first use for a port and kept until the app
+//! on that port is launched again or shut down, so a rebuilt app is asked again. Ports that cannot
+//! be reached are not cached.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use serde_json::Value;
+use tracing::debug;
+
+use super::client::BrpClient;
+use super::constants::BRP_EXTRAS_PREFIX;
+use super::constants::CAPABILITIES_METHOD_FIELD;
+use super::constants::CAPABILITIES_METHODS_FIELD;
+use super::constants::CAPABILITIES_PARAMS_SCHEMA_FIELD;
+use super::constants::CAPABILITIES_PLUGIN_VERSION_FIELD;
+use super::constants::JSON_RPC_ERROR_METHOD_NOT_FOUND;
+use super::response_handling::ResponseStatus;
+use crate::brp_tools::Port;
+use crate::tool::BrpMethod;
+
+/// Global capability cache keyed by BRP port
+static CAPABILITY_CACHE: LazyLock<Mutex<HashMap<Port, ExtrasCapabilities>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// What an app's `bevy_brp_extras` reported about itself
+#[derive(Debug, Clone)]
+pub(crate) enum ExtrasCapabilities {
+    /// `brp_extras/capabilities` answered with the registered methods
+    Known {
+        plugin_version: Option<String>,
+        /// Parameter schema of each registered method; `None` for methods without parameters
+        methods:        HashMap<String, Option<Value>>,
+    },
+    /// `brp_extras/capabilities` is not registered: extras are missing or predate the method
+    Unavailable,
+}
+
+impl ExtrasCapabilities {
+    fn from_response(response: &Value) -> Self {
+        let methods = response
+            .get(CAPABILITIES_METHODS_FIELD)
+            .and_then(Value::as_array)
+            .map(|methods| {
+                methods
+                    .iter()
+                    .filter_map(|method| {
+                        let name = method.get(CAPABILITIES_METHOD_FIELD)?.as_str()?;
+                        let schema = method.get(CAPABILITIES_PARAMS_SCHEMA_FIELD).cloned();
+                        Some((name.to_string(), schema))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self::Known {
+            plugin_version: response
+                .get(CAPABILITIES_PLUGIN_VERSION_FIELD)
+                .and_then(Value::as_str)
+                .map(ToString::to_string),
+            methods,
+        }
+    }
+
+    /// Whether `method` is registered; `None` when the app could not say
+    pub(crate) fn supports(&self, method: &str) -> Option<bool> {
+        match self {
+            Self::Known { methods, .. } => Some(methods.contains_key(method)),
+            Self::Unavailable => None,
+        }
+    }
+
+    /// Parameter schema the app reported for `method`
+    pub(crate) fn params_schema(&self, method: &str) -> Option<&Value> {
+        match self {
+            Self::Known { methods, .. } => methods.get(method).and_then(Option::as_ref),
+            Self::Unavailable => None,
+        }
+    }
+
+    pub(crate) fn plugin_version(&self) -> Option<&str> {
+        match self {
+            Self::Known { plugin_version, .. } => plugin_version.as_deref(),
+            Self::Unavailable => None,
+        }
+    }
+
+    /// Registered method names, sorted
+    pub(crate) fn method_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = match self {
+            Self::Known { methods, .. } => methods.keys().map(String::as_str).collect(),
+            Self::Unavailable => Vec::new(),
+        };
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Capabilities of the app on `port`, asking the app if they are not cached yet
+pub(crate) async fn extras_capabilities(port: Port) -> Option<ExtrasCapabilities> {
+    let cached = CAPABILITY_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&port).cloned());
+    match cached {
+        Some(capabilities) => Some(capabilities),
+        None => refresh_extras_capabilities(port).await,
+    }
+}
+
+/// Ask the app on `port` for its capabilities and replace the cached entry
+///
+/// Returns `None`, leaving nothing cached, when the app cannot be reached or answers unexpectedly.
+pub(crate) async fn refresh_extras_capabilities(port: Port) -> Option<ExtrasCapabilities> {
+    let client = BrpClient::new(BrpMethod::BrpExtrasCapabilities, port, None);
+    let capabilities = match client.execute_direct_internal_no_enhancement().await {
+        Ok(ResponseStatus::Success(Some(response))) => ExtrasCapabilities::from_response(&response),
+        Ok(ResponseStatus::Error(error)) if error.get_code() == JSON_RPC_ERROR_METHOD_NOT_FOUND => {
+            ExtrasCapabilities::Unavailable
+        },
+        _ => {
+            forget_extras_capabilities(port);
+            return None;
+        },
+    };
+
+    debug!("Cached extras capabilities for port {port}: {capabilities:?}");
+    if let Ok(mut cache) = CAPABILITY_CACHE.lock() {
+        cache.insert(port, capabilities.clone());
+    }
+    Some(capabilities)
+}
+
+/// Drop the cached capabilities of `port`, e.g. because a different app may now listen on it
+pub(crate) fn forget_extras_capabilities(port: Port) {
+    if let Ok(mut cache) = CAPABILITY_CACHE.lock() {
+        cache.remove(&port);
+    }
+}
+
+/// Whether every app that reported its capabilities lacks the extras `method`
+///
+/// Used to hide tools no known app can serve. `false` for non-extras methods and while no app has
+/// reported capabilities, so nothing is hidden before the MCP server knows better.
+pub(crate) fn extras_method_unsupported(method: &str) -> bool {
+    if !method.starts_with(BRP_EXTRAS_PREFIX) {
+        return false;
+    }
+    let Ok(cache) = CAPABILITY_CACHE.lock() else {
+        return false;
+    };
+    let mut known = cache
+        .values()
+        .filter_map(|capabilities| capabilities.supports(method))
+        .peekable();
+    known.peek().is_some() && known.all(|supported| !supported)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ExtrasCapabilities;
+
+    #[test]
+    fn parses_methods_and_schemas() {
+        let capabilities = ExtrasCapabilities::from_response(&json!({
+            "plugin_version": "0.23.0",
+            "methods": [
+                { "method": "brp_extras/ping", "watching": false },
+                {
+                    "method": "brp_extras/send_keys",
+                    "watching": false,
+                    "params_schema": { "type": "object" }
+                }
+            ]
+        }));
+
+        assert_eq!(capabilities.plugin_version(), Some("0.23.0"));
+        assert_eq!(capabilities.supports("brp_extras/ping"), Some(true));
+        assert_eq!(
+            capabilities.supports("brp_extras/get_diagnostics"),
+            Some(false)
+        );
+        assert!(capabilities.params_schema("brp_extras/ping").is_none());
+        assert_eq!(
+            capabilities.params_schema("brp_extras/send_keys"),
+            Some(&json!({ "type": "object" }))
+        );
+        assert_eq!(
+            capabilities.method_names(),
+            vec!["brp_extras/ping", "brp_extras/send_keys"]
+        );
+    }
+}
@@ -5,13 +5,22 @@
 //! - `execute<R>()`: Primary API with automatic format discovery for result types that support it
 //! - `execute_raw()`: Low-level API for debugging and format discovery engine
 //! - `execute_streaming()`: Specialized API for watch operations with streaming responses
+//!
+//! Requests go over HTTP, or over WebSocket for ports listed in `BRP_MCP_WEBSOCKET_PORTS`.
 
 use reqwest::Response;
 use serde_json::Value;
 use tracing::warn;
 
+use super::capabilities;
+use super::capabilities::ExtrasCapabilities;
+use super::constants::BRP_EXTRAS_ERROR_INVALID_PARAMS;
+use super::constants::BRP_EXTRAS_ERROR_MISSING_PARAMS;
 use super::constants::BRP_EXTRAS_PREFIX;
+use super::constants::CAPABILITIES_PARAMS_SCHEMA_FIELD;
 use super::constants::ERROR_PATTERNS;
+use super::constants::EXTRAS_AVAILABLE_METHODS_FIELD;
+use super::constants::EXTRAS_VERSION_FIELD;
 use super::constants::FORMAT_ERROR_HELP_FIELD;
 use super::constants::FORMAT_ERROR_HELP_MESSAGE;
 use super::constants::FORMAT_ERROR_ORIGINAL_ERROR_FIELD;
@@ -19,14 +28,23 @@ use super::constants::FORMAT_ERROR_SUGGESTED_ACTION;
 use super::constants::FORMAT_ERROR_SUGGESTED_ACTION_FIELD;
 use super::constants::FORMAT_ERROR_TYPE_GUIDE_FIELD;
 use super::constants::JSON_RPC_ERROR_METHOD_NOT_FOUND;
+use super::constants::MISSING_REFLECT_DESERIALIZE_PATTERN;
+use super::constants::MUTATE_REFLECT_SUGGESTED_ACTION;
+use super::constants::WEBSOCKET_PORTS_ENV_VAR;
+use super::format_corrections;
 use super::http_client::BrpHttpClient;
 use super::operation::Operation;
+use super::reconnect;
 use super::response_handling::BrpClientCallJsonResponse;
 use super::response_handling::BrpClientError;
 use super::response_handling::BrpToolConfig;
 use super::response_handling::FormatCorrectionStatus;
 use super::response_handling::ResponseStatus;
 use super::response_handling::ResultStructBrpExt;
+use super::undo_history;
+use super::undo_history::UndoEntry;
+use super::websocket_client;
+use super::websocket_client::BrpWebSocketClient;
 use crate::brp_tools::Port;
 use crate::brp_tools::brp_type_guide;
 use crate::error::Error;
@@ -41,6 +59,7 @@ pub struct BrpClient {
     params:     Option<Value>,
 }
 
+#[derive(Clone)]
 enum BrpMethodName {
     Known(BrpMethod),
     Application(String),
@@ -92,6 +111,14 @@ impl BrpClient {
     /// Appending the type guide to an error is only attempted for result types with
     /// `BrpToolConfig::ADD_TYPE_GUIDE_TO_ERROR = true`. Result types with `ADD_TYPE_GUIDE_TO_ERROR
     /// = false` will return errors immediately without added `TypeGuide` .
+    ///
+    /// The same result types also use format corrections learned on this port: a spawn or
+    /// insert in a shape that failed before is sent corrected, and reports the corrections in
+    /// `format_corrections`. A format error whose values hold math types written as objects, at
+    /// any depth, is retried once with those values rewritten as arrays.
+    ///
+    /// Component and resource mutations record the value they change, so `brp_undo_last` can
+    /// write it back.
     pub async fn execute<R>(&self) -> Result<R>
     where
         R: ResultStructBrpExt<
@@ -104,12 +131,30 @@ impl BrpClient {
             + Send
             + 'static,
     {
+        let operation = self.correctable_operation::<R>();
+        if let Some(operation) = operation
+            && let Some(result) = self.execute_corrected::<R>(operation).await?
+        {
+            return Ok(result);
+        }
+
+        let undo_entry = match self.brp_method.known() {
+            Some(method) => UndoEntry::capture(method, self.port, self.params.as_ref()).await,
+            None => None,
+        };
+
         // ALWAYS execute direct first
         let direct_result = self.execute_direct_internal().await?;
 
         match direct_result {
             ResponseStatus::Success(data) => {
                 // Success - no format discovery needed
+                if let (Some(operation), Some(params)) = (operation, &self.params) {
+                    format_corrections::learn_from_success(self.port, operation, params);
+                }
+                if let Some(undo_entry) = undo_entry {
+                    undo_history::record(self.port, undo_entry);
+                }
                 R::from_brp_client_response((
                     data,
                     None,
@@ -117,8 +162,22 @@ impl BrpClient {
                 ))
             },
             ResponseStatus::Error(err) => {
-                // Check if this result type supports adding the `TypeGuide`
-                if R::ADD_TYPE_GUIDE_TO_ERROR && err.has_format_error_code() {
+                if let (Some(operation), Some(params)) = (operation, &self.params)
+                    && err.has_format_error_code()
+                {
+                    format_corrections::record_failure(self.port, operation, params);
+                    if let Some(result) = self.execute_math_corrected::<R>(operation).await? {
+                        return Ok(result);
+                    }
+                }
+                if let Some(details) = self.mutate_reflect_fallback(&err) {
+                    Err(Error::tool_call_failed_with_details(
+                        "Mutation failed because a type cannot be deserialized",
+                        details,
+                    )
+                    .into())
+                } else if R::ADD_TYPE_GUIDE_TO_ERROR && err.has_format_error_code() {
+                    // This result type supports adding the `TypeGuide`
                     // embed type_guide information
                     self.try_add_type_guide_to_error(&err)
                         .await
@@ -128,6 +187,8 @@ impl BrpClient {
                             )
                             .into())
                         })
+                } else if let Some((message, details)) = self.explain_extras_error(&err).await {
+                    Err(Error::tool_call_failed_with_details(message, details).into())
                 } else {
                     // Regular error - enhance with context if possible
                     let enhanced_message =
@@ -138,6 +199,99 @@ impl BrpClient {
         }
     }
 
+    /// The spawn or insert operation learned format corrections apply to, for result types that
+    /// add type guides to format errors
+    fn correctable_operation<R: BrpToolConfig>(&self) -> Option<Operation> {
+        if !R::ADD_TYPE_GUIDE_TO_ERROR {
+            return None;
+        }
+        self.brp_method
+            .known()
+            .and_then(|method| Operation::try_from(method).ok())
+            .filter(|operation| matches!(operation, Operation::SpawnInsert { .. }))
+    }
+
+    /// Send the request with learned format corrections applied
+    ///
+    /// Returns `None` when no correction applies, or when the corrected request failed; its
+    /// corrections are then forgotten and the caller sends the request as given.
+    async fn execute_corrected<R>(&self, operation: Operation) -> Result<Option<R>>
+    where
+        R: ResultStructBrpExt<
+            Args = (
+                Option<Value>,
+                Option<Vec<Value>>,
+                Option<FormatCorrectionStatus>,
+            ),
+        >,
+    {
+        let Some((params, applied)) = self
+            .params
+            .as_ref()
+            .and_then(|params| format_corrections::apply_corrections(self.port, operation, params))
+        else {
+            return Ok(None);
+        };
+
+        let corrected = Self {
+            brp_method: self.brp_method.clone(),
+            port:       self.port,
+            params:     Some(params),
+        };
+        match corrected.execute_direct_internal().await? {
+            ResponseStatus::Success(data) => R::from_brp_client_response((
+                data,
+                Some(applied),
+                Some(FormatCorrectionStatus::Succeeded),
+            ))
+            .map(Some),
+            ResponseStatus::Error(_) => {
+                format_corrections::forget_corrections(self.port, &applied);
+                Ok(None)
+            },
+        }
+    }
+
+    /// Resend a spawn or insert that failed with a format error, with math values nested
+    /// anywhere in its values rewritten from objects to arrays
+    ///
+    /// Returns `None` when nothing needed rewriting or the rewritten request failed too, so the
+    /// caller reports the original error.
+    async fn execute_math_corrected<R>(&self, operation: Operation) -> Result<Option<R>>
+    where
+        R: ResultStructBrpExt<
+            Args = (
+                Option<Value>,
+                Option<Vec<Value>>,
+                Option<FormatCorrectionStatus>,
+            ),
+        >,
+    {
+        let Some(params) = &self.params else {
+            return Ok(None);
+        };
+        let Some((params, applied)) =
+            format_corrections::correct_math_values(self.port, operation, params).await
+        else {
+            return Ok(None);
+        };
+
+        let corrected = Self {
+            brp_method: self.brp_method.clone(),
+            port:       self.port,
+            params:     Some(params),
+        };
+        match corrected.execute_direct_internal().await? {
+            ResponseStatus::Success(data) => R::from_brp_client_response((
+                data,
+                Some(applied),
+                Some(FormatCorrectionStatus::Succeeded),
+            ))
+            .map(Some),
+            ResponseStatus::Error(_) => Ok(None),
+        }
+    }
+
     /// Low-level BRP execution without format discovery or result transformation
     ///
     /// This method provides direct access to BRP communication without any automatic
@@ -157,15 +311,7 @@ impl BrpClient {
     /// This method is identical to `execute_direct_internal()` but bypasses all error enhancement
     /// to prevent recursion when `TypeSchemaEngine` needs to fetch registry data.
     pub async fn execute_direct_internal_no_enhancement(&self) -> Result<ResponseStatus> {
-        // Create HTTP client with our data
-        let brp_http_client =
-            BrpHttpClient::new(self.brp_method.as_str(), self.port, self.params.clone());
-
-        // Send HTTP request (includes status check)
-        let response = brp_http_client.send_request().await?;
-
-        // Parse JSON-RPC response
-        let brp_response = self.parse_json_response(response).await?;
+        let brp_response = self.send().await?;
 
         // Convert to BrpClientResult with special handling for bevy_brp_extras
         // NO ERROR ENHANCEMENT - return directly
@@ -180,12 +326,24 @@ impl BrpClient {
     /// - Returns the raw response for the caller to process
     /// - Provides the same rich error context as other `BrpClient` methods
     pub async fn execute_streaming(&self) -> Result<Response> {
+        if websocket_client::uses_websocket(self.port) {
+            return Err(Error::BrpCommunication(format!(
+                "{} streams its results, which is not supported over WebSocket (port {} is \
+                 listed in {WEBSOCKET_PORTS_ENV_VAR})",
+                self.brp_method.as_str(),
+                self.port
+            ))
+            .into());
+        }
+
         // Create HTTP client with our data
         let brp_http_client =
             BrpHttpClient::new(self.brp_method.as_str(), self.port, self.params.clone());
 
         // Send HTTP request using streaming version (no timeout, includes status check)
-        let response = brp_http_client.send_streaming_request().await?;
+        let response =
+            reconnect::with_reconnect(self.port, || brp_http_client.send_streaming_request())
+                .await?;
 
         Ok(response)
     }
@@ -195,18 +353,41 @@ impl BrpClient {
     /// version we still allow to be called by bespoke tools like `brp_shutdown` and `brp_status`
     /// and the like.
     async fn execute_direct_internal(&self) -> Result<ResponseStatus> {
+        let brp_response = self.send().await?;
+
+        // Convert to BrpClientResult with special handling for bevy_brp_extras
+        Ok(self.to_response_status(brp_response))
+    }
+
+    /// Send the request over the port's transport and return the JSON-RPC response, reconnecting
+    /// while the app refuses the connection
+    async fn send(&self) -> Result<BrpClientCallJsonResponse> {
+        reconnect::with_reconnect(self.port, || self.send_once()).await
+    }
+
+    async fn send_once(&self) -> Result<BrpClientCallJsonResponse> {
+        if websocket_client::uses_websocket(self.port) {
+            return BrpWebSocketClient::new(
+                self.brp_method.as_str(),
+                self.port,
+                self.params.clone(),
+            )
+            .send_request()
+            .await;
+        }
+
         // Create HTTP client with our data
         let brp_http_client =
             BrpHttpClient::new(self.brp_method.as_str(), self.port, self.params.clone());
 
+        // Held until the body is parsed, capping the requests in flight to this port
+        let _slot = brp_http_client.acquire_slot().await?;
+
         // Send HTTP request (includes status check)
         let response = brp_http_client.send_request().await?;
 
         // Parse JSON-RPC response
-        let brp_response = self.parse_json_response(response).await?;
-
-        // Convert to BrpClientResult with special handling for bevy_brp_extras
-        Ok(self.to_response_status(brp_response))
+        self.parse_json_response(response).await
     }
 
     /// Parse the JSON response from the BRP call to a running bevy app
@@ -264,6 +445,45 @@ impl BrpClient {
         format!("{original_message} (error {error_code})")
     }
 
+    /// Explain a failed extras call with what the app's `bevy_brp_extras` reports it supports
+    ///
+    /// A missing method is reported against the methods the app does register, and a parameter
+    /// error carries the schema the app expects. Returns `None` when the capabilities add nothing.
+    async fn explain_extras_error(&self, error: &BrpClientError) -> Option<(String, Value)> {
+        let method = self.brp_method.as_str();
+        if !method.starts_with(BRP_EXTRAS_PREFIX) {
+            return None;
+        }
+
+        match error.get_code() {
+            JSON_RPC_ERROR_METHOD_NOT_FOUND => {
+                // The cached entry may describe an earlier build of the app
+                let capabilities = capabilities::refresh_extras_capabilities(self.port).await?;
+                if capabilities.supports(method) != Some(false) {
+                    return None;
+                }
+                Some(missing_extras_method_explanation(
+                    method,
+                    self.port,
+                    &capabilities,
+                ))
+            },
+            BRP_EXTRAS_ERROR_MISSING_PARAMS | BRP_EXTRAS_ERROR_INVALID_PARAMS => {
+                let capabilities = capabilities::extras_capabilities(self.port).await?;
+                let schema = capabilities.params_schema(method)?;
+                Some((
+                    format!(
+                        "{} (error {}) - see `{CAPABILITIES_PARAMS_SCHEMA_FIELD}` for the parameters `{method}` accepts",
+                        error.get_message(),
+                        error.get_code()
+                    ),
+                    serde_json::json!({ CAPABILITIES_PARAMS_SCHEMA_FIELD: schema }),
+                ))
+            },
+            _ => None,
+        }
+    }
+
     /// Enhanced format error creation with type guide embedding
     async fn try_add_type_guide_to_error(&self, error: &BrpClientError) -> Result<ResponseStatus> {
         // Step 1: Try parameter-based extraction using Operation enum
@@ -289,6 +509,23 @@ impl BrpClient {
         }
     }
 
+    /// Point a component mutation rejected for a missing `ReflectDeserialize` at
+    /// `brp_extras_mutate_reflect`, which a type guide cannot help with
+    fn mutate_reflect_fallback(&self, error: &BrpClientError) -> Option<Value> {
+        (matches!(
+            self.brp_method.known(),
+            Some(BrpMethod::WorldMutateComponents)
+        ) && error
+            .get_message()
+            .contains(MISSING_REFLECT_DESERIALIZE_PATTERN))
+        .then(|| {
+            serde_json::json!({
+                FORMAT_ERROR_ORIGINAL_ERROR_FIELD: error.get_message(),
+                FORMAT_ERROR_SUGGESTED_ACTION_FIELD: MUTATE_REFLECT_SUGGESTED_ACTION,
+            })
+        })
+    }
+
     /// Create minimal error when no types can be extracted
     fn create_minimal_type_error(error: &BrpClientError) -> Result<ResponseStatus> {
         Err(Error::tool_call_failed_with_details(
@@ -349,6 +586,23 @@ impl BrpClient {
     }
 }
 
+fn missing_extras_method_explanation(
+    method: &str,
+    port: Port,
+    capabilities: &ExtrasCapabilities,
+) -> (String, Value) {
+    let version = capabilities.plugin_version().unwrap_or("unknown");
+    (
+        format!(
+            "`{method}` is not registered by bevy_brp_extras {version} in the app on port {port}. Upgrade bevy_brp_extras, or enable the cargo feature that provides the method"
+        ),
+        serde_json::json!({
+            EXTRAS_VERSION_FIELD: capabilities.plugin_version(),
+            EXTRAS_AVAILABLE_METHODS_FIELD: capabilities.method_names(),
+        }),
+    )
+}
+
 pub(crate) fn method_not_found_message(method: &str, message: &str) -> String {
     if method.starts_with(BRP_EXTRAS_PREFIX) {
         format!(
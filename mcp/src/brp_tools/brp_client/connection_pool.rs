@@ -0,0 +1,192 @@
+//! Per-port pool of keep-alive HTTP clients for BRP requests
+//!
+//! Each `reqwest::Client` owns its own connection pool, so creating one per request forces a new
+//! TCP connection every time. Caching one client per port lets sequential BRP calls reuse idle
+//! connections. Requests to a port wait for one of its connection slots, so a burst of parallel
+//! calls cannot open an unbounded number of connections to the app; watch streams stay open for
+//! their whole lifetime and take no slot. Pool limits can be tuned with
+//! `BRP_MCP_POOL_MAX_CONNECTIONS`, `BRP_MCP_POOL_MAX_IDLE_CONNECTIONS`, and
+//! `BRP_MCP_POOL_IDLE_TIMEOUT_SECS`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use error_stack::Report;
+use reqwest::Client;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+
+use super::constants::DEFAULT_POOL_IDLE_TIMEOUT;
+use super::constants::DEFAULT_POOL_MAX_CONNECTIONS;
+use super::constants::DEFAULT_POOL_MAX_IDLE_CONNECTIONS;
+use super::constants::POOL_IDLE_TIMEOUT_ENV_VAR;
+use super::constants::POOL_MAX_CONNECTIONS_ENV_VAR;
+use super::constants::POOL_MAX_IDLE_CONNECTIONS_ENV_VAR;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Global pool of HTTP clients keyed by BRP port
+static CONNECTION_POOL: LazyLock<ConnectionPool> = LazyLock::new(ConnectionPool::from_env);
+
+/// Limits applied to every pooled client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PoolConfig {
+    /// Maximum requests in flight per port
+    max_connections:      usize,
+    /// Maximum idle keep-alive connections kept open per port
+    max_idle_connections: usize,
+    /// How long an idle connection is kept before it is closed
+    idle_timeout:         Duration,
+}
+
+impl PoolConfig {
+    /// Parse the environment overrides, falling back to the default for each one that is unset or
+    /// invalid
+    fn from_values(
+        max_connections: Option<&str>,
+        max_idle_connections: Option<&str>,
+        idle_timeout_secs: Option<&str>,
+    ) -> Self {
+        Self {
+            // Zero slots would make every request wait forever
+            max_connections:      parse_or_default(max_connections, DEFAULT_POOL_MAX_CONNECTIONS)
+                .max(1),
+            max_idle_connections: parse_or_default(
+                max_idle_connections,
+                DEFAULT_POOL_MAX_IDLE_CONNECTIONS,
+            ),
+            idle_timeout:         idle_timeout_secs
+                .and_then(|value| value.trim().parse().ok())
+                .map_or(DEFAULT_POOL_IDLE_TIMEOUT, Duration::from_secs),
+        }
+    }
+}
+
+fn parse_or_default<T: FromStr>(value: Option<&str>, default: T) -> T {
+    value
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+/// A port's shared client and the slots that cap its requests in flight
+#[derive(Clone)]
+pub(super) struct PooledClient {
+    pub(super) client: Client,
+    slots:             Arc<Semaphore>,
+}
+
+impl PooledClient {
+    /// Wait for a free connection slot, which is released when the returned permit is dropped
+    pub(super) async fn acquire_slot(&self) -> Result<OwnedSemaphorePermit> {
+        Arc::clone(&self.slots).acquire_owned().await.map_err(|_| {
+            Report::new(Error::InvalidState(
+                "BRP connection pool was closed".to_string(),
+            ))
+        })
+    }
+}
+
+struct ConnectionPool {
+    config:  PoolConfig,
+    clients: Mutex<HashMap<Port, PooledClient>>,
+}
+
+impl ConnectionPool {
+    fn from_env() -> Self {
+        let max_connections = std::env::var(POOL_MAX_CONNECTIONS_ENV_VAR).ok();
+        let max_idle_connections = std::env::var(POOL_MAX_IDLE_CONNECTIONS_ENV_VAR).ok();
+        let idle_timeout = std::env::var(POOL_IDLE_TIMEOUT_ENV_VAR).ok();
+
+        Self {
+            config:  PoolConfig::from_values(
+                max_connections.as_deref(),
+                max_idle_connections.as_deref(),
+                idle_timeout.as_deref(),
+            ),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn client_for(&self, port: Port) -> Result<PooledClient> {
+        let mut clients = self.clients.lock().map_err(|_| {
+            Report::new(Error::InvalidState(
+                "BRP connection pool lock poisoned".to_string(),
+            ))
+        })?;
+
+        if let Some(pooled) = clients.get(&port) {
+            // `Client` is an `Arc` handle, so cloning shares the pooled connections
+            return Ok(pooled.clone());
+        }
+
+        let client = Client::builder()
+            .pool_max_idle_per_host(self.config.max_idle_connections)
+            .pool_idle_timeout(self.config.idle_timeout)
+            .build()
+            .map_err(|error| {
+                Report::new(Error::BrpCommunication(format!(
+                    "Failed to create HTTP client for port {port}: {error}"
+                )))
+            })?;
+        let pooled = PooledClient {
+            client,
+            slots: Arc::new(Semaphore::new(self.config.max_connections)),
+        };
+        clients.insert(port, pooled.clone());
+        drop(clients);
+        Ok(pooled)
+    }
+}
+
+/// Get the shared keep-alive client for a BRP port, creating it on first use
+pub(super) fn client_for(port: Port) -> Result<PooledClient> { CONNECTION_POOL.client_for(port) }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::PoolConfig;
+    use crate::brp_tools::brp_client::constants::DEFAULT_POOL_IDLE_TIMEOUT;
+    use crate::brp_tools::brp_client::constants::DEFAULT_POOL_MAX_CONNECTIONS;
+    use crate::brp_tools::brp_client::constants::DEFAULT_POOL_MAX_IDLE_CONNECTIONS;
+
+    const DEFAULT_CONFIG: PoolConfig = PoolConfig {
+        max_connections:      DEFAULT_POOL_MAX_CONNECTIONS,
+        max_idle_connections: DEFAULT_POOL_MAX_IDLE_CONNECTIONS,
+        idle_timeout:         DEFAULT_POOL_IDLE_TIMEOUT,
+    };
+
+    #[test]
+    fn unset_limits_use_the_defaults() {
+        assert_eq!(PoolConfig::from_values(None, None, None), DEFAULT_CONFIG);
+    }
+
+    #[test]
+    fn set_limits_are_parsed() {
+        assert_eq!(
+            PoolConfig::from_values(Some("4"), Some(" 2 "), Some("30")),
+            PoolConfig {
+                max_connections:      4,
+                max_idle_connections: 2,
+                idle_timeout:         Duration::from_secs(30),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_limits_fall_back_to_the_defaults() {
+        assert_eq!(
+            PoolConfig::from_values(Some("many"), Some("-1"), Some("1.5")),
+            DEFAULT_CONFIG
+        );
+        assert_eq!(
+            PoolConfig::from_values(Some("0"), None, None).max_connections,
+            1
+        );
+    }
+}
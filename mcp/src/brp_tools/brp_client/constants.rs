@@ -9,6 +9,8 @@ pub(super) const BRP_EXTRAS_PREFIX: &str = "brp_extras/";
 pub(super) const BRP_HTTP_PROTOCOL: &str = "http";
 /// JSON-RPC path for BRP requests
 pub(super) const BRP_JSONRPC_PATH: &str = "/jsonrpc";
+/// WebSocket protocol for BRP connections through a relay
+pub(super) const BRP_WEBSOCKET_PROTOCOL: &str = "ws";
 /// Maximum characters of the request body to include in error reports
 pub(super) const ERROR_BODY_PREVIEW_CHARS: usize = 500;
 /// MIME type sent in the `Content-Type` header for BRP JSON-RPC requests
@@ -17,6 +19,42 @@ pub(super) const HTTP_CONTENT_TYPE_JSON: &str = "application/json";
 pub(super) const HTTP_HEADER_CONTENT_TYPE: &str = "Content-Type";
 /// Timeout for standard (non-streaming) HTTP requests
 pub(super) const HTTP_REQUEST_TIMEOUT: Duration = std::time::Duration::from_secs(30);
+/// Environment variable listing the ports (comma-separated) reached over WebSocket
+pub(super) const WEBSOCKET_PORTS_ENV_VAR: &str = "BRP_MCP_WEBSOCKET_PORTS";
+
+// capability constants
+pub(super) const CAPABILITIES_METHOD_FIELD: &str = "method";
+pub(super) const CAPABILITIES_METHODS_FIELD: &str = "methods";
+pub(super) const CAPABILITIES_PARAMS_SCHEMA_FIELD: &str = "params_schema";
+pub(super) const CAPABILITIES_PLUGIN_VERSION_FIELD: &str = "plugin_version";
+/// Field of an extras error's details listing the methods the app registered
+pub(super) const EXTRAS_AVAILABLE_METHODS_FIELD: &str = "available_methods";
+/// Field of an extras error's details carrying the app's `bevy_brp_extras` version
+pub(super) const EXTRAS_VERSION_FIELD: &str = "extras_version";
+
+// connection pool constants
+/// Default maximum idle keep-alive connections per port
+pub(super) const DEFAULT_POOL_MAX_IDLE_CONNECTIONS: usize = 8;
+/// Default time an idle pooled connection stays open
+pub(super) const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+/// Default maximum requests in flight per port
+pub(super) const DEFAULT_POOL_MAX_CONNECTIONS: usize = 16;
+/// Environment variable overriding the idle connection timeout, in seconds
+pub(super) const POOL_IDLE_TIMEOUT_ENV_VAR: &str = "BRP_MCP_POOL_IDLE_TIMEOUT_SECS";
+/// Environment variable overriding the maximum requests in flight per port
+pub(super) const POOL_MAX_CONNECTIONS_ENV_VAR: &str = "BRP_MCP_POOL_MAX_CONNECTIONS";
+/// Environment variable overriding the maximum idle connections per port
+pub(super) const POOL_MAX_IDLE_CONNECTIONS_ENV_VAR: &str = "BRP_MCP_POOL_MAX_IDLE_CONNECTIONS";
+
+// reconnect constants
+/// Default number of reconnects after an app refuses the connection
+pub(super) const DEFAULT_RECONNECT_RETRIES: u32 = 0;
+/// Delay before the first reconnect, doubled for each one after it
+pub(super) const RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+/// Longest delay between reconnects
+pub(super) const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(2);
+/// Environment variable overriding the number of reconnects
+pub(super) const RECONNECT_RETRIES_ENV_VAR: &str = "BRP_MCP_RECONNECT_RETRIES";
 
 // error constants
 /// Basically we're trying to to access a field of a struct or a resource with the wrong path - here
@@ -31,6 +69,10 @@ pub(super) const BRP_ERROR_ACCESS_ERROR: i32 = -23_501;
 ///   operations..."
 /// - "The struct accessed doesn't have a '...' field"
 pub(super) const BRP_ERROR_CODE_UNKNOWN_COMPONENT_TYPE: i32 = -23_402;
+/// `bevy_brp_extras` error for a method called without its required parameters
+pub(super) const BRP_EXTRAS_ERROR_MISSING_PARAMS: i32 = -24_000;
+/// `bevy_brp_extras` error for parameters that failed to parse or are out of range
+pub(super) const BRP_EXTRAS_ERROR_INVALID_PARAMS: i32 = -24_001;
 /// "Internal error" (JSON-RPC standard)
 pub(super) const JSON_RPC_ERROR_INTERNAL_ERROR: i32 = -32_603;
 /// "invalid type: ... expected ..." (parameter validation errors)
@@ -54,6 +96,10 @@ pub(super) const FORMAT_ERROR_SUGGESTED_ACTION: &str =
 pub(super) const FORMAT_ERROR_SUGGESTED_ACTION_FIELD: &str = "suggested_action";
 pub(super) const FORMAT_ERROR_TYPE_GUIDE_FIELD: &str = "type_guide";
 
+// missing `ReflectDeserialize` errors, which `brp_extras/mutate_reflect` can work around
+pub(super) const MISSING_REFLECT_DESERIALIZE_PATTERN: &str = "ReflectDeserialize";
+pub(super) const MUTATE_REFLECT_SUGGESTED_ACTION: &str = "A type in this value cannot be deserialized. Use brp_extras_mutate_reflect with the same parameters: it applies the value through reflection field by field, and struct fields left out of the value keep their current values, so leave out the fields that cannot be deserialized";
+
 // json-rpc constants
 pub(super) const JSONRPC_DEFAULT_ID: u64 = 1;
 pub(super) const JSONRPC_FIELD: &str = "jsonrpc";
@@ -0,0 +1,333 @@
+//! Per-port cache of learned format corrections
+//!
+//! A spawn or insert that fails with a format error is remembered per type. When a later call on
+//! the same port succeeds with the same values for that type in a different shape, the change is
+//! learned as a correction: either an object whose field values become an array (`{"x": 1, "y": 2,
+//! "z": 3}` to `[1, 2, 3]` for `Vec3`), or the reverse. Later spawns and inserts that use the
+//! failing shape are corrected before they are sent, so the agent skips the error and retry round
+//! trip. Corrections are dropped when they stop working, or when an app is launched or shut down
+//! on the port.
+//!
+//! A format error can also be corrected without a previous success: math values written as
+//! objects anywhere inside the sent values, such as the `Vec3` fields of structs in a resource's
+//! map, are rewritten as arrays by walking each value against its registry schema.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use super::operation::Operation;
+use crate::brp_tools::Port;
+use crate::brp_tools::brp_type_guide;
+use crate::tool::ParameterName;
+
+/// Values of the last failed spawn or insert per port and type
+static PENDING_FAILURES: LazyLock<Mutex<HashMap<Port, HashMap<String, Value>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Corrections learned per port and type
+static LEARNED_CORRECTIONS: LazyLock<Mutex<HashMap<Port, HashMap<String, Correction>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A shape change that turned a failing value into an accepted one
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Correction {
+    /// Send the object's field values as an array, in this key order
+    ObjectToArray { keys: Vec<String> },
+    /// Send the array as an object with these keys, in order
+    ArrayToObject { keys: Vec<String> },
+}
+
+impl Correction {
+    /// The correction turning `failed` into `succeeded`, if they hold the same values
+    ///
+    /// Values must be distinct, otherwise the key order cannot be told from the values.
+    fn learn(failed: &Value, succeeded: &Value) -> Option<Self> {
+        match (failed, succeeded) {
+            (Value::Object(object), Value::Array(array)) => {
+                key_order(object, array).map(|keys| Self::ObjectToArray { keys })
+            },
+            (Value::Array(array), Value::Object(object)) => {
+                key_order(object, array).map(|keys| Self::ArrayToObject { keys })
+            },
+            _ => None,
+        }
+    }
+
+    /// `value` in the accepted shape, or `None` if it is not in the failing shape
+    fn apply(&self, value: &Value) -> Option<Value> {
+        match (self, value) {
+            (Self::ObjectToArray { keys }, Value::Object(object)) if object.len() == keys.len() => {
+                keys.iter()
+                    .map(|key| object.get(key).cloned())
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array)
+            },
+            (Self::ArrayToObject { keys }, Value::Array(array)) if array.len() == keys.len() => {
+                Some(Value::Object(
+                    keys.iter().cloned().zip(array.iter().cloned()).collect(),
+                ))
+            },
+            _ => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::ObjectToArray { keys } => {
+                format!(
+                    "Converted object to array in field order [{}]",
+                    keys.join(", ")
+                )
+            },
+            Self::ArrayToObject { keys } => {
+                format!(
+                    "Converted array to object with fields [{}]",
+                    keys.join(", ")
+                )
+            },
+        }
+    }
+}
+
+/// Keys of `object` in the order their values appear in `array`
+fn key_order(object: &Map<String, Value>, array: &[Value]) -> Option<Vec<String>> {
+    if object.is_empty() || object.len() != array.len() {
+        return None;
+    }
+    let distinct: HashSet<String> = array.iter().map(Value::to_string).collect();
+    if distinct.len() != array.len() {
+        return None;
+    }
+
+    array
+        .iter()
+        .map(|element| {
+            object
+                .iter()
+                .find(|(_, value)| *value == element)
+                .map(|(key, _)| key.clone())
+        })
+        .collect()
+}
+
+/// Type names and values a spawn or insert sends
+fn typed_values(operation: Operation, params: &Value) -> Vec<(String, Value)> {
+    match operation {
+        Operation::SpawnInsert {
+            parameter_name: ParameterName::Components,
+        } => params
+            .get(ParameterName::Components.as_ref())
+            .and_then(Value::as_object)
+            .map(|components| {
+                components
+                    .iter()
+                    .map(|(type_name, value)| (type_name.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        Operation::SpawnInsert {
+            parameter_name: ParameterName::Value,
+        } => params
+            .get(ParameterName::Resource.as_ref())
+            .and_then(Value::as_str)
+            .zip(params.get(ParameterName::Value.as_ref()))
+            .map(|(type_name, value)| vec![(type_name.to_string(), value.clone())])
+            .unwrap_or_default(),
+        Operation::SpawnInsert { .. } | Operation::Mutate { .. } => Vec::new(),
+    }
+}
+
+/// Replace the value sent for `type_name`
+fn replace_value(operation: Operation, params: &mut Value, type_name: &str, value: Value) {
+    let slot = match operation {
+        Operation::SpawnInsert {
+            parameter_name: ParameterName::Components,
+        } => params
+            .get_mut(ParameterName::Components.as_ref())
+            .and_then(|components| components.get_mut(type_name)),
+        Operation::SpawnInsert {
+            parameter_name: ParameterName::Value,
+        } => params.get_mut(ParameterName::Value.as_ref()),
+        Operation::SpawnInsert { .. } | Operation::Mutate { .. } => None,
+    };
+    if let Some(slot) = slot {
+        *slot = value;
+    }
+}
+
+/// Remember the values of a spawn or insert that failed with a format error
+pub(super) fn record_failure(port: Port, operation: Operation, params: &Value) {
+    let Ok(mut pending) = PENDING_FAILURES.lock() else {
+        return;
+    };
+    pending
+        .entry(port)
+        .or_default()
+        .extend(typed_values(operation, params));
+}
+
+/// Learn corrections from a successful spawn or insert of types that failed before
+pub(super) fn learn_from_success(port: Port, operation: Operation, params: &Value) {
+    let Ok(mut pending) = PENDING_FAILURES.lock() else {
+        return;
+    };
+    let Some(failures) = pending.get_mut(&port) else {
+        return;
+    };
+
+    let learned: Vec<(String, Correction)> = typed_values(operation, params)
+        .into_iter()
+        .filter_map(|(type_name, value)| {
+            let failed = failures.remove(&type_name)?;
+            let correction = Correction::learn(&failed, &value)?;
+            Some((type_name, correction))
+        })
+        .collect();
+    drop(pending);
+
+    if learned.is_empty() {
+        return;
+    }
+    if let Ok(mut corrections) = LEARNED_CORRECTIONS.lock() {
+        corrections.entry(port).or_default().extend(learned);
+    }
+}
+
+/// Params with learned corrections applied, and a description of each one applied
+///
+/// Returns `None` when no learned correction matches the request.
+pub(super) fn apply_corrections(
+    port: Port,
+    operation: Operation,
+    params: &Value,
+) -> Option<(Value, Vec<Value>)> {
+    // Copied out so the lock is not held while the params are rewritten
+    let corrections = LEARNED_CORRECTIONS.lock().ok()?.get(&port)?.clone();
+
+    let mut corrected = params.clone();
+    let mut applied = Vec::new();
+    for (type_name, value) in typed_values(operation, params) {
+        let Some(correction) = corrections.get(&type_name) else {
+            continue;
+        };
+        let Some(corrected_value) = correction.apply(&value) else {
+            continue;
+        };
+        applied.push(json!({
+            "type": type_name,
+            "hint": correction.describe(),
+            "original_value": value,
+            "corrected_value": corrected_value,
+        }));
+        replace_value(operation, &mut corrected, &type_name, corrected_value);
+    }
+
+    (!applied.is_empty()).then_some((corrected, applied))
+}
+
+/// Params with nested math values rewritten from objects to arrays, and a description of each
+/// rewrite
+///
+/// Returns `None` when no math value needed rewriting, or the registry could not be fetched.
+pub(super) async fn correct_math_values(
+    port: Port,
+    operation: Operation,
+    params: &Value,
+) -> Option<(Value, Vec<Value>)> {
+    let values = typed_values(operation, params);
+    if values.is_empty() {
+        return None;
+    }
+    let (corrected_values, corrections) = brp_type_guide::correct_math_values(port, &values)
+        .await
+        .ok()?;
+    if corrections.is_empty() {
+        return None;
+    }
+
+    let mut corrected = params.clone();
+    for ((type_name, _), value) in values.iter().zip(corrected_values) {
+        replace_value(operation, &mut corrected, type_name, value);
+    }
+    let applied = corrections
+        .into_iter()
+        .map(|correction| {
+            json!({
+                "type": correction.type_name,
+                "path": correction.path,
+                "hint": format!("Converted {} object to array", correction.math_type),
+                "original_value": correction.original_value,
+                "corrected_value": correction.corrected_value,
+            })
+        })
+        .collect();
+    Some((corrected, applied))
+}
+
+/// Drop the learned corrections for the types of a corrected request that still failed
+pub(super) fn forget_corrections(port: Port, applied: &[Value]) {
+    let Ok(mut corrections) = LEARNED_CORRECTIONS.lock() else {
+        return;
+    };
+    let Some(corrections) = corrections.get_mut(&port) else {
+        return;
+    };
+    for type_name in applied
+        .iter()
+        .filter_map(|correction| correction.get("type").and_then(Value::as_str))
+    {
+        corrections.remove(type_name);
+    }
+}
+
+/// Drop everything learned about `port`, e.g. because a different build may now listen on it
+pub(crate) fn forget_format_corrections(port: Port) {
+    if let Ok(mut pending) = PENDING_FAILURES.lock() {
+        pending.remove(&port);
+    }
+    if let Ok(mut corrections) = LEARNED_CORRECTIONS.lock() {
+        corrections.remove(&port);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Correction;
+
+    #[test]
+    fn learns_and_applies_object_to_array() {
+        let correction = Correction::learn(
+            &json!({ "x": 1.0, "y": 2.0, "z": 3.0 }),
+            &json!([1.0, 2.0, 3.0]),
+        );
+
+        assert_eq!(
+            correction,
+            Some(Correction::ObjectToArray {
+                keys: vec!["x".to_string(), "y".to_string(), "z".to_string()],
+            })
+        );
+        assert_eq!(
+            correction.and_then(|correction| {
+                correction.apply(&json!({ "z": 6.0, "x": 4.0, "y": 5.0 }))
+            }),
+            Some(json!([4.0, 5.0, 6.0]))
+        );
+    }
+
+    #[test]
+    fn ambiguous_values_are_not_learned() {
+        assert_eq!(
+            Correction::learn(&json!({ "x": 1.0, "y": 1.0 }), &json!([1.0, 1.0])),
+            None
+        );
+    }
+}
@@ -8,9 +8,11 @@ use std::time::UNIX_EPOCH;
 
 use reqwest::Response;
 use serde_json::Value;
+use tokio::sync::OwnedSemaphorePermit;
 use tracing::debug;
 use tracing::warn;
 
+use super::connection_pool;
 use super::constants::BRP_DEFAULT_HOST;
 use super::constants::BRP_HTTP_PROTOCOL;
 use super::constants::BRP_JSONRPC_PATH;
@@ -32,6 +34,7 @@ pub(super) struct BrpHttpClient<'method> {
     params:     Option<Value>,
 }
 
+#[derive(Clone, Copy)]
 enum ReqwestErrorKind {
     Timeout,
     Connection,
@@ -102,11 +105,16 @@ impl<'method> BrpHttpClient<'method> {
         brp_json_rpc_builder.build().to_string()
     }
 
+    /// Wait for one of the port's connection slots, held until the permit is dropped
+    pub(super) async fn acquire_slot(&self) -> Result<OwnedSemaphorePermit> {
+        connection_pool::client_for(self.port)?.acquire_slot().await
+    }
+
     /// Send an HTTP request with timeout
     pub(super) async fn send_request(&self) -> Result<Response> {
         let url = self.build_url();
         let body = self.build_request_body();
-        let client = reqwest::Client::new();
+        let client = connection_pool::client_for(self.port)?.client;
 
         let response = client
             .post(&url)
@@ -130,8 +138,9 @@ impl<'method> BrpHttpClient<'method> {
     pub(super) async fn send_streaming_request(&self) -> Result<Response> {
         let url = self.build_url();
         let body = self.build_request_body();
-        // Create client with no timeout for streaming
-        let client = reqwest::Client::new();
+        // Pooled clients have no client-wide timeout, so streaming requests stay open. They take no
+        // connection slot, since a watch would hold it for its whole lifetime
+        let client = connection_pool::client_for(self.port)?.client;
 
         let response = client
             .post(&url)
@@ -222,7 +231,8 @@ impl<'method> BrpHttpClient<'method> {
         }
 
         // Determine error type and details
-        let error_type = ReqwestErrorKind::classify(&e).label();
+        let kind = ReqwestErrorKind::classify(&e);
+        let error_type = kind.label();
 
         context_info.push(format!("Error type: {error_type}"));
 
@@ -234,7 +244,21 @@ impl<'method> BrpHttpClient<'method> {
             self.brp_method
         );
 
-        Err(error_stack::Report::new(Error::JsonRpc(error_message))
+        // Nothing accepted the connection, so the request never reached an app
+        let error = match kind {
+            ReqwestErrorKind::Connection => Error::AppUnreachable {
+                message:  format!(
+                    "no app accepted the connection on port {} - {error_message}",
+                    self.port
+                ),
+                port:     self.port,
+                attempts: 1,
+            },
+            ReqwestErrorKind::Decode => Error::JsonRpc(error_message),
+            _ => Error::Transport(error_message),
+        };
+
+        Err(error_stack::Report::new(error)
             .attach(context_info.join(", "))
             .attach(format!("Full error: {e:?}"))
             .attach(format!(
@@ -1,17 +1,30 @@
+mod capabilities;
 mod client;
+mod connection_pool;
 mod constants;
+mod format_corrections;
 mod http_client;
 mod json_rpc_builder;
 mod operation;
+mod reconnect;
 mod response_handling;
+mod undo_history;
+mod websocket_client;
 
 // Re-export public items
+pub(crate) use capabilities::extras_method_unsupported;
+pub(crate) use capabilities::forget_extras_capabilities;
+pub(crate) use capabilities::refresh_extras_capabilities;
 pub use client::BrpClient;
 pub(crate) use client::method_not_found_message;
 // Re-export error constant needed by external modules
 pub use constants::JSON_RPC_ERROR_METHOD_NOT_FOUND;
+pub(crate) use format_corrections::forget_format_corrections;
 // Re-export types needed by result_struct macro and client operations
 pub use response_handling::BrpToolConfig;
 pub use response_handling::FormatCorrectionStatus;
 pub use response_handling::ResponseStatus;
 pub use response_handling::ResultStructBrpExt;
+pub use undo_history::UndoEntry;
+pub(crate) use undo_history::forget_undo_history;
+pub(crate) use undo_history::undo_last;
@@ -0,0 +1,92 @@
+//! Reconnecting to apps that refuse the connection
+//!
+//! A refused connection means the request never reached the app, so resending it is safe for every
+//! method, mutations included. This is what an app restarting or still starting up looks like.
+//! Set `BRP_MCP_RECONNECT_RETRIES` to reconnect that many times, waiting
+//! `RECONNECT_INITIAL_BACKOFF` and doubling the wait up to `RECONNECT_MAX_BACKOFF`. By default an
+//! unreachable app is reported right away as `Error::AppUnreachable`.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use tracing::debug;
+
+use super::constants::DEFAULT_RECONNECT_RETRIES;
+use super::constants::RECONNECT_INITIAL_BACKOFF;
+use super::constants::RECONNECT_MAX_BACKOFF;
+use super::constants::RECONNECT_RETRIES_ENV_VAR;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Reconnect budget, read once from the environment
+static RECONNECT_RETRIES: LazyLock<u32> = LazyLock::new(|| {
+    std::env::var(RECONNECT_RETRIES_ENV_VAR)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_RECONNECT_RETRIES)
+});
+
+/// Run `send`, reconnecting with exponential backoff while the app refuses the connection
+pub(super) async fn with_reconnect<T, F, Fut>(port: Port, mut send: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let retries = *RECONNECT_RETRIES;
+    let mut attempts = 1;
+    loop {
+        match send().await {
+            Err(report) if matches!(report.current_context(), Error::AppUnreachable { .. }) => {
+                if attempts > retries {
+                    if attempts == 1 {
+                        return Err(report);
+                    }
+                    let message = match report.current_context() {
+                        Error::AppUnreachable { message, .. } => {
+                            format!("{message} (gave up after {attempts} attempts)")
+                        },
+                        other => other.to_string(),
+                    };
+                    return Err(report.change_context(Error::AppUnreachable {
+                        message,
+                        port,
+                        attempts,
+                    }));
+                }
+                let delay = backoff(attempts - 1);
+                debug!(
+                    "Port {port} refused the connection, reconnecting in {delay:?} \
+                     ({attempts}/{retries})"
+                );
+                tokio::time::sleep(delay).await;
+                attempts += 1;
+            },
+            result => return result,
+        }
+    }
+}
+
+/// Delay before reconnect number `retry`, counting from 0
+fn backoff(retry: u32) -> Duration {
+    RECONNECT_INITIAL_BACKOFF
+        .saturating_mul(2_u32.saturating_pow(retry))
+        .min(RECONNECT_MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::backoff;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let delays: Vec<Duration> = (0..6).map(backoff).collect();
+        assert_eq!(
+            delays,
+            [200, 400, 800, 1600, 2000, 2000].map(Duration::from_millis)
+        );
+        assert_eq!(backoff(u32::MAX), Duration::from_secs(2));
+    }
+}
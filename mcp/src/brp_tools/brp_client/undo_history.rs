@@ -0,0 +1,245 @@
+//! Per-port history of component and resource mutations, for `brp_undo_last`
+//!
+//! Before `world.mutate_components` or `world.mutate_resources` is sent through
+//! [`BrpClient::execute`](super::BrpClient::execute), the whole value it is about to change is
+//! read. When the mutation succeeds, that prior value is pushed onto the port's history, and
+//! `brp_undo_last` writes the most recent one back. Mutations whose prior value could not be read
+//! are not recorded. History is dropped when an app is launched or shut down on the port.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::client::BrpClient;
+use super::response_handling::ResponseStatus;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::ParameterName;
+
+/// Mutations kept per port; the oldest are dropped first
+const MAX_UNDO_ENTRIES: usize = 64;
+
+/// Undoable mutations per port, most recent last
+static UNDO_HISTORY: LazyLock<Mutex<HashMap<Port, VecDeque<UndoEntry>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A value as it was before a mutation changed it
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UndoEntry {
+    /// A component on an entity
+    Component {
+        /// Entity the component is on
+        entity:    u64,
+        /// Fully-qualified component type
+        component: String,
+        /// The whole component before the mutation
+        value:     Value,
+    },
+    /// A resource
+    Resource {
+        /// Fully-qualified resource type
+        resource: String,
+        /// The whole resource before the mutation
+        value:    Value,
+    },
+}
+
+impl UndoEntry {
+    /// Read the value a `method` call with `params` is about to change, if it is an undoable
+    /// mutation and the value can be read
+    pub(super) async fn capture(
+        method: BrpMethod,
+        port: Port,
+        params: Option<&Value>,
+    ) -> Option<Self> {
+        let params = params?;
+        let field = |name: ParameterName| params.get(name.as_ref());
+        match method {
+            BrpMethod::WorldMutateComponents => {
+                let entity = field(ParameterName::Entity)?.as_u64()?;
+                let component = field(ParameterName::Component)?.as_str()?.to_string();
+                let value = read(
+                    BrpMethod::WorldGetComponents,
+                    port,
+                    json!({ "entity": entity, "components": [component], "strict": true }),
+                )
+                .await?
+                .get("components")?
+                .get(&component)?
+                .clone();
+                Some(Self::Component {
+                    entity,
+                    component,
+                    value,
+                })
+            },
+            BrpMethod::WorldMutateResources => {
+                let resource = field(ParameterName::Resource)?.as_str()?.to_string();
+                let value = read(
+                    BrpMethod::WorldGetResources,
+                    port,
+                    json!({ "resource": resource }),
+                )
+                .await?
+                .get("value")?
+                .clone();
+                Some(Self::Resource { resource, value })
+            },
+            _ => None,
+        }
+    }
+
+    /// Write the prior value back
+    ///
+    /// An empty path replaces the whole value in place, without re-inserting a component.
+    async fn restore(&self, port: Port) -> Result<()> {
+        let (method, params) = match self {
+            Self::Component {
+                entity,
+                component,
+                value,
+            } => (
+                BrpMethod::WorldMutateComponents,
+                json!({ "entity": entity, "component": component, "path": "", "value": value }),
+            ),
+            Self::Resource { resource, value } => (
+                BrpMethod::WorldMutateResources,
+                json!({ "resource": resource, "path": "", "value": value }),
+            ),
+        };
+        // `execute_raw` so the restore itself is not recorded
+        match BrpClient::new(method, port, Some(params))
+            .execute_raw()
+            .await?
+        {
+            ResponseStatus::Success(_) => Ok(()),
+            ResponseStatus::Error(error) => Err(Error::tool_call_failed(format!(
+                "Failed to restore {}: {}",
+                self.describe(),
+                error.get_message()
+            ))
+            .into()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Component {
+                entity, component, ..
+            } => format!("{component} on entity {entity}"),
+            Self::Resource { resource, .. } => resource.clone(),
+        }
+    }
+}
+
+async fn read(method: BrpMethod, port: Port, params: Value) -> Option<Value> {
+    match BrpClient::new(method, port, Some(params))
+        .execute_raw()
+        .await
+    {
+        Ok(ResponseStatus::Success(value)) => value,
+        _ => None,
+    }
+}
+
+/// Push a successful mutation's prior value onto the port's history
+pub(super) fn record(port: Port, entry: UndoEntry) {
+    if let Ok(mut history) = UNDO_HISTORY.lock() {
+        let history = history.entry(port).or_default();
+        if history.len() == MAX_UNDO_ENTRIES {
+            history.pop_front();
+        }
+        history.push_back(entry);
+    }
+}
+
+/// Restore the most recent recorded mutation on `port`
+///
+/// Returns the restored entry and how many remain. If the restore fails, the entry stays on the
+/// history so it can be retried.
+pub(crate) async fn undo_last(port: Port) -> Result<(UndoEntry, usize)> {
+    let entry = UNDO_HISTORY
+        .lock()
+        .map_err(|_| Error::General("Undo history is poisoned".to_string()))?
+        .get_mut(&port)
+        .and_then(VecDeque::pop_back)
+        .ok_or_else(|| {
+            Error::tool_call_failed(format!(
+                "Nothing to undo on port {port}; only world_mutate_components and \
+                 world_mutate_resources calls made since the app was launched are recorded"
+            ))
+        })?;
+
+    if let Err(error) = entry.restore(port).await {
+        record(port, entry);
+        return Err(error);
+    }
+    let remaining = UNDO_HISTORY
+        .lock()
+        .ok()
+        .and_then(|history| history.get(&port).map(VecDeque::len))
+        .unwrap_or_default();
+    Ok((entry, remaining))
+}
+
+/// Drop the history of `port`, e.g. because a different app may now listen on it
+pub(crate) fn forget_undo_history(port: Port) {
+    if let Ok(mut history) = UNDO_HISTORY.lock() {
+        history.remove(&port);
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::json;
+
+    use super::MAX_UNDO_ENTRIES;
+    use super::UNDO_HISTORY;
+    use super::UndoEntry;
+    use super::record;
+    use crate::brp_tools::Port;
+
+    const TEST_PORT: Port = Port(25_717);
+
+    #[test]
+    fn history_keeps_the_most_recent_entries() {
+        let port = TEST_PORT;
+        for index in 0..=MAX_UNDO_ENTRIES {
+            record(
+                port,
+                UndoEntry::Resource {
+                    resource: format!("Resource{index}"),
+                    value:    json!(index),
+                },
+            );
+        }
+
+        let history = UNDO_HISTORY
+            .lock()
+            .expect("history lock")
+            .get(&port)
+            .cloned()
+            .expect("history was recorded");
+        assert_eq!(history.len(), MAX_UNDO_ENTRIES);
+        assert_eq!(
+            history.front(),
+            Some(&UndoEntry::Resource {
+                resource: "Resource1".to_string(),
+                value:    json!(1),
+            })
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! WebSocket client for BRP (Bevy Remote Protocol) communication
+//!
+//! WASM apps cannot open an HTTP server, so their BRP traffic goes through a WebSocket relay such
+//! as the `wasm-server-runner` fork in `.cargo/config.toml`. Ports listed in
+//! `BRP_MCP_WEBSOCKET_PORTS` are reached over `ws://` instead of HTTP: each request opens a
+//! connection, sends the JSON-RPC request as one text message, and reads the reply from the next
+//! text message. Streaming methods (watches) are not supported over WebSocket.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use error_stack::Report;
+use futures::SinkExt;
+use futures::StreamExt;
+use serde_json::Value;
+use tokio_tungstenite::tungstenite;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::debug;
+use tracing::warn;
+
+use super::constants::BRP_DEFAULT_HOST;
+use super::constants::BRP_JSONRPC_PATH;
+use super::constants::BRP_WEBSOCKET_PROTOCOL;
+use super::constants::HTTP_REQUEST_TIMEOUT;
+use super::constants::WEBSOCKET_PORTS_ENV_VAR;
+use super::json_rpc_builder::BrpJsonRpcBuilder;
+use super::response_handling::BrpClientCallJsonResponse;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Ports configured to use the WebSocket transport
+static WEBSOCKET_PORTS: LazyLock<HashSet<Port>> = LazyLock::new(|| {
+    std::env::var(WEBSOCKET_PORTS_ENV_VAR)
+        .map(|ports| parse_ports(&ports))
+        .unwrap_or_default()
+});
+
+/// Whether requests to `port` go over WebSocket rather than HTTP
+pub(super) fn uses_websocket(port: Port) -> bool { WEBSOCKET_PORTS.contains(&port) }
+
+/// Parse a comma-separated port list, skipping entries that are not valid ports
+fn parse_ports(ports: &str) -> HashSet<Port> {
+    ports
+        .split(',')
+        .filter_map(|port| port.trim().parse::<u16>().ok())
+        .filter_map(|port| Port::try_from(port).ok())
+        .collect()
+}
+
+/// WebSocket client for BRP communication
+pub(super) struct BrpWebSocketClient<'method> {
+    brp_method: &'method str,
+    port:       Port,
+    params:     Option<Value>,
+}
+
+impl<'method> BrpWebSocketClient<'method> {
+    /// Create a new BRP WebSocket client
+    pub(super) const fn new(brp_method: &'method str, port: Port, params: Option<Value>) -> Self {
+        Self {
+            brp_method,
+            port,
+            params,
+        }
+    }
+
+    /// Build the relay URL for this client's port
+    fn build_url(&self) -> String {
+        format!(
+            "{BRP_WEBSOCKET_PROTOCOL}://{BRP_DEFAULT_HOST}:{}{BRP_JSONRPC_PATH}",
+            self.port
+        )
+    }
+
+    /// Send the request and wait for its JSON-RPC response
+    pub(super) async fn send_request(&self) -> Result<BrpClientCallJsonResponse> {
+        tokio::time::timeout(HTTP_REQUEST_TIMEOUT, self.exchange())
+            .await
+            .map_err(|_| {
+                self.error(format!(
+                    "No response within {}s",
+                    HTTP_REQUEST_TIMEOUT.as_secs()
+                ))
+            })?
+    }
+
+    async fn exchange(&self) -> Result<BrpClientCallJsonResponse> {
+        let url = self.build_url();
+        let mut builder = BrpJsonRpcBuilder::new(self.brp_method);
+        if let Some(ref params) = self.params {
+            builder = builder.params(params.clone());
+        }
+        let body = builder.build().to_string();
+
+        let (mut socket, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .map_err(|e| {
+                warn!("BRP WebSocket connection to {url} failed - error={e}");
+                match &e {
+                    tungstenite::Error::Io(io)
+                        if io.kind() == std::io::ErrorKind::ConnectionRefused =>
+                    {
+                        Report::new(Error::AppUnreachable {
+                            message:  format!(
+                                "no relay accepted the connection on port {} - {e}",
+                                self.port
+                            ),
+                            port:     self.port,
+                            attempts: 1,
+                        })
+                    },
+                    _ => self.error(format!("Connection failed: {e}")),
+                }
+            })?;
+        debug!("BRP WebSocket connected to {url}");
+
+        socket
+            .send(Message::text(body))
+            .await
+            .map_err(|e| self.error(format!("Send failed: {e}")))?;
+
+        while let Some(message) = socket.next().await {
+            let message = message.map_err(|e| self.error(format!("Receive failed: {e}")))?;
+            match message {
+                Message::Text(_) | Message::Binary(_) => {
+                    let _ = socket.close(None).await;
+                    let text = message.to_text().map_err(|e| {
+                        Report::new(Error::JsonRpc(format!("Response is not UTF-8: {e}")))
+                            .attach(format!("Method: {}, Port: {}", self.brp_method, self.port))
+                    })?;
+                    return serde_json::from_str(text).map_err(|e| {
+                        Report::new(Error::JsonRpc("JSON parsing failed".to_string()))
+                            .attach("Failed to parse BRP response JSON")
+                            .attach(format!("Method: {}, Port: {}", self.brp_method, self.port))
+                            .attach(format!("Error: {e}"))
+                    });
+                },
+                Message::Close(_) => break,
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {},
+            }
+        }
+
+        Err(self.error("Relay closed the connection before responding".to_string()))
+    }
+
+    fn error(&self, message: String) -> Report<Error> {
+        Report::new(Error::Transport(format!(
+            "WebSocket request failed for {} operation - {message}",
+            self.brp_method
+        )))
+        .attach(format!(
+            "Method: {}, Port: {}, URL: {}",
+            self.brp_method,
+            self.port,
+            self.build_url()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_ports;
+    use crate::brp_tools::Port;
+
+    #[test]
+    fn parses_listed_ports_and_skips_invalid_entries() {
+        let ports = parse_ports("15702, 15703,,abc,80");
+
+        assert_eq!(ports.len(), 2);
+        assert!(ports.contains(&Port(15_702)));
+        assert!(ports.contains(&Port(15_703)));
+    }
+}
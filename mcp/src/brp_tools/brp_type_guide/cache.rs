@@ -0,0 +1,225 @@
+//! Per-port cache of built type guides
+//!
+//! Agents ask for the same types over and over, and building guides for every registered type is
+//! the slowest part of `brp_all_type_guides`. Each guide is cached per port together with a
+//! checksum of the registry schemas it was built from: the type's own schema and every schema it
+//! reaches through `$ref`.
+//!
+//! While the process that served the cached guides still listens on the port, they are returned
+//! without contacting the app. After a restart the registry is fetched again and only guides whose
+//! checksum changed are rebuilt. Launching or shutting down an app through this server drops the
+//! port's cache, like the extras capability cache.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use super::brp_type_name::BrpTypeName;
+use super::guide::TypeGuide;
+use super::tool_type_guide::collect_type_refs;
+use crate::brp_tools::Port;
+
+/// Global type guide cache keyed by BRP port
+static TYPE_GUIDE_CACHE: LazyLock<Mutex<HashMap<Port, PortTypeGuides>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Guides built for the app on one port
+struct PortTypeGuides {
+    /// Process that was listening when the guides were last validated; `None` for apps without a
+    /// local listener, whose guides are always revalidated against the registry
+    owner_pid: Option<u32>,
+    guides:    HashMap<BrpTypeName, CachedGuide>,
+}
+
+struct CachedGuide {
+    registry_checksum: u64,
+    guide:             TypeGuide,
+}
+
+/// Cached guides for every requested type, if the process they were built for still serves `port`
+pub(super) fn cached_guides(
+    port: Port,
+    owner_pid: Option<u32>,
+    requested_types: &[String],
+) -> Option<HashMap<BrpTypeName, TypeGuide>> {
+    let owner_pid = owner_pid?;
+    TYPE_GUIDE_CACHE
+        .lock()
+        .ok()?
+        .get(&port)
+        .filter(|entry| entry.owner_pid == Some(owner_pid))
+        .and_then(|entry| {
+            requested_types
+                .iter()
+                .map(|type_name| {
+                    let type_name = BrpTypeName::from(type_name.as_str());
+                    let guide = entry.guides.get(&type_name)?.guide.clone();
+                    Some((type_name, guide))
+                })
+                .collect()
+        })
+}
+
+/// The cached guide for `type_name` if it was built from a registry with the same checksum
+pub(super) fn reusable_guide(
+    port: Port,
+    type_name: &BrpTypeName,
+    registry_checksum: u64,
+) -> Option<TypeGuide> {
+    let cache = TYPE_GUIDE_CACHE.lock().ok()?;
+    cache
+        .get(&port)?
+        .guides
+        .get(type_name)
+        .filter(|cached| cached.registry_checksum == registry_checksum)
+        .map(|cached| cached.guide.clone())
+}
+
+/// Cache guides validated against the registry `owner_pid` served
+///
+/// When the owner changed, guides that were not revalidated in this request are dropped, so the
+/// fast path never returns a guide built for a previous process.
+pub(super) fn store_guides(
+    port: Port,
+    owner_pid: Option<u32>,
+    guides: impl IntoIterator<Item = (BrpTypeName, u64, TypeGuide)>,
+) {
+    let Ok(mut cache) = TYPE_GUIDE_CACHE.lock() else {
+        return;
+    };
+    let entry = cache.entry(port).or_insert_with(|| PortTypeGuides {
+        owner_pid,
+        guides: HashMap::new(),
+    });
+    if entry.owner_pid != owner_pid {
+        entry.owner_pid = owner_pid;
+        entry.guides.clear();
+    }
+
+    entry.guides.extend(
+        guides
+            .into_iter()
+            .map(|(type_name, registry_checksum, guide)| {
+                (
+                    type_name,
+                    CachedGuide {
+                        registry_checksum,
+                        guide,
+                    },
+                )
+            }),
+    );
+}
+
+/// Drop the cached guides of `port`, e.g. because a different build may now listen on it
+pub(crate) fn forget_type_guides(port: Port) {
+    if let Ok(mut cache) = TYPE_GUIDE_CACHE.lock() {
+        cache.remove(&port);
+    }
+}
+
+/// Registry checksums for one registry fetch, hashing each schema only once
+pub(super) struct RegistryChecksums<'registry> {
+    registry:      &'registry HashMap<BrpTypeName, Value>,
+    schema_hashes: HashMap<&'registry BrpTypeName, u64>,
+}
+
+impl<'registry> RegistryChecksums<'registry> {
+    pub(super) fn new(registry: &'registry HashMap<BrpTypeName, Value>) -> Self {
+        let schema_hashes = registry
+            .iter()
+            .map(|(type_name, schema)| {
+                let mut hasher = DefaultHasher::new();
+                schema.to_string().hash(&mut hasher);
+                (type_name, hasher.finish())
+            })
+            .collect();
+        Self {
+            registry,
+            schema_hashes,
+        }
+    }
+
+    /// Checksum of the schemas a guide for `type_name` is built from
+    ///
+    /// Covers the type's schema and every schema reachable from it through `$ref`, in a stable
+    /// order. Types missing from the registry hash as absent, so registering them later changes
+    /// the checksum.
+    pub(super) fn checksum(&self, type_name: &BrpTypeName) -> u64 {
+        let mut reachable = HashSet::new();
+        let mut pending = vec![type_name.to_string()];
+        while let Some(type_path) = pending.pop() {
+            if !reachable.insert(type_path.clone()) {
+                continue;
+            }
+            if let Some(schema) = self.registry.get(&BrpTypeName::from(type_path.as_str())) {
+                collect_type_refs(schema, &mut pending);
+            }
+        }
+
+        let mut reachable: Vec<String> = reachable.into_iter().collect();
+        reachable.sort_unstable();
+        let mut hasher = DefaultHasher::new();
+        for type_path in reachable {
+            type_path.hash(&mut hasher);
+            self.schema_hashes
+                .get(&BrpTypeName::from(type_path.as_str()))
+                .hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::BrpTypeName;
+    use super::RegistryChecksums;
+
+    #[test]
+    fn checksum_changes_with_referenced_schemas_only() {
+        let player = BrpTypeName::from("game::Player");
+        let mut registry = HashMap::from([
+            (
+                player.clone(),
+                json!({ "properties": { "speed": { "type": { "$ref": "#/$defs/game::Speed" } } } }),
+            ),
+            (
+                BrpTypeName::from("game::Speed"),
+                json!({ "type": "number" }),
+            ),
+            (
+                BrpTypeName::from("game::Unrelated"),
+                json!({ "type": "string" }),
+            ),
+        ]);
+        let original = RegistryChecksums::new(&registry).checksum(&player);
+
+        registry.insert(
+            BrpTypeName::from("game::Unrelated"),
+            json!({ "type": "boolean" }),
+        );
+        assert_eq!(
+            RegistryChecksums::new(&registry).checksum(&player),
+            original
+        );
+
+        registry.insert(
+            BrpTypeName::from("game::Speed"),
+            json!({ "type": "object" }),
+        );
+        assert_ne!(
+            RegistryChecksums::new(&registry).checksum(&player),
+            original
+        );
+    }
+}
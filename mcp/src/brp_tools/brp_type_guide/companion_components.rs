@@ -0,0 +1,134 @@
+//! Companion components a component needs to produce a visible, functional entity
+//!
+//! Two sources feed the list:
+//! - `required`: the component's `#[require]` components, read from `componentInfo` in the registry
+//!   schema. Bevy inserts these with default values when the component is spawned.
+//! - `recommended`: a curated table of companions Bevy does not insert, such as the material that
+//!   makes a `Mesh3d` render. Spawning without them succeeds but leaves an inert entity.
+use serde::Serialize;
+use serde_json::Value;
+
+use super::constants::COMPANION_REQUIRED_REASON;
+use super::constants::TYPE_BEVY_MESH_MATERIAL_2D_COLOR;
+use super::constants::TYPE_BEVY_MESH_MATERIAL_3D_STANDARD;
+use super::constants::TYPE_BEVY_MESH2D;
+use super::constants::TYPE_BEVY_MESH3D;
+use super::response::BrpTypeName;
+use crate::support::JsonObjectAccess;
+use crate::support::SchemaField;
+
+/// Companions Bevy does not insert: `(component, companion, reason)`
+const RECOMMENDED_COMPANIONS: &[(&str, &str, &str)] = &[
+    (
+        TYPE_BEVY_MESH3D,
+        TYPE_BEVY_MESH_MATERIAL_3D_STANDARD,
+        "A Mesh3d renders nothing without a material; any MeshMaterial3d<M> works.",
+    ),
+    (
+        TYPE_BEVY_MESH2D,
+        TYPE_BEVY_MESH_MATERIAL_2D_COLOR,
+        "A Mesh2d renders nothing without a material; any MeshMaterial2d<M> works.",
+    ),
+];
+
+/// Where a companion entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompanionSource {
+    /// Listed by the component's `#[require]` attribute and inserted by Bevy automatically
+    Required,
+    /// From the curated table; must be added to the spawn explicitly
+    Recommended,
+}
+
+/// A component that should accompany the guided type on the same entity
+#[derive(Debug, Clone, Serialize)]
+pub struct CompanionComponent {
+    /// Fully-qualified type name of the companion
+    pub type_name: BrpTypeName,
+    /// Whether Bevy inserts the companion or the caller must
+    pub source:    CompanionSource,
+    /// Why the companion matters
+    pub reason:    &'static str,
+}
+
+/// Collect required companions from the registry followed by curated recommendations
+pub(super) fn companion_components(
+    type_name: &BrpTypeName,
+    registry_schema: &Value,
+) -> Vec<CompanionComponent> {
+    let mut companions: Vec<CompanionComponent> = required_components(registry_schema)
+        .into_iter()
+        .map(|companion| CompanionComponent {
+            type_name: companion,
+            source:    CompanionSource::Required,
+            reason:    COMPANION_REQUIRED_REASON,
+        })
+        .collect();
+
+    for &(component, companion, reason) in RECOMMENDED_COMPANIONS {
+        let companion = BrpTypeName::from(companion);
+        if type_name.as_str() != component
+            || companions.iter().any(|known| known.type_name == companion)
+        {
+            continue;
+        }
+        companions.push(CompanionComponent {
+            type_name: companion,
+            source: CompanionSource::Recommended,
+            reason,
+        });
+    }
+
+    companions
+}
+
+/// Type paths listed in `componentInfo.requiredComponents`, as strings or `{ typePath }` objects
+fn required_components(registry_schema: &Value) -> Vec<BrpTypeName> {
+    registry_schema
+        .get_field(SchemaField::ComponentInfo)
+        .and_then(|info| info.get_field_array(SchemaField::RequiredComponents))
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(|entry| {
+                    entry
+                        .as_str()
+                        .or_else(|| entry.get_field_str(SchemaField::TypePath))
+                })
+                .map(BrpTypeName::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::CompanionSource;
+    use super::companion_components;
+    use crate::brp_tools::brp_type_guide::BrpTypeName;
+    use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_MESH_MATERIAL_3D_STANDARD;
+    use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_MESH3D;
+
+    const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+
+    #[test]
+    fn mesh3d_lists_required_transform_and_recommended_material() {
+        let schema = json!({
+            "componentInfo": { "requiredComponents": [TRANSFORM] }
+        });
+
+        let companions = companion_components(&BrpTypeName::from(TYPE_BEVY_MESH3D), &schema);
+
+        assert_eq!(companions.len(), 2);
+        assert_eq!(companions[0].type_name.as_str(), TRANSFORM);
+        assert_eq!(companions[0].source, CompanionSource::Required);
+        assert_eq!(
+            companions[1].type_name.as_str(),
+            TYPE_BEVY_MESH_MATERIAL_3D_STANDARD
+        );
+        assert_eq!(companions[1].source, CompanionSource::Recommended);
+    }
+}
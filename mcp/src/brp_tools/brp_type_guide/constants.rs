@@ -6,8 +6,15 @@ use crate::support::JsonObjectAccess;
 // agent guidance constants
 /// Base warning message for AI agents about mutation paths
 pub(super) const AGENT_GUIDANCE: &str = "The 'mutation_paths' field provides valid 'path' arguments for 'mcp__brp__world_mutate_components' and 'mcp__brp__world_mutate_resources' tools, with example values suitable for testing.";
+/// Additional guidance when the type has companion components
+pub(super) const COMPANION_GUIDANCE: &str = " This component works together with the types in 'companion_components'. Entries with source 'required' are inserted by Bevy with default values, so include them in a spawn only to set other values. Entries with source 'recommended' are not inserted automatically; spawn them alongside this component or the entity may be invisible or inert.";
+/// Reason reported for companions listed by the component's `#[require]` attribute
+pub(super) const COMPANION_REQUIRED_REASON: &str =
+    "Required by this component; Bevy inserts it with default values if the spawn omits it.";
 /// Additional warning when Entity fields are present (with placeholder for entity ID)
-pub(super) const ENTITY_WARNING: &str = " CAUTION: This type contains bevy_ecs::entity::Entity fields - you must use valid Entity IDs from the running app to replace the example value '{}'. Invalid Entity values may crash the application.";
+pub(super) const ENTITY_WARNING: &str = " CAUTION: This type contains bevy_ecs::entity::Entity fields. The example value '{}' is a placeholder, not an entity in the running app - never send it or any other made-up number. Get real IDs first: call 'mcp__brp__world_query' (e.g. {\"data\": {}, \"filter\": {\"with\": [\"bevy_ecs::name::Name\"]}}) or 'mcp__brp__world_find_entities_by_name', then use the returned 'entity' values in place of the placeholder. Applying IDs that do not exist can crash the application or corrupt entity hierarchies.";
+/// Per-path guidance for `Entity` fields
+pub(super) const ENTITY_PATH_GUIDANCE: &str = "The example is a placeholder. Replace it with an 'entity' value returned by 'mcp__brp__world_query' or 'mcp__brp__world_find_entities_by_name' in the running app.";
 /// Guidance for types that failed during processing
 pub(super) const ERROR_GUIDANCE: &str = "This type was found in the registry but failed during processing. Check the 'error' field for details. No mutation paths or spawn format are available due to the processing failure.";
 
@@ -17,10 +24,17 @@ pub(super) const TYPE_BEVY_ALPHA_MODE_2D: &str =
     "bevy_sprite_render::mesh2d::material::AlphaMode2d";
 pub(super) const TYPE_BEVY_CAMERA: &str = "bevy_camera::camera::Camera";
 pub(super) const TYPE_BEVY_CAMERA3D: &str = "bevy_camera::components::Camera3d";
+pub(super) const TYPE_BEVY_CHILD_OF: &str = "bevy_ecs::hierarchy::ChildOf";
+pub(super) const TYPE_BEVY_CHILDREN: &str = "bevy_ecs::hierarchy::Children";
 pub(super) const TYPE_BEVY_ENTITY: &str = "bevy_ecs::entity::Entity";
 pub(super) const TYPE_BEVY_GLOBAL_TRANSFORM: &str =
     "bevy_transform::components::global_transform::GlobalTransform";
 pub(super) const TYPE_BEVY_GLYPH_ATLAS_LOCATION: &str = "bevy_text::glyph::GlyphAtlasLocation";
+pub(super) const TYPE_BEVY_MESH_MATERIAL_2D_COLOR: &str = "bevy_sprite_render::mesh2d::material::MeshMaterial2d<bevy_sprite_render::mesh2d::color_material::ColorMaterial>";
+pub(super) const TYPE_BEVY_MESH_MATERIAL_3D_STANDARD: &str =
+    "bevy_pbr::mesh_material::MeshMaterial3d<bevy_pbr::pbr_material::StandardMaterial>";
+pub(super) const TYPE_BEVY_MESH2D: &str = "bevy_mesh::components::Mesh2d";
+pub(super) const TYPE_BEVY_MESH3D: &str = "bevy_mesh::components::Mesh3d";
 pub(super) const TYPE_BEVY_NAME: &str = "bevy_ecs::name::Name";
 pub(super) const TYPE_BEVY_VIDEO_MODE: &str = "bevy_window::monitor::VideoMode";
 pub(super) const TYPE_BEVY_WINDOW_RESOLUTION: &str = "bevy_window::window::WindowResolution";
@@ -80,6 +94,8 @@ pub(super) const TYPE_GLAM_VEC3A: &str = "glam::Vec3A";
 pub(super) const TYPE_GLAM_VEC4: &str = "glam::Vec4";
 
 // json fields
+/// Field of a `world.get_components` result holding the component values
+pub(super) const COMPONENTS_FIELD: &str = "components";
 pub(super) const DURATION_FIELD_NANOS: &str = "nanos";
 pub(super) const DURATION_FIELD_SECS: &str = "secs";
 pub(super) const MUTABLE_FIELD: &str = "mutable";
@@ -116,6 +132,10 @@ pub(super) const NO_RESOURCE_EXAMPLE_TEMPLATE: &str =
 /// Guidance for `spawn` when type is a Component
 pub(super) const SPAWN_COMPONENT_GUIDANCE: &str =
     "The 'example' below can be used to spawn this component on an entity.";
+/// Guidance for `spawn` when type is a relationship component such as `ChildOf`
+pub(super) const SPAWN_RELATIONSHIP_GUIDANCE: &str = "This is a relationship component: its value is the target entity, and inserting it makes Bevy update the target's relationship list. There is no example because a made-up entity ID would attach this entity to the wrong target or a missing one. First get the target's 'entity' from 'mcp__brp__world_query' or 'mcp__brp__world_find_entities_by_name', then spawn or insert {\"<this type>\": <entity>}. To change a parent, use 'mcp__brp__world_reparent_entities'.";
+/// Guidance for `spawn` when type is a relationship target such as `Children`
+pub(super) const SPAWN_RELATIONSHIP_TARGET_GUIDANCE: &str = "This is a relationship target that Bevy maintains from the relationship components pointing at this entity. Do not spawn or insert it; insert the relationship component (e.g. ChildOf) on each related entity or use 'mcp__brp__world_reparent_entities' instead.";
 
 // operation name constants
 /// Operation word for Resource default guidance
@@ -156,6 +176,17 @@ pub(super) const TYPE_STRING: &str = "String";
 // time type constants
 pub(super) const TYPE_CORE_DURATION: &str = "core::time::Duration";
 
+// type guide generation constants
+/// Upper bound on threads building guides for one request
+pub(super) const MAX_TYPE_GUIDE_WORKERS: usize = 8;
+/// Fewest types worth handing to a separate worker
+pub(super) const MIN_TYPES_PER_WORKER: usize = 32;
+/// Requests naming more crates than this fetch the whole registry in one call instead of
+/// fetching crate by crate
+pub(super) const MAX_INCREMENTAL_REGISTRY_CRATES: usize = 8;
+/// Registry tokens remembered per port for `since_token`; older tokens must start over
+pub(super) const MAX_REGISTRY_SNAPSHOTS_PER_PORT: usize = 8;
+
 // type knowledge example arrays
 pub(super) const EXAMPLE_AFFINE2: [f32; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
 pub(super) const EXAMPLE_AFFINE3A: [f32; 12] =
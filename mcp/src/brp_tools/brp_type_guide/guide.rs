@@ -4,6 +4,7 @@
 //! - Mutation path generation (via `TypeKind` dispatch)
 //! - Spawn format extraction
 //! - Schema metadata extraction
+//! - Companion component annotations
 //! - Entity-aware guidance generation
 //!
 //! The `TypeGuide` struct is the final assembled response sent to MCP clients.
@@ -14,11 +15,15 @@ use std::sync::Arc;
 use serde::Serialize;
 use serde_json::Value;
 
+use super::companion_components;
+use super::companion_components::CompanionComponent;
 use super::constants::AGENT_GUIDANCE;
+use super::constants::COMPANION_GUIDANCE;
 use super::constants::ENTITY_WARNING;
 use super::constants::ERROR_GUIDANCE;
 use super::constants::TYPE_BEVY_ENTITY;
 use super::mutation_path_builder;
+use super::mutation_path_builder::Mutability;
 use super::mutation_path_builder::MutationPathExternal;
 use super::mutation_path_builder::SpawnInsertExample;
 use super::response::BrpTypeName;
@@ -49,9 +54,15 @@ pub struct TypeGuide {
     /// Mutation paths available for this type - using same format as V1
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub mutation_paths:       Vec<MutationPathExternal>,
+    /// Components that should be spawned alongside this one for a visible, functional entity
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub companion_components: Vec<CompanionComponent>,
     /// Schema information from the registry
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_info:          Option<SchemaInfo>,
+    /// Current value of the component on the entity passed to `brp_type_guide`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_value:        Option<Value>,
     /// Type information for direct fields (struct fields only, one level deep)
     /// Error message if discovery failed
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,16 +125,25 @@ impl TypeGuide {
         // Extract schema info from registry
         let schema_info = Some(Self::extract_schema_info(registry_schema));
 
+        // Collect `#[require]` companions and curated recommendations
+        let companion_components =
+            companion_components::companion_components(&brp_type_name, registry_schema);
+
         // Generate agent guidance (with Entity warning)
-        let agent_guidance = Self::generate_agent_guidance(&mutation_paths)?;
+        let mut agent_guidance = Self::generate_agent_guidance(&mutation_paths)?;
+        if !companion_components.is_empty() {
+            agent_guidance.push_str(COMPANION_GUIDANCE);
+        }
 
         Ok(Self {
             type_name: brp_type_name,
             in_registry: RegistryPresence::Registered,
             mutation_paths,
             spawn_insert_example,
+            companion_components,
             schema_info,
             agent_guidance,
+            current_value: None,
             error: None,
         })
     }
@@ -135,8 +155,10 @@ impl TypeGuide {
             in_registry: RegistryPresence::Unregistered,
             mutation_paths: Vec::new(),
             spawn_insert_example: None,
+            companion_components: Vec::new(),
             schema_info: None,
             agent_guidance: AGENT_GUIDANCE.to_string(),
+            current_value: None,
             error: Some(error_message),
         }
     }
@@ -151,12 +173,34 @@ impl TypeGuide {
             in_registry: RegistryPresence::Registered,
             mutation_paths: Vec::new(),
             spawn_insert_example: None,
+            companion_components: Vec::new(),
             schema_info: None,
             agent_guidance: ERROR_GUIDANCE.to_string(),
+            current_value: None,
             error: Some(error_message),
         }
     }
 
+    /// Attach an entity's current value, marking the enum variants it holds on the mutation
+    /// paths
+    pub(super) fn apply_current_value(&mut self, value: Value) {
+        mutation_path_builder::mark_current_variants(&mut self.mutation_paths, &value);
+        self.current_value = Some(value);
+    }
+
+    /// Mutability of the whole value, and why it is limited, from the root mutation path
+    pub(crate) fn root_mutability(&self) -> Option<(Mutability, Option<Value>)> {
+        self.mutation_paths
+            .iter()
+            .find(|path| path.path.is_empty())
+            .map(|path| {
+                (
+                    path.path_info.mutability,
+                    path.path_info.mutability_reason.clone(),
+                )
+            })
+    }
+
     pub(super) const fn is_successful_discovery(&self) -> bool {
         self.in_registry.is_registered() && self.error.is_none()
     }
@@ -0,0 +1,366 @@
+//! Corrects math values written as objects anywhere inside a spawn or insert value
+//!
+//! BRP serializes glam vectors and quaternions as arrays, but agents often write them as objects
+//! such as `{"x": 1.0, "y": 2.0, "z": 3.0}`. The value is walked alongside its registry schema -
+//! through struct fields, tuple elements, list, array and set items, map values, `Option`s and
+//! enum variants - and every math value written as an object with exactly its component fields is
+//! rewritten as an array. This reaches values nested as deep as a resource holding a map of
+//! structs of `Vec3`s, which a correction of the top-level value alone cannot fix.
+
+use std::collections::HashMap;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use super::brp_type_name::BrpTypeName;
+use super::constants::MAX_TYPE_RECURSION_DEPTH;
+use super::constants::TYPE_BEVY_QUAT;
+use super::constants::TYPE_BEVY_VEC2;
+use super::constants::TYPE_BEVY_VEC3;
+use super::constants::TYPE_BEVY_VEC3A;
+use super::constants::TYPE_BEVY_VEC4;
+use super::constants::TYPE_GLAM_DVEC2;
+use super::constants::TYPE_GLAM_DVEC3;
+use super::constants::TYPE_GLAM_DVEC4;
+use super::constants::TYPE_GLAM_IVEC2;
+use super::constants::TYPE_GLAM_IVEC3;
+use super::constants::TYPE_GLAM_IVEC4;
+use super::constants::TYPE_GLAM_QUAT;
+use super::constants::TYPE_GLAM_UVEC2;
+use super::constants::TYPE_GLAM_UVEC3;
+use super::constants::TYPE_GLAM_UVEC4;
+use super::constants::TYPE_GLAM_VEC2;
+use super::constants::TYPE_GLAM_VEC3;
+use super::constants::TYPE_GLAM_VEC3A;
+use super::constants::TYPE_GLAM_VEC4;
+use super::tool_type_guide;
+use crate::brp_tools::Port;
+use crate::error::Result;
+use crate::support::JsonObjectAccess;
+use crate::support::SchemaField;
+
+/// Prefix of `Option` type paths, whose `Some` values BRP serializes unwrapped
+const OPTION_TYPE_PREFIX: &str = "core::option::Option<";
+
+/// Component fields of the math types BRP serializes as arrays, in array order
+const MATH_TYPE_FIELDS: &[(&str, &[&str])] = &[
+    (TYPE_BEVY_QUAT, &["x", "y", "z", "w"]),
+    (TYPE_BEVY_VEC2, &["x", "y"]),
+    (TYPE_BEVY_VEC3, &["x", "y", "z"]),
+    (TYPE_BEVY_VEC3A, &["x", "y", "z"]),
+    (TYPE_BEVY_VEC4, &["x", "y", "z", "w"]),
+    (TYPE_GLAM_DVEC2, &["x", "y"]),
+    (TYPE_GLAM_DVEC3, &["x", "y", "z"]),
+    (TYPE_GLAM_DVEC4, &["x", "y", "z", "w"]),
+    (TYPE_GLAM_IVEC2, &["x", "y"]),
+    (TYPE_GLAM_IVEC3, &["x", "y", "z"]),
+    (TYPE_GLAM_IVEC4, &["x", "y", "z", "w"]),
+    (TYPE_GLAM_QUAT, &["x", "y", "z", "w"]),
+    (TYPE_GLAM_UVEC2, &["x", "y"]),
+    (TYPE_GLAM_UVEC3, &["x", "y", "z"]),
+    (TYPE_GLAM_UVEC4, &["x", "y", "z", "w"]),
+    (TYPE_GLAM_VEC2, &["x", "y"]),
+    (TYPE_GLAM_VEC3, &["x", "y", "z"]),
+    (TYPE_GLAM_VEC3A, &["x", "y", "z"]),
+    (TYPE_GLAM_VEC4, &["x", "y", "z", "w"]),
+];
+
+/// One math value rewritten from an object to an array
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MathCorrection {
+    /// Type of the value the correction was made in
+    pub type_name:       String,
+    /// Mutation-path style location of the corrected value inside it, `""` for the value itself
+    pub path:            String,
+    /// The math type of the corrected value
+    pub math_type:       String,
+    pub original_value:  Value,
+    pub corrected_value: Value,
+}
+
+/// Correct math values nested in each `(type, value)` pair
+///
+/// Returns the corrected values, in the same order, with every correction made; values without
+/// corrections are returned unchanged.
+pub async fn correct_math_values(
+    port: Port,
+    values: &[(String, Value)],
+) -> Result<(Vec<Value>, Vec<MathCorrection>)> {
+    let type_names: Vec<String> = values
+        .iter()
+        .map(|(type_name, _)| type_name.clone())
+        .collect();
+    let registry = tool_type_guide::fetch_registry(port, &type_names).await?;
+
+    let mut corrections = Vec::new();
+    let corrected = values
+        .iter()
+        .map(|(type_name, value)| {
+            let mut value = value.clone();
+            let mut walker = MathWalker {
+                registry:    &registry,
+                root_type:   type_name,
+                corrections: &mut corrections,
+            };
+            walker.walk(&BrpTypeName::from(type_name.as_str()), &mut value, "", 0);
+            value
+        })
+        .collect();
+    Ok((corrected, corrections))
+}
+
+struct MathWalker<'a> {
+    registry:    &'a HashMap<BrpTypeName, Value>,
+    root_type:   &'a str,
+    corrections: &'a mut Vec<MathCorrection>,
+}
+
+impl MathWalker<'_> {
+    fn walk(&mut self, type_name: &BrpTypeName, value: &mut Value, path: &str, depth: usize) {
+        if depth > MAX_TYPE_RECURSION_DEPTH {
+            return;
+        }
+        if let Some(fields) = math_type_fields(type_name) {
+            self.correct_math_value(type_name, fields, value, path);
+            return;
+        }
+        if let Some(inner) = option_inner_type(type_name) {
+            if !value.is_null() {
+                self.walk(&inner, value, path, depth + 1);
+            }
+            return;
+        }
+        let Some(schema) = self.registry.get(type_name) else {
+            return;
+        };
+
+        // Map values are keyed by arbitrary strings, so walk them before struct properties
+        if let Some(value_type) = schema.get_type(SchemaField::ValueType) {
+            if let Some(entries) = value.as_object_mut() {
+                for (key, entry) in entries {
+                    self.walk(&value_type, entry, &format!("{path}[{key:?}]"), depth + 1);
+                }
+            }
+            return;
+        }
+        if let Some(item_type) = schema.get_type(SchemaField::Items) {
+            if let Some(items) = value.as_array_mut() {
+                for (index, item) in items.iter_mut().enumerate() {
+                    self.walk(&item_type, item, &format!("{path}[{index}]"), depth + 1);
+                }
+            }
+            return;
+        }
+        if let Some(one_of) = schema.get_field_array(SchemaField::OneOf) {
+            self.walk_variant(one_of, value, path, depth);
+            return;
+        }
+        self.walk_fields(schema, value, path, depth);
+    }
+
+    /// Walk the fields of a struct or the elements of a tuple (struct) described by `schema`
+    fn walk_fields(&mut self, schema: &Value, value: &mut Value, path: &str, depth: usize) {
+        if let (Some(properties), Some(fields)) = (schema.get_properties(), value.as_object_mut()) {
+            for (field_name, field_schema) in properties {
+                if let (Some(field_type), Some(field_value)) = (
+                    field_schema.extract_field_type(),
+                    fields.get_mut(field_name),
+                ) {
+                    self.walk(
+                        &field_type,
+                        field_value,
+                        &format!("{path}.{field_name}"),
+                        depth + 1,
+                    );
+                }
+            }
+            return;
+        }
+
+        let element_types: Vec<BrpTypeName> = schema
+            .get_field_array(SchemaField::PrefixItems)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(JsonObjectAccess::extract_field_type)
+            .collect();
+        match (element_types.as_slice(), value) {
+            // Single-field tuple structs are serialized as their field
+            ([element_type], value) => {
+                self.walk(element_type, value, &format!("{path}.0"), depth + 1);
+            },
+            (element_types, Value::Array(elements)) => {
+                for (index, (element_type, element)) in
+                    element_types.iter().zip(elements).enumerate()
+                {
+                    self.walk(element_type, element, &format!("{path}.{index}"), depth + 1);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Walk the payload of an enum value serialized as `{"Variant": payload}`
+    fn walk_variant(&mut self, one_of: &[Value], value: &mut Value, path: &str, depth: usize) {
+        let Some((variant_name, payload)) = value
+            .as_object_mut()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.iter_mut().next())
+        else {
+            return;
+        };
+        let Some(variant_schema) = one_of.iter().find(|variant| {
+            variant.get_field_str(SchemaField::ShortPath) == Some(variant_name.as_str())
+        }) else {
+            return;
+        };
+        self.walk_fields(variant_schema, payload, path, depth);
+    }
+
+    /// Rewrite `value` as an array if it is an object with exactly the math type's fields
+    fn correct_math_value(
+        &mut self,
+        type_name: &BrpTypeName,
+        fields: &[&str],
+        value: &mut Value,
+        path: &str,
+    ) {
+        let Some(object) = value.as_object() else {
+            return;
+        };
+        let Some(components) = math_components(object, fields) else {
+            return;
+        };
+
+        let corrected = Value::Array(components);
+        self.corrections.push(MathCorrection {
+            type_name:       self.root_type.to_string(),
+            path:            path.to_string(),
+            math_type:       type_name.to_string(),
+            original_value:  value.clone(),
+            corrected_value: corrected.clone(),
+        });
+        *value = corrected;
+    }
+}
+
+/// The object's values in `fields` order, if the object has exactly those fields
+fn math_components(object: &Map<String, Value>, fields: &[&str]) -> Option<Vec<Value>> {
+    if object.len() != fields.len() {
+        return None;
+    }
+    fields
+        .iter()
+        .map(|field| {
+            object
+                .get(*field)
+                .filter(|value| value.is_number())
+                .cloned()
+        })
+        .collect()
+}
+
+pub(super) fn math_type_fields(type_name: &BrpTypeName) -> Option<&'static [&'static str]> {
+    MATH_TYPE_FIELDS
+        .iter()
+        .find(|(math_type, _)| *math_type == type_name.as_str())
+        .map(|(_, fields)| *fields)
+}
+
+pub(super) fn option_inner_type(type_name: &BrpTypeName) -> Option<BrpTypeName> {
+    type_name
+        .as_str()
+        .strip_prefix(OPTION_TYPE_PREFIX)
+        .and_then(|inner| inner.strip_suffix('>'))
+        .map(BrpTypeName::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::MathWalker;
+    use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+
+    /// A `TestConfigResource`-style resource holding a map of structs of `Vec3`s
+    fn registry() -> HashMap<BrpTypeName, Value> {
+        HashMap::from([
+            (
+                BrpTypeName::from("test_app::WaypointConfig"),
+                json!({
+                    "kind": "Struct",
+                    "properties": {
+                        "label": { "type": { "$ref": "#/$defs/alloc::string::String" } },
+                        "routes": {
+                            "type": {
+                                "$ref": "#/$defs/std::collections::HashMap<alloc::string::String, test_app::Route>"
+                            }
+                        }
+                    },
+                    "reflectTypes": ["Resource"]
+                }),
+            ),
+            (
+                BrpTypeName::from(
+                    "std::collections::HashMap<alloc::string::String, test_app::Route>",
+                ),
+                json!({
+                    "kind": "Map",
+                    "keyType": { "type": { "$ref": "#/$defs/alloc::string::String" } },
+                    "valueType": { "type": { "$ref": "#/$defs/test_app::Route" } }
+                }),
+            ),
+            (
+                BrpTypeName::from("test_app::Route"),
+                json!({
+                    "kind": "Struct",
+                    "properties": {
+                        "start": { "type": { "$ref": "#/$defs/glam::Vec3" } },
+                        "heading": {
+                            "type": { "$ref": "#/$defs/core::option::Option<glam::Quat>" }
+                        }
+                    }
+                }),
+            ),
+        ])
+    }
+
+    #[test]
+    fn corrects_math_values_nested_in_map_values() {
+        let registry = registry();
+        let mut corrections = Vec::new();
+        let mut value = json!({
+            "label": "patrol",
+            "routes": {
+                "north": {
+                    "start": { "x": 1.0, "y": 2.0, "z": 3.0 },
+                    "heading": { "x": 0.0, "y": 0.0, "z": 0.0, "w": 1.0 }
+                },
+                "south": { "start": [4.0, 5.0, 6.0], "heading": null }
+            }
+        });
+
+        MathWalker {
+            registry:    &registry,
+            root_type:   "test_app::WaypointConfig",
+            corrections: &mut corrections,
+        }
+        .walk(
+            &BrpTypeName::from("test_app::WaypointConfig"),
+            &mut value,
+            "",
+            0,
+        );
+
+        assert_eq!(value["routes"]["north"]["start"], json!([1.0, 2.0, 3.0]));
+        assert_eq!(
+            value["routes"]["north"]["heading"],
+            json!([0.0, 0.0, 0.0, 1.0])
+        );
+        assert_eq!(value["routes"]["south"]["start"], json!([4.0, 5.0, 6.0]));
+        assert_eq!(corrections.len(), 2);
+        assert_eq!(corrections[0].path, ".routes[\"north\"].start");
+    }
+}
@@ -5,20 +5,31 @@
 //! serialization knowledge to provide accurate format discovery for BRP operations.
 
 mod brp_type_name;
+mod cache;
+mod companion_components;
 mod constants;
 mod guide;
+mod math_corrections;
 mod mutation_path_builder;
+mod registry_token;
 mod response;
 mod struct_field_name;
 mod tool_all_types;
+mod tool_generate_types;
 mod tool_type_guide;
 mod type_kind;
 mod type_knowledge;
 mod variant_signature;
 
 pub use brp_type_name::BrpTypeName;
+pub(crate) use cache::forget_type_guides;
+pub(crate) use guide::TypeGuide;
+pub(super) use math_corrections::correct_math_values;
+pub(crate) use mutation_path_builder::Mutability;
 pub use tool_all_types::AllTypeGuidesParams;
 pub use tool_all_types::BrpAllTypeGuides;
+pub use tool_generate_types::BrpGenerateTypes;
+pub use tool_generate_types::GenerateTypesParams;
 pub use tool_type_guide::BrpTypeGuide;
 pub use tool_type_guide::TypeGuideParams;
 pub(super) use tool_type_guide::generate_type_guide_response;
@@ -18,10 +18,12 @@ use super::constants::RESPONSE_EXAMPLE_FIELD;
 use super::constants::RESPONSE_RESOURCE_FIELD;
 use super::constants::RESPONSE_SPAWN_FIELD;
 use super::mutation_path_external::MutationPathExternal;
+use super::not_mutable_reason::NotMutableReason;
 use super::path_builder;
 use super::path_example::Example;
 use super::path_kind::PathKind;
 use super::recursion_context::RecursionContext;
+use super::support;
 use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
 use crate::brp_tools::brp_type_guide::constants::INSERT_RESOURCE_GUIDANCE;
 use crate::brp_tools::brp_type_guide::constants::NO_COMPONENT_EXAMPLE_TEMPLATE;
@@ -31,6 +33,8 @@ use crate::brp_tools::brp_type_guide::constants::OPERATION_SPAWN;
 use crate::brp_tools::brp_type_guide::constants::REFLECT_TRAIT_COMPONENT;
 use crate::brp_tools::brp_type_guide::constants::REFLECT_TRAIT_RESOURCE;
 use crate::brp_tools::brp_type_guide::constants::SPAWN_COMPONENT_GUIDANCE;
+use crate::brp_tools::brp_type_guide::constants::SPAWN_RELATIONSHIP_GUIDANCE;
+use crate::brp_tools::brp_type_guide::constants::SPAWN_RELATIONSHIP_TARGET_GUIDANCE;
 use crate::brp_tools::brp_type_guide::type_kind::TypeKind;
 use crate::error::Error;
 use crate::error::Result;
@@ -158,10 +162,13 @@ pub fn extract_spawn_insert_example(
 
     // Build appropriate variant based on type
     if is_component {
-        let agent_guidance = if matches!(example, Example::NotApplicable) {
-            NO_COMPONENT_EXAMPLE_TEMPLATE.replace("{}", OPERATION_SPAWN)
-        } else {
-            SPAWN_COMPONENT_GUIDANCE.to_string()
+        let agent_guidance = match support::relationship_reason(&root_path.path_info.type_name) {
+            Some(NotMutableReason::Relationship(_)) => SPAWN_RELATIONSHIP_GUIDANCE.to_string(),
+            Some(_) => SPAWN_RELATIONSHIP_TARGET_GUIDANCE.to_string(),
+            None if matches!(example, Example::NotApplicable) => {
+                NO_COMPONENT_EXAMPLE_TEMPLATE.replace("{}", OPERATION_SPAWN)
+            },
+            None => SPAWN_COMPONENT_GUIDANCE.to_string(),
         };
 
         Some(SpawnInsertExample::Spawn {
@@ -190,3 +197,55 @@ fn spawn_insert_payload(agent_guidance: &str, example: &Example) -> Value {
     }
     Value::Object(payload)
 }
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::SpawnInsertExample;
+    use super::build_mutation_paths;
+    use super::extract_spawn_insert_example;
+    use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+    use crate::brp_tools::brp_type_guide::constants::SPAWN_RELATIONSHIP_GUIDANCE;
+    use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_CHILD_OF;
+    use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_ENTITY;
+    use crate::brp_tools::brp_type_guide::mutation_path_builder::mutability::Mutability;
+
+    #[test]
+    fn relationship_components_are_not_mutable_and_have_no_placeholder_example() {
+        let child_of = BrpTypeName::from(TYPE_BEVY_CHILD_OF);
+        let registry = Arc::new(HashMap::from([
+            (
+                child_of.clone(),
+                json!({
+                    "kind": "TupleStruct",
+                    "prefixItems": [{ "type": { "$ref": format!("#/$defs/{TYPE_BEVY_ENTITY}") } }],
+                    "reflectTypes": ["Component"]
+                }),
+            ),
+            (
+                BrpTypeName::from(TYPE_BEVY_ENTITY),
+                json!({ "kind": "Value", "reflectTypes": ["Serialize", "Deserialize"] }),
+            ),
+        ]));
+
+        let paths = build_mutation_paths(&child_of, registry).expect("paths should build");
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].path_info.mutability, Mutability::NotMutable);
+        let spawn = extract_spawn_insert_example(&paths, &["Component".to_string()])
+            .expect("ChildOf should produce spawn guidance");
+        assert!(matches!(
+            &spawn,
+            SpawnInsertExample::Spawn { agent_guidance, example }
+                if agent_guidance == SPAWN_RELATIONSHIP_GUIDANCE && example.is_null_equivalent()
+        ));
+    }
+}
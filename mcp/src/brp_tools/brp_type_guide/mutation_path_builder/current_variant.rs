@@ -0,0 +1,260 @@
+//! Marks the enum variants a live value currently holds
+//!
+//! Mutation paths are built from the type alone, so enum paths list every variant and nested
+//! paths only say which variants they need. Given a component's current value, this walks the
+//! paths from the root down, records the variant each enum path holds, and flags whether each
+//! variant-specific path is reachable without switching variants first.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::constants::OPTION_SOME_FIELD;
+use super::mutation_path_external::MutationPathExternal;
+use super::option_classification::OptionClassification;
+use super::path_kind::PathKind;
+use super::variant_name::VariantName;
+use crate::brp_tools::brp_type_guide::type_kind::TypeKind;
+use crate::brp_tools::brp_type_guide::variant_signature::VariantSignature;
+
+/// Variant short name `Option` values are reported with when they are `null`
+const OPTION_NONE_VARIANT: &str = "None";
+
+/// Live value found at one mutation path
+struct LiveNode {
+    value:   Value,
+    /// The variant held, for enum paths
+    variant: Option<LiveVariant>,
+}
+
+struct LiveVariant {
+    name:      VariantName,
+    /// Whether the enum is an `Option`, which BRP serializes unwrapped
+    is_option: bool,
+    /// Number of fields of a tuple variant
+    arity:     usize,
+}
+
+/// Mark current variants on `paths` from the component's live `value`
+pub fn mark_current_variants(paths: &mut [MutationPathExternal], value: &Value) {
+    let tuple_arity = tuple_arity(paths);
+
+    // Parents are always shorter than their children
+    let mut order: Vec<usize> = (0..paths.len()).collect();
+    order.sort_by_key(|&index| paths[index].path.len());
+
+    let mut live: HashMap<String, LiveNode> = HashMap::new();
+    for index in order {
+        let parent_path = parent_path(&paths[index]).map(String::from);
+        let path = &mut paths[index];
+        let value = match parent_path {
+            None => Some(value.clone()),
+            Some(parent_path) => {
+                let Some(parent) = live.get(&parent_path) else {
+                    continue;
+                };
+                if let (Some(variant), Some(applicable)) =
+                    (&parent.variant, &path.path_info.applicable_variants)
+                {
+                    let in_current_variant = applicable.contains(&variant.name);
+                    path.path_info.in_current_variant = Some(in_current_variant);
+                    if !in_current_variant {
+                        continue;
+                    }
+                }
+                child_value(
+                    parent,
+                    &path.path_info.path_kind,
+                    &tuple_arity,
+                    &parent_path,
+                )
+            },
+        };
+        let Some(value) = value else {
+            continue;
+        };
+
+        let variant = (path.path_info.type_kind == TypeKind::Enum)
+            .then(|| current_variant(path, &value))
+            .flatten();
+        if let Some(variant) = &variant {
+            path.set_current_variant(&variant.name);
+        }
+        live.insert(path.path.to_string(), LiveNode { value, variant });
+    }
+}
+
+/// Path of the value `path` is nested in, or `None` for the root path
+fn parent_path(path: &MutationPathExternal) -> Option<&str> {
+    let segment = match &path.path_info.path_kind {
+        PathKind::RootValue { .. } => return None,
+        PathKind::StructField { field_name, .. } => format!(".{field_name}"),
+        PathKind::IndexedElement { index, .. } => format!(".{index}"),
+        PathKind::ArrayElement { index, .. } => format!("[{index}]"),
+    };
+    path.path.strip_suffix(&segment)
+}
+
+/// Number of indexed children of each non-enum parent path, to tell newtype wrappers (serialized
+/// as their single field) from tuples (serialized as arrays)
+fn tuple_arity(paths: &[MutationPathExternal]) -> HashMap<String, usize> {
+    let mut arity: HashMap<String, usize> = HashMap::new();
+    for path in paths {
+        if let PathKind::IndexedElement { index, .. } = path.path_info.path_kind
+            && path.path_info.applicable_variants.is_none()
+            && let Some(parent_path) = parent_path(path)
+        {
+            let count = arity.entry(parent_path.to_string()).or_default();
+            *count = (*count).max(index + 1);
+        }
+    }
+    arity
+}
+
+/// The live value of a child path inside its parent's live value
+fn child_value(
+    parent: &LiveNode,
+    path_kind: &PathKind,
+    tuple_arity: &HashMap<String, usize>,
+    parent_path: &str,
+) -> Option<Value> {
+    let (container, arity) = match &parent.variant {
+        // `Some(value)` is serialized as `value`
+        Some(variant) if variant.is_option => return Some(parent.value.clone()),
+        // Variants with data are serialized as `{"Variant": payload}`
+        Some(variant) => (parent.value.as_object()?.values().next()?, variant.arity),
+        None => (
+            &parent.value,
+            tuple_arity.get(parent_path).copied().unwrap_or_default(),
+        ),
+    };
+
+    match path_kind {
+        PathKind::RootValue { .. } => None,
+        PathKind::StructField { field_name, .. } => container.get(field_name.as_str()).cloned(),
+        PathKind::IndexedElement { index, .. } if arity == 1 => {
+            (*index == 0).then(|| container.clone())
+        },
+        PathKind::IndexedElement { index, .. } | PathKind::ArrayElement { index, .. } => {
+            container.get(*index).cloned()
+        },
+    }
+}
+
+/// The variant an enum path's live value holds, if the path lists it
+fn current_variant(path: &MutationPathExternal, value: &Value) -> Option<LiveVariant> {
+    let option_classification: OptionClassification = (&path.path_info.type_name).into();
+    let is_option = option_classification.is_option();
+    let short_name = if is_option {
+        if value.is_null() {
+            OPTION_NONE_VARIANT
+        } else {
+            OPTION_SOME_FIELD
+        }
+    } else {
+        match value {
+            Value::String(name) => name.as_str(),
+            Value::Object(object) if object.len() == 1 => object.keys().next()?.as_str(),
+            _ => return None,
+        }
+    };
+
+    path.example_groups().iter().find_map(|group| {
+        let name = group
+            .applicable_variants
+            .iter()
+            .find(|variant| variant.short_name() == short_name)?;
+        let arity = match &group.signature {
+            VariantSignature::Tuple(types) => types.len(),
+            VariantSignature::Unit | VariantSignature::Struct(_) => 0,
+        };
+        Some(LiveVariant {
+            name: name.clone(),
+            is_option,
+            arity,
+        })
+    })
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::MutationPathExternal;
+    use super::mark_current_variants;
+    use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+    use crate::brp_tools::brp_type_guide::mutation_path_builder::build_mutation_paths;
+
+    fn mode_paths() -> Vec<MutationPathExternal> {
+        let mode = BrpTypeName::from("game::Mode");
+        let registry = Arc::new(HashMap::from([
+            (
+                mode.clone(),
+                json!({
+                    "kind": "Enum",
+                    "oneOf": [
+                        "Idle",
+                        {
+                            "kind": "Tuple",
+                            "shortPath": "Moving",
+                            "typePath": "game::Mode::Moving",
+                            "prefixItems": [{ "type": { "$ref": "#/$defs/f32" } }]
+                        }
+                    ],
+                    "reflectTypes": ["Component", "Serialize", "Deserialize"]
+                }),
+            ),
+            (
+                BrpTypeName::from("f32"),
+                json!({ "kind": "Value", "reflectTypes": ["Serialize", "Deserialize"] }),
+            ),
+        ]));
+        build_mutation_paths(&mode, registry).expect("paths should build")
+    }
+
+    fn path_json(paths: &[MutationPathExternal], path: &str) -> Value {
+        let path = paths
+            .iter()
+            .find(|candidate| candidate.path.as_str() == path)
+            .expect("path should exist");
+        serde_json::to_value(path).expect("path should serialize")
+    }
+
+    #[test]
+    fn marks_the_active_variant_and_its_fields() {
+        let mut paths = mode_paths();
+        mark_current_variants(&mut paths, &json!({ "Moving": 2.5 }));
+
+        let root = path_json(&paths, "");
+        let current = root["path_info"]["current_variant"]
+            .as_str()
+            .expect("the root should have a current variant");
+        assert!(current.ends_with("::Moving"));
+        assert_eq!(root["examples"][0]["applicable_variants"][0], current);
+        assert_eq!(
+            path_json(&paths, ".0")["path_info"]["in_current_variant"],
+            true
+        );
+    }
+
+    #[test]
+    fn fields_of_inactive_variants_are_flagged() {
+        let mut paths = mode_paths();
+        mark_current_variants(&mut paths, &json!("Idle"));
+
+        let root = path_json(&paths, "");
+        assert_eq!(root["path_info"]["current_variant"], "Mode::Idle");
+        assert_eq!(
+            path_json(&paths, ".0")["path_info"]["in_current_variant"],
+            false
+        );
+    }
+}
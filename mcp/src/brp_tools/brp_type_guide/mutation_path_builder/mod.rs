@@ -1,6 +1,7 @@
 mod api;
 mod builder_error;
 mod constants;
+mod current_variant;
 mod enum_builder;
 mod enum_path_info;
 mod example_group;
@@ -23,5 +24,7 @@ pub(super) use api::SpawnInsertExample;
 pub(super) use api::build_mutation_paths;
 pub(super) use api::extract_spawn_insert_example;
 use builder_error::BuilderError;
+pub(super) use current_variant::mark_current_variants;
+pub(crate) use mutability::Mutability;
 pub(super) use mutation_path_external::MutationPathExternal;
 use not_mutable_reason::NotMutableReason;
@@ -3,6 +3,7 @@
 use serde::Serialize;
 use serde_json::Value;
 
+use super::example_group::ExampleGroup;
 use super::mutability::Mutability;
 use super::mutation_path::MutationPath;
 use super::path_example::Example;
@@ -37,6 +38,16 @@ pub struct PathInfo {
     /// Instructions for setting variants required for this mutation path (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enum_instructions:   Option<String>,
+    /// Variant the inspected entity's value currently holds, for enum paths
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_variant:     Option<VariantName>,
+    /// Whether the inspected entity's value holds one of `applicable_variants`, so this path can
+    /// be mutated without switching variants first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_current_variant:  Option<bool>,
+    /// Where to get a real ID for an `Entity` path, whose example is only a placeholder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_guidance:     Option<&'static str>,
     /// Either the `root_example` or the `root_example_unavailable_reason`
     /// depending on which is available on this path
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
@@ -73,6 +84,27 @@ impl MutationPathExternal {
     }
 
     pub(super) fn preferred_example(&self) -> Example { self.path_example.preferred_example() }
+
+    /// Variant groups of an enum root path; empty for other paths
+    pub(super) fn example_groups(&self) -> &[ExampleGroup] {
+        match &self.path_example {
+            PathExample::EnumRoot { groups, .. } => groups,
+            PathExample::Simple(_) => &[],
+        }
+    }
+
+    /// Record `variant` as the one the live value holds and list its example group first, so
+    /// the first example mutates the variant that is already active
+    pub(super) fn set_current_variant(&mut self, variant: &VariantName) {
+        if let PathExample::EnumRoot { groups, .. } = &mut self.path_example
+            && let Some(index) = groups
+                .iter()
+                .position(|group| group.applicable_variants.contains(variant))
+        {
+            groups[..=index].rotate_right(1);
+        }
+        self.path_info.current_variant = Some(variant.clone());
+    }
 }
 
 /// Root example for an enum variant, either available for construction or unavailable with reason
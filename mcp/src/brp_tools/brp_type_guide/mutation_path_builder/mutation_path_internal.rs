@@ -24,11 +24,13 @@ use super::path_example::PathExample;
 use super::path_kind::PathKind;
 use super::variant_name::VariantName;
 use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+use crate::brp_tools::brp_type_guide::constants::ENTITY_PATH_GUIDANCE;
 use crate::brp_tools::brp_type_guide::constants::OPERATION_INSERT;
 use crate::brp_tools::brp_type_guide::constants::OPERATION_SPAWN;
 use crate::brp_tools::brp_type_guide::constants::REFLECT_TRAIT_COMPONENT;
 use crate::brp_tools::brp_type_guide::constants::REFLECT_TRAIT_DEFAULT;
 use crate::brp_tools::brp_type_guide::constants::REFLECT_TRAIT_RESOURCE;
+use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_ENTITY;
 use crate::brp_tools::brp_type_guide::type_kind::TypeKind;
 use crate::support::JsonObjectAccess;
 use crate::support::SchemaField;
@@ -56,6 +58,7 @@ struct PathInfoParams {
     mutability_reason:   Option<Value>,
     applicable_variants: Option<Vec<VariantName>>,
     enum_instructions:   Option<String>,
+    entity_guidance:     Option<&'static str>,
     root_example:        Option<RootExample>,
 }
 
@@ -69,6 +72,9 @@ impl From<PathInfoParams> for PathInfo {
             mutability_reason:   params.mutability_reason,
             applicable_variants: params.applicable_variants,
             enum_instructions:   params.enum_instructions,
+            current_variant:     None,
+            in_current_variant:  None,
+            entity_guidance:     params.entity_guidance,
             root_example:        params.root_example,
         }
     }
@@ -139,6 +145,11 @@ impl MutationPathInternal {
         // Extract enum-specific metadata only for mutable/partially mutable paths
         let (enum_instructions, applicable_variants, root_example) = self.resolve_enum_path_info();
 
+        // Entity examples are placeholders, so mutable entity paths say where real IDs come from
+        let entity_guidance = (self.type_name.as_str() == TYPE_BEVY_ENTITY
+            && !matches!(self.mutability, Mutability::NotMutable))
+        .then_some(ENTITY_PATH_GUIDANCE);
+
         MutationPathExternal::new(
             self.mutation_path.clone(),
             description,
@@ -153,6 +164,7 @@ impl MutationPathInternal {
                     .and_then(Option::<Value>::from),
                 applicable_variants,
                 enum_instructions,
+                entity_guidance,
                 root_example,
             }
             .into(),
@@ -56,6 +56,10 @@ pub(super) enum NotMutableReason {
     ImmutableChildren { parent_type: BrpTypeName },
     /// Leaf type registered in schema but has no hardcoded example value
     NoExampleAvailable(BrpTypeName),
+    /// Relationship component such as `ChildOf`, whose hooks only run on insert
+    Relationship(BrpTypeName),
+    /// Relationship target such as `Children`, which Bevy maintains from the relationship side
+    RelationshipTarget(BrpTypeName),
     /// Some children are mutable, others are not (results in `PartiallyMutable`)
     PartialChildMutability {
         parent_type:       BrpTypeName,
@@ -160,6 +164,14 @@ impl Display for NotMutableReason {
                 f,
                 "`{type_name}` is registered in the schema but has no discoverable example value available for mutations. If you look up the type definition yourself you may be able to use it to mutate this type directly."
             ),
+            Self::Relationship(type_name) => write!(
+                f,
+                "`{type_name}` is a relationship component. Mutating its entity in place skips the relationship hooks and leaves the old and new targets' relationship lists wrong. Insert the component again with a target entity from a live query, or use world.reparent_entities for parents"
+            ),
+            Self::RelationshipTarget(type_name) => write!(
+                f,
+                "`{type_name}` is a relationship target maintained by Bevy. Change the relationship components on the related entities instead, e.g. world.reparent_entities for children"
+            ),
             Self::PartialChildMutability { parent_type, .. } => write!(
                 f,
                 "`{parent_type}` has partial child mutability - some children can be mutated, others cannot"
@@ -177,7 +189,9 @@ impl From<&NotMutableReason> for Option<Value> {
             | NotMutableReason::RecursionLimitExceeded(_)
             | NotMutableReason::ComplexCollectionKey(_)
             | NotMutableReason::ImmutableChildren { .. }
-            | NotMutableReason::NoExampleAvailable(_) => Some(Value::String(format!("{reason}"))),
+            | NotMutableReason::NoExampleAvailable(_)
+            | NotMutableReason::Relationship(_)
+            | NotMutableReason::RelationshipTarget(_) => Some(Value::String(format!("{reason}"))),
             // PartialChildMutability returns structured JSON
             NotMutableReason::PartialChildMutability {
                 parent_type: _,
@@ -206,18 +206,26 @@ pub(super) fn recurse_mutation_paths(
     type_kind: TypeKind,
     context: &RecursionContext,
 ) -> Result<Vec<MutationPathInternal>> {
-    let mutation_result = match type_kind {
-        // Enum is distinct from the rest but now returns MutationResult too
-        TypeKind::Enum => enum_builder::process_enum(context),
-        TypeKind::Struct => MutationPathBuilder::new(StructMutationBuilder).build_paths(context),
-        TypeKind::Tuple | TypeKind::TupleStruct => {
-            MutationPathBuilder::new(TupleMutationBuilder).build_paths(context)
-        },
-        TypeKind::Array => MutationPathBuilder::new(ArrayMutationBuilder).build_paths(context),
-        TypeKind::List => MutationPathBuilder::new(ListMutationBuilder).build_paths(context),
-        TypeKind::Map => MutationPathBuilder::new(MapMutationBuilder).build_paths(context),
-        TypeKind::Set => MutationPathBuilder::new(SetMutationBuilder).build_paths(context),
-        TypeKind::Value => MutationPathBuilder::new(ValueMutationBuilder).build_paths(context),
+    // Relationships are checked before dispatch: their fields look like plain entities, but
+    // writing them bypasses the hooks that keep both sides of the relationship in sync
+    let mutation_result = if let Some(reason) = support::relationship_reason(context.type_name()) {
+        Err(BuilderError::NotMutable(reason))
+    } else {
+        match type_kind {
+            // Enum is distinct from the rest but now returns MutationResult too
+            TypeKind::Enum => enum_builder::process_enum(context),
+            TypeKind::Struct => {
+                MutationPathBuilder::new(StructMutationBuilder).build_paths(context)
+            },
+            TypeKind::Tuple | TypeKind::TupleStruct => {
+                MutationPathBuilder::new(TupleMutationBuilder).build_paths(context)
+            },
+            TypeKind::Array => MutationPathBuilder::new(ArrayMutationBuilder).build_paths(context),
+            TypeKind::List => MutationPathBuilder::new(ListMutationBuilder).build_paths(context),
+            TypeKind::Map => MutationPathBuilder::new(MapMutationBuilder).build_paths(context),
+            TypeKind::Set => MutationPathBuilder::new(SetMutationBuilder).build_paths(context),
+            TypeKind::Value => MutationPathBuilder::new(ValueMutationBuilder).build_paths(context),
+        }
     };
 
     // Convert BuilderError to public Result interface at module boundary
@@ -13,10 +13,26 @@ use serde_json::Value;
 use super::mutability::Mutability;
 use super::mutation_path_external::RootExample;
 use super::mutation_path_internal::MutationPathInternal;
+use super::not_mutable_reason::NotMutableReason;
 use super::path_example::Example;
 use super::path_kind::MutationPathDescriptor;
 use super::recursion_context::RecursionContext;
 use super::variant_name::VariantName;
+use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_CHILD_OF;
+use crate::brp_tools::brp_type_guide::constants::TYPE_BEVY_CHILDREN;
+
+/// Why a relationship type must not be mutated through its fields, if it is one
+///
+/// Relationship hooks only run when the component is inserted, so writing a new entity into
+/// `ChildOf` (or editing `Children` directly) desynchronizes the two sides of the hierarchy.
+pub(super) fn relationship_reason(type_name: &BrpTypeName) -> Option<NotMutableReason> {
+    match type_name.as_str() {
+        TYPE_BEVY_CHILD_OF => Some(NotMutableReason::Relationship(type_name.clone())),
+        TYPE_BEVY_CHILDREN => Some(NotMutableReason::RelationshipTarget(type_name.clone())),
+        _ => None,
+    }
+}
 
 /// Aggregate multiple mutation statuses into a single status
 ///
@@ -0,0 +1,86 @@
+//! Registry tokens for incremental `brp_all_type_guides` calls
+//!
+//! A registry token is a hash of the sorted component and resource type names an app had
+//! registered. Each call remembers the type names behind the token it returns, so a later call
+//! with that token as `since_token` only builds guides for types registered since. Tokens depend
+//! only on the registered types, so they stay valid across restarts of the same build.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use super::constants::MAX_REGISTRY_SNAPSHOTS_PER_PORT;
+use crate::brp_tools::Port;
+
+/// Recent registry snapshots keyed by BRP port, oldest first
+static REGISTRY_SNAPSHOTS: LazyLock<Mutex<HashMap<Port, VecDeque<RegistrySnapshot>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Type names registered when `token` was handed out
+struct RegistrySnapshot {
+    token: String,
+    types: HashSet<String>,
+}
+
+/// Token for the registry state made up of `types`
+pub(super) fn registry_token(types: &[String]) -> String {
+    let sorted: BTreeSet<&String> = types.iter().collect();
+    let mut hasher = DefaultHasher::new();
+    for type_name in sorted {
+        type_name.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+/// Remember the types behind `token` so it can be passed back as `since_token`
+pub(super) fn remember(port: Port, token: &str, types: &[String]) {
+    let Ok(mut snapshots) = REGISTRY_SNAPSHOTS.lock() else {
+        return;
+    };
+    let snapshots = snapshots.entry(port).or_default();
+    snapshots.retain(|snapshot| snapshot.token != token);
+    if snapshots.len() >= MAX_REGISTRY_SNAPSHOTS_PER_PORT {
+        snapshots.pop_front();
+    }
+    snapshots.push_back(RegistrySnapshot {
+        token: token.to_string(),
+        types: types.iter().cloned().collect(),
+    });
+}
+
+/// Type names that were registered when `token` was handed out for `port`
+pub(super) fn types_at(port: Port, token: &str) -> Option<HashSet<String>> {
+    let snapshots = REGISTRY_SNAPSHOTS.lock().ok()?;
+    snapshots
+        .get(&port)?
+        .iter()
+        .find(|snapshot| snapshot.token == token)
+        .map(|snapshot| snapshot.types.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::registry_token;
+
+    #[test]
+    fn token_ignores_order_and_duplicates() {
+        let types = ["game::Player".to_string(), "game::Score".to_string()];
+        let shuffled = [
+            "game::Score".to_string(),
+            "game::Player".to_string(),
+            "game::Score".to_string(),
+        ];
+
+        assert_eq!(registry_token(&types), registry_token(&shuffled));
+        assert_ne!(
+            registry_token(&types),
+            registry_token(&["game::Player".to_string()])
+        );
+    }
+}
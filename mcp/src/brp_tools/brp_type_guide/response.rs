@@ -82,6 +82,52 @@ pub struct TypeGuideResponse {
     pub summary:          TypeGuideSummary,
     /// Detailed information for each type, keyed by type name
     pub type_guide:       HashMap<BrpTypeName, TypeGuide>,
+    /// How long discovery took, reported as response metadata rather than in the result
+    #[serde(skip)]
+    pub timing:           TypeGuideTiming,
+}
+
+impl TypeGuideResponse {
+    /// Response for `requested_types`, with summary counts taken from the guides
+    pub(super) fn new(
+        requested_types: &[String],
+        type_guide: HashMap<BrpTypeName, TypeGuide>,
+        timing: TypeGuideTiming,
+    ) -> Self {
+        let successful_discoveries = type_guide
+            .values()
+            .filter(|tg| tg.is_successful_discovery())
+            .count();
+        let failed_discoveries = type_guide
+            .values()
+            .filter(|tg| tg.is_failed_discovery())
+            .count();
+
+        Self {
+            discovered_count: successful_discoveries,
+            requested_types: requested_types.to_vec(),
+            summary: TypeGuideSummary {
+                failed_discoveries,
+                successful_discoveries,
+                total_requested: requested_types.len(),
+            },
+            type_guide,
+            timing,
+        }
+    }
+}
+
+/// Timing of one type guide request
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypeGuideTiming {
+    /// Time spent fetching the registry schema, in milliseconds
+    pub registry_fetch_ms: u64,
+    /// Time spent building guides, in milliseconds
+    pub generation_ms:     u64,
+    /// Number of threads that built guides in parallel
+    pub workers:           usize,
+    /// Number of guides served from the type guide cache
+    pub cached_guides:     usize,
 }
 
 /// Summary statistics for the discovery operation
@@ -2,7 +2,8 @@
 //!
 //! This tool fetches all registered component and resource types from the Bevy app and returns
 //! their type schema information in a single call. It combines `world.list_components`,
-//! `world.list_resources`, and `brp_type_guide` functionality for convenience.
+//! `world.list_resources`, and `brp_type_guide` functionality for convenience. With a
+//! `since_token` from an earlier call it only returns types registered since that call.
 
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ToolFn;
@@ -10,6 +11,8 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::registry_token;
+use super::response::TypeGuideResponse;
 use super::tool_type_guide::TypeGuideResult;
 use crate::brp_tools::BrpClient;
 use crate::brp_tools::Port;
@@ -25,9 +28,43 @@ use crate::tool::ToolResult;
 /// Parameters for the `brp_all_type_guides` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct AllTypeGuidesParams {
+    /// Include mutation paths for each type (default: true)
+    #[to_metadata(skip_if_none)]
+    pub include_mutation_paths: Option<bool>,
+    /// Include spawn/insert examples for each type (default: true)
+    #[to_metadata(skip_if_none)]
+    pub include_examples:       Option<bool>,
+    /// Only return types whose fully-qualified name starts with this prefix (e.g. `my_game::`)
+    #[to_metadata(skip_if_none)]
+    pub paths_filter:           Option<String>,
+    /// Only return types registered since the call that returned this `registry_token`
+    #[to_metadata(skip_if_none)]
+    pub since_token:            Option<String>,
+    /// Return at most this many guides, with a `next_cursor` in `metadata.pagination` for the rest
+    #[to_metadata(skip_if_none)]
+    pub page_size:              Option<usize>,
+    /// `next_cursor` from a previous page, to get its next page without rebuilding the guides
+    #[to_metadata(skip_if_none)]
+    pub cursor:                 Option<String>,
     /// The BRP port (default: 15702)
     #[serde(default)]
-    pub port: Port,
+    pub port:                   Port,
+}
+
+impl AllTypeGuidesParams {
+    /// Drop the parts of each guide the caller did not ask for
+    fn project(&self, response: &mut TypeGuideResponse) {
+        let include_mutation_paths = self.include_mutation_paths.unwrap_or(true);
+        let include_examples = self.include_examples.unwrap_or(true);
+        for type_guide in response.type_guide.values_mut() {
+            if !include_mutation_paths {
+                type_guide.mutation_paths.clear();
+            }
+            if !include_examples {
+                type_guide.spawn_insert_example = None;
+            }
+        }
+    }
 }
 
 /// The main tool struct for getting all type guides
@@ -43,18 +80,51 @@ async fn handle_impl(params: AllTypeGuidesParams) -> Result<TypeGuideResult> {
     // Fetch resource types
     let resource_types = fetch_type_list(BrpMethod::WorldListResources, params.port).await?;
 
-    // Merge both lists
-    let mut all_types = component_types;
-    all_types.extend(resource_types);
+    // The token covers every registered type, whatever the filters of this call
+    let registered_types: Vec<String> = component_types.into_iter().chain(resource_types).collect();
+    let token = registry_token::registry_token(&registered_types);
+    let known_types = params
+        .since_token
+        .as_deref()
+        .map(|since_token| {
+            registry_token::types_at(params.port, since_token).ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "Unknown since_token '{since_token}' for port {}: tokens are forgotten after \
+                     newer calls or a server restart. Call brp_all_type_guides without \
+                     since_token to get every type and a fresh registry_token",
+                    params.port
+                ))
+            })
+        })
+        .transpose()?;
+    registry_token::remember(params.port, &token, &registered_types);
+
+    // Keep only new types under the requested prefix
+    let all_types: Vec<String> = registered_types
+        .into_iter()
+        .filter(|type_name| {
+            known_types
+                .as_ref()
+                .is_none_or(|known_types| !known_types.contains(type_name))
+        })
+        .filter(|type_name| {
+            params
+                .paths_filter
+                .as_deref()
+                .is_none_or(|prefix| type_name.starts_with(prefix))
+        })
+        .collect();
 
-    let response = super::generate_type_guide_response(params.port, &all_types).await?;
+    let mut response = super::generate_type_guide_response(params.port, &all_types).await?;
+    params.project(&mut response);
     let type_count = response.discovered_count;
+    let message = if params.since_token.is_some() {
+        format!("Discovered schemas for {type_count} type(s) registered since the given token")
+    } else {
+        format!("Discovered schemas for all {type_count} registered type(s)")
+    };
 
-    Ok(
-        TypeGuideResult::new(response, type_count).with_message_template(format!(
-            "Discovered schemas for all {type_count} registered type(s)"
-        )),
-    )
+    Ok(TypeGuideResult::from_response(response, message).with_registry_token(token))
 }
 
 /// Helper function to fetch a list of type names from a BRP method
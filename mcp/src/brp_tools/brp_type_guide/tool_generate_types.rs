@@ -0,0 +1,681 @@
+//! `brp_generate_types` tool - Generate Rust or TypeScript types from the registry
+//!
+//! Every struct and enum the app registers from the requested crates becomes a definition that
+//! (de)serializes the way BRP does: structs as objects, tuple structs as arrays (or as their only
+//! field), enums externally tagged, and glam vectors and quaternions as arrays. Referenced types
+//! from other crates map to their Rust or TypeScript equivalent when they are primitives,
+//! collections or `Option`s; anything else is emitted as an opaque JSON value and listed in
+//! `opaque_types`.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::brp_type_name::BrpTypeName;
+use super::math_corrections::math_type_fields;
+use super::math_corrections::option_inner_type;
+use super::tool_type_guide;
+use super::type_kind::TypeKind;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::support::JsonObjectAccess;
+use crate::support::SchemaField;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// Rust keywords that need a raw identifier when used as field names
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod",
+    "move", "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+    "unsafe", "use", "where", "while", "yield",
+];
+
+/// Registry value types serialized as JSON strings
+const STRING_TYPES: &[&str] = &[
+    "alloc::borrow::Cow<str>",
+    "alloc::string::String",
+    "char",
+    "smol_str::SmolStr",
+    "std::path::PathBuf",
+];
+
+/// Registry value types serialized as JSON numbers
+const NUMBER_TYPES: &[&str] = &[
+    "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+    "usize",
+];
+
+/// Language the types are generated in
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeLanguage {
+    /// Rust structs and enums deriving `serde::Serialize` and `serde::Deserialize`
+    #[default]
+    Rust,
+    /// TypeScript interfaces and type aliases
+    #[serde(rename = "typescript")]
+    TypeScript,
+}
+
+/// Parameters for the `brp_generate_types` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GenerateTypesParams {
+    /// Crates whose registered structs and enums to generate (e.g. [`my_game`])
+    pub crates:      Vec<String>,
+    /// File to write the generated types to
+    pub output_path: String,
+    /// Language to generate: `rust` (default) or `typescript`
+    #[serde(default)]
+    pub language:    TypeLanguage,
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port:        Port,
+}
+
+/// Result from generating types
+#[derive(Serialize, ResultStruct)]
+pub struct GenerateTypesResult {
+    /// Path of the generated file
+    #[to_result]
+    output_path:  String,
+    /// Number of types defined in the file
+    #[to_metadata]
+    type_count:   usize,
+    /// Referenced types outside the requested crates that were emitted as opaque JSON values
+    #[to_metadata]
+    opaque_types: Vec<String>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Generated {type_count} types in {output_path}")]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "GenerateTypesParams", output = "GenerateTypesResult")]
+pub struct BrpGenerateTypes;
+
+async fn handle_impl(params: GenerateTypesParams) -> Result<GenerateTypesResult> {
+    // No requested types means the complete registry, which referenced std types need
+    let registry = tool_type_guide::fetch_registry(params.port, &[]).await?;
+    let generated = generate_types(&registry, &params.crates, params.language, params.port);
+    if generated.type_count == 0 {
+        return Err(Error::InvalidArgument(format!(
+            "No structs or enums are registered for crates [{}] on port {}",
+            params.crates.join(", "),
+            params.port
+        ))
+        .into());
+    }
+
+    let output_path = PathBuf::from(&params.output_path);
+    tokio::fs::write(&output_path, generated.source)
+        .await
+        .map_err(|e| Error::io_failed("write generated types", &output_path, &e))?;
+
+    Ok(GenerateTypesResult::new(
+        output_path.display().to_string(),
+        generated.type_count,
+        generated.opaque_types.into_iter().collect(),
+    ))
+}
+
+/// Source generated for one set of crates
+struct GeneratedTypes {
+    source:       String,
+    type_count:   usize,
+    opaque_types: BTreeSet<String>,
+}
+
+/// Generate definitions for the structs and enums registered from `crates`
+fn generate_types(
+    registry: &HashMap<BrpTypeName, Value>,
+    crates: &[String],
+    language: TypeLanguage,
+    port: Port,
+) -> GeneratedTypes {
+    let mut generator = TypeGenerator {
+        registry,
+        identifiers: select_types(registry, crates),
+        language,
+        opaque_types: BTreeSet::new(),
+    };
+
+    let mut definitions: BTreeMap<String, String> = BTreeMap::new();
+    for (type_name, identifier) in &generator.identifiers.clone() {
+        if let Some(schema) = registry.get(type_name) {
+            let definition = generator.definition(type_name, identifier, schema);
+            definitions.insert(identifier.clone(), definition);
+        }
+    }
+
+    let body = definitions.into_values().collect::<Vec<_>>().join("\n");
+    let header = [
+        format!(
+            "Types generated by `brp_generate_types` from the registry of the app on port {port}."
+        ),
+        "Regenerate them instead of editing this file.".to_string(),
+    ];
+    let source = match language {
+        TypeLanguage::Rust => {
+            let mut imports = Vec::new();
+            if body.contains("HashMap<") {
+                imports.push("use std::collections::HashMap;\n");
+            }
+            imports.push("use serde::Deserialize;\nuse serde::Serialize;\n");
+            format!(
+                "//! {}\n//! {}\n\n{}\n{body}",
+                header[0],
+                header[1],
+                imports.join("\n")
+            )
+        },
+        TypeLanguage::TypeScript => format!("// {}\n// {}\n\n{body}", header[0], header[1]),
+    };
+
+    GeneratedTypes {
+        source,
+        type_count: generator.identifiers.len(),
+        opaque_types: generator.opaque_types,
+    }
+}
+
+/// Structs and enums registered from `crates`, with a unique identifier for each
+///
+/// Identifiers come from the short type name; when two types share one, both use their full
+/// path instead.
+fn select_types(
+    registry: &HashMap<BrpTypeName, Value>,
+    crates: &[String],
+) -> BTreeMap<BrpTypeName, String> {
+    let selected: BTreeMap<String, &Value> = registry
+        .iter()
+        .filter(|(type_name, schema)| {
+            let crate_name = schema
+                .get_field_str(SchemaField::CrateName)
+                .or_else(|| type_name.as_str().split("::").next());
+            crate_name.is_some_and(|crate_name| crates.iter().any(|wanted| wanted == crate_name))
+                && matches!(
+                    TypeKind::from(*schema),
+                    TypeKind::Struct | TypeKind::TupleStruct | TypeKind::Enum
+                )
+        })
+        .map(|(type_name, schema)| (type_name.to_string(), schema))
+        .collect();
+
+    let short_identifiers: Vec<(String, String)> = selected
+        .iter()
+        .map(|(type_name, schema)| {
+            let short_path = schema.get_field_str(SchemaField::ShortPath).map_or_else(
+                || BrpTypeName::from(type_name.as_str()).short_name(),
+                String::from,
+            );
+            (type_name.clone(), identifier(&short_path))
+        })
+        .collect();
+    let mut uses: HashMap<&str, usize> = HashMap::new();
+    for (_, short_identifier) in &short_identifiers {
+        *uses.entry(short_identifier.as_str()).or_default() += 1;
+    }
+
+    short_identifiers
+        .iter()
+        .map(|(type_name, short_identifier)| {
+            let identifier = if uses.get(short_identifier.as_str()) == Some(&1) {
+                short_identifier.clone()
+            } else {
+                identifier(type_name)
+            };
+            (BrpTypeName::from(type_name.as_str()), identifier)
+        })
+        .collect()
+}
+
+/// `PascalCase` identifier made from the alphanumeric runs of a type path
+fn identifier(type_path: &str) -> String {
+    let mut identifier = String::new();
+    let mut capitalize = true;
+    for character in type_path.chars() {
+        if character.is_ascii_alphanumeric() {
+            if capitalize {
+                identifier.push(character.to_ascii_uppercase());
+            } else {
+                identifier.push(character);
+            }
+            capitalize = false;
+        } else {
+            capitalize = true;
+        }
+    }
+    if identifier.starts_with(|first: char| first.is_ascii_digit()) {
+        identifier.insert(0, 'T');
+    }
+    identifier
+}
+
+struct TypeGenerator<'a> {
+    registry:     &'a HashMap<BrpTypeName, Value>,
+    identifiers:  BTreeMap<BrpTypeName, String>,
+    language:     TypeLanguage,
+    opaque_types: BTreeSet<String>,
+}
+
+impl TypeGenerator<'_> {
+    fn definition(&mut self, type_name: &BrpTypeName, identifier: &str, schema: &Value) -> String {
+        match self.language {
+            TypeLanguage::Rust => self.rust_definition(type_name, identifier, schema),
+            TypeLanguage::TypeScript => self.typescript_definition(type_name, identifier, schema),
+        }
+    }
+
+    fn rust_definition(
+        &mut self,
+        type_name: &BrpTypeName,
+        identifier: &str,
+        schema: &Value,
+    ) -> String {
+        let mut definition = format!(
+            "/// `{type_name}`\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n"
+        );
+        if TypeKind::from(schema) == TypeKind::Enum {
+            let _ = writeln!(definition, "pub enum {identifier} {{");
+            for variant in schema
+                .get_field_array(SchemaField::OneOf)
+                .unwrap_or_default()
+            {
+                let variant_definition = match variant {
+                    Value::String(name) => name.clone(),
+                    variant => {
+                        let name = variant
+                            .get_field_str(SchemaField::ShortPath)
+                            .unwrap_or_default();
+                        format!("{name}{}", self.rust_fields(variant, ""))
+                    },
+                };
+                let _ = writeln!(definition, "    {variant_definition},");
+            }
+            definition.push_str("}\n");
+        } else {
+            let fields = self.rust_fields(schema, "pub ");
+            let terminator = if fields.starts_with('(') { ";" } else { "" };
+            let _ = writeln!(definition, "pub struct {identifier}{fields}{terminator}");
+        }
+        definition
+    }
+
+    /// Fields of a struct, tuple struct or variant: `{ .. }`, `(..)`, or nothing for a unit variant
+    fn rust_fields(&mut self, schema: &Value, visibility: &str) -> String {
+        if let Some(properties) = schema.get_properties() {
+            let fields: Vec<String> = properties
+                .iter()
+                .map(|(field_name, field_schema)| {
+                    format!(
+                        "{visibility}{}: {}",
+                        raw_identifier(field_name),
+                        self.field_type(field_schema)
+                    )
+                })
+                .collect();
+            let indent = if visibility.is_empty() {
+                "        "
+            } else {
+                "    "
+            };
+            let closing = if visibility.is_empty() { "    }" } else { "}" };
+            return format!(
+                " {{\n{}\n{closing}",
+                fields
+                    .iter()
+                    .map(|field| format!("{indent}{field},"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+        let elements: Vec<String> = schema
+            .get_field_array(SchemaField::PrefixItems)
+            .unwrap_or_default()
+            .iter()
+            .map(|element| format!("{visibility}{}", self.field_type(element)))
+            .collect();
+        if elements.is_empty() {
+            // Field-less structs are serialized as empty objects
+            return if visibility.is_empty() {
+                String::new()
+            } else {
+                " {}".to_string()
+            };
+        }
+        format!("({})", elements.join(", "))
+    }
+
+    fn typescript_definition(
+        &mut self,
+        type_name: &BrpTypeName,
+        identifier: &str,
+        schema: &Value,
+    ) -> String {
+        let comment = format!("/** `{type_name}` */\n");
+        if TypeKind::from(schema) == TypeKind::Enum {
+            let variants: String = schema
+                .get_field_array(SchemaField::OneOf)
+                .unwrap_or_default()
+                .iter()
+                .map(|variant| match variant {
+                    Value::String(name) => format!("\n  | \"{name}\""),
+                    variant => {
+                        let name = variant
+                            .get_field_str(SchemaField::ShortPath)
+                            .unwrap_or_default();
+                        match self.typescript_payload(variant) {
+                            Some(payload) => {
+                                format!("\n  | {{ {}: {payload} }}", property_name(name))
+                            },
+                            None => format!("\n  | \"{name}\""),
+                        }
+                    },
+                })
+                .collect();
+            return format!("{comment}export type {identifier} ={variants};\n");
+        }
+
+        if let Some(properties) = schema.get_properties() {
+            let mut fields = String::new();
+            for (field_name, field_schema) in properties {
+                let _ = writeln!(
+                    fields,
+                    "  {}: {};",
+                    property_name(field_name),
+                    self.field_type(field_schema)
+                );
+            }
+            return format!("{comment}export interface {identifier} {{\n{fields}}}\n");
+        }
+        let payload = self
+            .typescript_payload(schema)
+            .unwrap_or_else(|| "Record<string, never>".to_string());
+        format!("{comment}export type {identifier} = {payload};\n")
+    }
+
+    /// Serialized shape of a tuple struct or variant payload, `None` for unit variants
+    fn typescript_payload(&mut self, schema: &Value) -> Option<String> {
+        if let Some(properties) = schema.get_properties() {
+            let fields: Vec<String> = properties
+                .iter()
+                .map(|(field_name, field_schema)| {
+                    format!(
+                        "{}: {}",
+                        property_name(field_name),
+                        self.field_type(field_schema)
+                    )
+                })
+                .collect();
+            return Some(format!("{{ {} }}", fields.join("; ")));
+        }
+        let elements: Vec<String> = schema
+            .get_field_array(SchemaField::PrefixItems)
+            .unwrap_or_default()
+            .iter()
+            .map(|element| self.field_type(element))
+            .collect();
+        match elements.as_slice() {
+            [] => None,
+            // Single-field tuples are serialized as their field
+            [element] => Some(element.clone()),
+            elements => Some(format!("[{}]", elements.join(", "))),
+        }
+    }
+
+    /// Type expression for a field schema (`{"type": {"$ref": ..}}`)
+    fn field_type(&mut self, field_schema: &Value) -> String {
+        match field_schema.extract_field_type() {
+            Some(type_name) => self.type_expression(&type_name),
+            None => self.opaque("unknown"),
+        }
+    }
+
+    fn type_expression(&mut self, type_name: &BrpTypeName) -> String {
+        let rust = self.language == TypeLanguage::Rust;
+        if let Some(identifier) = self.identifiers.get(type_name) {
+            return identifier.clone();
+        }
+        if let Some(fields) = math_type_fields(type_name) {
+            return math_array(type_name, fields.len(), rust);
+        }
+        if let Some(inner) = option_inner_type(type_name) {
+            let inner = self.type_expression(&inner);
+            return if rust {
+                format!("Option<{inner}>")
+            } else {
+                format!("{inner} | null")
+            };
+        }
+
+        let type_path = type_name.as_str();
+        if NUMBER_TYPES.contains(&type_path) {
+            return if rust { type_path } else { "number" }.to_string();
+        }
+        if STRING_TYPES.contains(&type_path) {
+            return if rust { "String" } else { "string" }.to_string();
+        }
+        if type_path == "bool" {
+            return if rust { "bool" } else { "boolean" }.to_string();
+        }
+
+        let Some(schema) = self.registry.get(type_name) else {
+            return self.opaque(type_path);
+        };
+        match TypeKind::from(schema) {
+            TypeKind::List | TypeKind::Array | TypeKind::Set => {
+                let Some(item_type) = schema.get_type(SchemaField::Items) else {
+                    return self.opaque(type_path);
+                };
+                let item = self.type_expression(&item_type);
+                if rust {
+                    format!("Vec<{item}>")
+                } else if item.contains(' ') {
+                    format!("({item})[]")
+                } else {
+                    format!("{item}[]")
+                }
+            },
+            TypeKind::Map => {
+                let (Some(key_type), Some(value_type)) = (
+                    schema.get_type(SchemaField::KeyType),
+                    schema.get_type(SchemaField::ValueType),
+                ) else {
+                    return self.opaque(type_path);
+                };
+                let value = self.type_expression(&value_type);
+                if rust {
+                    format!("HashMap<{}, {value}>", self.type_expression(&key_type))
+                } else {
+                    format!("Record<string, {value}>")
+                }
+            },
+            TypeKind::Tuple => {
+                let elements: Vec<String> = schema
+                    .get_field_array(SchemaField::PrefixItems)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|element| self.field_type(element))
+                    .collect();
+                if rust {
+                    format!("({})", elements.join(", "))
+                } else {
+                    format!("[{}]", elements.join(", "))
+                }
+            },
+            TypeKind::Enum | TypeKind::Struct | TypeKind::TupleStruct | TypeKind::Value => {
+                self.opaque(type_path)
+            },
+        }
+    }
+
+    /// Record `type_path` as opaque and return the language's any-JSON type
+    fn opaque(&mut self, type_path: &str) -> String {
+        self.opaque_types.insert(type_path.to_string());
+        match self.language {
+            TypeLanguage::Rust => "serde_json::Value".to_string(),
+            TypeLanguage::TypeScript => "unknown".to_string(),
+        }
+    }
+}
+
+/// Fixed-size array a glam vector or quaternion is serialized as
+fn math_array(type_name: &BrpTypeName, length: usize, rust: bool) -> String {
+    if !rust {
+        return format!("[{}]", vec!["number"; length].join(", "));
+    }
+    let element = match type_name.short_name().chars().next() {
+        Some('I') => "i32",
+        Some('U') => "u32",
+        Some('D') => "f64",
+        _ => "f32",
+    };
+    format!("[{element}; {length}]")
+}
+
+/// `name`, as a raw identifier if it is a Rust keyword
+fn raw_identifier(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// `name` as a TypeScript property name, quoted unless it is a plain identifier
+fn property_name(name: &str) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '_');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::TypeLanguage;
+    use super::generate_types;
+    use crate::brp_tools::Port;
+    use crate::brp_tools::brp_type_guide::brp_type_name::BrpTypeName;
+
+    fn registry() -> HashMap<BrpTypeName, Value> {
+        HashMap::from([
+            (
+                BrpTypeName::from("game::Player"),
+                json!({
+                    "kind": "Struct",
+                    "crateName": "game",
+                    "shortPath": "Player",
+                    "properties": {
+                        "position": { "type": { "$ref": "#/$defs/glam::Vec3" } },
+                        "mode": { "type": { "$ref": "#/$defs/game::Mode" } },
+                        "tags": { "type": { "$ref": "#/$defs/alloc::vec::Vec<alloc::string::String>" } },
+                        "type": { "type": { "$ref": "#/$defs/core::option::Option<u32>" } }
+                    }
+                }),
+            ),
+            (
+                BrpTypeName::from("game::Mode"),
+                json!({
+                    "kind": "Enum",
+                    "crateName": "game",
+                    "shortPath": "Mode",
+                    "oneOf": [
+                        "Idle",
+                        {
+                            "kind": "Tuple",
+                            "shortPath": "Moving",
+                            "prefixItems": [{ "type": { "$ref": "#/$defs/f32" } }]
+                        }
+                    ]
+                }),
+            ),
+            (
+                BrpTypeName::from("alloc::vec::Vec<alloc::string::String>"),
+                json!({
+                    "kind": "List",
+                    "crateName": "alloc",
+                    "items": { "type": { "$ref": "#/$defs/alloc::string::String" } }
+                }),
+            ),
+            (
+                BrpTypeName::from("bevy_ecs::entity::Entity"),
+                json!({ "kind": "Value", "crateName": "bevy_ecs" }),
+            ),
+        ])
+    }
+
+    #[test]
+    fn generates_rust_types_for_the_requested_crates() {
+        let generated = generate_types(
+            &registry(),
+            &["game".to_string()],
+            TypeLanguage::Rust,
+            Port::default(),
+        );
+
+        assert_eq!(generated.type_count, 2);
+        assert!(
+            generated
+                .source
+                .contains("pub struct Player {\n    pub position: [f32; 3],")
+        );
+        assert!(generated.source.contains("    pub tags: Vec<String>,"));
+        assert!(generated.source.contains("    pub r#type: Option<u32>,"));
+        assert!(
+            generated
+                .source
+                .contains("pub enum Mode {\n    Idle,\n    Moving(f32),\n}")
+        );
+        assert!(generated.opaque_types.is_empty());
+    }
+
+    #[test]
+    fn generates_typescript_types_for_the_requested_crates() {
+        let generated = generate_types(
+            &registry(),
+            &["game".to_string()],
+            TypeLanguage::TypeScript,
+            Port::default(),
+        );
+
+        assert!(
+            generated
+                .source
+                .contains("export interface Player {\n  position: [number, number, number];")
+        );
+        assert!(generated.source.contains("  type: number | null;"));
+        assert!(
+            generated
+                .source
+                .contains("export type Mode =\n  | \"Idle\"\n  | { Moving: number };")
+        );
+    }
+}
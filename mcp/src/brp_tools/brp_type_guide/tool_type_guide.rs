@@ -4,12 +4,17 @@
 //! the `bevy_brp_extras` plugin. It uses registry schema calls combined with hardcoded BRP
 //! serialization knowledge to provide accurate type schema information for BRP operations.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
+use std::time::Instant;
 
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
 use bevy_brp_mcp_macros::ToolFn;
+use futures::future::join_all;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -17,14 +22,22 @@ use serde_json::Value;
 use serde_json::json;
 
 use super::brp_type_name::BrpTypeName;
+use super::cache;
+use super::cache::RegistryChecksums;
+use super::constants::COMPONENTS_FIELD;
+use super::constants::MAX_INCREMENTAL_REGISTRY_CRATES;
+use super::constants::MAX_TYPE_GUIDE_WORKERS;
+use super::constants::MIN_TYPES_PER_WORKER;
 use super::guide::TypeGuide;
 use super::response::TypeGuideResponse;
-use super::response::TypeGuideSummary;
+use super::response::TypeGuideTiming;
 use crate::brp_tools::BrpClient;
 use crate::brp_tools::Port;
 use crate::brp_tools::ResponseStatus;
+use crate::constants::SCHEMA_REF_PREFIX;
 use crate::error::Error;
 use crate::error::Result;
+use crate::support::SchemaField;
 use crate::tool::BrpMethod;
 use crate::tool::HandlerContext;
 use crate::tool::HandlerResult;
@@ -37,6 +50,11 @@ pub struct TypeGuideParams {
     /// Array of fully-qualified component type names to discover formats for
     pub types: Vec<String>,
 
+    /// Entity whose current component values are included, with the enum variants they hold
+    /// marked on the mutation paths
+    #[to_metadata(skip_if_none)]
+    pub entity: Option<u64>,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
@@ -53,11 +71,55 @@ pub struct TypeGuideResult {
     #[to_metadata]
     type_count: usize,
 
+    /// Time spent fetching the registry schema, in milliseconds
+    #[to_metadata]
+    registry_fetch_ms: u64,
+
+    /// Time spent building guides, in milliseconds
+    #[to_metadata]
+    generation_ms: u64,
+
+    /// Number of threads that built guides in parallel
+    #[to_metadata]
+    generation_workers: usize,
+
+    /// Number of guides served from the type guide cache instead of being rebuilt
+    #[to_metadata]
+    cached_guides: usize,
+
+    /// Token for the current registry state, to pass as `since_token` to `brp_all_type_guides`
+    #[to_metadata(skip_if_none)]
+    registry_token: Option<String>,
+
     /// Message template for formatting responses
     #[to_message]
     message_template: Option<String>,
 }
 
+impl TypeGuideResult {
+    /// Wrap a response, lifting its counts and timing into metadata
+    pub(super) fn from_response(response: TypeGuideResponse, message_template: String) -> Self {
+        let type_count = response.discovered_count;
+        let timing = response.timing;
+        Self::new(
+            response,
+            type_count,
+            timing.registry_fetch_ms,
+            timing.generation_ms,
+            timing.workers,
+            timing.cached_guides,
+            None,
+        )
+        .with_message_template(message_template)
+    }
+
+    /// Attach the registry token a later incremental call can start from
+    pub(super) fn with_registry_token(mut self, registry_token: String) -> Self {
+        self.registry_token = Some(registry_token);
+        self
+    }
+}
+
 /// The main tool struct for type schema discovery
 #[derive(ToolFn)]
 #[tool_fn(params = "TypeGuideParams", output = "TypeGuideResult")]
@@ -65,31 +127,136 @@ pub struct BrpTypeGuide;
 
 /// Thin orchestration function: build engine and delegate the work to it.
 async fn handle_impl(params: TypeGuideParams) -> Result<TypeGuideResult> {
-    let type_guide_response = generate_type_guide_response(params.port, &params.types).await?;
+    let mut type_guide_response = generate_type_guide_response(params.port, &params.types).await?;
+    if let Some(entity) = params.entity {
+        apply_entity_values(params.port, entity, &mut type_guide_response).await?;
+    }
     let type_count = type_guide_response.discovered_count;
 
-    Ok(TypeGuideResult::new(type_guide_response, type_count)
-        .with_message_template(format!("Discovered {type_count} type(s)")))
+    Ok(TypeGuideResult::from_response(
+        type_guide_response,
+        format!("Discovered {type_count} type(s)"),
+    ))
+}
+
+/// Attach `entity`'s current values to the guides of the components it has
+///
+/// Components the entity lacks keep their type-only guide.
+async fn apply_entity_values(
+    port: Port,
+    entity: u64,
+    response: &mut TypeGuideResponse,
+) -> Result<()> {
+    let client = BrpClient::new(
+        BrpMethod::WorldGetComponents,
+        port,
+        Some(json!({ "entity": entity, "components": response.requested_types, "strict": false })),
+    );
+    let components = match client.execute_direct_internal_no_enhancement().await? {
+        ResponseStatus::Success(Some(result)) => result
+            .get(COMPONENTS_FIELD)
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default(),
+        ResponseStatus::Success(None) => serde_json::Map::new(),
+        ResponseStatus::Error(error) => {
+            return Err(Error::BrpCommunication(format!(
+                "Failed to get the components of entity {entity}: {}",
+                error.get_message()
+            ))
+            .into());
+        },
+    };
+
+    for (type_path, value) in components {
+        if let Some(type_guide) = response
+            .type_guide
+            .get_mut(&BrpTypeName::from(type_path.as_str()))
+        {
+            type_guide.apply_current_value(value);
+        }
+    }
+    Ok(())
 }
 
-/// orchestrates type schema generation using a single call to get the complete registry
+/// orchestrates type schema generation over the registry schemas the requested types reach
 struct TypeGuideEngine {
     registry: Arc<HashMap<BrpTypeName, Value>>,
 }
 
 impl TypeGuideEngine {
-    /// Create a new engine instance by fetching the complete registry
-    async fn new(port: Port) -> Result<Self> {
-        let registry = Arc::new(Self::get_full_registry(port).await?);
+    /// Create a new engine instance by fetching the registry schemas the requested types need
+    async fn new(port: Port, requested_types: &[String]) -> Result<Self> {
+        let registry = Arc::new(Self::get_registry(port, requested_types).await?);
         Ok(Self { registry })
     }
 
-    /// Get the complete registry
+    /// Get the registry schemas reachable from the requested types
+    ///
+    /// Schemas are fetched with `with_crates` filters, starting from the crates named in the
+    /// requested type paths. Each round follows `$ref`s from newly reachable schemas and fetches
+    /// the crates of any referenced types that are still missing, so asking about a couple of game
+    /// types only pulls the crates those types use. Requests naming more than
+    /// `MAX_INCREMENTAL_REGISTRY_CRATES` crates, or none, fetch the complete registry in one call.
+    async fn get_registry(
+        port: Port,
+        requested_types: &[String],
+    ) -> Result<HashMap<BrpTypeName, Value>> {
+        let requested_crates: BTreeSet<&str> = requested_types
+            .iter()
+            .flat_map(|type_path| crates_in_type_path(type_path))
+            .collect();
+        // An empty `with_crates` filter means no filter, so crate-less requests fetch everything
+        // too
+        if requested_crates.is_empty() || requested_crates.len() > MAX_INCREMENTAL_REGISTRY_CRATES {
+            return Self::fetch_schemas(port, json!({})).await;
+        }
+
+        let mut registry = HashMap::new();
+        let mut fetched_crates: HashSet<String> = HashSet::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut unresolved = requested_types.to_vec();
+
+        loop {
+            let crates: BTreeSet<String> = unresolved
+                .iter()
+                .flat_map(|type_path| crates_in_type_path(type_path))
+                .filter(|crate_name| !fetched_crates.contains(*crate_name))
+                .map(String::from)
+                .collect();
+            if crates.is_empty() {
+                return Ok(registry);
+            }
+            registry.extend(Self::fetch_schemas(port, json!({ "with_crates": crates })).await?);
+            fetched_crates.extend(crates);
+
+            // Walk everything reachable so far; types still missing wait for their crate
+            let mut pending = std::mem::take(&mut unresolved);
+            while let Some(type_path) = pending.pop() {
+                if visited.contains(&type_path) {
+                    continue;
+                }
+                match registry.get(&BrpTypeName::from(type_path.as_str())) {
+                    Some(schema) => {
+                        collect_type_refs(schema, &mut pending);
+                        visited.insert(type_path);
+                    },
+                    None => unresolved.push(type_path),
+                }
+            }
+            // A type whose crates were all fetched is simply not registered
+            unresolved.retain(|type_path| {
+                crates_in_type_path(type_path)
+                    .any(|crate_name| !fetched_crates.contains(crate_name))
+            });
+        }
+    }
+
+    /// Fetch registry schemas matching `filter`
     ///
     /// Fetches fresh registry data from the BRP server on each call.
-    async fn get_full_registry(port: Port) -> Result<HashMap<BrpTypeName, Value>> {
-        // Fetch full registry from BRP
-        let brp_client = BrpClient::new(BrpMethod::RegistrySchema, port, Some(json!({})));
+    async fn fetch_schemas(port: Port, filter: Value) -> Result<HashMap<BrpTypeName, Value>> {
+        let brp_client = BrpClient::new(BrpMethod::RegistrySchema, port, Some(filter));
 
         match brp_client.execute_direct_internal_no_enhancement().await {
             Ok(ResponseStatus::Success(Some(registry_data))) => {
@@ -113,56 +280,217 @@ impl TypeGuideEngine {
     }
 
     /// Generate response for requested types
-    fn generate_response(&self, requested_types: &[String]) -> TypeGuideResponse {
-        // Build the type_guide `HashMap` functionally
-        let type_guide: HashMap<BrpTypeName, TypeGuide> = requested_types
-            .iter()
-            .map(|s| BrpTypeName::from(s.as_str()))
-            .map(|brp_type_name| {
-                let type_guide =
-                    TypeGuide::build(brp_type_name.clone(), Arc::clone(&self.registry))
-                        .unwrap_or_else(|e| {
-                            // Processing failed - type was found but building failed
-                            TypeGuide::processing_failed(
-                                brp_type_name.clone(),
-                                format!("Failed to process type: {e}"),
-                            )
-                        });
-                (brp_type_name, type_guide)
-            })
-            .collect();
+    ///
+    /// Cached guides built from schemas with the same registry checksum are reused. The remaining
+    /// guides are independent of each other, so those types are split across up to
+    /// `MAX_TYPE_GUIDE_WORKERS` blocking tasks that share the registry.
+    async fn generate_response(
+        &self,
+        port: Port,
+        owner_pid: Option<u32>,
+        requested_types: &[String],
+    ) -> Result<TypeGuideResponse> {
+        let started = Instant::now();
+        let checksums = RegistryChecksums::new(&self.registry);
+        let mut registry_checksums: HashMap<BrpTypeName, u64> =
+            HashMap::with_capacity(requested_types.len());
+        let mut type_guide: HashMap<BrpTypeName, TypeGuide> =
+            HashMap::with_capacity(requested_types.len());
+        let mut missing_types = Vec::new();
+        for type_path in requested_types {
+            let type_name = BrpTypeName::from(type_path.as_str());
+            let registry_checksum = checksums.checksum(&type_name);
+            match cache::reusable_guide(port, &type_name, registry_checksum) {
+                Some(guide) => {
+                    type_guide.insert(type_name.clone(), guide);
+                },
+                None => missing_types.push(type_path.clone()),
+            }
+            registry_checksums.insert(type_name, registry_checksum);
+        }
+        let cached_guides = type_guide.len();
 
-        // Calculate summary statistics from the results
-        let successful_discoveries = type_guide
-            .values()
-            .filter(|tg| tg.is_successful_discovery())
-            .count();
-        let failed_discoveries = type_guide
-            .values()
-            .filter(|tg| tg.is_failed_discovery())
-            .count();
-
-        TypeGuideResponse {
-            discovered_count: successful_discoveries,
-            requested_types: requested_types.to_vec(),
-            summary: TypeGuideSummary {
-                failed_discoveries,
-                successful_discoveries,
-                total_requested: requested_types.len(),
-            },
-            type_guide,
+        let workers = worker_count(missing_types.len());
+        let chunk_size = missing_types.len().div_ceil(workers).max(1);
+        let tasks = missing_types.chunks(chunk_size).map(|chunk| {
+            let chunk = chunk.to_vec();
+            let registry = Arc::clone(&self.registry);
+            tokio::task::spawn_blocking(move || build_guides(&chunk, &registry))
+        });
+        for guides in join_all(tasks).await {
+            let guides = guides
+                .map_err(|e| Error::InvalidState(format!("Type guide worker failed: {e}")))?;
+            type_guide.extend(guides);
         }
+
+        // Failed discoveries are cheap to rebuild and may succeed once the type is registered
+        cache::store_guides(
+            port,
+            owner_pid,
+            type_guide
+                .iter()
+                .filter(|(_, guide)| guide.is_successful_discovery())
+                .filter_map(|(type_name, guide)| {
+                    let registry_checksum = registry_checksums.get(type_name)?;
+                    Some((type_name.clone(), *registry_checksum, guide.clone()))
+                }),
+        );
+
+        Ok(TypeGuideResponse::new(
+            requested_types,
+            type_guide,
+            TypeGuideTiming {
+                generation_ms: duration_ms(started),
+                workers,
+                cached_guides,
+                ..TypeGuideTiming::default()
+            },
+        ))
+    }
+}
+
+/// Number of workers for a request: one per `MIN_TYPES_PER_WORKER` types, bounded by the
+/// available parallelism and `MAX_TYPE_GUIDE_WORKERS`
+fn worker_count(type_count: usize) -> usize {
+    let available = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    type_count
+        .div_ceil(MIN_TYPES_PER_WORKER)
+        .clamp(1, available.min(MAX_TYPE_GUIDE_WORKERS))
+}
+
+fn build_guides(
+    requested_types: &[String],
+    registry: &Arc<HashMap<BrpTypeName, Value>>,
+) -> Vec<(BrpTypeName, TypeGuide)> {
+    requested_types
+        .iter()
+        .map(|s| BrpTypeName::from(s.as_str()))
+        .map(|brp_type_name| {
+            let type_guide = TypeGuide::build(brp_type_name.clone(), Arc::clone(registry))
+                .unwrap_or_else(|e| {
+                    // Processing failed - type was found but building failed
+                    TypeGuide::processing_failed(
+                        brp_type_name.clone(),
+                        format!("Failed to process type: {e}"),
+                    )
+                });
+            (brp_type_name, type_guide)
+        })
+        .collect()
+}
+
+/// Crate names in a type path, including those of generic arguments
+///
+/// `bevy_asset::handle::Handle<bevy_image::image::Image>` yields `bevy_asset` and `bevy_image`.
+/// Primitives such as `f32` have no crate and are returned by every registry fetch.
+fn crates_in_type_path(type_path: &str) -> impl Iterator<Item = &str> {
+    type_path
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .filter_map(|segment| segment.split_once("::").map(|(crate_name, _)| crate_name))
+        .filter(|crate_name| !crate_name.is_empty())
+}
+
+/// Collect every type a schema references through `$ref`
+pub(super) fn collect_type_refs(schema: &Value, type_refs: &mut Vec<String>) {
+    match schema {
+        Value::Object(object) => {
+            for (key, value) in object {
+                if key == SchemaField::Ref.as_ref()
+                    && let Some(type_path) = value
+                        .as_str()
+                        .and_then(|reference| reference.strip_prefix(SCHEMA_REF_PREFIX))
+                {
+                    type_refs.push(type_path.to_string());
+                } else {
+                    collect_type_refs(value, type_refs);
+                }
+            }
+        },
+        Value::Array(values) => {
+            for value in values {
+                collect_type_refs(value, type_refs);
+            }
+        },
+        _ => {},
     }
 }
 
+fn duration_ms(started: Instant) -> u64 {
+    u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
 /// Visibility facade over the file-local `TypeGuideEngine`.
 ///
 /// The parent `brp_type_guide` module uses this wrapper so sibling modules do not
-/// depend on the engine type itself.
+/// depend on the engine type itself. While the process that the cached guides were built for
+/// still serves `port`, they are returned without fetching the registry.
 pub async fn generate_type_guide_response(
     port: Port,
     requested_types: &[String],
 ) -> Result<TypeGuideResponse> {
-    let type_guide_engine = TypeGuideEngine::new(port).await?;
-    Ok(type_guide_engine.generate_response(requested_types))
+    if requested_types.is_empty() {
+        return Ok(TypeGuideResponse::new(
+            requested_types,
+            HashMap::new(),
+            TypeGuideTiming::default(),
+        ));
+    }
+
+    let owner_pid = port.listening_pid();
+    if let Some(type_guide) = cache::cached_guides(port, owner_pid, requested_types) {
+        let cached_guides = type_guide.len();
+        return Ok(TypeGuideResponse::new(
+            requested_types,
+            type_guide,
+            TypeGuideTiming {
+                cached_guides,
+                ..TypeGuideTiming::default()
+            },
+        ));
+    }
+
+    let started = Instant::now();
+    let type_guide_engine = TypeGuideEngine::new(port, requested_types).await?;
+    let registry_fetch_ms = duration_ms(started);
+
+    let mut response = type_guide_engine
+        .generate_response(port, owner_pid, requested_types)
+        .await?;
+    response.timing.registry_fetch_ms = registry_fetch_ms;
+    Ok(response)
+}
+
+/// Registry schemas reachable from `requested_types`, for walking values of those types
+pub(super) async fn fetch_registry(
+    port: Port,
+    requested_types: &[String],
+) -> Result<HashMap<BrpTypeName, Value>> {
+    TypeGuideEngine::get_registry(port, requested_types).await
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::collect_type_refs;
+    use super::crates_in_type_path;
+
+    #[test]
+    fn registry_crates_follow_generic_arguments_and_refs() {
+        let crates: Vec<&str> =
+            crates_in_type_path("bevy_asset::handle::Handle<bevy_image::image::Image>").collect();
+        assert_eq!(crates, vec!["bevy_asset", "bevy_image"]);
+        assert_eq!(crates_in_type_path("f32").count(), 0);
+
+        let schema = json!({
+            "properties": {
+                "translation": { "type": { "$ref": "#/$defs/glam::Vec3" } },
+                "tags": { "items": { "type": { "$ref": "#/$defs/alloc::string::String" } } }
+            }
+        });
+        let mut type_refs = Vec::new();
+        collect_type_refs(&schema, &mut type_refs);
+        type_refs.sort();
+        assert_eq!(type_refs, vec!["alloc::string::String", "glam::Vec3"]);
+    }
 }
@@ -6,6 +6,12 @@ pub(super) const AGENT_TOOL_CATALOG_USAGE: &str =
     "Pass an entry's method and matching params to brp_execute.";
 pub(super) const AGENT_TOOL_CATALOG_VERSION: u32 = 1;
 
+// assertion constants
+/// Tolerance of `approx_equals` expectations that give none
+pub(super) const DEFAULT_APPROX_TOLERANCE: f64 = 1e-4;
+/// Mismatching fields listed in a failed assertion before the rest are summarized
+pub(super) const MAX_REPORTED_MISMATCHES: usize = 10;
+
 // network constants
 /// Environment variable name for BRP port
 pub const BRP_EXTRAS_PORT_ENV_VAR: &str = "BRP_EXTRAS_PORT";
@@ -16,5 +22,11 @@ pub const MAX_VALID_PORT: u16 = 65534;
 pub(super) const MIN_VALID_PORT: u16 = 1024;
 pub(super) const VALID_PORT_RANGE: RangeInclusive<u16> = MIN_VALID_PORT..=MAX_VALID_PORT;
 
+// polling constants
+/// Delay between `brp_wait_until` polls when the call gives no `interval_ms`
+pub(super) const DEFAULT_WAIT_UNTIL_INTERVAL_MS: u64 = 100;
+/// How long `brp_wait_until` polls when the call gives no `timeout_ms`
+pub(super) const DEFAULT_WAIT_UNTIL_TIMEOUT_MS: u64 = 5_000;
+
 // query constants
 pub(super) const COMPONENT_SELECTOR_ALL: &str = "all";
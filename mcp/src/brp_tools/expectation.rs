@@ -0,0 +1,221 @@
+//! Predicates on values in BRP responses, shared by `brp_wait_until`, test plan steps, and
+//! scenario assertions
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::assertion;
+use super::assertion::Comparison;
+use super::constants::DEFAULT_APPROX_TOLERANCE;
+
+/// Expected value at a JSON pointer in a BRP response.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Expectation {
+    /// The value equals this JSON value. Differences are reported per field.
+    Equals(Value),
+    /// The value does not equal this JSON value.
+    NotEquals(Value),
+    /// The pointer resolves (`true`) or does not resolve (`false`).
+    Exists(bool),
+    /// A string contains this substring, or an array contains this element.
+    Contains(Value),
+    /// A number is strictly greater than this value.
+    GreaterThan(f64),
+    /// A number is strictly less than this value.
+    LessThan(f64),
+    /// Numbers are within `tolerance` of `value`. Arrays and objects are compared field by field,
+    /// so vectors such as `[x, y, z]` work.
+    ApproxEquals {
+        value:     Value,
+        #[serde(default = "default_approx_tolerance")]
+        tolerance: f64,
+    },
+    /// Every field of this JSON value is present and equal. Fields it does not name are ignored.
+    Includes(Value),
+    /// A string, array, or object has this many characters, items, or fields.
+    Length(usize),
+    /// A string matches this regular expression.
+    Matches(String),
+}
+
+impl Expectation {
+    /// Check `actual` (the value at the pointer, if it resolved) against this expectation.
+    pub(crate) fn check(&self, actual: Option<&Value>) -> std::result::Result<(), String> {
+        let satisfied = match (self, actual) {
+            (Self::Exists(expected), actual) => *expected == actual.is_some(),
+            (_, None) => return Err("value not found at path".to_string()),
+            (Self::Equals(expected), Some(actual)) => {
+                return compare(expected, actual, None, false);
+            },
+            (Self::ApproxEquals { value, tolerance }, Some(actual)) => {
+                return compare(value, actual, Some(*tolerance), false);
+            },
+            (Self::Includes(expected), Some(actual)) => {
+                return compare(expected, actual, None, true);
+            },
+            (Self::Length(expected), Some(actual)) => return check_length(*expected, actual),
+            (Self::Matches(pattern), Some(actual)) => {
+                let regex = Regex::new(pattern)
+                    .map_err(|error| format!("invalid regex '{pattern}': {error}"))?;
+                actual.as_str().is_some_and(|text| regex.is_match(text))
+            },
+            (Self::NotEquals(expected), Some(actual)) => expected != actual,
+            (Self::Contains(needle), Some(Value::Array(items))) => items.contains(needle),
+            (Self::Contains(Value::String(needle)), Some(Value::String(haystack))) => {
+                haystack.contains(needle.as_str())
+            },
+            (Self::Contains(_), Some(_)) => false,
+            (Self::GreaterThan(bound), Some(actual)) => {
+                actual.as_f64().is_some_and(|number| number > *bound)
+            },
+            (Self::LessThan(bound), Some(actual)) => {
+                actual.as_f64().is_some_and(|number| number < *bound)
+            },
+        };
+
+        if satisfied {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {self:?}, got {}",
+                actual.map_or_else(|| "nothing".to_string(), Value::to_string)
+            ))
+        }
+    }
+
+    /// Check the value at `path` (a JSON pointer, or the whole response without one).
+    pub(crate) fn check_at(
+        &self,
+        response: Option<&Value>,
+        path: Option<&str>,
+    ) -> std::result::Result<(), String> {
+        self.check(resolve(response, path))
+    }
+}
+
+/// Compare `actual` against `expected` field by field, failing with a diff of the mismatches.
+fn compare(
+    expected: &Value,
+    actual: &Value,
+    tolerance: Option<f64>,
+    partial: bool,
+) -> std::result::Result<(), String> {
+    let mismatches = assertion::diff(expected, actual, Comparison { tolerance, partial });
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(assertion::render(&mismatches))
+    }
+}
+
+fn check_length(expected: usize, actual: &Value) -> std::result::Result<(), String> {
+    let length = match actual {
+        Value::String(text) => text.chars().count(),
+        Value::Array(items) => items.len(),
+        Value::Object(fields) => fields.len(),
+        _ => {
+            return Err(format!(
+                "expected a string, array, or object of length {expected}, got {actual}"
+            ));
+        },
+    };
+    if length == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected length {expected}, got {length}: {actual}"
+        ))
+    }
+}
+
+const fn default_approx_tolerance() -> f64 { DEFAULT_APPROX_TOLERANCE }
+
+/// The value at `path` in a BRP response, or the whole response without a path.
+pub(crate) fn resolve<'a>(response: Option<&'a Value>, path: Option<&str>) -> Option<&'a Value> {
+    match (response, path) {
+        (Some(value), Some(pointer)) => value.pointer(pointer),
+        (value, None) => value,
+        (None, Some(_)) => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::json;
+
+    use super::Expectation;
+
+    #[test]
+    fn expectations_check_the_value_at_the_pointer() {
+        let response = json!([{ "components": { "my_game::Health": { "current": 40.0 } } }]);
+        let path = Some("/0/components/my_game::Health/current");
+
+        assert!(
+            Expectation::GreaterThan(30.0)
+                .check_at(Some(&response), path)
+                .is_ok()
+        );
+        assert!(
+            Expectation::Equals(json!(50.0))
+                .check_at(Some(&response), path)
+                .is_err()
+        );
+        assert!(
+            Expectation::Exists(false)
+                .check_at(Some(&response), Some("/1"))
+                .is_ok()
+        );
+        assert!(
+            Expectation::Contains(json!("Health"))
+                .check_at(Some(&json!("my_game::Health")), None)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn matchers_for_floats_lengths_subsets_and_patterns() {
+        let transform = json!({ "translation": [1.00001, 2.0, 3.0], "scale": [1.0, 1.0, 1.0] });
+        let approx: Expectation = serde_json::from_value(json!({
+            "approx_equals": { "value": [1.0, 2.0, 3.0] }
+        }))
+        .expect("approx_equals parses with the default tolerance");
+
+        assert!(
+            approx
+                .check_at(Some(&transform), Some("/translation"))
+                .is_ok()
+        );
+        assert!(
+            Expectation::Includes(json!({ "scale": [1.0, 1.0, 1.0] }))
+                .check(Some(&transform))
+                .is_ok()
+        );
+        assert!(
+            Expectation::Length(3)
+                .check_at(Some(&transform), Some("/scale"))
+                .is_ok()
+        );
+        assert_eq!(
+            Expectation::Length(2).check(Some(&json!("abc"))),
+            Err("expected length 2, got 3: \"abc\"".to_string())
+        );
+        assert!(
+            Expectation::Matches("^Player \\d+$".to_string())
+                .check(Some(&json!("Player 12")))
+                .is_ok()
+        );
+        assert!(
+            Expectation::Matches("(".to_string())
+                .check(Some(&json!("Player")))
+                .is_err_and(|message| message.starts_with("invalid regex"))
+        );
+    }
+}
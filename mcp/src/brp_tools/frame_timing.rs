@@ -0,0 +1,19 @@
+//! Frame timing accepted by the `bevy_brp_extras` input and mutation methods
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// When the app runs a request, flattened into the parameters of methods that support it so a call
+/// can be delayed to a later frame with `after_frames` or `run_at_frame`
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+pub struct FrameTiming {
+    /// Run this many frames from now instead of immediately; 1 is the next frame. The response
+    /// reports the `run_at_frame` it was scheduled for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_frames: Option<u32>,
+    /// Run at the start of the first frame whose frame count is at least this value, as reported
+    /// by `brp_extras_ping`. Not combined with `after_frames`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_at_frame: Option<u32>,
+}
@@ -1,9 +1,13 @@
+mod assertion;
 mod brp_client;
 mod brp_type_guide;
 mod constants;
+mod expectation;
+mod frame_timing;
 mod mouse;
 mod port;
 mod tools;
+mod transaction;
 mod watch_tools;
 
 // Public exports
@@ -16,61 +20,142 @@ pub use brp_client::FormatCorrectionStatus;
 pub use brp_client::JSON_RPC_ERROR_METHOD_NOT_FOUND;
 pub use brp_client::ResponseStatus;
 pub use brp_client::ResultStructBrpExt;
+pub use brp_client::UndoEntry;
+pub(crate) use brp_client::extras_method_unsupported;
+pub(crate) use brp_client::forget_extras_capabilities;
+pub(crate) use brp_client::forget_format_corrections;
+pub(crate) use brp_client::forget_undo_history;
 pub(crate) use brp_client::method_not_found_message;
+pub(crate) use brp_client::refresh_extras_capabilities;
+pub(crate) use brp_client::undo_last;
 //
 // Export brp_type_guide tools
 pub use brp_type_guide::AllTypeGuidesParams;
 pub use brp_type_guide::BrpAllTypeGuides;
+pub use brp_type_guide::BrpGenerateTypes;
 pub use brp_type_guide::BrpTypeGuide;
 pub use brp_type_guide::BrpTypeName;
+pub use brp_type_guide::GenerateTypesParams;
 pub use brp_type_guide::TypeGuideParams;
+pub(crate) use brp_type_guide::forget_type_guides;
 pub use constants::BRP_EXTRAS_PORT_ENV_VAR;
 pub use constants::MAX_VALID_PORT;
+pub use expectation::Expectation;
+pub use frame_timing::FrameTiming;
 pub use port::Port;
+pub use port::PortOwnerChange;
 //
 // Export all tool parameter and result structs via the tools facade
+pub use tools::BrpCompareScreenshots;
+pub use tools::BrpCompareSnapshots;
+pub use tools::BrpDiffEntities;
 pub use tools::BrpExecute;
+pub use tools::BrpExecuteBatch;
 pub use tools::BrpExtrasScreenshot;
 pub use tools::BrpListAgentTools;
+pub use tools::BrpListTags;
+pub use tools::BrpTagEntity;
+pub use tools::BrpUndoLast;
+pub use tools::BrpWaitUntil;
+pub use tools::CapabilitiesParams;
+pub use tools::CapabilitiesResult;
+pub use tools::ClearOverridesParams;
+pub use tools::ClearOverridesResult;
 pub use tools::ClickMouseParams;
 pub use tools::ClickMouseResult;
+pub use tools::ClickUiNodeParams;
+pub use tools::ClickUiNodeResult;
+pub use tools::CompareScreenshotsParams;
+pub use tools::CompareSnapshotsParams;
+pub use tools::ComponentStatsParams;
 pub use tools::DespawnEntityParams;
 pub use tools::DespawnEntityResult;
+pub use tools::DespawnRecursiveParams;
+pub use tools::DespawnRecursiveResult;
+pub use tools::DiffEntitiesParams;
 pub use tools::DoubleClickMouseParams;
 pub use tools::DoubleClickMouseResult;
 pub use tools::DoubleTapGestureParams;
 pub use tools::DoubleTapGestureResult;
 pub use tools::DragMouseParams;
 pub use tools::DragMouseResult;
+pub use tools::DrawGizmoParams;
+pub use tools::DrawGizmoResult;
+pub use tools::ExecuteBatchParams;
 pub use tools::ExecuteParams;
 pub use tools::FindEntitiesByNameParams;
+pub use tools::GetAssetInfoParams;
+pub use tools::GetAssetInfoResult;
+pub use tools::GetComponentRawParams;
+pub use tools::GetComponentRawResult;
 pub use tools::GetComponentsParams;
 pub use tools::GetComponentsResult;
+pub use tools::GetContactsParams;
+pub use tools::GetContactsResult;
 pub use tools::GetDiagnosticsParams;
 pub use tools::GetDiagnosticsResult;
 pub use tools::GetResourcesParams;
 pub use tools::GetResourcesResult;
+pub use tools::GetScheduleGraphParams;
+pub use tools::GetScheduleGraphResult;
+pub use tools::GetVisibleTextParams;
+pub use tools::GetVisibleTextResult;
+pub use tools::GetWorldStatsParams;
+pub use tools::GetWorldStatsResult;
+pub use tools::HighlightEntityParams;
+pub use tools::HighlightEntityResult;
+pub use tools::HoverMouseParams;
+pub use tools::HoverMouseResult;
 pub use tools::InsertComponentsParams;
 pub use tools::InsertComponentsResult;
 pub use tools::InsertResourcesParams;
 pub use tools::InsertResourcesResult;
+pub use tools::InspectEntityParams;
+pub use tools::InspectResourcesParams;
 pub use tools::ListAgentToolsParams;
+pub use tools::ListAssetsParams;
+pub use tools::ListAssetsResult;
 pub use tools::ListComponentsParams;
 pub use tools::ListComponentsResult;
 pub use tools::ListResourcesParams;
 pub use tools::ListResourcesResult;
+pub use tools::ListSystemsParams;
+pub use tools::ListSystemsResult;
+pub use tools::ListTagsParams;
+pub use tools::LoadWorldSnapshotParams;
+pub use tools::LoadWorldSnapshotResult;
+pub use tools::LookAtParams;
+pub use tools::LookAtResult;
 pub use tools::MoveMouseParams;
 pub use tools::MoveMouseResult;
 pub use tools::MutateComponentsParams;
 pub use tools::MutateComponentsResult;
+pub use tools::MutateReflectParams;
+pub use tools::MutateReflectResult;
 pub use tools::MutateResourcesParams;
 pub use tools::MutateResourcesResult;
+pub use tools::OrbitCameraParams;
+pub use tools::OrbitCameraResult;
+pub use tools::PickEntityParams;
+pub use tools::PickEntityResult;
 pub use tools::PinchGestureParams;
 pub use tools::PinchGestureResult;
+pub use tools::PingParams;
+pub use tools::PingResult;
+pub use tools::PressKeysParams;
+pub use tools::PressKeysResult;
+pub use tools::QueryChangedParams;
+pub use tools::QueryChangedResult;
 pub use tools::QueryParams;
 pub use tools::QueryResult;
+pub use tools::QuerySpatialParams;
+pub use tools::QuerySpatialResult;
 pub use tools::RegistrySchemaParams;
 pub use tools::RegistrySchemaResult;
+pub use tools::ReleaseKeysParams;
+pub use tools::ReleaseKeysResult;
+pub use tools::ReloadAssetParams;
+pub use tools::ReloadAssetResult;
 pub use tools::RemoveComponentsParams;
 pub use tools::RemoveComponentsResult;
 pub use tools::RemoveResourcesParams;
@@ -81,28 +166,86 @@ pub use tools::RotationGestureParams;
 pub use tools::RotationGestureResult;
 pub use tools::RpcDiscoverParams;
 pub use tools::RpcDiscoverResult;
+pub use tools::RunInputScriptParams;
+pub use tools::RunInputScriptResult;
+pub use tools::RunSystemByNameParams;
+pub use tools::RunSystemByNameResult;
+pub use tools::SaveWorldSnapshotParams;
+pub use tools::SaveWorldSnapshotResult;
+pub use tools::ScreenToWorldParams;
+pub use tools::ScreenToWorldResult;
 pub use tools::ScreenshotParams;
 pub use tools::ScrollMouseParams;
 pub use tools::ScrollMouseResult;
+pub use tools::SendEventParams;
+pub use tools::SendEventResult;
+pub use tools::SendImeParams;
+pub use tools::SendImeResult;
 pub use tools::SendKeysParams;
 pub use tools::SendKeysResult;
 pub use tools::SendMouseButtonParams;
 pub use tools::SendMouseButtonResult;
+pub use tools::SetAmbientLightParams;
+pub use tools::SetAmbientLightResult;
+pub use tools::SetCameraTransformParams;
+pub use tools::SetCameraTransformResult;
+pub use tools::SetClearColorParams;
+pub use tools::SetClearColorResult;
+pub use tools::SetComponentOverrideParams;
+pub use tools::SetComponentOverrideResult;
+pub use tools::SetGravityParams;
+pub use tools::SetGravityResult;
+pub use tools::SetLinearVelocityParams;
+pub use tools::SetLinearVelocityResult;
+pub use tools::SetWindowParams;
+pub use tools::SetWindowResult;
 pub use tools::SetWindowTitleParams;
 pub use tools::SetWindowTitleResult;
 pub use tools::SpawnEntityParams;
 pub use tools::SpawnEntityResult;
+pub use tools::StartInputRecordingParams;
+pub use tools::StartInputRecordingResult;
+pub use tools::StopInputRecordingParams;
+pub use tools::StopInputRecordingResult;
+pub use tools::TagEntityParams;
 pub use tools::TriggerEventParams;
 pub use tools::TriggerEventResult;
 pub use tools::TypeTextParams;
 pub use tools::TypeTextResult;
+pub use tools::UndoLastParams;
+pub use tools::WaitForParams;
+pub use tools::WaitForResult;
+pub use tools::WaitUntilParams;
+pub use tools::WorldComponentStats;
 pub use tools::WorldFindEntitiesByName;
+pub use tools::WorldInspectEntity;
+pub use tools::WorldInspectResources;
+pub use tools::WorldToScreenParams;
+pub use tools::WorldToScreenResult;
+//
+// Export transaction tool
+pub use transaction::BrpTransaction;
+pub use transaction::TransactionParams;
 //
 // Export watch tools
 pub use watch_tools::BevyListWatch;
+pub use watch_tools::BrpConvertWatchLog;
+pub use watch_tools::BrpExtrasDiagnosticsWatch;
 pub use watch_tools::BrpListActiveWatches;
 pub use watch_tools::BrpStopWatch;
+pub use watch_tools::ConvertWatchLogParams;
+pub use watch_tools::DiagnosticsWatchParams;
+pub use watch_tools::EventsWatchParams;
 pub use watch_tools::GetComponentsWatchParams;
 pub use watch_tools::ListComponentsWatchParams;
 pub use watch_tools::StopWatchParams;
+pub use watch_tools::WorldEventsWatch;
 pub use watch_tools::WorldGetComponentsWatch;
+pub(crate) use watch_tools::is_watch_resource;
+pub(crate) use watch_tools::list_watch_resources;
+pub(crate) use watch_tools::mark_watch_log_read;
+pub(crate) use watch_tools::read_watch_resource;
+pub(crate) use watch_tools::set_client_peer;
+pub(crate) use watch_tools::start_log_watch_task;
+pub(crate) use watch_tools::subscribe_watch_resource;
+pub(crate) use watch_tools::unsubscribe_watch_resource;
@@ -2,18 +2,38 @@
 //!
 //! Provides a type-safe wrapper around port numbers with built-in validation
 //! and default values for BRP connections.
+//!
+//! Ports also remember which process last served them. When a restart reshuffles ports, a call
+//! that reaches a different process than the previous call reports a `PortOwnerChange` so an agent
+//! does not keep mutating the wrong app with entity IDs from the old one.
 
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::net::Ipv4Addr;
+use std::net::TcpListener;
 use std::ops::Deref;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Instant;
 
+use netstat2::AddressFamilyFlags;
+use netstat2::ProtocolFlags;
+use netstat2::ProtocolSocketInfo;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use sysinfo::Pid;
+use sysinfo::ProcessesToUpdate;
+use sysinfo::System;
 
 use super::constants::DEFAULT_BRP_EXTRAS_PORT;
 use super::constants::VALID_PORT_RANGE;
 
+/// Process last seen serving each port, and when it was seen
+static PORT_OWNERS: LazyLock<Mutex<HashMap<Port, (PortOwner, Instant)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 /// Port number for BRP - defaults to 15702
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, JsonSchema, Serialize, Deserialize)]
 #[serde(try_from = "u16")]
@@ -48,3 +68,146 @@ impl Deref for Port {
 
     fn deref(&self) -> &Self::Target { &self.0 }
 }
+
+impl Port {
+    /// Get the PID of the process listening on this port
+    pub fn listening_pid(self) -> Option<u32> {
+        let address_family_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+        let protocol_flags = ProtocolFlags::TCP;
+
+        netstat2::get_sockets_info(address_family_flags, protocol_flags)
+            .ok()?
+            .into_iter()
+            .find_map(|socket_info| {
+                if let ProtocolSocketInfo::Tcp(tcp_socket_info) = socket_info.protocol_socket_info
+                    && tcp_socket_info.local_port == self.0
+                {
+                    return socket_info.associated_pids.first().copied();
+                }
+                None
+            })
+    }
+
+    /// Whether a listener could bind this port on localhost right now
+    pub fn is_available(self) -> bool { TcpListener::bind((Ipv4Addr::LOCALHOST, self.0)).is_ok() }
+
+    /// Remember the process now serving this port and report whether it changed since the last
+    /// check.
+    ///
+    /// Ports without a local listener (a stopped or remote app) keep their previous owner. Finding
+    /// the owner reads the socket table and process list, so it runs on a blocking thread.
+    pub async fn check_owner(self) -> Option<PortOwnerChange> {
+        let current = tokio::task::spawn_blocking(move || {
+            let pid = self.listening_pid()?;
+            Some(PortOwner {
+                pid,
+                app_name: process_name(pid),
+            })
+        })
+        .await
+        .ok()??;
+        self.record_owner(current)
+    }
+
+    /// Forget the recorded owner, e.g. after this server launches or shuts down the app on this
+    /// port, so the next call does not report the expected change
+    pub fn forget_owner(self) {
+        if let Ok(mut owners) = PORT_OWNERS.lock() {
+            owners.remove(&self);
+        }
+    }
+
+    fn record_owner(self, current: PortOwner) -> Option<PortOwnerChange> {
+        let Ok(mut owners) = PORT_OWNERS.lock() else {
+            return None;
+        };
+        let (previous, last_seen) = owners.insert(self, (current.clone(), Instant::now()))?;
+        if previous.pid == current.pid {
+            return None;
+        }
+
+        let message = format!(
+            "Port {self} is now served by {current}, not {previous} as on the previous call. The \
+             app was restarted or another app took the port: entity IDs from earlier calls may \
+             not exist or may refer to different entities."
+        );
+        Some(PortOwnerChange {
+            port: self,
+            previous,
+            current,
+            previous_seen_secs_ago: last_seen.elapsed().as_secs(),
+            message,
+        })
+    }
+}
+
+/// Process serving a port
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PortOwner {
+    pub pid:      u32,
+    /// Process name, when it can be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_name: Option<String>,
+}
+
+impl Display for PortOwner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.app_name {
+            Some(app_name) => write!(f, "{app_name} (PID {})", self.pid),
+            None => write!(f, "PID {}", self.pid),
+        }
+    }
+}
+
+/// Reported when a port is served by a different process than on the previous call
+#[derive(Debug, Clone, Serialize)]
+pub struct PortOwnerChange {
+    pub port:                   Port,
+    /// Process that served the previous call
+    pub previous:               PortOwner,
+    /// Process serving this call
+    pub current:                PortOwner,
+    /// Seconds since the previous process was last seen on the port
+    pub previous_seen_secs_ago: u64,
+    pub message:                String,
+}
+
+fn process_name(pid: u32) -> Option<String> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system
+        .process(pid)
+        .map(|process| process.name().to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Port;
+    use super::PortOwner;
+
+    const TEST_PORT: Port = Port(25_702);
+
+    fn owner(pid: u32, app_name: &str) -> PortOwner {
+        PortOwner {
+            pid,
+            app_name: Some(app_name.to_string()),
+        }
+    }
+
+    #[test]
+    fn changed_owner_is_reported_once() {
+        TEST_PORT.forget_owner();
+
+        assert!(TEST_PORT.record_owner(owner(100, "game")).is_none());
+        assert!(TEST_PORT.record_owner(owner(100, "game")).is_none());
+
+        let change = TEST_PORT.record_owner(owner(200, "editor"));
+        assert_eq!(change.as_ref().map(|change| change.previous.pid), Some(100));
+        assert_eq!(change.as_ref().map(|change| change.current.pid), Some(200));
+        assert!(TEST_PORT.record_owner(owner(200, "editor")).is_none());
+
+        TEST_PORT.forget_owner();
+        assert!(TEST_PORT.record_owner(owner(300, "game")).is_none());
+    }
+}
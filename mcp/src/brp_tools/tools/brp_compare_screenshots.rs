@@ -0,0 +1,236 @@
+//! Compare two PNG screenshots pixel by pixel.
+
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use image::Rgba;
+use image::RgbaImage;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// Color of differing pixels in the diff image
+const DIFF_COLOR: Rgba<u8> = Rgba([255, 0, 0, 255]);
+/// Matching pixels are drawn at this fraction of their brightness, so differences stand out
+const MATCH_DIM: f64 = 0.25;
+
+/// Parameters for comparing two screenshots.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct CompareScreenshotsParams {
+    /// PNG to compare from, such as a baseline `brp_extras_screenshot` capture.
+    pub image_a:   String,
+    /// PNG to compare to. Must have the same dimensions as `image_a`.
+    pub image_b:   String,
+    /// Largest per-channel difference (0-255) still counted as equal (default: 0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<u8>,
+    /// Write a PNG here with differing pixels in red over a dimmed `image_a`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_path: Option<String>,
+}
+
+/// Root mean square error of each channel, on the 0-255 scale.
+#[derive(Clone, Copy, Debug, Default, JsonSchema, PartialEq, Serialize)]
+pub struct ChannelRmse {
+    /// Red channel.
+    pub r: f64,
+    /// Green channel.
+    pub g: f64,
+    /// Blue channel.
+    pub b: f64,
+    /// Alpha channel.
+    pub a: f64,
+}
+
+/// Difference metrics between two screenshots.
+#[derive(Clone, Debug, JsonSchema, PartialEq, Serialize)]
+pub struct ScreenshotDiff {
+    /// Width of both images in pixels.
+    pub width:            u32,
+    /// Height of both images in pixels.
+    pub height:           u32,
+    /// Pixels with any channel differing by more than `tolerance`.
+    pub diff_pixel_count: u64,
+    /// `diff_pixel_count` as a fraction of all pixels.
+    pub diff_ratio:       f64,
+    /// Per-channel root mean square error over all pixels.
+    pub rmse:             ChannelRmse,
+    /// Where the diff image was written, when `diff_path` was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_path:        Option<String>,
+}
+
+/// Result of comparing two screenshots.
+#[derive(Serialize, ResultStruct)]
+pub struct CompareScreenshotsResult {
+    /// The difference metrics.
+    #[to_result]
+    pub diff:             ScreenshotDiff,
+    /// Number of differing pixels.
+    #[to_metadata]
+    pub diff_pixel_count: u64,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Screenshots differ in {diff_pixel_count} pixels")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that compares two PNG files without contacting an app.
+#[derive(ToolFn)]
+#[tool_fn(
+    params = "CompareScreenshotsParams",
+    output = "CompareScreenshotsResult"
+)]
+pub struct BrpCompareScreenshots;
+
+async fn handle_impl(params: CompareScreenshotsParams) -> Result<CompareScreenshotsResult> {
+    // Decoding and diffing full-size PNGs is CPU bound
+    let diff = tokio::task::spawn_blocking(move || compare_files(&params))
+        .await
+        .map_err(|e| Error::InvalidState(format!("Screenshot comparison task failed: {e}")))??;
+    let diff_pixel_count = diff.diff_pixel_count;
+    Ok(CompareScreenshotsResult::new(diff, diff_pixel_count))
+}
+
+fn compare_files(params: &CompareScreenshotsParams) -> Result<ScreenshotDiff> {
+    let image_a = load_png(&params.image_a)?;
+    let image_b = load_png(&params.image_b)?;
+    if image_a.dimensions() != image_b.dimensions() {
+        return Err(Error::invalid(
+            "screenshots",
+            format!(
+                "{} is {}x{} but {} is {}x{}",
+                params.image_a,
+                image_a.width(),
+                image_a.height(),
+                params.image_b,
+                image_b.width(),
+                image_b.height()
+            ),
+        )
+        .into());
+    }
+
+    let tolerance = params.tolerance.unwrap_or_default();
+    let (mut diff, diff_image) = compare_images(&image_a, &image_b, tolerance);
+    if let Some(path) = &params.diff_path {
+        let path = PathBuf::from(path);
+        diff_image
+            .save_with_format(&path, image::ImageFormat::Png)
+            .map_err(|e| Error::io_failed("write diff image", &path, e))?;
+        diff.diff_path = Some(path.display().to_string());
+    }
+    Ok(diff)
+}
+
+fn load_png(path: &str) -> Result<RgbaImage> {
+    let path = PathBuf::from(path);
+    let image = image::open(&path).map_err(|e| {
+        Error::invalid(
+            "screenshot",
+            format!("{} could not be decoded as a PNG: {e}", path.display()),
+        )
+    })?;
+    Ok(image.to_rgba8())
+}
+
+/// Metrics and diff image of two images with equal dimensions
+fn compare_images(
+    image_a: &RgbaImage,
+    image_b: &RgbaImage,
+    tolerance: u8,
+) -> (ScreenshotDiff, RgbaImage) {
+    let mut diff_image = RgbaImage::new(image_a.width(), image_a.height());
+    let mut squared_error = [0_f64; 4];
+    let mut diff_pixel_count = 0_u64;
+
+    for ((a, b), out) in image_a
+        .pixels()
+        .zip(image_b.pixels())
+        .zip(diff_image.pixels_mut())
+    {
+        let mut differs = false;
+        for (channel, channel_error) in squared_error.iter_mut().enumerate() {
+            let delta = a.0[channel].abs_diff(b.0[channel]);
+            *channel_error += f64::from(delta) * f64::from(delta);
+            differs |= delta > tolerance;
+        }
+        if differs {
+            diff_pixel_count += 1;
+            *out = DIFF_COLOR;
+        } else {
+            let luma = (f64::from(a.0[0]) + f64::from(a.0[1]) + f64::from(a.0[2])) / 3.0;
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "the dimmed luma is within 0..=255"
+            )]
+            let dimmed = (luma * MATCH_DIM) as u8;
+            *out = Rgba([dimmed, dimmed, dimmed, 255]);
+        }
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "pixel counts of real screenshots are far below f64 precision limits"
+    )]
+    let pixel_count = (u64::from(image_a.width()) * u64::from(image_a.height())).max(1) as f64;
+    let rmse = |channel: usize| (squared_error[channel] / pixel_count).sqrt();
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "pixel counts of real screenshots are far below f64 precision limits"
+    )]
+    let diff_ratio = diff_pixel_count as f64 / pixel_count;
+
+    let diff = ScreenshotDiff {
+        width: image_a.width(),
+        height: image_a.height(),
+        diff_pixel_count,
+        diff_ratio,
+        rmse: ChannelRmse {
+            r: rmse(0),
+            g: rmse(1),
+            b: rmse(2),
+            a: rmse(3),
+        },
+        diff_path: None,
+    };
+    (diff, diff_image)
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgba;
+    use image::RgbaImage;
+
+    use super::DIFF_COLOR;
+    use super::compare_images;
+
+    #[test]
+    fn differing_pixels_are_counted_beyond_the_tolerance() {
+        let image_a = RgbaImage::from_pixel(2, 2, Rgba([100, 100, 100, 255]));
+        let mut image_b = image_a.clone();
+        image_b.put_pixel(0, 0, Rgba([120, 100, 100, 255]));
+        image_b.put_pixel(1, 1, Rgba([102, 100, 100, 255]));
+
+        let (strict, diff_image) = compare_images(&image_a, &image_b, 0);
+        assert_eq!(strict.diff_pixel_count, 2);
+        assert!((strict.diff_ratio - 0.5).abs() < f64::EPSILON);
+        // sqrt((20² + 2²) / 4)
+        assert!((strict.rmse.r - 101_f64.sqrt()).abs() < 1e-9);
+        assert!(strict.rmse.g.abs() < f64::EPSILON);
+        assert_eq!(*diff_image.get_pixel(0, 0), DIFF_COLOR);
+        assert_ne!(*diff_image.get_pixel(1, 0), DIFF_COLOR);
+
+        let (tolerant, _) = compare_images(&image_a, &image_b, 2);
+        assert_eq!(tolerant.diff_pixel_count, 1);
+    }
+}
@@ -0,0 +1,312 @@
+//! Compare two snapshot files entity by entity.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::brp_diff_entities::ComponentChange;
+use super::brp_diff_entities::EntityComponents;
+use super::brp_diff_entities::diff_entities;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// Parameters for comparing two snapshot files.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct CompareSnapshotsParams {
+    /// Snapshot to diff from: a `brp_extras_save_world_snapshot` file or a saved `world_query`
+    /// result.
+    pub snapshot_a: String,
+    /// Snapshot to diff to, in either format.
+    pub snapshot_b: String,
+}
+
+/// Component changes on an entity present in both snapshots.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct EntityChange {
+    /// Entity ID.
+    pub entity:  u64,
+    /// Components only the entity in `snapshot_b` has.
+    pub added:   Vec<String>,
+    /// Components only the entity in `snapshot_a` has.
+    pub removed: Vec<String>,
+    /// Components whose values differ.
+    pub changed: Vec<ComponentChange>,
+}
+
+/// Resource changes between two world snapshots.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct ResourceChanges {
+    /// Resources only `snapshot_b` has.
+    pub added:   Vec<String>,
+    /// Resources only `snapshot_a` has.
+    pub removed: Vec<String>,
+    /// Resources whose values differ.
+    pub changed: Vec<ComponentChange>,
+}
+
+/// Structured diff from `snapshot_a` to `snapshot_b`.
+#[derive(Clone, Debug, Default, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct SnapshotDiff {
+    /// Entities only `snapshot_b` has.
+    pub added_entities:   Vec<u64>,
+    /// Entities only `snapshot_a` has.
+    pub removed_entities: Vec<u64>,
+    /// Entities in both snapshots whose components differ, in entity order.
+    pub changed_entities: Vec<EntityChange>,
+    /// Resource changes, present only when both snapshots contain resources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources:        Option<ResourceChanges>,
+}
+
+/// Result of comparing two snapshots.
+#[derive(Serialize, ResultStruct)]
+pub struct CompareSnapshotsResult {
+    /// The structured diff.
+    #[to_result]
+    pub diff:             SnapshotDiff,
+    /// Number of entities only in `snapshot_b`.
+    #[to_metadata]
+    pub added_count:      usize,
+    /// Number of entities only in `snapshot_a`.
+    #[to_metadata]
+    pub removed_count:    usize,
+    /// Number of shared entities with differing components.
+    #[to_metadata]
+    pub changed_count:    usize,
+    /// Message template for formatting responses.
+    #[to_message(
+        message_template = "Snapshots differ in {changed_count} changed, {added_count} added, and {removed_count} removed entities"
+    )]
+    pub message_template: String,
+}
+
+/// Local MCP handler that diffs two snapshot files without contacting an app.
+#[derive(ToolFn)]
+#[tool_fn(params = "CompareSnapshotsParams", output = "CompareSnapshotsResult")]
+pub struct BrpCompareSnapshots;
+
+async fn handle_impl(params: CompareSnapshotsParams) -> Result<CompareSnapshotsResult> {
+    let (a, b) = tokio::try_join!(
+        read_snapshot(PathBuf::from(params.snapshot_a)),
+        read_snapshot(PathBuf::from(params.snapshot_b)),
+    )?;
+    let diff = compare_snapshots(a, b);
+    let (added_count, removed_count, changed_count) = (
+        diff.added_entities.len(),
+        diff.removed_entities.len(),
+        diff.changed_entities.len(),
+    );
+    Ok(CompareSnapshotsResult::new(
+        diff,
+        added_count,
+        removed_count,
+        changed_count,
+    ))
+}
+
+/// Entities, and resources when the format has them, of one snapshot.
+#[derive(Debug, Default)]
+struct Snapshot {
+    entities:  BTreeMap<u64, EntityComponents>,
+    resources: Option<BTreeMap<String, Value>>,
+}
+
+async fn read_snapshot(path: PathBuf) -> Result<Snapshot> {
+    let contents = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| Error::io_failed("read snapshot", &path, &e))?;
+    let value: Value = serde_json::from_str(&contents).map_err(|e| {
+        Error::invalid(
+            "snapshot",
+            format!("{} is not valid JSON: {e}", path.display()),
+        )
+    })?;
+    parse_snapshot(&path, value)
+}
+
+/// Accept a world snapshot (`{ version, scene: { resources, entities } }`), a `world.query` result
+/// array, or a response object wrapping that array in `result`.
+fn parse_snapshot(path: &Path, value: Value) -> Result<Snapshot> {
+    let unrecognized = || -> error_stack::Report<Error> {
+        Error::invalid(
+            "snapshot",
+            format!(
+                "{} is neither a world snapshot nor a world.query result",
+                path.display()
+            ),
+        )
+        .into()
+    };
+
+    match value {
+        Value::Object(mut object) => {
+            if let Some(Value::Object(mut scene)) = object.remove("scene") {
+                let entities = match scene.remove("entities") {
+                    Some(Value::Object(entities)) => entities
+                        .into_iter()
+                        .map(|(entity, components)| {
+                            let entity = entity.parse().map_err(|_| unrecognized())?;
+                            let components =
+                                serde_json::from_value(components).map_err(|_| unrecognized())?;
+                            Ok((entity, components))
+                        })
+                        .collect::<Result<_>>()?,
+                    _ => BTreeMap::new(),
+                };
+                let resources = match scene.remove("resources") {
+                    Some(Value::Object(resources)) => Some(resources.into_iter().collect()),
+                    _ => None,
+                };
+                return Ok(Snapshot {
+                    entities,
+                    resources,
+                });
+            }
+            match object.remove("result") {
+                Some(rows @ Value::Array(_)) => parse_query_rows(rows).ok_or_else(unrecognized),
+                _ => Err(unrecognized()),
+            }
+        },
+        rows @ Value::Array(_) => parse_query_rows(rows).ok_or_else(unrecognized),
+        _ => Err(unrecognized()),
+    }
+}
+
+/// One row of a `world.query` result.
+#[derive(Deserialize)]
+struct QueryRow {
+    entity:     u64,
+    #[serde(flatten)]
+    components: EntityComponents,
+}
+
+fn parse_query_rows(rows: Value) -> Option<Snapshot> {
+    let rows: Vec<QueryRow> = serde_json::from_value(rows).ok()?;
+    Some(Snapshot {
+        entities:  rows
+            .into_iter()
+            .map(|row| (row.entity, row.components))
+            .collect(),
+        resources: None,
+    })
+}
+
+fn compare_snapshots(a: Snapshot, b: Snapshot) -> SnapshotDiff {
+    let mut diff = SnapshotDiff {
+        added_entities: b
+            .entities
+            .keys()
+            .filter(|entity| !a.entities.contains_key(entity))
+            .copied()
+            .collect(),
+        ..SnapshotDiff::default()
+    };
+
+    let mut b_entities = b.entities;
+    for (entity, components_a) in a.entities {
+        let Some(components_b) = b_entities.remove(&entity) else {
+            diff.removed_entities.push(entity);
+            continue;
+        };
+        let entity_diff = diff_entities(components_a, components_b);
+        if !entity_diff.added.is_empty()
+            || !entity_diff.removed.is_empty()
+            || !entity_diff.changed.is_empty()
+        {
+            diff.changed_entities.push(EntityChange {
+                entity,
+                added: entity_diff.added,
+                removed: entity_diff.removed,
+                changed: entity_diff.changed,
+            });
+        }
+    }
+
+    if let (Some(resources_a), Some(resources_b)) = (a.resources, b.resources) {
+        let resource_diff = diff_entities(
+            EntityComponents {
+                components: resources_a,
+                errors:     BTreeMap::new(),
+            },
+            EntityComponents {
+                components: resources_b,
+                errors:     BTreeMap::new(),
+            },
+        );
+        diff.resources = Some(ResourceChanges {
+            added:   resource_diff.added,
+            removed: resource_diff.removed,
+            changed: resource_diff.changed,
+        });
+    }
+
+    diff
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::compare_snapshots;
+    use super::parse_snapshot;
+
+    const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+
+    #[test]
+    fn world_snapshot_and_query_result_diff_by_entity() {
+        let a = parse_snapshot(
+            Path::new("a.json"),
+            json!({
+                "version": 1,
+                "scene": {
+                    "resources": {},
+                    "entities": {
+                        "1": { "components": { TRANSFORM: { "translation": [0.0, 1.0, 2.0] } } },
+                        "2": { "components": {} }
+                    }
+                }
+            }),
+        )
+        .expect("world snapshot should parse");
+        let b = parse_snapshot(
+            Path::new("b.json"),
+            json!({
+                "result": [
+                    { "entity": 1, "components": { TRANSFORM: { "translation": [0.0, 3.0, 2.0] } } },
+                    { "entity": 3, "components": {} }
+                ]
+            }),
+        )
+        .expect("query result should parse");
+
+        let diff = compare_snapshots(a, b);
+
+        assert_eq!(diff.added_entities, vec![3]);
+        assert_eq!(diff.removed_entities, vec![2]);
+        assert_eq!(diff.changed_entities.len(), 1);
+        assert_eq!(diff.changed_entities[0].entity, 1);
+        assert_eq!(
+            diff.changed_entities[0].changed[0].fields[0].path,
+            ".translation[1]"
+        );
+        assert!(diff.resources.is_none());
+    }
+}
@@ -0,0 +1,305 @@
+//! Diff the components of two entities.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use error_stack::Report;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::ToolFn;
+
+/// Parameters for diffing two entities through standard BRP.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct DiffEntitiesParams {
+    /// The entity to diff from.
+    pub entity_a: u64,
+    /// The entity to diff to.
+    pub entity_b: u64,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:     Port,
+}
+
+/// One field whose value differs between the two entities.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// Mutation-path style location inside the component, e.g. `.translation.x`; empty for the
+    /// whole component.
+    pub path: String,
+    /// Value on `entity_a`.
+    pub a:    Value,
+    /// Value on `entity_b`.
+    pub b:    Value,
+}
+
+/// A component present on both entities with differing values.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct ComponentChange {
+    /// Fully-qualified component type path.
+    pub component: String,
+    /// Differing fields, in path order.
+    pub fields:    Vec<FieldChange>,
+}
+
+/// Structured diff from `entity_a` to `entity_b`.
+#[derive(Clone, Debug, Default, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct EntityDiff {
+    /// Components only `entity_b` has.
+    pub added:      Vec<String>,
+    /// Components only `entity_a` has.
+    pub removed:    Vec<String>,
+    /// Components on both entities whose values differ.
+    pub changed:    Vec<ComponentChange>,
+    /// Components on both entities with identical values.
+    pub unchanged:  Vec<String>,
+    /// Components present on an entity but not readable through reflection, keyed by type path.
+    pub unreadable: BTreeMap<String, Value>,
+}
+
+/// Result of diffing two entities.
+#[derive(Serialize, ResultStruct)]
+pub struct DiffEntitiesResult {
+    /// The structured diff.
+    #[to_result]
+    pub diff:             EntityDiff,
+    /// Number of components only on `entity_b`.
+    #[to_metadata]
+    pub added_count:      usize,
+    /// Number of components only on `entity_a`.
+    #[to_metadata]
+    pub removed_count:    usize,
+    /// Number of shared components with differing values.
+    #[to_metadata]
+    pub changed_count:    usize,
+    /// Message template for formatting responses.
+    #[to_message(
+        message_template = "Entities differ in {added_count} added, {removed_count} removed, and {changed_count} changed components"
+    )]
+    pub message_template: String,
+}
+
+/// Local MCP handler that composes standard BRP `world.list_components` and
+/// `world.get_components` requests.
+pub struct BrpDiffEntities;
+
+#[async_trait]
+impl ToolFn for BrpDiffEntities {
+    type Output = DiffEntitiesResult;
+    type Params = DiffEntitiesParams;
+
+    async fn handle_impl(&self, params: DiffEntitiesParams) -> Result<DiffEntitiesResult> {
+        let (a, b) = tokio::try_join!(
+            fetch_entity_components(params.entity_a, params.port),
+            fetch_entity_components(params.entity_b, params.port),
+        )?;
+        let diff = diff_entities(a, b);
+        let (added_count, removed_count, changed_count) =
+            (diff.added.len(), diff.removed.len(), diff.changed.len());
+        Ok(DiffEntitiesResult::new(
+            diff,
+            added_count,
+            removed_count,
+            changed_count,
+        ))
+    }
+}
+
+/// Components of one entity as returned by non-strict `world.get_components`, or one entity of a
+/// snapshot.
+#[derive(Debug, Default, Deserialize)]
+pub(super) struct EntityComponents {
+    #[serde(default)]
+    pub(super) components: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub(super) errors:     BTreeMap<String, Value>,
+}
+
+pub(super) async fn fetch_entity_components(entity: u64, port: Port) -> Result<EntityComponents> {
+    let list = BrpClient::new(
+        BrpMethod::WorldListComponents,
+        port,
+        Some(json!({ "entity": entity })),
+    )
+    .execute_raw()
+    .await?;
+    let components: Vec<String> = decode_success(list, BrpMethod::WorldListComponents, entity)?;
+    if components.is_empty() {
+        return Ok(EntityComponents::default());
+    }
+
+    let get = BrpClient::new(
+        BrpMethod::WorldGetComponents,
+        port,
+        Some(json!({ "entity": entity, "components": components, "strict": false })),
+    )
+    .execute_raw()
+    .await?;
+    decode_success(get, BrpMethod::WorldGetComponents, entity)
+}
+
+fn decode_success<T: for<'de> Deserialize<'de>>(
+    response: ResponseStatus,
+    method: BrpMethod,
+    entity: u64,
+) -> Result<T> {
+    match response {
+        ResponseStatus::Success(Some(value)) => serde_json::from_value(value).map_err(|error| {
+            diff_error(
+                method,
+                entity,
+                format!("Unable to decode response: {error}"),
+            )
+        }),
+        ResponseStatus::Success(None) => Err(diff_error(method, entity, "returned no result")),
+        ResponseStatus::Error(error) => Err(diff_error(method, entity, error.message)),
+    }
+}
+
+fn diff_error(method: BrpMethod, entity: u64, message: impl Into<String>) -> Report<Error> {
+    let message = message.into();
+    Error::tool_call_failed_with_details(
+        format!("{} failed for entity {entity}: {message}", method.as_str()),
+        json!({
+            "method": method.as_str(),
+            "entity": entity,
+            "error": message,
+        }),
+    )
+    .into()
+}
+
+pub(super) fn diff_entities(a: EntityComponents, b: EntityComponents) -> EntityDiff {
+    let mut diff = EntityDiff::default();
+
+    for (component, value_a) in &a.components {
+        match b.components.get(component) {
+            Some(value_b) => {
+                let mut fields = Vec::new();
+                diff_values(String::new(), value_a, value_b, &mut fields);
+                if fields.is_empty() {
+                    diff.unchanged.push(component.clone());
+                } else {
+                    diff.changed.push(ComponentChange {
+                        component: component.clone(),
+                        fields,
+                    });
+                }
+            },
+            None if !b.errors.contains_key(component) => diff.removed.push(component.clone()),
+            None => {},
+        }
+    }
+    diff.added = b
+        .components
+        .keys()
+        .filter(|component| {
+            !a.components.contains_key(*component) && !a.errors.contains_key(*component)
+        })
+        .cloned()
+        .collect();
+    diff.unreadable = a.errors.into_iter().chain(b.errors).collect();
+
+    diff
+}
+
+/// Collect differing leaves; arrays of different lengths are reported as one change.
+fn diff_values(path: String, a: &Value, b: &Value, changes: &mut Vec<FieldChange>) {
+    match (a, b) {
+        (Value::Object(map_a), Value::Object(map_b)) => {
+            let keys: BTreeSet<&String> = map_a.keys().chain(map_b.keys()).collect();
+            for key in keys {
+                let child = format!("{path}.{key}");
+                match (map_a.get(key), map_b.get(key)) {
+                    (Some(value_a), Some(value_b)) => diff_values(child, value_a, value_b, changes),
+                    (value_a, value_b) => changes.push(FieldChange {
+                        path: child,
+                        a:    value_a.cloned().unwrap_or(Value::Null),
+                        b:    value_b.cloned().unwrap_or(Value::Null),
+                    }),
+                }
+            }
+        },
+        (Value::Array(items_a), Value::Array(items_b)) if items_a.len() == items_b.len() => {
+            for (index, (value_a, value_b)) in items_a.iter().zip(items_b).enumerate() {
+                diff_values(format!("{path}[{index}]"), value_a, value_b, changes);
+            }
+        },
+        _ if a == b => {},
+        _ => changes.push(FieldChange {
+            path,
+            a: a.clone(),
+            b: b.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::EntityComponents;
+    use super::FieldChange;
+    use super::diff_entities;
+
+    const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+    const NAME: &str = "bevy_ecs::name::Name";
+    const SPRITE: &str = "bevy_sprite::sprite::Sprite";
+    const VISIBILITY: &str = "bevy_camera::visibility::Visibility";
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_fields() {
+        let a = EntityComponents {
+            components: BTreeMap::from([
+                (
+                    TRANSFORM.to_string(),
+                    json!({ "translation": [0.0, 1.0, 2.0], "scale": [1.0, 1.0, 1.0] }),
+                ),
+                (NAME.to_string(), json!("Player")),
+                (SPRITE.to_string(), json!({})),
+            ]),
+            errors:     BTreeMap::new(),
+        };
+        let b = EntityComponents {
+            components: BTreeMap::from([
+                (
+                    TRANSFORM.to_string(),
+                    json!({ "translation": [0.0, 5.0, 2.0], "scale": [1.0, 1.0, 1.0] }),
+                ),
+                (NAME.to_string(), json!("Player")),
+                (VISIBILITY.to_string(), json!("Inherited")),
+            ]),
+            errors:     BTreeMap::new(),
+        };
+
+        let diff = diff_entities(a, b);
+
+        assert_eq!(diff.added, vec![VISIBILITY.to_string()]);
+        assert_eq!(diff.removed, vec![SPRITE.to_string()]);
+        assert_eq!(diff.unchanged, vec![NAME.to_string()]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].component, TRANSFORM);
+        assert_eq!(
+            diff.changed[0].fields,
+            vec![FieldChange {
+                path: ".translation[1]".to_string(),
+                a:    json!(1.0),
+                b:    json!(5.0),
+            }]
+        );
+    }
+}
@@ -1,13 +1,18 @@
 //! `brp_execute` allows for executing an arbitrary BRP method - generally this is used as a
 //! debugging tool for his MCP server but can also be used if (for example) a new brp method is
 //! added before it's been implemented in this server code.
+//!
+//! `brp_execute_batch` runs several such calls in one tool call, sequentially or in parallel, and
+//! reports each call's outcome separately.
 use async_trait::async_trait;
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
+use futures::future::join_all;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
+use serde_json::json;
 
 use super::rpc_discover;
 use crate::brp_tools;
@@ -94,6 +99,203 @@ impl ToolFn for BrpExecute {
     }
 }
 
+/// One BRP call in a batch
+#[derive(Clone, Deserialize, Serialize, JsonSchema)]
+pub struct BatchCall {
+    /// The BRP method to execute
+    pub method: String,
+    /// Optional parameters for the method
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ExecuteBatchParams {
+    /// Calls to execute, each `{method, params}`, in order
+    pub calls:         Vec<BatchCall>,
+    /// Run all calls concurrently instead of one after another (default: false). Use only when
+    /// the calls do not depend on each other's effects.
+    #[serde(default)]
+    pub parallel:      bool,
+    /// Skip the remaining calls after the first failure (default: false). Sequential batches only.
+    #[serde(default)]
+    pub stop_on_error: bool,
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port:          Port,
+}
+
+/// Outcome of one call in a batch
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchCallStatus {
+    Success,
+    Error,
+    /// Not executed because an earlier call failed and `stop_on_error` was set
+    Skipped,
+}
+
+/// Result of one call in a batch
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+pub struct BatchCallResult {
+    /// Position of the call in `calls`
+    pub index:  usize,
+    /// The BRP method that was requested
+    pub method: String,
+    pub status: BatchCallStatus,
+    /// The raw BRP result, for successful calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// `stage`, `message`, and the BRP `code` and `data` when the app reported the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error:  Option<Value>,
+}
+
+impl BatchCallResult {
+    const fn success(index: usize, method: String, result: Option<Value>) -> Self {
+        Self {
+            index,
+            method,
+            status: BatchCallStatus::Success,
+            result,
+            error: None,
+        }
+    }
+
+    const fn error(index: usize, method: String, error: Value) -> Self {
+        Self {
+            index,
+            method,
+            status: BatchCallStatus::Error,
+            result: None,
+            error: Some(error),
+        }
+    }
+
+    const fn skipped(index: usize, method: String) -> Self {
+        Self {
+            index,
+            method,
+            status: BatchCallStatus::Skipped,
+            result: None,
+            error: None,
+        }
+    }
+}
+
+/// Result type for the batch BRP execute tool
+#[derive(Serialize, ResultStruct)]
+pub struct ExecuteBatchResult {
+    /// Per-call results in `calls` order
+    #[to_result]
+    pub results:          Vec<BatchCallResult>,
+    /// Number of calls in the batch
+    #[to_metadata]
+    pub call_count:       usize,
+    /// Number of calls that succeeded
+    #[to_metadata]
+    pub succeeded:        usize,
+    /// Number of calls that failed
+    #[to_metadata]
+    pub failed:           usize,
+    /// Number of calls skipped after a failure
+    #[to_metadata]
+    pub skipped:          usize,
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Executed {call_count} calls: {succeeded} succeeded, {failed} failed, {skipped} skipped"
+    )]
+    pub message_template: String,
+}
+
+pub struct BrpExecuteBatch;
+
+#[async_trait]
+impl ToolFn for BrpExecuteBatch {
+    type Output = ExecuteBatchResult;
+    type Params = ExecuteBatchParams;
+
+    async fn handle_impl(&self, params: ExecuteBatchParams) -> Result<ExecuteBatchResult> {
+        if params.calls.is_empty() {
+            return Err(Error::invalid("calls", "the batch must contain at least one call").into());
+        }
+        let method_names = rpc_discover::discover_method_names(params.port).await?;
+
+        let calls = params.calls.into_iter().enumerate();
+        let results =
+            if params.parallel {
+                join_all(calls.map(|(index, call)| {
+                    execute_batch_call(index, call, params.port, &method_names)
+                }))
+                .await
+            } else {
+                let mut results = Vec::new();
+                let mut stopped = false;
+                for (index, call) in calls {
+                    if stopped {
+                        results.push(BatchCallResult::skipped(index, call.method));
+                        continue;
+                    }
+                    let result = execute_batch_call(index, call, params.port, &method_names).await;
+                    stopped = params.stop_on_error && result.status == BatchCallStatus::Error;
+                    results.push(result);
+                }
+                results
+            };
+
+        let count = |status: BatchCallStatus| results.iter().filter(|r| r.status == status).count();
+        let (succeeded, failed, skipped) = (
+            count(BatchCallStatus::Success),
+            count(BatchCallStatus::Error),
+            count(BatchCallStatus::Skipped),
+        );
+        let call_count = results.len();
+        Ok(ExecuteBatchResult::new(
+            results, call_count, succeeded, failed, skipped,
+        ))
+    }
+}
+
+/// Execute one batch call, reporting failures in the result instead of failing the batch
+async fn execute_batch_call(
+    index: usize,
+    call: BatchCall,
+    port: Port,
+    method_names: &[String],
+) -> BatchCallResult {
+    if !method_is_registered(method_names, &call.method) {
+        let message = format!(
+            "BRP method `{}` is not registered on port {port}",
+            call.method
+        );
+        return BatchCallResult::error(
+            index,
+            call.method,
+            json!({ "stage": "discovery", "message": message }),
+        );
+    }
+
+    let brp_client = BrpClient::for_application(call.method.clone(), port, call.params);
+    match brp_client.execute_raw().await {
+        Ok(ResponseStatus::Success(data)) => BatchCallResult::success(index, call.method, data),
+        Ok(ResponseStatus::Error(error)) => BatchCallResult::error(
+            index,
+            call.method,
+            json!({
+                "stage": "execution",
+                "message": error.get_message(),
+                "code": error.code,
+                "data": error.data,
+            }),
+        ),
+        Err(report) => BatchCallResult::error(
+            index,
+            call.method,
+            json!({ "stage": "transport", "message": report.current_context().to_string() }),
+        ),
+    }
+}
+
 fn method_is_registered(method_names: &[String], requested_method: &str) -> bool {
     method_names.iter().any(|method| method == requested_method)
 }
@@ -102,8 +304,12 @@ fn method_is_registered(method_names: &[String], requested_method: &str) -> bool
 mod tests {
     use serde_json::json;
 
+    use super::BatchCall;
+    use super::BatchCallStatus;
     use super::ExecuteParams;
+    use super::execute_batch_call;
     use super::method_is_registered;
+    use crate::brp_tools::Port;
 
     #[test]
     fn execute_params_accept_application_method_names() -> serde_json::Result<()> {
@@ -125,4 +331,22 @@ mod tests {
         assert!(method_is_registered(&methods, "test/multiply"));
         assert!(!method_is_registered(&methods, "test/multiply_more"));
     }
+
+    #[tokio::test]
+    async fn batch_call_reports_unregistered_method_without_executing() {
+        let methods = vec![String::from("rpc.discover")];
+        let call = BatchCall {
+            method: String::from("test/multiply"),
+            params: None,
+        };
+
+        let result = execute_batch_call(3, call, Port::default(), &methods).await;
+
+        assert_eq!(result.index, 3);
+        assert_eq!(result.status, BatchCallStatus::Error);
+        assert_eq!(
+            result.error.as_ref().map(|error| &error["stage"]),
+            Some(&json!("discovery"))
+        );
+    }
 }
@@ -0,0 +1,32 @@
+//! `brp_extras/capabilities` tool - List the extras methods the app registered
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/capabilities` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct CapabilitiesParams {
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/capabilities` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct CapabilitiesResult {
+    /// The raw BRP response with the extras version and registered methods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Retrieved extras capabilities")]
+    pub message_template: String,
+}
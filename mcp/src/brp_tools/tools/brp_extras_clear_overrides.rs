@@ -0,0 +1,44 @@
+//! `brp_extras/clear_overrides` tool - Restore overridden component values
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/clear_overrides` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ClearOverridesParams {
+    /// Only clear overrides of this entity (default: every entity)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+
+    /// Only clear overrides of this component type path (default: every component)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/clear_overrides` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ClearOverridesResult {
+    /// The raw BRP response listing each cleared override and its outcome
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Cleared component overrides")]
+    pub message_template: String,
+}
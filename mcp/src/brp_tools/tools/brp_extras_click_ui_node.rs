@@ -0,0 +1,49 @@
+//! `brp_extras/click_ui_node` tool - Click a UI node found by name or entity
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+use crate::brp_tools::mouse::MouseButtonWrapper;
+
+/// Parameters for the `brp_extras/click_ui_node` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ClickUiNodeParams {
+    /// `Name` of the UI node to click (provide this or `entity`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Entity ID of the UI node to click (provide this or `name`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+
+    /// Mouse button to click (Left, Right, Middle, Back, Forward; default: Left)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub button: Option<MouseButtonWrapper>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/click_ui_node` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ClickUiNodeResult {
+    /// The raw BRP response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "UI node clicked")]
+    pub message_template: String,
+}
@@ -7,11 +7,15 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 
 /// Parameters for the `brp_extras/double_tap_gesture` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct DoubleTapGestureParams {
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
@@ -7,9 +7,27 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 use crate::brp_tools::mouse::MouseButtonWrapper;
 
+/// Pace of a drag along its path
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DragEasing {
+    /// Constant speed
+    Linear,
+    /// Start slowly, then speed up
+    EaseIn,
+    /// Start quickly, then settle into the end
+    EaseOut,
+    /// Start and end slowly
+    EaseInOut,
+    /// CSS-style `cubic-bezier(x1, y1, x2, y2)` timing curve, e.g. `{"cubic_bezier": [0.25, 0.1,
+    /// 0.25, 1.0]}`; `x1` and `x2` must be in 0..=1
+    CubicBezier([f32; 4]),
+}
+
 /// Parameters for the `brp_extras/drag_mouse` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct DragMouseParams {
@@ -22,6 +40,14 @@ pub struct DragMouseParams {
     /// Ending position as [x, y]
     pub end: (f32, f32),
 
+    /// Positions as [x, y] to pass through between `start` and `end`, in order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waypoints: Option<Vec<(f32, f32)>>,
+
+    /// Pace of the drag along its path (default: linear)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub easing: Option<DragEasing>,
+
     /// Number of frames over which to interpolate the drag
     pub frames: u32,
 
@@ -29,6 +55,9 @@ pub struct DragMouseParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub window: Option<u64>,
 
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
@@ -0,0 +1,52 @@
+//! `brp_extras/draw_gizmo` tool - Draw temporary debug lines, spheres, boxes, and labels
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/draw_gizmo` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct DrawGizmoParams {
+    /// Shapes to draw, each tagged by `type` with an optional sRGBA `color`:
+    /// `{"type": "line", "start": [x, y, z], "end": [x, y, z]}`, `{"type": "sphere", "center":
+    /// [x, y, z], "radius": r}`, `{"type": "aabb", "min": [x, y, z], "max": [x, y, z]}` or
+    /// `{"type": "aabb", "entity": id}`, and `{"type": "text", "position": [x, y, z], "text":
+    /// "..."}`
+    #[serde(default)]
+    pub shapes: Vec<Value>,
+
+    /// Frames the shapes stay visible (default: 120)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_frames: Option<u32>,
+
+    /// Camera entity ID text labels are placed for (defaults to the highest-order active camera)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<u64>,
+
+    /// Remove every previously drawn shape first (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear: Option<bool>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/draw_gizmo` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct DrawGizmoResult {
+    /// The raw BRP response with the drawn and active shape counts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Drew gizmo shapes")]
+    pub message_template: String,
+}
@@ -0,0 +1,48 @@
+//! `brp_extras/get_asset_info` tool - Inspect one asset's load state and value
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_asset_info` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetAssetInfoParams {
+    /// Full or short type path of the asset type (e.g. `Image`)
+    pub asset_type: String,
+
+    /// Asset id as returned by `brp_extras_list_assets`. Provide exactly one of `id` or `path`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Asset path as loaded by the `AssetServer`. Provide exactly one of `id` or `path`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Include the reflected asset value (default: false). Large assets such as images can
+    /// produce very large responses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_value: Option<bool>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_asset_info` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetAssetInfoResult {
+    /// The raw BRP response with the asset's path and load states
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Asset info retrieved")]
+    pub message_template: String,
+}
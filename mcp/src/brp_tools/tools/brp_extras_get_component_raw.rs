@@ -0,0 +1,38 @@
+//! `brp_extras/get_component_raw` tool - Read-only reflection dump of a component
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_component_raw` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetComponentRawParams {
+    /// Entity to read the component from
+    pub entity: u64,
+
+    /// Full type path of the component
+    pub component: String,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_component_raw` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetComponentRawResult {
+    /// The raw BRP response with the read-only `value` and its `unserializable` paths
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Read {component} on entity {entity} through reflection")]
+    pub message_template: String,
+}
@@ -0,0 +1,35 @@
+//! `brp_extras/get_contacts` tool - List the entities an Avian collider is touching
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_contacts` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetContactsParams {
+    /// The collider entity ID
+    pub entity: u64,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_contacts` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetContactsResult {
+    /// The raw BRP response with the physics backend, component, and touching entities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Retrieved contacts of entity {entity}")]
+    pub message_template: String,
+}
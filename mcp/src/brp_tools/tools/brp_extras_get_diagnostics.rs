@@ -1,4 +1,4 @@
-//! `brp_extras/get_diagnostics` tool - Get FPS diagnostics
+//! `brp_extras/get_diagnostics` tool - Get FPS, per-system, and render diagnostics
 
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
@@ -9,9 +9,39 @@ use serde_json::Value;
 
 use crate::brp_tools::Port;
 
+/// How much the `brp_extras/get_diagnostics` response includes
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticsDetail {
+    /// FPS, frame time, and frame count
+    Summary,
+    /// The summary plus system information and per-system timings
+    Systems,
+}
+
+/// Optional sections of the `brp_extras/get_diagnostics` response
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticsCategory {
+    /// System information and per-system timings, the same as `detail: systems`
+    Systems,
+    /// Per-pass render timings, draw calls, and the visible entity count
+    Render,
+}
+
 /// Parameters for the `brp_extras/get_diagnostics` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct GetDiagnosticsParams {
+    /// `summary` (default) for FPS and frame time, or `systems` to also include system
+    /// information and per-system timings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<DiagnosticsDetail>,
+
+    /// Sections to add to the summary: `systems` and `render`. `render` reports per-pass
+    /// measurements from `RenderDiagnosticsPlugin`, summed draw calls, and visible entities
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<DiagnosticsCategory>>,
+
     /// Port number for BRP - defaults to 15702
     #[serde(default)]
     pub port: Port,
@@ -21,12 +51,13 @@ pub struct GetDiagnosticsParams {
 #[derive(Serialize, ResultStruct)]
 #[brp_result]
 pub struct GetDiagnosticsResult {
-    /// The raw BRP response containing FPS diagnostics
+    /// The raw BRP response containing FPS diagnostics and any requested system or render
+    /// diagnostics
     #[serde(skip_serializing_if = "Option::is_none")]
     #[to_result(skip_if_none)]
     pub result: Option<Value>,
 
     /// Message template for formatting responses
-    #[to_message(message_template = "FPS diagnostics retrieved")]
+    #[to_message(message_template = "Diagnostics retrieved")]
     pub message_template: String,
 }
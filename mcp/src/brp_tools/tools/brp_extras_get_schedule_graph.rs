@@ -0,0 +1,35 @@
+//! `brp_extras/get_schedule_graph` tool - Get one schedule's systems, sets, and ordering edges
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_schedule_graph` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetScheduleGraphParams {
+    /// Schedule to describe (e.g. `Update`)
+    pub schedule: String,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_schedule_graph` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetScheduleGraphResult {
+    /// The raw BRP response with system and set nodes, set hierarchy, and dependency edges
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Schedule graph retrieved")]
+    pub message_template: String,
+}
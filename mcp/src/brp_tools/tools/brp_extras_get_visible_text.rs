@@ -0,0 +1,40 @@
+//! `brp_extras/get_visible_text` tool - List the text currently visible on screen
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_visible_text` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetVisibleTextParams {
+    /// Only return text containing this substring
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+
+    /// Only return text shown in this window entity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_visible_text` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetVisibleTextResult {
+    /// The raw BRP response with each visible text entity and its screen rect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Visible text retrieved")]
+    pub message_template: String,
+}
@@ -0,0 +1,40 @@
+//! `brp_extras/get_world_stats` tool - Get entity, archetype, and table statistics
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/get_world_stats` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct GetWorldStatsParams {
+    /// Include archetypes that currently hold no entities (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_empty: Option<bool>,
+
+    /// Only list this many archetypes, largest first. Totals still cover every archetype.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/get_world_stats` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct GetWorldStatsResult {
+    /// The raw BRP response with world totals and per-archetype statistics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "World stats retrieved")]
+    pub message_template: String,
+}
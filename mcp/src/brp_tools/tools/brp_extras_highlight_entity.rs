@@ -0,0 +1,43 @@
+//! `brp_extras/highlight_entity` tool - Outline an entity for a few seconds
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/highlight_entity` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct HighlightEntityParams {
+    /// The entity ID to highlight
+    pub entity: u64,
+
+    /// Seconds the highlight stays visible (default: 3.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f32>,
+
+    /// Outline color as sRGBA `[r, g, b, a]` with components in 0.0-1.0 (default: cyan)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<[f32; 4]>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/highlight_entity` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct HighlightEntityResult {
+    /// The raw BRP response with the highlighted entity and duration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Highlighted entity {entity}")]
+    pub message_template: String,
+}
@@ -0,0 +1,47 @@
+//! `brp_extras/hover_mouse` tool - Rest the mouse cursor over a position for a dwell time
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/hover_mouse` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct HoverMouseParams {
+    /// Position to hover over as [x, y]
+    pub position: (f32, f32),
+
+    /// Milliseconds the cursor stays at `position` (default: 500ms, max: 60000ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dwell_ms: Option<u32>,
+
+    /// Optional window entity ID to target (defaults to primary window)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<u64>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/hover_mouse` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct HoverMouseResult {
+    /// The raw BRP response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Mouse hovering")]
+    pub message_template: String,
+}
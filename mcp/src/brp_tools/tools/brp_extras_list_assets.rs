@@ -0,0 +1,37 @@
+//! `brp_extras/list_assets` tool - List asset types or the assets of one type
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/list_assets` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ListAssetsParams {
+    /// Full or short type path of the asset type to list (e.g. `Image`). Omit to list every
+    /// reflected asset type with its asset count.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_type: Option<String>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/list_assets` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ListAssetsResult {
+    /// The raw BRP response with asset types or asset entries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Assets listed")]
+    pub message_template: String,
+}
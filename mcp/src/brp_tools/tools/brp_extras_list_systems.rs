@@ -0,0 +1,36 @@
+//! `brp_extras/list_systems` tool - List systems per schedule with their run conditions
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/list_systems` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ListSystemsParams {
+    /// Only list systems of this schedule (e.g. `Update`). Omit to list every schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/list_systems` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ListSystemsResult {
+    /// The raw BRP response with each schedule's systems, sets, conditions, and ordering
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Systems listed")]
+    pub message_template: String,
+}
@@ -0,0 +1,42 @@
+//! `brp_extras/load_world_snapshot` tool - Restore a saved world snapshot
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/load_world_snapshot` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct LoadWorldSnapshotParams {
+    /// Snapshot file written by `brp_extras_save_world_snapshot`. Provide exactly one of `path`
+    /// or `snapshot`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Inline snapshot as returned by `brp_extras_save_world_snapshot`. Provide exactly one of
+    /// `path` or `snapshot`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<Value>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/load_world_snapshot` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct LoadWorldSnapshotResult {
+    /// The raw BRP response with restored counts and any `respawned` entities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "World snapshot restored")]
+    pub message_template: String,
+}
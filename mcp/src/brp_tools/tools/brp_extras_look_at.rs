@@ -0,0 +1,47 @@
+//! `brp_extras/look_at` tool - Rotate a camera to face a point or entity
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/look_at` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct LookAtParams {
+    /// Camera entity ID to rotate
+    pub camera: u64,
+
+    /// World-space point to look at as `[x, y, z]` (provide this or `entity`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point: Option<[f32; 3]>,
+
+    /// Entity ID whose `GlobalTransform` translation is looked at (provide this or `point`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+
+    /// Up direction the camera keeps as `[x, y, z]` (default: `[0, 1, 0]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub up: Option<[f32; 3]>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/look_at` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct LookAtResult {
+    /// The raw BRP response with the camera's new transform, forward direction, and target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Pointed camera {camera} at its target")]
+    pub message_template: String,
+}
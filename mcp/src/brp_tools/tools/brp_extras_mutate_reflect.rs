@@ -0,0 +1,50 @@
+//! `brp_extras/mutate_reflect` tool - Mutate a component through reflection
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/mutate_reflect` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct MutateReflectParams {
+    /// The entity ID containing the component to mutate
+    pub entity: u64,
+
+    /// The fully-qualified type name of the component to mutate
+    pub component: String,
+
+    /// The new value for the mutation path; struct values may leave out fields to keep them
+    pub value: Value,
+
+    /// The path to the field within the component (e.g., '.translation.x'; default: whole
+    /// component)
+    #[serde(default)]
+    pub path: String,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/mutate_reflect` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct MutateReflectResult {
+    /// The raw BRP response with the value now at `path`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Mutated {component} for entity {entity} through reflection")]
+    pub message_template: String,
+}
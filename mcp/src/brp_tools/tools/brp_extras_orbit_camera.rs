@@ -0,0 +1,55 @@
+//! `brp_extras/orbit_camera` tool - Place a camera around a point or entity and face it
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/orbit_camera` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct OrbitCameraParams {
+    /// Camera entity ID to move
+    pub camera: u64,
+
+    /// World-space point to orbit around as `[x, y, z]` (provide this or `entity`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point: Option<[f32; 3]>,
+
+    /// Entity ID whose `GlobalTransform` translation is orbited around (provide this or `point`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+
+    /// Degrees around the Y axis; `0` places the camera on the target's `+Z` side (default: 0)
+    #[serde(default)]
+    pub yaw: f32,
+
+    /// Degrees above the target's horizontal plane, strictly between -90 and 90 (default: 0)
+    #[serde(default)]
+    pub pitch: f32,
+
+    /// Distance from the target (default: the camera's current distance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f32>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/orbit_camera` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct OrbitCameraResult {
+    /// The raw BRP response with the camera's new transform, forward direction, and target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Orbited camera {camera} around its target")]
+    pub message_template: String,
+}
@@ -0,0 +1,47 @@
+//! `brp_extras/pick_entity` tool - Find the mesh under a window position
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/pick_entity` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct PickEntityParams {
+    /// Horizontal window position in logical pixels from the left edge
+    pub x: f32,
+
+    /// Vertical window position in logical pixels from the top edge
+    pub y: f32,
+
+    /// Optional window entity ID to target (defaults to primary window)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<u64>,
+
+    /// Optional camera entity ID to cast from (defaults to the highest-order camera rendering
+    /// the position)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/pick_entity` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct PickEntityResult {
+    /// The raw BRP response with the camera, ray, and nearest hit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Ray cast completed")]
+    pub message_template: String,
+}
@@ -7,6 +7,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 
 /// Parameters for the `brp_extras/pinch_gesture` tool
@@ -15,6 +16,9 @@ pub struct PinchGestureParams {
     /// Pinch delta value (positive = zoom in, negative = zoom out)
     pub delta: f32,
 
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
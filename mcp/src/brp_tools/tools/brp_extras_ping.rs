@@ -0,0 +1,32 @@
+//! `brp_extras/ping` tool - Check that the app answers and report its extras version
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/ping` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct PingParams {
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/ping` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct PingResult {
+    /// The raw BRP response with versions, features, and uptime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "App responded to ping")]
+    pub message_template: String,
+}
@@ -0,0 +1,49 @@
+//! `brp_extras/press_keys` tool - Hold keys down across requests
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/press_keys` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct PressKeysParams {
+    /// Array of key code names to press and hold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+
+    /// Shortcut to hold instead of `keys`, e.g. "Shift+W"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chord: Option<String>,
+
+    /// Milliseconds after which the keys are released if `brp_extras_release_keys` is never
+    /// called (default: 10000ms, max: 60000ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_release_ms: Option<u32>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/press_keys` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct PressKeysResult {
+    /// The raw BRP response with the newly pressed keys and every held key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Pressed keys")]
+    pub message_template: String,
+}
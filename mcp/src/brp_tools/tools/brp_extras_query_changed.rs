@@ -0,0 +1,55 @@
+//! `brp_extras/query_changed` tool - Entities whose components changed since a cursor
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Which change ticks `brp_extras/query_changed` checks
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeFilter {
+    /// The component was added or mutated, like `Changed<T>`
+    Changed,
+    /// The component was added, like `Added<T>`
+    Added,
+}
+
+/// Parameters for the `brp_extras/query_changed` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct QueryChangedParams {
+    /// Full type paths of reflected components; entities must have all of them, each matching
+    /// `filter`
+    pub components: Vec<String>,
+
+    /// `changed` (default) or `added`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ChangeFilter>,
+
+    /// `cursor` from a previous response; only entities changed after that call are returned.
+    /// Omit to get every entity with the components.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<u32>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/query_changed` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct QueryChangedResult {
+    /// The raw BRP response with the changed `entities` and the `cursor` for the next call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Queried changed components")]
+    pub message_template: String,
+}
@@ -0,0 +1,52 @@
+//! `brp_extras/query_spatial` tool - Find entities near a point or inside a box
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/query_spatial` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct QuerySpatialParams {
+    /// Center of the search sphere as `[x, y, z]` in world space (use with `radius`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub center: Option<[f32; 3]>,
+
+    /// Radius of the search sphere (use with `center`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<f32>,
+
+    /// Minimum corner of the search box as `[x, y, z]` (use with `max` instead of a sphere)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<[f32; 3]>,
+
+    /// Maximum corner of the search box as `[x, y, z]` (use with `min` instead of a sphere)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<[f32; 3]>,
+
+    /// Only return this many entities, nearest first. `total_count` still counts every hit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/query_spatial` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct QuerySpatialResult {
+    /// The raw BRP response with the matching entities, nearest first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Spatial query completed")]
+    pub message_template: String,
+}
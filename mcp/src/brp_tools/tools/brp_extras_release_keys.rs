@@ -0,0 +1,44 @@
+//! `brp_extras/release_keys` tool - Release keys held by `brp_extras/press_keys`
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/release_keys` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ReleaseKeysParams {
+    /// Array of key code names to release (omit `keys` and `chord` to release every held key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+
+    /// Shortcut to release instead of `keys`, e.g. "Shift+W"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chord: Option<String>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/release_keys` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ReleaseKeysResult {
+    /// The raw BRP response with the released keys and the keys still held
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Released keys")]
+    pub message_template: String,
+}
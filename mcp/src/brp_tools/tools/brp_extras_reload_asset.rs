@@ -0,0 +1,35 @@
+//! `brp_extras/reload_asset` tool - Reload an asset from its source
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/reload_asset` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ReloadAssetParams {
+    /// Path of a loaded asset to reload (e.g. `textures/player.png`)
+    pub path: String,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/reload_asset` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ReloadAssetResult {
+    /// The raw BRP response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Asset reload queued")]
+    pub message_template: String,
+}
@@ -7,6 +7,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 
 /// Parameters for the `brp_extras/rotation_gesture` tool
@@ -15,6 +16,9 @@ pub struct RotationGestureParams {
     /// Rotation delta in radians
     pub delta: f32,
 
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
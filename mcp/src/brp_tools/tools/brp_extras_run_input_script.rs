@@ -0,0 +1,37 @@
+//! `brp_extras/run_input_script` tool - Play back scripted input frame-accurately
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/run_input_script` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct RunInputScriptParams {
+    /// Input events to play back. Each object has `frame` (offset from the start of playback),
+    /// `type` (`key`, `move_mouse`, `click`, or `scroll`), and the parameters of the matching
+    /// `brp_extras` method (e.g. `keys`, `position`, `button`, `x`/`y`/`unit`).
+    pub events: Vec<Value>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/run_input_script` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct RunInputScriptResult {
+    /// The raw BRP response with the scheduled event count and script length in frames
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Input script scheduled")]
+    pub message_template: String,
+}
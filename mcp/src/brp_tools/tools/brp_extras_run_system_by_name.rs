@@ -0,0 +1,39 @@
+//! `brp_extras/run_system_by_name` tool - Run a named one-shot system
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/run_system_by_name` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct RunSystemByNameParams {
+    /// Name the app registered the system under with `App::register_brp_system`
+    pub name: String,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/run_system_by_name` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct RunSystemByNameResult {
+    /// The raw BRP response with the `name` of the system that ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Ran system {name}")]
+    pub message_template: String,
+}
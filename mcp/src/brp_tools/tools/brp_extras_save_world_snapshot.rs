@@ -0,0 +1,45 @@
+//! `brp_extras/save_world_snapshot` tool - Capture reflectable entities and resources
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/save_world_snapshot` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SaveWorldSnapshotParams {
+    /// File to write the snapshot to, resolved by the app process. When omitted, the snapshot is
+    /// returned inline as `snapshot`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Entity IDs to capture (default: every entity)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<u64>>,
+
+    /// Capture reflectable resources too (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_resources: Option<bool>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/save_world_snapshot` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SaveWorldSnapshotResult {
+    /// The raw BRP response with entity and resource counts, and `path` or inline `snapshot`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "World snapshot saved")]
+    pub message_template: String,
+}
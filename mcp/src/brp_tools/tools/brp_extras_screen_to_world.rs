@@ -0,0 +1,58 @@
+//! `brp_extras/screen_to_world` tool - Get the world-space ray through a window position
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// A plane for `screen_to_world` to intersect the ray with
+#[derive(Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ProjectionPlane {
+    /// Any point on the plane as `[x, y, z]`
+    pub origin: [f32; 3],
+    /// Plane normal as `[x, y, z]`, for example `[0, 1, 0]` for the ground
+    pub normal: [f32; 3],
+}
+
+/// Parameters for the `brp_extras/screen_to_world` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ScreenToWorldParams {
+    /// Camera entity ID whose projection is used
+    pub camera: u64,
+
+    /// Horizontal window position in logical pixels from the left edge
+    pub x: f32,
+
+    /// Vertical window position in logical pixels from the top edge
+    pub y: f32,
+
+    /// Return the point this far along the ray (mutually exclusive with `plane`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f32>,
+
+    /// Return the point where the ray crosses this plane (mutually exclusive with `distance`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plane: Option<ProjectionPlane>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/screen_to_world` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct ScreenToWorldResult {
+    /// The raw BRP response with the ray and optional point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Converted window position to a world ray")]
+    pub message_template: String,
+}
@@ -24,6 +24,9 @@ use crate::tool::HandlerResult;
 use crate::tool::ToolFn;
 use crate::tool::ToolResult;
 
+/// Response field holding the base64 PNG of an inline capture.
+const INLINE_DATA_FIELD: &str = "data";
+
 /// Parameters for the terminal `brp_extras/screenshot` tool.
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct ScreenshotParams {
@@ -35,8 +38,17 @@ pub struct ScreenshotParams {
     pub camera:  Option<u64>,
     /// Physical pixels to add around an entity crop. Defaults to zero.
     pub padding: Option<u32>,
-    /// File path where the complete PNG should be published.
-    pub path:    String,
+    /// Window entity ID to capture instead of the primary window. Not combined with a selector
+    /// or `camera`.
+    pub window:  Option<u64>,
+    /// `[x, y, width, height]` in physical pixels to keep, relative to the captured window or
+    /// camera viewport. Not combined with a selector.
+    pub region:  Option<[u32; 4]>,
+    /// File path where the complete PNG should be published. Omit when `inline` is set.
+    pub path:    Option<String>,
+    /// Return the PNG base64-encoded in the result instead of writing `path`. WASM apps always
+    /// return inline.
+    pub inline:  Option<bool>,
     /// The BRP port (default: 15702).
     #[serde(default)]
     pub port:    Port,
@@ -86,6 +98,8 @@ impl ToolFn for BrpExtrasScreenshot {
 enum ScreenshotScope {
     Full {
         camera: Option<u64>,
+        window: Option<u64>,
+        region: Option<[u32; 4]>,
     },
     Entity {
         entity:  u64,
@@ -100,9 +114,15 @@ enum ScreenshotScope {
 }
 
 struct ScreenshotRequest {
-    path:  String,
-    port:  Port,
-    scope: ScreenshotScope,
+    output: ScreenshotOutput,
+    port:   Port,
+    scope:  ScreenshotScope,
+}
+
+/// Destination fields forwarded to extras unchanged; extras validates the combination.
+struct ScreenshotOutput {
+    path:   Option<String>,
+    inline: Option<bool>,
 }
 
 impl TryFrom<ScreenshotParams> for ScreenshotRequest {
@@ -114,10 +134,18 @@ impl TryFrom<ScreenshotParams> for ScreenshotRequest {
             name,
             camera,
             padding,
+            window,
+            region,
             path,
+            inline,
             port,
         } = params;
 
+        if (entity.is_some() || name.is_some()) && (window.is_some() || region.is_some()) {
+            return Err(selector_error(
+                "`window` and `region` only apply without an `entity` or `name` screenshot selector",
+            ));
+        }
         let scope = match (entity, name) {
             (Some(_), Some(_)) => {
                 return Err(selector_error(
@@ -139,10 +167,18 @@ impl TryFrom<ScreenshotParams> for ScreenshotRequest {
                     "`padding` requires an `entity` or `name` screenshot selector",
                 ));
             },
-            (None, None) => ScreenshotScope::Full { camera },
+            (None, None) => ScreenshotScope::Full {
+                camera,
+                window,
+                region,
+            },
         };
 
-        Ok(Self { path, port, scope })
+        Ok(Self {
+            output: ScreenshotOutput { path, inline },
+            port,
+            scope,
+        })
     }
 }
 
@@ -150,6 +186,8 @@ impl TryFrom<ScreenshotParams> for ScreenshotRequest {
 enum ResolvedScope {
     Full {
         camera: Option<u64>,
+        window: Option<u64>,
+        region: Option<[u32; 4]>,
     },
     Entity {
         entity:  u64,
@@ -160,21 +198,28 @@ enum ResolvedScope {
 }
 
 impl ResolvedScope {
-    fn extras_params(&self, path: String) -> Result<Value> {
-        let (entity, camera, padding) = match self {
-            Self::Full { camera } => (None, *camera, None),
+    fn extras_params(&self, output: ScreenshotOutput) -> Result<Value> {
+        let (entity, camera, padding, window, region) = match self {
+            Self::Full {
+                camera,
+                window,
+                region,
+            } => (None, *camera, None, *window, *region),
             Self::Entity {
                 entity,
                 camera,
                 padding,
                 ..
-            } => (Some(*entity), *camera, Some(*padding)),
+            } => (Some(*entity), *camera, Some(*padding), None, None),
         };
         let params = ExtrasScreenshotParams {
             camera,
             entity,
+            inline: output.inline,
             padding,
-            path,
+            path: output.path,
+            window,
+            region,
         };
 
         serde_json::to_value(params).map_err(|error| {
@@ -200,15 +245,26 @@ struct ExtrasScreenshotParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     entity:  Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    inline:  Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     padding: Option<u32>,
-    path:    String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path:    Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    window:  Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region:  Option<[u32; 4]>,
 }
 
 async fn take_screenshot(params: ScreenshotParams) -> Result<ScreenshotResult> {
     let request = ScreenshotRequest::try_from(params)?;
-    let ScreenshotRequest { path, port, scope } = request;
+    let ScreenshotRequest {
+        output,
+        port,
+        scope,
+    } = request;
     let resolved_scope = resolve_scope(scope, port).await?;
-    let extras_params = resolved_scope.extras_params(path)?;
+    let extras_params = resolved_scope.extras_params(output)?;
     let client = BrpClient::new(BrpMethod::BrpExtrasScreenshot, port, Some(extras_params));
     let response = client.execute_raw().await?;
 
@@ -217,7 +273,15 @@ async fn take_screenshot(params: ScreenshotParams) -> Result<ScreenshotResult> {
 
 async fn resolve_scope(scope: ScreenshotScope, port: Port) -> Result<ResolvedScope> {
     match scope {
-        ScreenshotScope::Full { camera } => Ok(ResolvedScope::Full { camera }),
+        ScreenshotScope::Full {
+            camera,
+            window,
+            region,
+        } => Ok(ResolvedScope::Full {
+            camera,
+            window,
+            region,
+        }),
         ScreenshotScope::Entity {
             entity,
             camera,
@@ -290,7 +354,15 @@ fn screenshot_result(
     match response {
         ResponseStatus::Success(result) => {
             let (entity, name) = resolved_scope.metadata();
-            Ok(ScreenshotResult::new(result, entity, name))
+            let inline = result
+                .as_ref()
+                .is_some_and(|result| result.get(INLINE_DATA_FIELD).is_some());
+            let screenshot = ScreenshotResult::new(result, entity, name);
+            if inline {
+                Ok(screenshot.with_message_template("Screenshot returned inline as base64 PNG"))
+            } else {
+                Ok(screenshot)
+            }
         },
         ResponseStatus::Error(error) => Err(screenshot_brp_error(
             error.code,
@@ -353,18 +425,42 @@ mod tests {
             name:    None,
             camera:  None,
             padding: None,
-            path:    TEST_PATH.to_string(),
+            window:  None,
+            region:  None,
+            path:    Some(TEST_PATH.to_string()),
+            inline:  None,
             port:    TEST_PORT,
         }
     }
 
+    const fn full(camera: Option<u64>) -> ResolvedScope {
+        ResolvedScope::Full {
+            camera,
+            window: None,
+            region: None,
+        }
+    }
+
+    fn output() -> ScreenshotOutput {
+        ScreenshotOutput {
+            path:   Some(TEST_PATH.to_string()),
+            inline: None,
+        }
+    }
+
     #[tokio::test]
     async fn request_modes_convert_to_typed_scopes_and_extras_payloads()
     -> core::result::Result<(), Box<dyn std::error::Error>> {
-        let full = ScreenshotRequest::try_from(params())?;
-        assert_eq!(full.scope, ScreenshotScope::Full { camera: None });
-        let full_extras =
-            ResolvedScope::Full { camera: None }.extras_params(TEST_PATH.to_string())?;
+        let full_request = ScreenshotRequest::try_from(params())?;
+        assert_eq!(
+            full_request.scope,
+            ScreenshotScope::Full {
+                camera: None,
+                window: None,
+                region: None,
+            }
+        );
+        let full_extras = full(None).extras_params(output())?;
         assert_eq!(
             full_extras,
             json!({
@@ -380,11 +476,13 @@ mod tests {
             camera.scope,
             ScreenshotScope::Full {
                 camera: Some(TEST_CAMERA),
+                window: None,
+                region: None,
             }
         );
         let camera_extras = resolve_scope(camera.scope, TEST_PORT)
             .await?
-            .extras_params(TEST_PATH.to_string())?;
+            .extras_params(output())?;
         assert_eq!(
             camera_extras,
             json!({
@@ -407,7 +505,7 @@ mod tests {
         );
         let entity_extras = resolve_scope(entity.scope, TEST_PORT)
             .await?
-            .extras_params(TEST_PATH.to_string())?;
+            .extras_params(output())?;
         assert_eq!(
             entity_extras,
             json!({
@@ -432,7 +530,7 @@ mod tests {
         );
         let camera_entity_extras = resolve_scope(camera_entity.scope, TEST_PORT)
             .await?
-            .extras_params(TEST_PATH.to_string())?;
+            .extras_params(output())?;
         assert_eq!(
             camera_entity_extras,
             json!({
@@ -454,7 +552,10 @@ mod tests {
             name:    None,
             camera:  Some(TEST_CAMERA),
             padding: Some(TEST_PADDING),
-            path:    TEST_PATH.to_string(),
+            window:  None,
+            region:  None,
+            path:    Some(TEST_PATH.to_string()),
+            inline:  None,
             port:    TEST_PORT,
         })?;
         assert_eq!(
@@ -464,7 +565,10 @@ mod tests {
                 "entity": TEST_ENTITY_LOW,
                 "name": null,
                 "padding": TEST_PADDING,
+                "window": null,
+                "region": null,
                 "path": TEST_PATH,
+                "inline": null,
                 "port": *TEST_PORT,
             })
         );
@@ -473,11 +577,26 @@ mod tests {
         assert!(public_schema.pointer("/properties/name").is_some());
         assert!(public_schema.pointer("/properties/camera").is_some());
         assert!(public_schema.pointer("/properties/padding").is_some());
+        assert!(public_schema.pointer("/properties/window").is_some());
+        assert!(public_schema.pointer("/properties/region").is_some());
         assert!(public_schema.pointer("/properties/path").is_some());
+        assert!(public_schema.pointer("/properties/inline").is_some());
         assert!(public_schema.pointer("/properties/port").is_some());
         Ok(())
     }
 
+    #[test]
+    fn inline_output_is_forwarded_without_a_path()
+    -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let extras_params = full(None).extras_params(ScreenshotOutput {
+            path:   None,
+            inline: Some(true),
+        })?;
+
+        assert_eq!(extras_params, json!({ "inline": true }));
+        Ok(())
+    }
+
     #[test]
     fn exact_name_defaults_to_zero_padding() -> core::result::Result<(), Box<dyn std::error::Error>>
     {
@@ -510,7 +629,7 @@ mod tests {
             }],
             TEST_PORT,
         )?;
-        let extras_params = resolved_scope.extras_params(TEST_PATH.to_string())?;
+        let extras_params = resolved_scope.extras_params(output())?;
 
         assert_eq!(
             extras_params,
@@ -586,6 +705,38 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn window_and_region_are_forwarded_for_full_captures()
+    -> core::result::Result<(), Box<dyn std::error::Error>> {
+        let region = ScreenshotRequest::try_from(ScreenshotParams {
+            window: Some(TEST_ENTITY_HIGH),
+            region: Some([10, 20, 30, 40]),
+            ..params()
+        })?;
+        let extras_params = resolve_scope(region.scope, TEST_PORT)
+            .await?
+            .extras_params(output())?;
+        assert_eq!(
+            extras_params,
+            json!({
+                "path": TEST_PATH,
+                "window": TEST_ENTITY_HIGH,
+                "region": [10, 20, 30, 40],
+            })
+        );
+
+        let with_entity = ScreenshotRequest::try_from(ScreenshotParams {
+            entity: Some(TEST_ENTITY_LOW),
+            region: Some([10, 20, 30, 40]),
+            ..params()
+        });
+        assert!(matches!(
+            with_entity.as_ref().map_err(error_stack::Report::current_context),
+            Err(Error::ToolCall { message, .. }) if message.contains("only apply without")
+        ));
+        Ok(())
+    }
+
     #[test]
     fn padding_is_invalid_without_an_entity_or_name() {
         let padding = ScreenshotRequest::try_from(ScreenshotParams {
@@ -652,9 +803,13 @@ mod tests {
             .map(|definition| definition.tool_name.to_string())
             .collect::<Vec<_>>();
 
+        // `brp_compare_screenshots` diffs saved files and is the only other screenshot tool
         assert_eq!(
             screenshot_tools,
-            vec![ToolName::BrpExtrasScreenshot.to_string()]
+            vec![
+                ToolName::BrpCompareScreenshots.to_string(),
+                ToolName::BrpExtrasScreenshot.to_string(),
+            ]
         );
         assert!(
             screenshot_tools
@@ -7,6 +7,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 use crate::brp_tools::mouse::ScrollUnitWrapper;
 
@@ -26,6 +27,9 @@ pub struct ScrollMouseParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub window: Option<u64>,
 
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
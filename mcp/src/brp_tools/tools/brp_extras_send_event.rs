@@ -0,0 +1,44 @@
+//! `brp_extras/send_event` tool - Write a reflected message into the app
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/send_event` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SendEventParams {
+    /// The full type path of the message to write (e.g., "`my_game::events::EnemyDefeated`").
+    /// The app must register it with `App::register_brp_message`.
+    pub event: String,
+
+    /// The message as JSON in BRP's reflect format. Omit for unit structs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/send_event` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SendEventResult {
+    /// The raw BRP response with the written `event` and the `frame` it was written in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Sent event {event}")]
+    pub message_template: String,
+}
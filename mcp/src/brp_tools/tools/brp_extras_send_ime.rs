@@ -0,0 +1,45 @@
+//! `brp_extras/send_ime` tool - Send IME composition events (preedit, commit)
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/send_ime` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SendImeParams {
+    /// IME events to write in order, each tagged by `type`: `{"type": "enabled"}`,
+    /// `{"type": "preedit", "value": "にほ", "cursor": [6, 6]}`, `{"type": "commit", "value":
+    /// "日本"}`, or `{"type": "disabled"}`. `cursor` is an optional byte range within `value`.
+    pub events: Vec<Value>,
+
+    /// Optional window entity ID to target (defaults to primary window)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<u64>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/send_ime` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SendImeResult {
+    /// The raw BRP response with the event count and committed text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Sent IME events")]
+    pub message_template: String,
+}
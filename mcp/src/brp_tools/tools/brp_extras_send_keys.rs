@@ -7,18 +7,27 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FrameTiming;
 use crate::brp_tools::Port;
 
 /// Parameters for the `brp_extras/send_keys` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct SendKeysParams {
     /// Array of key code names to send
-    pub keys: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+
+    /// Shortcut to send instead of `keys`, e.g. "Ctrl+Shift+P", "Cmd+S", or "Alt+F4"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chord: Option<String>,
 
     /// Duration in milliseconds to hold the keys before releasing (default: 100ms, max: 60000ms)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration_ms: Option<u32>,
 
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
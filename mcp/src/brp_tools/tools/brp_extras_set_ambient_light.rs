@@ -0,0 +1,44 @@
+//! `brp_extras/set_ambient_light` tool - Set the app-wide or one camera's ambient light
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/set_ambient_light` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetAmbientLightParams {
+    /// Light color as sRGBA `[r, g, b, a]` with components in 0.0-1.0 (default: unchanged)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<[f32; 4]>,
+
+    /// Brightness in candela per square meter (default: unchanged)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub brightness: Option<f32>,
+
+    /// Camera entity ID to override, instead of the app-wide `GlobalAmbientLight`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_ambient_light` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetAmbientLightResult {
+    /// The raw BRP response with the resulting color and brightness
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Set ambient light")]
+    pub message_template: String,
+}
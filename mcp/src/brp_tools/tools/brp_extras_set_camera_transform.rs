@@ -0,0 +1,48 @@
+//! `brp_extras/set_camera_transform` tool - Set parts of a camera's `Transform`
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/set_camera_transform` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetCameraTransformParams {
+    /// Camera entity ID whose `Transform` is set
+    pub camera: u64,
+
+    /// New translation as `[x, y, z]` (omit to keep the current one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<[f32; 3]>,
+
+    /// New rotation as a quaternion `[x, y, z, w]`, normalized before use (omit to keep the
+    /// current one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<[f32; 4]>,
+
+    /// New scale as `[x, y, z]` (omit to keep the current one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<[f32; 3]>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_camera_transform` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetCameraTransformResult {
+    /// The raw BRP response with the camera's new transform and forward direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Set transform of camera {camera}")]
+    pub message_template: String,
+}
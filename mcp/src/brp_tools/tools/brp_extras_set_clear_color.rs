@@ -0,0 +1,39 @@
+//! `brp_extras/set_clear_color` tool - Set the app-wide or one camera's clear color
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/set_clear_color` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetClearColorParams {
+    /// Clear color as sRGBA `[r, g, b, a]` with components in 0.0-1.0
+    pub color: [f32; 4],
+
+    /// Camera entity ID to set the color on, instead of the app-wide `ClearColor`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_clear_color` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetClearColorResult {
+    /// The raw BRP response with the new and previous colors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Set clear color")]
+    pub message_template: String,
+}
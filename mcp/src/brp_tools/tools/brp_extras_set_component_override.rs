@@ -0,0 +1,49 @@
+//! `brp_extras/set_component_override` tool - Override a component value temporarily
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/set_component_override` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetComponentOverrideParams {
+    /// The entity ID whose component is overridden
+    pub entity: u64,
+
+    /// Fully-qualified component type path
+    pub component: String,
+
+    /// Component value, in the same format as `world_insert_components`
+    pub value: Value,
+
+    /// Seconds until the original value is restored (default: until `brp_extras_clear_overrides`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f32>,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_component_override` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetComponentOverrideResult {
+    /// The raw BRP response with the `override_id` and the `original` value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Overrode {component} on entity {entity}")]
+    pub message_template: String,
+}
@@ -0,0 +1,38 @@
+//! `brp_extras/set_linear_velocity` tool - Set an Avian or `bevy_rapier` body's linear velocity
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/set_linear_velocity` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetLinearVelocityParams {
+    /// The rigid body entity ID
+    pub entity: u64,
+
+    /// New linear velocity: `[x, y]` for 2D engines, `[x, y, z]` for 3D engines
+    pub velocity: Vec<f64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_linear_velocity` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetLinearVelocityResult {
+    /// The raw BRP response with the physics backend, component, and new velocity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Set linear velocity of entity {entity}")]
+    pub message_template: String,
+}
@@ -0,0 +1,72 @@
+//! `brp_extras/set_window` tool - Change window properties
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Window mode for `brp_extras/set_window`
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowModeName {
+    /// Normal window
+    Windowed,
+    /// Exclusive fullscreen on the window's current monitor
+    Fullscreen,
+    /// Borderless fullscreen on the window's current monitor
+    Borderless,
+}
+
+/// Parameters for the `brp_extras/set_window` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct SetWindowParams {
+    /// Optional window entity ID to target (defaults to primary window)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<u64>,
+
+    /// Logical size as [width, height]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<[f32; 2]>,
+
+    /// Top-left corner in physical screen pixels as [x, y]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<[i32; 2]>,
+
+    /// Window mode: "windowed", "fullscreen", or "borderless"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<WindowModeName>,
+
+    /// Show the title bar and border
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decorations: Option<bool>,
+
+    /// Show the cursor while it is over the window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_visible: Option<bool>,
+
+    /// Synchronize presentation with the display refresh rate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vsync: Option<bool>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/set_window` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct SetWindowResult {
+    /// The raw BRP response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Window properties updated")]
+    pub message_template: String,
+}
@@ -12,7 +12,8 @@ use crate::brp_tools::Port;
 /// Parameters for the `brp_extras/set_window_title` tool
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct SetWindowTitleParams {
-    /// The new title to set for the window
+    /// The new title to set for the window. `{fps}`, `{frame}`, and `{entities}` placeholders
+    /// make it a live template that the app re-renders every second.
     pub title: String,
 
     /// The BRP port (default: 15702)
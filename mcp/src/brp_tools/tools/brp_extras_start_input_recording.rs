@@ -0,0 +1,32 @@
+//! `brp_extras/start_input_recording` tool - Start recording keyboard and mouse input
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/start_input_recording` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct StartInputRecordingParams {
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/start_input_recording` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct StartInputRecordingResult {
+    /// The raw BRP response, including events discarded from a recording already in progress
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Input recording started")]
+    pub message_template: String,
+}
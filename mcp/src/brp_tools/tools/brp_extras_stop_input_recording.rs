@@ -0,0 +1,32 @@
+//! `brp_extras/stop_input_recording` tool - Stop recording and return an input script
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/stop_input_recording` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct StopInputRecordingParams {
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/stop_input_recording` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct StopInputRecordingResult {
+    /// The raw BRP response with the recorded `events`, ready for `brp_extras_run_input_script`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Input recording stopped")]
+    pub message_template: String,
+}
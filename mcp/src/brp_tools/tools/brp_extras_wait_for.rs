@@ -0,0 +1,68 @@
+//! `brp_extras/wait_for` tool - Wait until a condition holds in the app
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// What `brp_extras/wait_for` waits for, selected by `kind`
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WaitCondition {
+    /// At least one entity has the component
+    ComponentExists {
+        /// Full type path of a component registered with `reflect(Component)`
+        component: String,
+    },
+    /// The resource exists and serializes to exactly `value`
+    ResourceEquals {
+        /// Full type path of a resource registered with `reflect(Resource)`
+        resource: String,
+        /// The value in BRP's reflect format, as `world_get_resources` returns it
+        value:    Value,
+    },
+    /// A diagnostic's smoothed value is above and/or below a threshold
+    DiagnosticThreshold {
+        /// Diagnostic path, e.g. `fps`
+        path:  String,
+        /// Hold once the value is greater than this
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        above: Option<f64>,
+        /// Hold once the value is less than this
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        below: Option<f64>,
+    },
+}
+
+/// Parameters for the `brp_extras/wait_for` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct WaitForParams {
+    /// The condition to wait for
+    pub condition: WaitCondition,
+
+    /// Milliseconds to wait before failing with a timeout error (default: 5000, max: 25000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/wait_for` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct WaitForResult {
+    /// The raw BRP response with what was `observed`, `elapsed_ms`, and `frames_waited`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Condition held")]
+    pub message_template: String,
+}
@@ -0,0 +1,43 @@
+//! `brp_extras/world_to_screen` tool - Project a world position to a window position
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/world_to_screen` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct WorldToScreenParams {
+    /// Camera entity ID whose projection is used
+    pub camera: u64,
+
+    /// World-space point as `[x, y, z]` (provide this or `entity`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point: Option<[f32; 3]>,
+
+    /// Entity ID whose `GlobalTransform` translation is projected (provide this or `point`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/world_to_screen` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct WorldToScreenResult {
+    /// The raw BRP response with the window position and whether it is in view
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Projected world position to the window")]
+    pub message_template: String,
+}
@@ -0,0 +1,59 @@
+//! List entity tags, refreshing their IDs by name.
+
+use async_trait::async_trait;
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::brp_tag_entity;
+use super::brp_tag_entity::ListedTag;
+use super::brp_tag_entity::TagStatus;
+use crate::brp_tools::Port;
+use crate::error::Result;
+use crate::tool::ToolFn;
+
+/// Parameters for listing entity tags.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct ListTagsParams {
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result of listing entity tags.
+#[derive(Serialize, ResultStruct)]
+pub struct ListTagsResult {
+    /// Tags in label order, each with whether its entity was found in the running app.
+    #[to_result]
+    pub tags:             Vec<ListedTag>,
+    /// Number of tags.
+    #[to_metadata]
+    pub tag_count:        usize,
+    /// Number of tags whose entity ID was updated by name.
+    #[to_metadata]
+    pub refreshed_count:  usize,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Listed {tag_count} entity tags")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that lists entity tags.
+pub struct BrpListTags;
+
+#[async_trait]
+impl ToolFn for BrpListTags {
+    type Output = ListTagsResult;
+    type Params = ListTagsParams;
+
+    async fn handle_impl(&self, params: ListTagsParams) -> Result<ListTagsResult> {
+        let tags = brp_tag_entity::refresh_tags(params.port).await?;
+        let tag_count = tags.len();
+        let refreshed_count = tags
+            .iter()
+            .filter(|listed| listed.status == TagStatus::Refreshed)
+            .count();
+        Ok(ListTagsResult::new(tags, tag_count, refreshed_count))
+    }
+}
@@ -0,0 +1,274 @@
+//! Label entities with human-friendly tags that survive app restarts.
+//!
+//! Tags are kept per port for the MCP session. Each tag remembers the entity's reflected `Name`
+//! alongside its ID, so when the app restarts and entity IDs change, [`refresh_tags`] finds the
+//! entity again by name.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::world_find_entities_by_name;
+use super::world_find_entities_by_name::NameMatchMode;
+use super::world_find_entities_by_name::NamedEntity;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::ToolFn;
+
+/// Tags per port, by label
+static ENTITY_TAGS: LazyLock<Mutex<HashMap<Port, BTreeMap<String, EntityTag>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A label pointing at an entity.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct EntityTag {
+    /// The human-friendly label.
+    pub label:  String,
+    /// Entity ID the label points at.
+    pub entity: u64,
+    /// Reflected `Name` of the entity, used to find it again after the app restarts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:   Option<String>,
+}
+
+/// Whether a listed tag's entity was found in the running app.
+#[derive(Clone, Copy, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagStatus {
+    /// The entity still has the tagged name.
+    Current,
+    /// The entity ID changed, e.g. after a restart, and was updated by name.
+    Refreshed,
+    /// No single entity has the tagged name; the stored ID is likely stale.
+    Missing,
+    /// The tag has no name to check the ID against.
+    Unnamed,
+    /// The app could not be queried, so the stored ID was not checked.
+    Unverified,
+}
+
+/// A tag as reported by `brp_list_tags`.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct ListedTag {
+    /// The tag.
+    #[serde(flatten)]
+    pub tag:    EntityTag,
+    /// Whether the entity was found in the running app.
+    pub status: TagStatus,
+}
+
+/// Parameters for tagging an entity.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct TagEntityParams {
+    /// Human-friendly label; tagging an existing label again points it at the new entity.
+    pub label:  String,
+    /// Entity ID to tag. Give this or `name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<u64>,
+    /// Exact reflected `Name` of the entity to tag; it must match exactly one entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name:   Option<String>,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:   Port,
+}
+
+/// Result of tagging an entity.
+#[derive(Serialize, ResultStruct)]
+pub struct TagEntityResult {
+    /// The stored tag.
+    #[to_result]
+    pub tag:              EntityTag,
+    /// Entity the label pointed at before, when it was re-tagged.
+    #[to_metadata(skip_if_none)]
+    pub previous_entity:  Option<u64>,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Tagged entity as '{label}'")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that stores an entity tag.
+pub struct BrpTagEntity;
+
+#[async_trait]
+impl ToolFn for BrpTagEntity {
+    type Output = TagEntityResult;
+    type Params = TagEntityParams;
+
+    async fn handle_impl(&self, params: TagEntityParams) -> Result<TagEntityResult> {
+        let tag = match (params.entity, params.name) {
+            (Some(entity), None) => EntityTag {
+                label: params.label,
+                entity,
+                // Without a name the tag still works, it just can't be refreshed
+                name: all_named_entities(params.port)
+                    .await
+                    .ok()
+                    .and_then(|named| named.into_iter().find(|named| named.entity == entity))
+                    .map(|named| named.name),
+            },
+            (None, Some(name)) => {
+                let matching: Vec<u64> = all_named_entities(params.port)
+                    .await?
+                    .into_iter()
+                    .filter(|named| named.name == name)
+                    .map(|named| named.entity)
+                    .collect();
+                let [entity] = matching[..] else {
+                    return Err(Error::invalid(
+                        "name",
+                        format!(
+                            "'{name}' matches {} entities on port {}; tag by `entity` instead",
+                            matching.len(),
+                            params.port
+                        ),
+                    )
+                    .into());
+                };
+                EntityTag {
+                    label: params.label,
+                    entity,
+                    name: Some(name),
+                }
+            },
+            _ => {
+                return Err(
+                    Error::invalid("parameters", "give exactly one of `entity` or `name`").into(),
+                );
+            },
+        };
+
+        let previous_entity = ENTITY_TAGS
+            .lock()
+            .map_err(|_| Error::General("Entity tag store is poisoned".to_string()))?
+            .entry(params.port)
+            .or_default()
+            .insert(tag.label.clone(), tag.clone())
+            .map(|previous| previous.entity)
+            .filter(|previous| *previous != tag.entity);
+        Ok(TagEntityResult::new(tag, previous_entity))
+    }
+}
+
+/// Every entity with a reflected `Name` on `port`
+async fn all_named_entities(port: Port) -> Result<Vec<NamedEntity>> {
+    // An empty prefix matches every name
+    world_find_entities_by_name::find_entities_by_name("", NameMatchMode::Prefix, port).await
+}
+
+/// The tags of `port`, with entity IDs updated by name when they no longer match
+pub(super) async fn refresh_tags(port: Port) -> Result<Vec<ListedTag>> {
+    let tags: Vec<EntityTag> = ENTITY_TAGS
+        .lock()
+        .map_err(|_| Error::General("Entity tag store is poisoned".to_string()))?
+        .get(&port)
+        .map(|tags| tags.values().cloned().collect())
+        .unwrap_or_default();
+    if tags.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let listed: Vec<ListedTag> = match all_named_entities(port).await {
+        Ok(named_entities) => tags
+            .into_iter()
+            .map(|tag| check_tag(tag, &named_entities))
+            .collect(),
+        Err(_) => tags
+            .into_iter()
+            .map(|tag| ListedTag {
+                tag,
+                status: TagStatus::Unverified,
+            })
+            .collect(),
+    };
+
+    let refreshed: Vec<&ListedTag> = listed
+        .iter()
+        .filter(|listed| listed.status == TagStatus::Refreshed)
+        .collect();
+    if !refreshed.is_empty()
+        && let Ok(mut store) = ENTITY_TAGS.lock()
+        && let Some(store) = store.get_mut(&port)
+    {
+        for listed in refreshed {
+            store.insert(listed.tag.label.clone(), listed.tag.clone());
+        }
+    }
+    Ok(listed)
+}
+
+/// Check a tag against the app's named entities, pointing it at the entity that now has its name
+fn check_tag(mut tag: EntityTag, named_entities: &[NamedEntity]) -> ListedTag {
+    let Some(name) = &tag.name else {
+        return ListedTag {
+            tag,
+            status: TagStatus::Unnamed,
+        };
+    };
+    let matching: Vec<u64> = named_entities
+        .iter()
+        .filter(|named| named.name == *name)
+        .map(|named| named.entity)
+        .collect();
+
+    let status = if matching.contains(&tag.entity) {
+        TagStatus::Current
+    } else if let [entity] = matching[..] {
+        tag.entity = entity;
+        TagStatus::Refreshed
+    } else {
+        TagStatus::Missing
+    };
+    ListedTag { tag, status }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityTag;
+    use super::NamedEntity;
+    use super::TagStatus;
+    use super::check_tag;
+
+    fn named(entity: u64, name: &str) -> NamedEntity {
+        NamedEntity {
+            entity,
+            name: name.to_string(),
+        }
+    }
+
+    fn player_tag() -> EntityTag {
+        EntityTag {
+            label:  "player".to_string(),
+            entity: 7,
+            name:   Some("Player".to_string()),
+        }
+    }
+
+    #[test]
+    fn tags_follow_their_name_to_a_new_entity() {
+        let current = check_tag(player_tag(), &[named(7, "Player")]);
+        assert_eq!(current.status, TagStatus::Current);
+
+        let refreshed = check_tag(player_tag(), &[named(3, "Enemy"), named(12, "Player")]);
+        assert_eq!(refreshed.status, TagStatus::Refreshed);
+        assert_eq!(refreshed.tag.entity, 12);
+    }
+
+    #[test]
+    fn ambiguous_or_missing_names_are_not_refreshed() {
+        let ambiguous = check_tag(player_tag(), &[named(12, "Player"), named(13, "Player")]);
+        assert_eq!(ambiguous.status, TagStatus::Missing);
+        assert_eq!(ambiguous.tag.entity, 7);
+
+        assert_eq!(check_tag(player_tag(), &[]).status, TagStatus::Missing);
+    }
+}
@@ -0,0 +1,50 @@
+//! Revert the most recent component or resource mutation made through the MCP.
+
+use async_trait::async_trait;
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::brp_tools;
+use crate::brp_tools::Port;
+use crate::brp_tools::UndoEntry;
+use crate::error::Result;
+use crate::tool::ToolFn;
+
+/// Parameters for undoing the last mutation.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct UndoLastParams {
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result of undoing the last mutation.
+#[derive(Serialize, ResultStruct)]
+pub struct UndoLastResult {
+    /// The component or resource that was written back, with its restored value.
+    #[to_result]
+    pub reverted:         UndoEntry,
+    /// Number of earlier mutations that can still be undone on this port.
+    #[to_metadata]
+    pub remaining:        usize,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Reverted the last mutation; {remaining} more can be undone")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that writes back the value changed by the last mutation.
+pub struct BrpUndoLast;
+
+#[async_trait]
+impl ToolFn for BrpUndoLast {
+    type Output = UndoLastResult;
+    type Params = UndoLastParams;
+
+    async fn handle_impl(&self, params: UndoLastParams) -> Result<UndoLastResult> {
+        let (reverted, remaining) = brp_tools::undo_last(params.port).await?;
+        Ok(UndoLastResult::new(reverted, remaining))
+    }
+}
@@ -0,0 +1,154 @@
+//! Poll a standard BRP read until a value in its response satisfies an expectation.
+
+use std::time::Duration;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+use tokio::time::Instant;
+
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Expectation;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::brp_tools::constants::DEFAULT_WAIT_UNTIL_INTERVAL_MS;
+use crate::brp_tools::constants::DEFAULT_WAIT_UNTIL_TIMEOUT_MS;
+use crate::brp_tools::expectation;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// Read-only BRP method polled by `brp_wait_until`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema)]
+pub enum PollMethod {
+    /// `world.query`
+    #[serde(rename = "world.query")]
+    Query,
+    /// `world.get_components`
+    #[serde(rename = "world.get_components")]
+    GetComponents,
+    /// `world.get_resources`
+    #[serde(rename = "world.get_resources")]
+    GetResources,
+}
+
+impl PollMethod {
+    const fn brp_method(self) -> BrpMethod {
+        match self {
+            Self::Query => BrpMethod::WorldQuery,
+            Self::GetComponents => BrpMethod::WorldGetComponents,
+            Self::GetResources => BrpMethod::WorldGetResources,
+        }
+    }
+}
+
+/// Parameters for polling standard BRP until a condition holds.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct WaitUntilParams {
+    /// The BRP method to poll: `world.query`, `world.get_components`, or `world.get_resources`.
+    pub method:      PollMethod,
+    /// Parameters sent to `method` on every poll, exactly as BRP takes them.
+    pub params:      Value,
+    /// JSON pointer into the method's result, e.g. `/0/components/my_game::Health/current`.
+    /// Omit to check the whole result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path:        Option<String>,
+    /// What the value at `path` must satisfy, e.g. `{"equals": 3}`, `{"greater_than": 0.5}`, or
+    /// `{"exists": true}`.
+    pub expect:      Expectation,
+    /// Milliseconds to poll before failing (default: 5000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms:  Option<u64>,
+    /// Milliseconds between polls (default: 100).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_ms: Option<u64>,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:        Port,
+}
+
+/// Result of a satisfied wait.
+#[derive(Serialize, ResultStruct)]
+pub struct WaitUntilResult {
+    /// The value at `path` that satisfied the expectation, absent when `exists: false` held.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    pub value:            Option<Value>,
+    /// Number of polls, including the one that satisfied the expectation.
+    #[to_metadata]
+    pub polls:            usize,
+    /// Milliseconds between the first poll and the satisfying response.
+    #[to_metadata]
+    pub elapsed_ms:       u64,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Condition met after {polls} polls")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that polls `method` until the expectation holds or the timeout passes.
+#[derive(ToolFn)]
+#[tool_fn(params = "WaitUntilParams", output = "WaitUntilResult")]
+pub struct BrpWaitUntil;
+
+async fn handle_impl(params: WaitUntilParams) -> Result<WaitUntilResult> {
+    let interval_ms = params.interval_ms.unwrap_or(DEFAULT_WAIT_UNTIL_INTERVAL_MS);
+    if interval_ms == 0 {
+        return Err(Error::invalid("interval_ms", "must be at least 1").into());
+    }
+    let timeout_ms = params.timeout_ms.unwrap_or(DEFAULT_WAIT_UNTIL_TIMEOUT_MS);
+    let brp_method = params.method.brp_method();
+
+    let started = Instant::now();
+    let deadline = started + Duration::from_millis(timeout_ms);
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        let (last_value, last_error) =
+            match BrpClient::new(brp_method, params.port, Some(params.params.clone()))
+                .execute_raw()
+                .await
+            {
+                Ok(ResponseStatus::Success(response)) => {
+                    let value = expectation::resolve(response.as_ref(), params.path.as_deref());
+                    match params.expect.check(value) {
+                        Ok(()) => {
+                            let elapsed_ms =
+                                u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+                            return Ok(WaitUntilResult::new(value.cloned(), polls, elapsed_ms));
+                        },
+                        Err(message) => (value.cloned(), message),
+                    }
+                },
+                Ok(ResponseStatus::Error(error)) => (None, error.message),
+                Err(report) => (None, report.current_context().to_string()),
+            };
+
+        if Instant::now() >= deadline {
+            return Err(Error::tool_call_failed_with_details(
+                format!(
+                    "Condition not met within {timeout_ms}ms after {polls} polls of {}: \
+                     {last_error}",
+                    brp_method.as_str()
+                ),
+                json!({
+                    "method": brp_method.as_str(),
+                    "polls": polls,
+                    "timeout_ms": timeout_ms,
+                    "last_value": last_value,
+                    "last_error": last_error,
+                }),
+            )
+            .into());
+        }
+        tokio::time::sleep(Duration::from_millis(interval_ms)).await;
+    }
+}
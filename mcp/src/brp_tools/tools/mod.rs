@@ -1,29 +1,83 @@
 //! Individual tool modules containing parameter and result structs for each BRP tool
 
+mod brp_compare_screenshots;
+mod brp_compare_snapshots;
+mod brp_diff_entities;
 mod brp_execute;
+mod brp_extras_capabilities;
+mod brp_extras_clear_overrides;
 mod brp_extras_click_mouse;
+mod brp_extras_click_ui_node;
 mod brp_extras_double_click_mouse;
 mod brp_extras_double_tap_gesture;
 mod brp_extras_drag_mouse;
+mod brp_extras_draw_gizmo;
+mod brp_extras_get_asset_info;
+mod brp_extras_get_component_raw;
+mod brp_extras_get_contacts;
 mod brp_extras_get_diagnostics;
+mod brp_extras_get_schedule_graph;
+mod brp_extras_get_visible_text;
+mod brp_extras_get_world_stats;
+mod brp_extras_highlight_entity;
+mod brp_extras_hover_mouse;
+mod brp_extras_list_assets;
+mod brp_extras_list_systems;
+mod brp_extras_load_world_snapshot;
+mod brp_extras_look_at;
 mod brp_extras_move_mouse;
+mod brp_extras_mutate_reflect;
+mod brp_extras_orbit_camera;
+mod brp_extras_pick_entity;
 mod brp_extras_pinch_gesture;
+mod brp_extras_ping;
+mod brp_extras_press_keys;
+mod brp_extras_query_changed;
+mod brp_extras_query_spatial;
+mod brp_extras_release_keys;
+mod brp_extras_reload_asset;
 mod brp_extras_rotation_gesture;
+mod brp_extras_run_input_script;
+mod brp_extras_run_system_by_name;
+mod brp_extras_save_world_snapshot;
+mod brp_extras_screen_to_world;
 mod brp_extras_screenshot;
 mod brp_extras_scroll_mouse;
+mod brp_extras_send_event;
+mod brp_extras_send_ime;
 mod brp_extras_send_keys;
 mod brp_extras_send_mouse_button;
+mod brp_extras_set_ambient_light;
+mod brp_extras_set_camera_transform;
+mod brp_extras_set_clear_color;
+mod brp_extras_set_component_override;
+mod brp_extras_set_gravity;
+mod brp_extras_set_linear_velocity;
+mod brp_extras_set_window;
 mod brp_extras_set_window_title;
+mod brp_extras_start_input_recording;
+mod brp_extras_stop_input_recording;
 mod brp_extras_type_text;
+mod brp_extras_wait_for;
+mod brp_extras_world_to_screen;
 mod brp_list_agent_tools;
+mod brp_list_tags;
+mod brp_tag_entity;
+mod brp_undo_last;
+mod brp_wait_until;
+mod query_filter_expression;
 mod registry_schema;
 mod rpc_discover;
+mod world_component_stats;
 mod world_despawn_entity;
+mod world_despawn_recursive;
 mod world_find_entities_by_name;
 mod world_get_components;
 mod world_get_resources;
 mod world_insert_components;
 mod world_insert_resources;
+mod world_inspect_entity;
+mod world_inspect_resources;
 mod world_list_components;
 mod world_list_resources;
 mod world_mutate_components;
@@ -35,44 +89,148 @@ mod world_reparent_entities;
 mod world_spawn_entity;
 mod world_trigger_event;
 
+pub use brp_compare_screenshots::BrpCompareScreenshots;
+pub use brp_compare_screenshots::CompareScreenshotsParams;
+pub use brp_compare_snapshots::BrpCompareSnapshots;
+pub use brp_compare_snapshots::CompareSnapshotsParams;
+pub use brp_diff_entities::BrpDiffEntities;
+pub use brp_diff_entities::DiffEntitiesParams;
 pub use brp_execute::BrpExecute;
+pub use brp_execute::BrpExecuteBatch;
+pub use brp_execute::ExecuteBatchParams;
 pub use brp_execute::ExecuteParams;
+pub use brp_extras_capabilities::CapabilitiesParams;
+pub use brp_extras_capabilities::CapabilitiesResult;
+pub use brp_extras_clear_overrides::ClearOverridesParams;
+pub use brp_extras_clear_overrides::ClearOverridesResult;
 pub use brp_extras_click_mouse::ClickMouseParams;
 pub use brp_extras_click_mouse::ClickMouseResult;
+pub use brp_extras_click_ui_node::ClickUiNodeParams;
+pub use brp_extras_click_ui_node::ClickUiNodeResult;
 pub use brp_extras_double_click_mouse::DoubleClickMouseParams;
 pub use brp_extras_double_click_mouse::DoubleClickMouseResult;
 pub use brp_extras_double_tap_gesture::DoubleTapGestureParams;
 pub use brp_extras_double_tap_gesture::DoubleTapGestureResult;
 pub use brp_extras_drag_mouse::DragMouseParams;
 pub use brp_extras_drag_mouse::DragMouseResult;
+pub use brp_extras_draw_gizmo::DrawGizmoParams;
+pub use brp_extras_draw_gizmo::DrawGizmoResult;
+pub use brp_extras_get_asset_info::GetAssetInfoParams;
+pub use brp_extras_get_asset_info::GetAssetInfoResult;
+pub use brp_extras_get_component_raw::GetComponentRawParams;
+pub use brp_extras_get_component_raw::GetComponentRawResult;
+pub use brp_extras_get_contacts::GetContactsParams;
+pub use brp_extras_get_contacts::GetContactsResult;
 pub use brp_extras_get_diagnostics::GetDiagnosticsParams;
 pub use brp_extras_get_diagnostics::GetDiagnosticsResult;
+pub use brp_extras_get_schedule_graph::GetScheduleGraphParams;
+pub use brp_extras_get_schedule_graph::GetScheduleGraphResult;
+pub use brp_extras_get_visible_text::GetVisibleTextParams;
+pub use brp_extras_get_visible_text::GetVisibleTextResult;
+pub use brp_extras_get_world_stats::GetWorldStatsParams;
+pub use brp_extras_get_world_stats::GetWorldStatsResult;
+pub use brp_extras_highlight_entity::HighlightEntityParams;
+pub use brp_extras_highlight_entity::HighlightEntityResult;
+pub use brp_extras_hover_mouse::HoverMouseParams;
+pub use brp_extras_hover_mouse::HoverMouseResult;
+pub use brp_extras_list_assets::ListAssetsParams;
+pub use brp_extras_list_assets::ListAssetsResult;
+pub use brp_extras_list_systems::ListSystemsParams;
+pub use brp_extras_list_systems::ListSystemsResult;
+pub use brp_extras_load_world_snapshot::LoadWorldSnapshotParams;
+pub use brp_extras_load_world_snapshot::LoadWorldSnapshotResult;
+pub use brp_extras_look_at::LookAtParams;
+pub use brp_extras_look_at::LookAtResult;
 pub use brp_extras_move_mouse::MoveMouseParams;
 pub use brp_extras_move_mouse::MoveMouseResult;
+pub use brp_extras_mutate_reflect::MutateReflectParams;
+pub use brp_extras_mutate_reflect::MutateReflectResult;
+pub use brp_extras_orbit_camera::OrbitCameraParams;
+pub use brp_extras_orbit_camera::OrbitCameraResult;
+pub use brp_extras_pick_entity::PickEntityParams;
+pub use brp_extras_pick_entity::PickEntityResult;
 pub use brp_extras_pinch_gesture::PinchGestureParams;
 pub use brp_extras_pinch_gesture::PinchGestureResult;
+pub use brp_extras_ping::PingParams;
+pub use brp_extras_ping::PingResult;
+pub use brp_extras_press_keys::PressKeysParams;
+pub use brp_extras_press_keys::PressKeysResult;
+pub use brp_extras_query_changed::QueryChangedParams;
+pub use brp_extras_query_changed::QueryChangedResult;
+pub use brp_extras_query_spatial::QuerySpatialParams;
+pub use brp_extras_query_spatial::QuerySpatialResult;
+pub use brp_extras_release_keys::ReleaseKeysParams;
+pub use brp_extras_release_keys::ReleaseKeysResult;
+pub use brp_extras_reload_asset::ReloadAssetParams;
+pub use brp_extras_reload_asset::ReloadAssetResult;
 pub use brp_extras_rotation_gesture::RotationGestureParams;
 pub use brp_extras_rotation_gesture::RotationGestureResult;
+pub use brp_extras_run_input_script::RunInputScriptParams;
+pub use brp_extras_run_input_script::RunInputScriptResult;
+pub use brp_extras_run_system_by_name::RunSystemByNameParams;
+pub use brp_extras_run_system_by_name::RunSystemByNameResult;
+pub use brp_extras_save_world_snapshot::SaveWorldSnapshotParams;
+pub use brp_extras_save_world_snapshot::SaveWorldSnapshotResult;
+pub use brp_extras_screen_to_world::ScreenToWorldParams;
+pub use brp_extras_screen_to_world::ScreenToWorldResult;
 pub use brp_extras_screenshot::BrpExtrasScreenshot;
 pub use brp_extras_screenshot::ScreenshotParams;
 pub use brp_extras_scroll_mouse::ScrollMouseParams;
 pub use brp_extras_scroll_mouse::ScrollMouseResult;
+pub use brp_extras_send_event::SendEventParams;
+pub use brp_extras_send_event::SendEventResult;
+pub use brp_extras_send_ime::SendImeParams;
+pub use brp_extras_send_ime::SendImeResult;
 pub use brp_extras_send_keys::SendKeysParams;
 pub use brp_extras_send_keys::SendKeysResult;
 pub use brp_extras_send_mouse_button::SendMouseButtonParams;
 pub use brp_extras_send_mouse_button::SendMouseButtonResult;
+pub use brp_extras_set_ambient_light::SetAmbientLightParams;
+pub use brp_extras_set_ambient_light::SetAmbientLightResult;
+pub use brp_extras_set_camera_transform::SetCameraTransformParams;
+pub use brp_extras_set_camera_transform::SetCameraTransformResult;
+pub use brp_extras_set_clear_color::SetClearColorParams;
+pub use brp_extras_set_clear_color::SetClearColorResult;
+pub use brp_extras_set_component_override::SetComponentOverrideParams;
+pub use brp_extras_set_component_override::SetComponentOverrideResult;
+pub use brp_extras_set_gravity::SetGravityParams;
+pub use brp_extras_set_gravity::SetGravityResult;
+pub use brp_extras_set_linear_velocity::SetLinearVelocityParams;
+pub use brp_extras_set_linear_velocity::SetLinearVelocityResult;
+pub use brp_extras_set_window::SetWindowParams;
+pub use brp_extras_set_window::SetWindowResult;
 pub use brp_extras_set_window_title::SetWindowTitleParams;
 pub use brp_extras_set_window_title::SetWindowTitleResult;
+pub use brp_extras_start_input_recording::StartInputRecordingParams;
+pub use brp_extras_start_input_recording::StartInputRecordingResult;
+pub use brp_extras_stop_input_recording::StopInputRecordingParams;
+pub use brp_extras_stop_input_recording::StopInputRecordingResult;
 pub use brp_extras_type_text::TypeTextParams;
 pub use brp_extras_type_text::TypeTextResult;
+pub use brp_extras_wait_for::WaitForParams;
+pub use brp_extras_wait_for::WaitForResult;
+pub use brp_extras_world_to_screen::WorldToScreenParams;
+pub use brp_extras_world_to_screen::WorldToScreenResult;
 pub use brp_list_agent_tools::BrpListAgentTools;
 pub use brp_list_agent_tools::ListAgentToolsParams;
+pub use brp_list_tags::BrpListTags;
+pub use brp_list_tags::ListTagsParams;
+pub use brp_tag_entity::BrpTagEntity;
+pub use brp_tag_entity::TagEntityParams;
+pub use brp_undo_last::BrpUndoLast;
+pub use brp_undo_last::UndoLastParams;
+pub use brp_wait_until::BrpWaitUntil;
+pub use brp_wait_until::WaitUntilParams;
 pub use registry_schema::RegistrySchemaParams;
 pub use registry_schema::RegistrySchemaResult;
 pub use rpc_discover::RpcDiscoverParams;
 pub use rpc_discover::RpcDiscoverResult;
+pub use world_component_stats::ComponentStatsParams;
+pub use world_component_stats::WorldComponentStats;
 pub use world_despawn_entity::DespawnEntityParams;
 pub use world_despawn_entity::DespawnEntityResult;
+pub use world_despawn_recursive::DespawnRecursiveParams;
+pub use world_despawn_recursive::DespawnRecursiveResult;
 pub use world_find_entities_by_name::FindEntitiesByNameParams;
 pub use world_find_entities_by_name::WorldFindEntitiesByName;
 pub use world_get_components::GetComponentsParams;
@@ -83,6 +241,10 @@ pub use world_insert_components::InsertComponentsParams;
 pub use world_insert_components::InsertComponentsResult;
 pub use world_insert_resources::InsertResourcesParams;
 pub use world_insert_resources::InsertResourcesResult;
+pub use world_inspect_entity::InspectEntityParams;
+pub use world_inspect_entity::WorldInspectEntity;
+pub use world_inspect_resources::InspectResourcesParams;
+pub use world_inspect_resources::WorldInspectResources;
 pub use world_list_components::ListComponentsParams;
 pub use world_list_components::ListComponentsResult;
 pub use world_list_resources::ListResourcesParams;
@@ -0,0 +1,136 @@
+//! Filter expressions for `world_query`
+//!
+//! A filter expression is a compact alternative to the `filter` object, e.g.
+//! `with(Transform) & !with(Camera)`. Terms are joined with `&`; each term is `with`, `without`,
+//! `changed` or `added` applied to one or more comma-separated type paths, optionally negated
+//! with `!`. `!with(T)` means `without(T)` and `!without(T)` means `with(T)`. Type paths may
+//! contain generics, so commas inside `<>`, `()` or `[]` do not split arguments.
+
+/// A parsed filter expression, grouped by filter kind
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct FilterExpression {
+    /// Entities must have all of these components
+    pub with:    Vec<String>,
+    /// Entities must not have any of these components
+    pub without: Vec<String>,
+    /// Entities whose component changed
+    pub changed: Vec<String>,
+    /// Entities whose component was added
+    pub added:   Vec<String>,
+}
+
+impl FilterExpression {
+    /// Parse an expression like `with(Transform) & !with(Camera) & changed(Velocity)`
+    pub(super) fn parse(expression: &str) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        for term in split_top_level(expression, '&') {
+            parsed.add_term(term.trim())?;
+        }
+        Ok(parsed)
+    }
+
+    fn add_term(&mut self, term: &str) -> Result<(), String> {
+        if term.is_empty() {
+            return Err("filter expression has an empty term; join terms with a single '&'".into());
+        }
+        let (negated, call) = term
+            .strip_prefix('!')
+            .map_or((false, term), |rest| (true, rest.trim_start()));
+
+        let (kind, arguments) = call
+            .split_once('(')
+            .ok_or_else(|| format!("'{term}' is not of the form kind(Type, ...)"))?;
+        let arguments = arguments
+            .strip_suffix(')')
+            .ok_or_else(|| format!("'{term}' is missing its closing ')'"))?;
+        let types = split_top_level(arguments, ',')
+            .into_iter()
+            .map(|type_path| type_path.trim().to_string())
+            .collect::<Vec<_>>();
+        if types.iter().any(String::is_empty) {
+            return Err(format!("'{term}' has an empty type path"));
+        }
+
+        let target = match (kind.trim(), negated) {
+            ("with", false) | ("without", true) => &mut self.with,
+            ("without", false) | ("with", true) => &mut self.without,
+            ("changed", false) => &mut self.changed,
+            ("added", false) => &mut self.added,
+            (kind @ ("changed" | "added"), true) => {
+                return Err(format!("'{term}': {kind}() cannot be negated"));
+            },
+            (kind, _) => {
+                return Err(format!(
+                    "'{term}': unknown filter '{kind}'; expected with, without, changed or added"
+                ));
+            },
+        };
+        target.extend(types);
+        Ok(())
+    }
+
+    /// Whether any term filters on change ticks
+    pub(super) const fn has_change_filters(&self) -> bool {
+        !self.changed.is_empty() || !self.added.is_empty()
+    }
+}
+
+/// Split on `separator` where it is not nested inside `()`, `<>` or `[]`
+fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (index, character) in input.char_indices() {
+        match character {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth = depth.saturating_sub(1),
+            _ if character == separator && depth == 0 => {
+                parts.push(&input[start..index]);
+                start = index + character.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use super::FilterExpression;
+
+    #[test]
+    fn terms_are_grouped_by_kind() {
+        let parsed = FilterExpression::parse(
+            "with(Transform) & !with(Camera) & changed(Velocity) & without(Foo<A, B>, Bar)",
+        )
+        .expect("expression parses");
+        assert_eq!(parsed.with, ["Transform"]);
+        assert_eq!(parsed.without, ["Camera", "Foo<A, B>", "Bar"]);
+        assert_eq!(parsed.changed, ["Velocity"]);
+        assert!(parsed.added.is_empty());
+        assert!(parsed.has_change_filters());
+    }
+
+    #[test]
+    fn malformed_terms_are_rejected() {
+        for expression in [
+            "",
+            "with(Transform) &",
+            "has(Transform)",
+            "with Transform",
+            "with(Transform",
+            "with(Transform, )",
+            "!changed(Velocity)",
+        ] {
+            assert!(
+                FilterExpression::parse(expression).is_err(),
+                "'{expression}' should not parse"
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Count the entities holding each component type.
+//!
+//! With `bevy_brp_extras`, one `brp_extras/get_world_stats` call returns every archetype with its
+//! component set and entity count, which covers all components, reflected or not. Without it,
+//! the tool falls back to `world.list_components` and one `world.query` per registered component,
+//! which only sees reflected components.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use error_stack::Report;
+use futures::future::try_join_all;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::ToolFn;
+
+/// Parameters for counting component usage.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct ComponentStatsParams {
+    /// Only return this many components, most used first (default: all).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:  Port,
+}
+
+/// How component usage was counted.
+#[derive(Clone, Copy, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatsSource {
+    /// Archetypes from `brp_extras/get_world_stats`; includes components without reflection.
+    ExtrasArchetypes,
+    /// One `world.query` per registered component; only reflected components are counted.
+    RegistryQueries,
+}
+
+/// One component type and how many entities hold it.
+#[derive(Clone, Debug, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct ComponentUsage {
+    /// Component type name.
+    pub component:    String,
+    /// Number of entities with the component.
+    pub entity_count: usize,
+}
+
+/// Result of counting component usage.
+#[derive(Serialize, ResultStruct)]
+pub struct ComponentStatsResult {
+    /// Components held by at least one entity, most used first, then by name.
+    #[to_result]
+    pub components:       Vec<ComponentUsage>,
+    /// Number of component types in use, before `limit`.
+    #[to_metadata]
+    pub component_count:  usize,
+    /// How the counts were gathered.
+    #[to_metadata]
+    pub source:           ComponentStatsSource,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Counted entities for {component_count} component types")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that counts entities per component type.
+pub struct WorldComponentStats;
+
+#[async_trait]
+impl ToolFn for WorldComponentStats {
+    type Output = ComponentStatsResult;
+    type Params = ComponentStatsParams;
+
+    async fn handle_impl(&self, params: ComponentStatsParams) -> Result<ComponentStatsResult> {
+        let (counts, source) = match count_from_archetypes(params.port).await? {
+            Some(counts) => (counts, ComponentStatsSource::ExtrasArchetypes),
+            None => (
+                count_from_queries(params.port).await?,
+                ComponentStatsSource::RegistryQueries,
+            ),
+        };
+
+        let mut components = rank_components(counts);
+        let component_count = components.len();
+        if let Some(limit) = params.limit {
+            components.truncate(limit);
+        }
+        Ok(ComponentStatsResult::new(
+            components,
+            component_count,
+            source,
+        ))
+    }
+}
+
+/// Archetype data as reported by `brp_extras/get_world_stats`
+#[derive(Deserialize)]
+struct WorldStats {
+    archetypes: Vec<ArchetypeStats>,
+}
+
+#[derive(Deserialize)]
+struct ArchetypeStats {
+    entity_count: usize,
+    components:   Vec<String>,
+}
+
+/// Entity counts summed over archetypes, or `None` when the app has no `bevy_brp_extras`
+async fn count_from_archetypes(port: Port) -> Result<Option<BTreeMap<String, usize>>> {
+    let response = BrpClient::new(
+        BrpMethod::BrpExtrasGetWorldStats,
+        port,
+        Some(json!({ "include_empty": false })),
+    )
+    .execute_raw()
+    .await?;
+    let ResponseStatus::Success(Some(value)) = response else {
+        return Ok(None);
+    };
+
+    let stats: WorldStats = serde_json::from_value(value).map_err(|error| {
+        stats_error(
+            BrpMethod::BrpExtrasGetWorldStats,
+            format!("Unable to decode response: {error}"),
+        )
+    })?;
+    let mut counts = BTreeMap::new();
+    for archetype in stats.archetypes {
+        for component in archetype.components {
+            *counts.entry(component).or_default() += archetype.entity_count;
+        }
+    }
+    Ok(Some(counts))
+}
+
+/// Entity counts from one `world.query` per registered component
+async fn count_from_queries(port: Port) -> Result<BTreeMap<String, usize>> {
+    let list = BrpClient::new(BrpMethod::WorldListComponents, port, None)
+        .execute_raw()
+        .await?;
+    let components: Vec<String> = decode_success(list, BrpMethod::WorldListComponents)?;
+
+    let counts = try_join_all(components.into_iter().map(|component| async move {
+        let rows = BrpClient::new(
+            BrpMethod::WorldQuery,
+            port,
+            Some(json!({ "data": {}, "filter": { "with": [component] } })),
+        )
+        .execute_raw()
+        .await?;
+        let rows: Vec<Value> = decode_success(rows, BrpMethod::WorldQuery)?;
+        Ok::<_, Report<Error>>((component, rows.len()))
+    }))
+    .await?;
+    Ok(counts.into_iter().collect())
+}
+
+/// Components in use, most used first, then by name
+fn rank_components(counts: BTreeMap<String, usize>) -> Vec<ComponentUsage> {
+    let mut components: Vec<ComponentUsage> = counts
+        .into_iter()
+        .filter(|(_, entity_count)| *entity_count > 0)
+        .map(|(component, entity_count)| ComponentUsage {
+            component,
+            entity_count,
+        })
+        .collect();
+    // Stable, so equal counts stay in name order
+    components.sort_by_key(|usage| Reverse(usage.entity_count));
+    components
+}
+
+fn decode_success<T: for<'de> Deserialize<'de>>(
+    response: ResponseStatus,
+    method: BrpMethod,
+) -> Result<T> {
+    match response {
+        ResponseStatus::Success(Some(value)) => serde_json::from_value(value)
+            .map_err(|error| stats_error(method, format!("Unable to decode response: {error}"))),
+        ResponseStatus::Success(None) => Err(stats_error(method, "returned no result")),
+        ResponseStatus::Error(error) => Err(stats_error(method, error.message)),
+    }
+}
+
+fn stats_error(method: BrpMethod, message: impl Into<String>) -> Report<Error> {
+    let message = message.into();
+    Error::tool_call_failed_with_details(
+        format!(
+            "{} failed while counting components: {message}",
+            method.as_str()
+        ),
+        json!({ "method": method.as_str(), "error": message }),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::rank_components;
+
+    #[test]
+    fn components_are_ranked_by_count_then_name() {
+        let counts = BTreeMap::from([
+            ("Visibility".to_string(), 4),
+            ("Transform".to_string(), 9),
+            ("Camera".to_string(), 0),
+            ("Name".to_string(), 4),
+        ]);
+        let ranked: Vec<(String, usize)> = rank_components(counts)
+            .into_iter()
+            .map(|usage| (usage.component, usage.entity_count))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                ("Transform".to_string(), 9),
+                ("Name".to_string(), 4),
+                ("Visibility".to_string(), 4),
+            ]
+        );
+    }
+}
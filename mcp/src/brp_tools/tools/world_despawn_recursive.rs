@@ -0,0 +1,39 @@
+//! `brp_extras/despawn_recursive` tool - Despawn an entity and all of its descendants
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::FrameTiming;
+use crate::brp_tools::Port;
+
+/// Parameters for the `brp_extras/despawn_recursive` tool
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct DespawnRecursiveParams {
+    /// The entity ID at the root of the hierarchy to despawn
+    pub entity: u64,
+
+    #[serde(flatten)]
+    pub timing: FrameTiming,
+
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port: Port,
+}
+
+/// Result for the `brp_extras/despawn_recursive` tool
+#[derive(Serialize, ResultStruct)]
+#[brp_result]
+pub struct DespawnRecursiveResult {
+    /// The raw BRP response: the despawned entities and their count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_result(skip_if_none)]
+    result: Option<Value>,
+
+    /// Message template for formatting responses
+    #[to_message(message_template = "Despawned entity {entity} and its descendants")]
+    message_template: String,
+}
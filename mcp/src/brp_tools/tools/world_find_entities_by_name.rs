@@ -8,6 +8,7 @@ use bevy::prelude::Name;
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
 use error_stack::Report;
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -34,14 +35,51 @@ pub enum NameMatchMode {
     Suffix,
     /// Match names that contain the requested text.
     Contains,
+    /// Match names in which the requested regular expression finds a match. Use `^` and `$` to
+    /// anchor it and `(?i)` to ignore case.
+    Regex,
+}
+
+/// A requested name compiled for its match mode.
+enum NameMatcher<'a> {
+    Exact(&'a str),
+    Prefix(&'a str),
+    Suffix(&'a str),
+    Contains(&'a str),
+    Regex(Regex),
+}
+
+impl<'a> NameMatcher<'a> {
+    fn new(requested_name: &'a str, match_mode: NameMatchMode) -> Result<Self> {
+        Ok(match match_mode {
+            NameMatchMode::Exact => Self::Exact(requested_name),
+            NameMatchMode::Prefix => Self::Prefix(requested_name),
+            NameMatchMode::Suffix => Self::Suffix(requested_name),
+            NameMatchMode::Contains => Self::Contains(requested_name),
+            NameMatchMode::Regex => Self::Regex(
+                Regex::new(requested_name).map_err(|error| Error::invalid("name regex", error))?,
+            ),
+        })
+    }
+
+    fn matches(&self, candidate: &str) -> bool {
+        match self {
+            Self::Exact(requested_name) => candidate == *requested_name,
+            Self::Prefix(requested_name) => candidate.starts_with(requested_name),
+            Self::Suffix(requested_name) => candidate.ends_with(requested_name),
+            Self::Contains(requested_name) => candidate.contains(requested_name),
+            Self::Regex(regex) => regex.is_match(candidate),
+        }
+    }
 }
 
 /// Parameters for local entity-name discovery through standard BRP.
 #[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
 pub struct FindEntitiesByNameParams {
-    /// Case-sensitive text to compare with reflected Bevy `Name` components.
+    /// Case-sensitive text, or a regular expression with `match_mode: regex`, to compare with
+    /// reflected Bevy `Name` components.
     pub name:       String,
-    /// Comparison mode. Defaults to `exact`; asterisks have no special meaning.
+    /// Comparison mode. Defaults to `exact`; asterisks have no special meaning outside `regex`.
     #[serde(default)]
     pub match_mode: NameMatchMode,
     /// The BRP port (default: 15702).
@@ -121,10 +159,11 @@ pub(super) async fn find_entities_by_name(
     match_mode: NameMatchMode,
     port: Port,
 ) -> Result<Vec<NamedEntity>> {
+    let matcher = NameMatcher::new(name, match_mode)?;
     let params = build_name_query_params()?;
     let client = BrpClient::new(BrpMethod::WorldQuery, port, Some(params));
     let response = client.execute_raw().await?;
-    parse_name_query_response(response, name, match_mode, port)
+    parse_name_query_response(response, &matcher, port)
 }
 
 fn build_name_query_params() -> Result<Value> {
@@ -148,14 +187,11 @@ fn build_name_query_params() -> Result<Value> {
 
 fn parse_name_query_response(
     response: ResponseStatus,
-    requested_name: &str,
-    match_mode: NameMatchMode,
+    matcher: &NameMatcher<'_>,
     port: Port,
 ) -> Result<Vec<NamedEntity>> {
     match response {
-        ResponseStatus::Success(Some(value)) => {
-            parse_name_query_rows(value, requested_name, match_mode, port)
-        },
+        ResponseStatus::Success(Some(value)) => parse_name_query_rows(value, matcher, port),
         ResponseStatus::Success(None) => Err(name_query_decode_error(
             port,
             "world.query returned no result",
@@ -171,8 +207,7 @@ fn parse_name_query_response(
 
 fn parse_name_query_rows(
     value: Value,
-    requested_name: &str,
-    match_mode: NameMatchMode,
+    matcher: &NameMatcher<'_>,
     port: Port,
 ) -> Result<Vec<NamedEntity>> {
     let rows = serde_json::from_value::<Vec<NameQueryRow>>(value)
@@ -202,20 +237,11 @@ fn parse_name_query_rows(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    entities.retain(|entity| name_matches(&entity.name, requested_name, match_mode));
+    entities.retain(|entity| matcher.matches(&entity.name));
     entities.sort_unstable_by_key(|entity| entity.entity);
     Ok(entities)
 }
 
-fn name_matches(candidate: &str, requested_name: &str, match_mode: NameMatchMode) -> bool {
-    match match_mode {
-        NameMatchMode::Exact => candidate == requested_name,
-        NameMatchMode::Prefix => candidate.starts_with(requested_name),
-        NameMatchMode::Suffix => candidate.ends_with(requested_name),
-        NameMatchMode::Contains => candidate.contains(requested_name),
-    }
-}
-
 fn name_query_decode_error(port: Port, error: impl ToString) -> Report<Error> {
     Error::tool_call_failed_with_details(
         format!("Unable to decode world.query name response from port {port}"),
@@ -258,9 +284,9 @@ mod tests {
 
     use super::FindEntitiesByNameParams;
     use super::NameMatchMode;
+    use super::NameMatcher;
     use super::NamedEntity;
     use super::build_name_query_params;
-    use super::name_matches;
     use super::name_query_brp_error;
     use super::parse_name_query_rows;
     use crate::brp_tools::Port;
@@ -281,8 +307,13 @@ mod tests {
     const TEST_OTHER_NAME: &str = "Other";
     const TEST_PORT: Port = Port(15_702);
     const TEST_PREFIX_NAME: &str = "Nates";
+    const TEST_REGEX_NAME: &str = "^Nates(List|Map)$";
     const TEST_SUFFIX_NAME: &str = "List";
 
+    fn name_matches(candidate: &str, requested_name: &str, match_mode: NameMatchMode) -> bool {
+        NameMatcher::new(requested_name, match_mode).is_ok_and(|matcher| matcher.matches(candidate))
+    }
+
     fn query_rows(rows: &[(u64, &str)]) -> Value {
         let component = type_name::<Name>();
         Value::Array(
@@ -328,6 +359,7 @@ mod tests {
             ("prefix", NameMatchMode::Prefix),
             ("suffix", NameMatchMode::Suffix),
             ("contains", NameMatchMode::Contains),
+            ("regex", NameMatchMode::Regex),
         ] {
             let params = serde_json::from_value::<FindEntitiesByNameParams>(json!({
                 "name": TEST_SUFFIX_NAME,
@@ -374,6 +406,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn regex_mode_matches_patterns_and_rejects_invalid_ones() {
+        assert!(name_matches(
+            TEST_NAME,
+            TEST_REGEX_NAME,
+            NameMatchMode::Regex
+        ));
+        assert!(!name_matches(
+            TEST_OTHER_NAME,
+            TEST_REGEX_NAME,
+            NameMatchMode::Regex
+        ));
+        assert!(name_matches(
+            TEST_NAME,
+            "(?i)^NATESLIST$",
+            NameMatchMode::Regex
+        ));
+
+        let result = NameMatcher::new("Nates(", NameMatchMode::Regex);
+        assert!(matches!(
+            result.map_err(|report| report.current_context().to_string()),
+            Err(message) if message.contains("name regex")
+        ));
+    }
+
     #[test]
     fn rows_are_filtered_and_sorted_by_entity_id()
     -> core::result::Result<(), Box<dyn std::error::Error>> {
@@ -383,8 +440,7 @@ mod tests {
                 (TEST_ENTITY_LOW, TEST_NAME),
                 (TEST_ENTITY_OTHER, TEST_OTHER_NAME),
             ]),
-            TEST_NAME,
-            NameMatchMode::Exact,
+            &NameMatcher::Exact(TEST_NAME),
             TEST_PORT,
         )?;
 
@@ -409,8 +465,7 @@ mod tests {
     {
         let entities = parse_name_query_rows(
             query_rows(&[(TEST_ENTITY_LOW, TEST_NAME)]),
-            TEST_MISSING_NAME,
-            NameMatchMode::Exact,
+            &NameMatcher::Exact(TEST_MISSING_NAME),
             TEST_PORT,
         )?;
 
@@ -423,8 +478,7 @@ mod tests {
         let component = type_name::<Name>();
         let result = parse_name_query_rows(
             json!([{"entity": TEST_ENTITY_LOW, "components": {(component): 12}}]),
-            TEST_NAME,
-            NameMatchMode::Exact,
+            &NameMatcher::Exact(TEST_NAME),
             TEST_PORT,
         );
 
@@ -9,6 +9,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::brp_tools::FormatCorrectionStatus;
 use crate::brp_tools::Port;
 
 /// Parameters for the `world.insert_components` tool
@@ -34,6 +35,16 @@ pub struct InsertComponentsResult {
     #[to_result(skip_if_none)]
     pub result: Option<Value>,
 
+    /// Learned format corrections applied to the request before it was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_metadata(skip_if_none)]
+    pub format_corrections: Option<Vec<Value>>,
+
+    /// Whether the request succeeded with learned format corrections applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[to_metadata(skip_if_none)]
+    pub format_corrected: Option<FormatCorrectionStatus>,
+
     /// Message template for formatting responses
     #[to_message(message_template = "Inserted components into entity {entity}")]
     pub message_template: String,
@@ -0,0 +1,222 @@
+//! Inspect every component of one entity in a single call.
+
+use std::collections::BTreeMap;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::debug;
+
+use super::brp_diff_entities::EntityComponents;
+use super::brp_diff_entities::fetch_entity_components;
+use crate::brp_tools::Port;
+use crate::brp_tools::brp_type_guide;
+use crate::brp_tools::brp_type_guide::Mutability;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+const CHILD_OF_COMPONENT: &str = "bevy_ecs::hierarchy::ChildOf";
+const CHILDREN_COMPONENT: &str = "bevy_ecs::hierarchy::Children";
+const NAME_COMPONENT: &str = "bevy_ecs::name::Name";
+
+/// Parameters for inspecting one entity through standard BRP.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct InspectEntityParams {
+    /// The entity to inspect.
+    pub entity: u64,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:   Port,
+}
+
+/// One component of the inspected entity.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct InspectedComponent {
+    /// The component's value, absent when it cannot be serialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value:             Option<Value>,
+    /// Why the value could not be read, from `world.get_components`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error:             Option<Value>,
+    /// Whether `world_mutate_components` can change it: `mutable`, `partially_mutable`, or
+    /// `not_mutable`. Absent when the type guide has no mutation paths for the type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutability:        Option<Mutability>,
+    /// Why the component is not fully mutable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutability_reason: Option<Value>,
+}
+
+/// Everything about one entity.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct EntityInspection {
+    /// The inspected entity.
+    pub entity:     u64,
+    /// Value of its `Name` component.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name:       Option<String>,
+    /// Entity its `ChildOf` points at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent:     Option<u64>,
+    /// Entities in its `Children`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children:   Vec<u64>,
+    /// Every component on the entity, keyed by type path.
+    pub components: BTreeMap<String, InspectedComponent>,
+}
+
+/// Result of inspecting an entity.
+#[derive(Serialize, ResultStruct)]
+pub struct InspectEntityResult {
+    /// The entity's components and relations.
+    #[to_result]
+    pub inspection:       EntityInspection,
+    /// Number of components on the entity.
+    #[to_metadata]
+    pub component_count:  usize,
+    /// Why mutability could not be determined, when the type guide failed.
+    #[to_metadata(skip_if_none)]
+    pub type_guide_error: Option<String>,
+    /// Message template for formatting responses.
+    #[to_message(message_template = "Entity has {component_count} components")]
+    pub message_template: String,
+}
+
+/// Local MCP handler that composes `world.list_components`, `world.get_components`, and the type
+/// guide.
+#[derive(ToolFn)]
+#[tool_fn(params = "InspectEntityParams", output = "InspectEntityResult")]
+pub struct WorldInspectEntity;
+
+async fn handle_impl(params: InspectEntityParams) -> Result<InspectEntityResult> {
+    let components = fetch_entity_components(params.entity, params.port).await?;
+    let type_names: Vec<String> = components
+        .components
+        .keys()
+        .chain(components.errors.keys())
+        .cloned()
+        .collect();
+
+    let mut inspection = inspect(params.entity, components);
+    let type_guide_error =
+        match brp_type_guide::generate_type_guide_response(params.port, &type_names).await {
+            Ok(response) => {
+                for (type_name, guide) in &response.type_guide {
+                    if let Some(component) = inspection.components.get_mut(type_name.as_str())
+                        && let Some((mutability, reason)) = guide.root_mutability()
+                    {
+                        component.mutability = Some(mutability);
+                        component.mutability_reason = reason;
+                    }
+                }
+                None
+            },
+            Err(report) => {
+                debug!("Type guide for entity {} failed: {report:?}", params.entity);
+                Some(report.current_context().to_string())
+            },
+        };
+
+    let component_count = inspection.components.len();
+    Ok(InspectEntityResult::new(
+        inspection,
+        component_count,
+        type_guide_error,
+    ))
+}
+
+/// Components, name, and hierarchy of an entity from its `world.get_components` response
+fn inspect(entity: u64, components: EntityComponents) -> EntityInspection {
+    let values = &components.components;
+    let name = values
+        .get(NAME_COMPONENT)
+        .and_then(Value::as_str)
+        .map(String::from);
+    let parent = values.get(CHILD_OF_COMPONENT).and_then(entity_id);
+    let children = values
+        .get(CHILDREN_COMPONENT)
+        .and_then(Value::as_array)
+        .map(|children| children.iter().filter_map(entity_id).collect())
+        .unwrap_or_default();
+
+    let readable = components.components.into_iter().map(|(type_name, value)| {
+        (
+            type_name,
+            InspectedComponent {
+                value:             Some(value),
+                error:             None,
+                mutability:        None,
+                mutability_reason: None,
+            },
+        )
+    });
+    let unreadable = components.errors.into_iter().map(|(type_name, error)| {
+        (
+            type_name,
+            InspectedComponent {
+                value:             None,
+                error:             Some(error),
+                mutability:        None,
+                mutability_reason: None,
+            },
+        )
+    });
+
+    EntityInspection {
+        entity,
+        name,
+        parent,
+        children,
+        components: readable.chain(unreadable).collect(),
+    }
+}
+
+/// An entity ID, serialized bare or as a one-field tuple struct
+fn entity_id(value: &Value) -> Option<u64> {
+    value
+        .as_u64()
+        .or_else(|| value.as_array()?.first()?.as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::CHILD_OF_COMPONENT;
+    use super::CHILDREN_COMPONENT;
+    use super::EntityComponents;
+    use super::NAME_COMPONENT;
+    use super::inspect;
+
+    #[test]
+    fn name_and_hierarchy_are_lifted_from_the_components() {
+        let components = EntityComponents {
+            components: BTreeMap::from([
+                (NAME_COMPONENT.to_string(), json!("Turret")),
+                (CHILD_OF_COMPONENT.to_string(), json!(4_294_967_301_u64)),
+                (CHILDREN_COMPONENT.to_string(), json!([12, 13])),
+            ]),
+            errors:     BTreeMap::from([(
+                "my_game::Handle".to_string(),
+                json!({ "message": "not serializable" }),
+            )]),
+        };
+
+        let inspection = inspect(7, components);
+
+        assert_eq!(inspection.name.as_deref(), Some("Turret"));
+        assert_eq!(inspection.parent, Some(4_294_967_301));
+        assert_eq!(inspection.children, [12, 13]);
+        assert_eq!(inspection.components.len(), 4);
+        assert!(inspection.components["my_game::Handle"].value.is_none());
+    }
+}
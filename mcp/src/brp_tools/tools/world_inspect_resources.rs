@@ -0,0 +1,203 @@
+//! Inspect every resource of an app in a single call.
+
+use std::collections::BTreeMap;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use futures::future::join_all;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::brp_tools::brp_type_guide;
+use crate::brp_tools::brp_type_guide::BrpTypeName;
+use crate::brp_tools::brp_type_guide::Mutability;
+use crate::brp_tools::brp_type_guide::TypeGuide;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+const SERIALIZE_REFLECT_TRAIT: &str = "Serialize";
+
+/// Parameters for inspecting every resource through standard BRP.
+#[derive(Clone, Deserialize, JsonSchema, ParamStruct, Serialize)]
+pub struct InspectResourcesParams {
+    /// Only inspect resources whose fully-qualified name starts with this prefix (e.g.
+    /// `my_game::`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paths_filter: Option<String>,
+    /// Return at most this many resources, with a `next_cursor` in `metadata.pagination` for the
+    /// rest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size:    Option<usize>,
+    /// `next_cursor` from a previous page. Returns the next page without querying the app again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor:       Option<String>,
+    /// The BRP port (default: 15702).
+    #[serde(default)]
+    pub port:         Port,
+}
+
+/// One resource of the app.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct InspectedResource {
+    /// The resource's value, absent when it is not serializable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value:             Option<Value>,
+    /// Why the value was not read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error:             Option<String>,
+    /// Whether `world_mutate_resources` can change it: `mutable`, `partially_mutable`, or
+    /// `not_mutable`. Absent when the type guide has no mutation paths for the type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutability:        Option<Mutability>,
+    /// The `NotMutableReason` of a resource that is not fully mutable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutability_reason: Option<Value>,
+}
+
+/// Result of inspecting the app's resources.
+#[derive(Serialize, ResultStruct)]
+pub struct InspectResourcesResult {
+    /// Every inspected resource, keyed by type path.
+    #[to_result]
+    pub resources:        BTreeMap<String, InspectedResource>,
+    /// Number of inspected resources.
+    #[to_metadata]
+    pub resource_count:   usize,
+    /// Number of resources whose value was read.
+    #[to_metadata]
+    pub value_count:      usize,
+    /// Message template for formatting responses.
+    #[to_message(
+        message_template = "Inspected {resource_count} resources, {value_count} with values"
+    )]
+    pub message_template: String,
+}
+
+/// Local MCP handler that composes `world.list_resources`, the type guide, and
+/// `world.get_resources`.
+#[derive(ToolFn)]
+#[tool_fn(params = "InspectResourcesParams", output = "InspectResourcesResult")]
+pub struct WorldInspectResources;
+
+async fn handle_impl(params: InspectResourcesParams) -> Result<InspectResourcesResult> {
+    let list = BrpClient::new(BrpMethod::WorldListResources, params.port, None)
+        .execute_raw()
+        .await?;
+    let resource_names: Vec<String> =
+        decode_success::<Vec<String>>(list, BrpMethod::WorldListResources)?
+            .into_iter()
+            .filter(|resource| {
+                params
+                    .paths_filter
+                    .as_deref()
+                    .is_none_or(|prefix| resource.starts_with(prefix))
+            })
+            .collect();
+
+    let guides = brp_type_guide::generate_type_guide_response(params.port, &resource_names).await?;
+    let mut resources: BTreeMap<String, InspectedResource> = BTreeMap::new();
+    let mut readable: Vec<String> = Vec::new();
+    for resource_name in resource_names {
+        let guide = guides
+            .type_guide
+            .get(&BrpTypeName::from(resource_name.as_str()));
+        let mut resource = InspectedResource::default();
+        if let Some((mutability, reason)) = guide.and_then(TypeGuide::root_mutability) {
+            resource.mutability = Some(mutability);
+            resource.mutability_reason = reason;
+        }
+        let serializable = guide
+            .and_then(|guide| guide.schema_info.as_ref())
+            .and_then(|schema_info| schema_info.reflect_traits.as_ref())
+            .is_some_and(|traits| traits.iter().any(|name| name == SERIALIZE_REFLECT_TRAIT));
+        if serializable {
+            readable.push(resource_name.clone());
+        } else {
+            resource.error = Some("Not serializable: the type does not reflect Serialize".into());
+        }
+        resources.insert(resource_name, resource);
+    }
+
+    let values = join_all(
+        readable
+            .into_iter()
+            .map(|resource_name: String| async move {
+                let value = fetch_resource_value(&resource_name, params.port).await;
+                (resource_name, value)
+            }),
+    )
+    .await;
+    for (resource_name, value) in values {
+        if let Some(resource) = resources.get_mut(&resource_name) {
+            match value {
+                Ok(value) => resource.value = Some(value),
+                Err(error) => resource.error = Some(error),
+            }
+        }
+    }
+
+    let resource_count = resources.len();
+    let value_count = resources
+        .values()
+        .filter(|resource| resource.value.is_some())
+        .count();
+    Ok(InspectResourcesResult::new(
+        resources,
+        resource_count,
+        value_count,
+    ))
+}
+
+/// Value of one resource, or the error message `world.get_resources` returned for it
+async fn fetch_resource_value(resource: &str, port: Port) -> core::result::Result<Value, String> {
+    let response = BrpClient::new(
+        BrpMethod::WorldGetResources,
+        port,
+        Some(json!({ "resource": resource })),
+    )
+    .execute_raw()
+    .await
+    .map_err(|report| report.current_context().to_string())?;
+    match response {
+        ResponseStatus::Success(Some(mut value)) => value
+            .get_mut("value")
+            .map(Value::take)
+            .ok_or_else(|| "world.get_resources returned no value".to_string()),
+        ResponseStatus::Success(None) => Err("world.get_resources returned no result".to_string()),
+        ResponseStatus::Error(error) => Err(error.message),
+    }
+}
+
+fn decode_success<T: for<'de> Deserialize<'de>>(
+    response: ResponseStatus,
+    method: BrpMethod,
+) -> Result<T> {
+    let message = match response {
+        ResponseStatus::Success(Some(value)) => match serde_json::from_value(value) {
+            Ok(decoded) => return Ok(decoded),
+            Err(error) => format!("Unable to decode response: {error}"),
+        },
+        ResponseStatus::Success(None) => "returned no result".to_string(),
+        ResponseStatus::Error(error) => error.message,
+    };
+    Err(Error::tool_call_failed_with_details(
+        format!(
+            "{} failed while inspecting resources: {message}",
+            method.as_str()
+        ),
+        json!({ "method": method.as_str(), "error": message }),
+    )
+    .into())
+}
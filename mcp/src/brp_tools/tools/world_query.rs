@@ -8,6 +8,7 @@ use serde::Serialize;
 use serde::de::Error;
 use serde_json::Value;
 
+use super::query_filter_expression::FilterExpression;
 use crate::brp_tools::Port;
 use crate::brp_tools::constants::COMPONENT_SELECTOR_ALL;
 
@@ -87,7 +88,7 @@ pub struct BrpQueryFilter {
 }
 
 /// Parameters for the `world.query` tool
-#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+#[derive(Clone, Serialize, JsonSchema, ParamStruct)]
 pub struct QueryParams {
     /// Object specifying what component data to retrieve. Required.
     /// Structure: {components: string[], option: "all" | string[], has: string[]}.
@@ -99,15 +100,84 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<BrpQueryFilter>,
 
+    /// Filter expression, merged into `filter`. Optional. Terms are joined with `&`, e.g.
+    /// `with(Transform) & !with(Camera)`. Supports `with(..)`, `without(..)` and `!` negation;
+    /// `changed(..)` and `added(..)` are rejected because `world.query` cannot filter on change
+    /// ticks. Not sent to the app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_expression: Option<String>,
+
     /// If true, returns error on unknown component types (default: false)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
 
+    /// Return at most this many entities, with a `next_cursor` in `metadata.pagination` for the
+    /// rest. Not sent to the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<usize>,
+
+    /// `next_cursor` from a previous page. Returns the next page of that query's results without
+    /// querying the app again. Not sent to the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+
     /// The BRP port (default: 15702)
     #[serde(default)]
     pub port: Port,
 }
 
+/// Wire shape of [`QueryParams`], before the filter expression is lowered
+#[derive(Deserialize)]
+struct QueryParamsInput {
+    data:              BrpQuery,
+    #[serde(default)]
+    filter:            Option<BrpQueryFilter>,
+    #[serde(default)]
+    filter_expression: Option<String>,
+    #[serde(default)]
+    strict:            Option<bool>,
+    #[serde(default)]
+    page_size:         Option<usize>,
+    #[serde(default)]
+    cursor:            Option<String>,
+    #[serde(default)]
+    port:              Port,
+}
+
+impl<'de> Deserialize<'de> for QueryParams {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let input = QueryParamsInput::deserialize(deserializer)?;
+        let mut filter = input.filter;
+
+        // Lower the expression into `filter` here, so it is never sent to the app
+        if let Some(expression) = input.filter_expression.as_deref() {
+            let parsed = FilterExpression::parse(expression).map_err(D::Error::custom)?;
+            if parsed.has_change_filters() {
+                return Err(Error::custom(
+                    "changed(..) and added(..) are not supported by world.query, which cannot \
+                     filter on change ticks; use brp_extras_query_changed instead",
+                ));
+            }
+            let filter = filter.get_or_insert_default();
+            filter.with.extend(parsed.with);
+            filter.without.extend(parsed.without);
+        }
+
+        Ok(Self {
+            data: input.data,
+            filter,
+            filter_expression: None,
+            strict: input.strict,
+            page_size: input.page_size,
+            cursor: input.cursor,
+            port: input.port,
+        })
+    }
+}
+
 /// Result for the `world.query` tool
 #[derive(Serialize, ResultStruct)]
 #[brp_result]
@@ -0,0 +1,214 @@
+//! `brp_transaction` applies several component mutations as one unit.
+//!
+//! Every component the mutations touch is read with `world.get_components` first. Mutations then
+//! run in order through `world.mutate_components`, and the first failure writes the captured
+//! values back over every component touched so far, leaving the world as it was.
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::snapshot::ComponentKey;
+use super::snapshot::ComponentSnapshot;
+use super::snapshot::ComponentValue;
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// One mutation in a transaction, with the same fields as `world_mutate_components`
+#[derive(Clone, Deserialize, Serialize, JsonSchema)]
+pub struct TransactionMutation {
+    /// The entity ID containing the component to mutate
+    pub entity:    u64,
+    /// The fully-qualified type name of the component to mutate
+    pub component: String,
+    /// The path to the field within the component (e.g., 'translation.x'); empty replaces the
+    /// whole component
+    #[serde(default)]
+    pub path:      String,
+    /// The new value for the mutation path
+    pub value:     Value,
+}
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct TransactionParams {
+    /// Mutations to apply in order; if any fails, every component already changed is restored
+    pub mutations: Vec<TransactionMutation>,
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port:      Port,
+}
+
+/// Result of a transaction whose mutations all succeeded
+#[derive(Serialize, ResultStruct)]
+pub struct TransactionResult {
+    /// Component values from before the transaction, in first-touch order
+    #[to_result]
+    previous_values:  Vec<ComponentValue>,
+    /// Number of mutations applied
+    #[to_metadata]
+    mutation_count:   usize,
+    /// Number of distinct components the mutations touched
+    #[to_metadata]
+    component_count:  usize,
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Applied {mutation_count} mutations to {component_count} components"
+    )]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "TransactionParams", output = "TransactionResult")]
+pub struct BrpTransaction;
+
+async fn handle_impl(params: TransactionParams) -> Result<TransactionResult> {
+    if params.mutations.is_empty() {
+        return Err(Error::invalid("mutations", "at least one mutation is required").into());
+    }
+
+    let keys = touched_components(&params.mutations);
+    let snapshot = ComponentSnapshot::capture(&keys, params.port).await?;
+
+    for (index, mutation) in params.mutations.iter().enumerate() {
+        if let Err(error) = apply_mutation(mutation, params.port).await {
+            // A transport failure leaves the failed mutation's outcome unknown, so its component
+            // is restored along with the ones already changed
+            let touched = touched_components(&params.mutations[..=index]);
+            let restore_failures = snapshot.restore(&touched, params.port).await;
+            let outcome = if restore_failures.is_empty() {
+                format!("rolled back {} components", touched.len())
+            } else {
+                format!(
+                    "rollback failed for {} of {} components",
+                    restore_failures.len(),
+                    touched.len()
+                )
+            };
+            return Err(Error::tool_call_failed_with_details(
+                format!(
+                    "Mutation {index} failed for {} on entity {}: {}; {outcome}",
+                    mutation.component,
+                    mutation.entity,
+                    error
+                        .get("message")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                ),
+                json!({
+                    "stage": "mutation",
+                    "failed_index": index,
+                    "entity": mutation.entity,
+                    "component": mutation.component,
+                    "path": mutation.path,
+                    "error": error,
+                    "rolled_back": restore_failures.is_empty(),
+                    "rollback_failures": restore_failures,
+                }),
+            )
+            .into());
+        }
+    }
+
+    let mutation_count = params.mutations.len();
+    let component_count = keys.len();
+    Ok(TransactionResult::new(
+        snapshot.into_values(),
+        mutation_count,
+        component_count,
+    ))
+}
+
+/// Distinct components the mutations touch, in first-touch order
+fn touched_components(mutations: &[TransactionMutation]) -> Vec<ComponentKey> {
+    let mut keys: Vec<ComponentKey> = Vec::new();
+    for mutation in mutations {
+        let key = ComponentKey {
+            entity:    mutation.entity,
+            component: mutation.component.clone(),
+        };
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+/// Apply one mutation, returning `message` and the BRP `code` and `data` on failure
+async fn apply_mutation(
+    mutation: &TransactionMutation,
+    port: Port,
+) -> std::result::Result<(), Value> {
+    let response = BrpClient::new(
+        BrpMethod::WorldMutateComponents,
+        port,
+        Some(json!({
+            "entity": mutation.entity,
+            "component": mutation.component,
+            "path": mutation.path,
+            "value": mutation.value,
+        })),
+    )
+    .execute_raw()
+    .await;
+
+    match response {
+        Ok(ResponseStatus::Success(_)) => Ok(()),
+        Ok(ResponseStatus::Error(error)) => Err(json!({
+            "message": error.get_message(),
+            "code": error.code,
+            "data": error.data,
+        })),
+        Err(report) => Err(json!({ "message": report.current_context().to_string() })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::TransactionMutation;
+    use super::touched_components;
+
+    const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+    const NAME: &str = "bevy_ecs::name::Name";
+
+    fn mutation(entity: u64, component: &str, path: &str) -> TransactionMutation {
+        TransactionMutation {
+            entity,
+            component: component.to_string(),
+            path: path.to_string(),
+            value: json!(1.0),
+        }
+    }
+
+    #[test]
+    fn touched_components_are_distinct_in_first_touch_order() {
+        let mutations = [
+            mutation(2, TRANSFORM, ".translation.x"),
+            mutation(1, NAME, ""),
+            mutation(2, TRANSFORM, ".translation.y"),
+            mutation(1, TRANSFORM, ".scale.x"),
+        ];
+
+        let keys = touched_components(&mutations);
+        let keys: Vec<(u64, &str)> = keys
+            .iter()
+            .map(|key| (key.entity, key.component.as_str()))
+            .collect();
+
+        assert_eq!(keys, vec![(2, TRANSFORM), (1, NAME), (1, TRANSFORM)]);
+    }
+}
@@ -0,0 +1,7 @@
+//! Transactional component mutation: snapshot, apply, and restore on failure
+
+mod brp_transaction;
+mod snapshot;
+
+pub use brp_transaction::BrpTransaction;
+pub use brp_transaction::TransactionParams;
@@ -0,0 +1,182 @@
+//! Component values captured before a transaction so they can be written back on rollback
+
+use std::collections::BTreeMap;
+
+use futures::future::try_join_all;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::BrpMethod;
+
+/// One component on one entity
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ComponentKey {
+    pub(super) entity:    u64,
+    pub(super) component: String,
+}
+
+/// A component's value before the transaction started
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+pub struct ComponentValue {
+    pub entity:    u64,
+    pub component: String,
+    pub value:     Value,
+}
+
+/// A component that could not be written back during rollback
+#[derive(Clone, Debug, Serialize)]
+pub(super) struct RestoreFailure {
+    pub(super) entity:    u64,
+    pub(super) component: String,
+    pub(super) error:     String,
+}
+
+/// Response of non-strict `world.get_components`
+#[derive(Default, Deserialize)]
+struct EntityComponents {
+    #[serde(default)]
+    components: BTreeMap<String, Value>,
+    #[serde(default)]
+    errors:     BTreeMap<String, Value>,
+}
+
+/// Values of every component a transaction touches, in first-touch order
+pub(super) struct ComponentSnapshot {
+    values: Vec<ComponentValue>,
+}
+
+impl ComponentSnapshot {
+    /// Read every key with one `world.get_components` per entity, failing if any value is
+    /// unreadable so nothing is mutated that could not be restored
+    pub(super) async fn capture(keys: &[ComponentKey], port: Port) -> Result<Self> {
+        let mut by_entity: BTreeMap<u64, Vec<&str>> = BTreeMap::new();
+        for key in keys {
+            by_entity
+                .entry(key.entity)
+                .or_default()
+                .push(key.component.as_str());
+        }
+
+        let fetched: BTreeMap<u64, EntityComponents> = try_join_all(
+            by_entity
+                .into_iter()
+                .map(|(entity, components)| fetch_components(entity, components, port)),
+        )
+        .await?
+        .into_iter()
+        .collect();
+
+        let mut values = Vec::with_capacity(keys.len());
+        for key in keys {
+            let entity = fetched.get(&key.entity);
+            let Some(value) = entity.and_then(|fetched| fetched.components.get(&key.component))
+            else {
+                let error = entity
+                    .and_then(|fetched| fetched.errors.get(&key.component))
+                    .cloned()
+                    .unwrap_or(Value::Null);
+                return Err(Error::tool_call_failed_with_details(
+                    format!(
+                        "Cannot snapshot {} on entity {}; no mutations were applied",
+                        key.component, key.entity
+                    ),
+                    json!({
+                        "stage": "snapshot",
+                        "entity": key.entity,
+                        "component": key.component,
+                        "error": error,
+                    }),
+                )
+                .into());
+            };
+            values.push(ComponentValue {
+                entity:    key.entity,
+                component: key.component.clone(),
+                value:     value.clone(),
+            });
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Write back the captured value of each touched component, most recently touched first
+    pub(super) async fn restore(
+        &self,
+        touched: &[ComponentKey],
+        port: Port,
+    ) -> Vec<RestoreFailure> {
+        let mut failures = Vec::new();
+        for snapshot in self.values.iter().rev().filter(|snapshot| {
+            touched
+                .iter()
+                .any(|key| key.entity == snapshot.entity && key.component == snapshot.component)
+        }) {
+            // An empty path replaces the whole component in place without re-inserting it
+            let response = BrpClient::new(
+                BrpMethod::WorldMutateComponents,
+                port,
+                Some(json!({
+                    "entity": snapshot.entity,
+                    "component": snapshot.component,
+                    "path": "",
+                    "value": snapshot.value,
+                })),
+            )
+            .execute_raw()
+            .await;
+            let error = match response {
+                Ok(ResponseStatus::Success(_)) => continue,
+                Ok(ResponseStatus::Error(error)) => error.get_message().to_string(),
+                Err(report) => report.current_context().to_string(),
+            };
+            failures.push(RestoreFailure {
+                entity: snapshot.entity,
+                component: snapshot.component.clone(),
+                error,
+            });
+        }
+        failures
+    }
+
+    pub(super) fn into_values(self) -> Vec<ComponentValue> { self.values }
+}
+
+async fn fetch_components(
+    entity: u64,
+    components: Vec<&str>,
+    port: Port,
+) -> Result<(u64, EntityComponents)> {
+    let response = BrpClient::new(
+        BrpMethod::WorldGetComponents,
+        port,
+        Some(json!({ "entity": entity, "components": components, "strict": false })),
+    )
+    .execute_raw()
+    .await?;
+
+    let message = match response {
+        ResponseStatus::Success(Some(value)) => match serde_json::from_value(value) {
+            Ok(fetched) => return Ok((entity, fetched)),
+            Err(error) => format!("Unable to decode response: {error}"),
+        },
+        ResponseStatus::Success(None) => "returned no result".to_string(),
+        ResponseStatus::Error(error) => error.get_message().to_string(),
+    };
+    Err(Error::tool_call_failed_with_details(
+        format!("Cannot snapshot entity {entity}: {message}; no mutations were applied"),
+        json!({
+            "stage": "snapshot",
+            "entity": entity,
+            "error": message,
+        }),
+    )
+    .into())
+}
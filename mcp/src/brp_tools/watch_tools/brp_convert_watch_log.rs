@@ -0,0 +1,93 @@
+//! Convert a legacy watch log to the versioned JSONL schema
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::constants::CONVERTED_LOG_EXTENSION;
+use super::constants::WATCH_LOG_SCHEMA_VERSION;
+use super::log_schema;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct ConvertWatchLogParams {
+    /// Path to a watch log written in the legacy `[timestamp] EVENT: {json}` format
+    pub path:        String,
+    /// Where to write the converted log. Defaults to the input path with a `.jsonl` extension
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+}
+
+/// Result from converting a legacy watch log
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct ConvertWatchLogResult {
+    /// Path of the converted JSONL log
+    #[to_result]
+    output_path:    String,
+    /// Number of records written
+    #[to_metadata]
+    records:        usize,
+    /// Number of lines that could not be parsed and were skipped
+    #[to_metadata]
+    skipped_lines:  usize,
+    /// Schema version of the written records
+    #[to_metadata]
+    schema_version: u32,
+
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Converted watch log to {output_path} ({records} records, {skipped_lines} lines skipped)"
+    )]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "ConvertWatchLogParams", output = "ConvertWatchLogResult")]
+pub struct BrpConvertWatchLog;
+
+async fn handle_impl(params: ConvertWatchLogParams) -> Result<ConvertWatchLogResult> {
+    let input_path = PathBuf::from(&params.path);
+    let output_path = params.output_path.map_or_else(
+        || input_path.with_extension(CONVERTED_LOG_EXTENSION),
+        PathBuf::from,
+    );
+    if output_path == input_path {
+        return Err(Error::invalid("output_path", "must differ from the legacy log path").into());
+    }
+
+    let contents = tokio::fs::read_to_string(&input_path)
+        .await
+        .map_err(|e| Error::io_failed("read watch log", &input_path, &e))?;
+
+    let conversion = log_schema::convert_legacy_log(&input_path, &contents);
+
+    let mut output = String::with_capacity(contents.len());
+    for record in &conversion.records {
+        let json = serde_json::to_string(record).map_err(|e| {
+            Error::FileOperation(format!("Failed to serialize watch log record: {e}"))
+        })?;
+        let _ = writeln!(output, "{json}");
+    }
+
+    tokio::fs::write(&output_path, output)
+        .await
+        .map_err(|e| Error::io_failed("write converted watch log", &output_path, &e))?;
+
+    Ok(ConvertWatchLogResult::new(
+        output_path.display().to_string(),
+        conversion.records.len(),
+        conversion.skipped_lines,
+        WATCH_LOG_SCHEMA_VERSION,
+    ))
+}
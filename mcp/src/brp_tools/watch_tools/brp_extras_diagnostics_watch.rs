@@ -0,0 +1,64 @@
+//! Start sampling an app's diagnostics into a watch log
+
+use std::time::Duration;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::task;
+use super::watch_start_result::WatchStartResult;
+use super::wrap_watch_error;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct DiagnosticsWatchParams {
+    /// Frames between samples (default: 60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_n_frames: Option<u32>,
+    /// Diagnostic paths to sample, e.g. `["fps", "frame_time", "process/cpu_usage"]` (default:
+    /// `fps` and `frame_time`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths:          Vec<String>,
+    /// Seconds until the watch stops on its own (default: runs until stopped, or until its log
+    /// goes unread for 30 minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds:    Option<u64>,
+    /// Notify the MCP client with a resource update each time records are written to the watch log
+    /// (default: false)
+    #[serde(default)]
+    pub notify:         bool,
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port:           Port,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "DiagnosticsWatchParams", output = "WatchStartResult")]
+pub struct BrpExtrasDiagnosticsWatch;
+
+async fn handle_impl(params: DiagnosticsWatchParams) -> Result<WatchStartResult> {
+    let result = task::start_diagnostics_watch_task(
+        params.every_n_frames,
+        params.paths,
+        params.ttl_seconds.map(Duration::from_secs),
+        params.notify,
+        params.port,
+    )
+    .await
+    .map_err(|e| wrap_watch_error::wrap_watch_error("Failed to start diagnostics watch", None, e));
+
+    result
+        .map(|(watch_id, log_path)| {
+            WatchStartResult::new(watch_id, log_path.to_string_lossy().to_string())
+        })
+        .map_err(|error| Error::tool_call_failed(error.to_string()).into())
+}
@@ -1,10 +1,14 @@
 //! List all active watches
 
+use std::time::Instant;
+
 use bevy_brp_mcp_macros::ResultStruct;
 use bevy_brp_mcp_macros::ToolFn;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::constants::MAX_ACTIVE_WATCHES;
+use super::constants::WATCH_IDLE_TIMEOUT;
 use super::manager::WATCH_MANAGER;
 use crate::brp_tools::Port;
 use crate::error::Result;
@@ -19,16 +23,27 @@ use crate::tool::ToolResult;
 struct WatchInfo {
     /// Watch ID
     #[serde(rename = "watch_id")]
-    id:        u32,
-    /// `Entity` ID being watched
-    entity_id: u64,
-    /// Type of watch (get/list)
+    id:                 u32,
+    /// `Entity` ID being watched; absent for log watches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entity_id:          Option<u64>,
+    /// Type of watch (get/list/log)
     #[serde(rename = "watch_type")]
-    kind:      String,
+    kind:               String,
     /// Log file path
-    log_path:  String,
+    log_path:           String,
     /// BRP port
-    port:      Port,
+    port:               Port,
+    /// Seconds the watch may run before it stops on its own; absent if it runs until stopped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ttl_seconds:        Option<u64>,
+    /// Seconds left before the watch's `ttl_seconds` runs out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_in_seconds: Option<u64>,
+    /// Seconds since the watch log was last read, or since the watch started
+    idle_seconds:       u64,
+    /// Whether the MCP client is notified with a resource update as records are written
+    notify:             bool,
 }
 
 /// Result from listing active watches
@@ -42,8 +57,16 @@ pub struct ListActiveWatchesResult {
     #[to_metadata]
     watch_count: usize,
 
+    /// Maximum number of watches that may be active at once
+    #[to_metadata]
+    max_watches: usize,
+
+    /// Seconds a watch log may go unread before the watch is stopped
+    #[to_metadata]
+    idle_timeout_seconds: u64,
+
     /// Message template for formatting responses
-    #[to_message(message_template = "Found {watch_count} active watches")]
+    #[to_message(message_template = "Found {watch_count} of {max_watches} active watches")]
     message_template: String,
 }
 
@@ -59,17 +82,27 @@ async fn handle_impl(_: NoParams) -> Result<ListActiveWatchesResult> {
     };
 
     // Convert to our typed format
+    let now = Instant::now();
     let watches: Vec<WatchInfo> = active_watches
         .iter()
         .map(|watch| WatchInfo {
-            id:        watch.id,
-            entity_id: watch.entity_id,
-            kind:      watch.kind.clone(),
-            log_path:  watch.log_path.to_string_lossy().to_string(),
-            port:      watch.port,
+            id:                 watch.id,
+            entity_id:          watch.entity_id,
+            kind:               watch.kind.clone(),
+            log_path:           watch.log_path.to_string_lossy().to_string(),
+            port:               watch.port,
+            ttl_seconds:        watch.ttl.map(|ttl| ttl.as_secs()),
+            expires_in_seconds: watch.expires_in(now).map(|left| left.as_secs()),
+            idle_seconds:       now.duration_since(watch.last_read).as_secs(),
+            notify:             watch.notify,
         })
         .collect();
 
     let watch_count = watches.len();
-    Ok(ListActiveWatchesResult::new(watches, watch_count))
+    Ok(ListActiveWatchesResult::new(
+        watches,
+        watch_count,
+        MAX_ACTIVE_WATCHES,
+        WATCH_IDLE_TIMEOUT.as_secs(),
+    ))
 }
@@ -8,6 +8,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::manager::WATCH_MANAGER;
+use super::summary::WatchSummary;
 use crate::error::Error;
 use crate::error::Result;
 use crate::tool::HandlerContext;
@@ -17,13 +18,18 @@ use crate::tool::ToolResult;
 
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct StopWatchParams {
-    /// The watch ID returned from `bevy_start_entity_watch` or `bevy_start_list_watch`
+    /// The watch ID returned from `bevy_start_entity_watch`, `bevy_start_list_watch`, or
+    /// `brp_watch_log`
     pub watch_id: u32,
 }
 
 /// Result from stopping a watch operation
 #[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
 pub struct StopWatchResult {
+    /// Why the watch ended, how long it ran, and its last update
+    #[to_result]
+    summary: WatchSummary,
+
     /// Watch ID that was stopped
     #[to_metadata]
     watch_id: u32,
@@ -44,11 +50,17 @@ async fn handle_impl(params: StopWatchParams) -> Result<StopWatchResult> {
         manager.stop_watch(params.watch_id)
     };
 
-    // Convert result to our typed response
-    result
-        .map(|()| StopWatchResult::new(params.watch_id))
-        .map_err(|error| {
-            Error::tool_call_failed(format!("Failed to stop watch {}: {error}", params.watch_id))
-                .into()
-        })
+    let handle = result.map_err(|error| {
+        Error::tool_call_failed(format!("Failed to stop watch {}: {error}", params.watch_id))
+    })?;
+
+    // The task writes its summary to the log before resolving
+    let summary = handle.await.map_err(|error| {
+        Error::tool_call_failed(format!(
+            "Watch {} did not finish cleanly: {error}",
+            params.watch_id
+        ))
+    })?;
+
+    Ok(StopWatchResult::new(summary, params.watch_id))
 }
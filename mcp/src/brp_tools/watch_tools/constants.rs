@@ -24,6 +24,7 @@ pub(super) const ELAPSED_SECONDS_FIELD: &str = "elapsed_seconds";
 pub(super) const EMPTY_LINES_FIELD: &str = "empty_lines";
 pub(super) const ENTITY_FIELD: &str = "entity";
 pub(super) const ERROR_FIELD: &str = "error";
+pub(super) const FILENAME_FIELD: &str = "filename";
 pub(super) const FINAL_BUFFER_SIZE_FIELD: &str = "final_buffer_size";
 pub(super) const FULL_DATA_FIELD: &str = "full_data";
 pub(super) const HAD_INCOMPLETE_LINE_FIELD: &str = "had_incomplete_line";
@@ -33,9 +34,12 @@ pub(super) const HAS_RESULT_FIELD: &str = "has_result";
 pub(super) const HEADERS_COUNT_FIELD: &str = "headers_count";
 pub(super) const IS_SSE_DATA_FIELD: &str = "is_sse_data";
 pub(super) const JSON_KEYS_FIELD: &str = "json_keys";
+pub(super) const KEYWORD_FIELD: &str = "keyword";
 pub(super) const LINE_BUFFER_SIZE_BEFORE_FIELD: &str = "line_buffer_size_before";
 pub(super) const LINE_FIELD: &str = "line";
 pub(super) const LINE_LENGTH_FIELD: &str = "line_length";
+/// Number of the line among those appended since the log watch started
+pub(super) const LINE_NUMBER_FIELD: &str = "line_number";
 pub(super) const LINES_PROCESSED_FIELD: &str = "lines_processed";
 pub(super) const PREVIEW_FIELD: &str = "preview";
 pub(super) const RAW_DATA_FIELD: &str = "raw_data";
@@ -50,6 +54,36 @@ pub(super) const TOTAL_CHUNKS_RECEIVED_FIELD: &str = "total_chunks_received";
 pub(super) const UNKNOWN_STATUS_TEXT: &str = "Unknown";
 pub(super) const WATCH_TYPE_FIELD: &str = "watch_type";
 
+// log schema constants
+/// Version written to every watch log record
+pub(super) const WATCH_LOG_SCHEMA_VERSION: u32 = 1;
+/// Extension used for converted legacy watch logs
+pub(super) const CONVERTED_LOG_EXTENSION: &str = "jsonl";
+/// Timestamp format of legacy `[timestamp] EVENT: {json}` lines
+pub(super) const LEGACY_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+/// Filename prefix shared by all watch logs
+pub(super) const WATCH_LOG_FILENAME_PREFIX: &str = "bevy_brp_mcp_watch_";
+
+// resource constants
+/// MIME type of watch logs read as resources
+pub(super) const WATCH_LOG_MIME_TYPE: &str = "application/jsonl";
+/// URI prefix of watch logs published as resources, followed by the watch id
+pub(super) const WATCH_RESOURCE_URI_PREFIX: &str = "brp-mcp://watches/";
+
+// diagnostics watch constants
+/// Watch type of diagnostics watches, shown by `brp_list_active_watches` and used in log filenames
+pub(super) const DIAGNOSTICS_WATCH_TYPE: &str = "diagnostics";
+
+// event watch constants
+/// Watch type of event watches, shown by `brp_list_active_watches` and used in log filenames
+pub(super) const EVENTS_WATCH_TYPE: &str = "events";
+
+// log watch constants
+/// Entity recorded for log, event, and diagnostics watches, which do not watch an entity
+pub(super) const NO_WATCHED_ENTITY: u64 = 0;
+/// Watch type of log watches, shown by `brp_list_active_watches` and used in log filenames
+pub(super) const LOG_WATCH_TYPE: &str = "log";
+
 // preview constants
 /// Maximum bytes to include in debug preview of watch stream data
 pub(super) const MAX_PREVIEW_BYTES: usize = 500;
@@ -60,6 +94,13 @@ pub(super) const JSON_RPC_ERROR_FIELD: &str = "error";
 pub(super) const JSON_RPC_ID_FIELD: &str = "id";
 pub(super) const JSON_RPC_RESULT_FIELD: &str = "result";
 
+// watch update fields
+pub(super) const UPDATE_ADDED_FIELD: &str = "added";
+pub(super) const UPDATE_COMPONENTS_FIELD: &str = "components";
+pub(super) const UPDATE_ERRORS_FIELD: &str = "errors";
+pub(super) const UPDATE_EVENTS_FIELD: &str = "events";
+pub(super) const UPDATE_REMOVED_FIELD: &str = "removed";
+
 // sse stream constants
 /// Canonical Server-Sent Events `data:` line prefix (including trailing space).
 pub(super) const SSE_DATA_PREFIX: &str = "data: ";
@@ -67,6 +108,18 @@ pub(super) const SSE_DATA_PREFIX: &str = "data: ";
 // timing constants
 /// Interval between automatic log buffer flushes
 pub(super) const WATCH_LOG_FLUSH_INTERVAL: Duration = std::time::Duration::from_millis(100);
+/// Interval between reads of a tailed app log
+pub(super) const LOG_WATCH_POLL_INTERVAL: Duration = std::time::Duration::from_millis(250);
+/// Minimum interval between checks whether the app of a tailed log is still listening
+pub(super) const LOG_WATCH_APP_CHECK_INTERVAL: Duration = std::time::Duration::from_secs(2);
+/// Interval between checks for expired and idle watches
+pub(super) const WATCH_REAPER_INTERVAL: Duration = std::time::Duration::from_secs(10);
+/// Time after which a watch whose log has not been read is stopped
+pub(super) const WATCH_IDLE_TIMEOUT: Duration = std::time::Duration::from_mins(30);
+
+// watch limit constants
+/// Maximum number of watches running at once
+pub(super) const MAX_ACTIVE_WATCHES: usize = 32;
 
 // watch event tags
 pub(super) const COMPONENT_UPDATE_EVENT: &str = "COMPONENT_UPDATE";
@@ -83,5 +136,8 @@ pub(super) const DEBUG_NO_RESULT_EVENT: &str = "DEBUG_NO_RESULT";
 pub(super) const DEBUG_STREAM_ENDED_EVENT: &str = "DEBUG_STREAM_ENDED";
 pub(super) const DEBUG_STREAM_ERROR_EVENT: &str = "DEBUG_STREAM_ERROR";
 pub(super) const DEBUG_STREAM_STARTED_EVENT: &str = "DEBUG_STREAM_STARTED";
+pub(super) const DIAGNOSTICS_SAMPLE_EVENT: &str = "DIAGNOSTICS_SAMPLE";
+pub(super) const LOG_LINE_EVENT: &str = "LOG_LINE";
+pub(super) const MESSAGE_EVENT: &str = "MESSAGE";
 pub(super) const WATCH_ENDED_EVENT: &str = "WATCH_ENDED";
 pub(super) const WATCH_STARTED_EVENT: &str = "WATCH_STARTED";
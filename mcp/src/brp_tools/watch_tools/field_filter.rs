@@ -0,0 +1,257 @@
+//! Field filters for component watches
+//!
+//! A component watch with `paths` only logs a component when one of those fields changed since
+//! the value last logged for it, by more than `epsilon` for numbers. Removals and errors are
+//! always logged.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::constants::UPDATE_COMPONENTS_FIELD;
+use super::constants::UPDATE_ERRORS_FIELD;
+use super::constants::UPDATE_REMOVED_FIELD;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Vector field names, which index the arrays vectors serialize as
+const VECTOR_FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+
+/// One step of a field path
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    /// `.name`: a struct field, a tuple index such as `.0`, or a vector component such as `.x`
+    Field(String),
+    /// `[n]`: a list or array element
+    Index(usize),
+}
+
+/// A parsed field path such as `.translation.x` or `.points[2]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FieldPath(Vec<Segment>);
+
+impl FieldPath {
+    fn parse(path: &str) -> Result<Self> {
+        let invalid = |details: &str| Error::invalid("paths", format!("'{path}' {details}"));
+        let mut segments = Vec::new();
+        let mut rest = path;
+        while let Some(first) = rest.chars().next() {
+            match first {
+                '.' => {
+                    let end = rest[1..].find(['.', '[']).map_or(rest.len(), |end| end + 1);
+                    let name = &rest[1..end];
+                    if name.is_empty() {
+                        return Err(invalid("has an empty field name").into());
+                    }
+                    segments.push(Segment::Field(name.to_string()));
+                    rest = &rest[end..];
+                },
+                '[' => {
+                    let end = rest
+                        .find(']')
+                        .ok_or_else(|| invalid("is missing a closing ']'"))?;
+                    let index = rest[1..end]
+                        .parse()
+                        .map_err(|_| invalid("has a non-numeric index"))?;
+                    segments.push(Segment::Index(index));
+                    rest = &rest[end + 1..];
+                },
+                _ => return Err(invalid("must start with '.' or '['").into()),
+            }
+        }
+        if segments.is_empty() {
+            return Err(invalid("is empty").into());
+        }
+        Ok(Self(segments))
+    }
+
+    /// The value at this path, if the component has it
+    fn resolve<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.0
+            .iter()
+            .try_fold(value, |value, segment| match (segment, value) {
+                (Segment::Field(name), Value::Object(fields)) => fields.get(name),
+                (Segment::Field(name), Value::Array(items)) => {
+                    let index = name
+                        .parse()
+                        .ok()
+                        .or_else(|| VECTOR_FIELDS.iter().position(|field| field == name))?;
+                    items.get(index)
+                },
+                (Segment::Index(index), Value::Array(items)) => items.get(*index),
+                _ => None,
+            })
+    }
+}
+
+/// Drops component values whose watched fields have not changed
+#[derive(Debug)]
+pub(super) struct FieldFilter {
+    paths:       Vec<FieldPath>,
+    epsilon:     f64,
+    /// Watched field values of each component as last logged
+    last_logged: HashMap<String, Vec<Option<Value>>>,
+}
+
+impl FieldFilter {
+    /// A filter for `paths`, or `None` when no paths are given
+    pub(super) fn from_params(paths: &[String], epsilon: Option<f64>) -> Result<Option<Self>> {
+        if paths.is_empty() {
+            return Ok(None);
+        }
+        let epsilon = epsilon.unwrap_or_default();
+        if !epsilon.is_finite() || epsilon < 0.0 {
+            return Err(Error::invalid("epsilon", "must be a non-negative number").into());
+        }
+        let paths = paths
+            .iter()
+            .map(|path| FieldPath::parse(path))
+            .collect::<Result<_>>()?;
+        Ok(Some(Self {
+            paths,
+            epsilon,
+            last_logged: HashMap::new(),
+        }))
+    }
+
+    /// The update without unchanged components, or `None` if nothing in it is left to log
+    pub(super) fn filter_update(&mut self, mut update: Value) -> Option<Value> {
+        if let Some(components) = update
+            .get_mut(UPDATE_COMPONENTS_FIELD)
+            .and_then(Value::as_object_mut)
+        {
+            components.retain(|component, value| self.changed(component, value));
+        }
+        let has_entries = |field: &str| {
+            update.get(field).is_some_and(|value| match value {
+                Value::Array(items) => !items.is_empty(),
+                Value::Object(entries) => !entries.is_empty(),
+                _ => false,
+            })
+        };
+        (has_entries(UPDATE_COMPONENTS_FIELD)
+            || has_entries(UPDATE_REMOVED_FIELD)
+            || has_entries(UPDATE_ERRORS_FIELD))
+        .then_some(update)
+    }
+
+    /// Whether a watched field of `component` changed, recording `value` as logged if so
+    ///
+    /// The first value of a component is always logged, as are components none of the paths
+    /// exist in.
+    fn changed(&mut self, component: &str, value: &Value) -> bool {
+        let fields: Vec<Option<Value>> = self
+            .paths
+            .iter()
+            .map(|path| path.resolve(value).cloned())
+            .collect();
+        if fields.iter().all(Option::is_none) {
+            return true;
+        }
+        let changed = self.last_logged.get(component).is_none_or(|last| {
+            last.iter()
+                .zip(&fields)
+                .any(|(last, field)| match (last, field) {
+                    (Some(last), Some(field)) => differs(last, field, self.epsilon),
+                    (None, None) => false,
+                    _ => true,
+                })
+        });
+        if changed {
+            self.last_logged.insert(component.to_string(), fields);
+        }
+        changed
+    }
+}
+
+/// Whether two values differ, treating numbers within `epsilon` of each other as equal
+fn differs(a: &Value, b: &Value, epsilon: f64) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => (a - b).abs() > epsilon,
+            _ => a != b,
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() != b.len() || a.iter().zip(b).any(|(a, b)| differs(a, b, epsilon))
+        },
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() != b.len()
+                || a.iter()
+                    .any(|(key, a)| b.get(key).is_none_or(|b| differs(a, b, epsilon)))
+        },
+        _ => a != b,
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::FieldFilter;
+    use super::FieldPath;
+
+    fn transform_update(x: f64, y: f64) -> Value {
+        json!({
+            "components": {
+                "bevy_transform::components::transform::Transform": {
+                    "translation": [x, y, 0.0],
+                    "rotation": [0.0, 0.0, 0.0, 1.0],
+                    "scale": [1.0, 1.0, 1.0]
+                }
+            },
+            "removed": []
+        })
+    }
+
+    #[test]
+    fn paths_resolve_fields_indexes_and_vector_components() {
+        let value = json!({ "translation": [1.0, 2.0, 3.0], "points": [[4.0, 5.0]] });
+        let resolve = |path: &str| {
+            FieldPath::parse(path)
+                .expect("path parses")
+                .resolve(&value)
+                .cloned()
+        };
+        assert_eq!(resolve(".translation.y"), Some(json!(2.0)));
+        assert_eq!(resolve(".translation[2]"), Some(json!(3.0)));
+        assert_eq!(resolve(".points[0].1"), Some(json!(5.0)));
+        assert_eq!(resolve(".scale"), None);
+
+        assert!(FieldPath::parse("translation").is_err());
+        assert!(FieldPath::parse(".translation[x]").is_err());
+        assert!(FieldPath::parse(".translation..x").is_err());
+    }
+
+    #[test]
+    fn only_changes_beyond_epsilon_are_logged() {
+        let mut filter = FieldFilter::from_params(&[".translation.x".to_string()], Some(0.5))
+            .expect("filter builds")
+            .expect("paths were given");
+
+        assert!(filter.filter_update(transform_update(0.0, 0.0)).is_some());
+        // `y` is not watched and `x` moved less than epsilon
+        assert!(filter.filter_update(transform_update(0.3, 9.0)).is_none());
+        // Small steps add up against the last logged value
+        assert!(filter.filter_update(transform_update(0.6, 9.0)).is_some());
+        assert!(filter.filter_update(transform_update(0.7, 9.0)).is_none());
+    }
+
+    #[test]
+    fn removals_are_always_logged() {
+        let mut filter = FieldFilter::from_params(&[".translation.x".to_string()], None)
+            .expect("filter builds")
+            .expect("paths were given");
+        assert!(filter.filter_update(transform_update(1.0, 0.0)).is_some());
+
+        let removal = json!({
+            "components": {},
+            "removed": ["bevy_transform::components::transform::Transform"]
+        });
+        assert_eq!(filter.filter_update(removal.clone()), Some(removal));
+    }
+}
@@ -0,0 +1,261 @@
+//! Versioned JSONL schema for watch logs
+//!
+//! Every line of a watch log is one [`WatchLogRecord`]. A component update is split into one
+//! record per component: changed components carry `value`, removed components carry a `diff`, and
+//! list watches carry a single `diff` with the added and removed component names. Lifecycle,
+//! error, and debug events carry their payload in `data`.
+//!
+//! Logs written before the schema existed used `[timestamp] EVENT: {json}` lines;
+//! [`convert_legacy_log`] rewrites them into the current schema.
+
+use std::path::Path;
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDateTime;
+use chrono::SecondsFormat;
+use chrono::TimeZone;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use super::constants::COMPONENT_UPDATE_EVENT;
+use super::constants::ENTITY_FIELD;
+use super::constants::LEGACY_TIMESTAMP_FORMAT;
+use super::constants::UPDATE_ADDED_FIELD;
+use super::constants::UPDATE_COMPONENTS_FIELD;
+use super::constants::UPDATE_ERRORS_FIELD;
+use super::constants::UPDATE_REMOVED_FIELD;
+use super::constants::WATCH_LOG_FILENAME_PREFIX;
+use super::constants::WATCH_LOG_SCHEMA_VERSION;
+
+/// One line of a watch log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct WatchLogRecord {
+    /// Schema version, bumped on any incompatible change
+    pub(super) schema_version: u32,
+    /// RFC 3339 timestamp with millisecond precision
+    pub(super) ts:             String,
+    /// Watch that produced the record
+    pub(super) watch_id:       u32,
+    /// Event name in `snake_case`, e.g. `component_update` or `watch_started`
+    pub(super) event:          String,
+    /// Watched entity
+    pub(super) entity:         u64,
+    /// Component type path for per-component records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) component:      Option<String>,
+    /// New component value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) value:          Option<Value>,
+    /// Structural change: `{"removed": true}` for one component, or `{"added": [..],
+    /// "removed": [..]}` for list watches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) diff:           Option<Value>,
+    /// Payload of lifecycle, error, and debug events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) data:           Option<Value>,
+}
+
+impl WatchLogRecord {
+    fn new(ts: &str, watch_id: u32, event: &str, entity: u64) -> Self {
+        Self {
+            schema_version: WATCH_LOG_SCHEMA_VERSION,
+            ts: ts.to_string(),
+            watch_id,
+            event: event.to_ascii_lowercase(),
+            entity,
+            component: None,
+            value: None,
+            diff: None,
+            data: None,
+        }
+    }
+}
+
+/// Format a timestamp the way the schema expects
+pub(super) fn format_timestamp(timestamp: &DateTime<Local>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Millis, false)
+}
+
+/// Build the records for one logged event
+pub(super) fn records_for_event(
+    ts: &str,
+    watch_id: u32,
+    entity: u64,
+    update_type: &str,
+    data: Value,
+) -> Vec<WatchLogRecord> {
+    if update_type != COMPONENT_UPDATE_EVENT {
+        let mut record = WatchLogRecord::new(ts, watch_id, update_type, entity);
+        record.data = Some(data);
+        return vec![record];
+    }
+
+    let record = || WatchLogRecord::new(ts, watch_id, update_type, entity);
+    let mut records = Vec::new();
+
+    if let Some(components) = data.get(UPDATE_COMPONENTS_FIELD).and_then(Value::as_object) {
+        // `world.get_components+watch`: values of changed components
+        for (component, value) in components {
+            records.push(WatchLogRecord {
+                component: Some(component.clone()),
+                value: Some(value.clone()),
+                ..record()
+            });
+        }
+        for component in string_array(&data, UPDATE_REMOVED_FIELD) {
+            records.push(WatchLogRecord {
+                component: Some(component),
+                diff: Some(json!({ UPDATE_REMOVED_FIELD: true })),
+                ..record()
+            });
+        }
+        if let Some(errors) = data.get(UPDATE_ERRORS_FIELD).and_then(Value::as_object) {
+            for (component, error) in errors {
+                records.push(WatchLogRecord {
+                    component: Some(component.clone()),
+                    data: Some(json!({ UPDATE_ERRORS_FIELD: error })),
+                    ..record()
+                });
+            }
+        }
+    } else if data.get(UPDATE_ADDED_FIELD).is_some() || data.get(UPDATE_REMOVED_FIELD).is_some() {
+        // `world.list_components+watch`: added and removed component names
+        records.push(WatchLogRecord {
+            diff: Some(json!({
+                UPDATE_ADDED_FIELD: string_array(&data, UPDATE_ADDED_FIELD),
+                UPDATE_REMOVED_FIELD: string_array(&data, UPDATE_REMOVED_FIELD),
+            })),
+            ..record()
+        });
+    }
+
+    if records.is_empty() {
+        records.push(WatchLogRecord {
+            data: Some(data),
+            ..record()
+        });
+    }
+    records
+}
+
+fn string_array(data: &Value, field: &str) -> Vec<String> {
+    data.get(field)
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Result of converting a legacy watch log
+pub(super) struct LegacyConversion {
+    pub(super) records:       Vec<WatchLogRecord>,
+    pub(super) skipped_lines: usize,
+}
+
+/// Convert the contents of a legacy `[timestamp] EVENT: {json}` watch log.
+///
+/// The watch id and entity come from the log filename
+/// (`bevy_brp_mcp_watch_{id}_{type}_{entity}_{timestamp}.log`) when it follows the naming
+/// convention, and default to `0` otherwise. Lines that do not parse are counted and skipped.
+pub(super) fn convert_legacy_log(path: &Path, contents: &str) -> LegacyConversion {
+    let (watch_id, entity) = ids_from_filename(path).unwrap_or_default();
+    let mut records = Vec::new();
+    let mut skipped_lines = 0;
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        match parse_legacy_line(line) {
+            Some((ts, update_type, data)) => {
+                let entity = data
+                    .get(ENTITY_FIELD)
+                    .and_then(Value::as_u64)
+                    .unwrap_or(entity);
+                records.extend(records_for_event(&ts, watch_id, entity, update_type, data));
+            },
+            None => skipped_lines += 1,
+        }
+    }
+
+    LegacyConversion {
+        records,
+        skipped_lines,
+    }
+}
+
+fn parse_legacy_line(line: &str) -> Option<(String, &str, Value)> {
+    let (timestamp, rest) = line.strip_prefix('[')?.split_once("] ")?;
+    let (update_type, json) = rest.split_once(": ")?;
+    let data = serde_json::from_str(json).ok()?;
+
+    // Legacy timestamps are local time without an offset
+    let ts = NaiveDateTime::parse_from_str(timestamp, LEGACY_TIMESTAMP_FORMAT)
+        .ok()
+        .and_then(|naive| Local.from_local_datetime(&naive).single())
+        .map_or_else(|| timestamp.to_string(), |local| format_timestamp(&local));
+
+    Some((ts, update_type, data))
+}
+
+fn ids_from_filename(path: &Path) -> Option<(u32, u64)> {
+    let stem = path.file_stem()?.to_str()?;
+    let mut parts = stem.strip_prefix(WATCH_LOG_FILENAME_PREFIX)?.split('_');
+    let watch_id = parts.next()?.parse().ok()?;
+    let _watch_type = parts.next()?;
+    let entity = parts.next()?.parse().ok()?;
+    Some((watch_id, entity))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::convert_legacy_log;
+    use super::records_for_event;
+    use crate::brp_tools::watch_tools::constants::WATCH_LOG_SCHEMA_VERSION;
+
+    const TRANSFORM: &str = "bevy_transform::components::transform::Transform";
+    const SPRITE: &str = "bevy_sprite::sprite::Sprite";
+
+    #[test]
+    fn component_update_splits_into_one_record_per_component() {
+        let data = json!({
+            "components": { TRANSFORM: { "translation": [1.0, 2.0, 3.0] } },
+            "removed": [SPRITE]
+        });
+
+        let records =
+            records_for_event("2026-01-01T00:00:00.000Z", 7, 42, "COMPONENT_UPDATE", data);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].schema_version, WATCH_LOG_SCHEMA_VERSION);
+        assert_eq!(records[0].event, "component_update");
+        assert_eq!(records[0].component.as_deref(), Some(TRANSFORM));
+        assert!(records[0].value.is_some());
+        assert_eq!(records[1].component.as_deref(), Some(SPRITE));
+        assert_eq!(records[1].diff, Some(json!({ "removed": true })));
+    }
+
+    #[test]
+    fn legacy_lines_convert_with_ids_from_filename() {
+        let path = Path::new("/tmp/bevy_brp_mcp_watch_3_list_99_1700000000.log");
+        let contents = "[2026-01-01 12:00:00.250] COMPONENT_UPDATE: {\"added\":[\"A\"],\"removed\":[]}\n\
+                        not a log line\n";
+
+        let conversion = convert_legacy_log(path, contents);
+
+        assert_eq!(conversion.skipped_lines, 1);
+        assert_eq!(conversion.records.len(), 1);
+        let record = &conversion.records[0];
+        assert_eq!((record.watch_id, record.entity), (3, 99));
+        assert_eq!(record.diff, Some(json!({ "added": ["A"], "removed": [] })));
+    }
+}
@@ -0,0 +1,253 @@
+//! Background task that tails an app log file into a watch log
+//!
+//! Log watches share the watch manager with entity watches, so `brp_stop_watch` and
+//! `brp_list_active_watches` work for them unchanged. They have no entity; their records carry
+//! entity `0`.
+
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde_json::json;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncSeekExt;
+use tokio::sync::oneshot;
+use tracing::info;
+
+use super::constants::FILENAME_FIELD;
+use super::constants::KEYWORD_FIELD;
+use super::constants::LINE_FIELD;
+use super::constants::LINE_NUMBER_FIELD;
+use super::constants::LOG_LINE_EVENT;
+use super::constants::LOG_WATCH_APP_CHECK_INTERVAL;
+use super::constants::LOG_WATCH_POLL_INTERVAL;
+use super::constants::LOG_WATCH_TYPE;
+use super::constants::NO_WATCHED_ENTITY;
+use super::constants::TIMESTAMP_FIELD;
+use super::constants::WATCH_ENDED_EVENT;
+use super::constants::WATCH_STARTED_EVENT;
+use super::logger::BufferedWatchLogger;
+use super::manager::ActiveWatch;
+use super::manager::WATCH_MANAGER;
+use super::manager::WatchInfo;
+use super::summary::WatchStopReason;
+use super::summary::WatchSummary;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::ParameterName;
+
+/// What a log watch follows and which lines it keeps
+struct LogWatchParams {
+    watch_id: u32,
+    app_log:  PathBuf,
+    keyword:  Option<String>,
+    port:     Port,
+}
+
+/// Start tailing `app_log` from its current end, logging every new line containing `keyword`
+///
+/// With `notify`, the MCP client is sent a resource update as records are written. The watch
+/// ends when stopped, when its `ttl` runs out or it goes unread, when the log file disappears, or
+/// when the app that was listening on `port` exits.
+pub(crate) async fn start_log_watch_task(
+    app_log: PathBuf,
+    keyword: Option<String>,
+    ttl: Option<Duration>,
+    notify: bool,
+    port: Port,
+) -> Result<(u32, PathBuf)> {
+    let mut file = File::open(&app_log)
+        .await
+        .map_err(|e| Error::io_failed("open log file", &app_log, &e))?;
+    let start_offset = file
+        .seek(SeekFrom::End(0))
+        .await
+        .map_err(|e| Error::io_failed("seek to end of log file", &app_log, &e))?;
+
+    let mut manager = WATCH_MANAGER.lock().await;
+    manager.reserve_watch()?;
+    let watch_id = manager.next_id();
+
+    let log_path =
+        BufferedWatchLogger::get_watch_log_path(watch_id, NO_WATCHED_ENTITY, LOG_WATCH_TYPE);
+    let logger = BufferedWatchLogger::new(log_path.clone(), watch_id, NO_WATCHED_ENTITY, notify);
+
+    let filename = app_log
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if let Err(e) = logger
+        .write_update(
+            WATCH_STARTED_EVENT,
+            json!({
+                FILENAME_FIELD: filename,
+                KEYWORD_FIELD: keyword,
+                ParameterName::Port: port,
+                TIMESTAMP_FIELD: chrono::Local::now().to_rfc3339()
+            }),
+        )
+        .await
+    {
+        return Err(error_stack::Report::new(Error::WatchOperation(format!(
+            "Failed to log initial entry for log file {filename}: {e}"
+        ))));
+    }
+
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let handle = tokio::spawn(run_log_watch(
+        LogWatchParams {
+            watch_id,
+            app_log,
+            keyword,
+            port,
+        },
+        file,
+        start_offset,
+        logger,
+        stop_rx,
+    ));
+
+    manager.active_watches.insert(
+        watch_id,
+        ActiveWatch {
+            info: WatchInfo::new(
+                watch_id,
+                None,
+                LOG_WATCH_TYPE.to_string(),
+                log_path.clone(),
+                port,
+                ttl,
+                notify,
+            ),
+            handle,
+            stop_tx,
+        },
+    );
+    drop(manager);
+
+    Ok((watch_id, log_path))
+}
+
+/// Tail the log until the watch is stopped or the log can no longer grow
+async fn run_log_watch(
+    params: LogWatchParams,
+    file: File,
+    start_offset: u64,
+    logger: BufferedWatchLogger,
+    stop_rx: oneshot::Receiver<WatchStopReason>,
+) -> WatchSummary {
+    info!(
+        "Starting log watch {} for {} on port {}",
+        params.watch_id,
+        params.app_log.display(),
+        params.port
+    );
+    let start_time = Instant::now();
+
+    let (reason, error) = tokio::select! {
+        ended = tail_log(&params, file, start_offset, &logger) => ended,
+        reason = stop_rx => (reason.unwrap_or(WatchStopReason::Stopped), None),
+    };
+
+    let summary = logger.summary(reason, start_time.elapsed(), error);
+    let _ = logger
+        .write_update(
+            WATCH_ENDED_EVENT,
+            serde_json::to_value(&summary).unwrap_or_default(),
+        )
+        .await;
+    logger.finish().await;
+
+    // A watch ended by the manager was already removed
+    if !reason.ended_by_manager() {
+        WATCH_MANAGER
+            .lock()
+            .await
+            .active_watches
+            .remove(&params.watch_id);
+        info!(
+            "Log watch {} automatically cleaned up after it ended",
+            params.watch_id
+        );
+    }
+
+    summary
+}
+
+/// Poll the file for appended bytes and log each complete matching line
+///
+/// Returns why tailing ended and the error that ended it, if any.
+async fn tail_log(
+    params: &LogWatchParams,
+    mut file: File,
+    start_offset: u64,
+    logger: &BufferedWatchLogger,
+) -> (WatchStopReason, Option<String>) {
+    let keyword = params.keyword.as_deref().map(str::to_lowercase);
+    let mut offset = start_offset;
+    let mut pending = Vec::new();
+    let mut line_number = 0_u64;
+    let mut app_was_listening = false;
+    let mut last_app_check = Instant::now();
+    let mut chunk = Vec::new();
+
+    loop {
+        tokio::time::sleep(LOG_WATCH_POLL_INTERVAL).await;
+
+        let Ok(metadata) = tokio::fs::metadata(&params.app_log).await else {
+            return (
+                WatchStopReason::StreamEnded,
+                Some(format!("log file {} was removed", params.app_log.display())),
+            );
+        };
+
+        // A truncated file is read again from its start
+        if metadata.len() < offset {
+            offset = 0;
+            pending.clear();
+            if let Err(e) = file.seek(SeekFrom::Start(0)).await {
+                return (WatchStopReason::StreamEnded, Some(e.to_string()));
+            }
+        }
+
+        chunk.clear();
+        if let Err(e) = file.read_to_end(&mut chunk).await {
+            return (WatchStopReason::StreamEnded, Some(e.to_string()));
+        }
+        offset += chunk.len() as u64;
+        pending.extend_from_slice(&chunk);
+
+        while let Some(newline) = pending.iter().position(|&byte| byte == b'\n') {
+            let line_bytes: Vec<u8> = pending.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line_bytes);
+            let line = line.trim_end_matches(['\r', '\n']);
+            line_number += 1;
+
+            if keyword
+                .as_deref()
+                .is_none_or(|keyword| line.to_lowercase().contains(keyword))
+            {
+                let _ = logger
+                    .write_update(
+                        LOG_LINE_EVENT,
+                        json!({ LINE_NUMBER_FIELD: line_number, LINE_FIELD: line }),
+                    )
+                    .await;
+            }
+        }
+
+        // The app only counts as exited once it has been seen listening; a freshly launched app
+        // may not have opened its port yet
+        if chunk.is_empty() && last_app_check.elapsed() >= LOG_WATCH_APP_CHECK_INTERVAL {
+            last_app_check = Instant::now();
+            let listening = params.port.listening_pid().is_some();
+            if app_was_listening && !listening {
+                return (WatchStopReason::AppExited, None);
+            }
+            app_was_listening |= listening;
+        }
+    }
+}
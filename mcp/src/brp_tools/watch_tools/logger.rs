@@ -1,7 +1,8 @@
 //! Optimized watch logging with buffering and batching
 
-use std::fmt::Write;
 use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
 use std::time::UNIX_EPOCH;
 
 use chrono::DateTime;
@@ -12,14 +13,27 @@ use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
 use tokio::time::Instant;
 use tracing::debug;
 use tracing::error;
 
 use super::constants::BUFFER_FLUSH_SIZE;
+use super::constants::COMPONENT_UPDATE_EVENT;
+use super::constants::DIAGNOSTICS_SAMPLE_EVENT;
+use super::constants::LOG_LINE_EVENT;
+use super::constants::MESSAGE_EVENT;
 use super::constants::WATCH_LOG_BUFFER_CAPACITY;
 use super::constants::WATCH_LOG_BUFFER_SIZE;
+use super::constants::WATCH_LOG_FILENAME_PREFIX;
 use super::constants::WATCH_LOG_FLUSH_INTERVAL;
+use super::field_filter::FieldFilter;
+use super::log_schema;
+use super::log_schema::WatchLogRecord;
+use super::notifier;
+use super::summary::WatchStats;
+use super::summary::WatchStopReason;
+use super::summary::WatchSummary;
 use crate::log_tools::TracingLevel;
 
 /// Log entry to be written
@@ -32,19 +46,32 @@ pub(super) struct LogEntry {
 
 /// Buffered logger for watch updates
 pub(super) struct BufferedWatchLogger {
-    tx:          mpsc::Sender<LogEntry>,
-    shutdown_tx: Option<oneshot::Sender<()>>,
+    tx:           mpsc::Sender<LogEntry>,
+    shutdown_tx:  Option<oneshot::Sender<()>>,
+    writer:       Option<JoinHandle<()>>,
+    stats:        Mutex<WatchStats>,
+    /// Drops component updates whose watched fields did not change
+    field_filter: Option<Mutex<FieldFilter>>,
 }
 
 impl BufferedWatchLogger {
     /// Create a new buffered logger and spawn the writer task
-    pub(super) fn new(log_path: PathBuf) -> Self {
+    ///
+    /// Entries are written as [`WatchLogRecord`] lines tagged with the watch id and entity. With
+    /// `notify`, each flush also tells the MCP client the watch's resource was updated.
+    pub(super) fn new(log_path: PathBuf, watch_id: u32, entity_id: u64, notify: bool) -> Self {
         let (tx, rx) = mpsc::channel(WATCH_LOG_BUFFER_SIZE);
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
         // Spawn the writer task
-        tokio::spawn(async move {
-            if let Err(e) = write_task(log_path, rx, shutdown_rx).await {
+        let writer = tokio::spawn(async move {
+            let target = WriteTarget {
+                log_path,
+                watch_id,
+                entity_id,
+                notify,
+            };
+            if let Err(e) = write_task(target, rx, shutdown_rx).await {
                 error!("Watch logger write task failed: {e}");
             }
         });
@@ -52,11 +79,48 @@ impl BufferedWatchLogger {
         Self {
             tx,
             shutdown_tx: Some(shutdown_tx),
+            writer: Some(writer),
+            stats: Mutex::new(WatchStats::default()),
+            field_filter: None,
         }
     }
 
+    /// Only log component updates that pass `field_filter`
+    pub(super) fn with_field_filter(mut self, field_filter: Option<FieldFilter>) -> Self {
+        self.field_filter = field_filter.map(Mutex::new);
+        self
+    }
+
     /// Queue a log entry for writing (non-blocking)
+    ///
+    /// Component updates, messages, diagnostics samples, and log lines are also counted for the
+    /// summary written when the watch ends. Component updates the field filter drops are neither
+    /// logged nor counted.
     pub(super) async fn write_update(&self, update_type: &str, data: Value) -> Result<(), String> {
+        let data = if update_type == COMPONENT_UPDATE_EVENT
+            && let Some(field_filter) = &self.field_filter
+            && let Ok(mut field_filter) = field_filter.lock()
+        {
+            match field_filter.filter_update(data) {
+                Some(data) => data,
+                None => return Ok(()),
+            }
+        } else {
+            data
+        };
+
+        if [
+            COMPONENT_UPDATE_EVENT,
+            MESSAGE_EVENT,
+            DIAGNOSTICS_SAMPLE_EVENT,
+            LOG_LINE_EVENT,
+        ]
+        .contains(&update_type)
+            && let Ok(mut stats) = self.stats.lock()
+        {
+            stats.record(&data);
+        }
+
         let entry = LogEntry {
             update_type: update_type.to_string(),
             data,
@@ -85,14 +149,42 @@ impl BufferedWatchLogger {
         }
     }
 
+    /// Summarize the watch from the updates logged so far
+    pub(super) fn summary(
+        &self,
+        reason: WatchStopReason,
+        duration: Duration,
+        error: Option<String>,
+    ) -> WatchSummary {
+        let stats = self
+            .stats
+            .lock()
+            .map(|mut stats| std::mem::take(&mut *stats))
+            .unwrap_or_default();
+        WatchSummary::new(reason, duration, stats, error)
+    }
+
+    /// Write every queued entry and wait for the log file to be flushed
+    pub(super) async fn finish(mut self) {
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(());
+        }
+        if let Some(writer) = self.writer.take()
+            && let Err(e) = writer.await
+        {
+            error!("Watch logger write task did not finish: {e}");
+        }
+    }
+
     /// Get the log file path for a watch (same as before)
     pub(super) fn get_watch_log_path(watch_id: u32, entity_id: u64, watch_type: &str) -> PathBuf {
         let timestamp = std::time::SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_or(0, |d| d.as_secs());
 
-        let filename =
-            format!("bevy_brp_mcp_watch_{watch_id}_{watch_type}_{entity_id}_{timestamp}.log");
+        let filename = format!(
+            "{WATCH_LOG_FILENAME_PREFIX}{watch_id}_{watch_type}_{entity_id}_{timestamp}.log"
+        );
 
         std::env::temp_dir().join(filename)
     }
@@ -124,9 +216,58 @@ async fn flush_buffer(
     Ok(())
 }
 
+/// Where a watch's records go
+struct WriteTarget {
+    log_path:  PathBuf,
+    watch_id:  u32,
+    entity_id: u64,
+    /// Notify the MCP client after each flush even if it did not subscribe
+    notify:    bool,
+}
+
+impl WriteTarget {
+    /// Format an entry into the buffer as one JSON object per line
+    fn buffer_entry(&self, buffer: &mut String, entry: LogEntry) {
+        let ts = log_schema::format_timestamp(&entry.timestamp);
+        let records = log_schema::records_for_event(
+            &ts,
+            self.watch_id,
+            self.entity_id,
+            &entry.update_type,
+            entry.data,
+        );
+        buffer_records(buffer, &records);
+    }
+
+    /// Write the buffer to the log, then tell the client there are new records
+    async fn flush(
+        &self,
+        file: &mut File,
+        buffer: &mut String,
+        last_flush: &mut Instant,
+    ) -> std::io::Result<()> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        flush_buffer(file, buffer, last_flush).await?;
+        notifier::notify_updated(self.watch_id, self.notify).await;
+        Ok(())
+    }
+}
+
+/// Append records to the buffer as one JSON object per line
+fn buffer_records(buffer: &mut String, records: &[WatchLogRecord]) {
+    for record in records {
+        if let Ok(json) = serde_json::to_string(record) {
+            buffer.push_str(&json);
+            buffer.push('\n');
+        }
+    }
+}
+
 /// Background task that batches and writes log entries
 async fn write_task(
-    log_path: PathBuf,
+    target: WriteTarget,
     mut rx: mpsc::Receiver<LogEntry>,
     mut shutdown_rx: oneshot::Receiver<()>,
 ) -> std::io::Result<()> {
@@ -134,7 +275,7 @@ async fn write_task(
     let mut file = OpenOptions::new()
         .create(true)
         .append(true)
-        .open(&log_path)
+        .open(&target.log_path)
         .await?;
 
     // Buffer for batching writes
@@ -155,21 +296,13 @@ async fn write_task(
             timeout_result = tokio::time::timeout(flush_interval, rx.recv()) => {
                 match timeout_result {
                     Ok(Some(entry)) => {
-                        // Format entry into buffer
-                        let timestamp = entry.timestamp.format("%Y-%m-%d %H:%M:%S%.3f");
-                        if let Ok(json) = serde_json::to_string(&entry.data) {
-                            let _ = writeln!(
-                                &mut buffer,
-                                "[{}] {}: {}",
-                                timestamp, entry.update_type, json
-                            );
-                        }
+                        target.buffer_entry(&mut buffer, entry);
 
                         // Check if we should flush (buffer size or time)
                         if buffer.len() > BUFFER_FLUSH_SIZE
                             || last_flush.elapsed() > flush_interval
                         {
-                            flush_buffer(&mut file, &mut buffer, &mut last_flush).await?;
+                            target.flush(&mut file, &mut buffer, &mut last_flush).await?;
                         }
                     }
                     Ok(None) => {
@@ -179,15 +312,22 @@ async fn write_task(
                     }
                     Err(_) => {
                         // Timeout - flush if buffer has content
-                        flush_buffer(&mut file, &mut buffer, &mut last_flush).await?;
+                        target.flush(&mut file, &mut buffer, &mut last_flush).await?;
                     }
                 }
             }
         }
     }
 
+    // Entries queued before the shutdown signal, such as the final summary, are still written
+    while let Ok(entry) = rx.try_recv() {
+        target.buffer_entry(&mut buffer, entry);
+    }
+
     // Final flush before shutdown
-    flush_buffer(&mut file, &mut buffer, &mut last_flush).await?;
+    target
+        .flush(&mut file, &mut buffer, &mut last_flush)
+        .await?;
     debug!("Watch logger write task shutting down cleanly");
 
     Ok(())
@@ -1,16 +1,31 @@
 //! Watch manager for coordinating file-based watch logging
+//!
+//! The manager also bounds how many watches run and how long they live, so long agent sessions
+//! don't leak background tasks: at most [`MAX_ACTIVE_WATCHES`] run at once, and a reaper task
+//! stops watches whose TTL ran out and watches whose log has not been read with `brp_read_log`
+//! for [`WATCH_IDLE_TIMEOUT`]. Watches that notify the client are never idle.
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::LazyLock;
+use std::sync::Once;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
 
 use tokio::sync::Mutex;
+use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
 use tracing::info;
 
+use super::constants::MAX_ACTIVE_WATCHES;
+use super::constants::WATCH_IDLE_TIMEOUT;
+use super::constants::WATCH_REAPER_INTERVAL;
+use super::summary::WatchStopReason;
+use super::summary::WatchSummary;
 use crate::brp_tools::Port;
 use crate::error::Error;
 use crate::error::Result;
@@ -19,14 +34,76 @@ use crate::error::Result;
 pub(super) static WATCH_MANAGER: LazyLock<Arc<Mutex<WatchManager>>> =
     std::sync::LazyLock::new(|| Arc::new(Mutex::new(WatchManager::new())));
 
+/// Starts the reaper the first time a watch starts
+static REAPER: Once = Once::new();
+
 /// Information about an active watch
 #[derive(Debug, Clone)]
 pub(super) struct WatchInfo {
-    pub(super) id:        u32,
-    pub(super) entity_id: u64,
-    pub(super) kind:      String,
-    pub(super) log_path:  PathBuf,
-    pub(super) port:      Port,
+    pub(super) id:         u32,
+    /// Watched entity; `None` for log watches
+    pub(super) entity_id:  Option<u64>,
+    pub(super) kind:       String,
+    pub(super) log_path:   PathBuf,
+    pub(super) port:       Port,
+    pub(super) started_at: Instant,
+    /// How long the watch may run before it is stopped; `None` runs until stopped or idle
+    pub(super) ttl:        Option<Duration>,
+    /// When the watch log was last read, or the watch started if it has not been read
+    pub(super) last_read:  Instant,
+    /// Records are sent to the MCP client, so an unread log does not make the watch idle
+    pub(super) notify:     bool,
+}
+
+impl WatchInfo {
+    pub(super) fn new(
+        id: u32,
+        entity_id: Option<u64>,
+        kind: String,
+        log_path: PathBuf,
+        port: Port,
+        ttl: Option<Duration>,
+        notify: bool,
+    ) -> Self {
+        let now = Instant::now();
+        Self {
+            id,
+            entity_id,
+            kind,
+            log_path,
+            port,
+            started_at: now,
+            ttl,
+            last_read: now,
+            notify,
+        }
+    }
+
+    /// Time left before the TTL runs out
+    pub(super) fn expires_in(&self, now: Instant) -> Option<Duration> {
+        self.ttl
+            .map(|ttl| ttl.saturating_sub(now.duration_since(self.started_at)))
+    }
+
+    /// Why the reaper should stop this watch now, if it should
+    fn reap_reason(&self, now: Instant) -> Option<WatchStopReason> {
+        if self.expires_in(now).is_some_and(|left| left.is_zero()) {
+            Some(WatchStopReason::Expired)
+        } else if !self.notify && now.duration_since(self.last_read) >= WATCH_IDLE_TIMEOUT {
+            Some(WatchStopReason::Idle)
+        } else {
+            None
+        }
+    }
+}
+
+/// A running watch task and the signal that stops it
+pub(super) struct ActiveWatch {
+    pub(super) info:    WatchInfo,
+    /// Resolves to the watch's summary once its final log record is written
+    pub(super) handle:  JoinHandle<WatchSummary>,
+    /// Sends why the watch was ended by the manager
+    pub(super) stop_tx: oneshot::Sender<WatchStopReason>,
 }
 
 /// Manager for watch subscriptions
@@ -34,7 +111,7 @@ pub(super) struct WatchManager {
     /// Monotonic counter for watch IDs
     next_watch_id:             AtomicU32,
     /// Active watches mapped by watch ID
-    pub(super) active_watches: HashMap<u32, (WatchInfo, JoinHandle<()>)>,
+    pub(super) active_watches: HashMap<u32, ActiveWatch>,
 }
 
 impl WatchManager {
@@ -49,12 +126,27 @@ impl WatchManager {
     /// Get the next watch ID (monotonically increasing)
     pub(super) fn next_id(&self) -> u32 { self.next_watch_id.fetch_add(1, Ordering::SeqCst) }
 
-    /// Stop a watch by ID
-    pub(super) fn stop_watch(&mut self, watch_id: u32) -> Result<()> {
-        if let Some((info, handle)) = self.active_watches.remove(&watch_id) {
-            info!("Stopping watch {watch_id} for entity {}", info.entity_id);
-            handle.abort();
-            Ok(())
+    /// Fail if another watch would exceed [`MAX_ACTIVE_WATCHES`], and make sure the reaper runs
+    pub(super) fn reserve_watch(&self) -> Result<()> {
+        REAPER.call_once(|| {
+            tokio::spawn(reap_watches());
+        });
+        if self.active_watches.len() >= MAX_ACTIVE_WATCHES {
+            return Err(error_stack::Report::new(Error::WatchOperation(format!(
+                "{MAX_ACTIVE_WATCHES} watches are already running. Stop unneeded watches with \
+                 brp_stop_watch; brp_list_active_watches lists them"
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Stop a watch by ID, returning the task handle that resolves to its summary
+    pub(super) fn stop_watch(&mut self, watch_id: u32) -> Result<JoinHandle<WatchSummary>> {
+        if let Some(watch) = self.active_watches.remove(&watch_id) {
+            info!("Stopping {} watch {watch_id}", watch.info.kind);
+            // The task may have ended on its own already; its handle still yields the summary
+            let _ = watch.stop_tx.send(WatchStopReason::Stopped);
+            Ok(watch.handle)
         } else {
             Err(error_stack::Report::new(Error::WatchOperation(format!(
                 "Failed to stop watch {watch_id}: watch not found"
@@ -66,7 +158,107 @@ impl WatchManager {
     pub(super) fn list_active_watches(&self) -> Vec<WatchInfo> {
         self.active_watches
             .values()
-            .map(|(info, _)| info.clone())
+            .map(|watch| watch.info.clone())
             .collect()
     }
+
+    /// Record that the watch writing `log_path` was read, so it is not stopped as idle
+    ///
+    /// Logs are matched by filename, which includes the watch ID.
+    pub(super) fn mark_read(&mut self, log_path: &Path) {
+        if let Some(watch) = self
+            .active_watches
+            .values_mut()
+            .find(|watch| watch.info.log_path.file_name() == log_path.file_name())
+        {
+            watch.info.last_read = Instant::now();
+        }
+    }
+
+    /// Stop every watch whose TTL ran out or that has been idle too long
+    fn reap(&mut self, now: Instant) {
+        let reaped: Vec<(u32, WatchStopReason)> = self
+            .active_watches
+            .values()
+            .filter_map(|watch| {
+                watch
+                    .info
+                    .reap_reason(now)
+                    .map(|reason| (watch.info.id, reason))
+            })
+            .collect();
+        for (watch_id, reason) in reaped {
+            if let Some(watch) = self.active_watches.remove(&watch_id) {
+                info!("Stopping {} watch {watch_id}: {reason:?}", watch.info.kind);
+                // The task writes its summary on its own; nobody awaits the handle
+                let _ = watch.stop_tx.send(reason);
+            }
+        }
+    }
+}
+
+/// Periodically stop expired and idle watches
+async fn reap_watches() {
+    let mut interval = tokio::time::interval(WATCH_REAPER_INTERVAL);
+    loop {
+        interval.tick().await;
+        WATCH_MANAGER.lock().await.reap(Instant::now());
+    }
+}
+
+/// Record that a watch log was read, e.g. by `brp_read_log`
+pub(crate) async fn mark_watch_log_read(log_path: &Path) {
+    WATCH_MANAGER.lock().await.mark_read(log_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::WATCH_IDLE_TIMEOUT;
+    use super::WatchInfo;
+    use super::WatchStopReason;
+    use crate::brp_tools::Port;
+
+    fn watch(ttl: Option<Duration>, notify: bool) -> WatchInfo {
+        WatchInfo::new(
+            1,
+            Some(7),
+            "get".to_string(),
+            PathBuf::from("watch.log"),
+            Port::default(),
+            ttl,
+            notify,
+        )
+    }
+
+    #[test]
+    fn watches_expire_after_their_ttl() {
+        let watch = watch(Some(Duration::from_mins(1)), false);
+        let started = watch.started_at;
+
+        assert_eq!(watch.reap_reason(started + Duration::from_secs(30)), None);
+        assert_eq!(
+            watch.reap_reason(started + Duration::from_mins(1)),
+            Some(WatchStopReason::Expired)
+        );
+    }
+
+    #[test]
+    fn unread_watches_become_idle() {
+        let mut watch = watch(None, false);
+        let later = watch.started_at + WATCH_IDLE_TIMEOUT;
+        assert_eq!(watch.reap_reason(later), Some(WatchStopReason::Idle));
+
+        watch.last_read = watch.started_at + Duration::from_secs(1);
+        assert_eq!(watch.reap_reason(later), None);
+    }
+
+    #[test]
+    fn notifying_watches_never_become_idle() {
+        let watch = watch(None, true);
+        let later = watch.started_at + WATCH_IDLE_TIMEOUT * 2;
+        assert_eq!(watch.reap_reason(later), None);
+    }
 }
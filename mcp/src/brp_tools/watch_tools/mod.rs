@@ -1,19 +1,41 @@
 // Watch module
 
+mod brp_convert_watch_log;
+mod brp_extras_diagnostics_watch;
 mod brp_list_active;
 mod brp_stop_watch;
 mod constants;
+mod field_filter;
+mod log_schema;
+mod log_watch;
 mod logger;
 mod manager;
+mod notifier;
+mod summary;
 mod task;
 mod watch_start_result;
+mod world_events_watch;
 mod world_get_components_watch;
 mod world_list_components_watch;
 mod wrap_watch_error;
 
+pub use brp_convert_watch_log::BrpConvertWatchLog;
+pub use brp_convert_watch_log::ConvertWatchLogParams;
+pub use brp_extras_diagnostics_watch::BrpExtrasDiagnosticsWatch;
+pub use brp_extras_diagnostics_watch::DiagnosticsWatchParams;
 pub use brp_list_active::BrpListActiveWatches;
 pub use brp_stop_watch::BrpStopWatch;
 pub use brp_stop_watch::StopWatchParams;
+pub(crate) use log_watch::start_log_watch_task;
+pub(crate) use manager::mark_watch_log_read;
+pub(crate) use notifier::is_watch_resource;
+pub(crate) use notifier::list_watch_resources;
+pub(crate) use notifier::read_watch_resource;
+pub(crate) use notifier::set_client_peer;
+pub(crate) use notifier::subscribe_watch_resource;
+pub(crate) use notifier::unsubscribe_watch_resource;
+pub use world_events_watch::EventsWatchParams;
+pub use world_events_watch::WorldEventsWatch;
 pub use world_get_components_watch::GetComponentsWatchParams;
 pub use world_get_components_watch::WorldGetComponentsWatch;
 pub use world_list_components_watch::BevyListWatch;
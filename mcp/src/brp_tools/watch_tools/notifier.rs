@@ -0,0 +1,188 @@
+//! Watch logs exposed to the MCP client as resources
+//!
+//! Every active watch is listed as a `brp-mcp://watches/{id}` resource whose contents are its JSON
+//! Lines log. After each batch of records is written, a watch started with `notify`, or one the
+//! client subscribed to, sends `notifications/resources/updated` for its URI, so updates arrive as
+//! they happen instead of being polled with `brp_read_log`. Appending `?start=N` to the URI skips
+//! the first N records, so a client can read only the ones it has not seen.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use rmcp::Peer;
+use rmcp::RoleServer;
+use rmcp::model::Resource;
+use rmcp::model::ResourceUpdatedNotificationParam;
+use tracing::debug;
+
+use super::constants::WATCH_LOG_MIME_TYPE;
+use super::constants::WATCH_RESOURCE_URI_PREFIX;
+use super::manager::WATCH_MANAGER;
+use super::manager::WatchInfo;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The connected MCP client, set once it has initialized the session
+static CLIENT_PEER: OnceLock<Peer<RoleServer>> = OnceLock::new();
+
+/// Watches the client subscribed to with `resources/subscribe`
+static SUBSCRIBED_WATCHES: LazyLock<Mutex<HashSet<u32>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Remember the MCP client so watches can notify it
+pub(crate) fn set_client_peer(peer: Peer<RoleServer>) { let _ = CLIENT_PEER.set(peer); }
+
+/// Whether `uri` names a watch resource
+pub(crate) fn is_watch_resource(uri: &str) -> bool { uri.starts_with(WATCH_RESOURCE_URI_PREFIX) }
+
+/// Send update notifications for the watch `uri` names until the client unsubscribes
+pub(crate) fn subscribe_watch_resource(uri: &str) -> Result<()> {
+    let (watch_id, _) = parse_uri(uri)?;
+    if let Ok(mut subscribed) = SUBSCRIBED_WATCHES.lock() {
+        subscribed.insert(watch_id);
+    }
+    Ok(())
+}
+
+/// Stop update notifications for the watch `uri` names, unless it was started with `notify`
+pub(crate) fn unsubscribe_watch_resource(uri: &str) -> Result<()> {
+    let (watch_id, _) = parse_uri(uri)?;
+    if let Ok(mut subscribed) = SUBSCRIBED_WATCHES.lock() {
+        subscribed.remove(&watch_id);
+    }
+    Ok(())
+}
+
+/// Tell the client that the watch's log has new records, if it asked to be told
+pub(super) async fn notify_updated(watch_id: u32, notify: bool) {
+    let Some(peer) = CLIENT_PEER.get() else {
+        return;
+    };
+    let subscribed = SUBSCRIBED_WATCHES
+        .lock()
+        .is_ok_and(|subscribed| subscribed.contains(&watch_id));
+    if !notify && !subscribed {
+        return;
+    }
+    // The watch log still has the records, so a lost notification is not fatal
+    let notification = ResourceUpdatedNotificationParam::new(resource_uri(watch_id));
+    if let Err(e) = peer.notify_resource_updated(notification).await {
+        debug!("Failed to send update notification for watch {watch_id}: {e}");
+    }
+}
+
+/// Active watches, as resources
+pub(crate) async fn list_watch_resources() -> Vec<Resource> {
+    let mut watches: Vec<WatchInfo> = WATCH_MANAGER
+        .lock()
+        .await
+        .active_watches
+        .values()
+        .map(|watch| watch.info.clone())
+        .collect();
+    watches.sort_by_key(|info| info.id);
+    watches
+        .into_iter()
+        .map(|info| {
+            Resource::new(
+                resource_uri(info.id),
+                format!("{} watch {}", info.kind, info.id),
+            )
+            .with_description(format!(
+                "Records logged by watch {}. Append ?start=N to skip the first N records",
+                info.id
+            ))
+            .with_mime_type(WATCH_LOG_MIME_TYPE)
+        })
+        .collect()
+}
+
+/// Records of the watch `uri` names, one JSON object per line, from its `start` query onward
+pub(crate) async fn read_watch_resource(uri: &str) -> Result<String> {
+    let (watch_id, query) = parse_uri(uri)?;
+    let start = parse_start(query)?;
+    let log_path = {
+        let mut manager = WATCH_MANAGER.lock().await;
+        let log_path = manager
+            .active_watches
+            .get(&watch_id)
+            .map(|watch| watch.info.log_path.clone())
+            .ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "Unknown resource '{uri}': watch {watch_id} is not active"
+                ))
+            })?;
+        manager.mark_read(&log_path);
+        log_path
+    };
+    let log = tokio::fs::read_to_string(&log_path).await.map_err(|e| {
+        Error::FileOrPathNotFound(format!(
+            "Failed to read watch log {}: {e}",
+            log_path.display()
+        ))
+    })?;
+    let mut records = String::new();
+    for line in log.lines().skip(start) {
+        records.push_str(line);
+        records.push('\n');
+    }
+    Ok(records)
+}
+
+fn resource_uri(watch_id: u32) -> String { format!("{WATCH_RESOURCE_URI_PREFIX}{watch_id}") }
+
+/// The watch id and query of a watch resource URI
+fn parse_uri(uri: &str) -> Result<(u32, &str)> {
+    let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+    let watch_id = path
+        .strip_prefix(WATCH_RESOURCE_URI_PREFIX)
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "Unknown resource '{uri}': expected {WATCH_RESOURCE_URI_PREFIX}{{watch_id}}"
+            ))
+        })?;
+    Ok((watch_id, query))
+}
+
+/// The number of records a `start=N` query skips
+fn parse_start(query: &str) -> Result<usize> {
+    if query.is_empty() {
+        return Ok(0);
+    }
+    let (key, value) = query.split_once('=').unwrap_or((query, ""));
+    if key != "start" {
+        return Err(Error::invalid(
+            "watch resource query",
+            format!("unknown query parameter '{key}', expected start"),
+        )
+        .into());
+    }
+    value.parse().map_err(|_| {
+        Error::invalid(
+            "watch resource query",
+            format!("'{query}' is not a non-negative integer"),
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_start;
+    use super::parse_uri;
+
+    #[test]
+    fn watch_uris_parse_their_id_and_start() {
+        assert!(matches!(
+            parse_uri("brp-mcp://watches/7?start=3"),
+            Ok((7, "start=3"))
+        ));
+        assert!(parse_uri("brp-mcp://responses/7").is_err());
+        assert!(matches!(parse_start("start=3"), Ok(3)));
+        assert!(matches!(parse_start(""), Ok(0)));
+        assert!(parse_start("end=3").is_err());
+    }
+}
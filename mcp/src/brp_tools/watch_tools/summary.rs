@@ -0,0 +1,129 @@
+//! Final summary of a watch, written as its last log record and returned by `brp_stop_watch`
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::brp_tools::Port;
+
+/// Why a watch ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchStopReason {
+    /// Stopped with `brp_stop_watch`
+    Stopped,
+    /// The app closed the stream while still listening on its port, or a tailed log file went away
+    StreamEnded,
+    /// The connection failed or broke while the app was still listening on its port
+    ConnectionLost,
+    /// Nothing is listening on the port anymore
+    AppExited,
+    /// The watch's `ttl_seconds` ran out
+    Expired,
+    /// The watch log went unread for too long
+    Idle,
+}
+
+impl WatchStopReason {
+    /// Whether the watch manager ended the watch, and so already removed it
+    pub(super) const fn ended_by_manager(self) -> bool {
+        matches!(self, Self::Stopped | Self::Expired | Self::Idle)
+    }
+
+    /// Classify a stream that ended on its own, checking whether the app is still listening
+    pub(super) fn for_ended_stream(port: Port, error: Option<&str>) -> Self {
+        if port.listening_pid().is_none() {
+            Self::AppExited
+        } else if error.is_some() {
+            Self::ConnectionLost
+        } else {
+            Self::StreamEnded
+        }
+    }
+}
+
+/// Component updates or log lines logged by a watch so far
+#[derive(Debug, Default)]
+pub(super) struct WatchStats {
+    update_count: usize,
+    last_value:   Option<Value>,
+}
+
+impl WatchStats {
+    pub(super) fn record(&mut self, update: &Value) {
+        self.update_count += 1;
+        self.last_value = Some(update.clone());
+    }
+}
+
+/// How a watch ended and what it saw
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchSummary {
+    pub reason:       WatchStopReason,
+    /// Time from the watch starting to ending
+    pub duration_ms:  u64,
+    /// Number of component updates, messages for event watches, samples for diagnostics watches,
+    /// or matching lines for log watches, logged
+    pub update_count: usize,
+    /// Payload of the last component update, message, sample, or log line
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_value:   Option<Value>,
+    /// Connection or stream error that ended the watch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error:        Option<String>,
+}
+
+impl WatchSummary {
+    pub(super) fn new(
+        reason: WatchStopReason,
+        duration: Duration,
+        stats: WatchStats,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            reason,
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+            update_count: stats.update_count,
+            last_value: stats.last_value,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::WatchStats;
+    use super::WatchStopReason;
+    use super::WatchSummary;
+
+    #[test]
+    fn summary_keeps_update_count_and_last_value() {
+        let mut stats = WatchStats::default();
+        stats.record(&json!({ "components": { "A": 1 } }));
+        stats.record(&json!({ "components": { "A": 2 } }));
+
+        let summary = WatchSummary::new(
+            WatchStopReason::ConnectionLost,
+            Duration::from_millis(1_500),
+            stats,
+            Some("connection reset".to_string()),
+        );
+
+        assert_eq!(
+            serde_json::to_value(summary).ok(),
+            Some(json!({
+                "reason": "connection_lost",
+                "duration_ms": 1_500,
+                "update_count": 2,
+                "last_value": { "components": { "A": 2 } },
+                "error": "connection reset"
+            }))
+        );
+    }
+}
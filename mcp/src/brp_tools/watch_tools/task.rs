@@ -1,12 +1,14 @@
 //! Background task management for watch connections
 
 use std::path::PathBuf;
+use std::time::Duration;
 use std::time::Instant;
 
 use error_stack::Report;
 use futures::StreamExt;
 use reqwest::Response;
 use serde_json::Value;
+use tokio::sync::oneshot;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
@@ -35,10 +37,13 @@ use super::constants::DEBUG_NO_RESULT_EVENT;
 use super::constants::DEBUG_STREAM_ENDED_EVENT;
 use super::constants::DEBUG_STREAM_ERROR_EVENT;
 use super::constants::DEBUG_STREAM_STARTED_EVENT;
+use super::constants::DIAGNOSTICS_SAMPLE_EVENT;
+use super::constants::DIAGNOSTICS_WATCH_TYPE;
 use super::constants::ELAPSED_SECONDS_FIELD;
 use super::constants::EMPTY_LINES_FIELD;
 use super::constants::ENTITY_FIELD;
 use super::constants::ERROR_FIELD;
+use super::constants::EVENTS_WATCH_TYPE;
 use super::constants::FINAL_BUFFER_SIZE_FIELD;
 use super::constants::FULL_DATA_FIELD;
 use super::constants::HAD_INCOMPLETE_LINE_FIELD;
@@ -58,6 +63,8 @@ use super::constants::LINES_PROCESSED_FIELD;
 use super::constants::MAX_BUFFER_SIZE;
 use super::constants::MAX_CHUNK_SIZE;
 use super::constants::MAX_PREVIEW_BYTES;
+use super::constants::MESSAGE_EVENT;
+use super::constants::NO_WATCHED_ENTITY;
 use super::constants::PREVIEW_FIELD;
 use super::constants::RAW_DATA_FIELD;
 use super::constants::REMAINING_BUFFER_SIZE_FIELD;
@@ -70,12 +77,17 @@ use super::constants::TIMESTAMP_FIELD;
 use super::constants::TOTAL_BUFFER_SIZE_BEFORE_FIELD;
 use super::constants::TOTAL_CHUNKS_RECEIVED_FIELD;
 use super::constants::UNKNOWN_STATUS_TEXT;
+use super::constants::UPDATE_EVENTS_FIELD;
 use super::constants::WATCH_ENDED_EVENT;
 use super::constants::WATCH_STARTED_EVENT;
 use super::constants::WATCH_TYPE_FIELD;
+use super::field_filter::FieldFilter;
 use super::logger::BufferedWatchLogger;
+use super::manager::ActiveWatch;
 use super::manager::WATCH_MANAGER;
 use super::manager::WatchInfo;
+use super::summary::WatchStopReason;
+use super::summary::WatchSummary;
 use crate::brp_tools::BrpClient;
 use crate::brp_tools::Port;
 use crate::error::Error;
@@ -158,7 +170,7 @@ async fn parse_sse_line(
 
     // Extract the result from JSON-RPC response
     if let Some(result) = data.get(JSON_RPC_RESULT_FIELD) {
-        log_update(logger, result.clone()).await?;
+        log_update(logger, watch_type, result.clone()).await?;
     } else {
         debug!("[{watch_type}] No result in JSON-RPC response: {data:?}");
 
@@ -179,8 +191,33 @@ async fn parse_sse_line(
 }
 
 /// Log a watch update with error handling
-async fn log_update(logger: &BufferedWatchLogger, result: Value) -> Result<()> {
-    if let Err(e) = logger.write_update(COMPONENT_UPDATE_EVENT, result).await {
+///
+/// Event watch updates carry a batch of messages, each logged as its own record. Diagnostics
+/// watch updates are logged as one sample record each.
+async fn log_update(logger: &BufferedWatchLogger, watch_type: &str, result: Value) -> Result<()> {
+    let written = if watch_type == DIAGNOSTICS_WATCH_TYPE {
+        logger.write_update(DIAGNOSTICS_SAMPLE_EVENT, result).await
+    } else if watch_type == EVENTS_WATCH_TYPE {
+        let messages = match result {
+            Value::Object(mut update) => match update.remove(UPDATE_EVENTS_FIELD) {
+                Some(Value::Array(messages)) => messages,
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+        let mut written = Ok(());
+        for message in messages {
+            written = logger.write_update(MESSAGE_EVENT, message).await;
+            if written.is_err() {
+                break;
+            }
+        }
+        written
+    } else {
+        logger.write_update(COMPONENT_UPDATE_EVENT, result).await
+    };
+
+    if let Err(e) = written {
         error!("Failed to write watch update to log: {e}");
         return Err(error_stack::Report::new(Error::failed_to(
             "write watch update to log",
@@ -418,6 +455,7 @@ async fn consume_stream_chunks(
     let mut line_buffer = String::new();
     let mut total_buffer_size = 0;
     let mut total_chunks = 0;
+    let mut stream_error = None;
 
     while let Some(chunk) = stream.next().await {
         match chunk {
@@ -440,6 +478,7 @@ async fn consume_stream_chunks(
                 .await?;
             },
             Err(e) => {
+                stream_error = Some(e.to_string());
                 handle_stream_error(e, entity_id, watch_type, logger, start_time, total_chunks)
                     .await;
                 break;
@@ -471,6 +510,11 @@ async fn consume_stream_chunks(
         )
         .await;
 
+    if let Some(error) = stream_error {
+        return Err(error_stack::Report::new(Error::BrpCommunication(format!(
+            "Watch stream interrupted after {total_chunks} chunks: {error}"
+        ))));
+    }
     Ok(total_chunks)
 }
 
@@ -500,8 +544,14 @@ async fn handle_connection_error(
         .await;
 }
 
-/// Run the watch connection in a spawned task
-async fn run_watch_connection(conn_params: WatchConnectionParams, logger: BufferedWatchLogger) {
+/// Run the watch connection in a spawned task until the stream ends or the watch is stopped
+///
+/// Returns the summary that was written as the log's final record.
+async fn run_watch_connection(
+    conn_params: WatchConnectionParams,
+    logger: BufferedWatchLogger,
+    stop_rx: oneshot::Receiver<WatchStopReason>,
+) -> WatchSummary {
     info!(
         "Starting {} watch task for entity {} on port {}",
         conn_params.kind, conn_params.entity_id, conn_params.port
@@ -510,6 +560,53 @@ async fn run_watch_connection(conn_params: WatchConnectionParams, logger: Buffer
     // Track start time for timeout detection
     let start_time = std::time::Instant::now();
 
+    let (reason, error) = tokio::select! {
+        error = watch_stream(&conn_params, &logger, start_time) => (
+            WatchStopReason::for_ended_stream(conn_params.port, error.as_deref()),
+            error,
+        ),
+        reason = stop_rx => (reason.unwrap_or(WatchStopReason::Stopped), None),
+    };
+
+    // Write the summary as the final log entry
+    let summary = logger.summary(reason, start_time.elapsed(), error);
+    let _ = logger
+        .write_update(
+            WATCH_ENDED_EVENT,
+            serde_json::to_value(&summary).unwrap_or_default(),
+        )
+        .await;
+    logger.finish().await;
+
+    // A watch ended by the manager was already removed
+    if !reason.ended_by_manager() {
+        let mut manager = WATCH_MANAGER.lock().await;
+        if manager
+            .active_watches
+            .remove(&conn_params.watch_id)
+            .is_some()
+        {
+            info!(
+                "Watch {} for entity {} automatically cleaned up after connection ended",
+                conn_params.watch_id, conn_params.entity_id
+            );
+        } else {
+            warn!(
+                "Watch {} for entity {} attempted to clean up but was not found in active watches - possible phantom watch removal",
+                conn_params.watch_id, conn_params.entity_id
+            );
+        }
+    }
+
+    summary
+}
+
+/// Connect and process the watch stream until it ends, returning the error that ended it
+async fn watch_stream(
+    conn_params: &WatchConnectionParams,
+    logger: &BufferedWatchLogger,
+    start_time: Instant,
+) -> Option<String> {
     // Create BRP client
     let brp_client = BrpClient::new(
         conn_params.brp_method,
@@ -538,76 +635,59 @@ async fn run_watch_connection(conn_params: WatchConnectionParams, logger: Buffer
                 )
                 .await;
 
-            if let Err(e) = process_watch_stream(
+            process_watch_stream(
                 response,
                 conn_params.entity_id,
                 &conn_params.kind,
-                &logger,
+                logger,
                 start_time,
             )
             .await
-            {
+            .err()
+            .map(|e| {
                 error!("Watch stream processing failed: {e}");
-            }
+                e.current_context().to_string()
+            })
         },
         Err(e) => {
-            handle_connection_error(e, &conn_params, &logger, start_time).await;
+            let error = e.current_context().to_string();
+            handle_connection_error(e, conn_params, logger, start_time).await;
+            Some(error)
         },
     }
-
-    // Write final log entry
-    let _ = logger
-        .write_update(
-            WATCH_ENDED_EVENT,
-            serde_json::json!({
-                ParameterName::Entity: conn_params.entity_id,
-                TIMESTAMP_FIELD: chrono::Local::now().to_rfc3339()
-            }),
-        )
-        .await;
-
-    // Remove this watch from the active watches with defensive checks
-    {
-        let mut manager = WATCH_MANAGER.lock().await;
-        if manager
-            .active_watches
-            .remove(&conn_params.watch_id)
-            .is_some()
-        {
-            info!(
-                "Watch {} for entity {} automatically cleaned up after connection ended",
-                conn_params.watch_id, conn_params.entity_id
-            );
-        } else {
-            warn!(
-                "Watch {} for entity {} attempted to clean up but was not found in active watches - possible phantom watch removal",
-                conn_params.watch_id, conn_params.entity_id
-            );
-        }
-    }
 }
 
 /// Generic function to start a watch task
+///
+/// Watches of no particular entity, like event and diagnostics watches, pass `None` as
+/// `watched_entity`.
 async fn start_watch_task(
-    entity_id: u64,
+    watched_entity: Option<u64>,
     watch_type: &str,
     brp_method: BrpMethod,
     params: Value,
+    field_filter: Option<FieldFilter>,
+    ttl: Option<Duration>,
+    notify: bool,
     port: Port,
 ) -> Result<(u32, PathBuf)> {
     // Prepare all data that doesn't require the watch_id
+    let entity_id = watched_entity.unwrap_or(NO_WATCHED_ENTITY);
     let watch_type_owned = watch_type.to_string();
     let brp_method_owned = brp_method;
 
     // Perform all operations within a single lock to ensure atomicity
     let mut manager = WATCH_MANAGER.lock().await;
+    manager.reserve_watch()?;
 
     // Generate ID while holding the lock
     let watch_id = manager.next_id();
 
     // Create log path and logger
     let log_path = BufferedWatchLogger::get_watch_log_path(watch_id, entity_id, watch_type);
-    let buffered_watch_logger = BufferedWatchLogger::new(log_path.clone());
+    let buffered_watch_logger =
+        BufferedWatchLogger::new(log_path.clone(), watch_id, entity_id, notify)
+            .with_field_filter(field_filter);
 
     // Create initial log entry
     let log_data = match params.clone() {
@@ -633,11 +713,12 @@ async fn start_watch_task(
 
     if let Err(e) = log_result {
         return Err(error_stack::Report::new(Error::WatchOperation(format!(
-            "Failed to log initial entry for entity {entity_id}: {e}"
+            "Failed to log initial entry for {watch_type} watch: {e}"
         ))));
     }
 
     // Spawn task
+    let (stop_tx, stop_rx) = oneshot::channel();
     let handle = tokio::spawn(run_watch_connection(
         WatchConnectionParams {
             watch_id,
@@ -648,21 +729,25 @@ async fn start_watch_task(
             port,
         },
         buffered_watch_logger,
+        stop_rx,
     ));
 
     // Register immediately while still holding the lock
     manager.active_watches.insert(
         watch_id,
-        (
-            WatchInfo {
-                id: watch_id,
-                entity_id,
-                kind: watch_type.to_string(),
-                log_path: log_path.clone(),
+        ActiveWatch {
+            info: WatchInfo::new(
+                watch_id,
+                watched_entity,
+                watch_type.to_string(),
+                log_path.clone(),
                 port,
-            },
+                ttl,
+                notify,
+            ),
             handle,
-        ),
+            stop_tx,
+        },
     );
 
     // Release lock by dropping manager
@@ -675,6 +760,9 @@ async fn start_watch_task(
 pub(super) async fn start_entity_watch_task(
     entity_id: u64,
     components: Option<Vec<String>>,
+    field_filter: Option<FieldFilter>,
+    ttl: Option<Duration>,
+    notify: bool,
     port: Port,
 ) -> Result<(u32, PathBuf)> {
     // Validate components parameter
@@ -696,26 +784,95 @@ pub(super) async fn start_entity_watch_task(
     });
 
     start_watch_task(
-        entity_id,
+        Some(entity_id),
         "get",
         BrpMethod::WorldGetComponentsWatch,
         params,
+        field_filter,
+        ttl,
+        notify,
         port,
     )
     .await
 }
 
 /// Start a background task for entity list watching
-pub(super) async fn start_list_watch_task(entity_id: u64, port: Port) -> Result<(u32, PathBuf)> {
+pub(super) async fn start_list_watch_task(
+    entity_id: u64,
+    ttl: Option<Duration>,
+    notify: bool,
+    port: Port,
+) -> Result<(u32, PathBuf)> {
     let params = serde_json::json!({
         ENTITY_FIELD: entity_id
     });
 
     start_watch_task(
-        entity_id,
+        Some(entity_id),
         "list",
         BrpMethod::WorldListComponentsWatch,
         params,
+        None,
+        ttl,
+        notify,
+        port,
+    )
+    .await
+}
+
+/// Start a background task for event watching
+pub(super) async fn start_events_watch_task(
+    types: Vec<String>,
+    ttl: Option<Duration>,
+    notify: bool,
+    port: Port,
+) -> Result<(u32, PathBuf)> {
+    let params = if types.is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_json::json!({ ParameterName::Types: types })
+    };
+
+    start_watch_task(
+        None,
+        EVENTS_WATCH_TYPE,
+        BrpMethod::WorldEventsWatch,
+        params,
+        None,
+        ttl,
+        notify,
+        port,
+    )
+    .await
+}
+
+/// Start a background task for diagnostics sampling
+pub(super) async fn start_diagnostics_watch_task(
+    every_n_frames: Option<u32>,
+    paths: Vec<String>,
+    ttl: Option<Duration>,
+    notify: bool,
+    port: Port,
+) -> Result<(u32, PathBuf)> {
+    let mut params = serde_json::Map::new();
+    if let Some(every_n_frames) = every_n_frames {
+        params.insert(
+            String::from(ParameterName::EveryNFrames),
+            serde_json::json!(every_n_frames),
+        );
+    }
+    if !paths.is_empty() {
+        params.insert(String::from(ParameterName::Paths), serde_json::json!(paths));
+    }
+
+    start_watch_task(
+        None,
+        DIAGNOSTICS_WATCH_TYPE,
+        BrpMethod::BrpExtrasDiagnosticsWatch,
+        Value::Object(params),
+        None,
+        ttl,
+        notify,
         port,
     )
     .await
@@ -0,0 +1,60 @@
+//! Start watching the messages an app writes
+
+use std::time::Duration;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::task;
+use super::watch_start_result::WatchStartResult;
+use super::wrap_watch_error;
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct EventsWatchParams {
+    /// Message type paths to watch, e.g. `["bevy_input::keyboard::KeyboardInput"]` (default:
+    /// every type the app lets BRP watch)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types:       Vec<String>,
+    /// Seconds until the watch stops on its own (default: runs until stopped, or until its log
+    /// goes unread for 30 minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+    /// Notify the MCP client with a resource update each time records are written to the watch log
+    /// (default: false)
+    #[serde(default)]
+    pub notify:      bool,
+    /// The BRP port (default: 15702)
+    #[serde(default)]
+    pub port:        Port,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "EventsWatchParams", output = "WatchStartResult")]
+pub struct WorldEventsWatch;
+
+async fn handle_impl(params: EventsWatchParams) -> Result<WatchStartResult> {
+    let result = task::start_events_watch_task(
+        params.types,
+        params.ttl_seconds.map(Duration::from_secs),
+        params.notify,
+        params.port,
+    )
+    .await
+    .map_err(|e| wrap_watch_error::wrap_watch_error("Failed to start event watch", None, e));
+
+    result
+        .map(|(watch_id, log_path)| {
+            WatchStartResult::new(watch_id, log_path.to_string_lossy().to_string())
+        })
+        .map_err(|error| Error::tool_call_failed(error.to_string()).into())
+}
@@ -1,11 +1,14 @@
 //! Start watching an entity for component changes
 
+use std::time::Duration;
+
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ToolFn;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::field_filter::FieldFilter;
 use super::task;
 use super::watch_start_result::WatchStartResult;
 use super::wrap_watch_error;
@@ -20,13 +23,29 @@ use crate::tool::ToolResult;
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct GetComponentsWatchParams {
     /// The entity ID to watch for component changes
-    pub entity: u64,
+    pub entity:      u64,
     /// Required array of component types to watch. Must contain at least one component. Without
     /// this, the watch will not detect any changes.
-    pub types:  Vec<String>,
+    pub types:       Vec<String>,
+    /// Only log a component when one of these fields changes, e.g. `[".translation.x"]`. Paths
+    /// use mutation path syntax, and `.x`, `.y`, `.z` and `.w` also index vectors. Components
+    /// none of the paths exist in are logged on every change.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths:       Vec<String>,
+    /// Smallest change of a numeric field in `paths` that is logged (default: 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epsilon:     Option<f64>,
+    /// Seconds until the watch stops on its own (default: runs until stopped, or until its log
+    /// goes unread for 30 minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+    /// Notify the MCP client with a resource update each time records are written to the watch log
+    /// (default: false)
+    #[serde(default)]
+    pub notify:      bool,
     /// The BRP port (default: 15702)
     #[serde(default)]
-    pub port:   Port,
+    pub port:        Port,
 }
 
 #[derive(ToolFn)]
@@ -34,16 +53,21 @@ pub struct GetComponentsWatchParams {
 pub struct WorldGetComponentsWatch;
 
 async fn handle_impl(params: GetComponentsWatchParams) -> Result<WatchStartResult> {
+    let field_filter = FieldFilter::from_params(&params.paths, params.epsilon)?;
+
     // Start the watch task
-    let result = task::start_entity_watch_task(params.entity, Some(params.types), params.port)
-        .await
-        .map_err(|e| {
-            wrap_watch_error::wrap_watch_error(
-                "Failed to start entity watch",
-                Some(params.entity),
-                e,
-            )
-        });
+    let result = task::start_entity_watch_task(
+        params.entity,
+        Some(params.types),
+        field_filter,
+        params.ttl_seconds.map(Duration::from_secs),
+        params.notify,
+        params.port,
+    )
+    .await
+    .map_err(|e| {
+        wrap_watch_error::wrap_watch_error("Failed to start entity watch", Some(params.entity), e)
+    });
 
     result
         .map(|(watch_id, log_path)| {
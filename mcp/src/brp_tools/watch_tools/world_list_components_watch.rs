@@ -1,5 +1,7 @@
 //! Start watching an entity for component list changes
 
+use std::time::Duration;
+
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ToolFn;
 use schemars::JsonSchema;
@@ -20,10 +22,18 @@ use crate::tool::ToolResult;
 #[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
 pub struct ListComponentsWatchParams {
     /// The entity ID to watch for component list changes
-    pub entity: u64,
+    pub entity:      u64,
+    /// Seconds until the watch stops on its own (default: runs until stopped, or until its log
+    /// goes unread for 30 minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+    /// Notify the MCP client with a resource update each time records are written to the watch log
+    /// (default: false)
+    #[serde(default)]
+    pub notify:      bool,
     /// The BRP port (default: 15702)
     #[serde(default)]
-    pub port:   Port,
+    pub port:        Port,
 }
 
 #[derive(ToolFn)]
@@ -32,11 +42,16 @@ pub struct BevyListWatch;
 
 async fn handle_impl(params: ListComponentsWatchParams) -> Result<WatchStartResult> {
     // Start the watch task
-    let result = task::start_list_watch_task(params.entity, params.port)
-        .await
-        .map_err(|e| {
-            wrap_watch_error::wrap_watch_error("Failed to start list watch", Some(params.entity), e)
-        });
+    let result = task::start_list_watch_task(
+        params.entity,
+        params.ttl_seconds.map(Duration::from_secs),
+        params.notify,
+        params.port,
+    )
+    .await
+    .map_err(|e| {
+        wrap_watch_error::wrap_watch_error("Failed to start list watch", Some(params.entity), e)
+    });
 
     result
         .map(|(watch_id, log_path)| {
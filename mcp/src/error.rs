@@ -6,6 +6,7 @@ use serde_json::Value;
 use thiserror::Error;
 
 use super::tool::ResultStruct;
+use crate::brp_tools::Port;
 use crate::constants::MSG_FAILED_TO_PREFIX;
 use crate::constants::MSG_INVALID_PREFIX;
 use crate::constants::MSG_MISSING_PREFIX;
@@ -16,6 +17,14 @@ pub(crate) type Result<T> = core::result::Result<T, Report<Error>>;
 // Internal error types for detailed error categorization
 #[derive(Error)]
 pub(crate) enum Error {
+    #[error("App unreachable: {message}")]
+    AppUnreachable {
+        message:  String,
+        port:     Port,
+        /// Connection attempts made, including reconnects
+        attempts: u32,
+    },
+
     #[error("BRP communication failed: {0}")]
     BrpCommunication(String),
 
@@ -66,6 +75,10 @@ pub(crate) enum Error {
         details: Option<Value>,
     },
 
+    /// The request or its response was lost in transit, e.g. a timeout or a reset connection
+    #[error("BRP transport failed: {0}")]
+    Transport(String),
+
     #[error("Watch operation failed: {0}")]
     WatchOperation(String),
 }
@@ -73,6 +86,16 @@ pub(crate) enum Error {
 impl std::fmt::Debug for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::AppUnreachable {
+                message,
+                port,
+                attempts,
+            } => f
+                .debug_struct("AppUnreachable")
+                .field("message", message)
+                .field("port", port)
+                .field("attempts", attempts)
+                .finish(),
             Self::BrpCommunication(s) => f.debug_tuple("BrpCommunication").field(s).finish(),
             Self::FileOperation(s) => f.debug_tuple("FileOperation").field(s).finish(),
             Self::FileOrPathNotFound(s) => f.debug_tuple("FileOrPathNotFound").field(s).finish(),
@@ -105,6 +128,7 @@ impl std::fmt::Debug for Error {
                 .field("message", message)
                 .field("details", details)
                 .finish(),
+            Self::Transport(s) => f.debug_tuple("Transport").field(s).finish(),
             Self::WatchOperation(s) => f.debug_tuple("WatchOperation").field(s).finish(),
         }
     }
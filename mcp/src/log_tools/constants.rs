@@ -7,6 +7,13 @@ pub(super) const LOG_EXTENSION: &str = ".log";
 pub(super) const LOG_PREFIX: &str = "bevy_brp_mcp_";
 pub(super) const TRACE_LOG_FILENAME: &str = "bevy_brp_mcp_trace.log";
 
+// trace query constants
+/// Entries returned by `brp_query_trace` when no `limit` is given
+#[cfg(feature = "mcp-debug")]
+pub(super) const DEFAULT_TRACE_QUERY_LIMIT: usize = 200;
+/// Name of the span wrapping each tool call in the trace log
+pub(super) const TOOL_CALL_SPAN: &str = "tool_call";
+
 // tracing filter constants
 /// Third-party HTTP/transport crate name prefixes whose tracing events are
 /// suppressed because they are noise for BRP debugging.
@@ -6,11 +6,14 @@ mod delete_logs;
 mod get_trace_log_path;
 mod lazy_file_writer;
 mod list_logs;
+#[cfg(feature = "mcp-debug")]
+mod query_trace;
 mod read_log;
 #[cfg(feature = "mcp-debug")]
 mod set_tracing_level;
 mod support;
 mod tracing;
+mod watch_log;
 
 // Re-export tracing functionality for other modules
 pub use delete_logs::DeleteLogs;
@@ -19,6 +22,10 @@ pub use delete_logs::DeleteLogsParams;
 pub use get_trace_log_path::GetTraceLogPath;
 pub use list_logs::ListLogs;
 pub use list_logs::ListLogsParams;
+#[cfg(feature = "mcp-debug")]
+pub use query_trace::QueryTrace;
+#[cfg(feature = "mcp-debug")]
+pub use query_trace::QueryTraceParams;
 pub use read_log::ReadLog;
 pub use read_log::ReadLogParams;
 #[cfg(feature = "mcp-debug")]
@@ -26,3 +33,6 @@ pub use set_tracing_level::SetTracingLevel;
 #[cfg(feature = "mcp-debug")]
 pub use set_tracing_level::SetTracingLevelParams;
 pub use tracing::TracingLevel;
+pub use tracing::tool_call_span;
+pub use watch_log::WatchLog;
+pub use watch_log::WatchLogParams;
@@ -0,0 +1,301 @@
+//! Query the trace log by tool, correlation ID, level, and time range.
+//!
+//! Trace lines are written by `tracing_subscriber`'s text formatter:
+//! `<timestamp> <LEVEL> tool_call{tool=<name> correlation_id=<id>}: <target>: <file>:<line>:
+//! <message>`. Lines logged outside a tool call have no span, and lines that do not start with a
+//! timestamp continue the message of the entry before them.
+
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::TracingLevel;
+use super::constants::DEFAULT_TRACE_QUERY_LIMIT;
+use super::constants::TOOL_CALL_SPAN;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+/// Span prefix of a trace line, e.g. `tool_call{tool=world_query correlation_id=3}:`
+static SPAN_PREFIX_REGEX: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^(?:\w+\{[^}]*\}:)+ ").ok());
+
+/// Fields of the tool call span inside a span prefix
+static TOOL_CALL_SPAN_REGEX: LazyLock<Option<Regex>> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"{TOOL_CALL_SPAN}\{{tool=([^ }}]+) correlation_id=(\d+)\}}"
+    ))
+    .ok()
+});
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct QueryTraceParams {
+    /// Only entries logged while this tool ran (e.g. `world_query`)
+    #[to_metadata(skip_if_none)]
+    pub tool:           Option<String>,
+    /// Only entries logged by the tool call with this correlation ID
+    #[to_metadata(skip_if_none)]
+    pub correlation_id: Option<u64>,
+    /// Minimum severity to include: error, warn, info, debug, or trace
+    #[to_metadata(skip_if_none)]
+    pub level:          Option<String>,
+    /// Only entries at or after this RFC 3339 timestamp
+    #[to_metadata(skip_if_none)]
+    pub since:          Option<String>,
+    /// Only entries before this RFC 3339 timestamp
+    #[to_metadata(skip_if_none)]
+    pub until:          Option<String>,
+    /// Maximum number of entries to return, keeping the most recent (default: 200)
+    #[to_metadata(skip_if_none)]
+    pub limit:          Option<usize>,
+}
+
+/// One trace log entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// When the entry was logged, as written in the log
+    pub timestamp:      String,
+    /// Severity in lowercase
+    pub level:          String,
+    /// Tool that was running, if the entry was logged during a tool call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool:           Option<String>,
+    /// Correlation ID of that tool call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<u64>,
+    /// Module that logged the entry
+    pub target:         String,
+    /// Source file and line, when recorded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location:       Option<String>,
+    pub message:        String,
+}
+
+/// Result from querying the trace log
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct QueryTraceResult {
+    /// Matching entries in log order
+    #[to_result]
+    entries:          Vec<TraceEntry>,
+    /// Full path to the trace log file
+    #[to_metadata]
+    log_path:         String,
+    /// Number of entries that matched the filters
+    #[to_metadata]
+    matched_count:    usize,
+    /// Number of entries returned after applying `limit`
+    #[to_metadata]
+    returned_count:   usize,
+    /// Message template for formatting responses
+    #[to_message(
+        message_template = "Returned {returned_count} of {matched_count} matching trace entries"
+    )]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "QueryTraceParams", output = "QueryTraceResult")]
+pub struct QueryTrace;
+
+async fn handle_impl(params: QueryTraceParams) -> Result<QueryTraceResult> {
+    let filter = TraceFilter::try_from(&params)?;
+    let log_path = TracingLevel::get_trace_log_path();
+
+    let contents = match tokio::fs::read_to_string(&log_path).await {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(Error::io_failed("read trace log", &log_path, &error).into()),
+    };
+
+    let matching: Vec<TraceEntry> = parse_trace_log(&contents)
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+        .collect();
+    let matched_count = matching.len();
+    let limit = params.limit.unwrap_or(DEFAULT_TRACE_QUERY_LIMIT);
+    let entries: Vec<TraceEntry> = matching
+        .into_iter()
+        .skip(matched_count.saturating_sub(limit))
+        .collect();
+    let returned_count = entries.len();
+
+    Ok(QueryTraceResult::new(
+        entries,
+        log_path.to_string_lossy().to_string(),
+        matched_count,
+        returned_count,
+    ))
+}
+
+/// Validated filters from `QueryTraceParams`
+struct TraceFilter<'a> {
+    tool:           Option<&'a str>,
+    correlation_id: Option<u64>,
+    level:          Option<TracingLevel>,
+    since:          Option<DateTime<FixedOffset>>,
+    until:          Option<DateTime<FixedOffset>>,
+}
+
+impl<'a> TryFrom<&'a QueryTraceParams> for TraceFilter<'a> {
+    type Error = error_stack::Report<Error>;
+
+    fn try_from(params: &'a QueryTraceParams) -> Result<Self> {
+        let level = params
+            .level
+            .as_deref()
+            .map(TracingLevel::from_str)
+            .transpose()
+            .map_err(|error| Error::invalid("level", error))?;
+        let parse_time = |name: &str, value: Option<&str>| {
+            value
+                .map(DateTime::parse_from_rfc3339)
+                .transpose()
+                .map_err(|error| {
+                    Error::invalid(name, format!("expected an RFC 3339 timestamp: {error}"))
+                })
+        };
+
+        Ok(Self {
+            tool: params.tool.as_deref(),
+            correlation_id: params.correlation_id,
+            level,
+            since: parse_time("since", params.since.as_deref())?,
+            until: parse_time("until", params.until.as_deref())?,
+        })
+    }
+}
+
+impl TraceFilter<'_> {
+    fn matches(&self, entry: &TraceEntry) -> bool {
+        if self.tool.is_some() && entry.tool.as_deref() != self.tool {
+            return false;
+        }
+        if self.correlation_id.is_some() && entry.correlation_id != self.correlation_id {
+            return false;
+        }
+        if let Some(level) = self.level
+            && TracingLevel::from_str(&entry.level)
+                .is_ok_and(|entry_level| entry_level.code() > level.code())
+        {
+            return false;
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let Ok(timestamp) = DateTime::parse_from_rfc3339(&entry.timestamp) else {
+                return false;
+            };
+            if self.since.is_some_and(|since| timestamp < since)
+                || self.until.is_some_and(|until| timestamp >= until)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Parse trace log text into entries, folding continuation lines into the preceding message
+fn parse_trace_log(contents: &str) -> Vec<TraceEntry> {
+    let mut entries: Vec<TraceEntry> = Vec::new();
+    for line in contents.lines() {
+        if let Some(entry) = parse_trace_line(line) {
+            entries.push(entry);
+        } else if let Some(previous) = entries.last_mut() {
+            previous.message.push('\n');
+            previous.message.push_str(line);
+        }
+    }
+    entries
+}
+
+fn parse_trace_line(line: &str) -> Option<TraceEntry> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    DateTime::parse_from_rfc3339(timestamp).ok()?;
+    let (level, rest) = rest.trim_start().split_once(' ')?;
+    let level = TracingLevel::from_str(level).ok()?;
+
+    let (spans, rest) = SPAN_PREFIX_REGEX
+        .as_ref()
+        .and_then(|regex| regex.find(rest))
+        .map_or(("", rest), |prefix| rest.split_at(prefix.end()));
+    let (tool, correlation_id) = TOOL_CALL_SPAN_REGEX
+        .as_ref()
+        .and_then(|regex| regex.captures(spans))
+        .map_or((None, None), |captures| {
+            (
+                captures.get(1).map(|tool| tool.as_str().to_string()),
+                captures
+                    .get(2)
+                    .and_then(|correlation_id| correlation_id.as_str().parse().ok()),
+            )
+        });
+
+    let (target, rest) = rest.split_once(": ").unwrap_or(("", rest));
+    let (location, message) = match rest.split_once(": ") {
+        Some((location, message)) if is_source_location(location) => {
+            (Some(location.to_string()), message)
+        },
+        _ => (None, rest),
+    };
+
+    Some(TraceEntry {
+        timestamp: timestamp.to_string(),
+        level: level.as_str().to_string(),
+        tool,
+        correlation_id,
+        target: target.to_string(),
+        location,
+        message: message.to_string(),
+    })
+}
+
+/// `file:line` as written by the formatter's `with_file` and `with_line_number`
+fn is_source_location(text: &str) -> bool {
+    text.rsplit_once(':').is_some_and(|(file, line)| {
+        !file.is_empty() && !line.is_empty() && line.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceEntry;
+    use super::parse_trace_log;
+
+    const TRACE_LOG: &str = "\
+2026-10-16T09:00:00.000001Z  INFO bevy_brp_mcp::log_tools::tracing: src/log_tools/tracing.rs:171: Tracing level set to: info
+2026-10-16T09:00:01.000001Z  WARN tool_call{tool=world_query correlation_id=7}: bevy_brp_mcp::brp_tools::brp_client::client: src/brp_tools/brp_client/client.rs:88: BRP request failed
+  caused by: connection refused
+";
+
+    #[test]
+    fn parses_tool_call_spans_and_continuation_lines() {
+        let entries = parse_trace_log(TRACE_LOG);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].tool, None);
+        assert_eq!(entries[0].level, "info");
+        assert_eq!(
+            entries[1],
+            TraceEntry {
+                timestamp:      "2026-10-16T09:00:01.000001Z".to_string(),
+                level:          "warn".to_string(),
+                tool:           Some("world_query".to_string()),
+                correlation_id: Some(7),
+                target:         "bevy_brp_mcp::brp_tools::brp_client::client".to_string(),
+                location:       Some("src/brp_tools/brp_client/client.rs:88".to_string()),
+                message:        "BRP request failed\n  caused by: connection refused".to_string(),
+            }
+        );
+    }
+}
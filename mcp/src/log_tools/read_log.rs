@@ -3,15 +3,21 @@ use std::fs::Metadata;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
 
 use bevy_brp_mcp_macros::ParamStruct;
 use bevy_brp_mcp_macros::ResultStruct;
 use bevy_brp_mcp_macros::ToolFn;
+use chrono::DateTime;
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::TracingLevel;
 use super::support;
+use crate::brp_tools::mark_watch_log_read;
 use crate::error::Error;
 use crate::error::Result;
 use crate::tool::HandlerContext;
@@ -19,6 +25,14 @@ use crate::tool::HandlerResult;
 use crate::tool::ToolFn;
 use crate::tool::ToolResult;
 
+/// ANSI color sequences that Bevy's log formatter writes around levels and targets
+static ANSI_ESCAPE_REGEX: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*m").ok());
+
+/// Span prefix of a tracing line, e.g. `frame{n=12}:`
+static SPAN_PREFIX_REGEX: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^(?:\w+\{[^}]*\}:)+ ").ok());
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(from = "bool", into = "bool")]
 enum KeywordFilterMode {
@@ -62,9 +76,84 @@ pub struct ReadLogParams {
     /// Optional keyword to filter lines (case-insensitive)
     #[to_metadata(skip_if_none)]
     pub keyword:    Option<String>,
-    /// Optional number of lines to read from the end of file
+    /// Optional number of lines to read from the end of file; counts entries when parsing
     #[to_metadata(skip_if_none)]
     pub tail_lines: Option<u32>,
+    /// Return `{timestamp, level, target, message}` entries parsed from Bevy's tracing output
+    /// instead of raw text
+    #[to_metadata(skip_if_none)]
+    pub structured: Option<bool>,
+    /// Minimum severity to include: error, warn, info, debug, or trace
+    #[to_metadata(skip_if_none)]
+    pub level:      Option<String>,
+    /// Only entries whose target starts with this module path (e.g. `bevy_render`)
+    #[to_metadata(skip_if_none)]
+    pub target:     Option<String>,
+}
+
+/// One entry of Bevy's tracing output
+///
+/// Lines that do not start a tracing entry continue the message of the entry before them. Lines
+/// before the first tracing entry, such as the launch header and cargo output, form an entry with
+/// only a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LogEntry {
+    /// When the entry was logged, as written in the log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>,
+    /// Severity in lowercase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level:     Option<String>,
+    /// Module that logged the entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target:    Option<String>,
+    message:   String,
+    /// Original lines of the entry, returned when filtering without `structured`
+    #[serde(skip)]
+    raw:       String,
+}
+
+/// Log content: raw text, or parsed entries when `structured` is set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum LogContent {
+    Text(String),
+    Entries(Vec<LogEntry>),
+}
+
+impl LogContent {
+    fn len(&self) -> usize {
+        match self {
+            Self::Text(text) => text.lines().count(),
+            Self::Entries(entries) => entries.len(),
+        }
+    }
+}
+
+/// Level and target filters, which require parsing the log into entries
+struct EntryFilter<'a> {
+    level:  Option<TracingLevel>,
+    target: Option<&'a str>,
+}
+
+impl EntryFilter<'_> {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        if let Some(level) = self.level
+            && entry
+                .level
+                .as_deref()
+                .and_then(|entry_level| TracingLevel::from_str(entry_level).ok())
+                .is_none_or(|entry_level| entry_level.code() > level.code())
+        {
+            return false;
+        }
+        self.target.is_none_or(|target| {
+            entry
+                .target
+                .as_deref()
+                .is_some_and(|entry_target| entry_target.starts_with(target))
+        })
+    }
 }
 
 /// Result from reading a log file
@@ -88,12 +177,12 @@ pub struct ReadLogResult {
     #[serde(rename = "size_human")]
     #[to_metadata]
     human:               String,
-    /// Number of lines read
+    /// Number of lines read, or entries when parsing
     #[to_metadata]
     lines_read:          usize,
     /// The actual log content
     #[to_result]
-    content:             String,
+    content:             LogContent,
     /// Whether content was filtered by keyword
     #[to_metadata]
     filtered_by_keyword: KeywordFilterMode,
@@ -109,10 +198,6 @@ pub struct ReadLogResult {
 #[tool_fn(params = "ReadLogParams", output = "ReadLogResult")]
 pub struct ReadLog;
 
-#[allow(
-    clippy::unused_async,
-    reason = "ToolFn trait requires async handler signature"
-)]
 async fn handle_impl(params: ReadLogParams) -> Result<ReadLogResult> {
     // Convert tail_lines if provided
     let tail_lines = match params.tail_lines {
@@ -125,6 +210,18 @@ async fn handle_impl(params: ReadLogParams) -> Result<ReadLogResult> {
         None => None,
     };
 
+    let level = params
+        .level
+        .as_deref()
+        .map(TracingLevel::from_str)
+        .transpose()
+        .map_err(|error| Error::invalid("level", error))?;
+    let entry_filter = EntryFilter {
+        level,
+        target: params.target.as_deref(),
+    };
+    let structured = params.structured.unwrap_or(false);
+
     let filename = &params.filename;
     let keyword = params.keyword.as_deref();
     // Validate filename format for security
@@ -140,15 +237,37 @@ async fn handle_impl(params: ReadLogParams) -> Result<ReadLogResult> {
         return Err(Error::missing(&format!("log file '{filename}'")).into());
     }
 
-    // Read the log file
-    let (content, metadata) = read_log_file(&log_path, keyword, tail_lines)?;
+    // Reading a watch log keeps its watch from being stopped as idle
+    mark_watch_log_read(&log_path).await;
+
+    // Read the log file, parsing it into entries when they are requested or filtered
+    let (content, metadata) =
+        if structured || entry_filter.level.is_some() || entry_filter.target.is_some() {
+            let (entries, metadata) =
+                read_log_entries(&log_path, keyword, &entry_filter, tail_lines)?;
+            let content = if structured {
+                LogContent::Entries(entries)
+            } else {
+                LogContent::Text(
+                    entries
+                        .into_iter()
+                        .map(|entry| entry.raw)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            };
+            (content, metadata)
+        } else {
+            let (content, metadata) = read_log_file(&log_path, keyword, tail_lines)?;
+            (LogContent::Text(content), metadata)
+        };
 
     Ok(ReadLogResult::new(
         params.filename,
         log_path.display().to_string(),
         metadata.len(),
         support::format_bytes(metadata.len()),
-        content.lines().count(),
+        content.len(),
         content,
         keyword.map_or(KeywordFilterMode::Unfiltered, |_| {
             KeywordFilterMode::Filtered
@@ -199,3 +318,130 @@ fn read_log_file(
     let content = final_lines.join("\n");
     Ok((content, metadata))
 }
+
+/// Read the log as tracing entries, keeping those that match the keyword and filters
+fn read_log_entries(
+    path: &Path,
+    keyword: Option<&str>,
+    filter: &EntryFilter,
+    tail_entries: Option<usize>,
+) -> Result<(Vec<LogEntry>, Metadata)> {
+    let metadata =
+        std::fs::metadata(path).map_err(|e| Error::io_failed("get file metadata", path, &e))?;
+    let contents = std::fs::read(path).map_err(|e| Error::io_failed("read log file", path, &e))?;
+
+    let keyword = keyword.map(str::to_lowercase);
+    let mut entries: Vec<LogEntry> = parse_log_entries(&String::from_utf8_lossy(&contents))
+        .into_iter()
+        .filter(|entry| {
+            keyword
+                .as_deref()
+                .is_none_or(|keyword| entry.raw.to_lowercase().contains(keyword))
+                && filter.matches(entry)
+        })
+        .collect();
+
+    if let Some(tail_count) = tail_entries
+        && tail_count > 0
+    {
+        entries.drain(..entries.len().saturating_sub(tail_count));
+    }
+
+    Ok((entries, metadata))
+}
+
+/// Parse log text into entries, folding continuation lines into the preceding entry
+fn parse_log_entries(contents: &str) -> Vec<LogEntry> {
+    let mut entries: Vec<LogEntry> = Vec::new();
+    for line in contents.lines() {
+        let line = ANSI_ESCAPE_REGEX.as_ref().map_or_else(
+            || line.to_string(),
+            |regex| regex.replace_all(line, "").to_string(),
+        );
+
+        if let Some(entry) = parse_log_line(&line) {
+            entries.push(entry);
+        } else if let Some(previous) = entries.last_mut() {
+            previous.message.push('\n');
+            previous.message.push_str(&line);
+            previous.raw.push('\n');
+            previous.raw.push_str(&line);
+        } else {
+            entries.push(LogEntry {
+                timestamp: None,
+                level:     None,
+                target:    None,
+                message:   line.clone(),
+                raw:       line,
+            });
+        }
+    }
+    entries
+}
+
+/// Parse `<timestamp> <LEVEL> [spans:] <target>: <message>`, the format of Bevy's `LogPlugin`
+fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    DateTime::parse_from_rfc3339(timestamp).ok()?;
+    let (level, rest) = rest.trim_start().split_once(' ')?;
+    let level = TracingLevel::from_str(level).ok()?;
+
+    let rest = SPAN_PREFIX_REGEX
+        .as_ref()
+        .and_then(|regex| regex.find(rest))
+        .map_or(rest, |prefix| &rest[prefix.end()..]);
+    let (target, message) = rest.split_once(": ").unwrap_or(("", rest));
+
+    Some(LogEntry {
+        timestamp: Some(timestamp.to_string()),
+        level:     Some(level.as_str().to_string()),
+        target:    Some(target.to_string()),
+        message:   message.to_string(),
+        raw:       line.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntryFilter;
+    use super::TracingLevel;
+    use super::parse_log_entries;
+
+    const APP_LOG: &str = "\
+=== Bevy BRP MCP Launch Log ===
+\x1b[2m2026-10-16T09:00:00.000001Z\x1b[0m \x1b[32m INFO\x1b[0m \x1b[2mbevy_render::renderer\x1b[0m\x1b[2m:\x1b[0m AdapterInfo { name: \"gpu\" }
+2026-10-16T09:00:01.000001Z  WARN frame{n=3}: my_game::player: Player fell: out of bounds
+  at (0, -10, 0)
+";
+
+    #[test]
+    fn parses_entries_spans_and_continuation_lines() {
+        let entries = parse_log_entries(APP_LOG);
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].level, None);
+        assert_eq!(entries[0].message, "=== Bevy BRP MCP Launch Log ===");
+        assert_eq!(entries[1].level.as_deref(), Some("info"));
+        assert_eq!(entries[1].target.as_deref(), Some("bevy_render::renderer"));
+        assert_eq!(entries[1].message, "AdapterInfo { name: \"gpu\" }");
+        assert_eq!(entries[2].target.as_deref(), Some("my_game::player"));
+        assert_eq!(
+            entries[2].message,
+            "Player fell: out of bounds\n  at (0, -10, 0)"
+        );
+
+        let warnings = EntryFilter {
+            level:  Some(TracingLevel::Warn),
+            target: Some("my_game"),
+        };
+        let matching: Vec<_> = entries
+            .iter()
+            .filter(|entry| warnings.matches(entry))
+            .collect();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(
+            matching[0].timestamp.as_deref(),
+            Some("2026-10-16T09:00:01.000001Z")
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::AtomicU8;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 
 use tracing::Level;
 use tracing::Metadata;
+use tracing::Span;
 use tracing::Subscriber;
 use tracing_subscriber::Layer;
 use tracing_subscriber::Registry;
@@ -12,12 +14,22 @@ use tracing_subscriber::layer::Context;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
+use super::constants::TOOL_CALL_SPAN;
 use super::constants::TRACE_LOG_FILENAME;
 use super::constants::TRACING_FILTERED_TARGET_PREFIXES;
 use super::lazy_file_writer::LazyFileWriter;
 
 static CURRENT_LEVEL: AtomicU8 = AtomicU8::new(TracingLevel::Warn.code()); // Default to WARN level for "do no harm"
 
+/// Correlation ID handed to the next tool call
+static NEXT_CORRELATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Span for one tool call, so every trace line it logs carries the tool name and a correlation ID
+pub fn tool_call_span(tool: &str) -> Span {
+    let correlation_id = NEXT_CORRELATION_ID.fetch_add(1, Ordering::Relaxed);
+    tracing::info_span!(TOOL_CALL_SPAN, tool = %tool, correlation_id)
+}
+
 /// Dynamic tracing filter that can be updated at runtime
 #[derive(Clone)]
 struct DynamicFilter;
@@ -36,6 +48,11 @@ where
             return false;
         }
 
+        // Spans only add context to the lines logged inside them, so keep them at every level
+        if metadata.is_span() {
+            return true;
+        }
+
         let current_level = TracingLevel::from(CURRENT_LEVEL.load(Ordering::Relaxed));
         let metadata_level = TracingLevel::from(*metadata.level());
         metadata_level.code() <= current_level.code()
@@ -94,7 +111,7 @@ impl From<Level> for TracingLevel {
 }
 
 impl TracingLevel {
-    const fn code(self) -> u8 {
+    pub(super) const fn code(self) -> u8 {
         match self {
             Self::Error => 0,
             Self::Warn => 1,
@@ -104,7 +121,6 @@ impl TracingLevel {
         }
     }
 
-    #[cfg(feature = "mcp-debug")]
     pub const fn as_str(self) -> &'static str {
         match self {
             Self::Error => "error",
@@ -0,0 +1,98 @@
+//! Start tailing a launched app's log file
+
+use std::time::Duration;
+
+use bevy_brp_mcp_macros::ParamStruct;
+use bevy_brp_mcp_macros::ResultStruct;
+use bevy_brp_mcp_macros::ToolFn;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::support;
+use crate::brp_tools::Port;
+use crate::brp_tools::start_log_watch_task;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::HandlerContext;
+use crate::tool::HandlerResult;
+use crate::tool::ToolFn;
+use crate::tool::ToolResult;
+
+#[derive(Clone, Deserialize, Serialize, JsonSchema, ParamStruct)]
+pub struct WatchLogParams {
+    /// The log filename (e.g., `bevy_brp_mcp_myapp_port15702_1234567890.log`)
+    pub filename:    String,
+    /// Optional keyword to filter lines (case-insensitive)
+    #[to_metadata(skip_if_none)]
+    pub keyword:     Option<String>,
+    /// Seconds until the watch stops on its own (default: runs until stopped, or until its log
+    /// goes unread for 30 minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+    /// Notify the MCP client with a resource update each time records are written to the watch log
+    /// (default: false)
+    #[serde(default)]
+    pub notify:      bool,
+    /// The BRP port of the app writing the log; the watch ends when that app exits (default:
+    /// 15702)
+    #[serde(default)]
+    pub port:        Port,
+}
+
+/// Result from starting a log watch
+#[derive(Debug, Clone, Serialize, Deserialize, ResultStruct)]
+pub struct WatchLogResult {
+    /// Watch ID, used with `brp_stop_watch`
+    #[to_metadata]
+    watch_id:         u32,
+    /// The app log being tailed
+    #[to_metadata]
+    filename:         String,
+    /// Watch log receiving the new lines
+    #[to_metadata]
+    log_path:         String,
+    /// Message template for formatting responses
+    #[to_message(message_template = "Started watch {watch_id} on {filename}")]
+    message_template: String,
+}
+
+#[derive(ToolFn)]
+#[tool_fn(params = "WatchLogParams", output = "WatchLogResult")]
+pub struct WatchLog;
+
+async fn handle_impl(params: WatchLogParams) -> Result<WatchLogResult> {
+    // Validate filename format for security
+    if !support::is_valid_log_filename(&params.filename) {
+        return Err(
+            Error::invalid("filename", "only bevy_brp_mcp log files can be watched").into(),
+        );
+    }
+
+    let app_log = support::get_log_file_path(&params.filename);
+    if !app_log.exists() {
+        return Err(Error::missing(&format!("log file '{}'", params.filename)).into());
+    }
+
+    let (watch_id, log_path) = start_log_watch_task(
+        app_log,
+        params.keyword,
+        params.ttl_seconds.map(Duration::from_secs),
+        params.notify,
+        params.port,
+    )
+    .await
+    .map_err(|error| {
+        Error::tool_call_failed(format!(
+            "Failed to start log watch for {}: {}",
+            params.filename,
+            error.current_context()
+        ))
+    })?;
+
+    Ok(WatchLogResult::new(
+        watch_id,
+        params.filename,
+        log_path.to_string_lossy().to_string(),
+    ))
+}
@@ -12,6 +12,7 @@ use log_tools::TracingLevel;
 use mcp_service::McpService;
 use rmcp::ServiceExt;
 use rmcp::transport;
+use tool::ToolAccess;
 
 mod app_tools;
 mod brp_tools;
@@ -19,6 +20,7 @@ mod constants;
 mod error;
 mod log_tools;
 mod mcp_service;
+mod prompts;
 mod support;
 mod tool;
 
@@ -28,10 +30,22 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Uses lazy file creation - file only created on first log write
     TracingLevel::init_file_tracing();
 
-    let mcp_service = McpService::new();
+    // Operators can hide tools, e.g. every tool that changes the app for read-only sessions
+    let access = ToolAccess::from_args(std::env::args().skip(1))
+        .map_err(|report| report.current_context().to_string())?;
+
+    // Reclaim apps and logs leaked by crashed sessions before accepting requests
+    let orphan_report = app_tools::cleanup_orphaned_sessions().summary();
+    if let Some(summary) = &orphan_report {
+        tracing::info!("{summary}");
+    }
+
+    let mcp_service = McpService::new(orphan_report, &access);
 
     let server = mcp_service.serve(transport::stdio()).await?;
     server.waiting().await?;
 
+    app_tools::end_session();
+
     Ok(())
 }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use itertools::Itertools;
 use rmcp::ErrorData as McpError;
@@ -6,15 +7,30 @@ use rmcp::RoleServer;
 use rmcp::ServerHandler;
 use rmcp::model::CallToolRequestParams;
 use rmcp::model::CallToolResult;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
+use rmcp::model::ListPromptsResult;
+use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::ReadResourceRequestParams;
+use rmcp::model::ReadResourceResult;
+use rmcp::model::ResourceContents;
 use rmcp::model::ServerCapabilities;
 use rmcp::model::ServerInfo;
+use rmcp::model::SubscribeRequestParams;
 use rmcp::model::Tool;
+use rmcp::model::UnsubscribeRequestParams;
+use rmcp::service::NotificationContext;
 use rmcp::service::RequestContext;
+use strum::IntoEnumIterator;
 
+use super::brp_tools;
+use super::prompts::WorkflowPrompt;
 use super::tool;
+use super::tool::ToolAccess;
 use super::tool::ToolDef;
+use super::tool::ToolName;
 
 /// MCP service implementation for Bevy Remote Protocol integration.
 ///
@@ -22,14 +38,21 @@ use super::tool::ToolDef;
 /// including entity manipulation, component management, and resource access.
 pub(crate) struct McpService {
     /// Tool definitions `HashMap` for O(1) lookup by name
-    tool_defs: HashMap<String, ToolDef>,
+    tool_defs:      HashMap<String, ToolDef>,
     /// Pre-converted MCP tools for list operations
-    tools:     Vec<Tool>,
+    tools:          Vec<Tool>,
+    /// Orphan cleanup summary from startup, surfaced as server instructions
+    startup_report: Option<String>,
 }
 
 impl McpService {
-    pub(crate) fn new() -> Self {
-        let all_defs = tool::get_all_tool_definitions();
+    pub(crate) fn new(startup_report: Option<String>, access: &ToolAccess) -> Self {
+        // Tools the operator disabled are neither listed nor callable, even from other tools
+        access.install();
+        let all_defs: Vec<ToolDef> = tool::get_all_tool_definitions()
+            .into_iter()
+            .filter(|tool_def| access.allows(tool_def))
+            .collect();
 
         // Build the `ToolDef` lookup table.
         let tool_defs = all_defs
@@ -50,29 +73,65 @@ impl McpService {
             })
             .collect();
 
-        Self { tool_defs, tools }
+        Self {
+            tool_defs,
+            tools,
+            startup_report,
+        }
     }
 
     /// Get tool definition by name with O(1) lookup
     fn get_tool_def(&self, name: &str) -> Option<&ToolDef> { self.tool_defs.get(name) }
 
-    /// List all MCP tools using pre-converted and sorted tools
+    /// List MCP tools using pre-converted and sorted tools
+    ///
+    /// Extras tools whose method no app with known capabilities registers are left out.
     fn list_mcp_tools(&self) -> ListToolsResult {
         ListToolsResult {
             meta:        None,
             next_cursor: None,
-            tools:       self.tools.clone(),
+            tools:       self
+                .tools
+                .iter()
+                .filter(|tool| !self.is_unsupported_extras_tool(&tool.name))
+                .cloned()
+                .collect(),
         }
     }
+
+    fn is_unsupported_extras_tool(&self, name: &str) -> bool {
+        self.get_tool_def(name)
+            .and_then(|tool_def| tool_def.tool_name.to_brp_method())
+            .is_some_and(|method| brp_tools::extras_method_unsupported(method.as_str()))
+    }
+
+    /// Workflows are only offered when every tool they call is enabled
+    fn offers_prompt(&self, prompt: WorkflowPrompt) -> bool {
+        prompt
+            .tools()
+            .iter()
+            .all(|tool| self.tool_defs.contains_key(tool.as_ref()))
+    }
 }
 
 impl ServerHandler for McpService {
     fn get_info(&self) -> ServerInfo {
         let mut info = rmcp::model::ServerInfo::default();
-        info.capabilities = ServerCapabilities::builder().enable_tools().build();
+        info.capabilities = ServerCapabilities::builder()
+            .enable_tools()
+            .enable_prompts()
+            .enable_resources()
+            .enable_resources_subscribe()
+            .build();
+        info.instructions.clone_from(&self.startup_report);
         info
     }
 
+    /// Keep the client's peer so watches can send it resource update notifications
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        brp_tools::set_client_peer(context.peer);
+    }
+
     async fn list_tools(
         &self,
         _: Option<PaginatedRequestParams>,
@@ -87,9 +146,103 @@ impl ServerHandler for McpService {
         _: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         let tool_def = self.get_tool_def(&request.name).ok_or_else(|| {
-            McpError::invalid_params(format!("unknown tool: {}", request.name), None)
+            if ToolName::from_str(&request.name).is_ok() {
+                McpError::invalid_params(
+                    format!(
+                        "tool {} is disabled by the server's --read-only, --allow, or --deny flags",
+                        request.name
+                    ),
+                    None,
+                )
+            } else {
+                McpError::invalid_params(format!("unknown tool: {}", request.name), None)
+            }
         })?;
 
         tool_def.call_tool(request).await
     }
+
+    /// Recommended tool workflows
+    async fn list_prompts(
+        &self,
+        _: Option<PaginatedRequestParams>,
+        _: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            meta:        None,
+            next_cursor: None,
+            prompts:     WorkflowPrompt::iter()
+                .filter(|prompt| self.offers_prompt(*prompt))
+                .map(WorkflowPrompt::to_prompt)
+                .collect(),
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        let prompt = WorkflowPrompt::from_str(&request.name)
+            .ok()
+            .filter(|prompt| self.offers_prompt(*prompt))
+            .ok_or_else(|| {
+                McpError::invalid_params(format!("unknown prompt: {}", request.name), None)
+            })?;
+        prompt
+            .render(request.arguments.as_ref())
+            .map_err(|e| McpError::invalid_params(e.current_context().to_string(), None))
+    }
+
+    /// Large tool results, published as resources when they are too big to return inline, and
+    /// the logs of active watches
+    async fn list_resources(
+        &self,
+        _: Option<PaginatedRequestParams>,
+        _: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let mut resources = tool::list_response_resources();
+        resources.extend(brp_tools::list_watch_resources().await);
+        Ok(ListResourcesResult {
+            meta: None,
+            next_cursor: None,
+            resources,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let text = if brp_tools::is_watch_resource(&request.uri) {
+            brp_tools::read_watch_resource(&request.uri).await
+        } else {
+            tool::read_response_resource(&request.uri)
+        }
+        .map_err(|e| McpError::resource_not_found(e.current_context().to_string(), None))?;
+        Ok(ReadResourceResult::new(vec![ResourceContents::text(
+            text,
+            request.uri,
+        )]))
+    }
+
+    /// Send update notifications for a watch log as records are written to it
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        _: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        brp_tools::subscribe_watch_resource(&request.uri)
+            .map_err(|e| McpError::resource_not_found(e.current_context().to_string(), None))
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        brp_tools::unsubscribe_watch_resource(&request.uri)
+            .map_err(|e| McpError::resource_not_found(e.current_context().to_string(), None))
+    }
 }
@@ -0,0 +1,231 @@
+//! Recommended tool workflows published as MCP prompts
+//!
+//! Agents new to this server tend to rediscover the same tool sequences, such as reading
+//! diagnostics before guessing at a frame rate problem. Each prompt names the tools of one
+//! workflow in its description, so clients can discover the sequence from `prompts/list`, and
+//! renders step-by-step instructions with the caller's arguments filled in. Prompts that use a
+//! tool hidden by `--read-only`, `--allow`, or `--deny` are not offered.
+
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
+use rmcp::model::Prompt;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::Role;
+use serde_json::Value;
+use strum::EnumIter;
+use strum::EnumString;
+use strum::IntoStaticStr;
+
+use crate::brp_tools::Port;
+use crate::error::Error;
+use crate::error::Result;
+use crate::tool::ToolName;
+
+/// A canned multi-step workflow
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum WorkflowPrompt {
+    DiagnoseLowFps,
+    VerifySpawnFormat,
+    InvestigateCrash,
+    ExploreApp,
+}
+
+/// An argument substituted for `{name}` in a prompt's text
+#[derive(Clone, Copy)]
+enum WorkflowArgument {
+    /// BRP port of the app, defaulting to the default port
+    Port,
+    /// Fully-qualified type name, required
+    TypeName,
+}
+
+impl WorkflowArgument {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Port => "port",
+            Self::TypeName => "type_name",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Port => "BRP port of the app (default: 15702)",
+            Self::TypeName => {
+                "Fully-qualified type name, e.g. bevy_transform::components::transform::Transform"
+            },
+        }
+    }
+
+    const fn required(self) -> bool { matches!(self, Self::TypeName) }
+
+    fn default_value(self) -> Option<String> {
+        match self {
+            Self::Port => Some(Port::default().to_string()),
+            Self::TypeName => None,
+        }
+    }
+
+    fn to_prompt_argument(self) -> PromptArgument {
+        let mut argument = PromptArgument::new(self.name());
+        argument.description = Some(self.description().to_string());
+        argument.required = Some(self.required());
+        argument
+    }
+}
+
+impl WorkflowPrompt {
+    const fn description(self) -> &'static str {
+        match self {
+            Self::DiagnoseLowFps => {
+                "Measure why an app runs at a low frame rate: diagnostics, render timings, an FPS \
+                 timeline, and entity growth."
+            },
+            Self::VerifySpawnFormat => {
+                "Find JSON that spawns a component type correctly by spawning, reading back, and \
+                 despawning a test entity."
+            },
+            Self::InvestigateCrash => {
+                "Find out why a launched app died, from its crash report and log, and relaunch it \
+                 with the fix built."
+            },
+            Self::ExploreApp => {
+                "Get oriented in an unfamiliar app: its targets, component usage, named entities, \
+                 and what is on screen."
+            },
+        }
+    }
+
+    const fn arguments(self) -> &'static [WorkflowArgument] {
+        match self {
+            Self::VerifySpawnFormat => &[WorkflowArgument::TypeName, WorkflowArgument::Port],
+            Self::DiagnoseLowFps | Self::InvestigateCrash | Self::ExploreApp => {
+                &[WorkflowArgument::Port]
+            },
+        }
+    }
+
+    /// Tools the workflow calls, in order
+    pub(crate) const fn tools(self) -> &'static [ToolName] {
+        match self {
+            Self::DiagnoseLowFps => &[
+                ToolName::BrpStatus,
+                ToolName::BrpExtrasGetDiagnostics,
+                ToolName::BrpExtrasDiagnosticsWatch,
+                ToolName::BrpReadLog,
+                ToolName::BrpStopWatch,
+                ToolName::BrpExtrasGetWorldStats,
+                ToolName::WorldComponentStats,
+                ToolName::BrpExtrasListSystems,
+            ],
+            Self::VerifySpawnFormat => &[
+                ToolName::BrpTypeGuide,
+                ToolName::WorldSpawnEntity,
+                ToolName::WorldGetComponents,
+                ToolName::WorldDespawnEntity,
+            ],
+            Self::InvestigateCrash => &[
+                ToolName::BrpStatus,
+                ToolName::BrpGetCrashReport,
+                ToolName::BrpReadLog,
+                ToolName::BrpLaunch,
+            ],
+            Self::ExploreApp => &[
+                ToolName::BrpListBevy,
+                ToolName::BrpLaunch,
+                ToolName::WorldComponentStats,
+                ToolName::WorldFindEntitiesByName,
+                ToolName::WorldGetComponents,
+                ToolName::BrpExtrasScreenshot,
+            ],
+        }
+    }
+
+    const fn text(self) -> &'static str {
+        match self {
+            Self::DiagnoseLowFps => include_str!("../prompt_text/diagnose_low_fps.txt"),
+            Self::VerifySpawnFormat => include_str!("../prompt_text/verify_spawn_format.txt"),
+            Self::InvestigateCrash => include_str!("../prompt_text/investigate_crash.txt"),
+            Self::ExploreApp => include_str!("../prompt_text/explore_app.txt"),
+        }
+    }
+
+    /// The prompt as listed by `prompts/list`, with its tool sequence in the description
+    pub(crate) fn to_prompt(self) -> Prompt {
+        let tools = self
+            .tools()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        Prompt::new(
+            <&'static str>::from(self),
+            Some(format!("{} Tools: {tools}", self.description())),
+            Some(
+                self.arguments()
+                    .iter()
+                    .map(|argument| argument.to_prompt_argument())
+                    .collect(),
+            ),
+        )
+    }
+
+    /// The workflow's instructions with `arguments` filled in
+    pub(crate) fn render(self, arguments: Option<&JsonObject>) -> Result<GetPromptResult> {
+        let mut text = self.text().to_string();
+        for argument in self.arguments() {
+            let value = arguments
+                .and_then(|arguments| arguments.get(argument.name()))
+                .map(|value| match value {
+                    Value::String(value) => value.clone(),
+                    other => other.to_string(),
+                })
+                .or_else(|| argument.default_value())
+                .ok_or_else(|| {
+                    Error::missing(&format!(
+                        "argument `{}` for prompt `{}`",
+                        argument.name(),
+                        <&'static str>::from(self)
+                    ))
+                })?;
+            text = text.replace(&format!("{{{}}}", argument.name()), &value);
+        }
+        Ok(
+            GetPromptResult::new(vec![PromptMessage::new_text(Role::User, text)])
+                .with_description(self.description()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::WorkflowPrompt;
+
+    #[test]
+    fn each_workflow_mentions_its_tools_and_fills_its_arguments() {
+        let arguments = serde_json::json!({ "type_name": "my_game::Health", "port": 15710 });
+        for prompt in WorkflowPrompt::iter() {
+            let text = prompt.text();
+            for tool in prompt.tools() {
+                assert!(
+                    text.contains(&tool.to_string()),
+                    "{prompt:?} does not mention {tool}"
+                );
+            }
+            let rendered = prompt.render(arguments.as_object());
+            assert!(rendered.is_ok(), "{prompt:?} failed to render");
+            let rendered = format!("{rendered:?}");
+            assert!(!rendered.contains("{port}") && !rendered.contains("{type_name}"));
+            assert!(rendered.contains("15710"));
+        }
+    }
+
+    #[test]
+    fn required_arguments_must_be_given() {
+        assert!(WorkflowPrompt::VerifySpawnFormat.render(None).is_err());
+        assert!(WorkflowPrompt::DiagnoseLowFps.render(None).is_ok());
+    }
+}
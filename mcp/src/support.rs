@@ -75,6 +75,8 @@ pub(crate) enum SchemaField {
     ReflectTypes,
     /// The `required` field for object types.
     Required,
+    /// The `requiredComponents` field of `componentInfo`.
+    RequiredComponents,
     /// The short path field.
     ShortPath,
     /// The type field.
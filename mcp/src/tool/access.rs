@@ -0,0 +1,240 @@
+//! Operator control over which tools the server exposes.
+//!
+//! Parsed from the server's command line flags, so read-only debugging sessions can hide every
+//! tool that changes the app:
+//! - `--read-only`: only expose tools annotated read-only
+//! - `--deny <selectors>`: hide matching tools
+//! - `--allow <selectors>`: only expose matching tools
+//!
+//! Selectors are comma-separated tool names (`brp_shutdown`), tool categories (`watch_monitoring`),
+//! or the groups `mutation` (every tool that is not read-only), `launch` and `shutdown`. Flags can
+//! be repeated, and deny wins over allow. Hidden tools are left out of `tools/list` and rejected
+//! when called.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use strum::IntoEnumIterator;
+
+use super::annotations::EnvironmentImpact;
+use super::annotations::ToolCategory;
+use super::def::ToolDef;
+use super::name::BrpMethod;
+use super::name::ToolName;
+use crate::error::Error;
+use crate::error::Result;
+
+const ALLOW_FLAG: &str = "--allow";
+const DENY_FLAG: &str = "--deny";
+const READ_ONLY_FLAG: &str = "--read-only";
+
+const MUTATION_GROUP: &str = "mutation";
+const LAUNCH_GROUP: &str = "launch";
+const SHUTDOWN_GROUP: &str = "shutdown";
+
+/// Access of the running server, checked when one tool calls another
+static SERVER_ACCESS: OnceLock<ToolAccess> = OnceLock::new();
+
+/// A set of tools named on the command line
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ToolSelector {
+    /// One tool, by name
+    Tool(ToolName),
+    /// Every tool in a category
+    Category(ToolCategory),
+    /// Every tool that is not read-only
+    Mutation,
+    /// Tools that start apps
+    Launch,
+    /// Tools that stop apps
+    Shutdown,
+}
+
+impl ToolSelector {
+    fn matches(&self, tool_def: &ToolDef) -> bool {
+        match self {
+            Self::Tool(tool_name) => tool_def.tool_name == *tool_name,
+            Self::Category(category) => tool_def.annotations.tool_category == *category,
+            Self::Mutation => {
+                tool_def.annotations.environment_impact != EnvironmentImpact::ReadOnly
+            },
+            Self::Launch => tool_def.tool_name == ToolName::BrpLaunch,
+            Self::Shutdown => tool_def.tool_name == ToolName::BrpShutdown,
+        }
+    }
+}
+
+impl FromStr for ToolSelector {
+    type Err = error_stack::Report<Error>;
+
+    fn from_str(selector: &str) -> Result<Self> {
+        match selector {
+            MUTATION_GROUP => return Ok(Self::Mutation),
+            LAUNCH_GROUP => return Ok(Self::Launch),
+            SHUTDOWN_GROUP => return Ok(Self::Shutdown),
+            _ => {},
+        }
+        if let Some(category) =
+            ToolCategory::iter().find(|category| category_selector(category) == selector)
+        {
+            return Ok(Self::Category(category));
+        }
+        ToolName::from_str(selector).map(Self::Tool).map_err(|_| {
+            Error::invalid(
+                "tool selector",
+                format!(
+                    "'{selector}' is not a tool name, a category ({}), or one of the groups \
+                     {MUTATION_GROUP}, {LAUNCH_GROUP}, {SHUTDOWN_GROUP}",
+                    ToolCategory::iter()
+                        .map(|category| category_selector(&category))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .into()
+        })
+    }
+}
+
+/// Selector name of a category: its display name in `snake_case`
+fn category_selector(category: &ToolCategory) -> String {
+    category.as_ref().to_lowercase().replace(' ', "_")
+}
+
+/// Which tools the operator lets the server expose
+#[derive(Clone, Debug, Default)]
+pub struct ToolAccess {
+    /// Only these tools are exposed, when given
+    allow: Option<Vec<ToolSelector>>,
+    /// These tools are never exposed
+    deny:  Vec<ToolSelector>,
+}
+
+impl ToolAccess {
+    /// Parse the access flags from the server's arguments, without the program name
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut access = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = arg
+                .split_once('=')
+                .map_or((arg.as_str(), None), |(flag, value)| (flag, Some(value)));
+            match flag {
+                READ_ONLY_FLAG if inline_value.is_none() => {
+                    access.deny.push(ToolSelector::Mutation);
+                },
+                ALLOW_FLAG | DENY_FLAG => {
+                    let value = match inline_value {
+                        Some(value) => value.to_string(),
+                        None => args
+                            .next()
+                            .ok_or_else(|| Error::missing(&format!("selectors after {flag}")))?,
+                    };
+                    let selectors = parse_selectors(&value)?;
+                    if flag == ALLOW_FLAG {
+                        access.allow.get_or_insert_default().extend(selectors);
+                    } else {
+                        access.deny.extend(selectors);
+                    }
+                },
+                _ => {
+                    return Err(Error::invalid(
+                        "argument",
+                        format!(
+                            "'{arg}'; expected {READ_ONLY_FLAG}, {ALLOW_FLAG} <selectors>, or \
+                             {DENY_FLAG} <selectors>"
+                        ),
+                    )
+                    .into());
+                },
+            }
+        }
+        Ok(access)
+    }
+
+    /// Whether the tool may be listed and called
+    pub fn allows(&self, tool_def: &ToolDef) -> bool {
+        self.allow
+            .as_ref()
+            .is_none_or(|allow| allow.iter().any(|selector| selector.matches(tool_def)))
+            && !self.deny.iter().any(|selector| selector.matches(tool_def))
+    }
+
+    /// Whether a BRP method may be sent directly, as `brp_run_test_plan` steps do. The method is
+    /// checked as the tool that sends it, or as `brp_execute` when no tool wraps it.
+    pub fn allows_brp_method(&self, method: &str) -> bool {
+        let tool_name = BrpMethod::from_str(method).map_or(ToolName::BrpExecute, ToolName::from);
+        self.allows(&tool_name.to_tool_def())
+    }
+
+    /// Apply this access to tools called by other tools, such as `brp_run_scenario` steps
+    pub fn install(&self) { let _ = SERVER_ACCESS.set(self.clone()); }
+}
+
+/// Whether the running server allows the tool. Everything is allowed until access is installed.
+pub(super) fn server_allows(tool_def: &ToolDef) -> bool {
+    SERVER_ACCESS
+        .get()
+        .is_none_or(|access| access.allows(tool_def))
+}
+
+/// The running server's access. Everything is allowed until access is installed.
+pub(super) fn server_access() -> ToolAccess { SERVER_ACCESS.get().cloned().unwrap_or_default() }
+
+fn parse_selectors(value: &str) -> Result<Vec<ToolSelector>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|selector| !selector.is_empty())
+        .map(ToolSelector::from_str)
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use super::ToolAccess;
+    use crate::tool::ToolDef;
+    use crate::tool::ToolName;
+
+    fn access(args: &[&str]) -> ToolAccess {
+        ToolAccess::from_args(args.iter().map(ToString::to_string)).expect("arguments parse")
+    }
+
+    fn tool(tool_name: ToolName) -> ToolDef { tool_name.to_tool_def() }
+
+    #[test]
+    fn read_only_hides_tools_that_change_the_app() {
+        let access = access(&["--read-only"]);
+        assert!(access.allows(&tool(ToolName::WorldGetComponents)));
+        assert!(!access.allows(&tool(ToolName::WorldMutateComponents)));
+        assert!(!access.allows(&tool(ToolName::BrpShutdown)));
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let access = access(&["--allow", "app,world_query", "--deny=shutdown"]);
+        assert!(access.allows(&tool(ToolName::WorldQuery)));
+        assert!(access.allows(&tool(ToolName::BrpStatus)));
+        assert!(!access.allows(&tool(ToolName::BrpShutdown)));
+        assert!(!access.allows(&tool(ToolName::WorldGetComponents)));
+    }
+
+    #[test]
+    fn brp_methods_are_checked_as_their_tool() {
+        let access = access(&["--deny", "world_insert_components,brp_execute"]);
+        assert!(access.allows_brp_method("world.get_components"));
+        assert!(!access.allows_brp_method("world.insert_components"));
+        assert!(!access.allows_brp_method("my_game/custom_method"));
+    }
+
+    #[test]
+    fn unknown_selectors_and_flags_are_rejected() {
+        assert!(ToolAccess::from_args(["--deny".to_string(), "everything".to_string()]).is_err());
+        assert!(ToolAccess::from_args(["--deny".to_string()]).is_err());
+        assert!(ToolAccess::from_args(["--verbose".to_string()]).is_err());
+    }
+}
@@ -2,9 +2,10 @@
 
 use rmcp::model::ToolAnnotations;
 use strum::AsRefStr;
+use strum::EnumIter;
 
 /// Tool categories for logical grouping and sorting
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, AsRefStr)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, AsRefStr, EnumIter)]
 pub enum ToolCategory {
     #[strum(serialize = "App")]
     App,
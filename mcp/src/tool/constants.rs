@@ -1,17 +1,46 @@
+use std::time::Duration;
+
+// app unreachable fields
+/// Details field with the number of connection attempts made before giving up
+pub(super) const CONNECTION_ATTEMPTS_FIELD: &str = "connection_attempts";
+/// Details field with the crash report of an app that died on the port
+pub(super) const CRASH_REPORT_FIELD: &str = "crash_report";
+/// Details field with the port nothing was listening on
+pub(super) const PORT_FIELD: &str = "port";
+
 // error response fields
 pub(super) const CALL_INFO_FIELD: &str = "call_info";
 pub(super) const ERROR_STATUS: &str = "error";
 pub(super) const MESSAGE_FIELD: &str = "message";
 pub(super) const STATUS_FIELD: &str = "status";
 
+// failure screenshot constants
+/// Prefix of screenshot files captured when a tool fails
+pub(super) const FAILURE_SCREENSHOT_FILE_PREFIX: &str = "bevy_brp_mcp_failure_";
+/// Maximum time to wait for a failure screenshot before returning the error without one
+pub(super) const FAILURE_SCREENSHOT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Environment variable enabling screenshots on tool failure
+pub(super) const SCREENSHOT_ON_FAILURE_ENV_VAR: &str = "BRP_MCP_SCREENSHOT_ON_FAILURE";
+
 // large response fields
 pub(super) const FILEPATH_FIELD: &str = "filepath";
 pub(super) const INSTRUCTIONS_FIELD: &str = "instructions";
-pub(super) const LARGE_RESPONSE_INSTRUCTIONS: &str =
-    "Use Read tool to examine, Grep to search, or jq commands to filter the data.";
+pub(super) const LARGE_RESPONSE_INSTRUCTIONS: &str = "Use Read tool to examine, Grep to search, or jq commands to filter the data. Clients with \
+     MCP resource support can read resource_uri instead, appending ?start=N&end=M to fetch a \
+     range of items.";
 pub(super) const ORIGINAL_SIZE_TOKENS_FIELD: &str = "original_size_tokens";
 pub(super) const SAVED_TO_FILE_FIELD: &str = "saved_to_file";
 
+// large response resource constants
+/// MIME type of published large responses
+pub(super) const JSON_MIME_TYPE: &str = "application/json";
+/// Number of large responses kept as MCP resources
+pub(super) const MAX_RESPONSE_RESOURCES: usize = 16;
+/// URI prefix of large responses published as MCP resources, followed by the response id
+pub(super) const RESPONSE_RESOURCE_URI_PREFIX: &str = "brp-mcp://responses/";
+/// Large response field holding the MCP resource URI of the result
+pub(super) const RESOURCE_URI_FIELD: &str = "resource_uri";
+
 // large response filename constants
 pub(super) const LARGE_RESPONSE_FILENAME_REPLACEMENT: &str = "_";
 pub(super) const LARGE_RESPONSE_FILENAME_SANITIZE_CHARS: [char; 2] = ['/', ' '];
@@ -25,6 +54,24 @@ pub(super) const CHARS_PER_TOKEN: usize = 4;
 /// model token counting can differ from our 4 chars/token estimate.
 pub(super) const DEFAULT_MAX_RESPONSE_TOKENS: usize = 25_000;
 
+// pagination constants
+/// Number of paginated responses whose remaining pages are kept for `cursor` calls
+pub(super) const MAX_PAGED_RESPONSES: usize = 8;
+/// Metadata field describing the returned page
+pub(super) const PAGINATION_FIELD: &str = "pagination";
+/// Result field holding the guides `brp_all_type_guides` pages through
+pub(super) const TYPE_GUIDE_RESULT_FIELD: &str = "type_guide";
+
+// read retry constants
+/// Default number of retries for read-only tools after a transient BRP failure
+pub(super) const DEFAULT_READ_RETRIES: u32 = 2;
+/// Maximum characters of an error message kept as a failure signature
+pub(super) const FAILURE_SIGNATURE_MAX_CHARS: usize = 120;
+/// Environment variable overriding the read-only retry budget
+pub(super) const READ_RETRIES_ENV_VAR: &str = "BRP_MCP_READ_RETRIES";
+/// Delay between retries of a read-only tool
+pub(super) const RETRY_BACKOFF: Duration = Duration::from_millis(250);
+
 // response placeholders
 pub(super) const ENTITY_COUNT_PLACEHOLDER: &str = "entity_count";
 pub(super) const RESULT_PLACEHOLDER: &str = "result";
@@ -33,6 +80,10 @@ pub(super) const RESULT_PLACEHOLDER: &str = "result";
 pub(super) const SKIP_NULL_FIELD_SENTINEL: &str = "__SKIP_NULL_FIELD__";
 
 // response tracking fields
+pub(super) const APP_RESTARTED_FIELD: &str = "app_restarted";
+pub(super) const FAILURE_SCREENSHOT_FIELD: &str = "failure_screenshot";
+pub(super) const PORT_OWNER_CHANGE_FIELD: &str = "port_owner_change";
+pub(super) const RETRY_FIELD: &str = "retry";
 pub(super) const OPTIONAL_PARAMETERS_NOT_PROVIDED_FIELD: &str = "optional_parameters_not_provided";
 
 // schema probes
@@ -8,6 +8,7 @@ use rmcp::model::CallToolResult;
 use rmcp::model::JsonObject;
 use rmcp::model::Tool;
 use schemars::generate::SchemaSettings;
+use tracing::Instrument;
 
 use super::HandlerContext;
 use super::annotations::Annotation;
@@ -15,6 +16,7 @@ use super::handler::ErasedToolFn;
 use super::json_response::ToolCallJsonResponse;
 use super::name::ToolName;
 use super::parameters::ParameterBuilder;
+use crate::log_tools;
 
 /// Unified tool definition that can handle both BRP and Local tools
 #[derive(Clone)]
@@ -40,7 +42,11 @@ impl ToolDef {
         let handler_context = HandlerContext::new(self.clone(), request);
 
         // Tools now always return `CallToolResult` - errors are already formatted as responses
-        Ok(self.handler.call_erased(handler_context).await)
+        Ok(self
+            .handler
+            .call_erased(handler_context)
+            .instrument(log_tools::tool_call_span(self.name()))
+            .await)
     }
 
     /// Generate unified output schema from the actual [`ToolCallJsonResponse`] struct
@@ -1,11 +1,21 @@
+use rmcp::model::CallToolRequestParams;
+use rmcp::model::CallToolResult;
+use serde_json::Map;
+use serde_json::Value;
+
 use super::HandlerContext;
 use super::HandlerResult;
 use super::ParamStruct;
 use super::ResultStruct;
+use super::ToolAccess;
 use super::ToolDef;
+use super::ToolName;
 use super::ToolResult;
+use super::access;
 use super::handler;
 use super::registry;
+use super::response_resources;
+use crate::error::Error;
 use crate::error::Result;
 
 /// Visibility facade for the tool catalog.
@@ -14,6 +24,12 @@ use crate::error::Result;
 /// rather than on `ToolName` owning whole-registry construction.
 pub fn get_all_tool_definitions() -> Vec<ToolDef> { registry::get_all_tool_definitions() }
 
+/// Visibility facade for the large responses published as MCP resources.
+pub fn list_response_resources() -> Vec<rmcp::model::Resource> { response_resources::list() }
+
+/// Visibility facade for reading a published large response, or a range of its items.
+pub fn read_response_resource(uri: &str) -> Result<String> { response_resources::read(uri) }
+
 /// Visibility facade for parameter extraction used by generated and framework code.
 ///
 /// This keeps request decoding owned by the `tool` subsystem instead of exposing
@@ -41,3 +57,33 @@ where
 {
     handler::call_with_typed_params(context, f)
 }
+
+/// Visibility facade for tools that send BRP methods directly, such as `brp_run_test_plan`
+pub fn server_access() -> ToolAccess { access::server_access() }
+
+/// Visibility facade for tools that call other tools, such as `brp_run_scenario`.
+///
+/// The call goes through the tool's regular handler, so the result is the same `CallToolResult` an
+/// MCP client would get. Tools the operator disabled are rejected.
+pub async fn call_tool(
+    tool_name: ToolName,
+    arguments: Map<String, Value>,
+) -> Result<CallToolResult> {
+    let tool_def = tool_name.to_tool_def();
+    if !access::server_allows(&tool_def) {
+        return Err(Error::InvalidState(format!(
+            "Tool '{}' is disabled by the server's --read-only, --allow, or --deny flags",
+            tool_def.name()
+        ))
+        .into());
+    }
+    let request = CallToolRequestParams::new(tool_def.name()).with_arguments(arguments);
+    tool_def.call_tool(request).await.map_err(|error| {
+        Error::tool_call_failed(format!(
+            "Tool '{}' failed: {}",
+            tool_def.name(),
+            error.message
+        ))
+        .into()
+    })
+}
@@ -0,0 +1,100 @@
+//! Automatic screenshot when a tool that changes the app fails.
+//!
+//! Enabled with the `BRP_MCP_SCREENSHOT_ON_FAILURE` environment variable. When a tool that is not
+//! read-only fails against a port, the server asks `brp_extras/screenshot` for a capture of what
+//! the app was showing and attaches the PNG path to the error details as `failure_screenshot`.
+//! Apps without `bevy_brp_extras` cannot take screenshots, so their errors are returned unchanged.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde_json::json;
+
+use super::annotations::EnvironmentImpact;
+use super::constants::FAILURE_SCREENSHOT_FILE_PREFIX;
+use super::constants::FAILURE_SCREENSHOT_TIMEOUT;
+use super::constants::SCREENSHOT_ON_FAILURE_ENV_VAR;
+use super::def::ToolDef;
+use super::name::BrpMethod;
+use super::name::ToolName;
+use crate::brp_tools::BrpClient;
+use crate::brp_tools::Port;
+use crate::brp_tools::ResponseStatus;
+
+/// Whether failures of this tool should be captured: the option is on, the tool changes the app,
+/// and it is not the screenshot tool itself
+pub(super) fn applies_to(tool_def: &ToolDef) -> bool {
+    tool_def.annotations.environment_impact != EnvironmentImpact::ReadOnly
+        && tool_def.tool_name != ToolName::BrpExtrasScreenshot
+        && is_enabled(std::env::var(SCREENSHOT_ON_FAILURE_ENV_VAR).ok().as_deref())
+}
+
+/// Capture the app on `port` and return the screenshot path, or `None` if it could not be taken
+pub(super) async fn capture(tool_name: ToolName, port: Port) -> Option<String> {
+    let path = screenshot_path(tool_name, port);
+    let client = BrpClient::new(
+        BrpMethod::BrpExtrasScreenshot,
+        port,
+        Some(json!({ "path": path })),
+    );
+
+    match tokio::time::timeout(FAILURE_SCREENSHOT_TIMEOUT, client.execute_raw()).await {
+        Ok(Ok(ResponseStatus::Success(_))) => Some(path),
+        Ok(Ok(ResponseStatus::Error(error))) => {
+            tracing::debug!(
+                "Failure screenshot for {tool_name} on port {port} failed: {}",
+                error.get_message()
+            );
+            None
+        },
+        Ok(Err(report)) => {
+            tracing::debug!(
+                "Failure screenshot for {tool_name} on port {port} failed: {}",
+                report.current_context()
+            );
+            None
+        },
+        Err(_) => {
+            tracing::debug!("Failure screenshot for {tool_name} on port {port} timed out");
+            None
+        },
+    }
+}
+
+/// Values of `BRP_MCP_SCREENSHOT_ON_FAILURE` that turn the option on
+fn is_enabled(value: Option<&str>) -> bool {
+    value.is_some_and(|value| {
+        matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "on" | "yes"
+        )
+    })
+}
+
+fn screenshot_path(tool_name: ToolName, port: Port) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    let filename = format!("{FAILURE_SCREENSHOT_FILE_PREFIX}{tool_name}_{port}_{timestamp}.png");
+    std::env::temp_dir()
+        .join(filename)
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_enabled;
+
+    #[test]
+    fn only_truthy_values_enable_the_option() {
+        assert!(is_enabled(Some("1")));
+        assert!(is_enabled(Some(" On ")));
+        assert!(is_enabled(Some("TRUE")));
+        assert!(!is_enabled(Some("0")));
+        assert!(!is_enabled(Some("off")));
+        assert!(!is_enabled(Some("")));
+        assert!(!is_enabled(None));
+    }
+}
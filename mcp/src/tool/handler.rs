@@ -22,8 +22,13 @@ use async_trait::async_trait;
 use rmcp::model::CallToolResult;
 
 use super::ParamStruct;
+use super::failure_screenshot;
 use super::handler_context::HandlerContext;
 use super::response_builder::ResponseBuilder;
+use super::retry;
+use super::retry::RetryPolicy;
+use super::retry::RetryReport;
+use crate::app_tools;
 use crate::error::Result;
 
 /// Framework-level result for tool handler execution.
@@ -134,7 +139,72 @@ impl<T: ToolFn> ErasedToolFn for T {
         Box::pin(async move {
             // we're making a judgement call that we passed a reference to call()
 
-            let result = self.call(context.clone()).await;
+            // Later pages of a paginated result come from the call that returned the first page
+            if let Some(page) = context.resume_paged_response() {
+                return page;
+            }
+
+            // Check who serves a port the caller named before the call, so a restarted app is
+            // reported
+            let port = context.brp_port::<T::Params>();
+            let port_owner_change = match context.supplied_brp_port::<T::Params>() {
+                Some(port) => port.check_owner().await,
+                None => None,
+            };
+            let app_restart = port.and_then(app_tools::take_restart_notice);
+
+            let retry_policy = RetryPolicy::for_tool(&context.tool_def);
+            let mut retries = 0;
+            let mut retry_report: Option<RetryReport> = None;
+            let result = loop {
+                let result = self.call(context.clone()).await;
+                let signature = match &result {
+                    Ok(tool_result) => tool_result
+                        .result
+                        .as_ref()
+                        .err()
+                        .and_then(retry::transient_signature),
+                    Err(_) => None,
+                };
+                let Some(signature) = signature else {
+                    if let Some(report) = retry_report.as_mut() {
+                        report.retries = retries;
+                        report.recovered = true;
+                    }
+                    break result;
+                };
+
+                // Count every transient failure, even when the tool is never retried
+                retry_report = Some(RetryReport {
+                    retries,
+                    recovered: false,
+                    signature_occurrences: retry::record_failure(
+                        context.tool_def.tool_name,
+                        &signature,
+                    ),
+                    failure_signature: signature,
+                });
+                if retries >= retry_policy.max_retries {
+                    break result;
+                }
+                retries += 1;
+                tokio::time::sleep(retry_policy.backoff).await;
+            };
+
+            // Capture what the app was showing when a tool that changes it failed
+            let failed = matches!(&result, Ok(tool_result) if tool_result.result.is_err());
+            let failure_screenshot = match port {
+                Some(port) if failed && failure_screenshot::applies_to(&context.tool_def) => {
+                    failure_screenshot::capture(context.tool_def.tool_name, port).await
+                },
+                _ => None,
+            };
+
+            let context = context
+                .with_retry_report(retry_report)
+                .with_port_owner_change(port_owner_change)
+                .with_app_restart(app_restart)
+                .with_failure_screenshot(failure_screenshot);
             result.map_or_else(
                 |error| context.format_framework_error(error),
                 |tool_result| context.format_result(tool_result),
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
 use std::fs;
+use std::sync::LazyLock;
+use std::sync::Mutex;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -7,6 +10,7 @@ use error_stack::ResultExt;
 use rmcp::model::CallToolRequestParams;
 use rmcp::model::CallToolResult;
 use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::Map;
 use serde_json::Value;
 use serde_json::json;
@@ -14,34 +18,154 @@ use serde_json::json;
 use super::ParamStruct;
 use super::ResultStruct;
 use super::ToolDef;
+use super::ToolName;
 use super::ToolResult;
+use super::annotations::ToolCategory;
+use super::constants::APP_RESTARTED_FIELD;
 use super::constants::CHARS_PER_TOKEN;
+use super::constants::CONNECTION_ATTEMPTS_FIELD;
+use super::constants::CRASH_REPORT_FIELD;
+use super::constants::FAILURE_SCREENSHOT_FIELD;
 use super::constants::FILEPATH_FIELD;
 use super::constants::INSTRUCTIONS_FIELD;
 use super::constants::LARGE_RESPONSE_FILENAME_REPLACEMENT;
 use super::constants::LARGE_RESPONSE_FILENAME_SANITIZE_CHARS;
 use super::constants::LARGE_RESPONSE_INSTRUCTIONS;
 use super::constants::ORIGINAL_SIZE_TOKENS_FIELD;
+use super::constants::PORT_FIELD;
+use super::constants::PORT_OWNER_CHANGE_FIELD;
+use super::constants::RESOURCE_URI_FIELD;
+use super::constants::RETRY_FIELD;
 use super::constants::SAVED_TO_FILE_FIELD;
 use super::json_response::AnySchemaValue;
 use super::json_response::ToolCallJsonResponse;
+use super::large_response;
 use super::large_response::LargeResponseConfig;
 use super::parameters;
+use super::parameters::ParameterName;
 use super::response_builder::Response;
+use super::response_resources;
+use super::retry::RetryReport;
+use crate::app_tools;
+use crate::app_tools::AppRestart;
+use crate::brp_tools::Port;
+use crate::brp_tools::PortOwnerChange;
 use crate::error::Error;
 use crate::error::Result;
 
+/// Whether each tool's parameters declare `port`, so its schema is only generated once
+static PORT_PARAMETER_TOOLS: LazyLock<Mutex<HashMap<ToolName, bool>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The `port` argument of a tool call, read without the rest of the tool's parameters
+#[derive(Deserialize, JsonSchema)]
+struct PortArgument {
+    #[serde(default)]
+    port: Port,
+}
+
 /// Context passed to all handlers containing service, request, and MCP context
 #[derive(Clone)]
 pub struct HandlerContext {
     pub(super) tool_def: ToolDef,
     request:             CallToolRequestParams,
+    retry_report:        Option<RetryReport>,
+    port_owner_change:   Option<PortOwnerChange>,
+    failure_screenshot:  Option<String>,
+    app_restart:         Option<AppRestart>,
 }
 
 impl HandlerContext {
     /// Create a new `HandlerContext`
     pub(super) const fn new(tool_def: ToolDef, request: CallToolRequestParams) -> Self {
-        Self { tool_def, request }
+        Self {
+            tool_def,
+            request,
+            retry_report: None,
+            port_owner_change: None,
+            failure_screenshot: None,
+            app_restart: None,
+        }
+    }
+
+    /// Attach the outcome of automatic retries so it is reported in response metadata
+    pub(super) fn with_retry_report(mut self, retry_report: Option<RetryReport>) -> Self {
+        self.retry_report = retry_report;
+        self
+    }
+
+    /// Retry outcome for this call, if any transient failure occurred
+    pub(super) const fn retry_report(&self) -> Option<&RetryReport> { self.retry_report.as_ref() }
+
+    /// Attach a change of the process serving the call's port so it is reported in the response
+    pub(super) fn with_port_owner_change(
+        mut self,
+        port_owner_change: Option<PortOwnerChange>,
+    ) -> Self {
+        self.port_owner_change = port_owner_change;
+        self
+    }
+
+    /// Change of the process serving the call's port since the previous call, if any
+    pub(super) const fn port_owner_change(&self) -> Option<&PortOwnerChange> {
+        self.port_owner_change.as_ref()
+    }
+
+    /// Attach the path of a screenshot captured after the call failed
+    pub(super) fn with_failure_screenshot(mut self, failure_screenshot: Option<String>) -> Self {
+        self.failure_screenshot = failure_screenshot;
+        self
+    }
+
+    /// Screenshot captured after the call failed, if the option is enabled and capture succeeded
+    pub(super) fn failure_screenshot(&self) -> Option<&str> { self.failure_screenshot.as_deref() }
+
+    /// Attach a restart of the app on the call's port by its restart policy
+    pub(super) fn with_app_restart(mut self, app_restart: Option<AppRestart>) -> Self {
+        self.app_restart = app_restart;
+        self
+    }
+
+    /// Restart of the app on the call's port since the previous call, if any
+    pub(super) const fn app_restart(&self) -> Option<&AppRestart> { self.app_restart.as_ref() }
+
+    /// Port of a running app this call talks to: set for tools with a `port` parameter, except
+    /// app management tools, which start and stop the apps themselves
+    pub(super) fn brp_port<P: JsonSchema>(&self) -> Option<Port> {
+        if self.tool_def.annotations.tool_category == ToolCategory::App
+            || !self.has_port_parameter::<P>()
+        {
+            return None;
+        }
+        self.extract_parameter_values::<PortArgument>()
+            .ok()
+            .map(|argument| argument.port)
+    }
+
+    /// Port of a running app that the caller named explicitly, rather than the default port
+    pub(super) fn supplied_brp_port<P: JsonSchema>(&self) -> Option<Port> {
+        let supplied = self
+            .request
+            .arguments
+            .as_ref()
+            .is_some_and(|arguments| arguments.contains_key(ParameterName::Port.as_ref()));
+        if supplied { self.brp_port::<P>() } else { None }
+    }
+
+    fn has_port_parameter<P: JsonSchema>(&self) -> bool {
+        let tool_name = self.tool_def.tool_name;
+        let cached = PORT_PARAMETER_TOOLS
+            .lock()
+            .ok()
+            .and_then(|tools| tools.get(&tool_name).copied());
+        if let Some(has_port) = cached {
+            return has_port;
+        }
+        let has_port = parameters::has_parameter::<P>(ParameterName::Port);
+        if let Ok(mut tools) = PORT_PARAMETER_TOOLS.lock() {
+            tools.insert(tool_name, has_port);
+        }
+        has_port
     }
 
     /// Common parameter extraction methods (used by both BRP and local handlers)
@@ -105,6 +229,12 @@ impl HandlerContext {
             Ok(data) => match Response::success(&data, tool_result.params, call_info.clone(), self)
             {
                 Ok(response) => {
+                    let response = match (tool_name.paged_items(), self.page_size()) {
+                        (Some(paged_items), Some(page_size)) => {
+                            large_response::first_page(tool_name, paged_items, response, page_size)
+                        },
+                        _ => response,
+                    };
                     // Handle large response here with access to tool_name
                     match self.handle_large_response_if_needed(response) {
                         Ok(processed) => processed.to_call_tool_result(),
@@ -138,13 +268,54 @@ impl HandlerContext {
                         .to_call_tool_result(),
                     }
                 },
+                Error::AppUnreachable {
+                    message,
+                    port,
+                    attempts,
+                } => {
+                    // Kept apart from errors so agents wait for a restarting app instead of
+                    // treating the call as a logical failure
+                    let mut details = json!({
+                        PORT_FIELD: port,
+                        CONNECTION_ATTEMPTS_FIELD: attempts,
+                    });
+                    if let Some(retry_report) = &self.retry_report {
+                        details[RETRY_FIELD] = json!(retry_report);
+                    }
+                    let message = match app_tools::crash_report(*port) {
+                        Some(crash_report) => {
+                            let message = format!(
+                                "App unreachable: '{}' (PID {}) {} - see \
+                                 brp_get_crash_report for its log",
+                                crash_report.app_name, crash_report.pid, crash_report.exit
+                            );
+                            details[CRASH_REPORT_FIELD] = json!(crash_report);
+                            message
+                        },
+                        None => format!("App unreachable: {message}"),
+                    };
+                    Response::app_unreachable(
+                        message,
+                        self.details_with_call_context(Some(&details)).as_ref(),
+                        call_info,
+                    )
+                    .to_call_tool_result()
+                },
                 Error::ToolCall { message, details } => {
                     // Create error response with the error message and details
+                    let details = self.details_with_call_context(details.as_ref());
                     Response::error_with_details(message, details.as_ref(), call_info)
                         .to_call_tool_result()
                 },
-                _ => Response::error_message(
+                _ => Response::error_with_details(
                     format!("Internal error: {}", report.current_context()),
+                    self.details_with_call_context(
+                        self.retry_report
+                            .as_ref()
+                            .map(|retry_report| json!({ RETRY_FIELD: retry_report }))
+                            .as_ref(),
+                    )
+                    .as_ref(),
                     call_info,
                 )
                 .to_call_tool_result(),
@@ -152,6 +323,35 @@ impl HandlerContext {
         }
     }
 
+    /// Add the port owner change and failure screenshot, if any, to tool error details
+    fn details_with_call_context(&self, details: Option<&Value>) -> Option<Value> {
+        let mut context = Map::new();
+        if let Some(change) = self
+            .port_owner_change
+            .as_ref()
+            .and_then(|change| serde_json::to_value(change).ok())
+        {
+            context.insert(PORT_OWNER_CHANGE_FIELD.to_string(), change);
+        }
+        if let Some(path) = &self.failure_screenshot {
+            context.insert(FAILURE_SCREENSHOT_FIELD.to_string(), json!(path));
+        }
+        if let Some(restart) = &self.app_restart {
+            context.insert(APP_RESTARTED_FIELD.to_string(), json!(restart));
+        }
+        if context.is_empty() {
+            return details.cloned();
+        }
+        match details.cloned() {
+            Some(Value::Object(mut map)) => {
+                map.extend(context);
+                Some(Value::Object(map))
+            },
+            Some(details) => Some(details),
+            None => Some(Value::Object(context)),
+        }
+    }
+
     /// Format framework errors
     pub(super) fn format_framework_error(&self, error: Report<Error>) -> CallToolResult {
         let tool_name = self.tool_def.tool_name;
@@ -164,6 +364,33 @@ impl HandlerContext {
         .to_call_tool_result()
     }
 
+    /// Requested page size of a paginated tool call
+    fn page_size(&self) -> Option<usize> {
+        self.extract_optional_named_field(ParameterName::PageSize.as_ref())
+            .and_then(Value::as_u64)
+            .and_then(|page_size| usize::try_from(page_size).ok())
+    }
+
+    /// The page a `cursor` argument points at, served without calling the tool again
+    ///
+    /// Returns `None` for tools without pagination and for calls without a `cursor`.
+    pub(super) fn resume_paged_response(&self) -> Option<CallToolResult> {
+        self.tool_def.tool_name.paged_items()?;
+        let cursor = self
+            .extract_optional_named_field(ParameterName::Cursor.as_ref())?
+            .as_str()?;
+        let call_info = self.tool_def.tool_name.get_call_info();
+        let response = large_response::resume(self.tool_def.tool_name, cursor, self.page_size())
+            .and_then(|page| self.handle_large_response_if_needed(page));
+        Some(
+            response
+                .unwrap_or_else(|e| {
+                    Response::error_message(e.current_context().to_string(), call_info)
+                })
+                .to_call_tool_result(),
+        )
+    }
+
     /// Handle large responses if needed
     fn handle_large_response_if_needed(
         &self,
@@ -205,10 +432,17 @@ impl HandlerContext {
                 filepath.display()
             )))?;
 
+            let resource_uri = response_resources::publish(
+                self.tool_def.tool_name,
+                &result_field.0,
+                result_json.len(),
+            );
+
             let mut modified_response = response;
             modified_response.result = Some(AnySchemaValue(json!({
                 SAVED_TO_FILE_FIELD: true,
                 FILEPATH_FIELD: filepath.to_string_lossy(),
+                RESOURCE_URI_FIELD: resource_uri,
                 INSTRUCTIONS_FIELD: LARGE_RESPONSE_INSTRUCTIONS,
                 ORIGINAL_SIZE_TOKENS_FIELD: estimated_tokens
             })));
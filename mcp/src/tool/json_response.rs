@@ -66,7 +66,9 @@ impl ToolCallJsonResponse {
 
         match self.status {
             ResponseStatus::Success => CallToolResult::structured(value),
-            ResponseStatus::Error => CallToolResult::structured_error(value),
+            ResponseStatus::Error | ResponseStatus::AppUnreachable => {
+                CallToolResult::structured_error(value)
+            },
         }
     }
 }
@@ -77,4 +79,7 @@ impl ToolCallJsonResponse {
 pub(super) enum ResponseStatus {
     Success,
     Error,
+    /// Nothing accepted the connection, so the app is not running or is restarting
+    #[serde(rename = "app_unreachable")]
+    AppUnreachable,
 }
@@ -1,6 +1,25 @@
+//! Large response handling: saving oversized results to files, and paginating results of tools
+//! that accept `page_size` and `cursor`
+
+use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
 use super::constants::DEFAULT_MAX_RESPONSE_TOKENS;
+use super::constants::MAX_PAGED_RESPONSES;
+use super::constants::PAGINATION_FIELD;
+use super::json_response::AnySchemaValue;
+use super::json_response::ToolCallJsonResponse;
+use super::name::ToolName;
+use crate::error::Error;
+use crate::error::Result;
 
 /// Configuration for large response handling
 #[derive(Clone)]
@@ -22,3 +41,256 @@ impl Default for LargeResponseConfig {
         }
     }
 }
+
+/// Full results of paginated responses, oldest first
+static PAGED_RESPONSES: LazyLock<Mutex<VecDeque<PagedResponse>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::new()));
+
+/// Id of the next paginated response
+static NEXT_PAGED_RESPONSE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Where the items of a paginated tool live in its result
+#[derive(Clone, Copy, Debug)]
+pub(super) enum PagedItems {
+    /// The result itself is the array or object to page through
+    Result,
+    /// The named field of the result is the array or object to page through
+    ResultField(&'static str),
+}
+
+/// Pagination details reported in the metadata of every page
+#[derive(Debug, Serialize)]
+struct PageInfo {
+    page:        usize,
+    page_count:  usize,
+    page_size:   usize,
+    total_items: usize,
+    /// Pass as `cursor` to get the next page; `None` on the last page
+    next_cursor: Option<String>,
+}
+
+/// The items of a result, in the order they are paged through
+enum PageItems {
+    List(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl PageItems {
+    const fn len(&self) -> usize {
+        match self {
+            Self::List(items) => items.len(),
+            Self::Map(entries) => entries.len(),
+        }
+    }
+
+    fn page(&self, offset: usize, page_size: usize) -> Value {
+        match self {
+            Self::List(items) => {
+                Value::Array(items.iter().skip(offset).take(page_size).cloned().collect())
+            },
+            Self::Map(entries) => Value::Object(
+                entries
+                    .iter()
+                    .skip(offset)
+                    .take(page_size)
+                    .cloned()
+                    .collect::<Map<String, Value>>(),
+            ),
+        }
+    }
+}
+
+/// A response whose items are served one page at a time
+struct PagedResponse {
+    id:          u64,
+    tool_name:   ToolName,
+    /// The response with its items taken out
+    template:    ToolCallJsonResponse,
+    paged_items: PagedItems,
+    items:       PageItems,
+}
+
+impl PagedResponse {
+    fn page(&self, offset: usize, page_size: usize) -> ToolCallJsonResponse {
+        let total_items = self.items.len();
+        let page_count = total_items.div_ceil(page_size).max(1);
+        let page = offset / page_size + 1;
+        let next_offset = offset + page_size;
+        let page_info = PageInfo {
+            page,
+            page_count,
+            page_size,
+            total_items,
+            next_cursor: (next_offset < total_items)
+                .then(|| format!("{}:{next_offset}:{page_size}", self.id)),
+        };
+
+        let mut response = self.template.clone();
+        if let Some(slot) = items_slot(&mut response, self.paged_items) {
+            *slot = self.items.page(offset, page_size);
+        }
+        response.message = format!("{} (page {page} of {page_count})", response.message);
+        let metadata = response
+            .metadata
+            .get_or_insert_with(|| AnySchemaValue(Value::Object(Map::new())));
+        if let Value::Object(metadata) = &mut metadata.0 {
+            metadata.insert(
+                PAGINATION_FIELD.to_string(),
+                serde_json::to_value(page_info).unwrap_or_default(),
+            );
+        }
+        response
+    }
+}
+
+/// The array or object holding a response's items
+fn items_slot(response: &mut ToolCallJsonResponse, paged_items: PagedItems) -> Option<&mut Value> {
+    let result = &mut response.result.as_mut()?.0;
+    match paged_items {
+        PagedItems::Result => Some(result),
+        PagedItems::ResultField(field) => result.get_mut(field),
+    }
+}
+
+/// First page of `response`, remembering the rest of its items for later `cursor` calls
+///
+/// Responses without an array or object of items are returned unchanged.
+pub(super) fn first_page(
+    tool_name: ToolName,
+    paged_items: PagedItems,
+    mut response: ToolCallJsonResponse,
+    page_size: usize,
+) -> ToolCallJsonResponse {
+    let page_size = page_size.max(1);
+    let items = match items_slot(&mut response, paged_items).map(Value::take) {
+        Some(Value::Array(items)) => PageItems::List(items),
+        Some(Value::Object(entries)) => PageItems::Map(entries.into_iter().collect()),
+        Some(other) => {
+            if let Some(slot) = items_slot(&mut response, paged_items) {
+                *slot = other;
+            }
+            return response;
+        },
+        None => return response,
+    };
+
+    let paged = PagedResponse {
+        id: NEXT_PAGED_RESPONSE_ID.fetch_add(1, Ordering::Relaxed),
+        tool_name,
+        template: response,
+        paged_items,
+        items,
+    };
+    let page = paged.page(0, page_size);
+    if paged.items.len() > page_size
+        && let Ok(mut paged_responses) = PAGED_RESPONSES.lock()
+    {
+        if paged_responses.len() >= MAX_PAGED_RESPONSES {
+            paged_responses.pop_front();
+        }
+        paged_responses.push_back(paged);
+    }
+    page
+}
+
+/// The page `cursor` points at, optionally with a different page size
+pub(super) fn resume(
+    tool_name: ToolName,
+    cursor: &str,
+    page_size: Option<usize>,
+) -> Result<ToolCallJsonResponse> {
+    let unknown_cursor = || {
+        Error::InvalidArgument(format!(
+            "Unknown cursor '{cursor}' for {tool_name}: cursors are forgotten after \
+             {MAX_PAGED_RESPONSES} newer paginated calls or a server restart. Call {tool_name} \
+             with page_size and without cursor to start over"
+        ))
+    };
+    let mut parts = cursor.splitn(3, ':').map(str::parse::<u64>);
+    let (Some(Ok(id)), Some(Ok(offset)), Some(Ok(cursor_page_size))) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(unknown_cursor().into());
+    };
+    let offset = usize::try_from(offset).map_err(|_| unknown_cursor())?;
+    let cursor_page_size = usize::try_from(cursor_page_size).map_err(|_| unknown_cursor())?;
+
+    let page_size = page_size.unwrap_or(cursor_page_size).max(1);
+    Ok(PAGED_RESPONSES
+        .lock()
+        .map_err(|_| Error::General("Paged response store is poisoned".to_string()))?
+        .iter()
+        .find(|paged| paged.id == id && paged.tool_name == tool_name)
+        .map(|paged| paged.page(offset, page_size))
+        .ok_or_else(unknown_cursor)?)
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::AnySchemaValue;
+    use super::PagedItems;
+    use super::ToolCallJsonResponse;
+    use super::ToolName;
+    use super::first_page;
+    use super::resume;
+    use crate::tool::json_response::ResponseStatus;
+
+    fn query_response(entities: usize) -> ToolCallJsonResponse {
+        ToolCallJsonResponse {
+            status:                ResponseStatus::Success,
+            message:               format!("Found {entities} entities"),
+            call_info:             ToolName::WorldQuery.get_call_info(),
+            metadata:              None,
+            parameters:            None,
+            result:                Some(AnySchemaValue(Value::Array(
+                (0..entities)
+                    .map(|entity| json!({ "entity": entity }))
+                    .collect(),
+            ))),
+            error_info:            None,
+            brp_extras_debug_info: None,
+        }
+    }
+
+    fn next_cursor(response: &ToolCallJsonResponse) -> Option<String> {
+        response
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.0["pagination"]["next_cursor"].as_str())
+            .map(String::from)
+    }
+
+    #[test]
+    fn pages_resume_from_the_cursor() {
+        let first = first_page(
+            ToolName::WorldQuery,
+            PagedItems::Result,
+            query_response(5),
+            2,
+        );
+        assert_eq!(first.message, "Found 5 entities (page 1 of 3)");
+        assert_eq!(
+            first.result.as_ref().map(|result| &result.0),
+            Some(&json!([{ "entity": 0 }, { "entity": 1 }]))
+        );
+
+        let cursor = next_cursor(&first).expect("the first page should have a next cursor");
+        let second = resume(ToolName::WorldQuery, &cursor, None).expect("cursor should resume");
+        assert_eq!(
+            second.result.as_ref().map(|result| &result.0),
+            Some(&json!([{ "entity": 2 }, { "entity": 3 }]))
+        );
+
+        let cursor = next_cursor(&second).expect("the second page should have a next cursor");
+        let last = resume(ToolName::WorldQuery, &cursor, None).expect("cursor should resume");
+        assert_eq!(next_cursor(&last), None);
+        assert!(resume(ToolName::BrpAllTypeGuides, &cursor, None).is_err());
+    }
+}
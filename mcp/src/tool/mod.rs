@@ -1,7 +1,9 @@
+mod access;
 mod annotations;
 mod constants;
 mod def;
 mod facade;
+mod failure_screenshot;
 mod field_placement;
 mod handler;
 mod handler_context;
@@ -11,11 +13,18 @@ mod name;
 mod parameters;
 mod registry;
 mod response_builder;
+mod response_resources;
+mod retry;
 
+pub use access::ToolAccess;
 pub use def::ToolDef;
+pub use facade::call_tool;
 pub use facade::call_with_typed_params;
 pub use facade::extract_parameter_values;
 pub use facade::get_all_tool_definitions;
+pub use facade::list_response_resources;
+pub use facade::read_response_resource;
+pub use facade::server_access;
 pub use field_placement::FieldPlacement;
 pub use field_placement::FieldPlacementInfo;
 pub use field_placement::HasFieldPlacement;
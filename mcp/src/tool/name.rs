@@ -20,13 +20,23 @@ use super::ToolDef;
 use super::annotations::Annotation;
 use super::annotations::EnvironmentImpact;
 use super::annotations::ToolCategory;
+use super::constants::TYPE_GUIDE_RESULT_FIELD;
 use super::handler::ErasedToolFn;
+use super::large_response::PagedItems;
 use super::parameters;
 use super::parameters::ParameterBuilder;
 use crate::app_tools;
+use crate::app_tools::GetCrashReport;
+use crate::app_tools::GetCrashReportParams;
 use crate::app_tools::LaunchBevyBinaryParams;
 use crate::app_tools::ListBevy;
 use crate::app_tools::ListBevyParams;
+use crate::app_tools::ListSessions;
+use crate::app_tools::ListSessionsParams;
+use crate::app_tools::RunScenario;
+use crate::app_tools::RunScenarioParams;
+use crate::app_tools::RunTestPlan;
+use crate::app_tools::RunTestPlanParams;
 use crate::app_tools::Shutdown;
 use crate::app_tools::ShutdownParams;
 use crate::app_tools::Status;
@@ -36,53 +46,129 @@ use crate::app_tools::StatusParams;
 use crate::brp_tools::AllTypeGuidesParams;
 use crate::brp_tools::BevyListWatch;
 use crate::brp_tools::BrpAllTypeGuides;
+use crate::brp_tools::BrpCompareScreenshots;
+use crate::brp_tools::BrpCompareSnapshots;
+use crate::brp_tools::BrpConvertWatchLog;
+use crate::brp_tools::BrpDiffEntities;
 use crate::brp_tools::BrpExecute;
+use crate::brp_tools::BrpExecuteBatch;
+use crate::brp_tools::BrpExtrasDiagnosticsWatch;
 use crate::brp_tools::BrpExtrasScreenshot;
+use crate::brp_tools::BrpGenerateTypes;
 use crate::brp_tools::BrpListActiveWatches;
 use crate::brp_tools::BrpListAgentTools;
+use crate::brp_tools::BrpListTags;
 use crate::brp_tools::BrpStopWatch;
+use crate::brp_tools::BrpTagEntity;
+use crate::brp_tools::BrpTransaction;
 use crate::brp_tools::BrpTypeGuide;
+use crate::brp_tools::BrpUndoLast;
+use crate::brp_tools::BrpWaitUntil;
+use crate::brp_tools::CapabilitiesParams;
+use crate::brp_tools::CapabilitiesResult;
+use crate::brp_tools::ClearOverridesParams;
+use crate::brp_tools::ClearOverridesResult;
 use crate::brp_tools::ClickMouseParams;
 use crate::brp_tools::ClickMouseResult;
+use crate::brp_tools::ClickUiNodeParams;
+use crate::brp_tools::ClickUiNodeResult;
+use crate::brp_tools::CompareScreenshotsParams;
+use crate::brp_tools::CompareSnapshotsParams;
+use crate::brp_tools::ComponentStatsParams;
+use crate::brp_tools::ConvertWatchLogParams;
 use crate::brp_tools::DespawnEntityParams;
 use crate::brp_tools::DespawnEntityResult;
+use crate::brp_tools::DespawnRecursiveParams;
+use crate::brp_tools::DespawnRecursiveResult;
+use crate::brp_tools::DiagnosticsWatchParams;
+use crate::brp_tools::DiffEntitiesParams;
 use crate::brp_tools::DoubleClickMouseParams;
 use crate::brp_tools::DoubleClickMouseResult;
 use crate::brp_tools::DoubleTapGestureParams;
 use crate::brp_tools::DoubleTapGestureResult;
 use crate::brp_tools::DragMouseParams;
 use crate::brp_tools::DragMouseResult;
+use crate::brp_tools::DrawGizmoParams;
+use crate::brp_tools::DrawGizmoResult;
+use crate::brp_tools::EventsWatchParams;
+use crate::brp_tools::ExecuteBatchParams;
 use crate::brp_tools::ExecuteParams;
 use crate::brp_tools::FindEntitiesByNameParams;
+use crate::brp_tools::GenerateTypesParams;
+use crate::brp_tools::GetAssetInfoParams;
+use crate::brp_tools::GetAssetInfoResult;
+use crate::brp_tools::GetComponentRawParams;
+use crate::brp_tools::GetComponentRawResult;
 use crate::brp_tools::GetComponentsParams;
 use crate::brp_tools::GetComponentsResult;
 use crate::brp_tools::GetComponentsWatchParams;
+use crate::brp_tools::GetContactsParams;
+use crate::brp_tools::GetContactsResult;
 use crate::brp_tools::GetDiagnosticsParams;
 use crate::brp_tools::GetDiagnosticsResult;
 use crate::brp_tools::GetResourcesParams;
 use crate::brp_tools::GetResourcesResult;
+use crate::brp_tools::GetScheduleGraphParams;
+use crate::brp_tools::GetScheduleGraphResult;
+use crate::brp_tools::GetVisibleTextParams;
+use crate::brp_tools::GetVisibleTextResult;
+use crate::brp_tools::GetWorldStatsParams;
+use crate::brp_tools::GetWorldStatsResult;
+use crate::brp_tools::HighlightEntityParams;
+use crate::brp_tools::HighlightEntityResult;
+use crate::brp_tools::HoverMouseParams;
+use crate::brp_tools::HoverMouseResult;
 use crate::brp_tools::InsertComponentsParams;
 use crate::brp_tools::InsertComponentsResult;
 use crate::brp_tools::InsertResourcesParams;
 use crate::brp_tools::InsertResourcesResult;
+use crate::brp_tools::InspectEntityParams;
+use crate::brp_tools::InspectResourcesParams;
 use crate::brp_tools::ListAgentToolsParams;
+use crate::brp_tools::ListAssetsParams;
+use crate::brp_tools::ListAssetsResult;
 use crate::brp_tools::ListComponentsParams;
 use crate::brp_tools::ListComponentsResult;
 use crate::brp_tools::ListComponentsWatchParams;
 use crate::brp_tools::ListResourcesParams;
 use crate::brp_tools::ListResourcesResult;
+use crate::brp_tools::ListSystemsParams;
+use crate::brp_tools::ListSystemsResult;
+use crate::brp_tools::ListTagsParams;
+use crate::brp_tools::LoadWorldSnapshotParams;
+use crate::brp_tools::LoadWorldSnapshotResult;
+use crate::brp_tools::LookAtParams;
+use crate::brp_tools::LookAtResult;
 use crate::brp_tools::MoveMouseParams;
 use crate::brp_tools::MoveMouseResult;
 use crate::brp_tools::MutateComponentsParams;
 use crate::brp_tools::MutateComponentsResult;
+use crate::brp_tools::MutateReflectParams;
+use crate::brp_tools::MutateReflectResult;
 use crate::brp_tools::MutateResourcesParams;
 use crate::brp_tools::MutateResourcesResult;
+use crate::brp_tools::OrbitCameraParams;
+use crate::brp_tools::OrbitCameraResult;
+use crate::brp_tools::PickEntityParams;
+use crate::brp_tools::PickEntityResult;
 use crate::brp_tools::PinchGestureParams;
 use crate::brp_tools::PinchGestureResult;
+use crate::brp_tools::PingParams;
+use crate::brp_tools::PingResult;
+use crate::brp_tools::PressKeysParams;
+use crate::brp_tools::PressKeysResult;
+use crate::brp_tools::QueryChangedParams;
+use crate::brp_tools::QueryChangedResult;
 use crate::brp_tools::QueryParams;
 use crate::brp_tools::QueryResult;
+use crate::brp_tools::QuerySpatialParams;
+use crate::brp_tools::QuerySpatialResult;
 use crate::brp_tools::RegistrySchemaParams;
 use crate::brp_tools::RegistrySchemaResult;
+use crate::brp_tools::ReleaseKeysParams;
+use crate::brp_tools::ReleaseKeysResult;
+use crate::brp_tools::ReloadAssetParams;
+use crate::brp_tools::ReloadAssetResult;
 use crate::brp_tools::RemoveComponentsParams;
 use crate::brp_tools::RemoveComponentsResult;
 use crate::brp_tools::RemoveResourcesParams;
@@ -93,37 +179,85 @@ use crate::brp_tools::RotationGestureParams;
 use crate::brp_tools::RotationGestureResult;
 use crate::brp_tools::RpcDiscoverParams;
 use crate::brp_tools::RpcDiscoverResult;
+use crate::brp_tools::RunInputScriptParams;
+use crate::brp_tools::RunInputScriptResult;
+use crate::brp_tools::RunSystemByNameParams;
+use crate::brp_tools::RunSystemByNameResult;
+use crate::brp_tools::SaveWorldSnapshotParams;
+use crate::brp_tools::SaveWorldSnapshotResult;
+use crate::brp_tools::ScreenToWorldParams;
+use crate::brp_tools::ScreenToWorldResult;
 use crate::brp_tools::ScreenshotParams;
 use crate::brp_tools::ScrollMouseParams;
 use crate::brp_tools::ScrollMouseResult;
+use crate::brp_tools::SendEventParams;
+use crate::brp_tools::SendEventResult;
+use crate::brp_tools::SendImeParams;
+use crate::brp_tools::SendImeResult;
 use crate::brp_tools::SendKeysParams;
 use crate::brp_tools::SendKeysResult;
 use crate::brp_tools::SendMouseButtonParams;
 use crate::brp_tools::SendMouseButtonResult;
+use crate::brp_tools::SetAmbientLightParams;
+use crate::brp_tools::SetAmbientLightResult;
+use crate::brp_tools::SetCameraTransformParams;
+use crate::brp_tools::SetCameraTransformResult;
+use crate::brp_tools::SetClearColorParams;
+use crate::brp_tools::SetClearColorResult;
+use crate::brp_tools::SetComponentOverrideParams;
+use crate::brp_tools::SetComponentOverrideResult;
+use crate::brp_tools::SetGravityParams;
+use crate::brp_tools::SetGravityResult;
+use crate::brp_tools::SetLinearVelocityParams;
+use crate::brp_tools::SetLinearVelocityResult;
+use crate::brp_tools::SetWindowParams;
+use crate::brp_tools::SetWindowResult;
 use crate::brp_tools::SetWindowTitleParams;
 use crate::brp_tools::SetWindowTitleResult;
 use crate::brp_tools::SpawnEntityParams;
 use crate::brp_tools::SpawnEntityResult;
+use crate::brp_tools::StartInputRecordingParams;
+use crate::brp_tools::StartInputRecordingResult;
+use crate::brp_tools::StopInputRecordingParams;
+use crate::brp_tools::StopInputRecordingResult;
 use crate::brp_tools::StopWatchParams;
+use crate::brp_tools::TagEntityParams;
+use crate::brp_tools::TransactionParams;
 use crate::brp_tools::TriggerEventParams;
 use crate::brp_tools::TriggerEventResult;
 use crate::brp_tools::TypeGuideParams;
 use crate::brp_tools::TypeTextParams;
 use crate::brp_tools::TypeTextResult;
+use crate::brp_tools::UndoLastParams;
+use crate::brp_tools::WaitForParams;
+use crate::brp_tools::WaitForResult;
+use crate::brp_tools::WaitUntilParams;
+use crate::brp_tools::WorldComponentStats;
+use crate::brp_tools::WorldEventsWatch;
 use crate::brp_tools::WorldFindEntitiesByName;
 use crate::brp_tools::WorldGetComponentsWatch;
+use crate::brp_tools::WorldInspectEntity;
+use crate::brp_tools::WorldInspectResources;
+use crate::brp_tools::WorldToScreenParams;
+use crate::brp_tools::WorldToScreenResult;
 use crate::log_tools::DeleteLogs;
 use crate::log_tools::DeleteLogsParams;
 #[cfg(feature = "mcp-debug")]
 use crate::log_tools::GetTraceLogPath;
 use crate::log_tools::ListLogs;
 use crate::log_tools::ListLogsParams;
+#[cfg(feature = "mcp-debug")]
+use crate::log_tools::QueryTrace;
+#[cfg(feature = "mcp-debug")]
+use crate::log_tools::QueryTraceParams;
 use crate::log_tools::ReadLog;
 use crate::log_tools::ReadLogParams;
 #[cfg(feature = "mcp-debug")]
 use crate::log_tools::SetTracingLevel;
 #[cfg(feature = "mcp-debug")]
 use crate::log_tools::SetTracingLevelParams;
+use crate::log_tools::WatchLog;
+use crate::log_tools::WatchLogParams;
 
 /// Call information for tracking tool execution
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -154,6 +288,7 @@ pub(super) enum CallInfo {
     EnumIter,
     EnumString,
     Eq,
+    Hash,
     IntoStaticStr,
     PartialEq,
     ToolDescription,
@@ -183,6 +318,13 @@ pub enum ToolName {
         result = "DespawnEntityResult"
     )]
     WorldDespawnEntity,
+    /// `world_despawn_recursive` - Despawn an entity and all of its descendants
+    #[brp_tool(
+        brp_method = "brp_extras/despawn_recursive",
+        params = "DespawnRecursiveParams",
+        result = "DespawnRecursiveResult"
+    )]
+    WorldDespawnRecursive,
     /// `world_insert_components` - Insert or replace components on entities
     #[brp_tool(
         brp_method = "world.insert_components",
@@ -256,6 +398,24 @@ pub enum ToolName {
     WorldQuery,
     /// `world_find_entities_by_name` - Discover canonical entity IDs by reflected names
     WorldFindEntitiesByName,
+    /// `world_component_stats` - Count the entities holding each component type
+    WorldComponentStats,
+    /// `world_inspect_entity` - Inspect every component of an entity with mutability and hierarchy
+    WorldInspectEntity,
+    /// `world_inspect_resources` - Inspect every resource with its value and mutability
+    WorldInspectResources,
+    /// `brp_diff_entities` - Diff the components of two entities
+    BrpDiffEntities,
+    /// `brp_compare_snapshots` - Diff two snapshot files entity by entity
+    BrpCompareSnapshots,
+    /// `brp_compare_screenshots` - Compare two PNG screenshots pixel by pixel
+    BrpCompareScreenshots,
+    /// `brp_tag_entity` - Label an entity with a tag that survives restarts
+    BrpTagEntity,
+    /// `brp_list_tags` - List entity tags, refreshing their IDs by name
+    BrpListTags,
+    /// `brp_undo_last` - Revert the last component or resource mutation
+    BrpUndoLast,
     /// `world_spawn_entity` - Spawn entities with components
     #[brp_tool(
         brp_method = "world.spawn_entity",
@@ -291,10 +451,22 @@ pub enum ToolName {
     /// `world_list_components_watch` - Watch entity component list changes
     #[brp_tool(brp_method = "world.list_components+watch")]
     WorldListComponentsWatch,
+    /// `world_events_watch` - Watch messages written by the app
+    #[brp_tool(brp_method = "brp_extras/events_watch")]
+    WorldEventsWatch,
+    /// `brp_extras_diagnostics_watch` - Sample diagnostics every N frames into a watch log
+    #[brp_tool(brp_method = "brp_extras/diagnostics_watch")]
+    BrpExtrasDiagnosticsWatch,
 
     // BRP Execute Tool
     /// `brp_execute` - Execute arbitrary BRP method
     BrpExecute,
+    /// `brp_execute_batch` - Execute several BRP methods in one call
+    BrpExecuteBatch,
+    /// `brp_transaction` - Apply component mutations together, rolling back on failure
+    BrpTransaction,
+    /// `brp_wait_until` - Poll a query or get until a value in the response satisfies a predicate
+    BrpWaitUntil,
     /// `brp_list_agent_tools` - List developer-published application method guidance
     BrpListAgentTools,
 
@@ -309,6 +481,20 @@ pub enum ToolName {
         result = "SendKeysResult"
     )]
     BrpExtrasSendKeys,
+    /// `brp_extras_press_keys` - Hold keys down until released or timed out
+    #[brp_tool(
+        brp_method = "brp_extras/press_keys",
+        params = "PressKeysParams",
+        result = "PressKeysResult"
+    )]
+    BrpExtrasPressKeys,
+    /// `brp_extras_release_keys` - Release keys held by `brp_extras_press_keys`
+    #[brp_tool(
+        brp_method = "brp_extras/release_keys",
+        params = "ReleaseKeysParams",
+        result = "ReleaseKeysResult"
+    )]
+    BrpExtrasReleaseKeys,
     /// `brp_extras_type_text` - Type text sequentially (one char per frame)
     #[brp_tool(
         brp_method = "brp_extras/type_text",
@@ -316,6 +502,27 @@ pub enum ToolName {
         result = "TypeTextResult"
     )]
     BrpExtrasTypeText,
+    /// `brp_extras_send_ime` - Send IME composition events (preedit, commit)
+    #[brp_tool(
+        brp_method = "brp_extras/send_ime",
+        params = "SendImeParams",
+        result = "SendImeResult"
+    )]
+    BrpExtrasSendIme,
+    /// `brp_extras_send_event` - Write a reflected message into the app
+    #[brp_tool(
+        brp_method = "brp_extras/send_event",
+        params = "SendEventParams",
+        result = "SendEventResult"
+    )]
+    BrpExtrasSendEvent,
+    /// `brp_extras_run_system_by_name` - Run a one-shot system registered under a name
+    #[brp_tool(
+        brp_method = "brp_extras/run_system_by_name",
+        params = "RunSystemByNameParams",
+        result = "RunSystemByNameResult"
+    )]
+    BrpExtrasRunSystemByName,
     /// `brp_extras_set_window_title` - Change window title
     #[brp_tool(
         brp_method = "brp_extras/set_window_title",
@@ -323,6 +530,27 @@ pub enum ToolName {
         result = "SetWindowTitleResult"
     )]
     BrpExtrasSetWindowTitle,
+    /// `brp_extras_set_window` - Change window resolution, position, mode, and other properties
+    #[brp_tool(
+        brp_method = "brp_extras/set_window",
+        params = "SetWindowParams",
+        result = "SetWindowResult"
+    )]
+    BrpExtrasSetWindow,
+    /// `brp_extras_set_clear_color` - Set the app-wide or one camera's clear color
+    #[brp_tool(
+        brp_method = "brp_extras/set_clear_color",
+        params = "SetClearColorParams",
+        result = "SetClearColorResult"
+    )]
+    BrpExtrasSetClearColor,
+    /// `brp_extras_set_ambient_light` - Set the app-wide or one camera's ambient light
+    #[brp_tool(
+        brp_method = "brp_extras/set_ambient_light",
+        params = "SetAmbientLightParams",
+        result = "SetAmbientLightResult"
+    )]
+    BrpExtrasSetAmbientLight,
     /// `brp_extras_move_mouse` - Move mouse cursor
     #[brp_tool(
         brp_method = "brp_extras/move_mouse",
@@ -330,6 +558,13 @@ pub enum ToolName {
         result = "MoveMouseResult"
     )]
     BrpExtrasMoveMouse,
+    /// `brp_extras_hover_mouse` - Rest the mouse cursor over a position for a dwell time
+    #[brp_tool(
+        brp_method = "brp_extras/hover_mouse",
+        params = "HoverMouseParams",
+        result = "HoverMouseResult"
+    )]
+    BrpExtrasHoverMouse,
     /// `brp_extras_send_mouse_button` - Send mouse button input
     #[brp_tool(
         brp_method = "brp_extras/send_mouse_button",
@@ -344,6 +579,13 @@ pub enum ToolName {
         result = "ClickMouseResult"
     )]
     BrpExtrasClickMouse,
+    /// `brp_extras_click_ui_node` - Click a UI node found by name or entity
+    #[brp_tool(
+        brp_method = "brp_extras/click_ui_node",
+        params = "ClickUiNodeParams",
+        result = "ClickUiNodeResult"
+    )]
+    BrpExtrasClickUiNode,
     /// `brp_extras_double_click_mouse` - Perform double click
     #[brp_tool(
         brp_method = "brp_extras/double_click_mouse",
@@ -386,6 +628,69 @@ pub enum ToolName {
         result = "DoubleTapGestureResult"
     )]
     BrpExtrasDoubleTapGesture,
+    /// `brp_extras_run_input_script` - Play back scripted input frame-accurately
+    #[brp_tool(
+        brp_method = "brp_extras/run_input_script",
+        params = "RunInputScriptParams",
+        result = "RunInputScriptResult"
+    )]
+    BrpExtrasRunInputScript,
+    /// `brp_extras_start_input_recording` - Start recording keyboard and mouse input
+    #[brp_tool(
+        brp_method = "brp_extras/start_input_recording",
+        params = "StartInputRecordingParams",
+        result = "StartInputRecordingResult"
+    )]
+    BrpExtrasStartInputRecording,
+    /// `brp_extras_stop_input_recording` - Stop recording and return the input script
+    #[brp_tool(
+        brp_method = "brp_extras/stop_input_recording",
+        params = "StopInputRecordingParams",
+        result = "StopInputRecordingResult"
+    )]
+    BrpExtrasStopInputRecording,
+    /// `brp_extras_save_world_snapshot` - Save reflectable entities and resources
+    #[brp_tool(
+        brp_method = "brp_extras/save_world_snapshot",
+        params = "SaveWorldSnapshotParams",
+        result = "SaveWorldSnapshotResult"
+    )]
+    BrpExtrasSaveWorldSnapshot,
+    /// `brp_extras_load_world_snapshot` - Restore a saved world snapshot
+    #[brp_tool(
+        brp_method = "brp_extras/load_world_snapshot",
+        params = "LoadWorldSnapshotParams",
+        result = "LoadWorldSnapshotResult"
+    )]
+    BrpExtrasLoadWorldSnapshot,
+    /// `brp_extras_set_component_override` - Override a component value temporarily
+    #[brp_tool(
+        brp_method = "brp_extras/set_component_override",
+        params = "SetComponentOverrideParams",
+        result = "SetComponentOverrideResult"
+    )]
+    BrpExtrasSetComponentOverride,
+    /// `brp_extras_clear_overrides` - Restore overridden component values
+    #[brp_tool(
+        brp_method = "brp_extras/clear_overrides",
+        params = "ClearOverridesParams",
+        result = "ClearOverridesResult"
+    )]
+    BrpExtrasClearOverrides,
+    /// `brp_extras_get_component_raw` - Read a component through reflection, read-only
+    #[brp_tool(
+        brp_method = "brp_extras/get_component_raw",
+        params = "GetComponentRawParams",
+        result = "GetComponentRawResult"
+    )]
+    BrpExtrasGetComponentRaw,
+    /// `brp_extras_mutate_reflect` - Mutate a component through reflection
+    #[brp_tool(
+        brp_method = "brp_extras/mutate_reflect",
+        params = "MutateReflectParams",
+        result = "MutateReflectResult"
+    )]
+    BrpExtrasMutateReflect,
     /// `brp_extras_get_diagnostics` - Get FPS diagnostics
     #[brp_tool(
         brp_method = "brp_extras/get_diagnostics",
@@ -393,12 +698,176 @@ pub enum ToolName {
         result = "GetDiagnosticsResult"
     )]
     BrpExtrasGetDiagnostics,
+    /// `brp_extras_list_assets` - List asset types or the assets of one type
+    #[brp_tool(
+        brp_method = "brp_extras/list_assets",
+        params = "ListAssetsParams",
+        result = "ListAssetsResult"
+    )]
+    BrpExtrasListAssets,
+    /// `brp_extras_get_asset_info` - Inspect one asset's load state and value
+    #[brp_tool(
+        brp_method = "brp_extras/get_asset_info",
+        params = "GetAssetInfoParams",
+        result = "GetAssetInfoResult"
+    )]
+    BrpExtrasGetAssetInfo,
+    /// `brp_extras_reload_asset` - Reload an asset from its source
+    #[brp_tool(
+        brp_method = "brp_extras/reload_asset",
+        params = "ReloadAssetParams",
+        result = "ReloadAssetResult"
+    )]
+    BrpExtrasReloadAsset,
+    /// `brp_extras_list_systems` - List systems per schedule with their run conditions
+    #[brp_tool(
+        brp_method = "brp_extras/list_systems",
+        params = "ListSystemsParams",
+        result = "ListSystemsResult"
+    )]
+    BrpExtrasListSystems,
+    /// `brp_extras_get_schedule_graph` - Get one schedule's systems, sets, and ordering edges
+    #[brp_tool(
+        brp_method = "brp_extras/get_schedule_graph",
+        params = "GetScheduleGraphParams",
+        result = "GetScheduleGraphResult"
+    )]
+    BrpExtrasGetScheduleGraph,
+    /// `brp_extras_get_visible_text` - List visible UI and 2D text with screen rects
+    #[brp_tool(
+        brp_method = "brp_extras/get_visible_text",
+        params = "GetVisibleTextParams",
+        result = "GetVisibleTextResult"
+    )]
+    BrpExtrasGetVisibleText,
+    /// `brp_extras_get_world_stats` - Get entity, archetype, and table statistics
+    #[brp_tool(
+        brp_method = "brp_extras/get_world_stats",
+        params = "GetWorldStatsParams",
+        result = "GetWorldStatsResult"
+    )]
+    BrpExtrasGetWorldStats,
+    /// `brp_extras_query_changed` - Find entities whose components changed since a cursor
+    #[brp_tool(
+        brp_method = "brp_extras/query_changed",
+        params = "QueryChangedParams",
+        result = "QueryChangedResult"
+    )]
+    BrpExtrasQueryChanged,
+    /// `brp_extras_wait_for` - Wait until a component, resource, or diagnostic condition holds
+    #[brp_tool(
+        brp_method = "brp_extras/wait_for",
+        params = "WaitForParams",
+        result = "WaitForResult"
+    )]
+    BrpExtrasWaitFor,
+    /// `brp_extras_query_spatial` - Find entities near a point or inside a box
+    #[brp_tool(
+        brp_method = "brp_extras/query_spatial",
+        params = "QuerySpatialParams",
+        result = "QuerySpatialResult"
+    )]
+    BrpExtrasQuerySpatial,
+    /// `brp_extras_pick_entity` - Find the mesh under a window position
+    #[brp_tool(
+        brp_method = "brp_extras/pick_entity",
+        params = "PickEntityParams",
+        result = "PickEntityResult"
+    )]
+    BrpExtrasPickEntity,
+    /// `brp_extras_set_linear_velocity` - Set an Avian or `bevy_rapier` body's linear velocity
+    #[brp_tool(
+        brp_method = "brp_extras/set_linear_velocity",
+        params = "SetLinearVelocityParams",
+        result = "SetLinearVelocityResult"
+    )]
+    BrpExtrasSetLinearVelocity,
+    /// `brp_extras_get_contacts` - List the entities an Avian collider is touching
+    #[brp_tool(
+        brp_method = "brp_extras/get_contacts",
+        params = "GetContactsParams",
+        result = "GetContactsResult"
+    )]
+    BrpExtrasGetContacts,
+    /// `brp_extras_set_gravity` - Set an Avian or `bevy_rapier` world's gravity
+    #[brp_tool(
+        brp_method = "brp_extras/set_gravity",
+        params = "SetGravityParams",
+        result = "SetGravityResult"
+    )]
+    BrpExtrasSetGravity,
+    /// `brp_extras_world_to_screen` - Project a world position to a window position
+    #[brp_tool(
+        brp_method = "brp_extras/world_to_screen",
+        params = "WorldToScreenParams",
+        result = "WorldToScreenResult"
+    )]
+    BrpExtrasWorldToScreen,
+    /// `brp_extras_screen_to_world` - Get the world-space ray through a window position
+    #[brp_tool(
+        brp_method = "brp_extras/screen_to_world",
+        params = "ScreenToWorldParams",
+        result = "ScreenToWorldResult"
+    )]
+    BrpExtrasScreenToWorld,
+    /// `brp_extras_set_camera_transform` - Set a camera's translation, rotation, or scale
+    #[brp_tool(
+        brp_method = "brp_extras/set_camera_transform",
+        params = "SetCameraTransformParams",
+        result = "SetCameraTransformResult"
+    )]
+    BrpExtrasSetCameraTransform,
+    /// `brp_extras_look_at` - Rotate a camera to face a point or entity
+    #[brp_tool(
+        brp_method = "brp_extras/look_at",
+        params = "LookAtParams",
+        result = "LookAtResult"
+    )]
+    BrpExtrasLookAt,
+    /// `brp_extras_orbit_camera` - Place a camera around a point or entity and face it
+    #[brp_tool(
+        brp_method = "brp_extras/orbit_camera",
+        params = "OrbitCameraParams",
+        result = "OrbitCameraResult"
+    )]
+    BrpExtrasOrbitCamera,
+    /// `brp_extras_draw_gizmo` - Draw temporary debug lines, spheres, boxes, and labels
+    #[brp_tool(
+        brp_method = "brp_extras/draw_gizmo",
+        params = "DrawGizmoParams",
+        result = "DrawGizmoResult"
+    )]
+    BrpExtrasDrawGizmo,
+    /// `brp_extras_highlight_entity` - Outline an entity for a few seconds
+    #[brp_tool(
+        brp_method = "brp_extras/highlight_entity",
+        params = "HighlightEntityParams",
+        result = "HighlightEntityResult"
+    )]
+    BrpExtrasHighlightEntity,
+    /// `brp_extras_ping` - Check that the app answers and report its extras version
+    #[brp_tool(
+        brp_method = "brp_extras/ping",
+        params = "PingParams",
+        result = "PingResult"
+    )]
+    BrpExtrasPing,
+    /// `brp_extras_capabilities` - List the extras methods the app registered with their
+    /// parameter schemas
+    #[brp_tool(
+        brp_method = "brp_extras/capabilities",
+        params = "CapabilitiesParams",
+        result = "CapabilitiesResult"
+    )]
+    BrpExtrasCapabilities,
 
     // BRP Watch Assist Tools
     /// `brp_stop_watch` - Stop active watch subscriptions
     BrpStopWatch,
     /// `brp_list_active_watches` - List active watch subscriptions
     BrpListActiveWatches,
+    /// `brp_convert_watch_log` - Convert a legacy watch log to the JSONL schema
+    BrpConvertWatchLog,
 
     // Application Management Tools
     /// `brp_list_bevy` - List all Bevy apps and examples in workspace
@@ -410,17 +879,30 @@ pub enum ToolName {
     BrpShutdown,
     /// `brp_status` - Check if Bevy app is running with BRP
     BrpStatus,
+    /// `brp_list_sessions` - List the apps this server launched or probed, by port
+    BrpListSessions,
+    /// `brp_get_crash_report` - Report how a launched app exited without `brp_shutdown`
+    BrpGetCrashReport,
+    /// `brp_run_test_plan` - Run a declarative test plan and write a `JUnit` report
+    BrpRunTestPlan,
+    /// `brp_run_scenario` - Run a scenario of tool calls with assertions and waits
+    BrpRunScenario,
 
     // Log Management Tools
     /// `brp_list_logs` - List `bevy_brp_mcp` log files
     BrpListLogs,
     /// `brp_read_log` - Read `bevy_brp_mcp` log file contents
     BrpReadLog,
+    /// `brp_watch_log` - Tail a launched app's log file into a watch log
+    BrpWatchLog,
     /// `brp_delete_logs` - Delete `bevy_brp_mcp` log files
     BrpDeleteLogs,
     /// `brp_get_trace_log_path` - Get trace log path
     #[cfg(feature = "mcp-debug")]
     BrpGetTraceLogPath,
+    /// `brp_query_trace` - Query trace log entries
+    #[cfg(feature = "mcp-debug")]
+    BrpQueryTrace,
     /// `brp_set_tracing_level` - Set tracing level
     #[cfg(feature = "mcp-debug")]
     BrpSetTracingLevel,
@@ -430,6 +912,8 @@ pub enum ToolName {
     BrpTypeGuide,
     /// `brp_all_type_guides` - Get type guides for all registered types
     BrpAllTypeGuides,
+    /// `brp_generate_types` - Generate Rust or TypeScript types from the registry
+    BrpGenerateTypes,
 }
 
 impl ToolName {
@@ -451,6 +935,15 @@ impl ToolName {
         }
     }
 
+    /// Where the items live in the result of tools that accept `page_size` and `cursor`
+    pub(super) const fn paged_items(self) -> Option<PagedItems> {
+        match self {
+            Self::WorldQuery | Self::WorldInspectResources => Some(PagedItems::Result),
+            Self::BrpAllTypeGuides => Some(PagedItems::ResultField(TYPE_GUIDE_RESULT_FIELD)),
+            _ => None,
+        }
+    }
+
     /// Build `Annotation` metadata for the MCP `Tool` title and behavior hints.
     ///
     /// `ToolName` is macro-generated, while `Annotation::new` calls stay manual
@@ -466,6 +959,11 @@ impl ToolName {
                 ToolCategory::Entity,
                 EnvironmentImpact::DestructiveIdempotent,
             ),
+            Self::WorldDespawnRecursive => Annotation::new(
+                "despawn entity hierarchy",
+                ToolCategory::Entity,
+                EnvironmentImpact::DestructiveIdempotent,
+            ),
             Self::WorldGetComponents => Annotation::new(
                 "get component data",
                 ToolCategory::Component,
@@ -516,6 +1014,51 @@ impl ToolName {
                 ToolCategory::Discovery,
                 EnvironmentImpact::ReadOnly,
             ),
+            Self::WorldComponentStats => Annotation::new(
+                "component usage statistics",
+                ToolCategory::Discovery,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::WorldInspectEntity => Annotation::new(
+                "inspect entity",
+                ToolCategory::Component,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::WorldInspectResources => Annotation::new(
+                "inspect resources",
+                ToolCategory::Resource,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpDiffEntities => Annotation::new(
+                "diff entities",
+                ToolCategory::Component,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpCompareSnapshots => Annotation::new(
+                "compare snapshots",
+                ToolCategory::Component,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpCompareScreenshots => Annotation::new(
+                "compare screenshots",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpTagEntity => Annotation::new(
+                "tag entity",
+                ToolCategory::Discovery,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpListTags => Annotation::new(
+                "list entity tags",
+                ToolCategory::Discovery,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpUndoLast => Annotation::new(
+                "undo last mutation",
+                ToolCategory::Component,
+                EnvironmentImpact::DestructiveNonIdempotent,
+            ),
             Self::RegistrySchema => Annotation::new(
                 "get type schemas using 'registry.schema' method",
                 ToolCategory::Discovery,
@@ -556,6 +1099,21 @@ impl ToolName {
                 ToolCategory::DynamicBrp,
                 EnvironmentImpact::DestructiveNonIdempotent,
             ),
+            Self::BrpExecuteBatch => Annotation::new(
+                "execute brp method batch",
+                ToolCategory::DynamicBrp,
+                EnvironmentImpact::DestructiveNonIdempotent,
+            ),
+            Self::BrpTransaction => Annotation::new(
+                "mutate components transactionally",
+                ToolCategory::Component,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpWaitUntil => Annotation::new(
+                "wait until condition",
+                ToolCategory::DynamicBrp,
+                EnvironmentImpact::ReadOnly,
+            ),
             Self::BrpListAgentTools => Annotation::new(
                 "list agent tools",
                 ToolCategory::Discovery,
@@ -571,21 +1129,66 @@ impl ToolName {
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::BrpExtrasPressKeys => Annotation::new(
+                "press and hold keys",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasReleaseKeys => Annotation::new(
+                "release held keys",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
             Self::BrpExtrasSetWindowTitle => Annotation::new(
                 "change window title",
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveIdempotent,
             ),
+            Self::BrpExtrasSetWindow => Annotation::new(
+                "change window properties",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasSetClearColor => Annotation::new(
+                "set clear color",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasSetAmbientLight => Annotation::new(
+                "set ambient light",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
             Self::BrpExtrasTypeText => Annotation::new(
                 "type text sequentially",
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::BrpExtrasSendIme => Annotation::new(
+                "send IME composition events",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasSendEvent => Annotation::new(
+                "send event",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasRunSystemByName => Annotation::new(
+                "run named system",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
             Self::BrpExtrasMoveMouse => Annotation::new(
                 "move mouse cursor",
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::BrpExtrasHoverMouse => Annotation::new(
+                "hover mouse cursor",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
             Self::BrpExtrasSendMouseButton => Annotation::new(
                 "send mouse button",
                 ToolCategory::Extras,
@@ -596,6 +1199,11 @@ impl ToolName {
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::BrpExtrasClickUiNode => Annotation::new(
+                "click UI node",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
             Self::BrpExtrasDoubleClickMouse => Annotation::new(
                 "double click mouse",
                 ToolCategory::Extras,
@@ -626,11 +1234,171 @@ impl ToolName {
                 ToolCategory::Extras,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::BrpExtrasRunInputScript => Annotation::new(
+                "run input script",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasStartInputRecording => Annotation::new(
+                "start input recording",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasStopInputRecording => Annotation::new(
+                "stop input recording",
+                ToolCategory::Extras,
+                EnvironmentImpact::DestructiveIdempotent,
+            ),
+            Self::BrpExtrasSaveWorldSnapshot => Annotation::new(
+                "save world snapshot",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasLoadWorldSnapshot => Annotation::new(
+                "load world snapshot",
+                ToolCategory::Extras,
+                EnvironmentImpact::DestructiveIdempotent,
+            ),
+            Self::BrpExtrasSetComponentOverride => Annotation::new(
+                "set component override",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasClearOverrides => Annotation::new(
+                "clear component overrides",
+                ToolCategory::Extras,
+                EnvironmentImpact::DestructiveIdempotent,
+            ),
+            Self::BrpExtrasGetComponentRaw => Annotation::new(
+                "get component through reflection",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasMutateReflect => Annotation::new(
+                "mutate component through reflection",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
             Self::BrpExtrasGetDiagnostics => Annotation::new(
                 "get FPS diagnostics",
                 ToolCategory::Extras,
                 EnvironmentImpact::ReadOnly,
             ),
+            Self::BrpExtrasListAssets => Annotation::new(
+                "list assets",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasGetAssetInfo => Annotation::new(
+                "get asset info",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasReloadAsset => Annotation::new(
+                "reload asset",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasListSystems => Annotation::new(
+                "list systems",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasGetScheduleGraph => Annotation::new(
+                "get schedule graph",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasGetVisibleText => Annotation::new(
+                "get visible text",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasGetWorldStats => Annotation::new(
+                "get world stats",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasQueryChanged => Annotation::new(
+                "query changed components",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasWaitFor => Annotation::new(
+                "wait for condition",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasQuerySpatial => Annotation::new(
+                "find entities in a region",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasPickEntity => Annotation::new(
+                "pick entity at position",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasSetLinearVelocity => Annotation::new(
+                "set linear velocity",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasGetContacts => Annotation::new(
+                "get collider contacts",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasSetGravity => Annotation::new(
+                "set gravity",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasWorldToScreen => Annotation::new(
+                "project world to screen",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasScreenToWorld => Annotation::new(
+                "project screen to world",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasSetCameraTransform => Annotation::new(
+                "set camera transform",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasLookAt => Annotation::new(
+                "point camera at target",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasOrbitCamera => Annotation::new(
+                "orbit camera around target",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
+            Self::BrpExtrasDrawGizmo => Annotation::new(
+                "draw debug gizmo",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasHighlightEntity => Annotation::new(
+                "highlight entity",
+                ToolCategory::Extras,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasPing => Annotation::new(
+                "ping app",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpExtrasCapabilities => Annotation::new(
+                "list extras capabilities",
+                ToolCategory::Extras,
+                EnvironmentImpact::ReadOnly,
+            ),
             Self::WorldGetComponentsWatch => Annotation::new(
                 "watch component changes",
                 ToolCategory::WatchMonitoring,
@@ -641,6 +1409,16 @@ impl ToolName {
                 ToolCategory::WatchMonitoring,
                 EnvironmentImpact::AdditiveNonIdempotent,
             ),
+            Self::WorldEventsWatch => Annotation::new(
+                "watch events",
+                ToolCategory::WatchMonitoring,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpExtrasDiagnosticsWatch => Annotation::new(
+                "watch diagnostics",
+                ToolCategory::WatchMonitoring,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
             Self::BrpDeleteLogs => Annotation::new(
                 "delete log files",
                 ToolCategory::Logging,
@@ -652,6 +1430,12 @@ impl ToolName {
                 ToolCategory::Logging,
                 EnvironmentImpact::ReadOnly,
             ),
+            #[cfg(feature = "mcp-debug")]
+            Self::BrpQueryTrace => Annotation::new(
+                "query trace log",
+                ToolCategory::Logging,
+                EnvironmentImpact::ReadOnly,
+            ),
             Self::BrpLaunch => Annotation::new(
                 "launch bevy app or example",
                 ToolCategory::App,
@@ -672,6 +1456,11 @@ impl ToolName {
                 ToolCategory::WatchMonitoring,
                 EnvironmentImpact::DestructiveIdempotent,
             ),
+            Self::BrpConvertWatchLog => Annotation::new(
+                "convert watch log",
+                ToolCategory::WatchMonitoring,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
             Self::BrpListLogs => Annotation::new(
                 "list log files",
                 ToolCategory::Logging,
@@ -682,6 +1471,11 @@ impl ToolName {
                 ToolCategory::Logging,
                 EnvironmentImpact::ReadOnly,
             ),
+            Self::BrpWatchLog => Annotation::new(
+                "watch log file",
+                ToolCategory::Logging,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
             #[cfg(feature = "mcp-debug")]
             Self::BrpSetTracingLevel => Annotation::new(
                 "set tracing level",
@@ -693,11 +1487,31 @@ impl ToolName {
                 ToolCategory::App,
                 EnvironmentImpact::ReadOnly,
             ),
+            Self::BrpListSessions => Annotation::new(
+                "list app sessions",
+                ToolCategory::App,
+                EnvironmentImpact::ReadOnly,
+            ),
+            Self::BrpGetCrashReport => Annotation::new(
+                "get app crash report",
+                ToolCategory::App,
+                EnvironmentImpact::ReadOnly,
+            ),
             Self::BrpShutdown => Annotation::new(
                 "shutdown bevy app",
                 ToolCategory::App,
                 EnvironmentImpact::DestructiveIdempotent,
             ),
+            Self::BrpRunTestPlan => Annotation::new(
+                "run test plan",
+                ToolCategory::App,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
+            Self::BrpRunScenario => Annotation::new(
+                "run scenario",
+                ToolCategory::App,
+                EnvironmentImpact::AdditiveNonIdempotent,
+            ),
             Self::BrpTypeGuide => Annotation::new(
                 "type guide for components and resources",
                 ToolCategory::Discovery,
@@ -708,6 +1522,11 @@ impl ToolName {
                 ToolCategory::Discovery,
                 EnvironmentImpact::ReadOnly,
             ),
+            Self::BrpGenerateTypes => Annotation::new(
+                "generate types from the registry",
+                ToolCategory::Discovery,
+                EnvironmentImpact::AdditiveIdempotent,
+            ),
         }
     }
 
@@ -725,6 +1544,9 @@ impl ToolName {
             Self::WorldDespawnEntity => {
                 Some(parameters::build_parameters_from::<DespawnEntityParams>)
             },
+            Self::WorldDespawnRecursive => {
+                Some(parameters::build_parameters_from::<DespawnRecursiveParams>)
+            },
             Self::WorldGetComponents => {
                 Some(parameters::build_parameters_from::<GetComponentsParams>)
             },
@@ -753,6 +1575,25 @@ impl ToolName {
             Self::WorldFindEntitiesByName => {
                 Some(parameters::build_parameters_from::<FindEntitiesByNameParams>)
             },
+            Self::WorldComponentStats => {
+                Some(parameters::build_parameters_from::<ComponentStatsParams>)
+            },
+            Self::WorldInspectEntity => {
+                Some(parameters::build_parameters_from::<InspectEntityParams>)
+            },
+            Self::WorldInspectResources => {
+                Some(parameters::build_parameters_from::<InspectResourcesParams>)
+            },
+            Self::BrpDiffEntities => Some(parameters::build_parameters_from::<DiffEntitiesParams>),
+            Self::BrpCompareSnapshots => {
+                Some(parameters::build_parameters_from::<CompareSnapshotsParams>)
+            },
+            Self::BrpCompareScreenshots => {
+                Some(parameters::build_parameters_from::<CompareScreenshotsParams>)
+            },
+            Self::BrpTagEntity => Some(parameters::build_parameters_from::<TagEntityParams>),
+            Self::BrpListTags => Some(parameters::build_parameters_from::<ListTagsParams>),
+            Self::BrpUndoLast => Some(parameters::build_parameters_from::<UndoLastParams>),
             Self::RegistrySchema => Some(parameters::build_parameters_from::<RegistrySchemaParams>),
             Self::WorldRemoveComponents => {
                 Some(parameters::build_parameters_from::<RemoveComponentsParams>)
@@ -769,6 +1610,9 @@ impl ToolName {
                 Some(parameters::build_parameters_from::<TriggerEventParams>)
             },
             Self::BrpExecute => Some(parameters::build_parameters_from::<ExecuteParams>),
+            Self::BrpExecuteBatch => Some(parameters::build_parameters_from::<ExecuteBatchParams>),
+            Self::BrpTransaction => Some(parameters::build_parameters_from::<TransactionParams>),
+            Self::BrpWaitUntil => Some(parameters::build_parameters_from::<WaitUntilParams>),
             Self::BrpListAgentTools => {
                 Some(parameters::build_parameters_from::<ListAgentToolsParams>)
             },
@@ -776,17 +1620,39 @@ impl ToolName {
                 Some(parameters::build_parameters_from::<ScreenshotParams>)
             },
             Self::BrpExtrasSendKeys => Some(parameters::build_parameters_from::<SendKeysParams>),
+            Self::BrpExtrasPressKeys => Some(parameters::build_parameters_from::<PressKeysParams>),
+            Self::BrpExtrasReleaseKeys => {
+                Some(parameters::build_parameters_from::<ReleaseKeysParams>)
+            },
             Self::BrpExtrasTypeText => Some(parameters::build_parameters_from::<TypeTextParams>),
+            Self::BrpExtrasSendIme => Some(parameters::build_parameters_from::<SendImeParams>),
+            Self::BrpExtrasSendEvent => Some(parameters::build_parameters_from::<SendEventParams>),
+            Self::BrpExtrasRunSystemByName => {
+                Some(parameters::build_parameters_from::<RunSystemByNameParams>)
+            },
             Self::BrpExtrasSetWindowTitle => {
                 Some(parameters::build_parameters_from::<SetWindowTitleParams>)
             },
+            Self::BrpExtrasSetWindow => Some(parameters::build_parameters_from::<SetWindowParams>),
+            Self::BrpExtrasSetClearColor => {
+                Some(parameters::build_parameters_from::<SetClearColorParams>)
+            },
+            Self::BrpExtrasSetAmbientLight => {
+                Some(parameters::build_parameters_from::<SetAmbientLightParams>)
+            },
             Self::BrpExtrasMoveMouse => Some(parameters::build_parameters_from::<MoveMouseParams>),
+            Self::BrpExtrasHoverMouse => {
+                Some(parameters::build_parameters_from::<HoverMouseParams>)
+            },
             Self::BrpExtrasSendMouseButton => {
                 Some(parameters::build_parameters_from::<SendMouseButtonParams>)
             },
             Self::BrpExtrasClickMouse => {
                 Some(parameters::build_parameters_from::<ClickMouseParams>)
             },
+            Self::BrpExtrasClickUiNode => {
+                Some(parameters::build_parameters_from::<ClickUiNodeParams>)
+            },
             Self::BrpExtrasDoubleClickMouse => {
                 Some(parameters::build_parameters_from::<DoubleClickMouseParams>)
             },
@@ -803,12 +1669,104 @@ impl ToolName {
             Self::BrpExtrasDoubleTapGesture => {
                 Some(parameters::build_parameters_from::<DoubleTapGestureParams>)
             },
+            Self::BrpExtrasRunInputScript => {
+                Some(parameters::build_parameters_from::<RunInputScriptParams>)
+            },
+            Self::BrpExtrasStartInputRecording => {
+                Some(parameters::build_parameters_from::<StartInputRecordingParams>)
+            },
+            Self::BrpExtrasStopInputRecording => {
+                Some(parameters::build_parameters_from::<StopInputRecordingParams>)
+            },
+            Self::BrpExtrasSaveWorldSnapshot => {
+                Some(parameters::build_parameters_from::<SaveWorldSnapshotParams>)
+            },
+            Self::BrpExtrasLoadWorldSnapshot => {
+                Some(parameters::build_parameters_from::<LoadWorldSnapshotParams>)
+            },
+            Self::BrpExtrasSetComponentOverride => {
+                Some(parameters::build_parameters_from::<SetComponentOverrideParams>)
+            },
+            Self::BrpExtrasClearOverrides => {
+                Some(parameters::build_parameters_from::<ClearOverridesParams>)
+            },
+            Self::BrpExtrasGetComponentRaw => {
+                Some(parameters::build_parameters_from::<GetComponentRawParams>)
+            },
+            Self::BrpExtrasMutateReflect => {
+                Some(parameters::build_parameters_from::<MutateReflectParams>)
+            },
             Self::BrpExtrasGetDiagnostics => {
                 Some(parameters::build_parameters_from::<GetDiagnosticsParams>)
             },
+            Self::BrpExtrasListAssets => {
+                Some(parameters::build_parameters_from::<ListAssetsParams>)
+            },
+            Self::BrpExtrasGetAssetInfo => {
+                Some(parameters::build_parameters_from::<GetAssetInfoParams>)
+            },
+            Self::BrpExtrasReloadAsset => {
+                Some(parameters::build_parameters_from::<ReloadAssetParams>)
+            },
+            Self::BrpExtrasListSystems => {
+                Some(parameters::build_parameters_from::<ListSystemsParams>)
+            },
+            Self::BrpExtrasGetScheduleGraph => {
+                Some(parameters::build_parameters_from::<GetScheduleGraphParams>)
+            },
+            Self::BrpExtrasGetVisibleText => {
+                Some(parameters::build_parameters_from::<GetVisibleTextParams>)
+            },
+            Self::BrpExtrasGetWorldStats => {
+                Some(parameters::build_parameters_from::<GetWorldStatsParams>)
+            },
+            Self::BrpExtrasQueryChanged => {
+                Some(parameters::build_parameters_from::<QueryChangedParams>)
+            },
+            Self::BrpExtrasWaitFor => Some(parameters::build_parameters_from::<WaitForParams>),
+            Self::BrpExtrasQuerySpatial => {
+                Some(parameters::build_parameters_from::<QuerySpatialParams>)
+            },
+            Self::BrpExtrasPickEntity => {
+                Some(parameters::build_parameters_from::<PickEntityParams>)
+            },
+            Self::BrpExtrasSetLinearVelocity => {
+                Some(parameters::build_parameters_from::<SetLinearVelocityParams>)
+            },
+            Self::BrpExtrasGetContacts => {
+                Some(parameters::build_parameters_from::<GetContactsParams>)
+            },
+            Self::BrpExtrasSetGravity => {
+                Some(parameters::build_parameters_from::<SetGravityParams>)
+            },
+            Self::BrpExtrasWorldToScreen => {
+                Some(parameters::build_parameters_from::<WorldToScreenParams>)
+            },
+            Self::BrpExtrasScreenToWorld => {
+                Some(parameters::build_parameters_from::<ScreenToWorldParams>)
+            },
+            Self::BrpExtrasSetCameraTransform => {
+                Some(parameters::build_parameters_from::<SetCameraTransformParams>)
+            },
+            Self::BrpExtrasLookAt => Some(parameters::build_parameters_from::<LookAtParams>),
+            Self::BrpExtrasOrbitCamera => {
+                Some(parameters::build_parameters_from::<OrbitCameraParams>)
+            },
+            Self::BrpExtrasDrawGizmo => Some(parameters::build_parameters_from::<DrawGizmoParams>),
+            Self::BrpExtrasHighlightEntity => {
+                Some(parameters::build_parameters_from::<HighlightEntityParams>)
+            },
+            Self::BrpExtrasPing => Some(parameters::build_parameters_from::<PingParams>),
+            Self::BrpExtrasCapabilities => {
+                Some(parameters::build_parameters_from::<CapabilitiesParams>)
+            },
             Self::WorldGetComponentsWatch => {
                 Some(parameters::build_parameters_from::<GetComponentsWatchParams>)
             },
+            Self::WorldEventsWatch => Some(parameters::build_parameters_from::<EventsWatchParams>),
+            Self::BrpExtrasDiagnosticsWatch => {
+                Some(parameters::build_parameters_from::<DiagnosticsWatchParams>)
+            },
             Self::WorldListComponentsWatch => {
                 Some(parameters::build_parameters_from::<ListComponentsWatchParams>)
             },
@@ -823,26 +1781,46 @@ impl ToolName {
             // App and watch `ToolName` variants with `ParameterBuilder` implementations
             Self::BrpLaunch => Some(parameters::build_parameters_from::<LaunchBevyBinaryParams>),
             Self::BrpStopWatch => Some(parameters::build_parameters_from::<StopWatchParams>),
+            Self::BrpConvertWatchLog => {
+                Some(parameters::build_parameters_from::<ConvertWatchLogParams>)
+            },
             Self::BrpListLogs => Some(parameters::build_parameters_from::<ListLogsParams>),
             Self::BrpReadLog => Some(parameters::build_parameters_from::<ReadLogParams>),
+            Self::BrpWatchLog => Some(parameters::build_parameters_from::<WatchLogParams>),
+            #[cfg(feature = "mcp-debug")]
+            Self::BrpQueryTrace => Some(parameters::build_parameters_from::<QueryTraceParams>),
             #[cfg(feature = "mcp-debug")]
             Self::BrpSetTracingLevel => {
                 Some(parameters::build_parameters_from::<SetTracingLevelParams>)
             },
             Self::BrpStatus => Some(parameters::build_parameters_from::<StatusParams>),
             Self::BrpShutdown => Some(parameters::build_parameters_from::<ShutdownParams>),
+            Self::BrpListSessions => Some(parameters::build_parameters_from::<ListSessionsParams>),
+            Self::BrpGetCrashReport => {
+                Some(parameters::build_parameters_from::<GetCrashReportParams>)
+            },
+            Self::BrpRunTestPlan => Some(parameters::build_parameters_from::<RunTestPlanParams>),
+            Self::BrpRunScenario => Some(parameters::build_parameters_from::<RunScenarioParams>),
             Self::BrpTypeGuide => Some(parameters::build_parameters_from::<TypeGuideParams>),
             Self::BrpAllTypeGuides => {
                 Some(parameters::build_parameters_from::<AllTypeGuidesParams>)
             },
+            Self::BrpGenerateTypes => {
+                Some(parameters::build_parameters_from::<GenerateTypesParams>)
+            },
         }
     }
 
     /// Create handler for this tool
+    #[allow(
+        clippy::too_many_lines,
+        reason = "trivial per-variant constructor calls"
+    )]
     fn create_handler(self) -> Arc<dyn ErasedToolFn> {
         match self {
             // BRP tools generated by the macro
             Self::WorldDespawnEntity => Arc::new(WorldDespawnEntity),
+            Self::WorldDespawnRecursive => Arc::new(WorldDespawnRecursive),
             Self::WorldGetComponents => Arc::new(WorldGetComponents),
             Self::WorldGetResources => Arc::new(WorldGetResources),
             Self::WorldInsertComponents => Arc::new(WorldInsertComponents),
@@ -853,6 +1831,15 @@ impl ToolName {
             Self::WorldMutateResources => Arc::new(WorldMutateResources),
             Self::WorldQuery => Arc::new(WorldQuery),
             Self::WorldFindEntitiesByName => Arc::new(WorldFindEntitiesByName),
+            Self::WorldComponentStats => Arc::new(WorldComponentStats),
+            Self::WorldInspectEntity => Arc::new(WorldInspectEntity),
+            Self::WorldInspectResources => Arc::new(WorldInspectResources),
+            Self::BrpDiffEntities => Arc::new(BrpDiffEntities),
+            Self::BrpCompareSnapshots => Arc::new(BrpCompareSnapshots),
+            Self::BrpCompareScreenshots => Arc::new(BrpCompareScreenshots),
+            Self::BrpTagEntity => Arc::new(BrpTagEntity),
+            Self::BrpListTags => Arc::new(BrpListTags),
+            Self::BrpUndoLast => Arc::new(BrpUndoLast),
             Self::RegistrySchema => Arc::new(RegistrySchema),
             Self::WorldRemoveComponents => Arc::new(WorldRemoveComponents),
             Self::WorldRemoveResources => Arc::new(WorldRemoveResources),
@@ -862,28 +1849,77 @@ impl ToolName {
             Self::WorldTriggerEvent => Arc::new(WorldTriggerEvent),
             Self::BrpExtrasScreenshot => Arc::new(BrpExtrasScreenshot),
             Self::BrpExtrasSendKeys => Arc::new(BrpExtrasSendKeys),
+            Self::BrpExtrasPressKeys => Arc::new(BrpExtrasPressKeys),
+            Self::BrpExtrasReleaseKeys => Arc::new(BrpExtrasReleaseKeys),
             Self::BrpExtrasTypeText => Arc::new(BrpExtrasTypeText),
+            Self::BrpExtrasSendIme => Arc::new(BrpExtrasSendIme),
+            Self::BrpExtrasSendEvent => Arc::new(BrpExtrasSendEvent),
+            Self::BrpExtrasRunSystemByName => Arc::new(BrpExtrasRunSystemByName),
             Self::BrpExtrasSetWindowTitle => Arc::new(BrpExtrasSetWindowTitle),
+            Self::BrpExtrasSetWindow => Arc::new(BrpExtrasSetWindow),
+            Self::BrpExtrasSetClearColor => Arc::new(BrpExtrasSetClearColor),
+            Self::BrpExtrasSetAmbientLight => Arc::new(BrpExtrasSetAmbientLight),
             Self::BrpExtrasMoveMouse => Arc::new(BrpExtrasMoveMouse),
+            Self::BrpExtrasHoverMouse => Arc::new(BrpExtrasHoverMouse),
             Self::BrpExtrasSendMouseButton => Arc::new(BrpExtrasSendMouseButton),
             Self::BrpExtrasClickMouse => Arc::new(BrpExtrasClickMouse),
+            Self::BrpExtrasClickUiNode => Arc::new(BrpExtrasClickUiNode),
             Self::BrpExtrasDoubleClickMouse => Arc::new(BrpExtrasDoubleClickMouse),
             Self::BrpExtrasDragMouse => Arc::new(BrpExtrasDragMouse),
             Self::BrpExtrasScrollMouse => Arc::new(BrpExtrasScrollMouse),
             Self::BrpExtrasPinchGesture => Arc::new(BrpExtrasPinchGesture),
             Self::BrpExtrasRotationGesture => Arc::new(BrpExtrasRotationGesture),
             Self::BrpExtrasDoubleTapGesture => Arc::new(BrpExtrasDoubleTapGesture),
+            Self::BrpExtrasRunInputScript => Arc::new(BrpExtrasRunInputScript),
+            Self::BrpExtrasStartInputRecording => Arc::new(BrpExtrasStartInputRecording),
+            Self::BrpExtrasStopInputRecording => Arc::new(BrpExtrasStopInputRecording),
+            Self::BrpExtrasSaveWorldSnapshot => Arc::new(BrpExtrasSaveWorldSnapshot),
+            Self::BrpExtrasLoadWorldSnapshot => Arc::new(BrpExtrasLoadWorldSnapshot),
+            Self::BrpExtrasSetComponentOverride => Arc::new(BrpExtrasSetComponentOverride),
+            Self::BrpExtrasClearOverrides => Arc::new(BrpExtrasClearOverrides),
+            Self::BrpExtrasGetComponentRaw => Arc::new(BrpExtrasGetComponentRaw),
+            Self::BrpExtrasMutateReflect => Arc::new(BrpExtrasMutateReflect),
             Self::BrpExtrasGetDiagnostics => Arc::new(BrpExtrasGetDiagnostics),
+            Self::BrpExtrasListAssets => Arc::new(BrpExtrasListAssets),
+            Self::BrpExtrasGetAssetInfo => Arc::new(BrpExtrasGetAssetInfo),
+            Self::BrpExtrasReloadAsset => Arc::new(BrpExtrasReloadAsset),
+            Self::BrpExtrasListSystems => Arc::new(BrpExtrasListSystems),
+            Self::BrpExtrasGetScheduleGraph => Arc::new(BrpExtrasGetScheduleGraph),
+            Self::BrpExtrasGetVisibleText => Arc::new(BrpExtrasGetVisibleText),
+            Self::BrpExtrasGetWorldStats => Arc::new(BrpExtrasGetWorldStats),
+            Self::BrpExtrasQueryChanged => Arc::new(BrpExtrasQueryChanged),
+            Self::BrpExtrasWaitFor => Arc::new(BrpExtrasWaitFor),
+            Self::BrpExtrasQuerySpatial => Arc::new(BrpExtrasQuerySpatial),
+            Self::BrpExtrasPickEntity => Arc::new(BrpExtrasPickEntity),
+            Self::BrpExtrasSetLinearVelocity => Arc::new(BrpExtrasSetLinearVelocity),
+            Self::BrpExtrasGetContacts => Arc::new(BrpExtrasGetContacts),
+            Self::BrpExtrasSetGravity => Arc::new(BrpExtrasSetGravity),
+            Self::BrpExtrasWorldToScreen => Arc::new(BrpExtrasWorldToScreen),
+            Self::BrpExtrasScreenToWorld => Arc::new(BrpExtrasScreenToWorld),
+            Self::BrpExtrasSetCameraTransform => Arc::new(BrpExtrasSetCameraTransform),
+            Self::BrpExtrasLookAt => Arc::new(BrpExtrasLookAt),
+            Self::BrpExtrasOrbitCamera => Arc::new(BrpExtrasOrbitCamera),
+            Self::BrpExtrasDrawGizmo => Arc::new(BrpExtrasDrawGizmo),
+            Self::BrpExtrasHighlightEntity => Arc::new(BrpExtrasHighlightEntity),
+            Self::BrpExtrasPing => Arc::new(BrpExtrasPing),
+            Self::BrpExtrasCapabilities => Arc::new(BrpExtrasCapabilities),
 
             // Special tools with their own implementations
             Self::BrpExecute => Arc::new(BrpExecute),
+            Self::BrpExecuteBatch => Arc::new(BrpExecuteBatch),
+            Self::BrpTransaction => Arc::new(BrpTransaction),
+            Self::BrpWaitUntil => Arc::new(BrpWaitUntil),
             Self::BrpListAgentTools => Arc::new(BrpListAgentTools),
             Self::WorldGetComponentsWatch => Arc::new(WorldGetComponentsWatch),
             Self::WorldListComponentsWatch => Arc::new(BevyListWatch),
+            Self::WorldEventsWatch => Arc::new(WorldEventsWatch),
+            Self::BrpExtrasDiagnosticsWatch => Arc::new(BrpExtrasDiagnosticsWatch),
             Self::BrpListActiveWatches => Arc::new(BrpListActiveWatches),
             Self::BrpStopWatch => Arc::new(BrpStopWatch),
+            Self::BrpConvertWatchLog => Arc::new(BrpConvertWatchLog),
             Self::BrpTypeGuide => Arc::new(BrpTypeGuide),
             Self::BrpAllTypeGuides => Arc::new(BrpAllTypeGuides),
+            Self::BrpGenerateTypes => Arc::new(BrpGenerateTypes),
 
             // App tools
             Self::BrpDeleteLogs => Arc::new(DeleteLogs),
@@ -893,10 +1929,17 @@ impl ToolName {
             Self::BrpListBevy => Arc::new(ListBevy),
             Self::BrpListLogs => Arc::new(ListLogs),
             Self::BrpReadLog => Arc::new(ReadLog),
+            Self::BrpWatchLog => Arc::new(WatchLog),
+            #[cfg(feature = "mcp-debug")]
+            Self::BrpQueryTrace => Arc::new(QueryTrace),
             #[cfg(feature = "mcp-debug")]
             Self::BrpSetTracingLevel => Arc::new(SetTracingLevel),
             Self::BrpStatus => Arc::new(Status),
             Self::BrpShutdown => Arc::new(Shutdown),
+            Self::BrpListSessions => Arc::new(ListSessions),
+            Self::BrpGetCrashReport => Arc::new(GetCrashReport),
+            Self::BrpRunTestPlan => Arc::new(RunTestPlan),
+            Self::BrpRunScenario => Arc::new(RunScenario),
         }
     }
 
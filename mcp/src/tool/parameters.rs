@@ -62,6 +62,8 @@ pub enum ParameterName {
     Component,
     /// Components parameter for operations
     Components,
+    /// Cursor of the next page of a paginated result
+    Cursor,
     /// Data parameter for queries
     Data,
     /// Duration in milliseconds
@@ -70,6 +72,8 @@ pub enum ParameterName {
     Enabled,
     /// Multiple entities for batch operations
     Entities,
+    /// Frames between samples of a watch
+    EveryNFrames,
     /// `Entity` ID parameter
     Entity,
     /// Example name
@@ -88,12 +92,16 @@ pub enum ParameterName {
     Method,
     /// Age threshold in seconds
     OlderThanSeconds,
+    /// Number of items per page of a paginated result
+    PageSize,
     /// Parameters for dynamic method execution
     Params,
     /// Parent entity for reparenting
     Parent,
     /// Path for field mutations or file paths
     Path,
+    /// Several field or diagnostic paths
+    Paths,
     /// Port number for connections
     Port,
     /// Build profile (debug/release)
@@ -578,6 +586,15 @@ pub(super) fn normalize_arguments_for<T: JsonSchema>(arguments: &mut Map<String,
     }
 }
 
+/// Whether a parameter struct declares the named parameter
+pub(super) fn has_parameter<T: JsonSchema>(name: ParameterName) -> bool {
+    let schema = schemars::schema_for!(T);
+    schema
+        .as_object()
+        .and_then(|root_obj| root_obj.get_properties())
+        .is_some_and(|properties| properties.contains_key(name.as_ref()))
+}
+
 /// Build parameters from a `JsonSchema` type directly into a `ParameterBuilder`
 /// All tools with parameters derive `JsonSchema` making it possible for us
 /// to build the parameters from the schema
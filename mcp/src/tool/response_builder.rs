@@ -6,9 +6,13 @@ use super::HandlerContext;
 use super::ParamStruct;
 use super::ParameterName;
 use super::ResultStruct;
+use super::constants::APP_RESTARTED_FIELD;
 use super::constants::ENTITY_COUNT_PLACEHOLDER;
+use super::constants::FAILURE_SCREENSHOT_FIELD;
 use super::constants::OPTIONAL_PARAMETERS_NOT_PROVIDED_FIELD;
+use super::constants::PORT_OWNER_CHANGE_FIELD;
 use super::constants::RESULT_PLACEHOLDER;
+use super::constants::RETRY_FIELD;
 use super::constants::SKIP_NULL_FIELD_SENTINEL;
 use super::field_placement::FieldPlacement;
 use super::json_response::AnySchemaValue;
@@ -53,6 +57,18 @@ impl Response {
         ResponseBuilder::error(call_info).message(message).build()
     }
 
+    /// Create an `app_unreachable` response with message and optional details
+    pub(super) fn app_unreachable(
+        message: impl Into<String>,
+        details: Option<&Value>,
+        call_info: CallInfo,
+    ) -> ToolCallJsonResponse {
+        ResponseBuilder::app_unreachable(call_info)
+            .message(message)
+            .add_optional_details(details)
+            .build()
+    }
+
     /// Create an error response with message and optional details
     pub(super) fn error_with_details(
         message: impl Into<String>,
@@ -82,22 +98,22 @@ pub struct ResponseBuilder {
 impl ResponseBuilder {
     /// Create a success response with call info pre-populated
     pub(super) const fn success(call_info: CallInfo) -> Self {
-        Self {
-            status: ResponseStatus::Success,
-            message: String::new(),
-            call_info,
-            metadata: None,
-            parameters: None,
-            result: None,
-            error_info: None,
-            brp_extras_debug_info: None,
-        }
+        Self::with_status(ResponseStatus::Success, call_info)
     }
 
     /// Create an error response with call info pre-populated
     pub(super) const fn error(call_info: CallInfo) -> Self {
+        Self::with_status(ResponseStatus::Error, call_info)
+    }
+
+    /// Create a response for an app that refused the connection, with call info pre-populated
+    pub(super) const fn app_unreachable(call_info: CallInfo) -> Self {
+        Self::with_status(ResponseStatus::AppUnreachable, call_info)
+    }
+
+    const fn with_status(status: ResponseStatus, call_info: CallInfo) -> Self {
         Self {
-            status: ResponseStatus::Error,
+            status,
             message: String::new(),
             call_info,
             metadata: None,
@@ -302,6 +318,26 @@ impl ResponseBuilder {
             self = self.parameters(params)?;
         }
 
+        // Report automatic retries of transient failures
+        if let Some(retry_report) = handler_context.retry_report() {
+            self = self.add_field(RETRY_FIELD, retry_report)?;
+        }
+
+        // Report that the port now reaches a different process than the previous call did
+        if let Some(port_owner_change) = handler_context.port_owner_change() {
+            self = self.add_field(PORT_OWNER_CHANGE_FIELD, port_owner_change)?;
+        }
+
+        // Report that the app on the port crashed and was relaunched by its restart policy
+        if let Some(app_restart) = handler_context.app_restart() {
+            self = self.add_field(APP_RESTARTED_FIELD, app_restart)?;
+        }
+
+        // Point at the screenshot captured after a failed call
+        if let Some(failure_screenshot) = handler_context.failure_screenshot() {
+            self = self.add_field(FAILURE_SCREENSHOT_FIELD, failure_screenshot)?;
+        }
+
         // Perform template substitution
         let template_str = result.get_message_template()?;
         tracing::debug!("Template before substitution: '{template_str}'");
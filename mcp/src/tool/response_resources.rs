@@ -0,0 +1,219 @@
+//! Large tool results exposed as MCP resources
+//!
+//! Results too large to return inline are published under a `brp-mcp://responses/{id}` URI as
+//! well as being saved to a file, so clients that support MCP resources can read them through the
+//! MCP connection instead of the filesystem. Appending `?start={n}&end={m}` reads only that range
+//! of the result's items - entities for `world_query`, guides for `brp_all_type_guides`, and the
+//! top-level array or object for other tools - so a client can fetch a large result lazily.
+
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use rmcp::model::Resource;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::constants::JSON_MIME_TYPE;
+use super::constants::MAX_RESPONSE_RESOURCES;
+use super::constants::RESPONSE_RESOURCE_URI_PREFIX;
+use super::large_response::PagedItems;
+use super::name::ToolName;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Published results, oldest first
+static RESPONSE_RESOURCES: LazyLock<Mutex<VecDeque<ResponseResource>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::new()));
+
+/// Id of the next published result
+static NEXT_RESPONSE_RESOURCE_ID: AtomicU64 = AtomicU64::new(1);
+
+struct ResponseResource {
+    id:        u64,
+    tool_name: ToolName,
+    result:    Value,
+    /// Size of the serialized result in bytes
+    size:      usize,
+}
+
+impl ResponseResource {
+    fn uri(&self) -> String { format!("{RESPONSE_RESOURCE_URI_PREFIX}{}", self.id) }
+
+    /// The items a range selects from
+    fn items(&self) -> Option<&Value> {
+        match self.tool_name.paged_items() {
+            Some(PagedItems::ResultField(field)) => self.result.get(field),
+            Some(PagedItems::Result) | None => Some(&self.result),
+        }
+    }
+
+    fn item_count(&self) -> usize {
+        match self.items() {
+            Some(Value::Array(items)) => items.len(),
+            Some(Value::Object(entries)) => entries.len(),
+            _ => 0,
+        }
+    }
+
+    fn to_resource(&self) -> Resource {
+        let resource = Resource::new(
+            self.uri(),
+            format!("{} response {}", self.tool_name, self.id),
+        )
+        .with_description(format!(
+            "Result of {} with {} items. Append ?start=N&end=M to read a range of items",
+            self.tool_name,
+            self.item_count()
+        ))
+        .with_mime_type(JSON_MIME_TYPE);
+        match u64::try_from(self.size) {
+            Ok(size) => resource.with_size(size),
+            Err(_) => resource,
+        }
+    }
+
+    /// Items `start..end` of the result, clamped to the number of items
+    fn range(&self, start: usize, end: usize) -> Value {
+        let take = end.saturating_sub(start);
+        match self.items() {
+            Some(Value::Array(items)) => {
+                Value::Array(items.iter().skip(start).take(take).cloned().collect())
+            },
+            Some(Value::Object(entries)) => Value::Object(
+                entries
+                    .iter()
+                    .skip(start)
+                    .take(take)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<Map<String, Value>>(),
+            ),
+            _ => Value::Array(Vec::new()),
+        }
+    }
+}
+
+/// Publish a tool result and return its resource URI
+pub(super) fn publish(tool_name: ToolName, result: &Value, size: usize) -> String {
+    let resource = ResponseResource {
+        id: NEXT_RESPONSE_RESOURCE_ID.fetch_add(1, Ordering::Relaxed),
+        tool_name,
+        result: result.clone(),
+        size,
+    };
+    let uri = resource.uri();
+    if let Ok(mut resources) = RESPONSE_RESOURCES.lock() {
+        if resources.len() >= MAX_RESPONSE_RESOURCES {
+            resources.pop_front();
+        }
+        resources.push_back(resource);
+    }
+    uri
+}
+
+/// Published results, newest first
+pub(super) fn list() -> Vec<Resource> {
+    RESPONSE_RESOURCES
+        .lock()
+        .map(|resources| {
+            resources
+                .iter()
+                .rev()
+                .map(ResponseResource::to_resource)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// JSON text of the result `uri` names, or of the item range its query selects
+pub(super) fn read(uri: &str) -> Result<String> {
+    let unknown = || {
+        Error::InvalidArgument(format!(
+            "Unknown resource '{uri}': only the last {MAX_RESPONSE_RESOURCES} large responses \
+             are kept"
+        ))
+    };
+    let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+    let id: u64 = path
+        .strip_prefix(RESPONSE_RESOURCE_URI_PREFIX)
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(unknown)?;
+    let range = parse_range(query)?;
+
+    let text = RESPONSE_RESOURCES
+        .lock()
+        .map_err(|_| Error::General("Response resource store is poisoned".to_string()))?
+        .iter()
+        .find(|resource| resource.id == id)
+        .map(|resource| match range {
+            Some((start, end)) => serde_json::to_string_pretty(&resource.range(start, end)),
+            None => serde_json::to_string_pretty(&resource.result),
+        })
+        .ok_or_else(unknown)?;
+    text.map_err(|e| Error::General(format!("Failed to serialize resource '{uri}': {e}")).into())
+}
+
+/// The `start` and `end` of a `start=N&end=M` query; either bound may be left out
+fn parse_range(query: &str) -> Result<Option<(usize, usize)>> {
+    if query.is_empty() {
+        return Ok(None);
+    }
+    let mut start = 0;
+    let mut end = usize::MAX;
+    for pair in query.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let bound = value.parse::<usize>().map_err(|_| {
+            Error::invalid(
+                "resource range",
+                format!("'{pair}' is not a non-negative integer"),
+            )
+        });
+        match key {
+            "start" => start = bound?,
+            "end" => end = bound?,
+            _ => {
+                return Err(Error::invalid(
+                    "resource range",
+                    format!("unknown query parameter '{key}', expected start and end"),
+                )
+                .into());
+            },
+        }
+    }
+    Ok(Some((start, end)))
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use super::ToolName;
+    use super::publish;
+    use super::read;
+
+    #[test]
+    fn reads_ranges_of_published_items() {
+        let result = json!({
+            "discovered_count": 3,
+            "type_guide": { "a::A": 1, "b::B": 2, "c::C": 3 }
+        });
+        let uri = publish(ToolName::BrpAllTypeGuides, &result, 0);
+
+        let range: Value =
+            serde_json::from_str(&read(&format!("{uri}?start=1&end=2")).expect("range reads"))
+                .expect("range is JSON");
+        assert_eq!(range, json!({ "b::B": 2 }));
+
+        let whole: Value =
+            serde_json::from_str(&read(&uri).expect("resource reads")).expect("resource is JSON");
+        assert_eq!(whole, result);
+        assert!(read(&format!("{uri}?first=1")).is_err());
+    }
+}
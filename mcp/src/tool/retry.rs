@@ -0,0 +1,149 @@
+//! Automatic retry of transient BRP failures for read-only tools.
+//!
+//! A transient failure is a transport-level problem talking to the app (refused connection,
+//! timeout, reset connection), as opposed to a BRP error the app deliberately returned or a
+//! response that could not be parsed, which would fail the same way again.
+//! Read-only tools are retried on transient failures; tools that change the world never are,
+//! because a request that timed out may already have been applied.
+//!
+//! Every transient failure is counted per tool and failure signature for the lifetime of the
+//! server, so a client can tell a one-off hiccup from a consistently flaky call.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use error_stack::Report;
+use serde::Serialize;
+
+use super::annotations::EnvironmentImpact;
+use super::constants::DEFAULT_READ_RETRIES;
+use super::constants::FAILURE_SIGNATURE_MAX_CHARS;
+use super::constants::READ_RETRIES_ENV_VAR;
+use super::constants::RETRY_BACKOFF;
+use super::def::ToolDef;
+use super::name::ToolName;
+use crate::error::Error;
+
+/// Transient failure counts keyed by tool and failure signature
+static FAILURE_SIGNATURES: LazyLock<Mutex<HashMap<(ToolName, String), u32>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// How many times a tool call may be retried after a transient failure
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RetryPolicy {
+    pub(super) max_retries: u32,
+    pub(super) backoff:     Duration,
+}
+
+impl RetryPolicy {
+    /// Read-only tools use the configured retry budget; everything else is never retried.
+    ///
+    /// The budget defaults to [`DEFAULT_READ_RETRIES`] and can be overridden with the
+    /// `BRP_MCP_READ_RETRIES` environment variable (`0` disables retries).
+    pub(super) fn for_tool(tool_def: &ToolDef) -> Self {
+        let max_retries = if tool_def.annotations.environment_impact == EnvironmentImpact::ReadOnly
+        {
+            std::env::var(READ_RETRIES_ENV_VAR)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(DEFAULT_READ_RETRIES)
+        } else {
+            0
+        };
+
+        Self {
+            max_retries,
+            backoff: RETRY_BACKOFF,
+        }
+    }
+}
+
+/// Retry summary reported in response metadata when a call hit a transient failure
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct RetryReport {
+    /// Number of retries performed after the first attempt
+    pub(super) retries:               u32,
+    /// Whether a retry eventually got past the transient failure
+    pub(super) recovered:             bool,
+    /// Signature of the last transient failure
+    pub(super) failure_signature:     String,
+    /// How many times this tool has hit this signature since the server started
+    pub(super) signature_occurrences: u32,
+}
+
+/// Return a stable signature if the error is a transient transport failure.
+pub(super) fn transient_signature(report: &Report<Error>) -> Option<String> {
+    match report.current_context() {
+        Error::AppUnreachable { message, .. } | Error::Transport(message) => {
+            Some(message.chars().take(FAILURE_SIGNATURE_MAX_CHARS).collect())
+        },
+        _ => None,
+    }
+}
+
+/// Count a transient failure and return how often this tool has seen the signature.
+pub(super) fn record_failure(tool_name: ToolName, signature: &str) -> u32 {
+    let Ok(mut signatures) = FAILURE_SIGNATURES.lock() else {
+        return 1;
+    };
+    let count = signatures
+        .entry((tool_name, signature.to_string()))
+        .or_default();
+    *count = count.saturating_add(1);
+    *count
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+
+    use super::record_failure;
+    use super::transient_signature;
+    use crate::brp_tools::Port;
+    use crate::error::Error;
+    use crate::tool::ToolName;
+
+    const TEST_SIGNATURE: &str = "HTTP request failed for world.query operation - Timeout";
+
+    #[test]
+    fn only_transport_errors_are_transient() {
+        let transport = Report::new(Error::Transport(TEST_SIGNATURE.to_string()));
+        let unreachable = Report::new(Error::AppUnreachable {
+            message:  TEST_SIGNATURE.to_string(),
+            port:     Port::default(),
+            attempts: 1,
+        });
+        let rejected = Report::new(Error::tool_call_failed("Unknown component type"));
+
+        assert_eq!(
+            transient_signature(&transport).as_deref(),
+            Some(TEST_SIGNATURE)
+        );
+        assert_eq!(
+            transient_signature(&unreachable).as_deref(),
+            Some(TEST_SIGNATURE)
+        );
+        assert_eq!(transient_signature(&rejected), None);
+    }
+
+    #[test]
+    fn app_returned_and_parse_errors_are_not_retried() {
+        let app_error = Report::new(Error::BrpCommunication(
+            "Registry call failed: Unknown type".to_string(),
+        ));
+        let parse_error = Report::new(Error::JsonRpc("JSON parsing failed".to_string()));
+
+        assert_eq!(transient_signature(&app_error), None);
+        assert_eq!(transient_signature(&parse_error), None);
+    }
+
+    #[test]
+    fn failures_are_counted_per_tool_and_signature() {
+        let first = record_failure(ToolName::WorldListResources, TEST_SIGNATURE);
+        let second = record_failure(ToolName::WorldListResources, TEST_SIGNATURE);
+
+        assert_eq!(second, first + 1);
+    }
+}
@@ -123,13 +123,22 @@ fn generate_tool_fn_impl(
                     let port = params.port;
                     let params_json = serde_json::to_value(&params).ok();
 
-                    // Filter out transport-only metadata before sending BRP params.
+                    // Filter out transport-only metadata and MCP-side pagination before sending
+                    // BRP params.
                     let mut params_value = serde_json::to_value(&params)
                         .map_err(|e| crate::error::Error::InvalidArgument(format!(
                             "Failed to serialize parameters: {e}"
                         )))?;
                     let brp_params = if let serde_json::Value::Object(ref mut map) = params_value {
-                        map.retain(|key, _| key != &String::from(crate::tool::ParameterName::Port));
+                        map.retain(|key, _| {
+                            ![
+                                crate::tool::ParameterName::Port,
+                                crate::tool::ParameterName::PageSize,
+                                crate::tool::ParameterName::Cursor,
+                            ]
+                            .iter()
+                            .any(|parameter| key == parameter.as_ref())
+                        });
                         if map.is_empty() {
                             None
                         } else {